@@ -0,0 +1,54 @@
+// ============================================================================
+// Benchmark : rendu du graphique en chandeliers
+// ============================================================================
+// Mesure CandlestickRenderer::render_lines sur un grand terminal :
+// 300 colonnes × 60 lignes, 250 chandeliers visibles
+//
+// Lancer : cargo bench --bench candlestick
+// ============================================================================
+
+use chrono::{Duration, TimeZone, Utc};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ratatui::layout::Rect;
+
+use lazywallet::models::{Interval, OHLC};
+use lazywallet::ui::candlestick_text::CandlestickRenderer;
+
+/// Série déterministe de `count` bougies horaires, par séances de 7 heures
+fn candles(count: usize) -> Vec<OHLC> {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    (0..count)
+        .map(|i| {
+            let open = 100.0 + (i as f64 * 0.3).sin() * 10.0;
+            let close = open + (i as f64 * 0.7).cos() * 2.0;
+            OHLC::new(
+                start + Duration::hours((i + i / 7 * 17) as i64),
+                open,
+                open.max(close) + 1.0,
+                open.min(close) - 1.0,
+                close,
+                1_000,
+            )
+        })
+        .collect()
+}
+
+fn bench_render_lines(c: &mut Criterion) {
+    let candles = candles(400);
+    let area = Rect::new(0, 0, 300, 60);
+
+    c.bench_function("render_lines 300x60, 250 bougies", |b| {
+        b.iter(|| CandlestickRenderer::new(black_box(&candles), Interval::H1, area).render_lines())
+    });
+
+    c.bench_function("render_lines 300x60, séparateurs de séance", |b| {
+        b.iter(|| {
+            CandlestickRenderer::new(black_box(&candles), Interval::H1, area)
+                .with_session_separators(true)
+                .render_lines()
+        })
+    });
+}
+
+criterion_group!(benches, bench_render_lines);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+// ============================================================================
+// Exemple : utiliser la couche données sans l'interface terminal
+// ============================================================================
+// cargo run --example watchlist_service -- AAPL BTC-USD
+// ============================================================================
+
+use lazywallet::models::Interval;
+use lazywallet::WatchlistService;
+
+#[tokio::main]
+async fn main() {
+    let mut service = WatchlistService::new();
+    for symbol in std::env::args().skip(1) {
+        service.add(&symbol);
+    }
+    if service.symbols().is_empty() {
+        service.add("AAPL");
+    }
+
+    for (symbol, error) in service.refresh().await {
+        eprintln!("{} : {}", symbol, error);
+    }
+
+    for quote in service.quotes() {
+        match (quote.price, quote.change_percent) {
+            (Some(price), Some(change)) => println!("{:<10} {:>12.2} {:>+7.2}%  {}", quote.symbol, price, change, quote.name),
+            _ => println!("{:<10} {:>12}", quote.symbol, "—"),
+        }
+    }
+
+    // Historique journalier du premier symbole (chargé puis mis en cache)
+    let first = service.symbols()[0].to_string();
+    if let Ok(daily) = service.fetch_history(&first, Interval::D1).await {
+        println!("{} : {} bougies journalières", first, daily.len());
+    }
+}
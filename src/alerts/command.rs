@@ -0,0 +1,263 @@
+// ============================================================================
+// Commande shell exécutée au déclenchement d'une alerte
+// ============================================================================
+// Alternative aux webhooks : un modèle de commande, par exemple
+//
+//   notify-send "LazyWallet" "{symbol} : {condition} ({price})"
+//
+// Variables disponibles : {symbol}, {condition}, {price}, {time}
+//
+// CONCEPT SÉCURITÉ : pas de shell
+// - Le modèle est découpé en arguments AVANT la substitution (guillemets
+//   simples / doubles et '\' pris en charge)
+// - Les valeurs substituées restent chacune dans leur argument : un
+//   symbole contenant "; rm -rf ~" n'est jamais interprété
+// - Le programme est lancé directement (std::process::Command), sans sh -c
+// - Substitution en une seule passe : un "{time}" présent dans le symbole ou
+//   la condition reste tel quel
+//
+// CONCEPT : Délai maximal
+// - Un script bloqué est tué après COMMAND_TIMEOUT
+// - La commande tourne dans son propre thread (voir spawn_alert_command) :
+//   les téléchargements et rafraîchissements n'attendent jamais un script
+// ============================================================================
+
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+
+use crate::alerts::AlertTrigger;
+
+/// Durée maximale d'une commande d'alerte avant d'être tuée
+pub const COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Intervalle entre deux vérifications de la fin du processus
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Découpe un modèle de commande en arguments (façon shell, sans expansion)
+fn split_args(template: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    let mut chars = template.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            // Fin de la zone entre guillemets
+            (Some(q), c) if c == q => quote = None,
+            // '\' échappe le caractère suivant (sauf entre guillemets simples)
+            (None, '\\') | (Some('"'), '\\') => {
+                current.push(chars.next().context("'\\' en fin de commande")?);
+                in_arg = true;
+            }
+            (Some(_), c) => current.push(c),
+            (None, '"') | (None, '\'') => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            (None, c) => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        anyhow::bail!("Guillemet non fermé dans la commande : {}", template);
+    }
+    if in_arg {
+        args.push(current);
+    }
+    if args.is_empty() {
+        anyhow::bail!("Commande d'alerte vide");
+    }
+
+    Ok(args)
+}
+
+/// Construit la liste d'arguments de la commande pour une alerte déclenchée
+pub fn build_command(template: &str, trigger: &AlertTrigger) -> Result<Vec<String>> {
+    let price = format!("{:.2}", trigger.price);
+    let time = trigger.time.to_rfc3339();
+
+    let values = [
+        ("{symbol}", trigger.symbol.as_str()),
+        ("{condition}", trigger.condition.as_str()),
+        ("{price}", price.as_str()),
+        ("{time}", time.as_str()),
+    ];
+
+    Ok(split_args(template)?.iter().map(|arg| substitute(arg, &values)).collect())
+}
+
+/// Remplace les variables d'un argument en une seule passe
+///
+/// Le texte substitué n'est jamais relu : une valeur contenant "{time}"
+/// reste telle quelle
+fn substitute(arg: &str, values: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(arg.len());
+    let mut rest = arg;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match values.iter().find(|(name, _)| rest.starts_with(name)) {
+            Some((name, value)) => {
+                result.push_str(value);
+                rest = &rest[name.len()..];
+            }
+            None => {
+                result.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Exécute la commande d'une alerte et retourne sa sortie (stdout + stderr)
+///
+/// Bloquant, au plus COMMAND_TIMEOUT : préférer spawn_alert_command
+pub fn run_alert_command(template: &str, trigger: &AlertTrigger) -> Result<String> {
+    run_with_timeout(&build_command(template, trigger)?, COMMAND_TIMEOUT)
+}
+
+/// Lance la commande d'une alerte dans un thread dédié
+///
+/// L'appelant n'attend pas : le résultat est lu sur le JoinHandle (ou
+/// journalisé par le thread lui-même)
+pub fn spawn_alert_command<F>(template: String, trigger: AlertTrigger, on_done: F) -> JoinHandle<()>
+where
+    F: FnOnce(&AlertTrigger, Result<String>) + Send + 'static,
+{
+    std::thread::spawn(move || {
+        let result = run_alert_command(&template, &trigger);
+        on_done(&trigger, result);
+    })
+}
+
+/// Lance un programme, attend sa fin au plus `timeout` et le tue au-delà
+fn run_with_timeout(args: &[String], timeout: Duration) -> Result<String> {
+    let mut child = Command::new(&args[0])
+        .args(&args[1..])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Impossible de lancer {}", args[0]))?;
+
+    // Sorties lues en parallèle : un tuyau plein bloquerait le programme
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() >= timeout {
+            kill(&mut child);
+            anyhow::bail!("{} interrompu après {} s", args[0], timeout.as_secs_f64());
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    };
+
+    let text = format!("{}{}", stdout.join().unwrap_or_default(), stderr.join().unwrap_or_default())
+        .trim()
+        .to_string();
+
+    if !status.success() {
+        anyhow::bail!("{} a échoué ({}) : {}", args[0], status, text);
+    }
+
+    Ok(text)
+}
+
+/// Lit un tuyau jusqu'à sa fermeture, dans un thread
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+/// Tue le processus et récupère son statut (pas de zombie)
+fn kill(child: &mut Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn trigger(symbol: &str) -> AlertTrigger {
+        AlertTrigger {
+            symbol: symbol.to_string(),
+            condition: "RSI(14) < 30".to_string(),
+            price: 168.421,
+            time: Utc.with_ymd_and_hms(2024, 5, 2, 14, 30, 0).unwrap(),
+            webhook: None,
+            command: None,
+        }
+    }
+
+    #[test]
+    fn test_split_args() {
+        assert_eq!(
+            split_args(r#"notify-send "LazyWallet alert" 'it''s'  a\ b"#).unwrap(),
+            vec!["notify-send", "LazyWallet alert", "its", "a b"]
+        );
+        assert_eq!(split_args(r#"echo """#).unwrap(), vec!["echo", ""]);
+        assert!(split_args(r#"echo "oops"#).is_err());
+        assert!(split_args("   ").is_err());
+    }
+
+    #[test]
+    fn test_substitution_stays_in_one_argument() {
+        let args = build_command(r#"notify-send "{symbol} hit {price}""#, &trigger("AAPL")).unwrap();
+        assert_eq!(args, vec!["notify-send", "AAPL hit 168.42"]);
+
+        // Une valeur hostile ne crée pas de nouvel argument
+        let args = build_command("echo {symbol}", &trigger("X; rm -rf ~")).unwrap();
+        assert_eq!(args, vec!["echo", "X; rm -rf ~"]);
+    }
+
+    #[test]
+    fn test_substitution_is_single_pass() {
+        let args = build_command("echo {symbol}@{time} {unknown} {", &trigger("{time}")).unwrap();
+        assert_eq!(args, vec!["echo", "{time}@2024-05-02T14:30:00+00:00", "{unknown}", "{"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_with_timeout() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(run_with_timeout(&args(&["echo", "ok"]), Duration::from_secs(5)).unwrap(), "ok");
+        assert!(run_with_timeout(&args(&["false"]), Duration::from_secs(5)).is_err());
+
+        // Script bloqué : tué une fois le délai écoulé
+        let started = Instant::now();
+        let error = run_with_timeout(&args(&["sleep", "10"]), Duration::from_millis(200)).unwrap_err();
+        assert!(error.to_string().contains("interrompu"), "{}", error);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
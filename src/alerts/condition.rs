@@ -0,0 +1,261 @@
+// ============================================================================
+// Conditions d'alerte sur indicateurs
+// ============================================================================
+// Petit langage d'expressions : <opérande> <comparaison> <opérande>
+//
+//   RSI(14) < 30
+//   price > 200
+//   price crosses SMA50
+//   SMA(20) crosses above SMA(50)
+//
+// Opérandes   : price (ou close), RSI(n), SMA(n) (SMA50 accepté), nombre
+// Comparaison : <, <=, >, >=, crosses, crosses above, crosses below
+//
+// CONCEPT SERDE : try_from / into
+// - Dans alerts.json une condition est stockée sous forme de texte
+// - La désérialisation passe par Condition::parse : un fichier édité à la
+//   main avec une expression invalide est refusé avec un message clair
+// ============================================================================
+
+use std::fmt;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::models::indicators::{rsi, sma};
+use crate::models::OHLCData;
+
+/// Période par défaut quand elle est omise ("RSI" = "RSI(14)")
+const DEFAULT_RSI_PERIOD: usize = 14;
+const DEFAULT_SMA_PERIOD: usize = 20;
+
+/// Membre d'une condition
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand {
+    /// Prix de clôture
+    Price,
+    /// RSI sur n périodes
+    Rsi(usize),
+    /// Moyenne mobile simple sur n périodes
+    Sma(usize),
+    /// Valeur constante
+    Value(f64),
+}
+
+/// Opérateur de comparaison
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    /// Passe au-dessus ou en dessous (entre les deux dernières chandelles)
+    Crosses,
+    CrossesAbove,
+    CrossesBelow,
+}
+
+/// Opérateurs reconnus, les plus longs d'abord ("<=" avant "<")
+const COMPARISONS: [(&str, Comparison); 7] = [
+    ("crosses above", Comparison::CrossesAbove),
+    ("crosses below", Comparison::CrossesBelow),
+    ("crosses", Comparison::Crosses),
+    ("<=", Comparison::LessOrEqual),
+    (">=", Comparison::GreaterOrEqual),
+    ("<", Comparison::Less),
+    (">", Comparison::Greater),
+];
+
+/// Condition d'alerte : left <comparison> right
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Condition {
+    pub left: Operand,
+    pub comparison: Comparison,
+    pub right: Operand,
+}
+
+impl Condition {
+    /// Parse une expression du type "RSI(14) < 30" (insensible à la casse)
+    pub fn parse(input: &str) -> Result<Self> {
+        let lower = input.trim().to_lowercase();
+
+        let (left, comparison, right) = COMPARISONS
+            .iter()
+            .find_map(|(token, comparison)| {
+                let (left, right) = lower.split_once(token)?;
+                Some((left, *comparison, right))
+            })
+            .with_context(|| format!("Opérateur manquant dans \"{}\" (<, >, <=, >=, crosses)", input.trim()))?;
+
+        Ok(Self {
+            left: parse_operand(left)?,
+            comparison,
+            right: parse_operand(right)?,
+        })
+    }
+
+    /// Évalue la condition sur la dernière chandelle des données
+    ///
+    /// None si les indicateurs ne sont pas encore définis (historique trop court)
+    pub fn evaluate(&self, data: &OHLCData) -> Option<bool> {
+        let closes = data.closes();
+        let last = closes.len().checked_sub(1)?;
+        let left = operand_series(self.left, &closes);
+        let right = operand_series(self.right, &closes);
+        let diff = |i: usize| Some(left[i]? - right[i]?);
+
+        let result = match self.comparison {
+            Comparison::Less => diff(last)? < 0.0,
+            Comparison::LessOrEqual => diff(last)? <= 0.0,
+            Comparison::Greater => diff(last)? > 0.0,
+            Comparison::GreaterOrEqual => diff(last)? >= 0.0,
+            crossing => {
+                let (previous, current) = (diff(last.checked_sub(1)?)?, diff(last)?);
+                let above = previous <= 0.0 && current > 0.0;
+                let below = previous >= 0.0 && current < 0.0;
+                match crossing {
+                    Comparison::CrossesAbove => above,
+                    Comparison::CrossesBelow => below,
+                    _ => above || below,
+                }
+            }
+        };
+
+        Some(result)
+    }
+}
+
+/// Parse un opérande ("price", "rsi(14)", "sma50", "30", "1,5")
+fn parse_operand(input: &str) -> Result<Operand> {
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+
+    let period = |rest: &str, default: usize| -> Result<usize> {
+        let digits = rest.trim_start_matches('(').trim_end_matches(')');
+        if digits.is_empty() {
+            return Ok(default);
+        }
+        match digits.parse::<usize>() {
+            Ok(period) if period > 0 => Ok(period),
+            _ => anyhow::bail!("Période invalide : \"{}\"", rest),
+        }
+    };
+
+    match compact.as_str() {
+        "" => anyhow::bail!("Opérande manquant"),
+        "price" | "close" | "prix" => Ok(Operand::Price),
+        other => {
+            if let Some(rest) = other.strip_prefix("rsi") {
+                Ok(Operand::Rsi(period(rest, DEFAULT_RSI_PERIOD)?))
+            } else if let Some(rest) = other.strip_prefix("sma") {
+                Ok(Operand::Sma(period(rest, DEFAULT_SMA_PERIOD)?))
+            } else {
+                other
+                    .replace(',', ".")
+                    .parse::<f64>()
+                    .map(Operand::Value)
+                    .with_context(|| format!("Opérande inconnu : \"{}\"", input.trim()))
+            }
+        }
+    }
+}
+
+/// Valeurs d'un opérande pour chaque chandelle
+fn operand_series(operand: Operand, closes: &[f64]) -> Vec<Option<f64>> {
+    match operand {
+        Operand::Price => closes.iter().map(|close| Some(*close)).collect(),
+        Operand::Rsi(period) => rsi(closes, period),
+        Operand::Sma(period) => sma(closes, period),
+        Operand::Value(value) => vec![Some(value); closes.len()],
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Price => write!(f, "price"),
+            Operand::Rsi(period) => write!(f, "RSI({})", period),
+            Operand::Sma(period) => write!(f, "SMA({})", period),
+            Operand::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let token = COMPARISONS
+            .iter()
+            .find(|(_, comparison)| *comparison == self.comparison)
+            .map(|(token, _)| *token)
+            .unwrap_or("?");
+        write!(f, "{} {} {}", self.left, token, self.right)
+    }
+}
+
+impl TryFrom<String> for Condition {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Condition::parse(&value)
+    }
+}
+
+impl From<Condition> for String {
+    fn from(condition: Condition) -> Self {
+        condition.to_string()
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_conditions() {
+        let condition = Condition::parse("RSI(14) < 30").unwrap();
+        assert_eq!(condition.left, Operand::Rsi(14));
+        assert_eq!(condition.comparison, Comparison::Less);
+        assert_eq!(condition.right, Operand::Value(30.0));
+
+        let condition = Condition::parse("price crosses SMA50").unwrap();
+        assert_eq!(condition.comparison, Comparison::Crosses);
+        assert_eq!(condition.right, Operand::Sma(50));
+        assert_eq!(condition.to_string(), "price crosses SMA(50)");
+
+        assert_eq!(Condition::parse("sma(20) crosses above sma(50)").unwrap().comparison, Comparison::CrossesAbove);
+        assert_eq!(Condition::parse("price >= 1,5").unwrap().right, Operand::Value(1.5));
+
+        assert!(Condition::parse("price 30").is_err());
+        assert!(Condition::parse("volume > 30").is_err());
+        assert!(Condition::parse("RSI(0) < 30").is_err());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let data = OHLCData::from_closes(&[10.0, 11.0, 12.0, 9.0]);
+
+        assert_eq!(Condition::parse("price < 10").unwrap().evaluate(&data), Some(true));
+        assert_eq!(Condition::parse("price > 10").unwrap().evaluate(&data), Some(false));
+
+        // SMA(2) : 11.5 puis 10.5 ; prix 12 puis 9 → passe en dessous
+        let crossing = Condition::parse("price crosses below SMA(2)").unwrap();
+        assert_eq!(crossing.evaluate(&data), Some(true));
+        assert_eq!(Condition::parse("price crosses above SMA(2)").unwrap().evaluate(&data), Some(false));
+
+        // Historique trop court pour SMA(50)
+        assert_eq!(Condition::parse("price > SMA50").unwrap().evaluate(&data), None);
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let condition = Condition::parse("RSI < 30").unwrap();
+        let json = serde_json::to_string(&condition).unwrap();
+        assert_eq!(json, "\"RSI(14) < 30\"");
+        assert_eq!(serde_json::from_str::<Condition>(&json).unwrap(), condition);
+        assert!(serde_json::from_str::<Condition>("\"nope\"").is_err());
+    }
+}
@@ -0,0 +1,526 @@
+// ============================================================================
+// Alertes
+// ============================================================================
+// Notifications persistantes affichées dans un bandeau jusqu'à ce que
+// l'utilisateur les ferme (touche 'x'), contrairement aux toasts qui
+// disparaissent seuls
+//
+// CONCEPT : Déclenchement unique
+// - Chaque alerte possède une clé (ex: "earnings:AAPL:2024-05-02")
+// - App retient les clés déjà déclenchées pour ne pas répéter l'alerte à
+//   chaque tick
+//
+// Alertes sur indicateurs (voir condition.rs) : définies par l'utilisateur,
+// sauvegardées dans ~/.config/lazywallet/alerts.json (alerts.toml avec
+// "storage_format": "toml", voir persist.rs)
+// {
+//   "rules": [
+//     { "symbol": "AAPL", "condition": "RSI(14) < 30" },
+//     { "symbol": "BTC-USD", "condition": "price crosses SMA(50)",
+//       "webhook": "https://ntfy.sh/mon-topic" },
+//     { "symbol": "MSFT", "condition": "price > 450",
+//       "command": "notify-send \"{symbol} hit {price}\"" },
+//     { "symbol": "NVDA", "condition": "price > 1000", "recurrence": "once" },
+//     { "symbol": "TSLA", "condition": "price < 150", "recurrence": "rearm 3%" }
+//   ]
+// }
+//
+// CONCEPT : Récurrence d'une règle
+// - Par défaut : à chaque fois que la condition redevient vraie
+// - "once" : une seule fois, puis la règle est désactivée
+// - "rearm 3%" : après un déclenchement, la règle ne se réarme que lorsque
+//   le prix s'est éloigné de 3% du prix de déclenchement (condition fausse) :
+//   un cours qui oscille autour du seuil ne notifie plus en boucle
+// - Snooze (touche [z] du bandeau) : la règle se tait pendant une durée
+// ============================================================================
+
+pub mod command;
+pub mod condition;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::WatchlistItem;
+use crate::persist;
+use crate::profile;
+use condition::Condition;
+
+/// Alerte en attente d'être vue par l'utilisateur
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// Clé unique (évite les doublons)
+    pub key: String,
+
+    /// Symbole concerné
+    pub symbol: String,
+
+    /// Texte affiché dans le bandeau
+    pub message: String,
+
+    /// Clé de la règle d'alerte à l'origine de la notification (snooze)
+    pub rule: Option<String>,
+}
+
+/// Alertes "résultats demain" pour les items de la watchlist
+///
+/// Une alerte par ticker dont la publication des résultats a lieu le
+/// lendemain de `today`
+pub fn earnings_alerts(watchlist: &[WatchlistItem], today: NaiveDate) -> Vec<Notification> {
+    watchlist
+        .iter()
+        .filter(|item| item.days_until_earnings(today) == Some(1))
+        .filter_map(|item| {
+            let date = item.earnings_date?.date_naive();
+            Some(Notification {
+                key: format!("earnings:{}:{}", item.symbol, date),
+                symbol: item.symbol.clone(),
+                message: format!("📅 {} publie ses résultats demain ({})", item.symbol, date.format("%d/%m")),
+                rule: None,
+            })
+        })
+        .collect()
+}
+
+// ============================================================================
+// Alertes sur indicateurs
+// ============================================================================
+
+/// Récurrence d'une règle : combien de fois elle peut se déclencher
+///
+/// CONCEPT RUST : Sérialisation via String
+/// - "once", "rearm 3%" dans alerts.json (absent : à chaque fois)
+/// - try_from / into : serde passe par parse() et Display
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Recurrence {
+    /// À chaque fois que la condition redevient vraie
+    #[default]
+    EveryTime,
+
+    /// Une seule fois, puis la règle est désactivée
+    Once,
+
+    /// Réarmée quand le prix s'est éloigné de `percent`% du prix de déclenchement
+    Rearm { percent: f64 },
+}
+
+impl Recurrence {
+    /// Lit "every", "once" ou "rearm 3%" (insensible à la casse)
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim().to_lowercase();
+        match input.as_str() {
+            "" | "every" => return Ok(Recurrence::EveryTime),
+            "once" => return Ok(Recurrence::Once),
+            _ => {}
+        }
+
+        let Some(percent) = input.strip_prefix("rearm") else {
+            bail!("Récurrence inconnue : \"{}\" (once, rearm 3%)", input);
+        };
+        match percent.trim().trim_end_matches('%').trim().parse::<f64>() {
+            Ok(percent) if percent > 0.0 && percent.is_finite() => Ok(Recurrence::Rearm { percent }),
+            _ => bail!("Pourcentage de réarmement invalide : \"{}\" (ex: rearm 3%)", percent.trim()),
+        }
+    }
+
+    /// Sépare la récurrence en fin de saisie : "price > 200 rearm 2%" → ("price > 200", Rearm 2%)
+    ///
+    /// Sans mot-clé en fin de saisie : EveryTime
+    pub fn split_input(input: &str) -> Result<(&str, Self)> {
+        let lower = input.to_lowercase();
+        if let Some(position) = lower.rfind(" rearm") {
+            return Ok((&input[..position], Self::parse(&input[position..])?));
+        }
+        if lower.trim_end().ends_with(" once") {
+            let end = input.trim_end().len() - " once".len();
+            return Ok((&input[..end], Recurrence::Once));
+        }
+        Ok((input, Recurrence::EveryTime))
+    }
+
+    fn is_every_time(&self) -> bool {
+        *self == Recurrence::EveryTime
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Recurrence::EveryTime => write!(f, "every"),
+            Recurrence::Once => write!(f, "once"),
+            Recurrence::Rearm { percent } => write!(f, "rearm {}%", percent),
+        }
+    }
+}
+
+impl TryFrom<String> for Recurrence {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::parse(&value)
+    }
+}
+
+impl From<Recurrence> for String {
+    fn from(recurrence: Recurrence) -> Self {
+        recurrence.to_string()
+    }
+}
+
+/// Règle d'alerte définie par l'utilisateur
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertRule {
+    /// Symbole surveillé
+    pub symbol: String,
+
+    /// Condition à surveiller (ex: "RSI(14) < 30")
+    pub condition: Condition,
+
+    /// URL appelée (POST JSON) quand l'alerte se déclenche
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+
+    /// Commande lancée quand l'alerte se déclenche (voir command.rs)
+    /// Sans valeur : config.alert_command s'applique
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// Nombre de déclenchements possibles (à chaque fois par défaut)
+    #[serde(default, skip_serializing_if = "Recurrence::is_every_time")]
+    pub recurrence: Recurrence,
+
+    /// Règle désactivée (règle "once" déjà déclenchée)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+
+    /// Règle muette jusqu'à cette date (snooze depuis le bandeau)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until: Option<DateTime<Utc>>,
+}
+
+impl AlertRule {
+    /// Règle déclenchée à chaque fois, sans webhook ni commande
+    pub fn new(symbol: &str, condition: Condition) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            condition,
+            webhook: None,
+            command: None,
+            recurrence: Recurrence::default(),
+            disabled: false,
+            snoozed_until: None,
+        }
+    }
+
+    /// Clé identifiant la règle (suivi de son état entre deux évaluations)
+    pub fn key(&self) -> String {
+        format!("{}:{}", self.symbol, self.condition)
+    }
+
+    /// Indique si la règle est en snooze à `now`
+    pub fn is_snoozed(&self, now: DateTime<Utc>) -> bool {
+        self.snoozed_until.is_some_and(|until| until > now)
+    }
+}
+
+/// Ensemble des règles d'alerte (fichier alerts.json)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AlertBook {
+    #[serde(default)]
+    pub rules: Vec<AlertRule>,
+}
+
+impl AlertBook {
+    /// Chemin par défaut du fichier des alertes
+    ///
+    /// - Linux : ~/.config/lazywallet/alerts.json (alerts.toml en TOML)
+    /// - Profil : ~/.config/lazywallet/profiles/<nom>/alerts.json
+    /// - Fallback : ./alerts.json
+    pub fn default_path() -> PathBuf {
+        let name = persist::file_name("alerts");
+        profile::config_dir()
+            .map(|dir| dir.join(&name))
+            .unwrap_or_else(|| PathBuf::from(".").join(name))
+    }
+
+    /// Charge les alertes (absent = aucune alerte)
+    pub fn load(path: &Path) -> Result<Self> {
+        persist::load(path)
+    }
+
+    /// Sauvegarde les alertes (crée le répertoire si besoin)
+    ///
+    /// Règles triées par symbole puis condition : l'ordre du fichier ne
+    /// dépend pas de l'ordre de création
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut sorted = self.clone();
+        sorted.rules.sort_by_cached_key(AlertRule::key);
+        persist::save(path, &sorted).map(|_| ())
+    }
+}
+
+/// Alerte déclenchée (condition devenue vraie)
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertTrigger {
+    pub symbol: String,
+    pub condition: String,
+
+    /// Dernier prix au moment du déclenchement
+    pub price: f64,
+
+    /// Horodatage de la chandelle qui a déclenché l'alerte
+    pub time: DateTime<Utc>,
+
+    /// Webhook de la règle (voir api::webhook)
+    pub webhook: Option<String>,
+
+    /// Modèle de commande shell à exécuter (voir command.rs)
+    pub command: Option<String>,
+}
+
+impl AlertTrigger {
+    /// Notification affichée dans le bandeau
+    pub fn notification(&self) -> Notification {
+        Notification {
+            key: format!("alert:{}:{}:{}", self.symbol, self.condition, self.time.timestamp()),
+            symbol: self.symbol.clone(),
+            message: format!("🔔 {} : {} (prix {:.2})", self.symbol, self.condition, self.price),
+            rule: Some(format!("{}:{}", self.symbol, self.condition)),
+        }
+    }
+}
+
+/// État d'une règle entre deux évaluations
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RuleState {
+    /// Résultat de la dernière évaluation
+    pub active: bool,
+
+    /// Prix du dernier déclenchement, tant que la règle n'est pas réarmée
+    /// (récurrence "rearm" seulement)
+    pub disarmed_at: Option<f64>,
+}
+
+/// Évalue les règles sur les données de la watchlist
+///
+/// CONCEPT : Déclenchement sur front montant
+/// - `states` retient le dernier résultat de chaque règle
+/// - Une alerte ne se déclenche que lorsque sa condition devient vraie :
+///   "RSI < 30" ne notifie qu'une fois tant que le RSI reste sous 30
+///
+/// Règles désactivées ou en snooze : l'état est suivi mais rien ne se
+/// déclenche. Une règle "once" déclenchée est désactivée ici (à sauvegarder)
+pub fn evaluate_rules(
+    rules: &mut [AlertRule],
+    watchlist: &[WatchlistItem],
+    states: &mut HashMap<String, RuleState>,
+    now: DateTime<Utc>,
+) -> Vec<AlertTrigger> {
+    let mut triggers = Vec::new();
+
+    for rule in rules.iter_mut() {
+        let Some(data) = watchlist
+            .iter()
+            .find(|item| item.symbol == rule.symbol)
+            .and_then(|item| item.data.as_ref())
+        else {
+            continue;
+        };
+        let Some(active) = rule.condition.evaluate(data) else {
+            continue;
+        };
+
+        let Some(last) = data.last() else {
+            continue;
+        };
+
+        let state = states.entry(rule.key()).or_default();
+        let was_active = std::mem::replace(&mut state.active, active);
+
+        // Réarmement : condition fausse et prix assez loin du déclenchement
+        if let (Recurrence::Rearm { percent }, Some(price)) = (rule.recurrence, state.disarmed_at) {
+            if !active && price != 0.0 && ((last.close - price) / price).abs() * 100.0 >= percent {
+                state.disarmed_at = None;
+            }
+        }
+
+        let silent = rule.disabled || rule.is_snoozed(now) || state.disarmed_at.is_some();
+        if active && !was_active && !silent {
+            match rule.recurrence {
+                Recurrence::EveryTime => {}
+                Recurrence::Once => rule.disabled = true,
+                Recurrence::Rearm { .. } => state.disarmed_at = Some(last.close),
+            }
+            triggers.push(AlertTrigger {
+                symbol: rule.symbol.clone(),
+                condition: rule.condition.to_string(),
+                price: last.close,
+                time: last.timestamp,
+                webhook: rule.webhook.clone(),
+                command: rule.command.clone(),
+            });
+        }
+    }
+
+    triggers
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interval, OHLCData, OHLC};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_earnings_alerts_day_before() {
+        let mut aapl = WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string());
+        aapl.earnings_date = Some(Utc.with_ymd_and_hms(2024, 5, 2, 20, 30, 0).unwrap());
+        let mut msft = WatchlistItem::new("MSFT".to_string(), "Microsoft".to_string());
+        msft.earnings_date = Some(Utc.with_ymd_and_hms(2024, 5, 10, 20, 30, 0).unwrap());
+        let watchlist = vec![aapl, msft];
+
+        let alerts = earnings_alerts(&watchlist, NaiveDate::from_ymd_opt(2024, 5, 1).unwrap());
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].key, "earnings:AAPL:2024-05-02");
+
+        assert!(earnings_alerts(&watchlist, NaiveDate::from_ymd_opt(2024, 4, 28).unwrap()).is_empty());
+    }
+
+    fn watchlist_at(price: f64) -> Vec<WatchlistItem> {
+        let mut data = OHLCData::with_interval("AAPL".to_string(), Interval::D1);
+        data.add_candle(OHLC::new(Utc::now(), price, price, price, price, 0));
+        vec![WatchlistItem::with_data("AAPL".to_string(), "Apple".to_string(), data)]
+    }
+
+    fn set_price(watchlist: &mut [WatchlistItem], price: f64) {
+        watchlist[0].data.as_mut().unwrap().candles[0].close = price;
+    }
+
+    #[test]
+    fn test_rule_fires_on_rising_edge() {
+        let mut watchlist = watchlist_at(150.0);
+        let mut rules = vec![AlertRule::new("AAPL", Condition::parse("price > 140").unwrap())];
+        let mut states = HashMap::new();
+        let now = Utc::now();
+
+        assert_eq!(evaluate_rules(&mut rules, &watchlist, &mut states, now).len(), 1);
+        // Condition toujours vraie : pas de nouvelle alerte
+        assert!(evaluate_rules(&mut rules, &watchlist, &mut states, now).is_empty());
+
+        // Repasse sous 140 puis au-dessus : nouvelle alerte
+        set_price(&mut watchlist, 130.0);
+        assert!(evaluate_rules(&mut rules, &watchlist, &mut states, now).is_empty());
+        set_price(&mut watchlist, 145.0);
+        assert_eq!(evaluate_rules(&mut rules, &watchlist, &mut states, now)[0].price, 145.0);
+    }
+
+    #[test]
+    fn test_once_rule_is_disabled_after_trigger() {
+        let mut watchlist = watchlist_at(150.0);
+        let mut rules = vec![AlertRule::new("AAPL", Condition::parse("price > 140").unwrap())];
+        rules[0].recurrence = Recurrence::Once;
+        let mut states = HashMap::new();
+        let now = Utc::now();
+
+        assert_eq!(evaluate_rules(&mut rules, &watchlist, &mut states, now).len(), 1);
+        assert!(rules[0].disabled);
+
+        set_price(&mut watchlist, 130.0);
+        evaluate_rules(&mut rules, &watchlist, &mut states, now);
+        set_price(&mut watchlist, 145.0);
+        assert!(evaluate_rules(&mut rules, &watchlist, &mut states, now).is_empty());
+    }
+
+    #[test]
+    fn test_rearm_rule_waits_for_retrace() {
+        let mut watchlist = watchlist_at(101.0);
+        let mut rules = vec![AlertRule::new("AAPL", Condition::parse("price > 100").unwrap())];
+        rules[0].recurrence = Recurrence::Rearm { percent: 5.0 };
+        let mut states = HashMap::new();
+        let now = Utc::now();
+
+        assert_eq!(evaluate_rules(&mut rules, &watchlist, &mut states, now).len(), 1);
+
+        // Oscillation autour du seuil : pas de nouvelle alerte
+        set_price(&mut watchlist, 99.0);
+        evaluate_rules(&mut rules, &watchlist, &mut states, now);
+        set_price(&mut watchlist, 101.0);
+        assert!(evaluate_rules(&mut rules, &watchlist, &mut states, now).is_empty());
+
+        // Repli de plus de 5% depuis 101 : la règle est réarmée
+        set_price(&mut watchlist, 95.0);
+        evaluate_rules(&mut rules, &watchlist, &mut states, now);
+        set_price(&mut watchlist, 102.0);
+        assert_eq!(evaluate_rules(&mut rules, &watchlist, &mut states, now)[0].price, 102.0);
+    }
+
+    #[test]
+    fn test_snoozed_rule_stays_silent() {
+        let mut watchlist = watchlist_at(150.0);
+        let mut rules = vec![AlertRule::new("AAPL", Condition::parse("price > 140").unwrap())];
+        let now = Utc::now();
+        rules[0].snoozed_until = Some(now + chrono::Duration::minutes(30));
+        let mut states = HashMap::new();
+
+        assert!(evaluate_rules(&mut rules, &watchlist, &mut states, now).is_empty());
+
+        // Fin du snooze : le prochain front montant se déclenche
+        let later = now + chrono::Duration::hours(1);
+        set_price(&mut watchlist, 130.0);
+        evaluate_rules(&mut rules, &watchlist, &mut states, later);
+        set_price(&mut watchlist, 145.0);
+        assert_eq!(evaluate_rules(&mut rules, &watchlist, &mut states, later).len(), 1);
+    }
+
+    #[test]
+    fn test_recurrence_parse_and_split() {
+        assert_eq!(Recurrence::parse("once").unwrap(), Recurrence::Once);
+        assert_eq!(Recurrence::parse("Rearm 2.5%").unwrap(), Recurrence::Rearm { percent: 2.5 });
+        assert!(Recurrence::parse("rearm -1%").is_err());
+        assert!(Recurrence::parse("twice").is_err());
+
+        assert_eq!(
+            Recurrence::split_input("price > 200 rearm 2%").unwrap(),
+            ("price > 200", Recurrence::Rearm { percent: 2.0 })
+        );
+        assert_eq!(Recurrence::split_input("RSI(14) < 30 once").unwrap(), ("RSI(14) < 30", Recurrence::Once));
+        assert_eq!(Recurrence::split_input("price > 200").unwrap(), ("price > 200", Recurrence::EveryTime));
+
+        // Sérialisation : champ absent pour EveryTime, texte sinon
+        let mut rule = AlertRule::new("AAPL", Condition::parse("price > 200").unwrap());
+        assert!(!serde_json::to_string(&rule).unwrap().contains("recurrence"));
+        rule.recurrence = Recurrence::Rearm { percent: 3.0 };
+        let json = serde_json::to_string(&rule).unwrap();
+        assert!(json.contains("\"recurrence\":\"rearm 3%\""), "{}", json);
+        assert_eq!(serde_json::from_str::<AlertRule>(&json).unwrap(), rule);
+    }
+
+    #[test]
+    fn test_save_toml_sorts_rules() {
+        let dir = std::env::temp_dir().join(format!("lazywallet-alerts-{}", std::process::id()));
+        let path = dir.join("alerts.toml");
+        let mut book = AlertBook::default();
+        book.rules.push(AlertRule::new("MSFT", Condition::parse("price > 450").unwrap()));
+        let mut rule = AlertRule::new("AAPL", Condition::parse("RSI(14) < 30").unwrap());
+        rule.recurrence = Recurrence::Once;
+        book.rules.push(rule);
+
+        book.save(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("[[rules]]\nsymbol = \"AAPL\"\n"), "{}", content);
+        assert!(content.contains("recurrence = \"once\""), "{}", content);
+
+        let loaded = AlertBook::load(&path).unwrap();
+        assert_eq!(loaded.rules.iter().map(|rule| rule.symbol.as_str()).collect::<Vec<_>>(), ["AAPL", "MSFT"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,152 @@
+// ============================================================================
+// API Client : Binance
+// ============================================================================
+// Récupère la profondeur de marché (carnet d'ordres) des cryptos depuis
+// l'endpoint public de Binance (pas de clé API nécessaire)
+//
+// Endpoint : GET https://api.binance.com/api/v3/depth?symbol=BTCUSDT&limit=20
+// Réponse  : { "lastUpdateId": 1, "bids": [["43250.10", "0.52"], ...], "asks": [...] }
+//            (prix et quantités sont des strings)
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::{debug, instrument};
+
+use super::health::{self, Provider};
+use crate::models::order_book::{BookLevel, OrderBook};
+
+/// URL de base de l'API Binance
+const BINANCE_BASE_URL: &str = "https://api.binance.com";
+
+/// Nombre de niveaux demandés de chaque côté du carnet
+pub const DEFAULT_DEPTH: usize = 20;
+
+/// Réponse de l'endpoint /api/v3/depth
+#[derive(Debug, Deserialize)]
+struct DepthResponse {
+    bids: Vec<[String; 2]>,
+    asks: Vec<[String; 2]>,
+}
+
+/// Convertit un symbole Yahoo crypto en paire Binance
+///
+/// CONCEPT : Mapping de symboles entre fournisseurs
+/// - Yahoo : "BTC-USD", "ETH-EUR", "SOL-USDT"
+/// - Binance : "BTCUSDT", "ETHEUR", "SOLUSDT"
+/// - USD n'existe pas sur Binance → remplacé par USDT (stablecoin)
+///
+/// Retourne None si le symbole n'est pas une paire crypto
+pub fn binance_symbol(yahoo_symbol: &str) -> Option<String> {
+    let (base, quote) = yahoo_symbol.split_once('-')?;
+
+    if base.is_empty() || !base.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    let quote = match quote {
+        "USD" | "USDT" => "USDT",
+        "USDC" => "USDC",
+        "EUR" => "EUR",
+        "BTC" => "BTC",
+        "ETH" => "ETH",
+        _ => return None,
+    };
+
+    Some(format!("{}{}", base.to_uppercase(), quote))
+}
+
+/// Récupère le carnet d'ordres d'une crypto depuis Binance
+///
+/// # Arguments
+/// * `symbol` - Symbole Yahoo (ex: "BTC-USD"), converti avec binance_symbol()
+/// * `depth` - Nombre de niveaux de chaque côté
+#[instrument]
+pub async fn fetch_order_book(symbol: &str, depth: usize) -> Result<OrderBook> {
+    let pair = binance_symbol(symbol)
+        .with_context(|| format!("{} n'est pas une paire crypto supportée par Binance", symbol))?;
+
+    // Mode démo : carnet synthétique, aucun appel réseau
+    if crate::demo::is_enabled() {
+        return Ok(crate::demo::order_book(symbol, depth));
+    }
+
+    let url = format!("{}/api/v3/depth?symbol={}&limit={}", BINANCE_BASE_URL, pair, depth);
+    debug!(url = %url, "Fetching Binance order book");
+
+    let response = match super::http::client().get(&url).send().await {
+        Ok(response) => response,
+        Err(e) => {
+            health::record_failure(Provider::Binance, &e.to_string());
+            return Err(e).context("Échec de la requête HTTP vers Binance");
+        }
+    };
+
+    let status = response.status();
+    health::record_status(Provider::Binance, status);
+    if !status.is_success() {
+        anyhow::bail!("Binance a retourné une erreur : HTTP {}", status);
+    }
+
+    let depth_response: DepthResponse = response
+        .json()
+        .await
+        .context("Échec du parsing JSON de la réponse Binance")?;
+
+    parse_depth_response(depth_response, symbol)
+}
+
+/// Convertit la réponse Binance (strings) en OrderBook
+fn parse_depth_response(response: DepthResponse, symbol: &str) -> Result<OrderBook> {
+    fn parse_levels(levels: Vec<[String; 2]>) -> Result<Vec<BookLevel>> {
+        levels
+            .into_iter()
+            .map(|[price, quantity]| {
+                Ok(BookLevel {
+                    price: price.parse().context("Prix invalide dans le carnet d'ordres")?,
+                    quantity: quantity.parse().context("Quantité invalide dans le carnet d'ordres")?,
+                })
+            })
+            .collect()
+    }
+
+    Ok(OrderBook::new(
+        symbol.to_string(),
+        parse_levels(response.bids)?,
+        parse_levels(response.asks)?,
+    ))
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binance_symbol() {
+        assert_eq!(binance_symbol("BTC-USD"), Some("BTCUSDT".to_string()));
+        assert_eq!(binance_symbol("ETH-EUR"), Some("ETHEUR".to_string()));
+        assert_eq!(binance_symbol("SOL-USDT"), Some("SOLUSDT".to_string()));
+        assert_eq!(binance_symbol("AAPL"), None);
+        assert_eq!(binance_symbol("EURUSD=X"), None);
+        assert_eq!(binance_symbol("BRK-B"), None);
+    }
+
+    #[test]
+    fn test_parse_depth_response() {
+        let json = r#"{
+            "lastUpdateId": 1027024,
+            "bids": [["43250.10", "0.52"], ["43249.00", "1.20"]],
+            "asks": [["43251.00", "0.30"]]
+        }"#;
+        let response: DepthResponse = serde_json::from_str(json).unwrap();
+        let book = parse_depth_response(response, "BTC-USD").unwrap();
+
+        assert_eq!(book.bids.len(), 2);
+        assert_eq!(book.best_bid().unwrap().price, 43250.10);
+        assert_eq!(book.best_ask().unwrap().quantity, 0.30);
+    }
+}
@@ -0,0 +1,203 @@
+// ============================================================================
+// API Client : CoinGecko
+// ============================================================================
+// Récupère les statistiques de marché des cryptos (API publique, sans clé)
+//
+// Endpoints :
+// - GET /coins/markets?vs_currency=usd&symbols=btc
+//   → [{ "id": "bitcoin", "symbol": "btc", "market_cap": 1350000000000,
+//        "market_cap_rank": 1, "total_volume": 32100000000,
+//        "circulating_supply": 19740000, "max_supply": 21000000 }]
+// - GET /global
+//   → { "data": { "total_market_cap": { "usd": 2580000000000, "eur": ... } } }
+//
+// CONCEPT : Symbole ambigu
+// - Plusieurs jetons partagent parfois un symbole : on garde celui de plus
+//   grande capitalisation
+//
+// CONCEPT : Cache disque
+// - Dernières statistiques de chaque crypto dans
+//   ~/.local/share/lazywallet/crypto_stats.json (partagé entre profils,
+//   comme le cache de l'indice Fear & Greed)
+// ============================================================================
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tracing::{debug, instrument};
+
+use crate::models::crypto_stats::CryptoStats;
+use crate::models::{Currency, TickerType};
+
+/// URL de base de l'API publique
+const COINGECKO_URL: &str = "https://api.coingecko.com/api/v3";
+
+/// Ligne de /coins/markets
+#[derive(Debug, Deserialize)]
+struct MarketJson {
+    id: String,
+    market_cap: Option<f64>,
+    market_cap_rank: Option<u32>,
+    total_volume: Option<f64>,
+    circulating_supply: Option<f64>,
+    max_supply: Option<f64>,
+}
+
+/// Réponse de /global
+#[derive(Debug, Deserialize)]
+struct GlobalResponse {
+    data: GlobalData,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlobalData {
+    #[serde(default)]
+    total_market_cap: HashMap<String, f64>,
+}
+
+/// Crypto et devise de cotation d'une paire Yahoo : BTC-EUR → ("btc", "eur")
+///
+/// None si le symbole n'est pas une paire crypto
+fn coin_and_currency(symbol: &str) -> Option<(String, String)> {
+    if TickerType::from_symbol(symbol) != TickerType::Crypto {
+        return None;
+    }
+    let (coin, _) = symbol.split_once('-')?;
+    Some((coin.to_lowercase(), Currency::from_symbol(symbol).code().to_lowercase()))
+}
+
+/// GET JSON sur l'API CoinGecko
+async fn get_json<T: DeserializeOwned>(url: &str) -> Result<T> {
+    let response = super::http::client()
+        .get(url)
+        .send()
+        .await
+        .context("Échec de la requête HTTP vers CoinGecko")?;
+
+    let status = response.status();
+    if !status.is_success() {
+        anyhow::bail!("CoinGecko a retourné une erreur : HTTP {}", status);
+    }
+    response.json().await.context("Échec du parsing JSON de la réponse CoinGecko")
+}
+
+/// Récupère capitalisation, volume 24h, offre et dominance d'une paire crypto
+///
+/// # Retourne
+/// * `Ok(None)` - Crypto inconnue de CoinGecko (ou symbole non crypto)
+#[instrument]
+pub async fn fetch_crypto_stats(symbol: &str) -> Result<Option<CryptoStats>> {
+    // Mode démo : statistiques synthétiques, aucun appel réseau
+    if crate::demo::is_enabled() {
+        return Ok(crate::demo::crypto_stats(symbol));
+    }
+    let Some((coin, currency)) = coin_and_currency(symbol) else {
+        return Ok(None);
+    };
+
+    let url = format!("{}/coins/markets?vs_currency={}&symbols={}", COINGECKO_URL, currency, coin);
+    debug!(url = %url, "Fetching crypto market stats");
+    let markets: Vec<MarketJson> = get_json(&url).await?;
+
+    let global: GlobalResponse = get_json(&format!("{}/global", COINGECKO_URL)).await?;
+    Ok(parse_stats(markets, global.data.total_market_cap.get(&currency).copied()))
+}
+
+/// Garde la crypto de plus grande capitalisation et calcule sa dominance
+fn parse_stats(markets: Vec<MarketJson>, total_market_cap: Option<f64>) -> Option<CryptoStats> {
+    let market = markets
+        .into_iter()
+        .max_by(|a, b| a.market_cap.unwrap_or(0.0).total_cmp(&b.market_cap.unwrap_or(0.0)))?;
+
+    let dominance = match (market.market_cap, total_market_cap) {
+        (Some(cap), Some(total)) if total > 0.0 => Some(cap / total * 100.0),
+        _ => None,
+    };
+    Some(CryptoStats {
+        coin_id: market.id,
+        market_cap: market.market_cap,
+        market_cap_rank: market.market_cap_rank,
+        volume_24h: market.total_volume,
+        circulating_supply: market.circulating_supply,
+        max_supply: market.max_supply,
+        dominance,
+        fetched_at: Utc::now(),
+    })
+}
+
+/// Chemin du cache
+///
+/// - Linux : ~/.local/share/lazywallet/crypto_stats.json
+pub fn cache_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("lazywallet").join("crypto_stats.json"))
+}
+
+/// Lit les statistiques en cache, par symbole (vide : absent ou illisible)
+pub fn load_cache(path: &Path) -> HashMap<String, CryptoStats> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Remplace les statistiques d'un symbole dans le cache (crée le répertoire si besoin)
+pub fn update_cache(path: &Path, symbol: &str, stats: &CryptoStats) -> Result<()> {
+    let mut cache = load_cache(path);
+    cache.insert(symbol.to_string(), stats.clone());
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+    }
+    let content = serde_json::to_string_pretty(&cache)?;
+    std::fs::write(path, content).with_context(|| format!("Échec de l'écriture de {}", path.display()))
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coin_and_currency() {
+        assert_eq!(coin_and_currency("BTC-EUR"), Some(("btc".to_string(), "eur".to_string())));
+        assert_eq!(coin_and_currency("ETH-USD"), Some(("eth".to_string(), "usd".to_string())));
+        assert_eq!(coin_and_currency("AAPL"), None);
+    }
+
+    #[test]
+    fn test_parse_stats_keeps_largest_coin() {
+        let json = r#"[
+            { "id": "bitcoin-token", "symbol": "btc", "market_cap": 1200000, "market_cap_rank": 3021,
+              "total_volume": 5000, "circulating_supply": 100, "max_supply": null },
+            { "id": "bitcoin", "symbol": "btc", "market_cap": 1350000000000, "market_cap_rank": 1,
+              "total_volume": 32100000000, "circulating_supply": 19740000, "max_supply": 21000000 }
+        ]"#;
+        let markets: Vec<MarketJson> = serde_json::from_str(json).unwrap();
+        let stats = parse_stats(markets, Some(2.7e12)).unwrap();
+        assert_eq!(stats.coin_id, "bitcoin");
+        assert_eq!(stats.market_cap_rank, Some(1));
+        assert!((stats.dominance.unwrap() - 50.0).abs() < 1e-9);
+
+        assert!(parse_stats(Vec::new(), Some(2.7e12)).is_none());
+    }
+
+    #[test]
+    fn test_cache_roundtrip() {
+        let path = std::env::temp_dir().join(format!("lazywallet_crypto_stats_{}.json", std::process::id()));
+        assert!(load_cache(&path).is_empty());
+
+        let markets: Vec<MarketJson> = serde_json::from_str(r#"[{ "id": "ethereum", "market_cap": 4.1e11 }]"#).unwrap();
+        let stats = parse_stats(markets, None).unwrap();
+        update_cache(&path, "ETH-USD", &stats).unwrap();
+        assert_eq!(load_cache(&path).get("ETH-USD"), Some(&stats));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,39 @@
+// ============================================================================
+// Connectivité : sonde réseau périodique
+// ============================================================================
+// Une requête HEAD légère vers Yahoo, envoyée par le worker à intervalle
+// régulier (voir App::connectivity_due) :
+//
+// - Une réponse, quel que soit son statut (même 404 ou 429), prouve que le
+//   réseau fonctionne : seule une erreur de connexion compte comme hors ligne
+// - Hors ligne, l'application cesse ses rafraîchissements périodiques et
+//   affiche les données en cache ; au retour du réseau, la watchlist est
+//   rechargée
+// - Mode démo et rejeu (--replay) : aucune requête réseau, toujours en ligne
+// ============================================================================
+
+use std::time::Duration;
+
+use crate::api::http;
+use crate::api::recording::{self, Mode};
+use crate::demo;
+
+/// URL sondée (la racine suffit : seul compte le fait de recevoir une réponse)
+const PROBE_URL: &str = "https://query1.finance.yahoo.com/";
+
+/// Délai maximal de la sonde (plus court que celui des requêtes de données)
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Vérifie que le réseau répond
+pub async fn probe() -> bool {
+    if demo::is_enabled() || matches!(recording::mode(), Some(Mode::Replay(_))) {
+        return true;
+    }
+
+    http::client()
+        .head(PROBE_URL)
+        .timeout(PROBE_TIMEOUT)
+        .send()
+        .await
+        .is_ok()
+}
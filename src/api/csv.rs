@@ -0,0 +1,218 @@
+// ============================================================================
+// Instruments personnalisés : bougies lues depuis un fichier CSV
+// ============================================================================
+// Pour suivre ce que Yahoo ne cote pas (fonds privés, actifs non listés),
+// la section "custom_instruments" de config.json associe un symbole à un
+// fichier local :
+//
+//   "custom_instruments": [
+//     { "symbol": "FONDS-X", "name": "Fonds privé X", "csv": "~/finance/fonds_x.csv" }
+//   ]
+//
+// Format du fichier (en-tête facultatif, volume facultatif) :
+//
+//   timestamp,open,high,low,close,volume
+//   2024-03-01,101.2,102.0,100.8,101.5,0
+//   2024-03-04 14:30,101.5,103.1,101.1,102.9,1200
+//
+// - Dates acceptées : RFC 3339, "AAAA-MM-JJ[ HH:MM[:SS]]" (UTC), timestamp Unix
+// - L'intervalle est déduit de l'écart le plus court entre deux lignes
+// - fetch_ticker_data relit le fichier à chaque chargement : un
+//   rafraîchissement suffit pour voir les lignes ajoutées
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Interval, OHLCData, OHLC};
+
+/// Instrument dont les bougies viennent d'un fichier CSV
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomInstrument {
+    /// Symbole affiché dans la watchlist
+    pub symbol: String,
+
+    /// Nom affiché (défaut : le symbole)
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Chemin du fichier ("~/" accepté)
+    pub csv: PathBuf,
+}
+
+static INSTRUMENTS: OnceLock<Vec<CustomInstrument>> = OnceLock::new();
+
+/// Déclare les instruments personnalisés (une fois, au démarrage)
+///
+/// Retourne false s'ils ont déjà été déclarés
+pub fn configure(instruments: &[CustomInstrument]) -> bool {
+    INSTRUMENTS.set(instruments.to_vec()).is_ok()
+}
+
+/// Instrument personnalisé associé à un symbole
+pub fn instrument(symbol: &str) -> Option<&'static CustomInstrument> {
+    INSTRUMENTS.get()?.iter().find(|instrument| instrument.symbol == symbol)
+}
+
+/// Lit les bougies d'un instrument à l'intervalle demandé
+///
+/// Les bougies sont agrégées si possible (fichier journalier affiché en 1w) ;
+/// sinon elles restent à l'intervalle du fichier (on ne devine pas l'intraday)
+pub fn fetch(instrument: &CustomInstrument, interval: Interval) -> Result<(OHLCData, Option<String>)> {
+    let path = expand_home(&instrument.csv);
+    let content =
+        std::fs::read_to_string(&path).with_context(|| format!("Échec de la lecture de {}", path.display()))?;
+    let data = parse(&instrument.symbol, &content).with_context(|| format!("Format invalide dans {}", path.display()))?;
+
+    let data = if data.interval == interval {
+        data
+    } else {
+        data.resample(interval).unwrap_or(data)
+    };
+    Ok((data, instrument.name.clone()))
+}
+
+/// Remplace "~/" par le répertoire personnel
+pub(crate) fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Parse le contenu d'un fichier CSV en bougies triées
+fn parse(symbol: &str, content: &str) -> Result<OHLCData> {
+    let mut candles = Vec::new();
+
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split([',', ';']).map(str::trim).collect();
+        let Some(timestamp) = parse_timestamp(fields[0]) else {
+            // En-tête (première ligne de données uniquement)
+            if candles.is_empty() && fields[0].parse::<f64>().is_err() {
+                continue;
+            }
+            anyhow::bail!("Ligne {} : date invalide \"{}\"", number + 1, fields[0]);
+        };
+
+        let price = |index: usize| -> Result<f64> {
+            let field = fields.get(index).with_context(|| format!("Ligne {} : colonne manquante", number + 1))?;
+            field.parse().with_context(|| format!("Ligne {} : nombre invalide \"{}\"", number + 1, field))
+        };
+        let volume = match fields.get(5).filter(|field| !field.is_empty()) {
+            Some(field) => field
+                .parse::<f64>()
+                .with_context(|| format!("Ligne {} : volume invalide \"{}\"", number + 1, field))? as u64,
+            None => 0,
+        };
+
+        candles.push(OHLC::new(timestamp, price(1)?, price(2)?, price(3)?, price(4)?, volume));
+    }
+
+    if candles.is_empty() {
+        anyhow::bail!("Aucune bougie");
+    }
+    candles.sort_by_key(|candle| candle.timestamp);
+    candles.dedup_by_key(|candle| candle.timestamp);
+
+    let mut data = OHLCData::with_interval(symbol.to_string(), infer_interval(&candles));
+    data.set_candles(candles);
+    Ok(data)
+}
+
+/// Parse une date (RFC 3339, "AAAA-MM-JJ[ HH:MM[:SS]]" en UTC ou timestamp Unix)
+fn parse_timestamp(field: &str) -> Option<DateTime<Utc>> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(field) {
+        return Some(date.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"] {
+        if let Ok(date) = NaiveDateTime::parse_from_str(field, format) {
+            return Some(date.and_utc());
+        }
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(field, "%Y-%m-%d") {
+        return date.and_hms_opt(0, 0, 0).map(|date| date.and_utc());
+    }
+    // Timestamp Unix en secondes (ou en millisecondes)
+    let seconds: i64 = field.parse().ok()?;
+    let seconds = if seconds > 100_000_000_000 { seconds / 1000 } else { seconds };
+    DateTime::from_timestamp(seconds, 0)
+}
+
+/// Intervalle le plus long ne dépassant pas l'écart minimal entre deux bougies
+///
+/// Exemple : cotations quotidiennes avec des week-ends → écart minimal de
+/// 1 jour → 1d ; valeurs mensuelles → 1w
+fn infer_interval(candles: &[OHLC]) -> Interval {
+    let Some(step) = candles
+        .windows(2)
+        .map(|pair| (pair[1].timestamp - pair[0].timestamp).num_minutes())
+        .min()
+    else {
+        return Interval::D1;
+    };
+
+    Interval::all()
+        .into_iter()
+        .rev()
+        .find(|interval| interval.minutes() <= step)
+        .unwrap_or(Interval::M5)
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_daily_csv() {
+        let content = "timestamp,open,high,low,close,volume\n\
+                       2024-03-04,101.5,103.1,101.1,102.9,1200\n\
+                       \n\
+                       2024-03-01;101.2;102.0;100.8;101.5\n";
+        let data = parse("FONDS-X", content).unwrap();
+
+        assert_eq!(data.interval, Interval::D1);
+        assert_eq!(data.len(), 2);
+        // Lignes triées, volume absent = 0
+        assert_eq!(data.candles[0].timestamp, Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap());
+        assert_eq!(data.candles[0].volume, 0);
+        assert_eq!(data.candles[1].close, 102.9);
+    }
+
+    #[test]
+    fn test_parse_timestamps_and_errors() {
+        let expected = Utc.with_ymd_and_hms(2024, 3, 4, 14, 30, 0).unwrap();
+        assert_eq!(parse_timestamp("2024-03-04T14:30:00Z"), Some(expected));
+        assert_eq!(parse_timestamp("2024-03-04 14:30"), Some(expected));
+        assert_eq!(parse_timestamp(&expected.timestamp().to_string()), Some(expected));
+        assert_eq!(parse_timestamp(&expected.timestamp_millis().to_string()), Some(expected));
+        assert_eq!(parse_timestamp("open"), None);
+
+        let error = parse("X", "2024-03-04,1,2,0.5,abc\n").unwrap_err();
+        assert!(error.to_string().contains("Ligne 1"), "{}", error);
+        assert!(parse("X", "2024-03-04,1,2\n").is_err());
+        assert!(parse("X", "timestamp,open,high,low,close\n").is_err());
+    }
+
+    #[test]
+    fn test_infer_interval() {
+        let hourly = "2024-03-04 14:00,1,1,1,1\n2024-03-04 15:00,1,1,1,1\n2024-03-05 14:00,1,1,1,1\n";
+        assert_eq!(parse("X", hourly).unwrap().interval, Interval::H1);
+
+        let monthly = "2024-01-31,1,1,1,1\n2024-02-29,1,1,1,1\n2024-03-31,1,1,1,1\n";
+        assert_eq!(parse("X", monthly).unwrap().interval, Interval::W1);
+    }
+}
@@ -0,0 +1,247 @@
+// ============================================================================
+// Calendrier économique (flux ICS ou JSON)
+// ============================================================================
+// Publications majeures (CPI, FOMC, NFP, ...) affichées sur le dashboard le
+// jour même, avec un compte à rebours :
+//
+//   "economic_calendar": { "url": "https://corp/macro.ics", "refresh_minutes": 360 }
+//
+// L'URL peut aussi être le chemin d'un fichier local ("~/agenda/macro.ics")
+//
+// Formats acceptés :
+// - ICS (iCalendar) : un VEVENT par publication
+//     BEGIN:VEVENT
+//     SUMMARY:CPI
+//     DTSTART:20240612T123000Z
+//     END:VEVENT
+// - JSON : liste (ou { "events": [...] }) d'objets
+//     { "title": "CPI", "country": "US", "time": "2024-06-12T12:30:00Z" }
+//     { "title": "Juneteenth", "country": "US", "date": "2024-06-19" }
+//
+// CONCEPT : Heures ICS
+// - "...Z" (ou TZID=UTC) : heure UTC
+// - Sans fuseau, ou TZID d'un autre fuseau : heure locale (pas de base des
+//   fuseaux horaires embarquée ; préférer un flux en UTC)
+// - VALUE=DATE (20240619) : événement sur toute la journée
+// ============================================================================
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, instrument};
+
+use crate::api::csv::expand_home;
+use crate::models::economic_calendar::EconomicEvent;
+
+/// Source du calendrier économique (section "economic_calendar" de config.json)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EconomicCalendar {
+    /// URL du flux (ICS ou JSON), ou chemin d'un fichier local
+    pub url: String,
+
+    /// Intervalle entre deux rechargements du flux (minutes, 1 minimum)
+    #[serde(default = "default_refresh_minutes")]
+    pub refresh_minutes: u64,
+}
+
+fn default_refresh_minutes() -> u64 {
+    360
+}
+
+impl EconomicCalendar {
+    /// Intervalle entre deux rechargements
+    pub fn refresh_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.refresh_minutes.max(1) * 60)
+    }
+
+    /// Flux téléchargé (http ou https), plutôt que lu sur le disque
+    pub fn is_remote(&self) -> bool {
+        is_remote(&self.url)
+    }
+}
+
+fn is_remote(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+/// Événement JSON : heure précise ("time") ou journée entière ("date")
+#[derive(Debug, Deserialize)]
+struct JsonEvent {
+    title: String,
+    country: Option<String>,
+    time: Option<DateTime<Utc>>,
+    date: Option<NaiveDate>,
+}
+
+/// Réponse JSON : liste seule ou objet { "events": [...] }
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum JsonCalendar {
+    Events(Vec<JsonEvent>),
+    Object { events: Vec<JsonEvent> },
+}
+
+/// Télécharge (ou lit) le flux et extrait ses événements
+#[instrument]
+pub async fn fetch_events(url: &str) -> Result<Vec<EconomicEvent>> {
+    debug!("Fetching economic calendar");
+    let body = if is_remote(url) {
+        let response = super::http::client()
+            .get(url)
+            .send()
+            .await
+            .with_context(|| format!("Échec de la requête HTTP vers {}", url))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("{} a retourné une erreur : HTTP {}", url, status);
+        }
+        response.text().await.context("Échec de la lecture du calendrier économique")?
+    } else {
+        let path = expand_home(Path::new(url));
+        std::fs::read_to_string(&path).with_context(|| format!("Échec de la lecture de {}", path.display()))?
+    };
+    parse_events(&body)
+}
+
+/// Extrait les événements d'un flux ICS ou JSON
+pub fn parse_events(body: &str) -> Result<Vec<EconomicEvent>> {
+    let body = body.trim_start_matches('\u{feff}').trim();
+    if body.starts_with('[') || body.starts_with('{') {
+        parse_json(body)
+    } else if body.starts_with("BEGIN:VCALENDAR") {
+        Ok(parse_ics(body))
+    } else {
+        anyhow::bail!("Calendrier économique ni ICS ni JSON")
+    }
+}
+
+/// Événements d'une réponse JSON (sans "time" ni "date" : ignorés)
+fn parse_json(body: &str) -> Result<Vec<EconomicEvent>> {
+    let events = match serde_json::from_str(body).context("Calendrier économique JSON invalide")? {
+        JsonCalendar::Events(events) | JsonCalendar::Object { events } => events,
+    };
+
+    Ok(events
+        .into_iter()
+        .filter_map(|event| {
+            let (at, all_day) = match (event.time, event.date) {
+                (Some(time), _) => (time, false),
+                (None, Some(date)) => (local_midnight(date)?, true),
+                (None, None) => return None,
+            };
+            Some(EconomicEvent { title: event.title, country: event.country, at, all_day })
+        })
+        .collect())
+}
+
+/// Événements d'un flux ICS (VEVENT sans SUMMARY ou DTSTART lisible : ignorés)
+fn parse_ics(body: &str) -> Vec<EconomicEvent> {
+    // Lignes repliées : une ligne commençant par un espace prolonge la précédente
+    let unfolded = body.replace("\r\n", "\n").replace("\n ", "").replace("\n\t", "");
+
+    let mut events = Vec::new();
+    let mut title: Option<String> = None;
+    let mut start: Option<(DateTime<Utc>, bool)> = None;
+    for line in unfolded.lines() {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let mut params = name.split(';');
+        match params.next().unwrap_or_default() {
+            "BEGIN" if value == "VEVENT" => {
+                title = None;
+                start = None;
+            }
+            "SUMMARY" => title = Some(unescape(value)),
+            "DTSTART" => start = ics_time(value, params.find_map(|param| param.strip_prefix("TZID="))),
+            "END" if value == "VEVENT" => {
+                if let (Some(title), Some((at, all_day))) = (title.take(), start.take()) {
+                    events.push(EconomicEvent { title, country: None, at, all_day });
+                }
+            }
+            _ => {}
+        }
+    }
+    events
+}
+
+/// Heure d'un DTSTART : (heure UTC, journée entière)
+fn ics_time(value: &str, tzid: Option<&str>) -> Option<(DateTime<Utc>, bool)> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+        return Some((local_midnight(date)?, true));
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        return Some((NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?.and_utc(), false));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    let at = match tzid {
+        Some("UTC" | "Etc/UTC" | "GMT" | "Etc/GMT") => naive.and_utc(),
+        _ => naive.and_local_timezone(Local).earliest()?.with_timezone(&Utc),
+    };
+    Some((at, false))
+}
+
+/// Minuit local d'un jour, en UTC
+fn local_midnight(date: NaiveDate) -> Option<DateTime<Utc>> {
+    Some(date.and_hms_opt(0, 0, 0)?.and_local_timezone(Local).earliest()?.with_timezone(&Utc))
+}
+
+/// Texte ICS échappé : "\," "\;" "\n" "\\"
+fn unescape(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+        .trim()
+        .to_string()
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ics() {
+        let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\n\
+                   BEGIN:VEVENT\r\nSUMMARY:CPI\\, core\r\nDTSTART:20240612T123000Z\r\nEND:VEVENT\r\n\
+                   BEGIN:VEVENT\r\nSUMMARY:FOMC rate\r\n  decision\r\nDTSTART;TZID=Etc/UTC:20240612T180000\r\nEND:VEVENT\r\n\
+                   BEGIN:VEVENT\r\nSUMMARY:Juneteenth\r\nDTSTART;VALUE=DATE:20240619\r\nEND:VEVENT\r\n\
+                   BEGIN:VEVENT\r\nDTSTART:20240613T123000Z\r\nEND:VEVENT\r\n\
+                   END:VCALENDAR\r\n";
+        let events = parse_events(ics).unwrap();
+        let titles: Vec<&str> = events.iter().map(|event| event.title.as_str()).collect();
+        assert_eq!(titles, ["CPI, core", "FOMC rate decision", "Juneteenth"]);
+        assert_eq!(events[0].at.to_rfc3339(), "2024-06-12T12:30:00+00:00");
+        assert_eq!(events[1].at.to_rfc3339(), "2024-06-12T18:00:00+00:00");
+        assert!(!events[1].all_day);
+        assert!(events[2].all_day);
+        assert_eq!(events[2].local_date().to_string(), "2024-06-19");
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let json = r#"{ "events": [
+            { "title": "CPI", "country": "US", "time": "2024-06-12T14:30:00+02:00" },
+            { "title": "Juneteenth", "country": "US", "date": "2024-06-19" },
+            { "title": "Sans date" }
+        ] }"#;
+        let events = parse_events(json).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].label(), "US CPI");
+        assert_eq!(events[0].at.to_rfc3339(), "2024-06-12T12:30:00+00:00");
+        assert!(events[1].all_day);
+
+        assert_eq!(parse_events(r#"[{ "title": "NFP", "time": "2024-06-07T12:30:00Z" }]"#).unwrap().len(), 1);
+        assert!(parse_events("CPI demain").is_err());
+    }
+}
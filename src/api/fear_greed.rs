@@ -0,0 +1,138 @@
+// ============================================================================
+// API Client : Fear & Greed (alternative.me)
+// ============================================================================
+// Récupère l'indice Fear & Greed du marché crypto (pas de clé API)
+//
+// Endpoint : GET https://api.alternative.me/fng/?limit=1
+// Réponse  : { "data": [{ "value": "40", "value_classification": "Fear",
+//                         "timestamp": "1551157200" }] }
+//            (valeur et date Unix sont des strings)
+//
+// CONCEPT : Cache disque
+// - La dernière valeur est gardée dans ~/.local/share/lazywallet/fear_greed.json
+//   (partagée entre profils, comme les autres données de marché)
+// - Au démarrage, la jauge s'affiche aussitôt depuis le cache ; l'indice
+//   n'est rechargé qu'une fois la valeur suivante publiée (voir is_stale)
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::DateTime;
+use serde::Deserialize;
+use tracing::{debug, instrument};
+
+use crate::models::fear_greed::FearGreed;
+
+/// URL de l'indice (valeur du jour seulement)
+const FEAR_GREED_URL: &str = "https://api.alternative.me/fng/?limit=1";
+
+/// Réponse de l'endpoint /fng/
+#[derive(Debug, Deserialize)]
+struct FngResponse {
+    data: Vec<FngValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FngValue {
+    value: String,
+    timestamp: String,
+}
+
+/// Récupère la valeur du jour de l'indice
+#[instrument]
+pub async fn fetch_fear_greed() -> Result<FearGreed> {
+    // Mode démo : valeur synthétique, aucun appel réseau
+    if crate::demo::is_enabled() {
+        return Ok(crate::demo::fear_greed());
+    }
+
+    debug!(url = FEAR_GREED_URL, "Fetching Fear & Greed index");
+    let response = super::http::client()
+        .get(FEAR_GREED_URL)
+        .send()
+        .await
+        .context("Échec de la requête HTTP vers alternative.me")?;
+
+    let status = response.status();
+    if !status.is_success() {
+        anyhow::bail!("alternative.me a retourné une erreur : HTTP {}", status);
+    }
+
+    let fng: FngResponse = response
+        .json()
+        .await
+        .context("Échec du parsing JSON de la réponse alternative.me")?;
+    parse_response(fng)
+}
+
+/// Convertit la réponse (strings) en FearGreed
+fn parse_response(response: FngResponse) -> Result<FearGreed> {
+    let latest = response.data.into_iter().next().context("Réponse alternative.me sans valeur")?;
+    let value: u8 = latest.value.parse().context("Valeur de l'indice invalide")?;
+    let timestamp: i64 = latest.timestamp.parse().context("Date de l'indice invalide")?;
+
+    Ok(FearGreed {
+        value: value.min(100),
+        published_at: DateTime::from_timestamp(timestamp, 0).context("Date de l'indice invalide")?,
+    })
+}
+
+/// Chemin du cache
+///
+/// - Linux : ~/.local/share/lazywallet/fear_greed.json
+pub fn cache_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("lazywallet").join("fear_greed.json"))
+}
+
+/// Lit la dernière valeur en cache (None : absente ou illisible)
+pub fn load_cache(path: &Path) -> Option<FearGreed> {
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Écrit la valeur dans le cache (crée le répertoire si besoin)
+pub fn save_cache(path: &Path, index: &FearGreed) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+    }
+    let content = serde_json::to_string_pretty(index)?;
+    std::fs::write(path, content).with_context(|| format!("Échec de l'écriture de {}", path.display()))
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let json = r#"{
+            "name": "Fear and Greed Index",
+            "data": [{ "value": "40", "value_classification": "Fear", "timestamp": "1551157200", "time_until_update": "68499" }],
+            "metadata": { "error": null }
+        }"#;
+        let response: FngResponse = serde_json::from_str(json).unwrap();
+        let index = parse_response(response).unwrap();
+        assert_eq!(index.value, 40);
+        assert_eq!(index.published_at.timestamp(), 1551157200);
+
+        let empty: FngResponse = serde_json::from_str(r#"{ "data": [] }"#).unwrap();
+        assert!(parse_response(empty).is_err());
+    }
+
+    #[test]
+    fn test_cache_roundtrip() {
+        let path = std::env::temp_dir().join(format!("lazywallet_fng_{}.json", std::process::id()));
+        assert!(load_cache(&path).is_none());
+
+        let index = FearGreed { value: 72, published_at: DateTime::from_timestamp(1551157200, 0).unwrap() };
+        save_cache(&path, &index).unwrap();
+        assert_eq!(load_cache(&path), Some(index));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,247 @@
+// ============================================================================
+// Santé des fournisseurs de données (Yahoo, Binance)
+// ============================================================================
+// Chaque requête réseau enregistre son issue dans une fenêtre glissante par
+// fournisseur ; le dashboard en affiche un résumé :
+//
+//   Yahoo ● OK  │  Binance ● limité 14:32
+//
+// - OK      : pas d'échec récent
+// - dégradé : dernière requête en échec, ou plus d'un quart de la fenêtre
+// - limité  : dernière réponse HTTP 429 (après les nouvelles tentatives)
+//
+// CONCEPT RUST : Mutex global
+// - Les requêtes partent du worker, du démon et de l'API HTTP : l'état est
+//   partagé par tous, sans passer de paramètre dans chaque fonction
+// - La logique vit dans HealthTracker (testable sans état global)
+// ============================================================================
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+
+/// Nombre de requêtes gardées dans la fenêtre de chaque fournisseur
+const WINDOW: usize = 20;
+
+/// Fournisseur de données
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Provider {
+    Yahoo,
+    Binance,
+}
+
+impl Provider {
+    /// Nom affiché
+    pub fn label(&self) -> &'static str {
+        match self {
+            Provider::Yahoo => "Yahoo",
+            Provider::Binance => "Binance",
+        }
+    }
+}
+
+/// État de santé d'un fournisseur
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthState {
+    Ok,
+    Degraded,
+    RateLimited,
+}
+
+impl HealthState {
+    /// Libellé affiché
+    pub fn label(&self) -> &'static str {
+        match self {
+            HealthState::Ok => "OK",
+            HealthState::Degraded => "dégradé",
+            HealthState::RateLimited => "limité",
+        }
+    }
+}
+
+/// Issue d'une requête
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Success,
+    Failure,
+    RateLimited,
+}
+
+/// Résumé de la santé d'un fournisseur
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderStatus {
+    pub provider: Provider,
+    pub state: HealthState,
+
+    /// Date et message de la dernière erreur (même ancienne)
+    pub last_error: Option<(DateTime<Utc>, String)>,
+}
+
+/// Fenêtre glissante d'un fournisseur
+#[derive(Debug, Default)]
+struct Window {
+    outcomes: VecDeque<Outcome>,
+    last_error: Option<(DateTime<Utc>, String)>,
+}
+
+impl Window {
+    fn push(&mut self, outcome: Outcome) {
+        if self.outcomes.len() == WINDOW {
+            self.outcomes.pop_front();
+        }
+        self.outcomes.push_back(outcome);
+    }
+
+    fn state(&self) -> HealthState {
+        let failures = self.outcomes.iter().filter(|outcome| **outcome != Outcome::Success).count();
+        match self.outcomes.back() {
+            Some(Outcome::RateLimited) => HealthState::RateLimited,
+            Some(Outcome::Failure) => HealthState::Degraded,
+            _ if failures * 4 > self.outcomes.len() => HealthState::Degraded,
+            _ => HealthState::Ok,
+        }
+    }
+}
+
+/// Fenêtres de tous les fournisseurs utilisés
+#[derive(Debug, Default)]
+pub struct HealthTracker {
+    windows: HashMap<Provider, Window>,
+}
+
+impl HealthTracker {
+    /// Enregistre une requête réussie
+    pub fn success(&mut self, provider: Provider) {
+        self.windows.entry(provider).or_default().push(Outcome::Success);
+    }
+
+    /// Enregistre une requête en échec (réseau, HTTP 5xx, ...)
+    pub fn failure(&mut self, provider: Provider, error: &str, at: DateTime<Utc>) {
+        let window = self.windows.entry(provider).or_default();
+        window.push(Outcome::Failure);
+        window.last_error = Some((at, error.to_string()));
+    }
+
+    /// Enregistre une requête refusée pour dépassement du débit (HTTP 429)
+    pub fn rate_limited(&mut self, provider: Provider, at: DateTime<Utc>) {
+        let window = self.windows.entry(provider).or_default();
+        window.push(Outcome::RateLimited);
+        window.last_error = Some((at, "HTTP 429 Too Many Requests".to_string()));
+    }
+
+    /// État des fournisseurs déjà sollicités (ordre stable)
+    pub fn statuses(&self) -> Vec<ProviderStatus> {
+        let mut statuses: Vec<ProviderStatus> = self
+            .windows
+            .iter()
+            .map(|(provider, window)| ProviderStatus {
+                provider: *provider,
+                state: window.state(),
+                last_error: window.last_error.clone(),
+            })
+            .collect();
+        statuses.sort_by_key(|status| status.provider);
+        statuses
+    }
+}
+
+static TRACKER: Mutex<Option<HealthTracker>> = Mutex::new(None);
+
+/// Applique une modification au suivi global
+fn with_tracker(update: impl FnOnce(&mut HealthTracker)) {
+    let mut tracker = TRACKER.lock().unwrap();
+    update(tracker.get_or_insert_with(HealthTracker::default));
+}
+
+/// Enregistre une requête réussie
+pub fn record_success(provider: Provider) {
+    with_tracker(|tracker| tracker.success(provider));
+}
+
+/// Enregistre une requête en échec
+pub fn record_failure(provider: Provider, error: &str) {
+    with_tracker(|tracker| tracker.failure(provider, error, Utc::now()));
+}
+
+/// Enregistre un HTTP 429
+pub fn record_rate_limited(provider: Provider) {
+    with_tracker(|tracker| tracker.rate_limited(provider, Utc::now()));
+}
+
+/// Enregistre l'issue d'une requête d'après son statut HTTP
+///
+/// Seuls les HTTP 429 et 5xx comptent comme des échecs : un 404 (symbole
+/// inconnu) prouve que le service répond
+pub fn record_status(provider: Provider, status: reqwest::StatusCode) {
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        record_rate_limited(provider);
+    } else if status.is_server_error() {
+        record_failure(provider, &format!("HTTP {}", status));
+    } else {
+        record_success(provider);
+    }
+}
+
+/// État actuel des fournisseurs déjà sollicités
+pub fn snapshot() -> Vec<ProviderStatus> {
+    TRACKER.lock().unwrap().as_ref().map(HealthTracker::statuses).unwrap_or_default()
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_follows_window() {
+        let mut tracker = HealthTracker::default();
+        assert!(tracker.statuses().is_empty());
+
+        tracker.success(Provider::Yahoo);
+        assert_eq!(tracker.statuses()[0].state, HealthState::Ok);
+
+        let at = Utc::now();
+        tracker.failure(Provider::Yahoo, "timeout", at);
+        assert_eq!(tracker.statuses()[0].state, HealthState::Degraded);
+        assert_eq!(tracker.statuses()[0].last_error, Some((at, "timeout".to_string())));
+
+        // 1 échec sur 2 : toujours dégradé après un succès
+        tracker.success(Provider::Yahoo);
+        assert_eq!(tracker.statuses()[0].state, HealthState::Degraded);
+
+        // L'échec devient minoritaire dans la fenêtre : retour à OK
+        for _ in 0..3 {
+            tracker.success(Provider::Yahoo);
+        }
+        assert_eq!(tracker.statuses()[0].state, HealthState::Ok);
+        assert!(tracker.statuses()[0].last_error.is_some());
+    }
+
+    #[test]
+    fn test_rate_limited_and_order() {
+        let mut tracker = HealthTracker::default();
+        tracker.rate_limited(Provider::Binance, Utc::now());
+        tracker.success(Provider::Yahoo);
+
+        let statuses = tracker.statuses();
+        assert_eq!(statuses[0].provider, Provider::Yahoo);
+        assert_eq!(statuses[1].state, HealthState::RateLimited);
+    }
+
+    #[test]
+    fn test_window_is_bounded() {
+        let mut tracker = HealthTracker::default();
+        for _ in 0..WINDOW {
+            tracker.failure(Provider::Yahoo, "down", Utc::now());
+        }
+        for _ in 0..WINDOW {
+            tracker.success(Provider::Yahoo);
+        }
+        assert_eq!(tracker.windows[&Provider::Yahoo].outcomes.len(), WINDOW);
+        assert_eq!(tracker.statuses()[0].state, HealthState::Ok);
+    }
+}
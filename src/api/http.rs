@@ -0,0 +1,131 @@
+// ============================================================================
+// Client HTTP partagé
+// ============================================================================
+// Un seul reqwest::Client pour toute l'application
+//
+// CONCEPT RUST : OnceLock (initialisation paresseuse thread-safe)
+// - Le client est créé au premier appel de client()
+// - Les appels suivants réutilisent la même instance
+// - reqwest::Client maintient un pool de connexions : le réutiliser évite
+//   de refaire le handshake TCP/TLS à chaque requête
+//
+// CONCEPT : Proxy et timeouts (section "http" de config.json)
+// - Sans proxy explicite, reqwest suit HTTP_PROXY / HTTPS_PROXY / NO_PROXY
+// - Un proxy explicite remplace les variables d'environnement
+// - Les timeouts évitent qu'une requête bloquée (proxy d'entreprise qui
+//   ne répond pas) fige le chargement indéfiniment
+//
+// Exemple :
+//   "http": { "proxy": "http://proxy.corp:3128", "timeout_secs": 30, "connect_timeout_secs": 10 }
+// ============================================================================
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// User-Agent envoyé avec chaque requête (évite le blocage par Yahoo)
+const USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36";
+
+static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+static SETTINGS: OnceLock<HttpSettings> = OnceLock::new();
+
+/// Réglages réseau du client HTTP
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HttpSettings {
+    /// Proxy pour toutes les requêtes (ex: "http://proxy.corp:3128")
+    /// Sans valeur : HTTP_PROXY / HTTPS_PROXY si définies
+    pub proxy: Option<String>,
+
+    /// Durée maximale d'une requête, réponse comprise (secondes)
+    pub timeout_secs: u64,
+
+    /// Durée maximale d'établissement de la connexion (secondes)
+    pub connect_timeout_secs: u64,
+}
+
+impl Default for HttpSettings {
+    fn default() -> Self {
+        Self {
+            proxy: None,
+            timeout_secs: 30,
+            connect_timeout_secs: 10,
+        }
+    }
+}
+
+/// Applique les réglages réseau (à appeler avant la première requête)
+///
+/// Retourne une erreur si le proxy est invalide ou si le client a déjà été
+/// configuré ou créé
+pub fn configure(settings: &HttpSettings) -> Result<()> {
+    // Valide les réglages tout de suite plutôt qu'au premier appel
+    build_client(settings)?;
+
+    if CLIENT.get().is_some() || SETTINGS.set(settings.clone()).is_err() {
+        anyhow::bail!("Le client HTTP est déjà configuré");
+    }
+    Ok(())
+}
+
+/// Retourne le client HTTP partagé (créé au premier appel)
+pub fn client() -> &'static reqwest::Client {
+    CLIENT.get_or_init(|| {
+        let settings = SETTINGS.get().cloned().unwrap_or_default();
+        build_client(&settings).expect("Échec de la création du client HTTP")
+    })
+}
+
+/// Construit un client avec les réglages donnés
+fn build_client(settings: &HttpSettings) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(settings.timeout_secs))
+        .connect_timeout(Duration::from_secs(settings.connect_timeout_secs));
+
+    if let Some(url) = &settings.proxy {
+        // NO_PROXY reste respectée avec un proxy explicite
+        let proxy = reqwest::Proxy::all(url)
+            .with_context(|| format!("Proxy invalide : \"{}\"", url))?
+            .no_proxy(reqwest::NoProxy::from_env());
+        builder = builder.proxy(proxy);
+    }
+
+    builder.build().context("Échec de la création du client HTTP")
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_client_validates_proxy() {
+        assert!(build_client(&HttpSettings::default()).is_ok());
+
+        let proxied = HttpSettings {
+            proxy: Some("http://proxy.corp:3128".to_string()),
+            ..HttpSettings::default()
+        };
+        assert!(build_client(&proxied).is_ok());
+
+        let invalid = HttpSettings {
+            proxy: Some("not a url".to_string()),
+            ..HttpSettings::default()
+        };
+        assert!(build_client(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_partial_settings_use_defaults() {
+        let settings: HttpSettings = serde_json::from_str(r#"{ "timeout_secs": 5 }"#).unwrap();
+        assert_eq!(settings.timeout_secs, 5);
+        assert_eq!(settings.connect_timeout_secs, HttpSettings::default().connect_timeout_secs);
+        assert_eq!(settings.proxy, None);
+    }
+}
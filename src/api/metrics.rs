@@ -0,0 +1,215 @@
+// ============================================================================
+// Métriques des téléchargements par ticker
+// ============================================================================
+// Chaque série téléchargée depuis Yahoo enregistre sa durée, la taille de la
+// réponse, les nouvelles tentatives (HTTP 429) et son statut, par symbole :
+//
+//   AAPL   12 requêtes  dernière 184 ms  42.1 Ko  0 retry  HTTP 200
+//   TSLA    3 requêtes  dernière 2.3 s    0 o     2 retry  HTTP 429
+//
+// Lues par l'overlay de debug (F12) et exportées au format Prometheus par
+// l'API HTTP locale (GET /metrics, si "http_api" est configurée)
+//
+// CONCEPT RUST : Mutex global (comme health.rs)
+// - Les téléchargements partent du worker, du démon et de l'API HTTP
+// - La logique vit dans MetricsStore (testable sans état global)
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// Issue d'un téléchargement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FetchSample {
+    pub duration: Duration,
+
+    /// Taille du corps de la réponse, en octets
+    pub bytes: usize,
+
+    /// Nouvelles tentatives après un HTTP 429
+    pub retries: u32,
+
+    /// Statut HTTP (None : pas de réponse, erreur réseau)
+    pub status: Option<u16>,
+}
+
+impl FetchSample {
+    /// Vérifie si le téléchargement a réussi (HTTP 2xx)
+    pub fn is_success(&self) -> bool {
+        self.status.is_some_and(|status| (200..300).contains(&status))
+    }
+}
+
+/// Métriques cumulées d'un symbole
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickerMetrics {
+    pub symbol: String,
+
+    /// Nombre de téléchargements, et parmi eux ceux en échec
+    pub fetches: u64,
+    pub failures: u64,
+
+    /// Cumuls (moyennes et compteurs Prometheus)
+    pub total_duration: Duration,
+    pub total_bytes: u64,
+    pub total_retries: u64,
+
+    /// Dernier téléchargement et sa date
+    pub last: FetchSample,
+    pub last_at: DateTime<Utc>,
+}
+
+impl TickerMetrics {
+    /// Durée moyenne d'un téléchargement
+    pub fn average_duration(&self) -> Duration {
+        self.total_duration / self.fetches.max(1) as u32
+    }
+}
+
+/// Métriques de tous les symboles téléchargés
+#[derive(Debug, Default)]
+pub struct MetricsStore {
+    tickers: HashMap<String, TickerMetrics>,
+}
+
+impl MetricsStore {
+    /// Enregistre un téléchargement
+    pub fn record(&mut self, symbol: &str, sample: FetchSample, at: DateTime<Utc>) {
+        let metrics = self.tickers.entry(symbol.to_string()).or_insert_with(|| TickerMetrics {
+            symbol: symbol.to_string(),
+            fetches: 0,
+            failures: 0,
+            total_duration: Duration::ZERO,
+            total_bytes: 0,
+            total_retries: 0,
+            last: sample,
+            last_at: at,
+        });
+        metrics.fetches += 1;
+        if !sample.is_success() {
+            metrics.failures += 1;
+        }
+        metrics.total_duration += sample.duration;
+        metrics.total_bytes += sample.bytes as u64;
+        metrics.total_retries += sample.retries as u64;
+        metrics.last = sample;
+        metrics.last_at = at;
+    }
+
+    /// Métriques triées par symbole
+    pub fn tickers(&self) -> Vec<TickerMetrics> {
+        let mut tickers: Vec<TickerMetrics> = self.tickers.values().cloned().collect();
+        tickers.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        tickers
+    }
+}
+
+static STORE: Mutex<Option<MetricsStore>> = Mutex::new(None);
+
+/// Enregistre un téléchargement dans les métriques globales
+pub fn record(symbol: &str, sample: FetchSample) {
+    let mut store = STORE.lock().unwrap();
+    store.get_or_insert_with(MetricsStore::default).record(symbol, sample, Utc::now());
+}
+
+/// Métriques actuelles des symboles déjà téléchargés
+pub fn snapshot() -> Vec<TickerMetrics> {
+    STORE.lock().unwrap().as_ref().map(MetricsStore::tickers).unwrap_or_default()
+}
+
+// ============================================================================
+// Export Prometheus
+// ============================================================================
+// Format texte d'exposition (text/plain; version=0.0.4) :
+//
+//   # HELP lazywallet_fetch_total Yahoo downloads per symbol
+//   # TYPE lazywallet_fetch_total counter
+//   lazywallet_fetch_total{symbol="AAPL"} 12
+//
+// Les symboles sont échappés (\ " et retour à la ligne)
+// ============================================================================
+
+/// Métriques au format texte Prometheus
+pub fn prometheus_text(tickers: &[TickerMetrics]) -> String {
+    type Value = fn(&TickerMetrics) -> String;
+    let families: [(&str, &str, &str, Value); 7] = [
+        ("lazywallet_fetch_total", "counter", "Yahoo downloads per symbol", |m| m.fetches.to_string()),
+        ("lazywallet_fetch_failures_total", "counter", "Downloads without a 2xx response", |m| m.failures.to_string()),
+        ("lazywallet_fetch_duration_seconds_total", "counter", "Time spent downloading", |m| {
+            m.total_duration.as_secs_f64().to_string()
+        }),
+        ("lazywallet_fetch_bytes_total", "counter", "Response bytes received", |m| m.total_bytes.to_string()),
+        ("lazywallet_fetch_retries_total", "counter", "Retries after HTTP 429", |m| m.total_retries.to_string()),
+        ("lazywallet_fetch_last_duration_seconds", "gauge", "Duration of the last download", |m| {
+            m.last.duration.as_secs_f64().to_string()
+        }),
+        ("lazywallet_fetch_last_status", "gauge", "HTTP status of the last download (0: no response)", |m| {
+            m.last.status.unwrap_or(0).to_string()
+        }),
+    ];
+
+    let mut text = String::new();
+    for (name, kind, help, value) in families {
+        let _ = writeln!(text, "# HELP {} {}", name, help);
+        let _ = writeln!(text, "# TYPE {} {}", name, kind);
+        for metrics in tickers {
+            let _ = writeln!(text, "{}{{symbol=\"{}\"}} {}", name, escape_label(&metrics.symbol), value(metrics));
+        }
+    }
+    text
+}
+
+/// Échappe une valeur d'étiquette Prometheus
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(millis: u64, bytes: usize, retries: u32, status: Option<u16>) -> FetchSample {
+        FetchSample { duration: Duration::from_millis(millis), bytes, retries, status }
+    }
+
+    #[test]
+    fn test_store_accumulates_per_symbol() {
+        let mut store = MetricsStore::default();
+        let at = Utc::now();
+        store.record("TSLA", sample(2300, 0, 2, Some(429)), at);
+        store.record("AAPL", sample(100, 40_000, 0, Some(200)), at);
+        store.record("AAPL", sample(300, 42_000, 1, Some(200)), at);
+        store.record("AAPL", sample(500, 0, 0, None), at);
+
+        let tickers = store.tickers();
+        assert_eq!(tickers.iter().map(|m| m.symbol.as_str()).collect::<Vec<_>>(), ["AAPL", "TSLA"]);
+        let aapl = &tickers[0];
+        assert_eq!((aapl.fetches, aapl.failures, aapl.total_bytes, aapl.total_retries), (3, 1, 82_000, 1));
+        assert_eq!(aapl.average_duration(), Duration::from_millis(300));
+        assert_eq!(aapl.last.status, None);
+        assert_eq!(tickers[1].failures, 1);
+    }
+
+    #[test]
+    fn test_prometheus_text() {
+        let mut store = MetricsStore::default();
+        store.record("AAPL", sample(250, 1024, 1, Some(200)), Utc::now());
+        store.record("A\"B", sample(0, 0, 0, None), Utc::now());
+
+        let text = prometheus_text(&store.tickers());
+        assert!(text.contains("# TYPE lazywallet_fetch_total counter\n"), "{}", text);
+        assert!(text.contains("lazywallet_fetch_total{symbol=\"AAPL\"} 1\n"), "{}", text);
+        assert!(text.contains("lazywallet_fetch_bytes_total{symbol=\"AAPL\"} 1024\n"), "{}", text);
+        assert!(text.contains("lazywallet_fetch_last_duration_seconds{symbol=\"AAPL\"} 0.25\n"), "{}", text);
+        assert!(text.contains("lazywallet_fetch_last_status{symbol=\"A\\\"B\"} 0\n"), "{}", text);
+        assert!(prometheus_text(&[]).lines().all(|line| line.starts_with('#')));
+    }
+}
@@ -5,7 +5,19 @@
 // financières depuis différentes sources (Yahoo Finance, CoinGecko, etc.)
 // ============================================================================
 
-pub mod yahoo;  // Client API Yahoo Finance
+pub mod yahoo;   // Client API Yahoo Finance
+pub mod binance; // Client API Binance (carnet d'ordres crypto)
+pub mod http;    // Client HTTP partagé
+pub mod webhook; // Envoi des alertes déclenchées vers un webhook
+pub mod recording; // Enregistrement / rejeu des réponses Yahoo (--record, --replay)
+pub mod health;  // Santé des fournisseurs (fenêtre glissante succès / échecs)
+pub mod metrics; // Métriques des téléchargements par ticker (overlay, Prometheus)
+pub mod csv;     // Instruments personnalisés (bougies lues depuis un fichier CSV)
+pub mod connectivity; // Sonde réseau périodique (mode hors ligne)
+pub mod fear_greed; // Indice Fear & Greed crypto (alternative.me, en cache)
+pub mod coingecko;  // Statistiques de marché des cryptos (CoinGecko, en cache)
+pub mod remote_watchlist; // Watchlist partagée publiée à une URL (rechargée périodiquement)
+pub mod economic_calendar; // Publications économiques (flux ICS ou JSON, rechargé périodiquement)
 
 // Re-export des fonctions principales
 pub use yahoo::fetch_ticker_data;
@@ -0,0 +1,182 @@
+// ============================================================================
+// Enregistrement / rejeu des réponses Yahoo Finance
+// ============================================================================
+// - `--record DIR` : chaque réponse Yahoo (statut + corps brut) est aussi
+//   écrite dans DIR, un fichier JSON par requête
+// - `--replay DIR` : les requêtes sont servies depuis DIR, sans réseau
+//
+// Utile pour joindre des réponses réelles à un rapport de bug, ou pour
+// développer hors ligne sur des charges utiles telles que Yahoo les envoie
+//
+// Fichier : DIR/v8_finance_chart_AAPL_interval_30m.json
+// { "url": "https://query1.finance.yahoo.com/v8/finance/chart/AAPL?...",
+//   "status": 200, "body": "{\"chart\":{...}}" }
+//
+// CONCEPT : Clé de requête stable
+// - Les paramètres period1 / period2 dépendent de l'heure et le crumb de la
+//   session (voir yahoo.rs) : ils sont exclus de la clé pour qu'un
+//   enregistrement reste rejouable plus tard
+// - L'hôte est exclu aussi (query1 / query2 / serveur de test)
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Mode d'enregistrement choisi au démarrage
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mode {
+    /// Enregistre chaque réponse dans ce répertoire
+    Record(PathBuf),
+
+    /// Sert les réponses depuis ce répertoire
+    Replay(PathBuf),
+}
+
+/// Réponse enregistrée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    pub url: String,
+    pub status: u16,
+    pub body: String,
+}
+
+static MODE: OnceLock<Mode> = OnceLock::new();
+
+/// Active l'enregistrement ou le rejeu pour toute la durée du programme
+///
+/// Retourne false si un mode a déjà été choisi
+pub fn set_mode(mode: Mode) -> bool {
+    MODE.set(mode).is_ok()
+}
+
+/// Mode actif (None : requêtes réseau normales)
+pub fn mode() -> Option<&'static Mode> {
+    MODE.get()
+}
+
+/// Lit "--record DIR" ou "--replay DIR" dans les arguments
+///
+/// Sans DIR, utilise le répertoire par défaut
+pub fn parse_args(args: &[String]) -> Option<Mode> {
+    let value = |position: usize| {
+        args.get(position + 1)
+            .filter(|value| !value.starts_with("--"))
+            .map(PathBuf::from)
+            .unwrap_or_else(default_dir)
+    };
+
+    if let Some(position) = args.iter().position(|arg| arg == "--replay") {
+        return Some(Mode::Replay(value(position)));
+    }
+    args.iter()
+        .position(|arg| arg == "--record")
+        .map(|position| Mode::Record(value(position)))
+}
+
+/// Répertoire par défaut des enregistrements
+///
+/// - Linux : ~/.local/share/lazywallet/recordings
+/// - Fallback : ./recordings
+pub fn default_dir() -> PathBuf {
+    dirs::data_dir()
+        .map(|dir| dir.join("lazywallet").join("recordings"))
+        .unwrap_or_else(|| PathBuf::from("./recordings"))
+}
+
+/// Nom de fichier d'une requête (chemin + paramètres stables)
+fn file_name(url: &str) -> String {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path_and_query = without_scheme.find('/').map_or("", |index| &without_scheme[index..]);
+    let (path, query) = path_and_query.split_once('?').unwrap_or((path_and_query, ""));
+
+    let params: Vec<&str> = query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .filter(|param| !["period1=", "period2=", "crumb="].iter().any(|prefix| param.starts_with(prefix)))
+        .collect();
+
+    let key = format!("{}_{}", path, params.join("_"));
+    let safe: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+        .collect();
+
+    format!("{}.json", safe.trim_matches('_'))
+}
+
+/// Écrit une réponse dans le répertoire d'enregistrement
+pub fn save(dir: &Path, recording: &Recording) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Échec de la création du répertoire {}", dir.display()))?;
+
+    let path = dir.join(file_name(&recording.url));
+    let content = serde_json::to_string_pretty(recording)?;
+    std::fs::write(&path, content).with_context(|| format!("Échec de l'écriture de {}", path.display()))?;
+    Ok(path)
+}
+
+/// Relit la réponse enregistrée pour une URL
+pub fn load(dir: &Path, url: &str) -> Result<Recording> {
+    let path = dir.join(file_name(url));
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Aucun enregistrement pour cette requête ({})", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Format invalide dans {}", path.display()))
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name_ignores_time_window_and_host() {
+        let a = file_name("https://query1.finance.yahoo.com/v8/finance/chart/AAPL?interval=30m&period1=1&period2=2");
+        let b = file_name("http://127.0.0.1:8080/v8/finance/chart/AAPL?interval=30m&period1=9&period2=10");
+        assert_eq!(a, "v8_finance_chart_AAPL_interval_30m.json");
+        assert_eq!(a, b);
+
+        assert_eq!(
+            file_name("https://query1.finance.yahoo.com/v8/finance/chart/^GSPC?interval=1d"),
+            "v8_finance_chart__GSPC_interval_1d.json"
+        );
+        assert_eq!(
+            file_name("https://query2.finance.yahoo.com/v10/finance/quoteSummary/AAPL?modules=price&crumb=a%2Fb"),
+            "v10_finance_quoteSummary_AAPL_modules_price.json"
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("lazywallet_recordings_{}", std::process::id()));
+        let recording = Recording {
+            url: "https://query1.finance.yahoo.com/v8/finance/chart/AAPL?interval=1d&period1=1".to_string(),
+            status: 200,
+            body: "{\"chart\":{}}".to_string(),
+        };
+
+        save(&dir, &recording).unwrap();
+        let replayed = load(&dir, "https://query1.finance.yahoo.com/v8/finance/chart/AAPL?interval=1d&period1=2").unwrap();
+        assert_eq!(replayed, recording);
+        assert!(load(&dir, "https://query1.finance.yahoo.com/v8/finance/chart/MSFT?interval=1d").is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(parse_args(&args(&["--demo"])), None);
+        assert_eq!(
+            parse_args(&args(&["--record", "/tmp/rec"])),
+            Some(Mode::Record(PathBuf::from("/tmp/rec")))
+        );
+        assert_eq!(parse_args(&args(&["--replay", "--demo"])), Some(Mode::Replay(default_dir())));
+    }
+}
@@ -0,0 +1,159 @@
+// ============================================================================
+// Watchlist distante (liste de symboles publiée à une URL)
+// ============================================================================
+// Une équipe maintient une liste centrale ; chaque lazywallet la suit :
+//
+//   "remote_watchlist": { "url": "https://intranet/watchlist.txt", "refresh_minutes": 60 }
+//
+// Formats acceptés pour la réponse :
+// - Texte : un symbole par ligne, "#" commente la fin de ligne
+//     # Valeurs suivies par l'équipe
+//     AAPL
+//     MC.PA   # LVMH
+// - JSON : ["AAPL", "MC.PA"] ou { "symbols": ["AAPL", "MC.PA"] }
+//
+// CONCEPT : Cache disque
+// - La dernière liste reçue est gardée dans le répertoire de données du
+//   profil (remote_watchlist.json) : au démarrage sans réseau, les symboles
+//   distants sont quand même chargés
+// - Les symboles de config.watchlist restent suivis quoi qu'il arrive ;
+//   un symbole retiré de la liste distante quitte la watchlist (voir
+//   App::apply_remote_watchlist)
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, instrument};
+
+use crate::profile;
+
+/// Source distante de la watchlist (section "remote_watchlist" de config.json)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteWatchlist {
+    /// URL de la liste (texte ou JSON)
+    pub url: String,
+
+    /// Intervalle entre deux rechargements de la liste (minutes, 1 minimum)
+    #[serde(default = "default_refresh_minutes")]
+    pub refresh_minutes: u64,
+}
+
+fn default_refresh_minutes() -> u64 {
+    60
+}
+
+impl RemoteWatchlist {
+    /// Intervalle entre deux rechargements
+    pub fn refresh_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.refresh_minutes.max(1) * 60)
+    }
+}
+
+/// Réponse JSON : liste seule ou objet { "symbols": [...] }
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum JsonList {
+    Symbols(Vec<String>),
+    Object { symbols: Vec<String> },
+}
+
+/// Télécharge la liste de symboles
+#[instrument]
+pub async fn fetch_symbols(url: &str) -> Result<Vec<String>> {
+    debug!("Fetching remote watchlist");
+    let response = super::http::client()
+        .get(url)
+        .send()
+        .await
+        .with_context(|| format!("Échec de la requête HTTP vers {}", url))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        anyhow::bail!("{} a retourné une erreur : HTTP {}", url, status);
+    }
+
+    let body = response.text().await.context("Échec de la lecture de la liste distante")?;
+    parse_symbols(&body)
+}
+
+/// Extrait les symboles d'une réponse texte ou JSON
+///
+/// Les symboles sont mis en majuscules et dédoublonnés (ordre conservé)
+pub fn parse_symbols(body: &str) -> Result<Vec<String>> {
+    let body = body.trim_start_matches('\u{feff}').trim();
+    let raw: Vec<String> = if body.starts_with('[') || body.starts_with('{') {
+        match serde_json::from_str(body).context("Liste distante JSON invalide")? {
+            JsonList::Symbols(symbols) | JsonList::Object { symbols } => symbols,
+        }
+    } else {
+        body.lines()
+            .map(|line| line.split('#').next().unwrap_or_default().trim().to_string())
+            .collect()
+    };
+
+    let mut symbols: Vec<String> = Vec::new();
+    for symbol in raw.iter().map(|symbol| symbol.trim().to_uppercase()) {
+        if !symbol.is_empty() && !symbol.contains(char::is_whitespace) && !symbols.contains(&symbol) {
+            symbols.push(symbol);
+        }
+    }
+    Ok(symbols)
+}
+
+/// Chemin du cache
+///
+/// - Linux : ~/.local/share/lazywallet/remote_watchlist.json (ou .../profiles/NOM)
+pub fn cache_path() -> Option<PathBuf> {
+    profile::data_dir().map(|dir| dir.join("remote_watchlist.json"))
+}
+
+/// Lit la dernière liste en cache (vide : absente ou illisible)
+pub fn load_cache(path: &Path) -> Vec<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Écrit la liste dans le cache (crée le répertoire si besoin)
+pub fn save_cache(path: &Path, symbols: &[String]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+    }
+    let content = serde_json::to_string_pretty(symbols)?;
+    std::fs::write(path, content).with_context(|| format!("Échec de l'écriture de {}", path.display()))
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_symbols() {
+        let text = "# Valeurs suivies par l'équipe\r\naapl\n\nMC.PA   # LVMH\nAAPL\n^GSPC\n";
+        assert_eq!(parse_symbols(text).unwrap(), ["AAPL", "MC.PA", "^GSPC"]);
+
+        assert_eq!(parse_symbols(r#"["btc-usd", " EURUSD=X "]"#).unwrap(), ["BTC-USD", "EURUSD=X"]);
+        assert_eq!(parse_symbols(r#"{ "name": "Équipe", "symbols": ["NVDA"] }"#).unwrap(), ["NVDA"]);
+        assert!(parse_symbols(r#"{ "tickers": ["NVDA"] }"#).is_err());
+        assert!(parse_symbols("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cache_roundtrip() {
+        let path = std::env::temp_dir().join(format!("lazywallet_remote_{}.json", std::process::id()));
+        assert!(load_cache(&path).is_empty());
+
+        let symbols = vec!["AAPL".to_string(), "MC.PA".to_string()];
+        save_cache(&path, &symbols).unwrap();
+        assert_eq!(load_cache(&path), symbols);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,84 @@
+// ============================================================================
+// Webhook : notification HTTP des alertes déclenchées
+// ============================================================================
+// Quand une règle d'alerte possède une URL "webhook", on y envoie un POST
+// JSON (Slack, Discord, ntfy, serveur perso, ...) :
+//
+//   POST https://ntfy.sh/mon-topic
+//   {
+//     "symbol": "AAPL",
+//     "condition": "RSI(14) < 30",
+//     "price": 168.42,
+//     "time": "2024-05-02T14:30:00Z",
+//     "text": "AAPL : RSI(14) < 30 (prix 168.42)"
+//   }
+//
+// Le champ "text" permet aux services qui n'affichent qu'un message (Slack,
+// Discord via "content" ...) d'avoir une version lisible
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use tracing::{debug, instrument, warn};
+
+use crate::alerts::AlertTrigger;
+
+/// Corps JSON envoyé au webhook
+fn payload(trigger: &AlertTrigger) -> Value {
+    json!({
+        "symbol": trigger.symbol,
+        "condition": trigger.condition,
+        "price": trigger.price,
+        "time": trigger.time.to_rfc3339(),
+        "text": format!("{} : {} (prix {:.2})", trigger.symbol, trigger.condition, trigger.price),
+    })
+}
+
+/// Envoie une alerte déclenchée à un webhook
+#[instrument(skip(trigger), fields(symbol = %trigger.symbol))]
+pub async fn post_alert(url: &str, trigger: &AlertTrigger) -> Result<()> {
+    debug!("Posting alert to webhook");
+
+    let response = super::http::client()
+        .post(url)
+        .json(&payload(trigger))
+        .send()
+        .await
+        .with_context(|| format!("Échec de la requête HTTP vers {}", url))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        warn!(status = %status, "Webhook returned error status");
+        anyhow::bail!("Le webhook a retourné une erreur : HTTP {}", status);
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_payload() {
+        let trigger = AlertTrigger {
+            symbol: "AAPL".to_string(),
+            condition: "RSI(14) < 30".to_string(),
+            price: 168.42,
+            time: Utc.with_ymd_and_hms(2024, 5, 2, 14, 30, 0).unwrap(),
+            webhook: None,
+            command: None,
+        };
+
+        let body = payload(&trigger);
+        assert_eq!(body["symbol"], "AAPL");
+        assert_eq!(body["price"], 168.42);
+        assert_eq!(body["time"], "2024-05-02T14:30:00+00:00");
+        assert_eq!(body["text"], "AAPL : RSI(14) < 30 (prix 168.42)");
+    }
+}
@@ -10,12 +10,274 @@
 // 4. Lifetimes : gestion de la durée de vie des références
 // ============================================================================
 
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
 use anyhow::{Context, Result};
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde::de::{Deserializer, SeqAccess, Visitor};
 use serde::Deserialize;
 use tracing::{debug, error, info, instrument, warn};
 
-use crate::models::{Interval, OHLCData, Timeframe, OHLC};
+use super::health::{self, Provider};
+use super::metrics::{self, FetchSample};
+use super::recording::{self, Mode};
+use crate::models::analyst::AnalystRatings;
+use crate::models::screener::{Screener, ScreenerQuote};
+use crate::models::{Interval, OHLCData, TickerType, Timeframe, OHLC};
+use crate::models::validation::OHLCDataBuilder;
+
+pub mod holders; // quoteSummary : initiés et détenteurs institutionnels
+pub mod financials; // fundamentals-timeseries : chiffre d'affaires, résultat net, free cash flow
+pub mod dividends; // quoteSummary : dates et montant du prochain dividende
+
+// ============================================================================
+// Hôtes et requêtes
+// ============================================================================
+// CONCEPT : URL de base injectable
+// - Par défaut : query1 / query2.finance.yahoo.com
+// - set_base_url() remplace les deux hôtes (serveur de test local, miroir)
+//
+// CONCEPT : Retry sur HTTP 429 (Too Many Requests)
+// - Yahoo limite le débit : on réessaie MAX_RETRIES fois
+// - Délai : en-tête Retry-After s'il est présent, sinon 1s puis 2s
+// ============================================================================
+
+const QUERY1_HOST: &str = "https://query1.finance.yahoo.com";
+const QUERY2_HOST: &str = "https://query2.finance.yahoo.com";
+
+/// Nombre de nouvelles tentatives après un HTTP 429
+const MAX_RETRIES: u32 = 2;
+
+/// Délai avant la première nouvelle tentative (doublé ensuite)
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Délai maximum accepté depuis un en-tête Retry-After
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(10);
+
+static BASE_URL: OnceLock<String> = OnceLock::new();
+
+/// Remplace les hôtes Yahoo Finance pour toute la durée du programme
+///
+/// Retourne false si une URL de base a déjà été définie
+pub fn set_base_url(url: &str) -> bool {
+    BASE_URL.set(url.trim_end_matches('/').to_string()).is_ok()
+}
+
+/// Hôte à utiliser (URL de base injectée, sinon l'hôte Yahoo par défaut)
+fn host(default: &'static str) -> &'static str {
+    BASE_URL.get().map(String::as_str).unwrap_or(default)
+}
+
+/// Envoie un GET et réessaie tant que Yahoo répond HTTP 429
+///
+/// Les autres statuts (succès ou erreur) sont retournés tels quels, avec le
+/// nombre de nouvelles tentatives
+///
+/// # Arguments
+/// * `cookie` - En-tête Cookie de la session Yahoo (endpoints protégés)
+async fn get_with_retry(url: &str, cookie: Option<&str>) -> Result<(reqwest::Response, u32)> {
+    let mut attempt = 0;
+    loop {
+        let mut request = super::http::client().get(url);
+        if let Some(cookie) = cookie {
+            request = request.header(reqwest::header::COOKIE, cookie);
+        }
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                health::record_failure(Provider::Yahoo, &e.to_string());
+                return Err(e).context("Échec de la requête HTTP vers Yahoo Finance");
+            }
+        };
+
+        if response.status() != StatusCode::TOO_MANY_REQUESTS || attempt >= MAX_RETRIES {
+            health::record_status(Provider::Yahoo, response.status());
+            return Ok((response, attempt));
+        }
+
+        let delay = retry_after(&response).unwrap_or(RETRY_BASE_DELAY * 2u32.pow(attempt));
+        attempt += 1;
+        warn!(attempt, delay_ms = delay.as_millis() as u64, "Yahoo rate limit hit, retrying");
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Réponse Yahoo : statut HTTP, corps brut et nouvelles tentatives
+struct Exchange {
+    status: StatusCode,
+    body: String,
+    retries: u32,
+}
+
+/// Réponse Yahoo : statut HTTP et corps brut
+async fn get(url: &str) -> Result<(StatusCode, String)> {
+    get_with_cookie(url, None).await
+}
+
+/// Comme get(), avec l'en-tête Cookie d'une session Yahoo
+async fn get_with_cookie(url: &str, cookie: Option<&str>) -> Result<(StatusCode, String)> {
+    let exchange = exchange(url, cookie).await?;
+    Ok((exchange.status, exchange.body))
+}
+
+/// Comme get_with_cookie(), avec le nombre de nouvelles tentatives (métriques)
+///
+/// CONCEPT : Point de passage unique
+/// - Toutes les requêtes Yahoo passent ici
+/// - En rejeu, la réponse vient du disque ; en enregistrement, elle y est
+///   copiée (voir recording.rs)
+async fn exchange(url: &str, cookie: Option<&str>) -> Result<Exchange> {
+    if let Some(Mode::Replay(dir)) = recording::mode() {
+        let recorded = recording::load(dir, url)?;
+        debug!(url = %url, status = recorded.status, "Replaying recorded response");
+        let status = StatusCode::from_u16(recorded.status).context("Statut HTTP enregistré invalide")?;
+        return Ok(Exchange { status, body: recorded.body, retries: 0 });
+    }
+
+    let (response, retries) = get_with_retry(url, cookie).await?;
+    let status = response.status();
+    let body = response.text().await.context("Échec de la lecture de la réponse Yahoo")?;
+
+    if let Some(Mode::Record(dir)) = recording::mode() {
+        let recorded = recording::Recording {
+            url: url.to_string(),
+            status: status.as_u16(),
+            body: body.clone(),
+        };
+        match recording::save(dir, &recorded) {
+            Ok(path) => debug!(path = %path.display(), "Response recorded"),
+            Err(e) => warn!(error = ?e, "Failed to record response"),
+        }
+    }
+
+    Ok(Exchange { status, body, retries })
+}
+
+/// Délai demandé par l'en-tête Retry-After (en secondes, plafonné)
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let seconds: u64 = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds).min(MAX_RETRY_AFTER))
+}
+
+// ============================================================================
+// Session Yahoo : cookie de consentement + crumb
+// ============================================================================
+// Certains endpoints (quoteSummary, screeners) refusent les requêtes
+// anonymes (HTTP 401 "Invalid Crumb") :
+//
+// 1. GET fc.yahoo.com           → Set-Cookie: A3=... (cookie de session)
+// 2. GET /v1/test/getcrumb      → "AbCdEf1.gH/" (crumb lié au cookie)
+// 3. GET /v10/...&crumb=AbCd... → avec l'en-tête Cookie
+//
+// CONCEPT : Cache invalidé sur refus
+// - La session est obtenue au premier appel protégé puis réutilisée
+// - Un HTTP 401 / 403 (crumb expiré) l'efface : une nouvelle session est
+//   obtenue et la requête rejouée une fois
+// - Sans session possible (réseau filtré), la requête part sans crumb
+// ============================================================================
+
+const COOKIE_HOST: &str = "https://fc.yahoo.com";
+
+/// Cookie et crumb d'une session Yahoo
+#[derive(Debug, Clone, PartialEq)]
+struct Session {
+    cookie: String,
+    crumb: String,
+}
+
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+
+/// Session en cache, ou nouvelle session si aucune
+async fn cached_session() -> Result<Session> {
+    if let Some(session) = SESSION.lock().unwrap().clone() {
+        return Ok(session);
+    }
+
+    let session = open_session().await?;
+    *SESSION.lock().unwrap() = Some(session.clone());
+    Ok(session)
+}
+
+/// Obtient un cookie de consentement puis le crumb associé
+async fn open_session() -> Result<Session> {
+    let (response, _) = get_with_retry(&format!("{}/", host(COOKIE_HOST)), None).await?;
+    let cookie = session_cookie(response.headers()).context("Yahoo n'a pas fourni de cookie de session")?;
+
+    let (response, _) = get_with_retry(&format!("{}/v1/test/getcrumb", host(QUERY2_HOST)), Some(&cookie)).await?;
+    let status = response.status();
+    let crumb = response.text().await.context("Échec de la lecture du crumb Yahoo")?;
+    let crumb = crumb.trim();
+    if !status.is_success() || crumb.is_empty() || crumb.contains(['{', '<', ' ']) {
+        anyhow::bail!("Yahoo Finance a refusé de fournir un crumb : HTTP {}", status);
+    }
+
+    info!("Yahoo session opened");
+    Ok(Session {
+        cookie,
+        crumb: crumb.to_string(),
+    })
+}
+
+/// Construit l'en-tête Cookie à partir des Set-Cookie d'une réponse
+///
+/// Ne garde que "nom=valeur" (les attributs Domain, Path, Expires... sont ignorés)
+fn session_cookie(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let pairs: Vec<&str> = headers
+        .get_all(reqwest::header::SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|value| value.split(';').next())
+        .map(str::trim)
+        .filter(|pair| pair.contains('='))
+        .collect();
+
+    (!pairs.is_empty()).then(|| pairs.join("; "))
+}
+
+/// Ajoute le crumb aux paramètres d'une URL (encodé)
+fn with_crumb(url: &str, crumb: &str) -> Result<String> {
+    let mut url = reqwest::Url::parse(url).with_context(|| format!("URL invalide : {}", url))?;
+    url.query_pairs_mut().append_pair("crumb", crumb);
+    Ok(url.to_string())
+}
+
+/// GET sur un endpoint protégé par crumb
+///
+/// Rejoue la requête une fois avec une nouvelle session si le crumb est refusé
+async fn get_authenticated(url: &str) -> Result<(StatusCode, String)> {
+    // Rejeu : les réponses viennent du disque, pas de session à ouvrir
+    if matches!(recording::mode(), Some(Mode::Replay(_))) {
+        return get(url).await;
+    }
+
+    let session = match cached_session().await {
+        Ok(session) => session,
+        Err(e) => {
+            warn!(error = ?e, "Failed to open Yahoo session, sending request without crumb");
+            return get(url).await;
+        }
+    };
+
+    let (status, body) = get_with_cookie(&with_crumb(url, &session.crumb)?, Some(&session.cookie)).await?;
+    if status != StatusCode::UNAUTHORIZED && status != StatusCode::FORBIDDEN {
+        return Ok((status, body));
+    }
+
+    // Crumb expiré : nouvelle session et seconde (dernière) tentative
+    warn!(status = %status, "Yahoo crumb refused, opening a new session");
+    SESSION.lock().unwrap().take();
+    let session = cached_session().await?;
+    get_with_cookie(&with_crumb(url, &session.crumb)?, Some(&session.cookie)).await
+}
 
 // ============================================================================
 // Structures pour parser la réponse JSON de Yahoo Finance
@@ -29,6 +291,12 @@ use crate::models::{Interval, OHLCData, Timeframe, OHLC};
 // ============================================================================
 
 /// Réponse complète de l'API Yahoo Finance
+///
+/// CONCEPT : Réponses partielles
+/// - Tous les champs sont optionnels : Yahoo omet ou met à null des blocs
+///   entiers selon le symbole (indice sans volume, fonds sans quote, ...)
+/// - Les cas connus deviennent une ChartError précise plutôt qu'un échec de
+///   parsing générique
 #[derive(Debug, Deserialize)]
 struct YahooResponse {
     chart: Chart,
@@ -36,40 +304,174 @@ struct YahooResponse {
 
 #[derive(Debug, Deserialize)]
 struct Chart {
-    result: Vec<ChartResult>,
-    error: Option<serde_json::Value>,
+    result: Option<Vec<ChartResult>>,
+    error: Option<ChartApiError>,
+}
+
+/// Erreur renvoyée par Yahoo dans chart.error
+///
+/// Exemple : { "code": "Not Found", "description": "No data found, symbol may be delisted" }
+#[derive(Debug, Deserialize)]
+struct ChartApiError {
+    code: Option<String>,
+    description: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 struct ChartResult {
-    meta: Meta,
+    meta: Option<Meta>,
     timestamp: Option<Vec<i64>>,
-    indicators: Indicators,
+    indicators: Option<Indicators>,
 }
 
 /// Métadonnées du ticker
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]  // Convertit automatiquement snake_case -> camelCase
+#[allow(dead_code)] // Certains champs ne sont pas encore utilisés par l'app
 struct Meta {
-    symbol: String,
+    symbol: Option<String>,
     long_name: Option<String>,
+    currency: Option<String>,
     regular_market_price: Option<f64>,
     chart_previous_close: Option<f64>,
 }
 
 #[derive(Debug, Deserialize)]
 struct Indicators {
-    quote: Vec<Quote>,
+    quote: Option<Vec<Quote>>,
+    adjclose: Option<Vec<AdjClose>>,
 }
 
 /// Données OHLCV (Open, High, Low, Close, Volume)
 #[derive(Debug, Deserialize)]
 struct Quote {
-    open: Option<Vec<Option<f64>>>,
-    high: Option<Vec<Option<f64>>>,
-    low: Option<Vec<Option<f64>>>,
-    close: Option<Vec<Option<f64>>>,
-    volume: Option<Vec<Option<u64>>>,
+    open: Option<Column<f64>>,
+    high: Option<Column<f64>>,
+    low: Option<Column<f64>>,
+    close: Option<Column<f64>>,
+    volume: Option<Column<u64>>,
+}
+
+/// Clôtures ajustées (dividendes, splits), seules données de certains fonds
+#[derive(Debug, Deserialize)]
+struct AdjClose {
+    adjclose: Option<Column<f64>>,
+}
+
+/// Colonne de valeurs Yahoo ([172.5, null, 173.1, ...]) sans Option par case
+///
+/// CONCEPT : Mémoire d'un historique de plusieurs années
+/// - Vec<Option<f64>> : 16 octets par valeur, et la colonne entière est
+///   désérialisée avant de construire la première bougie
+/// - Column<f64> : 8 octets, null gardé comme NaN (absent du JSON, donc sans
+///   ambiguïté) ; un volume null vaut 0 comme dans OHLC
+/// - Capacité réservée d'après la taille annoncée par le parser
+///
+/// CONCEPT RUST : Visitor (serde)
+/// - deserialize_seq() appelle visit_seq() élément par élément : chaque
+///   valeur est convertie à la lecture, sans Vec intermédiaire
+#[derive(Debug, Default)]
+struct Column<T>(Vec<T>);
+
+/// Valeur d'une case null dans une Column
+trait ColumnValue: Copy {
+    const NULL: Self;
+
+    /// None si la case était null
+    fn present(self) -> Option<Self>;
+}
+
+impl ColumnValue for f64 {
+    const NULL: f64 = f64::NAN;
+
+    fn present(self) -> Option<f64> {
+        (!self.is_nan()).then_some(self)
+    }
+}
+
+impl ColumnValue for u64 {
+    const NULL: u64 = 0;
+
+    fn present(self) -> Option<u64> {
+        Some(self)
+    }
+}
+
+impl<T: ColumnValue> Column<T> {
+    /// Valeur à l'index `i` (None si l'index manque ou si la case est null)
+    fn get(&self, i: usize) -> Option<T> {
+        self.0.get(i).copied().and_then(T::present)
+    }
+
+    /// Vérifie si au moins une case est renseignée
+    fn any_present(&self) -> bool {
+        self.0.iter().any(|value| value.present().is_some())
+    }
+}
+
+impl<'de, T: ColumnValue + Deserialize<'de>> Deserialize<'de> for Column<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct ColumnVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T: ColumnValue + Deserialize<'de>> Visitor<'de> for ColumnVisitor<T> {
+            type Value = Column<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an array of numbers or nulls")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<Column<T>, A::Error> {
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(value) = seq.next_element::<Option<T>>()? {
+                    values.push(value.unwrap_or(T::NULL));
+                }
+                Ok(Column(values))
+            }
+        }
+
+        deserializer.deserialize_seq(ColumnVisitor(std::marker::PhantomData))
+    }
+}
+
+/// Échec d'une réponse chart de Yahoo
+///
+/// Transporté dans anyhow::Error : `error.downcast_ref::<ChartError>()`
+/// distingue un symbole inconnu d'une panne réseau
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChartError {
+    /// chart.error renseigné (symbole inconnu ou délisté, période refusée...)
+    Api { code: String, description: String },
+
+    /// Ni résultat ni erreur
+    NoResult,
+
+    /// Ni quote ni clôtures ajustées dans le résultat
+    NoQuotes,
+
+    /// Aucune bougie complète
+    NoCandles { symbol: String },
+}
+
+impl std::fmt::Display for ChartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChartError::Api { code, description } => write!(f, "Yahoo Finance : {} ({})", description, code),
+            ChartError::NoResult => write!(f, "Aucune données retournée par Yahoo Finance"),
+            ChartError::NoQuotes => write!(f, "Pas de données OHLC dans la réponse"),
+            ChartError::NoCandles { symbol } => write!(f, "Aucune donnée OHLC valide trouvée pour {}", symbol),
+        }
+    }
+}
+
+impl std::error::Error for ChartError {}
+
+impl From<ChartApiError> for ChartError {
+    fn from(error: ChartApiError) -> Self {
+        ChartError::Api {
+            code: error.code.unwrap_or_else(|| "inconnu".to_string()),
+            description: error.description.unwrap_or_else(|| "erreur sans description".to_string()),
+        }
+    }
 }
 
 // ============================================================================
@@ -106,18 +508,93 @@ struct Quote {
 /// - Tous les logs à l'intérieur auront le contexte symbol + interval
 #[instrument(skip(interval), fields(interval = ?interval))]
 pub async fn fetch_ticker_data(symbol: &str, interval: Interval) -> Result<(OHLCData, Option<String>)> {
+    // Mode démo : série synthétique, aucun appel réseau
+    if crate::demo::is_enabled() {
+        return Ok((crate::demo::ticker_data(symbol, interval), crate::demo::name(symbol)));
+    }
+
+    // Instrument personnalisé : bougies relues depuis son fichier CSV
+    if let Some(instrument) = super::csv::instrument(symbol) {
+        return super::csv::fetch(instrument, interval);
+    }
+
     // Le timeframe est déterminé automatiquement selon l'intervalle
     let timeframe = interval.default_timeframe();
+    let (data, long_name) = fetch_source_data(symbol, interval.source_interval(), timeframe).await?;
+
+    // Intervalle absent de Yahoo (4h) : agrégation locale des bougies 1h
+    let data = if interval.source_interval() == interval {
+        data
+    } else {
+        debug!(source = %data.interval.label(), "Resampling candles locally");
+        data.resample(interval)
+            .with_context(|| format!("Impossible d'agréger {} en {}", data.interval.label(), interval.label()))?
+    };
+
+    info!(candles = data.len(), long_name = ?long_name, "Successfully fetched ticker data");
+    Ok((data, long_name))
+}
+
+/// Télécharge une série complétée par l'historique local (voir store.rs)
+///
+/// - Série déjà connue : seules les bougies depuis la dernière connue sont
+///   demandées, puis tout l'historique est relu depuis la base
+/// - Yahoo injoignable : la dernière série connue est servie telle quelle
+#[cfg(feature = "store")]
+async fn fetch_source_data(symbol: &str, interval: Interval, timeframe: Timeframe) -> Result<(OHLCData, Option<String>)> {
+    let since = crate::store::last_timestamp(symbol, interval);
+    let history = || {
+        since.and_then(|_| crate::store::load(symbol, interval)).map(|mut history| {
+            history.timeframe = timeframe;
+            history
+        })
+    };
 
+    match fetch_chart(symbol, interval, timeframe, since).await {
+        Ok((data, long_name)) => {
+            crate::store::save(&data);
+            // La base ne garde que les bougies : la devise vient de la réponse
+            let history = history().map(|mut history| {
+                history.currency = data.currency.clone();
+                history
+            });
+            Ok((history.unwrap_or(data), long_name))
+        }
+        Err(e) => match history() {
+            Some(history) => {
+                warn!(error = %e, candles = history.len(), "Yahoo unavailable, serving candles from the local store");
+                Ok((history, None))
+            }
+            None => Err(e),
+        },
+    }
+}
+
+/// Télécharge une série (sans historique local)
+#[cfg(not(feature = "store"))]
+async fn fetch_source_data(symbol: &str, interval: Interval, timeframe: Timeframe) -> Result<(OHLCData, Option<String>)> {
+    fetch_chart(symbol, interval, timeframe, None).await
+}
+
+/// Télécharge et parse une série Yahoo à un intervalle qu'elle fournit
+///
+/// `since` : ne demande que les bougies à partir de cette date (delta),
+/// sans remonter au-delà du timeframe
+async fn fetch_chart(
+    symbol: &str,
+    interval: Interval,
+    timeframe: Timeframe,
+    since: Option<DateTime<Utc>>,
+) -> Result<(OHLCData, Option<String>)> {
     // Construit l'URL de l'API Yahoo Finance
     // CONCEPT RUST : format! macro
     // - Équivalent à sprintf en C ou f-string en Python
     // - Type-safe et performant
-    let url = build_yahoo_url(symbol, interval, timeframe);
+    let url = build_yahoo_url(symbol, interval, timeframe, since);
     debug!(url = %url, interval = %interval.label(), timeframe = %timeframe.label(), "Built Yahoo Finance API URL");
 
     // CONCEPT RUST : async/await
-    // - reqwest::get() retourne une Future
+    // - send() retourne une Future
     // - .await suspend l'exécution jusqu'à ce que la requête soit terminée
     // - ? propage l'erreur si la requête échoue
     //
@@ -125,26 +602,29 @@ pub async fn fetch_ticker_data(symbol: &str, interval: Interval) -> Result<(OHLC
     // - .context() ajoute du contexte à une erreur
     // - Aide au debugging en donnant plus d'infos
     //
-    // Ajout d'un User-Agent pour éviter le blocage par Yahoo
-    debug!("Creating HTTP client");
-    let client = reqwest::Client::builder()
-        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
-        .build()
-        .context("Échec de la création du client HTTP")?;
-
+    // Client partagé (User-Agent configuré pour éviter le blocage par Yahoo),
+    // nouvelles tentatives si Yahoo limite le débit
+    // Durée, taille, nouvelles tentatives et statut : métriques du symbole
     debug!("Sending HTTP request to Yahoo Finance");
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .context("Échec de la requête HTTP vers Yahoo Finance")?;
-
-    let status = response.status();
+    let started = Instant::now();
+    let exchange = exchange(&url, None).await;
+    metrics::record(symbol, FetchSample {
+        duration: started.elapsed(),
+        bytes: exchange.as_ref().map_or(0, |exchange| exchange.body.len()),
+        retries: exchange.as_ref().map_or(0, |exchange| exchange.retries),
+        status: exchange.as_ref().ok().map(|exchange| exchange.status.as_u16()),
+    });
+    let Exchange { status, body, .. } = exchange?;
     debug!(status = %status, "Received HTTP response");
 
     // Vérifie que la réponse est un succès HTTP (200-299)
+    // Yahoo détaille souvent l'échec dans chart.error (404 d'un symbole inconnu)
     if !status.is_success() {
         error!(status = %status, "Yahoo Finance returned error status");
+        let api_error = serde_json::from_str::<YahooResponse>(&body).ok().and_then(|response| response.chart.error);
+        if let Some(api_error) = api_error {
+            return Err(ChartError::from(api_error).into());
+        }
         anyhow::bail!(
             "Yahoo Finance a retourné une erreur : HTTP {}",
             status
@@ -153,20 +633,15 @@ pub async fn fetch_ticker_data(symbol: &str, interval: Interval) -> Result<(OHLC
 
     // Parse la réponse JSON
     // CONCEPT RUST : Serde deserialization
-    // - .json::<T>() désérialise automatiquement le JSON vers le type T
+    // - from_str::<T>() désérialise automatiquement le JSON vers le type T
     // - Vérifie que la structure JSON match exactement
     debug!("Parsing JSON response");
-    let yahoo_response: YahooResponse = response
-        .json()
-        .await
-        .context("Échec du parsing JSON de la réponse Yahoo")?;
+    let yahoo_response: YahooResponse =
+        serde_json::from_str(&body).context("Échec du parsing JSON de la réponse Yahoo")?;
 
     // Convertit la réponse Yahoo en notre structure OHLCData et extrait le long_name
     debug!("Parsing Yahoo response to OHLCData");
-    let (data, long_name) = parse_yahoo_response(yahoo_response, symbol, interval, timeframe)?;
-
-    info!(candles = data.len(), long_name = ?long_name, "Successfully fetched ticker data");
-    Ok((data, long_name))
+    parse_yahoo_response(yahoo_response, symbol, interval, timeframe)
 }
 
 /// Construit l'URL de l'API Yahoo Finance
@@ -177,19 +652,22 @@ pub async fn fetch_ticker_data(symbol: &str, interval: Interval) -> Result<(OHLC
 /// - Pas de lifetime ici car String est owned
 ///
 /// L'intervalle est maintenant configurable (1m, 5m, 30m, 1h, 1d, etc.)
-fn build_yahoo_url(symbol: &str, interval: Interval, timeframe: Timeframe) -> String {
+fn build_yahoo_url(symbol: &str, interval: Interval, timeframe: Timeframe, since: Option<DateTime<Utc>>) -> String {
     // Calcule les timestamps Unix
+    // Delta : part de la dernière bougie connue, jamais avant le timeframe
+    // (Yahoo refuse l'intraday trop ancien)
     let now = chrono::Utc::now().timestamp();
     let days_ago = timeframe.to_days() as i64;
-    let period1 = now - (days_ago * 24 * 60 * 60);
+    let oldest = now - (days_ago * 24 * 60 * 60);
+    let period1 = since.map_or(oldest, |since| since.timestamp().max(oldest));
     let period2 = now;
 
     // Utilise l'intervalle fourni, converti au format Yahoo (ex: "30m", "1h", "1d")
     let interval_str = interval.to_yahoo_string();
 
     format!(
-        "https://query1.finance.yahoo.com/v8/finance/chart/{}?interval={}&period1={}&period2={}",
-        symbol, interval_str, period1, period2
+        "{}/v8/finance/chart/{}?interval={}&period1={}&period2={}",
+        host(QUERY1_HOST), symbol, interval_str, period1, period2
     )
 }
 
@@ -200,84 +678,101 @@ fn build_yahoo_url(symbol: &str, interval: Interval, timeframe: Timeframe) -> St
 /// - symbol est borrowed (&str), on ne le copie pas
 /// - interval et timeframe sont Copy (enums simples), donc copiés automatiquement
 ///
+/// Cas tolérés :
+/// - meta absent ou à null : pas de long_name
+/// - quote absent mais clôtures ajustées présentes : bougies "plates"
+///   (open = high = low = close = clôture ajustée)
+///
 /// Retourne un tuple (OHLCData, Option<String>) où le String est le long_name du ticker
+/// Erreurs : une ChartError (chart.error, résultat ou quote absents, aucune bougie)
 fn parse_yahoo_response(
     yahoo_response: YahooResponse,
     symbol: &str,
     interval: Interval,
     timeframe: Timeframe,
 ) -> Result<(OHLCData, Option<String>)> {
+    let chart = yahoo_response.chart;
+    if let Some(api_error) = chart.error {
+        return Err(ChartError::from(api_error).into());
+    }
+
     // Récupère le premier résultat
-    // CONCEPT RUST : Pattern matching avec if let
-    let result = yahoo_response
-        .chart
+    let result = chart
         .result
+        .unwrap_or_default()
         .into_iter()  // Consomme le Vec (move)
         .next()       // Prend le premier élément
-        .context("Aucune données retournée par Yahoo Finance")?;
+        .ok_or(ChartError::NoResult)?;
 
-    // Extrait le long_name depuis les métadonnées
-    let long_name = result.meta.long_name.clone();
+    // Extrait le long_name et la devise depuis les métadonnées
+    let meta = result.meta.unwrap_or_default();
+    let long_name = meta.long_name;
 
-    // Crée la structure OHLCData avec interval et timeframe
-    let mut ohlc_data = OHLCData::new(symbol.to_string(), interval, timeframe);
+    // Crée la série avec interval et timeframe : chaque bougie est validée
+    // (mèches incohérentes réparées, bougies irréparables écartées)
+    let mut builder = OHLCDataBuilder::new(symbol.to_string(), interval, timeframe);
 
     // Récupère les arrays de données
     // CONCEPT RUST : Option unwrap et default
     let timestamps = result.timestamp.unwrap_or_default();
     debug!(timestamp_count = timestamps.len(), "Received timestamps from Yahoo");
 
-    let quote = result.indicators.quote.into_iter().next()
-        .context("Pas de données OHLC dans la réponse")?;
+    let (quotes, adjcloses) = match result.indicators {
+        Some(indicators) => (indicators.quote.unwrap_or_default(), indicators.adjclose.unwrap_or_default()),
+        None => (Vec::new(), Vec::new()),
+    };
+    let quote = quotes.into_iter().next();
+    let adjcloses = adjcloses.into_iter().next().and_then(|adjclose| adjclose.adjclose);
+
+    let (opens, highs, lows, closes, volumes) = match quote {
+        Some(quote) => (
+            quote.open.unwrap_or_default(),
+            quote.high.unwrap_or_default(),
+            quote.low.unwrap_or_default(),
+            quote.close.unwrap_or_default(),
+            quote.volume.unwrap_or_default(),
+        ),
+        None => Default::default(),
+    };
 
-    let opens = quote.open.unwrap_or_default();
-    let highs = quote.high.unwrap_or_default();
-    let lows = quote.low.unwrap_or_default();
-    let closes = quote.close.unwrap_or_default();
-    let volumes = quote.volume.unwrap_or_default();
+    if timestamps.is_empty() {
+        return Err(ChartError::NoCandles { symbol: symbol.to_string() }.into());
+    }
+
+    // Sans aucune clôture, la série se rabat sur les clôtures ajustées
+    let adjusted_only = !closes.any_present();
+    let adjcloses = match adjcloses {
+        Some(adjcloses) if adjusted_only && adjcloses.any_present() => {
+            warn!(symbol = %symbol, "Only adjusted closes in Yahoo response, drawing flat candles");
+            adjcloses
+        }
+        _ if adjusted_only => return Err(ChartError::NoQuotes.into()),
+        _ => Column::default(),
+    };
 
     // CONCEPT RUST : Iterators et zip
     // - .iter() crée un itérateur sur une slice
     // - .enumerate() ajoute l'index
-    // - zip combine plusieurs itérateurs
-    // - for loop consomme l'itérateur
+    // - Column::get() : None si l'index manque ou si la valeur est null
+    // Les bougies sont construites directement depuis les colonnes
+    builder.reserve(timestamps.len());
     let mut skipped_count = 0;
     for (i, &timestamp) in timestamps.iter().enumerate() {
         // Extrait les valeurs à l'index i, skip si None
-        // CONCEPT RUST : Pattern matching avec match
-        let open = match opens.get(i).and_then(|&v| v) {
-            Some(v) => v,
-            None => {
-                skipped_count += 1;
-                continue;  // Skip cette chandelle si pas de données
+        let prices = if adjusted_only {
+            adjcloses.get(i).map(|close| (close, close, close, close))
+        } else {
+            match (opens.get(i), highs.get(i), lows.get(i), closes.get(i)) {
+                (Some(open), Some(high), Some(low), Some(close)) => Some((open, high, low, close)),
+                _ => None,
             }
         };
-
-        let high = match highs.get(i).and_then(|&v| v) {
-            Some(v) => v,
-            None => {
-                skipped_count += 1;
-                continue;
-            }
+        let Some((open, high, low, close)) = prices else {
+            skipped_count += 1;
+            continue;  // Skip cette chandelle si pas de données
         };
 
-        let low = match lows.get(i).and_then(|&v| v) {
-            Some(v) => v,
-            None => {
-                skipped_count += 1;
-                continue;
-            }
-        };
-
-        let close = match closes.get(i).and_then(|&v| v) {
-            Some(v) => v,
-            None => {
-                skipped_count += 1;
-                continue;
-            }
-        };
-
-        let volume = volumes.get(i).and_then(|&v| v).unwrap_or(0);
+        let volume = volumes.get(i).unwrap_or(0);
 
         // Convertit le timestamp Unix en DateTime<Utc>
         // CONCEPT RUST : Result et ? operator
@@ -285,7 +780,7 @@ fn parse_yahoo_response(
             .context("Timestamp invalide")?;
 
         // Crée et ajoute la chandelle OHLC
-        ohlc_data.add_candle(OHLC::new(
+        builder.push(OHLC::new(
             datetime,
             open,
             high,
@@ -295,6 +790,11 @@ fn parse_yahoo_response(
         ));
     }
 
+    let (mut ohlc_data, report) = builder.build();
+    if !report.is_clean() {
+        warn!(repaired = report.repaired, rejected = report.rejected, "Invalid candles in Yahoo response");
+    }
+
     // Log des statistiques de parsing
     if skipped_count > 0 {
         warn!(
@@ -314,12 +814,353 @@ fn parse_yahoo_response(
     // Vérifie qu'on a au moins quelques données
     if ohlc_data.is_empty() {
         error!("No valid OHLC data found");
-        anyhow::bail!("Aucune donnée OHLC valide trouvée pour {}", symbol);
+        return Err(ChartError::NoCandles { symbol: symbol.to_string() }.into());
     }
+    ohlc_data.currency = meta.currency;
 
     Ok((ohlc_data, long_name))
 }
 
+// ============================================================================
+// quoteSummary : calendrier des résultats (earnings)
+// ============================================================================
+// Endpoint : GET /v10/finance/quoteSummary/AAPL?modules=calendarEvents
+// Réponse  : { "quoteSummary": { "result": [ { "calendarEvents": { "earnings":
+//              { "earningsDate": [ { "raw": 1714680000, "fmt": "2024-05-02" } ] } } } ] } }
+//
+// Yahoo donne parfois une fourchette de deux dates : on garde la première
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuoteSummaryResponse {
+    quote_summary: QuoteSummary,
+}
+
+#[derive(Debug, Deserialize)]
+struct QuoteSummary {
+    result: Option<Vec<QuoteSummaryResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuoteSummaryResult {
+    calendar_events: Option<CalendarEvents>,
+    quote_type: Option<QuoteTypeModule>,
+    recommendation_trend: Option<RecommendationTrend>,
+    financial_data: Option<FinancialData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CalendarEvents {
+    earnings: Option<EarningsEvents>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EarningsEvents {
+    #[serde(default)]
+    earnings_date: Vec<RawValue>,
+}
+
+/// Valeur Yahoo au format { "raw": ..., "fmt": ... }
+#[derive(Debug, Deserialize)]
+struct RawValue {
+    raw: i64,
+}
+
+/// Récupère la date de la prochaine publication de résultats d'une action
+///
+/// # Retourne
+/// * `Ok(None)` - Yahoo ne connaît pas de date (ETF, crypto, date non annoncée)
+#[instrument]
+pub async fn fetch_earnings_date(symbol: &str) -> Result<Option<DateTime<chrono::Utc>>> {
+    if crate::demo::is_enabled() {
+        return Ok(None);
+    }
+
+    let url = format!(
+        "{}/v10/finance/quoteSummary/{}?modules=calendarEvents",
+        host(QUERY2_HOST),
+        symbol
+    );
+    debug!(url = %url, "Fetching earnings calendar");
+
+    let (status, body) = get_authenticated(&url).await?;
+    if !status.is_success() {
+        warn!(status = %status, "Yahoo quoteSummary returned error status");
+        anyhow::bail!("Yahoo Finance a retourné une erreur : HTTP {}", status);
+    }
+
+    let summary: QuoteSummaryResponse =
+        serde_json::from_str(&body).context("Échec du parsing JSON de la réponse quoteSummary")?;
+
+    Ok(parse_earnings_date(summary))
+}
+
+/// Extrait la première date de résultats de la réponse quoteSummary
+fn parse_earnings_date(response: QuoteSummaryResponse) -> Option<DateTime<chrono::Utc>> {
+    let timestamp = response
+        .quote_summary
+        .result?
+        .into_iter()
+        .next()?
+        .calendar_events?
+        .earnings?
+        .earnings_date
+        .first()?
+        .raw;
+
+    DateTime::from_timestamp(timestamp, 0)
+}
+
+// ============================================================================
+// quoteSummary : type d'actif (quoteType)
+// ============================================================================
+// Endpoint : GET /v10/finance/quoteSummary/VWCE.DE?modules=quoteType
+// Réponse  : { "quoteSummary": { "result": [ { "quoteType": { "symbol": "VWCE.DE",
+//              "quoteType": "ETF", "exchange": "GER" } } ] } }
+//
+// Le symbole seul ne distingue pas un ETF hors de KNOWN_ETFS (VWCE.DE)
+// d'une action : Yahoo, lui, connaît le type de chaque instrument
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuoteTypeModule {
+    quote_type: Option<String>,
+}
+
+/// Récupère le type d'actif d'un ticker (quoteType de Yahoo)
+///
+/// # Retourne
+/// * `Ok(None)` - Type inconnu de Yahoo ou sans équivalent (mode démo,
+///   instrument CSV) : l'appelant garde TickerType::from_symbol()
+#[instrument]
+pub async fn fetch_quote_type(symbol: &str) -> Result<Option<TickerType>> {
+    if crate::demo::is_enabled() || super::csv::instrument(symbol).is_some() {
+        return Ok(None);
+    }
+
+    let url = format!("{}/v10/finance/quoteSummary/{}?modules=quoteType", host(QUERY2_HOST), symbol);
+    debug!(url = %url, "Fetching quote type");
+
+    let (status, body) = get_authenticated(&url).await?;
+    if !status.is_success() {
+        warn!(status = %status, "Yahoo quoteSummary returned error status");
+        anyhow::bail!("Yahoo Finance a retourné une erreur : HTTP {}", status);
+    }
+
+    let summary: QuoteSummaryResponse =
+        serde_json::from_str(&body).context("Échec du parsing JSON de la réponse quoteSummary")?;
+
+    Ok(parse_quote_type(summary))
+}
+
+/// Extrait le type d'actif de la réponse quoteSummary
+fn parse_quote_type(response: QuoteSummaryResponse) -> Option<TickerType> {
+    let quote_type = response.quote_summary.result?.into_iter().next()?.quote_type?.quote_type?;
+    TickerType::from_quote_type(&quote_type)
+}
+
+// ============================================================================
+// quoteSummary : recommandations des analystes et objectif de cours
+// ============================================================================
+// Endpoint : GET /v10/finance/quoteSummary/AAPL?modules=recommendationTrend,financialData
+// Réponse  : { "quoteSummary": { "result": [ {
+//              "recommendationTrend": { "trend": [ { "period": "0m", "strongBuy": 12,
+//                "buy": 20, "hold": 8, "sell": 1, "strongSell": 0 }, { "period": "-1m", ... } ] },
+//              "financialData": { "targetMeanPrice": { "raw": 250.0, "fmt": "250.00" },
+//                "targetLowPrice": {...}, "targetHighPrice": {...},
+//                "numberOfAnalystOpinions": { "raw": 41 }, "recommendationKey": "buy" } } ] } }
+//
+// Les champs absents valent {} (et non null) : RawNumber accepte les deux
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct RecommendationTrend {
+    #[serde(default)]
+    trend: Vec<TrendPeriod>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrendPeriod {
+    period: String,
+    #[serde(default)]
+    strong_buy: u32,
+    #[serde(default)]
+    buy: u32,
+    #[serde(default)]
+    hold: u32,
+    #[serde(default)]
+    sell: u32,
+    #[serde(default)]
+    strong_sell: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FinancialData {
+    target_mean_price: Option<RawNumber>,
+    target_low_price: Option<RawNumber>,
+    target_high_price: Option<RawNumber>,
+    number_of_analyst_opinions: Option<RawNumber>,
+    recommendation_key: Option<String>,
+}
+
+/// Nombre Yahoo au format { "raw": 250.0, "fmt": "250.00" } ({} si inconnu)
+#[derive(Debug, Deserialize)]
+struct RawNumber {
+    raw: Option<f64>,
+}
+
+/// Récupère les recommandations des analystes et l'objectif de cours
+///
+/// # Retourne
+/// * `Ok(None)` - Action non suivie par les analystes (ETF, crypto, mode démo)
+#[instrument]
+pub async fn fetch_analyst_ratings(symbol: &str) -> Result<Option<AnalystRatings>> {
+    if crate::demo::is_enabled() || super::csv::instrument(symbol).is_some() {
+        return Ok(None);
+    }
+
+    let url = format!(
+        "{}/v10/finance/quoteSummary/{}?modules=recommendationTrend,financialData",
+        host(QUERY2_HOST),
+        symbol
+    );
+    debug!(url = %url, "Fetching analyst ratings");
+
+    let (status, body) = get_authenticated(&url).await?;
+    if !status.is_success() {
+        warn!(status = %status, "Yahoo quoteSummary returned error status");
+        anyhow::bail!("Yahoo Finance a retourné une erreur : HTTP {}", status);
+    }
+
+    let summary: QuoteSummaryResponse =
+        serde_json::from_str(&body).context("Échec du parsing JSON de la réponse quoteSummary")?;
+
+    Ok(parse_analyst_ratings(summary))
+}
+
+/// Extrait la répartition du mois en cours et l'objectif de cours
+fn parse_analyst_ratings(response: QuoteSummaryResponse) -> Option<AnalystRatings> {
+    let result = response.quote_summary.result?.into_iter().next()?;
+    let mut ratings = AnalystRatings::default();
+
+    if let Some(current) = result
+        .recommendation_trend
+        .and_then(|trend| trend.trend.into_iter().find(|period| period.period == "0m"))
+    {
+        ratings.strong_buy = current.strong_buy;
+        ratings.buy = current.buy;
+        ratings.hold = current.hold;
+        ratings.sell = current.sell;
+        ratings.strong_sell = current.strong_sell;
+    }
+
+    if let Some(financial) = result.financial_data {
+        let raw = |value: Option<RawNumber>| value.and_then(|value| value.raw).filter(|value| *value > 0.0);
+        ratings.target_mean = raw(financial.target_mean_price);
+        ratings.target_low = raw(financial.target_low_price);
+        ratings.target_high = raw(financial.target_high_price);
+        ratings.analysts = raw(financial.number_of_analyst_opinions).map(|count| count as u32);
+        ratings.recommendation = financial.recommendation_key.filter(|key| key != "none");
+    }
+
+    (!ratings.is_empty()).then_some(ratings)
+}
+
+// ============================================================================
+// Screeners prédéfinis (hausses, baisses, plus actifs)
+// ============================================================================
+// Endpoint : GET /v1/finance/screener/predefined/saved?scrIds=day_gainers&count=25
+// Réponse  : { "finance": { "result": [ { "quotes": [ { "symbol": "NVDA",
+//              "shortName": "NVIDIA", "regularMarketPrice": 905.1,
+//              "regularMarketChangePercent": 6.2, "regularMarketVolume": 5.2e7 } ] } ] } }
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct ScreenerResponse {
+    finance: ScreenerFinance,
+}
+
+#[derive(Debug, Deserialize)]
+struct ScreenerFinance {
+    result: Option<Vec<ScreenerResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ScreenerResult {
+    #[serde(default)]
+    quotes: Vec<ScreenerQuoteJson>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ScreenerQuoteJson {
+    symbol: String,
+    short_name: Option<String>,
+    long_name: Option<String>,
+    regular_market_price: Option<f64>,
+    regular_market_change_percent: Option<f64>,
+    regular_market_volume: Option<f64>,
+}
+
+/// Nombre de résultats demandés par screener
+pub const SCREENER_COUNT: usize = 25;
+
+/// Récupère les résultats d'un screener prédéfini de Yahoo Finance
+#[instrument]
+pub async fn fetch_screener(screener: Screener) -> Result<Vec<ScreenerQuote>> {
+    if crate::demo::is_enabled() {
+        return Ok(crate::demo::screener_quotes(screener));
+    }
+
+    let url = format!(
+        "{}/v1/finance/screener/predefined/saved?scrIds={}&count={}",
+        host(QUERY1_HOST),
+        screener.scr_id(),
+        SCREENER_COUNT
+    );
+    debug!(url = %url, "Fetching predefined screener");
+
+    let (status, body) = get_authenticated(&url).await?;
+    if !status.is_success() {
+        warn!(status = %status, "Yahoo screener returned error status");
+        anyhow::bail!("Yahoo Finance a retourné une erreur : HTTP {}", status);
+    }
+
+    let response: ScreenerResponse =
+        serde_json::from_str(&body).context("Échec du parsing JSON de la réponse screener")?;
+
+    Ok(parse_screener(response))
+}
+
+/// Convertit la réponse screener (les lignes sans prix sont ignorées)
+fn parse_screener(response: ScreenerResponse) -> Vec<ScreenerQuote> {
+    response
+        .finance
+        .result
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|result| result.quotes)
+        .filter_map(|quote| {
+            Some(ScreenerQuote {
+                name: quote
+                    .short_name
+                    .or(quote.long_name)
+                    .unwrap_or_else(|| quote.symbol.clone()),
+                price: quote.regular_market_price?,
+                change_percent: quote.regular_market_change_percent.unwrap_or(0.0),
+                volume: quote.regular_market_volume.map(|v| v as u64),
+                symbol: quote.symbol,
+            })
+        })
+        .collect()
+}
+
 // ============================================================================
 // Tests unitaires
 // ============================================================================
@@ -328,12 +1169,250 @@ fn parse_yahoo_response(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_session_cookie_keeps_name_value_pairs() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.append(
+            reqwest::header::SET_COOKIE,
+            "A3=d=AQAB&S=xyz; Expires=Thu, 1 May 2025 10:00:00 GMT; Domain=.yahoo.com; Path=/".parse().unwrap(),
+        );
+        headers.append(reqwest::header::SET_COOKIE, "A1S=abc; Path=/".parse().unwrap());
+
+        assert_eq!(session_cookie(&headers).as_deref(), Some("A3=d=AQAB&S=xyz; A1S=abc"));
+        assert_eq!(session_cookie(&reqwest::header::HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_with_crumb_encodes_value() {
+        let url = with_crumb("https://query2.finance.yahoo.com/v10/finance/quoteSummary/AAPL?modules=price", "a/b.c");
+        assert_eq!(
+            url.unwrap(),
+            "https://query2.finance.yahoo.com/v10/finance/quoteSummary/AAPL?modules=price&crumb=a%2Fb.c"
+        );
+    }
+
+    /// Parse une réponse chart (AAPL 1d, 1 semaine)
+    fn parse_chart(json: &str) -> Result<(OHLCData, Option<String>)> {
+        let response: YahooResponse = serde_json::from_str(json).unwrap();
+        parse_yahoo_response(response, "AAPL", Interval::D1, Timeframe::OneWeek)
+    }
+
+    fn chart_error(json: &str) -> ChartError {
+        parse_chart(json).unwrap_err().downcast::<ChartError>().unwrap()
+    }
+
+    #[test]
+    fn test_parse_chart_with_null_meta_fields() {
+        let json = r#"{ "chart": { "result": [{
+            "meta": { "symbol": null, "longName": null, "regularMarketPrice": null },
+            "timestamp": [1714521600, 1714608000, 1714694400],
+            "indicators": { "quote": [{
+                "open": [170.0, null, 172.0], "high": [172.0, 173.0, 174.0],
+                "low": [169.0, 170.0, 171.0], "close": [171.0, 172.0, 173.0], "volume": [1000, 1100, null]
+            }] }
+        }], "error": null } }"#;
+        let (data, long_name) = parse_chart(json).unwrap();
+
+        assert_eq!(long_name, None);
+        assert_eq!(data.len(), 2); // bougie sans ouverture écartée
+        assert_eq!(data.candles[1].volume, 0);
+    }
+
+    #[test]
+    fn test_column_keeps_nulls_without_option() {
+        let prices: Column<f64> = serde_json::from_str("[170.5, null, 0.0]").unwrap();
+        assert_eq!(prices.0.len(), 3);
+        assert_eq!((prices.get(0), prices.get(1), prices.get(2), prices.get(3)), (Some(170.5), None, Some(0.0), None));
+        assert!(prices.any_present());
+
+        let volumes: Column<u64> = serde_json::from_str("[null, 1200]").unwrap();
+        assert_eq!((volumes.get(0), volumes.get(1)), (Some(0), Some(1200)));
+
+        let empty: Column<f64> = serde_json::from_str("[null, null]").unwrap();
+        assert!(!empty.any_present());
+        assert!(serde_json::from_str::<Column<f64>>(r#"["170.5"]"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_chart_error_and_missing_blocks() {
+        let error = chart_error(r#"{ "chart": { "result": null, "error": {
+            "code": "Not Found", "description": "No data found, symbol may be delisted" } } }"#);
+        assert_eq!(
+            error,
+            ChartError::Api { code: "Not Found".to_string(), description: "No data found, symbol may be delisted".to_string() }
+        );
+        assert!(error.to_string().contains("delisted"));
+
+        assert_eq!(chart_error(r#"{ "chart": { "result": [], "error": null } }"#), ChartError::NoResult);
+        assert_eq!(
+            chart_error(r#"{ "chart": { "result": [{ "meta": null, "timestamp": [1714521600], "indicators": {} }] } }"#),
+            ChartError::NoQuotes
+        );
+        assert_eq!(
+            chart_error(r#"{ "chart": { "result": [{ "timestamp": [1714521600], "indicators": { "quote": [{
+                "open": [null], "high": [null], "low": [null], "close": [171.0] }] } }] } }"#),
+            ChartError::NoCandles { symbol: "AAPL".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_parse_chart_adjclose_only() {
+        let json = r#"{ "chart": { "result": [{
+            "meta": { "symbol": "AAPL" },
+            "timestamp": [1714521600, 1714608000],
+            "indicators": { "quote": [{}], "adjclose": [{ "adjclose": [101.5, 102.25] }] }
+        }] } }"#;
+        let (data, _) = parse_chart(json).unwrap();
+
+        assert_eq!(data.len(), 2);
+        let last = data.last().unwrap();
+        assert_eq!((last.open, last.high, last.low, last.close), (102.25, 102.25, 102.25, 102.25));
+    }
+
+    #[test]
+    fn test_parse_chart_currency() {
+        let json = r#"{ "chart": { "result": [{
+            "meta": { "symbol": "AIR.PA", "currency": "EUR" },
+            "timestamp": [1714521600],
+            "indicators": { "quote": [{
+                "open": [150.0], "high": [154.0], "low": [149.0], "close": [153.42], "volume": [1000]
+            }] }
+        }] } }"#;
+        let (data, _) = parse_chart(json).unwrap();
+        assert_eq!(data.currency.as_deref(), Some("EUR"));
+
+        let (data, _) = parse_chart(&json.replace(r#", "currency": "EUR""#, "")).unwrap();
+        assert_eq!(data.currency, None);
+    }
+
+    #[test]
+    fn test_parse_screener() {
+        let json = r#"{
+            "finance": {
+                "result": [{
+                    "quotes": [
+                        { "symbol": "NVDA", "shortName": "NVIDIA Corporation",
+                          "regularMarketPrice": 905.1, "regularMarketChangePercent": 6.2,
+                          "regularMarketVolume": 52000000 },
+                        { "symbol": "XYZ" }
+                    ]
+                }],
+                "error": null
+            }
+        }"#;
+        let response: ScreenerResponse = serde_json::from_str(json).unwrap();
+        let quotes = parse_screener(response);
+
+        assert_eq!(quotes.len(), 1); // XYZ n'a pas de prix
+        assert_eq!(quotes[0].symbol, "NVDA");
+        assert_eq!(quotes[0].name, "NVIDIA Corporation");
+        assert_eq!(quotes[0].change_percent, 6.2);
+        assert_eq!(quotes[0].volume, Some(52_000_000));
+    }
+
+    #[test]
+    fn test_parse_earnings_date() {
+        let json = r#"{
+            "quoteSummary": {
+                "result": [{
+                    "calendarEvents": {
+                        "earnings": {
+                            "earningsDate": [
+                                { "raw": 1714680000, "fmt": "2024-05-02" },
+                                { "raw": 1715112000, "fmt": "2024-05-07" }
+                            ]
+                        }
+                    }
+                }],
+                "error": null
+            }
+        }"#;
+        let response: QuoteSummaryResponse = serde_json::from_str(json).unwrap();
+        let date = parse_earnings_date(response).unwrap();
+        assert_eq!(date.format("%Y-%m-%d").to_string(), "2024-05-02");
+
+        // Pas de date annoncée
+        let json = r#"{ "quoteSummary": { "result": [{ "calendarEvents": { "earnings": {} } }] } }"#;
+        let response: QuoteSummaryResponse = serde_json::from_str(json).unwrap();
+        assert!(parse_earnings_date(response).is_none());
+    }
+
+    #[test]
+    fn test_parse_quote_type() {
+        let json = r#"{
+            "quoteSummary": {
+                "result": [{ "quoteType": { "symbol": "VWCE.DE", "quoteType": "ETF", "exchange": "GER" } }],
+                "error": null
+            }
+        }"#;
+        let response: QuoteSummaryResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(parse_quote_type(response), Some(TickerType::ETF));
+
+        // Type sans équivalent (contrat à terme) ou absent
+        let json = r#"{ "quoteSummary": { "result": [{ "quoteType": { "quoteType": "FUTURE" } }] } }"#;
+        let response: QuoteSummaryResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(parse_quote_type(response), None);
+        let json = r#"{ "quoteSummary": { "result": null } }"#;
+        let response: QuoteSummaryResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(parse_quote_type(response), None);
+    }
+
+    #[test]
+    fn test_parse_analyst_ratings() {
+        let json = r#"{
+            "quoteSummary": {
+                "result": [{
+                    "recommendationTrend": {
+                        "trend": [
+                            { "period": "0m", "strongBuy": 12, "buy": 20, "hold": 8, "sell": 1, "strongSell": 0 },
+                            { "period": "-1m", "strongBuy": 10, "buy": 21, "hold": 9, "sell": 1, "strongSell": 0 }
+                        ]
+                    },
+                    "financialData": {
+                        "targetMeanPrice": { "raw": 250.0, "fmt": "250.00" },
+                        "targetLowPrice": { "raw": 180.0, "fmt": "180.00" },
+                        "targetHighPrice": { "raw": 300.0, "fmt": "300.00" },
+                        "numberOfAnalystOpinions": { "raw": 38, "fmt": "38" },
+                        "recommendationKey": "buy"
+                    }
+                }],
+                "error": null
+            }
+        }"#;
+        let response: QuoteSummaryResponse = serde_json::from_str(json).unwrap();
+        let ratings = parse_analyst_ratings(response).unwrap();
+        assert_eq!((ratings.strong_buy, ratings.buy, ratings.hold, ratings.sell), (12, 20, 8, 1));
+        assert_eq!(ratings.target_mean, Some(250.0));
+        assert_eq!((ratings.target_low, ratings.target_high), (Some(180.0), Some(300.0)));
+        assert_eq!(ratings.analysts, Some(38));
+        assert_eq!(ratings.recommendation.as_deref(), Some("buy"));
+
+        // ETF : modules vides ({} au lieu de null)
+        let json = r#"{ "quoteSummary": { "result": [{
+            "recommendationTrend": { "trend": [] },
+            "financialData": { "targetMeanPrice": {}, "recommendationKey": "none" }
+        }] } }"#;
+        let response: QuoteSummaryResponse = serde_json::from_str(json).unwrap();
+        assert!(parse_analyst_ratings(response).is_none());
+    }
+
     #[test]
     fn test_build_yahoo_url() {
-        let url = build_yahoo_url("AAPL", Interval::D1, Timeframe::OneWeek);
+        let url = build_yahoo_url("AAPL", Interval::D1, Timeframe::OneWeek, None);
         assert!(url.contains("AAPL"));
         assert!(url.contains("interval=1d"));
         assert!(url.contains("yahoo.com"));
+
+        let url = build_yahoo_url("AAPL", Interval::H4, Timeframe::OneYear, None);
+        assert!(url.contains("interval=1h"));
+
+        // Delta : part de la dernière bougie connue, bornée au timeframe
+        let since = Utc::now() - chrono::Duration::hours(3);
+        let url = build_yahoo_url("AAPL", Interval::H1, Timeframe::OneYear, Some(since));
+        assert!(url.contains(&format!("period1={}", since.timestamp())));
+
+        let url = build_yahoo_url("AAPL", Interval::H1, Timeframe::OneWeek, Some(since - chrono::Duration::days(30)));
+        assert!(!url.contains(&format!("period1={}", (since - chrono::Duration::days(30)).timestamp())));
     }
 
     // Test async nécessite tokio test runtime
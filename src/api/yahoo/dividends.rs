@@ -0,0 +1,151 @@
+// ============================================================================
+// quoteSummary : prochain dividende
+// ============================================================================
+// Endpoint : GET /v10/finance/quoteSummary/KO?modules=calendarEvents,defaultKeyStatistics
+// Réponse  : { "quoteSummary": { "result": [ {
+//              "calendarEvents": {
+//                "exDividendDate": { "raw": 1718236800, "fmt": "2024-06-13" },
+//                "dividendDate": { "raw": 1719792000, "fmt": "2024-07-01" } },
+//              "defaultKeyStatistics": {
+//                "lastDividendValue": { "raw": 0.485, "fmt": "0.485" } } } ] } }
+//
+// - Dates : dernier détachement annoncé et son paiement (passés une fois
+//   le dividende versé, jusqu'à l'annonce du suivant)
+// - Montant : dernier dividende par action, reconduit pour le prochain
+// ============================================================================
+
+use anyhow::{Context, Result};
+use chrono::DateTime;
+use serde::Deserialize;
+use tracing::{debug, instrument, warn};
+
+use super::{get_authenticated, host, QUERY2_HOST};
+use crate::models::dividends::DividendInfo;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DividendsResponse {
+    quote_summary: DividendsSummary,
+}
+
+#[derive(Debug, Deserialize)]
+struct DividendsSummary {
+    result: Option<Vec<DividendsResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DividendsResult {
+    calendar_events: Option<DividendEvents>,
+    default_key_statistics: Option<KeyStatistics>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DividendEvents {
+    ex_dividend_date: Option<Raw>,
+    dividend_date: Option<Raw>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyStatistics {
+    last_dividend_value: Option<Raw>,
+}
+
+/// Nombre Yahoo au format { "raw": ..., "fmt": ... } ({} si inconnu)
+#[derive(Debug, Deserialize)]
+struct Raw {
+    raw: Option<f64>,
+}
+
+/// Valeur brute d'un champ optionnel
+fn raw(value: &Option<Raw>) -> Option<f64> {
+    value.as_ref()?.raw
+}
+
+/// Récupère les dates et le montant du dernier dividende annoncé
+///
+/// # Retourne
+/// * `Ok(DividendInfo::default())` - Aucun dividende (crypto, mode démo, ...)
+#[instrument]
+pub async fn fetch_dividends(symbol: &str) -> Result<DividendInfo> {
+    if crate::demo::is_enabled() || crate::api::csv::instrument(symbol).is_some() {
+        return Ok(DividendInfo::default());
+    }
+
+    let url = format!(
+        "{}/v10/finance/quoteSummary/{}?modules=calendarEvents,defaultKeyStatistics",
+        host(QUERY2_HOST),
+        symbol
+    );
+    debug!(url = %url, "Fetching dividend calendar");
+
+    let (status, body) = get_authenticated(&url).await?;
+    if !status.is_success() {
+        warn!(status = %status, "Yahoo quoteSummary returned error status");
+        anyhow::bail!("Yahoo Finance a retourné une erreur : HTTP {}", status);
+    }
+
+    let response: DividendsResponse =
+        serde_json::from_str(&body).context("Échec du parsing JSON de la réponse quoteSummary")?;
+    Ok(parse_dividends(response))
+}
+
+/// Extrait les deux dates et le montant (valeurs nulles ignorées)
+fn parse_dividends(response: DividendsResponse) -> DividendInfo {
+    let Some(result) = response.quote_summary.result.and_then(|results| results.into_iter().next()) else {
+        return DividendInfo::default();
+    };
+
+    let date = |value: &Option<Raw>| {
+        let timestamp = raw(value)? as i64;
+        Some(DateTime::from_timestamp(timestamp, 0)?.date_naive())
+    };
+    let events = result.calendar_events.as_ref();
+    DividendInfo {
+        ex_date: events.and_then(|events| date(&events.ex_dividend_date)),
+        payment_date: events.and_then(|events| date(&events.dividend_date)),
+        amount: result
+            .default_key_statistics
+            .as_ref()
+            .and_then(|statistics| raw(&statistics.last_dividend_value))
+            .filter(|amount| *amount > 0.0),
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dividends() {
+        let json = r#"{
+            "quoteSummary": {
+                "result": [{
+                    "calendarEvents": {
+                        "earnings": { "earningsDate": [] },
+                        "exDividendDate": { "raw": 1718236800, "fmt": "2024-06-13" },
+                        "dividendDate": { "raw": 1719792000, "fmt": "2024-07-01" }
+                    },
+                    "defaultKeyStatistics": { "lastDividendValue": { "raw": 0.485, "fmt": "0.485" } }
+                }],
+                "error": null
+            }
+        }"#;
+        let info = parse_dividends(serde_json::from_str(json).unwrap());
+        assert_eq!(info.ex_date.map(|date| date.to_string()).as_deref(), Some("2024-06-13"));
+        assert_eq!(info.payment_date.map(|date| date.to_string()).as_deref(), Some("2024-07-01"));
+        assert_eq!(info.amount, Some(0.485));
+
+        let none = parse_dividends(
+            serde_json::from_str(r#"{ "quoteSummary": { "result": [{ "calendarEvents": {}, "defaultKeyStatistics": { "lastDividendValue": {} } }] } }"#)
+                .unwrap(),
+        );
+        assert_eq!(none, DividendInfo::default());
+    }
+}
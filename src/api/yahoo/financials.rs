@@ -0,0 +1,223 @@
+// ============================================================================
+// fundamentals-timeseries : historique des comptes
+// ============================================================================
+// Endpoint : GET /ws/fundamentals-timeseries/v1/finance/timeseries/AAPL
+//            ?symbol=AAPL&type=annualTotalRevenue,quarterlyNetIncome,...
+//            &period1=...&period2=...
+// Réponse  : { "timeseries": { "result": [ {
+//              "meta": { "symbol": ["AAPL"], "type": ["annualTotalRevenue"] },
+//              "timestamp": [1632960000, ...],
+//              "annualTotalRevenue": [ {
+//                "asOfDate": "2021-09-30", "periodType": "12M", "currencyCode": "USD",
+//                "reportedValue": { "raw": 365817000000, "fmt": "365.82B" } }, null, ... ] },
+//              ... ] } }
+//
+// CONCEPT : Une série par poste et par périodicité
+// - Le nom du champ des valeurs est le type de la série (meta.type[0])
+// - Les périodes non publiées valent null
+// ============================================================================
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use tracing::{debug, instrument, warn};
+
+use super::{get_authenticated, host, QUERY2_HOST};
+use crate::models::financials::{Financials, StatementPeriod, StatementValue, Statements};
+use crate::models::Currency;
+
+/// Postes demandés pour chaque périodicité
+const SERIES: [&str; 3] = ["TotalRevenue", "NetIncome", "FreeCashFlow"];
+
+/// Profondeur de l'historique demandé (Yahoo en publie 4 ans au plus)
+const HISTORY_YEARS: i64 = 6;
+
+#[derive(Debug, Deserialize)]
+struct TimeseriesResponse {
+    timeseries: Timeseries,
+}
+
+#[derive(Debug, Deserialize)]
+struct Timeseries {
+    result: Option<Vec<SeriesJson>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SeriesJson {
+    meta: SeriesMeta,
+
+    /// Valeurs, sous le nom de la série ("annualTotalRevenue": [...])
+    #[serde(flatten)]
+    fields: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SeriesMeta {
+    #[serde(rename = "type", default)]
+    kind: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PointJson {
+    as_of_date: NaiveDate,
+    currency_code: Option<String>,
+    reported_value: Option<ReportedValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReportedValue {
+    raw: Option<f64>,
+}
+
+/// URL des six séries (trois postes, annuels et trimestriels)
+fn timeseries_url(symbol: &str, now: i64) -> String {
+    let types: Vec<String> = [StatementPeriod::Annual, StatementPeriod::Quarterly]
+        .iter()
+        .flat_map(|period| SERIES.iter().map(move |series| format!("{}{}", period.yahoo_prefix(), series)))
+        .collect();
+    format!(
+        "{}/ws/fundamentals-timeseries/v1/finance/timeseries/{}?symbol={}&type={}&period1={}&period2={}",
+        host(QUERY2_HOST),
+        symbol,
+        symbol,
+        types.join(","),
+        now - HISTORY_YEARS * 365 * 24 * 3600,
+        now
+    )
+}
+
+/// Récupère le chiffre d'affaires, le résultat net et le free cash flow publiés
+///
+/// # Retourne
+/// * `Ok(Financials::default())` - Aucun compte publié (ETF, crypto, mode démo)
+#[instrument]
+pub async fn fetch_financials(symbol: &str) -> Result<Financials> {
+    if crate::demo::is_enabled() || crate::api::csv::instrument(symbol).is_some() {
+        return Ok(Financials::default());
+    }
+
+    let url = timeseries_url(symbol, Utc::now().timestamp());
+    debug!(url = %url, "Fetching financial statements");
+
+    let (status, body) = get_authenticated(&url).await?;
+    if !status.is_success() {
+        warn!(status = %status, "Yahoo timeseries returned error status");
+        anyhow::bail!("Yahoo Finance a retourné une erreur : HTTP {}", status);
+    }
+
+    let response: TimeseriesResponse =
+        serde_json::from_str(&body).context("Échec du parsing JSON de la réponse timeseries")?;
+    Ok(parse_financials(response))
+}
+
+/// Range chaque série dans son poste, périodes non publiées ignorées
+fn parse_financials(response: TimeseriesResponse) -> Financials {
+    let mut financials = Financials::default();
+    let mut currency = None;
+
+    for mut series in response.timeseries.result.unwrap_or_default() {
+        let Some(kind) = series.meta.kind.first().cloned() else {
+            continue;
+        };
+        let Some(points) = series.fields.remove(&kind) else {
+            continue;
+        };
+        let Ok(points) = serde_json::from_value::<Vec<Option<PointJson>>>(points) else {
+            warn!(series = %kind, "Unexpected timeseries values, ignored");
+            continue;
+        };
+
+        let (statements, name) = if let Some(name) = kind.strip_prefix(StatementPeriod::Annual.yahoo_prefix()) {
+            (&mut financials.annual, name)
+        } else if let Some(name) = kind.strip_prefix(StatementPeriod::Quarterly.yahoo_prefix()) {
+            (&mut financials.quarterly, name)
+        } else {
+            continue;
+        };
+        let Some(values) = statement_values(statements, name) else {
+            continue;
+        };
+
+        for point in points.into_iter().flatten() {
+            if let Some(value) = point.reported_value.and_then(|value| value.raw) {
+                currency = currency.or(point.currency_code);
+                values.push(StatementValue { date: point.as_of_date, value });
+            }
+        }
+    }
+
+    financials.annual.sort();
+    financials.quarterly.sort();
+    if let Some(code) = currency {
+        financials.currency = Currency::new(&code);
+    }
+    financials
+}
+
+/// Poste correspondant au nom de la série ("TotalRevenue" → revenue)
+fn statement_values<'a>(statements: &'a mut Statements, name: &str) -> Option<&'a mut Vec<StatementValue>> {
+    match name {
+        "TotalRevenue" => Some(&mut statements.revenue),
+        "NetIncome" => Some(&mut statements.net_income),
+        "FreeCashFlow" => Some(&mut statements.free_cash_flow),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeseries_url_lists_six_series() {
+        let url = timeseries_url("AAPL", 1_700_000_000);
+        assert!(url.contains("/timeseries/AAPL?symbol=AAPL&type=annualTotalRevenue,annualNetIncome,annualFreeCashFlow,quarterlyTotalRevenue,"));
+        assert!(url.ends_with("&period2=1700000000"));
+    }
+
+    #[test]
+    fn test_parse_financials() {
+        let json = r#"{
+            "timeseries": {
+                "result": [
+                    { "meta": { "symbol": ["SAP"], "type": ["annualTotalRevenue"] },
+                      "timestamp": [1703980800, 1672444800],
+                      "annualTotalRevenue": [
+                        { "asOfDate": "2023-12-31", "periodType": "12M", "currencyCode": "EUR",
+                          "reportedValue": { "raw": 31207000000, "fmt": "31.21B" } },
+                        { "asOfDate": "2022-12-31", "periodType": "12M", "currencyCode": "EUR",
+                          "reportedValue": { "raw": 29520000000, "fmt": "29.52B" } }
+                      ] },
+                    { "meta": { "symbol": ["SAP"], "type": ["quarterlyNetIncome"] },
+                      "timestamp": [1711843200],
+                      "quarterlyNetIncome": [
+                        null,
+                        { "asOfDate": "2024-03-31", "periodType": "3M", "currencyCode": "EUR",
+                          "reportedValue": { "raw": -824000000, "fmt": "-824M" } }
+                      ] },
+                    { "meta": { "symbol": ["SAP"], "type": ["annualFreeCashFlow"] } }
+                ],
+                "error": null
+            }
+        }"#;
+        let financials = parse_financials(serde_json::from_str(json).unwrap());
+
+        assert_eq!(financials.currency.code(), "EUR");
+        assert_eq!(financials.annual.revenue.len(), 2);
+        assert_eq!(financials.annual.revenue[0].date.to_string(), "2022-12-31");
+        assert_eq!(financials.annual.revenue[1].value, 31_207_000_000.0);
+        assert!(financials.annual.free_cash_flow.is_empty());
+        assert_eq!(financials.quarterly.net_income.len(), 1);
+        assert_eq!(financials.quarterly.net_income[0].value, -824_000_000.0);
+
+        let empty = parse_financials(serde_json::from_str(r#"{ "timeseries": { "result": [] } }"#).unwrap());
+        assert!(empty.is_empty());
+    }
+}
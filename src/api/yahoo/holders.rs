@@ -0,0 +1,220 @@
+// ============================================================================
+// quoteSummary : initiés et détenteurs institutionnels
+// ============================================================================
+// Endpoint : GET /v10/finance/quoteSummary/AAPL?modules=insiderTransactions,institutionOwnership
+// Réponse  : { "quoteSummary": { "result": [ {
+//              "insiderTransactions": { "transactions": [ {
+//                "filerName": "COOK TIMOTHY D", "filerRelation": "Chief Executive Officer",
+//                "transactionText": "Sale at price 223.33 - 231.31 per share.",
+//                "startDate": { "raw": 1712016000, "fmt": "2024-04-02" },
+//                "shares": { "raw": 196410 }, "value": { "raw": 45834560 } } ] },
+//              "institutionOwnership": { "ownershipList": [ {
+//                "organization": "Vanguard Group Inc",
+//                "reportDate": { "raw": 1711843200, "fmt": "2024-03-31" },
+//                "pctHeld": { "raw": 0.0877, "fmt": "8.77%" },
+//                "position": { "raw": 1353017330 } } ] } } ] } }
+//
+// Même session (cookie + crumb) que les autres modules quoteSummary
+// ============================================================================
+
+use anyhow::{Context, Result};
+use chrono::DateTime;
+use serde::Deserialize;
+use tracing::{debug, instrument, warn};
+
+use super::{get_authenticated, host, QUERY2_HOST};
+use crate::models::holders::{Holders, InsiderKind, InsiderTransaction, InstitutionalHolder};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HoldersResponse {
+    quote_summary: HoldersSummary,
+}
+
+#[derive(Debug, Deserialize)]
+struct HoldersSummary {
+    result: Option<Vec<HoldersResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HoldersResult {
+    insider_transactions: Option<InsiderTransactions>,
+    institution_ownership: Option<InstitutionOwnership>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InsiderTransactions {
+    #[serde(default)]
+    transactions: Vec<TransactionJson>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionJson {
+    #[serde(default)]
+    filer_name: String,
+    #[serde(default)]
+    filer_relation: String,
+    #[serde(default)]
+    transaction_text: String,
+    start_date: Option<Raw>,
+    shares: Option<Raw>,
+    value: Option<Raw>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InstitutionOwnership {
+    #[serde(default)]
+    ownership_list: Vec<OwnershipJson>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OwnershipJson {
+    #[serde(default)]
+    organization: String,
+    report_date: Option<Raw>,
+    pct_held: Option<Raw>,
+    position: Option<Raw>,
+}
+
+/// Nombre Yahoo au format { "raw": ..., "fmt": ... } ({} si inconnu)
+#[derive(Debug, Deserialize)]
+struct Raw {
+    raw: Option<f64>,
+}
+
+/// Valeur brute d'un champ optionnel
+fn raw(value: &Option<Raw>) -> Option<f64> {
+    value.as_ref()?.raw
+}
+
+/// Récupère les transactions des initiés et les principaux institutionnels
+///
+/// # Retourne
+/// * `Ok(Holders::default())` - Aucune donnée (ETF, crypto, mode démo)
+#[instrument]
+pub async fn fetch_holders(symbol: &str) -> Result<Holders> {
+    if crate::demo::is_enabled() || crate::api::csv::instrument(symbol).is_some() {
+        return Ok(Holders::default());
+    }
+
+    let url = format!(
+        "{}/v10/finance/quoteSummary/{}?modules=insiderTransactions,institutionOwnership",
+        host(QUERY2_HOST),
+        symbol
+    );
+    debug!(url = %url, "Fetching holders");
+
+    let (status, body) = get_authenticated(&url).await?;
+    if !status.is_success() {
+        warn!(status = %status, "Yahoo quoteSummary returned error status");
+        anyhow::bail!("Yahoo Finance a retourné une erreur : HTTP {}", status);
+    }
+
+    let response: HoldersResponse =
+        serde_json::from_str(&body).context("Échec du parsing JSON de la réponse quoteSummary")?;
+    Ok(parse_holders(response))
+}
+
+/// Convertit la réponse, lignes incomplètes (sans date ni nom) ignorées
+fn parse_holders(response: HoldersResponse) -> Holders {
+    let Some(result) = response.quote_summary.result.and_then(|results| results.into_iter().next()) else {
+        return Holders::default();
+    };
+
+    let date = |value: &Option<Raw>| {
+        let timestamp = raw(value)? as i64;
+        Some(DateTime::from_timestamp(timestamp, 0)?.date_naive())
+    };
+
+    let insider_transactions = result
+        .insider_transactions
+        .map(|module| module.transactions)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|transaction| {
+            Some(InsiderTransaction {
+                date: date(&transaction.start_date)?,
+                kind: InsiderKind::from_text(&transaction.transaction_text),
+                shares: raw(&transaction.shares).unwrap_or(0.0),
+                value: raw(&transaction.value).filter(|value| *value > 0.0),
+                name: transaction.filer_name,
+                relation: transaction.filer_relation,
+            })
+        })
+        .collect();
+
+    let institutions = result
+        .institution_ownership
+        .map(|module| module.ownership_list)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|holder| !holder.organization.is_empty())
+        .map(|holder| InstitutionalHolder {
+            percent_held: raw(&holder.pct_held).unwrap_or(0.0) * 100.0,
+            shares: raw(&holder.position).unwrap_or(0.0),
+            report_date: date(&holder.report_date),
+            organization: holder.organization,
+        })
+        .collect();
+
+    let mut holders = Holders { insider_transactions, institutions };
+    holders.sort();
+    holders
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_holders() {
+        let json = r#"{
+            "quoteSummary": {
+                "result": [{
+                    "insiderTransactions": { "transactions": [
+                        { "filerName": "ADAMS KATHERINE L", "filerRelation": "General Counsel",
+                          "transactionText": "Purchase at price 170.00 per share.",
+                          "startDate": { "raw": 1711929600, "fmt": "2024-04-01" },
+                          "shares": { "raw": 1000 }, "value": { "raw": 170000 } },
+                        { "filerName": "COOK TIMOTHY D", "filerRelation": "Chief Executive Officer",
+                          "transactionText": "Sale at price 223.33 - 231.31 per share.",
+                          "startDate": { "raw": 1712016000, "fmt": "2024-04-02" },
+                          "shares": { "raw": 196410 }, "value": { "raw": 45834560 } },
+                        { "filerName": "SANS DATE", "transactionText": "", "startDate": {} }
+                    ] },
+                    "institutionOwnership": { "ownershipList": [
+                        { "organization": "Blackrock Inc.", "pctHeld": { "raw": 0.0652 }, "position": { "raw": 1000 },
+                          "reportDate": { "raw": 1711843200, "fmt": "2024-03-31" } },
+                        { "organization": "Vanguard Group Inc", "pctHeld": { "raw": 0.0877 }, "position": { "raw": 2000 } }
+                    ] }
+                }],
+                "error": null
+            }
+        }"#;
+        let holders = parse_holders(serde_json::from_str(json).unwrap());
+
+        assert_eq!(holders.insider_transactions.len(), 2);
+        let latest = &holders.insider_transactions[0];
+        assert_eq!(latest.name, "COOK TIMOTHY D");
+        assert_eq!(latest.kind, InsiderKind::Sale);
+        assert_eq!(latest.date.to_string(), "2024-04-02");
+        assert_eq!(latest.value, Some(45_834_560.0));
+        assert_eq!(holders.insider_transactions[1].kind, InsiderKind::Buy);
+
+        assert_eq!(holders.institutions[0].organization, "Vanguard Group Inc");
+        assert!((holders.institutions[0].percent_held - 8.77).abs() < 1e-9);
+        assert_eq!(holders.institutions[0].report_date, None);
+        assert_eq!(holders.institutions[1].report_date.map(|date| date.to_string()).as_deref(), Some("2024-03-31"));
+
+        let empty = parse_holders(serde_json::from_str(r#"{ "quoteSummary": { "result": [{}] } }"#).unwrap());
+        assert!(empty.is_empty());
+    }
+}
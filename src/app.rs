@@ -14,7 +14,106 @@
 // - Garantit la cohérence de l'état
 // ============================================================================
 
-use crate::models::{Interval, WatchlistItem};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local, Utc};
+
+use crate::alerts::condition::Condition;
+use crate::alerts::{
+    earnings_alerts, evaluate_rules, AlertBook, AlertRule, AlertTrigger, Notification, Recurrence, RuleState,
+};
+use crate::api::binance::binance_symbol;
+use crate::api::health::ProviderStatus;
+use crate::api::metrics::TickerMetrics;
+use crate::backtest::{self, BacktestResult, PRESET_STRATEGIES};
+use crate::config::Config;
+use crate::models::annotations::{Annotations, TrendLine};
+use crate::models::beta::{beta_stats, BetaStats};
+use crate::models::cost_basis::{position_pnl, PositionPnl};
+use crate::models::crypto_stats::{self, CryptoStats};
+use crate::models::dividends::{upcoming_dividends, UpcomingDividend};
+use crate::models::economic_calendar::{self, EconomicEvent};
+use crate::models::fear_greed::FearGreed;
+use crate::models::financials::StatementPeriod;
+use crate::models::journal::{CashMovement, Journal, Transaction};
+use crate::models::measure::{self, Measurement};
+use crate::models::order_book::OrderBook;
+use crate::models::portfolio::Portfolio;
+use crate::models::returns::{self, ReturnPeriod};
+use crate::models::schedule;
+use crate::models::screener::{Screener, ScreenerQuote};
+use crate::models::session_stats::{session_stats, SessionStats};
+use crate::models::notes::Notes;
+use crate::models::tags::Tags;
+use crate::models::ticker::{MAJOR_FOREX_PAIRS, POPULAR_SYMBOLS};
+use crate::models::{Interval, OHLCData, TickerType, WatchlistItem};
+
+/// Durée d'affichage d'une notification (toast)
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Fréquence de rafraîchissement du carnet d'ordres
+const ORDER_BOOK_REFRESH: Duration = Duration::from_secs(2);
+
+/// Fréquence de rafraîchissement du bandeau des indices
+const MARKET_OVERVIEW_REFRESH: Duration = Duration::from_secs(300);
+
+/// Délai avant une nouvelle tentative de chargement de l'indice Fear & Greed
+/// (la valeur du jour n'est pas encore publiée, ou la requête a échoué)
+const FEAR_GREED_RETRY: Duration = Duration::from_secs(3600);
+
+/// Fréquence de la sonde de connectivité (en ligne)
+const CONNECTIVITY_PROBE: Duration = Duration::from_secs(30);
+
+/// Fréquence de la sonde hors ligne (retour du réseau détecté plus vite)
+const OFFLINE_PROBE: Duration = Duration::from_secs(10);
+
+/// Rafraîchissement automatique des lignes masquées par le filtre de tags :
+/// période multipliée par ce facteur
+const AUTO_REFRESH_HIDDEN_FACTOR: i32 = 4;
+
+/// Nombre maximal de tickers comparés (une couleur chacun)
+pub const MAX_COMPARED: usize = 8;
+
+/// Notification temporaire affichée à l'utilisateur
+///
+/// CONCEPT : Toast (message éphémère)
+/// - Créée par show_toast() / show_error_toast()
+/// - Effacée automatiquement par tick() après TOAST_DURATION
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// Texte du message
+    pub message: String,
+
+    /// true pour un message d'erreur (affiché en rouge)
+    pub is_error: bool,
+
+    /// Instant de création (pour l'expiration)
+    pub created_at: Instant,
+}
+
+/// Curseur de l'outil de mesure (index dans les chandeliers du graphique)
+///
+/// CONCEPT : Deux temps, comme la règle de TradingView
+/// - Le curseur se déplace de chandelier en chandelier (← / →)
+/// - Enter pose l'ancre ; la mesure va de l'ancre au curseur
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeasureCursor {
+    /// Chandelier sous le curseur
+    pub cursor: usize,
+
+    /// Chandelier d'ancrage (None tant qu'Enter n'a pas été pressé)
+    pub anchor: Option<usize>,
+}
+
+/// Résultat de la touche des droites de tendance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendLineEdit {
+    /// Droite tracée de l'ancre au curseur
+    Added,
+    /// Droites retirées (0 : aucune au curseur)
+    Removed(usize),
+}
 
 // ============================================================================
 // Enum : Screen
@@ -39,6 +138,69 @@ pub enum Screen {
     /// - Capture les touches pour construire un buffer
     /// - Enter valide, ESC annule
     InputMode,
+
+    /// Vue répartition : allocation du portefeuille par ticker et classe d'actif
+    Allocation,
+
+    /// Menu d'ajout rapide des paires de devises majeures (par-dessus le dashboard)
+    ForexMenu,
+
+    /// Vue découverte : screeners prédéfinis (hausses, baisses, plus actifs)
+    Screener,
+
+    /// Vue rendements : tableau 1D ... 1Y / YTD de toute la watchlist
+    Returns,
+
+    /// Classement de la watchlist par rendement sur une période
+    Leaderboard,
+
+    /// Vue comparaison : performances normalisées de plusieurs tickers
+    Compare,
+
+    /// Vue backtest : résultats d'une stratégie sur les données du graphique
+    Backtest,
+
+    /// Fiche détaillée du ticker sélectionné (cours, statistiques, position, note)
+    Detail,
+
+    /// Calendrier des prochains détachements et paiements de dividendes
+    Dividends,
+}
+
+/// Objet de la saisie en cours (mode Input)
+///
+/// CONCEPT : Un seul mode Input, plusieurs usages
+/// - Le prompt change selon l'usage
+/// - La validation (Enter) est routée selon cette valeur
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputPurpose {
+    /// Saisie d'un symbole à ajouter à la watchlist
+    #[default]
+    AddTicker,
+
+    /// Saisie de la quantité détenue pour un symbole
+    SetPosition { symbol: String },
+
+    /// Saisie d'une transaction (achat / vente) pour un symbole
+    RecordTransaction { symbol: String },
+
+    /// Saisie d'un dépôt / retrait de liquidités (ex: "+1000 EUR")
+    RecordCash,
+
+    /// Saisie d'une condition d'alerte pour un symbole (ex: "RSI(14) < 30")
+    AddAlert { symbol: String },
+
+    /// Saisie des tags d'un symbole (ex: "dividend long")
+    SetTags { symbol: String },
+
+    /// Saisie de la note libre d'un symbole
+    SetNote { symbol: String },
+
+    /// Saisie de l'annotation d'un chandelier (heure d'ouverture)
+    AddAnnotation { symbol: String, timestamp: DateTime<Utc> },
+
+    /// Saisie d'une commande après ':' (voir command.rs), depuis un écran
+    Command { from: Screen },
 }
 
 /// État principal de l'application
@@ -70,8 +232,10 @@ pub struct App {
 
     /// Indique si l'utilisateur a demandé à quitter (attend confirmation)
     /// CONCEPT : Two-step quit pour éviter les sorties accidentelles
-    /// - Première pression de 'q' : confirm_quit = true
-    /// - Deuxième pression de 'q' : running = false (quit réel)
+    /// - Seulement si du travail serait perdu (voir quit_blocker), sinon
+    ///   'q' quitte directement
+    /// - Première pression de 'q' : confirm_quit = true (popup centré)
+    /// - Deuxième pression de 'q' ou Entrée : running = false (quit réel)
     /// - N'importe quelle autre touche : confirm_quit = false (annulation)
     pub confirm_quit: bool,
 
@@ -98,12 +262,209 @@ pub struct App {
     /// - Ex: "Add ticker: ", "Search: ", etc.
     pub input_prompt: String,
 
+    /// Objet de la saisie en cours (ajout de ticker, position, ...)
+    pub input_purpose: InputPurpose,
+
+    /// Ligne sélectionnée dans le menu d'ajout rapide forex
+    pub forex_menu_index: usize,
+
+    /// Symbole populaire sélectionné quand la watchlist est vide
+    pub empty_state_index: usize,
+
+    /// Positions détenues (résumé du portefeuille sur le dashboard)
+    pub portfolio: Portfolio,
+
+    /// Journal des transactions (prix de revient et P&L)
+    pub journal: Journal,
+
+    /// Tags des tickers (tags.json)
+    pub tags: Tags,
+
+    /// Notes des tickers (notes.json)
+    pub notes: Notes,
+
+    /// Annotations des graphiques (annotations.json)
+    pub annotations: Annotations,
+
+    /// Tag filtrant la watchlist du dashboard (None : tous les tickers)
+    pub tag_filter: Option<String>,
+
+    /// Configuration utilisateur (config.json)
+    pub config: Config,
+
+    /// Indices du bandeau "marchés" (config.market_overview), avec leurs données
+    pub market_overview: Vec<WatchlistItem>,
+
+    /// Instant de la dernière demande de rafraîchissement des indices
+    pub market_overview_requested_at: Option<Instant>,
+
+    /// Dernière valeur de l'indice Fear & Greed crypto (cache compris)
+    pub fear_greed: Option<FearGreed>,
+
+    /// Instant de la dernière demande de l'indice Fear & Greed
+    pub fear_greed_requested_at: Option<Instant>,
+
+    /// Statistiques de marché CoinGecko des cryptos, par symbole (cache compris)
+    pub crypto_stats: HashMap<String, CryptoStats>,
+
+    /// Instant de la dernière demande de statistiques, par symbole
+    pub crypto_stats_requested_at: HashMap<String, Instant>,
+
+    /// Symboles de la liste distante (config.remote_watchlist), cache compris
+    pub remote_watchlist: Vec<String>,
+
+    /// Instant de la dernière demande de la liste distante
+    pub remote_watchlist_requested_at: Option<Instant>,
+
+    /// Publications du calendrier économique (config.economic_calendar)
+    pub economic_events: Vec<EconomicEvent>,
+
+    /// Instant de la dernière demande du calendrier économique
+    pub economic_calendar_requested_at: Option<Instant>,
+
+    /// Santé des fournisseurs de données (copie de api::health, mise à jour
+    /// à chaque tour de boucle)
+    pub provider_health: Vec<ProviderStatus>,
+
+    /// Overlay de debug affiché (F12) et métriques des téléchargements
+    /// (copie de api::metrics, mise à jour tant que l'overlay est affiché)
+    pub debug_overlay: bool,
+    pub fetch_metrics: Vec<TickerMetrics>,
+
+    /// Screener affiché dans la vue découverte
+    pub screener: Screener,
+
+    /// Résultats du screener affiché (vide pendant le chargement)
+    pub screener_quotes: Vec<ScreenerQuote>,
+
+    /// Index de la ligne sélectionnée dans les résultats du screener
+    pub screener_index: usize,
+
+    /// Période du classement (une de ReturnPeriod::LEADERBOARD)
+    pub leaderboard_period: ReturnPeriod,
+
+    /// Rang sélectionné dans le classement
+    pub leaderboard_index: usize,
+
+    /// Ligne sélectionnée dans le calendrier des dividendes
+    pub dividends_index: usize,
+
+    /// Tickers comparés (symboles, dans l'ordre de sélection)
+    pub compare_symbols: Vec<String>,
+
+    /// Ligne sélectionnée dans la liste de la vue comparaison
+    pub compare_index: usize,
+
+    /// Période de la comparaison (une de ReturnPeriod::COMPARE)
+    pub compare_period: ReturnPeriod,
+
+    /// Index de la stratégie testée (dans PRESET_STRATEGIES)
+    pub backtest_strategy: usize,
+
+    /// Résultat du dernier backtest (None sans données)
+    pub backtest: Option<BacktestResult>,
+
     /// Indique si l'utilisateur a demandé à supprimer un item (attend confirmation)
     /// CONCEPT : Two-step delete pour éviter les suppressions accidentelles
-    /// - Première pression de 'd' : confirm_delete = true
-    /// - Deuxième pression de 'd' : suppression réelle
+    /// - Première pression de 'd' : confirm_delete = true (popup centré)
+    /// - Deuxième pression de 'd' ou Entrée : suppression réelle
     /// - N'importe quelle autre touche : confirm_delete = false (annulation)
     pub confirm_delete: bool,
+
+    /// Notification temporaire en cours d'affichage (export réussi, erreur, ...)
+    pub toast: Option<Toast>,
+
+    /// Dernière notification affichée (gardée dans la barre d'état après expiration)
+    pub last_toast: Option<Toast>,
+
+    /// Heure du dernier chargement de données réussi (barre d'état)
+    pub last_refresh: Option<DateTime<Local>>,
+
+    /// Heure de la perte du réseau (None : en ligne)
+    pub offline_since: Option<DateTime<Local>>,
+
+    /// Instant de la dernière sonde de connectivité
+    pub connectivity_probed_at: Option<Instant>,
+
+    /// Alertes affichées dans le bandeau jusqu'à leur fermeture ('x')
+    pub notifications: Vec<Notification>,
+
+    /// Clés des alertes déjà déclenchées (une seule fois chacune)
+    pub fired_alerts: HashSet<String>,
+
+    /// Règles d'alerte sur indicateurs (alerts.json)
+    pub alert_book: AlertBook,
+
+    /// État de chaque règle (déclenchement sur front montant, réarmement)
+    pub alert_states: HashMap<String, RuleState>,
+
+    /// Règles modifiées depuis la dernière sauvegarde (règle "once"
+    /// désactivée, snooze) : alerts.json est à réécrire
+    pub alert_book_dirty: bool,
+
+    /// Journal ou portefeuille modifié mais pas sauvegardé (échec
+    /// d'écriture) : quitter demande confirmation
+    pub unsaved_portfolio: bool,
+
+    /// Vue multi-timeframe : deux graphiques du même ticker superposés
+    /// - Haut : current_interval (tendance)
+    /// - Bas : secondary_interval (détail)
+    pub split_view: bool,
+
+    /// Intervalle du graphique secondaire (vue multi-timeframe)
+    /// Modifiable avec les touches H et L
+    pub secondary_interval: Interval,
+
+    /// Sépare visuellement les séances sur les graphiques intraday (touche g)
+    pub session_separators: bool,
+
+    /// Outil de mesure du graphique principal (touche u, None : inactif)
+    pub measure: Option<MeasureCursor>,
+
+    /// Bornes (min, max) de l'axe Y verrouillées (touche y, None : automatique)
+    /// L'échelle ne bouge plus à chaque nouveau chandelier
+    pub y_axis_lock: Option<(f64, f64)>,
+
+    /// Tickers dont l'indice de référence a été basculé (touche v) par
+    /// rapport à la configuration (config.benchmarks)
+    pub benchmark_toggles: HashSet<String>,
+
+    /// Séries des indices de référence absents de la watchlist
+    pub benchmark_cache: HashMap<String, WatchlistItem>,
+
+    /// Affiche le panneau du carnet d'ordres à côté du graphique (crypto)
+    pub order_book_visible: bool,
+
+    /// Affiche le panneau des statistiques de séance (intervalles intraday)
+    pub session_stats_visible: bool,
+
+    /// Affiche le panneau des annotations du graphique
+    pub annotations_visible: bool,
+
+    /// Affiche le panneau des initiés et institutionnels sur la fiche détaillée
+    pub holders_visible: bool,
+
+    /// Comptes affichés sur la fiche détaillée (annuels ou trimestriels)
+    pub statement_period: StatementPeriod,
+
+    /// Dernier carnet d'ordres reçu
+    pub order_book: Option<OrderBook>,
+
+    /// Instant de la dernière demande de carnet d'ordres (rafraîchissement périodique)
+    pub order_book_requested_at: Option<Instant>,
+
+    /// Dernière demande de rechargement envoyée au worker, par symbole
+    /// (voir begin_load / resolve_load)
+    pub pending_loads: HashMap<String, u64>,
+
+    /// Identifiant de la prochaine demande de rechargement
+    pub next_request_id: u64,
+
+    /// Dernier rafraîchissement automatique de chaque ticker
+    pub auto_refreshed_at: HashMap<String, DateTime<Utc>>,
+
+    /// Rafraîchissement automatique en cours (symbole, identifiant)
+    pub auto_refresh_request: Option<(String, u64)>,
 }
 
 impl App {
@@ -125,25 +486,82 @@ impl App {
             loading_message: None,
             input_buffer: String::new(),
             input_prompt: String::new(),
+            input_purpose: InputPurpose::default(),
+            forex_menu_index: 0,
+            empty_state_index: 0,
+            portfolio: Portfolio::default(),
+            journal: Journal::default(),
+            tags: Tags::default(),
+            notes: Notes::default(),
+            annotations: Annotations::default(),
+            tag_filter: None,
+            config: Config::default(),
+            market_overview: Vec::new(),
+            market_overview_requested_at: None,
+            fear_greed: None,
+            fear_greed_requested_at: None,
+            crypto_stats: HashMap::new(),
+            crypto_stats_requested_at: HashMap::new(),
+            remote_watchlist: Vec::new(),
+            remote_watchlist_requested_at: None,
+            economic_events: Vec::new(),
+            economic_calendar_requested_at: None,
+            provider_health: Vec::new(),
+            debug_overlay: false,
+            fetch_metrics: Vec::new(),
+            screener: Screener::default(),
+            screener_quotes: Vec::new(),
+            screener_index: 0,
+            leaderboard_period: ReturnPeriod::OneDay,
+            leaderboard_index: 0,
+            dividends_index: 0,
+            compare_symbols: Vec::new(),
+            compare_index: 0,
+            compare_period: ReturnPeriod::OneMonth,
+            backtest_strategy: 0,
+            backtest: None,
             confirm_delete: false,
+            toast: None,
+            last_toast: None,
+            last_refresh: None,
+            offline_since: None,
+            connectivity_probed_at: None,
+            notifications: Vec::new(),
+            fired_alerts: HashSet::new(),
+            alert_book: AlertBook::default(),
+            alert_states: HashMap::new(),
+            alert_book_dirty: false,
+            unsaved_portfolio: false,
+            split_view: false,
+            session_separators: false,
+            measure: None,
+            y_axis_lock: None,
+            secondary_interval: Interval::default().finer(),
+            benchmark_toggles: HashSet::new(),
+            benchmark_cache: HashMap::new(),
+            order_book_visible: false,
+            session_stats_visible: false,
+            annotations_visible: false,
+            holders_visible: false,
+            statement_period: StatementPeriod::default(),
+            order_book: None,
+            order_book_requested_at: None,
+            pending_loads: HashMap::new(),
+            next_request_id: 0,
+            auto_refreshed_at: HashMap::new(),
+            auto_refresh_request: None,
         }
     }
 
     /// Crée une App avec une watchlist préchargée
+    ///
+    /// CONCEPT RUST : Réutilisation du constructeur
+    /// - Part de App::new() et ne remplace que la watchlist
+    /// - Évite de dupliquer la liste des champs
     pub fn with_watchlist(watchlist: Vec<WatchlistItem>) -> Self {
-        Self {
-            running: true,
-            watchlist,
-            selected_index: 0,
-            current_screen: Screen::Dashboard,
-            current_interval: Interval::default(), // 30m par défaut
-            confirm_quit: false,
-            is_loading: false,
-            loading_message: None,
-            input_buffer: String::new(),
-            input_prompt: String::new(),
-            confirm_delete: false,
-        }
+        let mut app = Self::new();
+        app.watchlist = watchlist;
+        app
     }
 
     /// Quitte l'application
@@ -158,21 +576,83 @@ impl App {
 
     /// Navigue vers le haut dans la watchlist
     ///
-    /// CONCEPT RUST : Saturating arithmetic
-    /// - saturating_sub() : soustrait mais ne descend pas en dessous de 0
-    /// - Évite les panics avec les unsigned
+    /// CONCEPT RUST : Recherche inversée
+    /// - rev() + find() : premier index visible avant la sélection
+    /// - Rien trouvé : la sélection reste en haut (pas de bouclage)
+    /// - Watchlist vide : parcourt les symboles populaires (avec bouclage)
     pub fn navigate_up(&mut self) {
-        self.selected_index = self.selected_index.saturating_sub(1);
+        if self.watchlist.is_empty() {
+            let len = POPULAR_SYMBOLS.len();
+            self.empty_state_index = (self.empty_state_index + len - 1) % len;
+        } else if let Some(index) = self.visible_rows().into_iter().rev().find(|&i| i < self.selected_index) {
+            self.selected_index = index;
+        }
     }
 
     /// Navigue vers le bas dans la watchlist
     ///
-    /// CONCEPT RUST : min() pour éviter le dépassement
-    /// - Limite l'index à watchlist.len() - 1
-    /// - saturating_sub(1) gère le cas watchlist vide (0 - 1 = 0)
+    /// Seules les lignes visibles (filtre par tag) sont parcourues ; la
+    /// sélection reste sur la dernière ligne en bas de liste
     pub fn navigate_down(&mut self) {
-        let max_index = self.watchlist.len().saturating_sub(1);
-        self.selected_index = (self.selected_index + 1).min(max_index);
+        if self.watchlist.is_empty() {
+            self.empty_state_index = (self.empty_state_index + 1) % POPULAR_SYMBOLS.len();
+        } else if let Some(index) = self.visible_rows().into_iter().find(|&i| i > self.selected_index) {
+            self.selected_index = index;
+        }
+    }
+
+    /// Symbole populaire sélectionné (symbole Yahoo, libellé)
+    ///
+    /// None dès que la watchlist contient un ticker
+    pub fn selected_suggestion(&self) -> Option<(&'static str, &'static str)> {
+        self.watchlist.is_empty().then(|| POPULAR_SYMBOLS[self.empty_state_index])
+    }
+
+    /// Sélectionne directement une ligne visible de la watchlist (touches 1 à 9)
+    ///
+    /// Retourne false si la ligne n'existe pas (sélection inchangée)
+    pub fn select_row(&mut self, row: usize) -> bool {
+        match self.visible_rows().get(row) {
+            Some(&index) => {
+                self.selected_index = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // ========================================================================
+    // Tags
+    // ========================================================================
+
+    /// Indices des items affichés sur le dashboard (tous sans filtre)
+    pub fn visible_rows(&self) -> Vec<usize> {
+        (0..self.watchlist.len())
+            .filter(|&index| {
+                self.tag_filter
+                    .as_ref()
+                    .is_none_or(|tag| self.tags.has(&self.watchlist[index].symbol, tag))
+            })
+            .collect()
+    }
+
+    /// Passe au filtre suivant : tous → premier tag → ... → dernier tag → tous
+    ///
+    /// La sélection est déplacée sur la première ligne visible si elle est masquée
+    pub fn cycle_tag_filter(&mut self) {
+        let all = self.tags.all();
+        let next = match &self.tag_filter {
+            None => all.first(),
+            Some(current) => all.iter().skip_while(|tag| *tag != current).nth(1),
+        };
+        self.tag_filter = next.cloned();
+
+        let visible = self.visible_rows();
+        if !visible.contains(&self.selected_index) {
+            if let Some(&first) = visible.first() {
+                self.selected_index = first;
+            }
+        }
     }
 
     /// Retourne l'item sélectionné dans la watchlist
@@ -184,6 +664,46 @@ impl App {
         self.watchlist.get(self.selected_index)
     }
 
+    /// Prépare le nouvel essai de chargement de l'item sélectionné
+    ///
+    /// Retourne le symbole si le dernier chargement a échoué, et efface
+    /// l'erreur pour que la ligne repasse en "Loading..."
+    /// None si l'item est chargé correctement (rien à réessayer)
+    pub fn retry_selected(&mut self) -> Option<String> {
+        let item = self.watchlist.get_mut(self.selected_index)?;
+        item.load_error.take()?;
+        Some(item.symbol.clone())
+    }
+
+    // ========================================================================
+    // Rechargements en cours (worker)
+    // ========================================================================
+    // CONCEPT : Résultats adressés par symbole
+    // - Un index de la watchlist n'est plus valable si un ticker est supprimé
+    //   ou déplacé pendant le chargement : le résultat irait au mauvais item
+    // - Chaque demande porte un identifiant ; seul le résultat de la dernière
+    //   demande d'un symbole est appliqué (l'intervalle a pu changer entre
+    //   temps), les autres sont ignorés
+
+    /// Enregistre une demande de rechargement et retourne son identifiant
+    pub fn begin_load(&mut self, symbol: &str) -> u64 {
+        self.next_request_id += 1;
+        self.pending_loads.insert(symbol.to_string(), self.next_request_id);
+        self.next_request_id
+    }
+
+    /// Item destinataire du résultat d'une demande de rechargement
+    ///
+    /// None si la demande a été remplacée par une plus récente, ou si le
+    /// ticker a quitté la watchlist : le résultat est à ignorer
+    pub fn resolve_load(&mut self, symbol: &str, request_id: u64) -> Option<&mut WatchlistItem> {
+        if self.pending_loads.get(symbol) != Some(&request_id) {
+            return None;
+        }
+        self.pending_loads.remove(symbol);
+        self.watchlist.iter_mut().find(|item| item.symbol == symbol)
+    }
+
     /// Tick : appelé à chaque itération de la boucle
     ///
     /// CONCEPT : Event Loop Pattern
@@ -191,14 +711,15 @@ impl App {
     /// - Permet de mettre à jour l'état même sans événement utilisateur
     /// - Utile pour animations, compteurs, rafraîchissements auto
     ///
-    /// Pour l'instant c'est vide, mais on ajoutera du code plus tard
-    /// (ex: décrémenter un compteur de rafraîchissement)
+    /// Efface les notifications expirées
     pub fn tick(&mut self) {
-        // Pour l'instant, rien à faire à chaque tick
-        // Dans les prochaines étapes :
-        // - Décrémenter un timer de rafraîchissement
-        // - Mettre à jour des animations
-        // - etc.
+        if self
+            .toast
+            .as_ref()
+            .is_some_and(|toast| toast.created_at.elapsed() >= TOAST_DURATION)
+        {
+            self.toast = None;
+        }
     }
 
     /// Vérifie si l'application doit continuer
@@ -211,165 +732,1373 @@ impl App {
     /// CONCEPT RUST : State transition
     /// - Change l'état de current_screen
     /// - Pattern "State Machine" : transition Dashboard → ChartView
+    ///
+    /// Ouvert depuis le dashboard (ou la fiche détaillée), le graphique prend
+    /// l'intervalle de l'item sélectionné (celui de ses données, sinon celui
+    /// de son type d'actif)
     pub fn show_chart(&mut self) {
+        if matches!(self.current_screen, Screen::Dashboard | Screen::Detail) {
+            if let Some(interval) = self.selected_item().map(WatchlistItem::interval) {
+                self.current_interval = interval;
+            }
+        }
         self.current_screen = Screen::ChartView;
     }
 
     /// Retourne à la vue dashboard
     pub fn show_dashboard(&mut self) {
         self.current_screen = Screen::Dashboard;
+        self.measure = None;
+        self.y_axis_lock = None;
     }
 
-    /// Vérifie si on est sur le dashboard
-    pub fn is_on_dashboard(&self) -> bool {
-        self.current_screen == Screen::Dashboard
+    /// Affiche la vue répartition du portefeuille
+    pub fn show_allocation(&mut self) {
+        self.current_screen = Screen::Allocation;
     }
 
-    /// Vérifie si on est sur la vue graphique
-    pub fn is_on_chart(&self) -> bool {
-        self.current_screen == Screen::ChartView
+    /// Vérifie si on est sur la vue répartition
+    pub fn is_on_allocation(&self) -> bool {
+        self.current_screen == Screen::Allocation
     }
 
-    /// Passe à l'intervalle suivant
-    ///
-    /// CONCEPT : Cycle d'états
-    /// - M1 → M5 → M15 → M30 → H1 → H4 → D1 → W1 → M1
-    /// - Utilisé avec la touche ]
-    pub fn next_interval(&mut self) {
-        self.current_interval = self.current_interval.next();
+    /// Affiche le tableau des rendements de la watchlist
+    pub fn show_returns(&mut self) {
+        self.current_screen = Screen::Returns;
     }
 
-    /// Passe à l'intervalle précédent
-    ///
-    /// CONCEPT : Cycle d'états (inverse)
-    /// - W1 → D1 → H4 → H1 → M30 → M15 → M5 → M1 → W1
-    /// - Utilisé avec la touche [
-    pub fn previous_interval(&mut self) {
-        self.current_interval = self.current_interval.previous();
+    /// Vérifie si on est sur la vue rendements
+    pub fn is_on_returns(&self) -> bool {
+        self.current_screen == Screen::Returns
     }
 
-    /// Demande la confirmation de quitter
-    ///
-    /// CONCEPT : Two-step quit pattern
-    /// - Appelé lors de la première pression de 'q'
-    /// - Active l'état confirm_quit pour attendre une seconde pression
-    /// - Évite les sorties accidentelles
-    pub fn request_quit(&mut self) {
-        self.confirm_quit = true;
+    /// Affiche le classement de la watchlist (sélection en tête)
+    pub fn show_leaderboard(&mut self) {
+        self.current_screen = Screen::Leaderboard;
+        self.leaderboard_index = 0;
     }
 
-    /// Annule la demande de quit
-    ///
-    /// CONCEPT : Reset de l'état de confirmation
-    /// - Appelé quand l'utilisateur presse une touche autre que 'q'
-    /// - Remet confirm_quit à false
-    pub fn cancel_quit(&mut self) {
-        self.confirm_quit = false;
+    /// Vérifie si on est sur le classement
+    pub fn is_on_leaderboard(&self) -> bool {
+        self.current_screen == Screen::Leaderboard
     }
 
-    /// Vérifie si on attend la confirmation de quit
-    pub fn is_awaiting_quit_confirmation(&self) -> bool {
-        self.confirm_quit
+    /// Passe à la période suivante (ou précédente) du classement
+    pub fn switch_leaderboard_period(&mut self, forward: bool) {
+        let periods = ReturnPeriod::LEADERBOARD;
+        let current = periods.iter().position(|period| *period == self.leaderboard_period).unwrap_or(0);
+        let next = if forward { current + 1 } else { current + periods.len() - 1 };
+        self.leaderboard_period = periods[next % periods.len()];
+        self.leaderboard_index = 0;
     }
 
-    /// Démarre le chargement avec un message optionnel
-    ///
-    /// CONCEPT : Loading state management
-    /// - Active is_loading pour afficher l'indicateur
-    /// - Stocke le message pour l'utilisateur
-    pub fn start_loading(&mut self, message: Option<String>) {
-        self.is_loading = true;
-        self.loading_message = message;
+    /// Classement de la watchlist sur la période affichée
+    pub fn leaderboard(&self) -> Vec<(usize, Option<f64>)> {
+        returns::leaderboard(&self.watchlist, self.leaderboard_period)
     }
 
-    /// Termine le chargement
-    pub fn stop_loading(&mut self) {
-        self.is_loading = false;
-        self.loading_message = None;
+    /// Déplace la sélection vers le haut dans le classement
+    pub fn leaderboard_up(&mut self) {
+        self.leaderboard_index = self.leaderboard_index.saturating_sub(1);
     }
 
-    /// Vérifie si des données sont en cours de chargement
-    pub fn is_loading_data(&self) -> bool {
-        self.is_loading
+    /// Déplace la sélection vers le bas dans le classement
+    pub fn leaderboard_down(&mut self) {
+        let max_index = self.watchlist.len().saturating_sub(1);
+        self.leaderboard_index = (self.leaderboard_index + 1).min(max_index);
     }
 
-    // ========================================================================
-    // Input Mode Management
-    // ========================================================================
-
-    /// Entre en mode input avec un prompt donné
+    /// Sélectionne le ticker du rang choisi dans la watchlist
     ///
-    /// CONCEPT : Modal input (Vim-like)
-    /// - Change l'écran vers InputMode
-    /// - Initialise le buffer vide
-    /// - Configure le prompt à afficher
-    pub fn start_input(&mut self, prompt: String) {
-        self.current_screen = Screen::InputMode;
-        self.input_buffer.clear();
-        self.input_prompt = prompt;
+    /// Retourne false si le classement est vide
+    pub fn select_leaderboard_entry(&mut self) -> bool {
+        match self.leaderboard().get(self.leaderboard_index) {
+            Some(&(index, _)) => {
+                self.selected_index = index;
+                true
+            }
+            None => false,
+        }
     }
 
-    /// Annule le mode input et retourne au dashboard
-    pub fn cancel_input(&mut self) {
-        self.current_screen = Screen::Dashboard;
-        self.input_buffer.clear();
-        self.input_prompt.clear();
+    /// Affiche le calendrier des dividendes (première date en tête)
+    pub fn show_dividends(&mut self) {
+        self.current_screen = Screen::Dividends;
+        self.dividends_index = 0;
     }
 
-    /// Récupère la valeur saisie et retourne au dashboard
-    ///
-    /// CONCEPT : Consume input
-    /// - Retourne le contenu du buffer
-    /// - Vide le buffer
-    /// - Retourne au dashboard
-    pub fn submit_input(&mut self) -> String {
-        let value = self.input_buffer.clone();
-        self.current_screen = Screen::Dashboard;
-        self.input_buffer.clear();
-        self.input_prompt.clear();
-        value
+    /// Vérifie si on est sur le calendrier des dividendes
+    pub fn is_on_dividends(&self) -> bool {
+        self.current_screen == Screen::Dividends
     }
 
-    /// Ajoute un caractère au buffer d'input
-    pub fn append_char(&mut self, c: char) {
-        self.input_buffer.push(c);
+    /// Détachements et paiements à venir de la watchlist
+    pub fn dividend_calendar(&self) -> Vec<UpcomingDividend> {
+        upcoming_dividends(&self.watchlist, &self.portfolio, Local::now().date_naive())
     }
 
-    /// Supprime le dernier caractère du buffer
-    pub fn backspace(&mut self) {
-        self.input_buffer.pop();
+    /// Tickers dont le dividende reste à charger (actions et ETF)
+    pub fn dividends_to_load(&self) -> Vec<String> {
+        self.watchlist
+            .iter()
+            .filter(|item| matches!(item.ticker_type(), TickerType::Stock | TickerType::ETF) && item.dividends.is_none())
+            .map(|item| item.symbol.clone())
+            .collect()
     }
 
-    /// Vérifie si on est en mode input
-    pub fn is_in_input_mode(&self) -> bool {
-        self.current_screen == Screen::InputMode
+    /// Déplace la sélection vers le haut dans le calendrier
+    pub fn dividends_up(&mut self) {
+        self.dividends_index = self.dividends_index.saturating_sub(1);
     }
 
-    // ========================================================================
-    // Delete Confirmation Management
-    // ========================================================================
+    /// Déplace la sélection vers le bas dans le calendrier
+    pub fn dividends_down(&mut self) {
+        let max_index = self.dividend_calendar().len().saturating_sub(1);
+        self.dividends_index = (self.dividends_index + 1).min(max_index);
+    }
 
-    /// Demande la confirmation de suppression
+    /// Sélectionne dans la watchlist le ticker de la ligne choisie
     ///
-    /// CONCEPT : Two-step delete pattern
-    /// - Appelé lors de la première pression de 'd'
-    /// - Active l'état confirm_delete pour attendre une seconde pression
-    /// - Évite les suppressions accidentelles
-    pub fn request_delete(&mut self) {
-        self.confirm_delete = true;
+    /// Retourne false si le calendrier est vide
+    pub fn select_dividend_entry(&mut self) -> bool {
+        let Some(entry) = self.dividend_calendar().into_iter().nth(self.dividends_index) else {
+            return false;
+        };
+        match self.watchlist.iter().position(|item| item.symbol == entry.symbol) {
+            Some(index) => {
+                self.selected_index = index;
+                true
+            }
+            None => false,
+        }
     }
 
-    /// Annule la demande de suppression
-    pub fn cancel_delete(&mut self) {
-        self.confirm_delete = false;
+    /// Affiche la vue comparaison, curseur sur le ticker sélectionné
+    ///
+    /// Première ouverture : le ticker sélectionné est déjà comparé
+    pub fn show_compare(&mut self) {
+        self.current_screen = Screen::Compare;
+        self.compare_index = self.selected_index.min(self.watchlist.len().saturating_sub(1));
+        if self.compare_symbols.is_empty() {
+            self.compare_symbols.extend(self.selected_item().map(|item| item.symbol.clone()));
+        }
     }
 
-    /// Vérifie si on attend la confirmation de suppression
-    pub fn is_awaiting_delete_confirmation(&self) -> bool {
-        self.confirm_delete
+    /// Vérifie si on est sur la vue comparaison
+    pub fn is_on_compare(&self) -> bool {
+        self.current_screen == Screen::Compare
     }
 
-    /// Supprime l'item sélectionné de la watchlist
+    /// Déplace le curseur vers le haut dans la liste de la comparaison
+    pub fn compare_up(&mut self) {
+        self.compare_index = self.compare_index.saturating_sub(1);
+    }
+
+    /// Déplace le curseur vers le bas dans la liste de la comparaison
+    pub fn compare_down(&mut self) {
+        let max_index = self.watchlist.len().saturating_sub(1);
+        self.compare_index = (self.compare_index + 1).min(max_index);
+    }
+
+    /// Ajoute ou retire de la comparaison le ticker sous le curseur
+    ///
+    /// Retourne false si la comparaison est déjà pleine (MAX_COMPARED)
+    pub fn toggle_compare_selection(&mut self) -> bool {
+        let Some(symbol) = self.watchlist.get(self.compare_index).map(|item| item.symbol.clone()) else {
+            return true;
+        };
+        if let Some(position) = self.compare_symbols.iter().position(|compared| *compared == symbol) {
+            self.compare_symbols.remove(position);
+        } else if self.compare_symbols.len() >= MAX_COMPARED {
+            return false;
+        } else {
+            self.compare_symbols.push(symbol);
+        }
+        true
+    }
+
+    /// Passe à la période suivante (ou précédente) de la comparaison
+    pub fn switch_compare_period(&mut self, forward: bool) {
+        let periods = ReturnPeriod::COMPARE;
+        let current = periods.iter().position(|period| *period == self.compare_period).unwrap_or(0);
+        let next = if forward { current + 1 } else { current + periods.len() - 1 };
+        self.compare_period = periods[next % periods.len()];
+    }
+
+    /// Début de la période comparée, calculé depuis la clôture la plus
+    /// récente des tickers comparés : la même date pour toutes les courbes
+    pub fn compare_start(&self) -> Option<DateTime<Utc>> {
+        let last = self
+            .compared_items()
+            .filter_map(|(_, item)| item.data_for(Interval::D1)?.last().map(|candle| candle.timestamp))
+            .max()?;
+        self.compare_period.reference_date(last)
+    }
+
+    /// Tickers comparés présents dans la watchlist : (index, ticker), dans
+    /// l'ordre de sélection (qui fixe aussi leur couleur)
+    pub fn compared_items(&self) -> impl Iterator<Item = (usize, &WatchlistItem)> {
+        self.compare_symbols.iter().filter_map(|symbol| {
+            self.watchlist.iter().enumerate().find(|(_, item)| item.symbol == *symbol)
+        })
+    }
+
+    /// Sélectionne dans la watchlist le ticker sous le curseur de la comparaison
+    ///
+    /// Retourne false si la watchlist est vide
+    pub fn select_compare_entry(&mut self) -> bool {
+        if self.compare_index < self.watchlist.len() {
+            self.selected_index = self.compare_index;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Affiche la fiche détaillée du ticker sélectionné
+    pub fn show_detail(&mut self) {
+        self.current_screen = Screen::Detail;
+    }
+
+    /// Vérifie si on est sur la fiche détaillée
+    pub fn is_on_detail(&self) -> bool {
+        self.current_screen == Screen::Detail
+    }
+
+    /// Active/désactive le panneau des initiés et institutionnels
+    pub fn toggle_holders(&mut self) {
+        self.holders_visible = !self.holders_visible;
+    }
+
+    /// Bascule les comptes de la fiche détaillée entre annuels et trimestriels
+    pub fn toggle_statement_period(&mut self) {
+        self.statement_period = self.statement_period.toggle();
+    }
+
+    /// Ouvre la vue backtest sur les données du graphique affiché
+    pub fn show_backtest(&mut self) {
+        self.current_screen = Screen::Backtest;
+        self.run_backtest();
+    }
+
+    /// Vérifie si on est sur la vue backtest
+    pub fn is_on_backtest(&self) -> bool {
+        self.current_screen == Screen::Backtest
+    }
+
+    /// Passe à la stratégie suivante (ou précédente) et relance le backtest
+    pub fn switch_backtest_strategy(&mut self, forward: bool) {
+        let len = PRESET_STRATEGIES.len();
+        self.backtest_strategy = if forward {
+            (self.backtest_strategy + 1) % len
+        } else {
+            (self.backtest_strategy + len - 1) % len
+        };
+        self.run_backtest();
+    }
+
+    /// Rejoue la stratégie courante sur les données du ticker sélectionné
+    ///
+    /// Calcul local et rapide : exécuté directement, sans passer par le worker
+    fn run_backtest(&mut self) {
+        let strategy = PRESET_STRATEGIES[self.backtest_strategy];
+        self.backtest = self
+            .selected_item()
+            .and_then(|item| item.data.as_ref())
+            .map(|data| backtest::run(data, strategy));
+    }
+
+    /// Ouvre le menu d'ajout rapide des paires de devises
+    pub fn show_forex_menu(&mut self) {
+        self.current_screen = Screen::ForexMenu;
+        self.forex_menu_index = 0;
+    }
+
+    /// Vérifie si le menu forex est ouvert
+    pub fn is_on_forex_menu(&self) -> bool {
+        self.current_screen == Screen::ForexMenu
+    }
+
+    /// Déplace la sélection du menu forex (avec bouclage)
+    pub fn forex_menu_up(&mut self) {
+        let len = MAJOR_FOREX_PAIRS.len();
+        self.forex_menu_index = (self.forex_menu_index + len - 1) % len;
+    }
+
+    /// Déplace la sélection du menu forex vers le bas (avec bouclage)
+    pub fn forex_menu_down(&mut self) {
+        self.forex_menu_index = (self.forex_menu_index + 1) % MAJOR_FOREX_PAIRS.len();
+    }
+
+    /// Paire sélectionnée dans le menu forex (symbole Yahoo, libellé)
+    pub fn selected_forex_pair(&self) -> (&'static str, &'static str) {
+        MAJOR_FOREX_PAIRS[self.forex_menu_index]
+    }
+
+    /// Ouvre la vue découverte sur le screener courant
+    ///
+    /// Les résultats précédents sont effacés : l'appelant demande leur
+    /// rechargement au worker
+    pub fn show_screener(&mut self) {
+        self.current_screen = Screen::Screener;
+        self.screener_quotes.clear();
+        self.screener_index = 0;
+    }
+
+    /// Vérifie si on est sur la vue découverte
+    pub fn is_on_screener(&self) -> bool {
+        self.current_screen == Screen::Screener
+    }
+
+    /// Passe au screener suivant (ou précédent) et efface les résultats
+    pub fn switch_screener(&mut self, forward: bool) {
+        self.screener = if forward { self.screener.next() } else { self.screener.previous() };
+        self.show_screener();
+    }
+
+    /// Enregistre les résultats d'un screener (ignorés s'ils ne sont plus affichés)
+    pub fn set_screener_quotes(&mut self, screener: Screener, quotes: Vec<ScreenerQuote>) {
+        if screener == self.screener {
+            self.screener_quotes = quotes;
+            self.screener_index = 0;
+        }
+    }
+
+    /// Déplace la sélection vers le haut dans les résultats du screener
+    pub fn screener_up(&mut self) {
+        self.screener_index = self.screener_index.saturating_sub(1);
+    }
+
+    /// Déplace la sélection vers le bas dans les résultats du screener
+    pub fn screener_down(&mut self) {
+        let max_index = self.screener_quotes.len().saturating_sub(1);
+        self.screener_index = (self.screener_index + 1).min(max_index);
+    }
+
+    /// Résultat sélectionné dans le screener
+    pub fn selected_screener_quote(&self) -> Option<&ScreenerQuote> {
+        self.screener_quotes.get(self.screener_index)
+    }
+
+    /// Vérifie si un symbole est déjà dans la watchlist
+    pub fn is_in_watchlist(&self, symbol: &str) -> bool {
+        self.watchlist.iter().any(|item| item.symbol == symbol)
+    }
+
+    /// Vérifie si on est sur le dashboard
+    pub fn is_on_dashboard(&self) -> bool {
+        self.current_screen == Screen::Dashboard
+    }
+
+    /// Vérifie si on est sur la vue graphique
+    pub fn is_on_chart(&self) -> bool {
+        self.current_screen == Screen::ChartView
+    }
+
+    /// Passe à l'intervalle suivant
+    ///
+    /// CONCEPT : Cycle d'états
+    /// - M1 → M5 → M15 → M30 → H1 → H4 → D1 → W1 → M1
+    /// - Utilisé avec la touche ]
+    pub fn next_interval(&mut self) {
+        self.current_interval = self.current_interval.next();
+        self.measure = None;
+        self.y_axis_lock = None;
+    }
+
+    /// Passe à l'intervalle précédent
+    ///
+    /// CONCEPT : Cycle d'états (inverse)
+    /// - W1 → D1 → H4 → H1 → M30 → M15 → M5 → M1 → W1
+    /// - Utilisé avec la touche [
+    pub fn previous_interval(&mut self) {
+        self.current_interval = self.current_interval.previous();
+        self.measure = None;
+        self.y_axis_lock = None;
+    }
+
+    // ========================================================================
+    // Outil de mesure (graphique principal)
+    // ========================================================================
+
+    /// Nombre de chandeliers du graphique principal
+    fn chart_len(&self) -> usize {
+        self.selected_item()
+            .and_then(|item| item.data.as_ref())
+            .map_or(0, |data| data.candles.len())
+    }
+
+    /// Active l'outil de mesure (curseur sur le dernier chandelier) ou le quitte
+    pub fn toggle_measure(&mut self) {
+        let len = self.chart_len();
+        self.measure = match self.measure {
+            None if len > 0 => Some(MeasureCursor { cursor: len - 1, anchor: None }),
+            _ => None,
+        };
+    }
+
+    /// Déplace le curseur de mesure de `step` chandeliers
+    ///
+    /// Borné à la fenêtre affichée (les MAX_VISIBLE_CANDLES derniers)
+    pub fn move_measure_cursor(&mut self, step: isize) {
+        let len = self.chart_len();
+        if let Some(measure) = self.measure.as_mut().filter(|_| len > 0) {
+            let target = measure.cursor.saturating_add_signed(step);
+            measure.cursor = target.clamp(measure::first_visible(len), len - 1);
+        }
+    }
+
+    /// Pose l'ancre de la mesure sur le chandelier du curseur
+    pub fn anchor_measure(&mut self) {
+        if let Some(measure) = self.measure.as_mut() {
+            measure.anchor = Some(measure.cursor);
+        }
+    }
+
+    /// Mesure de l'ancre au curseur (None sans ancre ou si les données ont changé)
+    pub fn measurement(&self) -> Option<Measurement> {
+        let cursor = self.measure?;
+        let data = self.selected_item()?.data.as_ref()?;
+        measure::measure(&data.candles, cursor.anchor?, cursor.cursor)
+    }
+
+    /// Trace la droite de tendance de l'ancre au curseur (clôtures des deux
+    /// chandeliers), ou la retire si elle existe déjà
+    ///
+    /// Sans ancre : retire les droites qui partent ou arrivent au chandelier
+    /// du curseur
+    ///
+    /// Retourne None si l'outil de mesure est inactif ou l'ancre sur le curseur
+    pub fn toggle_trend_line(&mut self) -> Option<TrendLineEdit> {
+        let cursor = self.measure?;
+        let item = self.watchlist.get(self.selected_index)?;
+        let data = item.data.as_ref()?;
+        let symbol = item.symbol.clone();
+        let end = data.candles.get(cursor.cursor)?;
+
+        let Some(anchor) = cursor.anchor else {
+            let until = end.timestamp + chrono::Duration::minutes(data.interval.minutes());
+            let removed = self.annotations.remove_trend_lines_at(&symbol, end.timestamp, until);
+            return Some(TrendLineEdit::Removed(removed));
+        };
+        let start = data.candles.get(anchor).filter(|_| anchor != cursor.cursor)?;
+
+        let line = TrendLine::new((start.timestamp, start.close), (end.timestamp, end.close));
+        Some(if self.annotations.toggle_trend_line(&symbol, line) {
+            TrendLineEdit::Added
+        } else {
+            TrendLineEdit::Removed(1)
+        })
+    }
+
+    /// Active/désactive le panneau des annotations
+    pub fn toggle_annotations(&mut self) {
+        self.annotations_visible = !self.annotations_visible;
+    }
+
+    /// Active/désactive les séparateurs de séance
+    pub fn toggle_session_separators(&mut self) {
+        self.session_separators = !self.session_separators;
+    }
+
+    /// Active/désactive la vue multi-timeframe
+    ///
+    /// À l'activation, le graphique secondaire prend l'intervalle plus fin
+    /// associé à l'intervalle courant (ex: 1d → 1h)
+    pub fn toggle_split_view(&mut self) {
+        self.split_view = !self.split_view;
+        if self.split_view {
+            self.secondary_interval = self.current_interval.finer();
+        }
+    }
+
+    /// Passe à l'intervalle suivant pour le graphique secondaire
+    pub fn next_secondary_interval(&mut self) {
+        self.secondary_interval = self.secondary_interval.next();
+    }
+
+    /// Passe à l'intervalle précédent pour le graphique secondaire
+    pub fn previous_secondary_interval(&mut self) {
+        self.secondary_interval = self.secondary_interval.previous();
+    }
+
+    // ========================================================================
+    // Indice de référence (benchmark)
+    // ========================================================================
+    // CONCEPT : Performance relative
+    // - Le graphique superpose l'indice ramené au cours du ticker
+    // - Le titre compare les deux variations : "AAPL +8.3% vs SPY +2.1%"
+    // - Réglage par ticker : config.benchmarks l'active d'emblée (avec un
+    //   indice propre), la touche v le bascule pour la session
+
+    /// Indice de référence d'un ticker (config.benchmarks, sinon config.benchmark)
+    pub fn benchmark_symbol(&self, symbol: &str) -> &str {
+        self.config
+            .benchmarks
+            .get(symbol)
+            .unwrap_or(&self.config.benchmark)
+    }
+
+    /// Vérifie si le graphique d'un ticker superpose son indice de référence
+    ///
+    /// Jamais pour l'indice lui-même
+    pub fn benchmark_enabled(&self, symbol: &str) -> bool {
+        let enabled = self.config.benchmarks.contains_key(symbol) != self.benchmark_toggles.contains(symbol);
+        enabled && !self.benchmark_symbol(symbol).eq_ignore_ascii_case(symbol)
+    }
+
+    /// Bascule l'indice de référence du ticker sélectionné
+    ///
+    /// Retourne le nouvel état (None sans sélection)
+    pub fn toggle_benchmark(&mut self) -> Option<bool> {
+        let symbol = self.selected_item()?.symbol.clone();
+        if !self.benchmark_toggles.remove(&symbol) {
+            self.benchmark_toggles.insert(symbol.clone());
+        }
+        Some(self.benchmark_enabled(&symbol))
+    }
+
+    /// Série de l'indice pour un intervalle (watchlist d'abord, puis cache)
+    pub fn benchmark_data(&self, benchmark: &str, interval: Interval) -> Option<&OHLCData> {
+        self.watchlist
+            .iter()
+            .chain(self.benchmark_cache.get(benchmark))
+            .filter(|item| item.symbol == benchmark)
+            .find_map(|item| item.data_for(interval))
+    }
+
+    /// Range la série d'un indice de référence
+    pub fn cache_benchmark(&mut self, data: OHLCData) {
+        match self.benchmark_cache.get_mut(&data.symbol) {
+            Some(item) => item.cache_data(data),
+            None => {
+                let symbol = data.symbol.clone();
+                self.benchmark_cache.insert(symbol.clone(), WatchlistItem::with_data(symbol.clone(), symbol, data));
+            }
+        }
+    }
+
+    /// Bêta et corrélation d'un ticker vs son indice de référence (séries D1)
+    ///
+    /// None pour l'indice lui-même, ou tant que l'une des séries manque
+    pub fn beta(&self, symbol: &str) -> Option<BetaStats> {
+        let benchmark = self.benchmark_symbol(symbol);
+        if benchmark.eq_ignore_ascii_case(symbol) {
+            return None;
+        }
+        let item = self.watchlist.iter().find(|item| item.symbol == symbol)?;
+        beta_stats(item.data_for(Interval::D1)?, self.benchmark_data(benchmark, Interval::D1)?)
+    }
+
+    /// Force relative d'un ticker vs son indice de référence sur la fenêtre
+    /// de config.relative_strength_days (séries D1)
+    ///
+    /// None si l'option est désactivée, pour l'indice lui-même, ou tant que
+    /// l'une des séries manque
+    pub fn relative_strength(&self, symbol: &str) -> Option<f64> {
+        let days = self.config.relative_strength_days;
+        let benchmark = self.benchmark_symbol(symbol);
+        if days == 0 || benchmark.eq_ignore_ascii_case(symbol) {
+            return None;
+        }
+        let item = self.watchlist.iter().find(|item| item.symbol == symbol)?;
+        returns::relative_strength(item.data_for(Interval::D1)?, self.benchmark_data(benchmark, Interval::D1)?, days)
+    }
+
+    /// Indice à charger pour le graphique du ticker sélectionné
+    ///
+    /// None si l'indice est désactivé ou déjà disponible à cet intervalle
+    pub fn missing_benchmark(&self) -> Option<(String, Interval)> {
+        let item = self.selected_item()?;
+        if !self.benchmark_enabled(&item.symbol) {
+            return None;
+        }
+        let benchmark = self.benchmark_symbol(&item.symbol);
+        self.benchmark_data(benchmark, self.current_interval)
+            .is_none()
+            .then(|| (benchmark.to_string(), self.current_interval))
+    }
+
+    // ========================================================================
+    // Statistiques de séance (intraday)
+    // ========================================================================
+
+    /// Active/désactive le panneau des statistiques de séance
+    pub fn toggle_session_stats(&mut self) {
+        self.session_stats_visible = !self.session_stats_visible;
+    }
+
+    /// Statistiques de la séance en cours du ticker sélectionné
+    ///
+    /// None sur un intervalle journalier ou plus long
+    pub fn selected_session_stats(&self) -> Option<SessionStats> {
+        let item = self.selected_item()?;
+        session_stats(item.data.as_ref()?, item.data_for(Interval::D1))
+    }
+
+    // ========================================================================
+    // Order Book (carnet d'ordres crypto)
+    // ========================================================================
+
+    /// Vérifie si le ticker sélectionné a un carnet d'ordres (paire crypto)
+    pub fn selected_has_order_book(&self) -> bool {
+        self.selected_item()
+            .is_some_and(|item| binance_symbol(&item.symbol).is_some())
+    }
+
+    /// Active/désactive le panneau du carnet d'ordres
+    pub fn toggle_order_book(&mut self) {
+        self.order_book_visible = !self.order_book_visible;
+        self.order_book_requested_at = None; // Force un fetch immédiat
+    }
+
+    /// Indique s'il faut (re)demander le carnet d'ordres
+    ///
+    /// CONCEPT : Polling périodique
+    /// - Seulement si le panneau est visible sur la vue graphique
+    /// - Et si la dernière demande date de plus de ORDER_BOOK_REFRESH
+    /// - Jamais hors ligne (la sonde de connectivité prend le relais)
+    pub fn order_book_due(&self) -> bool {
+        !self.is_offline()
+            && self.order_book_visible
+            && self.is_on_chart()
+            && self.selected_has_order_book()
+            && self
+                .order_book_requested_at
+                .is_none_or(|at| at.elapsed() >= ORDER_BOOK_REFRESH)
+    }
+
+    /// Mémorise l'instant de la demande de carnet d'ordres
+    pub fn mark_order_book_requested(&mut self) {
+        self.order_book_requested_at = Some(Instant::now());
+    }
+
+    /// Retourne le carnet d'ordres s'il correspond au ticker sélectionné
+    pub fn selected_order_book(&self) -> Option<&OrderBook> {
+        let symbol = &self.selected_item()?.symbol;
+        self.order_book.as_ref().filter(|book| &book.symbol == symbol)
+    }
+
+    /// Demande la confirmation de quitter
+    ///
+    /// CONCEPT : Two-step quit pattern
+    /// - Appelé lors de la première pression de 'q'
+    /// - Active l'état confirm_quit pour attendre une seconde pression
+    /// - Évite les sorties accidentelles
+    pub fn request_quit(&mut self) {
+        self.confirm_quit = true;
+    }
+
+    /// Annule la demande de quit
+    ///
+    /// CONCEPT : Reset de l'état de confirmation
+    /// - Appelé quand l'utilisateur presse une touche autre que 'q'
+    /// - Remet confirm_quit à false
+    pub fn cancel_quit(&mut self) {
+        self.confirm_quit = false;
+    }
+
+    /// Vérifie si on attend la confirmation de quit
+    pub fn is_awaiting_quit_confirmation(&self) -> bool {
+        self.confirm_quit
+    }
+
+    /// Raison de confirmer avant de quitter (None : rien ne serait perdu)
+    ///
+    /// - Chargement visible en cours (ajout de ticker, rechargement, ...)
+    /// - Journal ou portefeuille non sauvegardé
+    ///
+    /// Les rafraîchissements silencieux ne comptent pas : ils reprennent au
+    /// prochain lancement
+    pub fn quit_blocker(&self) -> Option<String> {
+        if self.is_loading {
+            let message = self.loading_message.as_deref().unwrap_or("Chargement en cours...");
+            Some(format!("Téléchargement en cours : {}", message))
+        } else if self.unsaved_portfolio {
+            Some("Journal ou portefeuille non sauvegardé (échec d'écriture)".to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Démarre le chargement avec un message optionnel
+    ///
+    /// CONCEPT : Loading state management
+    /// - Active is_loading pour afficher l'indicateur
+    /// - Stocke le message pour l'utilisateur
+    pub fn start_loading(&mut self, message: Option<String>) {
+        self.is_loading = true;
+        self.loading_message = message;
+    }
+
+    /// Termine le chargement
+    pub fn stop_loading(&mut self) {
+        self.is_loading = false;
+        self.loading_message = None;
+    }
+
+    /// Vérifie si des données sont en cours de chargement
+    pub fn is_loading_data(&self) -> bool {
+        self.is_loading
+    }
+
+    // ========================================================================
+    // Notifications (toasts)
+    // ========================================================================
+
+    /// Affiche une notification d'information
+    pub fn show_toast(&mut self, message: String) {
+        let toast = Toast {
+            message,
+            is_error: false,
+            created_at: Instant::now(),
+        };
+        self.last_toast = Some(toast.clone());
+        self.toast = Some(toast);
+    }
+
+    /// Affiche une notification d'erreur
+    pub fn show_error_toast(&mut self, message: String) {
+        let toast = Toast {
+            message,
+            is_error: true,
+            created_at: Instant::now(),
+        };
+        self.last_toast = Some(toast.clone());
+        self.toast = Some(toast);
+    }
+
+    /// Retourne la notification active (None si aucune ou expirée)
+    pub fn active_toast(&self) -> Option<&Toast> {
+        self.toast
+            .as_ref()
+            .filter(|toast| toast.created_at.elapsed() < TOAST_DURATION)
+    }
+
+    // ========================================================================
+    // Alertes (bandeau persistant)
+    // ========================================================================
+
+    /// Ajoute une alerte au bandeau si elle n'a jamais été déclenchée
+    pub fn push_notification(&mut self, notification: Notification) {
+        if self.fired_alerts.insert(notification.key.clone()) {
+            self.notifications.push(notification);
+        }
+    }
+
+    /// Déclenche les alertes "résultats demain" (si activées dans la config)
+    pub fn check_earnings_alerts(&mut self, today: chrono::NaiveDate) {
+        if !self.config.earnings_alert {
+            return;
+        }
+
+        for notification in earnings_alerts(&self.watchlist, today) {
+            self.push_notification(notification);
+        }
+    }
+
+    /// Ajoute une règle d'alerte (condition déjà validée)
+    pub fn add_alert_rule(&mut self, symbol: &str, condition: Condition, recurrence: Recurrence, webhook: Option<String>) {
+        let mut rule = AlertRule::new(symbol, condition);
+        rule.recurrence = recurrence;
+        rule.webhook = webhook;
+        self.alert_book.rules.push(rule);
+    }
+
+    /// Évalue les règles d'alerte sur les dernières données
+    ///
+    /// Les alertes déclenchées sont ajoutées au bandeau et retournées pour
+    /// que l'appelant puisse les journaliser, appeler leurs webhooks et
+    /// lancer leurs commandes (sauf pendant les heures calmes)
+    pub fn evaluate_alerts(&mut self) -> Vec<AlertTrigger> {
+        let disabled = self.alert_book.rules.iter().filter(|rule| rule.disabled).count();
+        let mut triggers = evaluate_rules(&mut self.alert_book.rules, &self.watchlist, &mut self.alert_states, Utc::now());
+        for trigger in &mut triggers {
+            // Commande par défaut de la configuration si la règle n'en a pas
+            if trigger.command.is_none() {
+                trigger.command = self.config.alert_command.clone();
+            }
+            self.push_notification(trigger.notification());
+        }
+        // Heures calmes : alertes dans le bandeau seulement
+        if self.is_quiet_time(Local::now().time()) {
+            for trigger in &mut triggers {
+                trigger.webhook = None;
+                trigger.command = None;
+            }
+        }
+        // Règle "once" déclenchée : désactivée dans alerts.json
+        if self.alert_book.rules.iter().filter(|rule| rule.disabled).count() != disabled {
+            self.alert_book_dirty = true;
+        }
+        triggers
+    }
+
+    /// Indique (une seule fois) si les règles d'alerte ont changé depuis la
+    /// dernière sauvegarde
+    pub fn take_alert_book_changes(&mut self) -> bool {
+        std::mem::take(&mut self.alert_book_dirty)
+    }
+
+    /// Met en snooze la règle de l'alerte affichée et ferme l'alerte
+    ///
+    /// La règle se tait pendant config.alert_snooze_minutes
+    /// Retourne la fin du snooze (None si l'alerte ne vient pas d'une règle)
+    pub fn snooze_notification(&mut self) -> Option<DateTime<Utc>> {
+        let key = self.current_notification()?.rule.clone()?;
+        let until = Utc::now() + chrono::Duration::minutes(self.config.alert_snooze_minutes as i64);
+
+        let rule = self.alert_book.rules.iter_mut().find(|rule| rule.key() == key)?;
+        rule.snoozed_until = Some(until);
+        self.alert_book_dirty = true;
+
+        // Les autres alertes de la même règle encore affichées sont fermées aussi
+        self.notifications.retain(|notification| notification.rule.as_deref() != Some(key.as_str()));
+        Some(until)
+    }
+
+    /// Alerte la plus ancienne encore affichée
+    pub fn current_notification(&self) -> Option<&Notification> {
+        self.notifications.first()
+    }
+
+    /// Ferme l'alerte affichée (la suivante prend sa place)
+    pub fn dismiss_notification(&mut self) {
+        if !self.notifications.is_empty() {
+            self.notifications.remove(0);
+        }
+    }
+
+    // ========================================================================
+    // Input Mode Management
+    // ========================================================================
+
+    /// Entre en mode input avec un prompt donné
+    ///
+    /// CONCEPT : Modal input (Vim-like)
+    /// - Change l'écran vers InputMode
+    /// - Initialise le buffer vide
+    /// - Configure le prompt à afficher
+    pub fn start_input(&mut self, prompt: String) {
+        self.current_screen = Screen::InputMode;
+        self.input_buffer.clear();
+        self.input_prompt = prompt;
+        self.input_purpose = InputPurpose::AddTicker;
+    }
+
+    /// Entre en mode input pour saisir la position du ticker sélectionné
+    ///
+    /// Le buffer est prérempli avec la quantité actuelle (si détenue)
+    pub fn start_position_input(&mut self) {
+        let Some(symbol) = self.selected_item().map(|item| item.symbol.clone()) else {
+            return;
+        };
+
+        self.current_screen = Screen::InputMode;
+        self.input_prompt = format!("Quantité détenue ({}, 0 = aucune) : ", symbol);
+        self.input_buffer = self
+            .portfolio
+            .position(&symbol)
+            .map(|p| p.quantity.to_string())
+            .unwrap_or_default();
+        self.input_purpose = InputPurpose::SetPosition { symbol };
+    }
+
+    /// Entre en mode input pour saisir une transaction sur le ticker sélectionné
+    pub fn start_transaction_input(&mut self) {
+        let Some(symbol) = self.selected_item().map(|item| item.symbol.clone()) else {
+            return;
+        };
+
+        self.current_screen = Screen::InputMode;
+        self.input_prompt = format!("Transaction {} (+qté prix = achat, -qté prix = vente) : ", symbol);
+        self.input_buffer.clear();
+        self.input_purpose = InputPurpose::RecordTransaction { symbol };
+    }
+
+    /// Entre en mode input pour saisir un dépôt ou un retrait de liquidités
+    pub fn start_cash_input(&mut self) {
+        self.current_screen = Screen::InputMode;
+        self.input_prompt = "Liquidités (+montant devise = dépôt, -montant devise = retrait, ex: +1000 EUR) : ".to_string();
+        self.input_buffer.clear();
+        self.input_purpose = InputPurpose::RecordCash;
+    }
+
+    /// Entre en mode input pour saisir une commande (':')
+    ///
+    /// La validation ou l'annulation ramène à l'écran courant
+    pub fn start_command_input(&mut self) {
+        let from = match self.current_screen {
+            Screen::InputMode => return,
+            ref screen => screen.clone(),
+        };
+        self.current_screen = Screen::InputMode;
+        self.input_prompt = ":".to_string();
+        self.input_buffer.clear();
+        self.input_purpose = InputPurpose::Command { from };
+    }
+
+    /// Entre en mode input pour saisir une alerte sur le ticker sélectionné
+    pub fn start_alert_input(&mut self) {
+        let Some(symbol) = self.selected_item().map(|item| item.symbol.clone()) else {
+            return;
+        };
+
+        self.current_screen = Screen::InputMode;
+        self.input_prompt = format!("Alerte {} (ex: RSI(14) < 30, price > 200 once, price crosses SMA50 rearm 2%) : ", symbol);
+        self.input_buffer.clear();
+        self.input_purpose = InputPurpose::AddAlert { symbol };
+    }
+
+    /// Entre en mode input pour modifier les tags du ticker sélectionné
+    pub fn start_tags_input(&mut self) {
+        let Some(symbol) = self.selected_item().map(|item| item.symbol.clone()) else {
+            return;
+        };
+
+        self.current_screen = Screen::InputMode;
+        self.input_prompt = format!("Tags de {} (ex: dividend ai long, vide = aucun) : ", symbol);
+        self.input_buffer = self.tags.of(&symbol).join(" ");
+        self.input_purpose = InputPurpose::SetTags { symbol };
+    }
+
+    /// Entre en mode input pour modifier la note du ticker sélectionné
+    pub fn start_note_input(&mut self) {
+        let Some(symbol) = self.selected_item().map(|item| item.symbol.clone()) else {
+            return;
+        };
+
+        self.current_screen = Screen::InputMode;
+        self.input_prompt = format!("Note sur {} (vide = aucune) : ", symbol);
+        self.input_buffer = self.notes.of(&symbol).unwrap_or_default().to_string();
+        self.input_purpose = InputPurpose::SetNote { symbol };
+    }
+
+    /// Entre en mode input pour annoter le chandelier sous le curseur de mesure
+    ///
+    /// Une annotation déjà posée dans ce chandelier (à cet intervalle ou à un
+    /// plus fin) est modifiée plutôt que doublée
+    ///
+    /// Retourne false si l'outil de mesure est inactif
+    pub fn start_annotation_input(&mut self) -> bool {
+        let Some(cursor) = self.measure else {
+            return false;
+        };
+        let Some((symbol, interval, start)) = self.selected_item().and_then(|item| {
+            let data = item.data.as_ref()?;
+            Some((item.symbol.clone(), data.interval, data.candles.get(cursor.cursor)?.timestamp))
+        }) else {
+            return false;
+        };
+
+        let end = start + chrono::Duration::minutes(interval.minutes());
+        let (timestamp, text) = match self.annotations.within(&symbol, start, end) {
+            Some(annotation) => (annotation.timestamp, annotation.text.clone()),
+            None => (start, String::new()),
+        };
+
+        let date_format = if interval.is_intraday() { "%d/%m %H:%M" } else { "%d/%m/%Y" };
+        self.current_screen = Screen::InputMode;
+        self.input_prompt = format!("Annotation {} {} (vide = aucune) : ", symbol, start.format(date_format));
+        self.input_buffer = text;
+        self.input_purpose = InputPurpose::AddAnnotation { symbol, timestamp };
+        true
+    }
+
+    /// Écran affiché à la sortie du mode input
+    ///
+    /// Annotations et liquidités reviennent à l'écran d'où elles ont été
+    /// saisies, le reste au dashboard
+    fn input_return_screen(&self) -> Screen {
+        match self.input_purpose {
+            InputPurpose::AddAnnotation { .. } => Screen::ChartView,
+            InputPurpose::RecordCash => Screen::Allocation,
+            InputPurpose::Command { ref from } => from.clone(),
+            _ => Screen::Dashboard,
+        }
+    }
+
+    /// Annule le mode input et retourne à l'écran de départ
+    pub fn cancel_input(&mut self) {
+        self.current_screen = self.input_return_screen();
+        self.input_buffer.clear();
+        self.input_prompt.clear();
+    }
+
+    /// Récupère la valeur saisie et retourne à l'écran de départ
+    ///
+    /// CONCEPT : Consume input
+    /// - Retourne le contenu du buffer
+    /// - Vide le buffer
+    /// - Retourne au dashboard (ou à l'écran de la saisie)
+    pub fn submit_input(&mut self) -> String {
+        let value = self.input_buffer.clone();
+        self.current_screen = self.input_return_screen();
+        self.input_buffer.clear();
+        self.input_prompt.clear();
+        value
+    }
+
+    /// Ajoute un caractère au buffer d'input
+    pub fn append_char(&mut self, c: char) {
+        self.input_buffer.push(c);
+    }
+
+    /// Supprime le dernier caractère du buffer
+    pub fn backspace(&mut self) {
+        self.input_buffer.pop();
+    }
+
+    /// Vérifie si on est en mode input
+    pub fn is_in_input_mode(&self) -> bool {
+        self.current_screen == Screen::InputMode
+    }
+
+    /// Vérifie si le bandeau des indices doit être (re)chargé
+    ///
+    /// Places toutes fermées : ralenti comme la watchlist (voir refresh_due)
+    pub fn market_overview_due(&self) -> bool {
+        if self.is_offline() || self.config.market_overview.is_empty() {
+            return false;
+        }
+        let Some(at) = self.market_overview_requested_at else {
+            return true;
+        };
+        let now = Utc::now();
+        let last = chrono::Duration::from_std(at.elapsed()).ok().map(|elapsed| now - elapsed);
+        at.elapsed() >= MARKET_OVERVIEW_REFRESH
+            && self.config.market_overview.iter().any(|symbol| self.refresh_due(symbol, now, last))
+    }
+
+    /// Vérifie si la watchlist contient au moins une crypto
+    pub fn has_crypto(&self) -> bool {
+        self.watchlist.iter().any(|item| item.ticker_type() == TickerType::Crypto)
+    }
+
+    /// Vérifie si l'indice Fear & Greed doit être (re)chargé
+    ///
+    /// - Seulement avec de la crypto dans la watchlist (jauge affichée)
+    /// - Une fois par jour : la valeur en cache suffit tant que la suivante
+    ///   n'est pas publiée
+    pub fn fear_greed_due(&self) -> bool {
+        if !self.config.fear_greed || self.is_offline() || !self.has_crypto() {
+            return false;
+        }
+        self.fear_greed.is_none_or(|index| index.is_stale(Utc::now()))
+            && self.fear_greed_requested_at.is_none_or(|at| at.elapsed() >= FEAR_GREED_RETRY)
+    }
+
+    /// Note qu'un chargement de l'indice Fear & Greed vient d'être demandé
+    pub fn mark_fear_greed_requested(&mut self) {
+        self.fear_greed_requested_at = Some(Instant::now());
+    }
+
+    /// Statistiques CoinGecko du ticker sélectionné
+    pub fn selected_crypto_stats(&self) -> Option<&CryptoStats> {
+        self.crypto_stats.get(&self.selected_item()?.symbol)
+    }
+
+    /// Vérifie si les statistiques de la crypto sélectionnée doivent être
+    /// (re)chargées
+    ///
+    /// - Seulement sur la fiche détaillée d'une crypto
+    /// - Toutes les crypto_stats::REFRESH : le cache suffit tant qu'il est frais
+    pub fn crypto_stats_due(&self) -> bool {
+        let Some(item) = self.selected_item() else {
+            return false;
+        };
+        if self.is_offline() || !self.is_on_detail() || item.ticker_type() != TickerType::Crypto {
+            return false;
+        }
+        self.crypto_stats.get(&item.symbol).is_none_or(|stats| stats.is_stale(Utc::now()))
+            && self
+                .crypto_stats_requested_at
+                .get(&item.symbol)
+                .is_none_or(|at| at.elapsed() >= crypto_stats::REFRESH)
+    }
+
+    /// Note que les statistiques de la crypto sélectionnée viennent d'être demandées
+    pub fn mark_crypto_stats_requested(&mut self) {
+        if let Some(symbol) = self.selected_item().map(|item| item.symbol.clone()) {
+            self.crypto_stats_requested_at.insert(symbol, Instant::now());
+        }
+    }
+
+    /// Vérifie si la liste distante doit être rechargée
+    /// (config.remote_watchlist, toutes les refresh_minutes)
+    pub fn remote_watchlist_due(&self) -> bool {
+        let Some(remote) = &self.config.remote_watchlist else {
+            return false;
+        };
+        !self.is_offline()
+            && self.remote_watchlist_requested_at.is_none_or(|at| at.elapsed() >= remote.refresh_interval())
+    }
+
+    /// Note qu'un chargement de la liste distante vient d'être demandé
+    pub fn mark_remote_watchlist_requested(&mut self) {
+        self.remote_watchlist_requested_at = Some(Instant::now());
+    }
+
+    /// Vérifie si le calendrier économique doit être rechargé
+    /// (config.economic_calendar, toutes les refresh_minutes ; un fichier
+    /// local se relit même hors ligne)
+    pub fn economic_calendar_due(&self) -> bool {
+        let Some(calendar) = &self.config.economic_calendar else {
+            return false;
+        };
+        (!calendar.is_remote() || !self.is_offline())
+            && self.economic_calendar_requested_at.is_none_or(|at| at.elapsed() >= calendar.refresh_interval())
+    }
+
+    /// Note qu'un chargement du calendrier économique vient d'être demandé
+    pub fn mark_economic_calendar_requested(&mut self) {
+        self.economic_calendar_requested_at = Some(Instant::now());
+    }
+
+    /// Publications du jour (heure locale), dans l'ordre chronologique
+    pub fn todays_economic_events(&self) -> Vec<&EconomicEvent> {
+        economic_calendar::events_on(&self.economic_events, Local::now().date_naive())
+    }
+
+    /// Applique une nouvelle version de la liste distante
+    ///
+    /// - Les symboles retirés de la liste quittent la watchlist, sauf ceux
+    ///   de config.watchlist (suivis localement)
+    /// - Retourne les symboles à ajouter (absents de la watchlist)
+    pub fn apply_remote_watchlist(&mut self, symbols: Vec<String>) -> Vec<String> {
+        let removed: Vec<String> = self
+            .remote_watchlist
+            .iter()
+            .filter(|symbol| !symbols.contains(symbol) && !self.config.watchlist.contains(symbol))
+            .cloned()
+            .collect();
+        for symbol in &removed {
+            self.remove_symbol(symbol);
+        }
+
+        let added = symbols
+            .iter()
+            .filter(|symbol| !self.watchlist.iter().any(|item| item.symbol == **symbol))
+            .cloned()
+            .collect();
+        self.remote_watchlist = symbols;
+        added
+    }
+
+    /// Vérifie si un ticker est à recharger selon les heures de son marché
+    ///
+    /// Sans config.market_hours : toujours
+    pub fn refresh_due(&self, symbol: &str, now: DateTime<Utc>, last_refresh: Option<DateTime<Utc>>) -> bool {
+        if !self.config.market_hours {
+            return true;
+        }
+        let closed_every = match self.config.closed_refresh_minutes {
+            0 => None,
+            minutes => Some(chrono::Duration::minutes(minutes as i64)),
+        };
+        schedule::refresh_due(symbol, now, last_refresh, closed_every)
+    }
+
+    // ========================================================================
+    // Rafraîchissement automatique (config.auto_refresh_seconds)
+    // ========================================================================
+    // CONCEPT : File de priorité tenue par l'App
+    // - Un seul rechargement automatique à la fois : la file du worker ne se
+    //   remplit pas, la priorité est recalculée à chaque envoi
+    // - Ordre : ticker sélectionné, puis lignes visibles (filtre de tags),
+    //   puis lignes masquées ; dans chaque rang, le plus ancien d'abord
+    // - Lignes masquées : période AUTO_REFRESH_HIDDEN_FACTOR fois plus longue
+    // - Sous limitation de débit (429), les requêtes vont en priorité aux
+    //   données que l'utilisateur regarde
+
+    /// Vérifie si le dernier rafraîchissement automatique attend son résultat
+    pub fn auto_refresh_in_flight(&self) -> bool {
+        self.auto_refresh_request
+            .as_ref()
+            .is_some_and(|(symbol, request_id)| self.pending_loads.get(symbol) == Some(request_id))
+    }
+
+    /// Démarre le prochain rafraîchissement automatique s'il y en a un à faire
+    ///
+    /// Retourne (symbole, intervalle, identifiant de demande) à envoyer au
+    /// worker ; None si désactivé, hors ligne, déjà en cours ou rien d'échu
+    pub fn start_auto_refresh(&mut self, now: DateTime<Utc>) -> Option<(String, Interval, u64)> {
+        if self.config.auto_refresh_seconds == 0 || self.is_offline() || self.auto_refresh_in_flight() {
+            return None;
+        }
+
+        // Ticker jamais rafraîchi : ses données viennent d'être chargées
+        for item in &self.watchlist {
+            self.auto_refreshed_at.entry(item.symbol.clone()).or_insert(now);
+        }
+
+        let index = self.auto_refresh_candidate(now)?;
+        let (symbol, interval) = (self.watchlist[index].symbol.clone(), self.watchlist[index].interval());
+        let request_id = self.begin_load(&symbol);
+        self.auto_refreshed_at.insert(symbol.clone(), now);
+        self.auto_refresh_request = Some((symbol.clone(), request_id));
+        Some((symbol, interval, request_id))
+    }
+
+    /// Index du ticker le plus prioritaire dont le rafraîchissement est échu
+    ///
+    /// Un ticker dont un chargement est déjà en file (changement d'intervalle,
+    /// rechargement demandé) est ignoré : une nouvelle demande remplacerait
+    /// celle de l'utilisateur (voir begin_load)
+    fn auto_refresh_candidate(&self, now: DateTime<Utc>) -> Option<usize> {
+        let period = chrono::Duration::seconds(self.config.auto_refresh_seconds as i64);
+        let visible = self.visible_rows();
+
+        self.watchlist
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !self.pending_loads.contains_key(&item.symbol))
+            .filter_map(|(index, item)| {
+                let rank = if index == self.selected_index {
+                    0
+                } else if visible.contains(&index) {
+                    1
+                } else {
+                    2
+                };
+                let every = if rank == 2 { period * AUTO_REFRESH_HIDDEN_FACTOR } else { period };
+                let last = self.auto_refreshed_at.get(&item.symbol).copied();
+                let stale = last.is_none_or(|at| now - at >= every);
+                (stale && self.refresh_due(&item.symbol, now, last)).then_some((rank, last, index))
+            })
+            .min_by_key(|(rank, last, _)| (*rank, *last))
+            .map(|(_, _, index)| index)
+    }
+
+    /// Vérifie si l'heure locale tombe dans les heures calmes
+    pub fn is_quiet_time(&self, time: chrono::NaiveTime) -> bool {
+        self.config.quiet_hours.is_some_and(|quiet| quiet.contains(time))
+    }
+
+    /// Note qu'un rafraîchissement du bandeau des indices vient d'être demandé
+    pub fn mark_market_overview_requested(&mut self) {
+        self.market_overview_requested_at = Some(Instant::now());
+    }
+
+    // ========================================================================
+    // Connectivité
+    // ========================================================================
+
+    /// Indique si la dernière sonde a échoué
+    pub fn is_offline(&self) -> bool {
+        self.offline_since.is_some()
+    }
+
+    /// Indique s'il faut sonder le réseau (plus souvent hors ligne)
+    pub fn connectivity_due(&self) -> bool {
+        let period = if self.is_offline() { OFFLINE_PROBE } else { CONNECTIVITY_PROBE };
+        self.connectivity_probed_at.is_none_or(|at| at.elapsed() >= period)
+    }
+
+    /// Mémorise l'instant de la sonde de connectivité
+    pub fn mark_connectivity_probed(&mut self) {
+        self.connectivity_probed_at = Some(Instant::now());
+    }
+
+    /// Applique le résultat d'une sonde
+    ///
+    /// Retourne true au retour du réseau : l'appelant relance alors les
+    /// rafraîchissements suspendus
+    pub fn set_online(&mut self, online: bool) -> bool {
+        match (online, self.offline_since.is_some()) {
+            (true, true) => {
+                self.offline_since = None;
+                // Bandeau des indices (et Fear & Greed) rechargés au prochain tour de boucle
+                self.market_overview_requested_at = None;
+                self.fear_greed_requested_at = None;
+                self.crypto_stats_requested_at.clear();
+                self.remote_watchlist_requested_at = None;
+                self.economic_calendar_requested_at = None;
+                true
+            }
+            (false, false) => {
+                self.offline_since = Some(Local::now());
+                false
+            }
+            _ => false,
+        }
+    }
+
+    // ========================================================================
+    // Portfolio / Journal
+    // ========================================================================
+
+    /// Enregistre une transaction et aligne la position sur le journal
+    ///
+    /// CONCEPT : Le journal fait foi
+    /// - La quantité détenue devient la quantité nette des transactions
+    /// - Les soldes de liquidités suivent les achats et ventes
+    /// - La persistance est à la charge de l'appelant (main.rs)
+    pub fn record_transaction(&mut self, transaction: Transaction) {
+        let symbol = transaction.symbol.clone();
+        self.journal.record(transaction);
+        let quantity = self.journal.net_quantity(&symbol);
+        self.portfolio.set_position(&symbol, quantity);
+        self.portfolio.set_cash(self.journal.cash_balances());
+    }
+
+    /// Enregistre un dépôt ou un retrait et aligne les liquidités sur le journal
+    pub fn record_cash_movement(&mut self, movement: CashMovement) {
+        self.journal.record_cash(movement);
+        self.portfolio.set_cash(self.journal.cash_balances());
+    }
+
+    /// Prix actuel d'un symbole de la watchlist
+    fn current_price_of(&self, symbol: &str) -> Option<f64> {
+        self.watchlist
+            .iter()
+            .find(|item| item.symbol == symbol)?
+            .current_price()
+    }
+
+    /// P&L de toutes les positions du journal (méthode de la configuration)
+    pub fn pnl(&self) -> Vec<PositionPnl> {
+        self.journal
+            .pnl(self.config.cost_basis, |symbol| self.current_price_of(symbol))
+    }
+
+    /// P&L d'un symbole (None s'il n'a aucune transaction)
+    pub fn position_pnl(&self, symbol: &str) -> Option<PositionPnl> {
+        let mut transactions = self.journal.for_symbol(symbol).peekable();
+        transactions.peek()?;
+        Some(position_pnl(
+            symbol,
+            transactions,
+            self.config.cost_basis,
+            self.current_price_of(symbol),
+        ))
+    }
+
+    // ========================================================================
+    // Delete Confirmation Management
+    // ========================================================================
+
+    /// Demande la confirmation de suppression
+    ///
+    /// CONCEPT : Two-step delete pattern
+    /// - Appelé lors de la première pression de 'd'
+    /// - Active l'état confirm_delete pour attendre une seconde pression
+    /// - Évite les suppressions accidentelles
+    pub fn request_delete(&mut self) {
+        self.confirm_delete = true;
+    }
+
+    /// Annule la demande de suppression
+    pub fn cancel_delete(&mut self) {
+        self.confirm_delete = false;
+    }
+
+    /// Vérifie si on attend la confirmation de suppression
+    pub fn is_awaiting_delete_confirmation(&self) -> bool {
+        self.confirm_delete
+    }
+
+    /// Supprime l'item sélectionné de la watchlist
     ///
     /// CONCEPT : Safe deletion
     /// - Supprime l'item à selected_index
@@ -377,7 +2106,10 @@ impl App {
     /// - Reset confirm_delete
     pub fn delete_selected(&mut self) {
         if self.selected_index < self.watchlist.len() {
-            self.watchlist.remove(self.selected_index);
+            let item = self.watchlist.remove(self.selected_index);
+            // Un chargement en cours ne doit pas viser un ticker ajouté ensuite
+            self.pending_loads.remove(&item.symbol);
+            self.auto_refreshed_at.remove(&item.symbol);
 
             // Ajuste l'index si on a supprimé le dernier élément
             if self.selected_index >= self.watchlist.len() && self.selected_index > 0 {
@@ -387,6 +2119,21 @@ impl App {
 
         self.confirm_delete = false;
     }
+
+    /// Retire un ticker de la watchlist (sans confirmation), la sélection
+    /// reste sur le même item si possible
+    pub fn remove_symbol(&mut self, symbol: &str) {
+        let Some(index) = self.watchlist.iter().position(|item| item.symbol == symbol) else {
+            return;
+        };
+        self.watchlist.remove(index);
+        self.pending_loads.remove(symbol);
+        self.auto_refreshed_at.remove(symbol);
+
+        if index < self.selected_index || (self.selected_index >= self.watchlist.len() && self.selected_index > 0) {
+            self.selected_index -= 1;
+        }
+    }
 }
 
 // ============================================================================
@@ -413,6 +2160,7 @@ impl Default for App {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::dividends::DividendInfo;
 
     #[test]
     fn test_app_creation() {
@@ -491,4 +2239,705 @@ mod tests {
         let selected = app.selected_item().unwrap();
         assert_eq!(selected.symbol, "AAPL");
     }
+
+    #[test]
+    fn test_retry_selected_only_after_error() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string()),
+            WatchlistItem::new("TSLA".to_string(), "Tesla".to_string()),
+        ]);
+
+        // Pas d'erreur : rien à réessayer
+        assert_eq!(app.retry_selected(), None);
+
+        app.watchlist[1].load_error = Some("HTTP 500".to_string());
+        app.navigate_down();
+        assert_eq!(app.retry_selected(), Some("TSLA".to_string()));
+        assert_eq!(app.watchlist[1].load_error, None);
+    }
+
+    #[test]
+    fn test_auto_refresh_follows_focus() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string()),
+            WatchlistItem::new("MSFT".to_string(), "Microsoft".to_string()),
+            WatchlistItem::new("TSLA".to_string(), "Tesla".to_string()),
+        ]);
+        app.config.market_hours = false;
+        let t0 = Utc::now();
+        assert_eq!(app.start_auto_refresh(t0), None);
+
+        app.config.auto_refresh_seconds = 60;
+        assert_eq!(app.start_auto_refresh(t0), None);
+
+        // Ticker sélectionné d'abord, un seul rechargement à la fois
+        app.navigate_down();
+        let t1 = t0 + chrono::Duration::seconds(61);
+        let (symbol, _, request_id) = app.start_auto_refresh(t1).unwrap();
+        assert_eq!(symbol, "MSFT");
+        assert_eq!(app.start_auto_refresh(t1), None);
+        app.resolve_load(&symbol, request_id);
+
+        // Puis les lignes visibles ; TSLA, masqué par le filtre, est différé
+        app.tags.set("AAPL", vec!["long".to_string()]);
+        app.tags.set("MSFT", vec!["long".to_string()]);
+        app.tag_filter = Some("long".to_string());
+        let (symbol, _, request_id) = app.start_auto_refresh(t1).unwrap();
+        assert_eq!(symbol, "AAPL");
+        app.resolve_load(&symbol, request_id);
+        assert_eq!(app.start_auto_refresh(t1), None);
+        let t2 = t0 + chrono::Duration::seconds(4 * 60);
+        let mut order = Vec::new();
+        while let Some((symbol, _, request_id)) = app.start_auto_refresh(t2) {
+            app.resolve_load(&symbol, request_id);
+            order.push(symbol);
+        }
+        assert_eq!(order, ["MSFT", "AAPL", "TSLA"]);
+    }
+
+    #[test]
+    fn test_auto_refresh_keeps_pending_user_reload() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string()),
+            WatchlistItem::new("MSFT".to_string(), "Microsoft".to_string()),
+        ]);
+        app.config.market_hours = false;
+        app.config.auto_refresh_seconds = 60;
+        let t0 = Utc::now();
+        assert_eq!(app.start_auto_refresh(t0), None);
+
+        // Changement d'intervalle sur AAPL : rechargement utilisateur en file
+        let user_request = app.begin_load("AAPL");
+        let t1 = t0 + chrono::Duration::seconds(61);
+        let (symbol, _, request_id) = app.start_auto_refresh(t1).unwrap();
+        assert_eq!(symbol, "MSFT");
+        app.resolve_load(&symbol, request_id);
+        assert_eq!(app.start_auto_refresh(t1), None);
+
+        // Le résultat de l'utilisateur reste celui appliqué
+        assert!(app.resolve_load("AAPL", user_request).is_some());
+        assert_eq!(app.start_auto_refresh(t1).map(|(symbol, _, _)| symbol), Some("AAPL".to_string()));
+    }
+
+    #[test]
+    fn test_load_results_are_keyed_by_symbol() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string()),
+            WatchlistItem::new("TSLA".to_string(), "Tesla".to_string()),
+        ]);
+
+        // AAPL supprimé pendant le chargement de TSLA : le résultat suit le symbole
+        let tsla = app.begin_load("TSLA");
+        let aapl = app.begin_load("AAPL");
+        app.delete_selected();
+        assert_eq!(app.resolve_load("TSLA", tsla).map(|item| item.symbol.clone()), Some("TSLA".to_string()));
+        assert!(app.resolve_load("AAPL", aapl).is_none());
+
+        // Deux demandes successives : seule la dernière est appliquée
+        let first = app.begin_load("TSLA");
+        let second = app.begin_load("TSLA");
+        assert!(app.resolve_load("TSLA", first).is_none());
+        assert!(app.resolve_load("TSLA", second).is_some());
+        assert!(app.resolve_load("TSLA", second).is_none());
+    }
+
+    #[test]
+    fn test_leaderboard_navigation() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string()),
+            WatchlistItem::new("TSLA".to_string(), "Tesla".to_string()),
+        ]);
+        app.show_leaderboard();
+        assert!(app.is_on_leaderboard());
+
+        app.switch_leaderboard_period(false);
+        assert_eq!(app.leaderboard_period, ReturnPeriod::YearToDate);
+        app.switch_leaderboard_period(true);
+        assert_eq!(app.leaderboard_period, ReturnPeriod::OneDay);
+
+        app.leaderboard_down();
+        app.leaderboard_down();
+        assert_eq!(app.leaderboard_index, 1);
+        assert!(app.select_leaderboard_entry());
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn test_dividends_navigation() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string()),
+            WatchlistItem::new("KO".to_string(), "Coca-Cola".to_string()),
+            WatchlistItem::new("BTC-USD".to_string(), "Bitcoin".to_string()),
+        ]);
+        assert_eq!(app.dividends_to_load(), vec!["AAPL".to_string(), "KO".to_string()]);
+
+        let in_days = |days| Some(Local::now().date_naive() + chrono::Duration::days(days));
+        app.watchlist[0].dividends = Some(DividendInfo { ex_date: None, payment_date: None, amount: None });
+        app.watchlist[1].dividends = Some(DividendInfo { ex_date: in_days(3), payment_date: in_days(20), amount: Some(0.49) });
+        assert!(app.dividends_to_load().is_empty());
+
+        app.show_dividends();
+        assert!(app.is_on_dividends());
+        assert_eq!(app.dividend_calendar().len(), 2);
+
+        app.dividends_down();
+        app.dividends_down();
+        assert_eq!(app.dividends_index, 1);
+        assert!(app.select_dividend_entry());
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn test_compare_selection() {
+        let items: Vec<WatchlistItem> = (0..10)
+            .map(|i| WatchlistItem::new(format!("T{}", i), format!("Ticker {}", i)))
+            .collect();
+        let mut app = App::with_watchlist(items);
+        app.selected_index = 2;
+
+        // Première ouverture : ticker sélectionné déjà comparé, curseur dessus
+        app.show_compare();
+        assert!(app.is_on_compare());
+        assert_eq!(app.compare_index, 2);
+        assert_eq!(app.compare_symbols, vec!["T2".to_string()]);
+
+        // Espace sur un ticker comparé : retiré
+        assert!(app.toggle_compare_selection());
+        assert!(app.compare_symbols.is_empty());
+
+        // Au plus MAX_COMPARED tickers
+        app.compare_index = 0;
+        for _ in 0..MAX_COMPARED {
+            assert!(app.toggle_compare_selection());
+            app.compare_down();
+        }
+        assert!(!app.toggle_compare_selection());
+        assert_eq!(app.compared_items().count(), MAX_COMPARED);
+
+        app.switch_compare_period(false);
+        assert_eq!(app.compare_period, ReturnPeriod::OneWeek);
+        app.switch_compare_period(false);
+        assert_eq!(app.compare_period, ReturnPeriod::YearToDate);
+        assert!(app.compare_start().is_none());
+    }
+
+    #[test]
+    fn test_toggle_split_view() {
+        let mut app = App::new();
+        app.current_interval = Interval::D1;
+
+        app.toggle_split_view();
+        assert!(app.split_view);
+        assert_eq!(app.secondary_interval, Interval::H1);
+
+        app.next_secondary_interval();
+        assert_eq!(app.secondary_interval, Interval::M90);
+
+        app.toggle_split_view();
+        assert!(!app.split_view);
+    }
+
+    #[test]
+    fn test_benchmark_toggle_and_cache() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string()),
+            WatchlistItem::new("AIR.PA".to_string(), "Airbus SE".to_string()),
+            WatchlistItem::new("SPY".to_string(), "SPDR S&P 500".to_string()),
+        ]);
+        app.config.benchmarks.insert("AIR.PA".to_string(), "^FCHI".to_string());
+        app.current_interval = Interval::D1;
+
+        // Désactivé par défaut, activé d'emblée par config.benchmarks
+        assert!(!app.benchmark_enabled("AAPL"));
+        assert!(app.benchmark_enabled("AIR.PA"));
+        assert_eq!(app.benchmark_symbol("AIR.PA"), "^FCHI");
+        assert_eq!(app.missing_benchmark(), None);
+
+        assert_eq!(app.toggle_benchmark(), Some(true));
+        assert_eq!(app.missing_benchmark(), Some(("SPY".to_string(), Interval::D1)));
+        app.cache_benchmark(OHLCData::with_interval("SPY".to_string(), Interval::D1));
+        assert!(app.benchmark_data("SPY", Interval::D1).is_some());
+        assert_eq!(app.missing_benchmark(), None);
+        assert_eq!(app.toggle_benchmark(), Some(false));
+
+        app.selected_index = 1;
+        assert_eq!(app.toggle_benchmark(), Some(false));
+
+        // Jamais contre lui-même
+        app.selected_index = 2;
+        assert_eq!(app.toggle_benchmark(), Some(false));
+    }
+
+    #[test]
+    fn test_chart_opens_at_item_interval() {
+        let items = vec![
+            WatchlistItem::new("BTC-USD".to_string(), "Bitcoin USD".to_string()),
+            WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string()),
+        ];
+        let mut app = App::with_watchlist(items);
+        app.show_chart();
+        assert_eq!(app.current_interval, Interval::M15);
+
+        // Intervalle choisi sur le graphique conservé au retour du backtest
+        app.next_interval();
+        app.show_backtest();
+        app.show_chart();
+        assert_eq!(app.current_interval, Interval::M30);
+
+        app.show_dashboard();
+        app.navigate_down();
+        app.show_chart();
+        assert_eq!(app.current_interval, Interval::D1);
+    }
+
+    #[test]
+    fn test_order_book_due() {
+        let items = vec![
+            WatchlistItem::new("BTC-USD".to_string(), "Bitcoin USD".to_string()),
+            WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string()),
+        ];
+        let mut app = App::with_watchlist(items);
+        app.show_chart();
+        assert!(!app.order_book_due()); // Panneau masqué
+
+        app.toggle_order_book();
+        assert!(app.order_book_due());
+
+        app.mark_order_book_requested();
+        assert!(!app.order_book_due()); // Demande récente
+
+        // Pas de carnet d'ordres pour une action
+        app.order_book_requested_at = None;
+        app.navigate_down();
+        assert!(!app.order_book_due());
+    }
+
+    #[test]
+    fn test_fear_greed_due() {
+        let mut app = App::with_watchlist(vec![WatchlistItem::new("AAPL".to_string(), "Apple".to_string())]);
+        assert!(!app.fear_greed_due()); // Pas de crypto : jauge masquée
+
+        app.watchlist.push(WatchlistItem::new("BTC-USD".to_string(), "Bitcoin".to_string()));
+        assert!(app.fear_greed_due());
+
+        // Valeur du jour en cache : rien à recharger
+        app.fear_greed = Some(FearGreed { value: 40, published_at: Utc::now() - chrono::Duration::hours(2) });
+        assert!(!app.fear_greed_due());
+
+        // Valeur de la veille : rechargée, puis pas avant FEAR_GREED_RETRY
+        app.fear_greed = Some(FearGreed { value: 40, published_at: Utc::now() - chrono::Duration::hours(25) });
+        assert!(app.fear_greed_due());
+        app.mark_fear_greed_requested();
+        assert!(!app.fear_greed_due());
+
+        app.fear_greed_requested_at = None;
+        app.config.fear_greed = false;
+        assert!(!app.fear_greed_due());
+    }
+
+    #[test]
+    fn test_crypto_stats_due() {
+        let mut app = App::with_watchlist(vec![WatchlistItem::new("BTC-USD".to_string(), "Bitcoin".to_string())]);
+        assert!(!app.crypto_stats_due()); // Dashboard : pas de fiche affichée
+
+        app.show_detail();
+        assert!(app.crypto_stats_due());
+        app.mark_crypto_stats_requested();
+        assert!(!app.crypto_stats_due());
+
+        // Cache périmé : rechargé dès que la dernière demande date de REFRESH
+        app.crypto_stats_requested_at.clear();
+        let stats = CryptoStats {
+            coin_id: "bitcoin".to_string(),
+            market_cap: Some(1.35e12),
+            market_cap_rank: Some(1),
+            volume_24h: None,
+            circulating_supply: None,
+            max_supply: None,
+            dominance: None,
+            fetched_at: Utc::now(),
+        };
+        app.crypto_stats.insert("BTC-USD".to_string(), stats.clone());
+        assert!(!app.crypto_stats_due());
+        app.crypto_stats.insert("BTC-USD".to_string(), CryptoStats { fetched_at: Utc::now() - chrono::Duration::hours(1), ..stats });
+        assert!(app.crypto_stats_due());
+
+        let mut stocks = App::with_watchlist(vec![WatchlistItem::new("AAPL".to_string(), "Apple".to_string())]);
+        stocks.show_detail();
+        assert!(!stocks.crypto_stats_due());
+    }
+
+    #[test]
+    fn test_apply_remote_watchlist() {
+        let symbols = |list: &[&str]| list.iter().map(|symbol| symbol.to_string()).collect::<Vec<_>>();
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple".to_string()),
+            WatchlistItem::new("MSFT".to_string(), "Microsoft".to_string()),
+            WatchlistItem::new("NVDA".to_string(), "Nvidia".to_string()),
+        ]);
+        app.config.watchlist = symbols(&["AAPL"]);
+        assert!(!app.remote_watchlist_due()); // Pas de liste distante configurée
+
+        app.config.remote_watchlist = Some(crate::api::remote_watchlist::RemoteWatchlist {
+            url: "https://corp/list.txt".to_string(),
+            refresh_minutes: 60,
+        });
+        assert!(app.remote_watchlist_due());
+        app.mark_remote_watchlist_requested();
+        assert!(!app.remote_watchlist_due());
+
+        // Première liste : seuls les symboles absents sont à ajouter
+        app.remote_watchlist = symbols(&["MSFT", "NVDA"]);
+        app.selected_index = 2;
+        assert_eq!(app.apply_remote_watchlist(symbols(&["AAPL", "NVDA", "TSLA"])), ["TSLA"]);
+
+        // MSFT retiré de la liste distante : il quitte la watchlist, la sélection suit NVDA
+        let remaining: Vec<&str> = app.watchlist.iter().map(|item| item.symbol.as_str()).collect();
+        assert_eq!(remaining, ["AAPL", "NVDA"]);
+        assert_eq!(app.selected_index, 1);
+
+        // AAPL est aussi dans config.watchlist : il reste suivi
+        assert!(app.apply_remote_watchlist(Vec::new()).is_empty());
+        let remaining: Vec<&str> = app.watchlist.iter().map(|item| item.symbol.as_str()).collect();
+        assert_eq!(remaining, ["AAPL"]);
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_economic_calendar_due() {
+        let mut app = App::new();
+        assert!(!app.economic_calendar_due()); // Pas de flux configuré
+
+        app.config.economic_calendar = Some(crate::api::economic_calendar::EconomicCalendar {
+            url: "https://corp/macro.ics".to_string(),
+            refresh_minutes: 360,
+        });
+        assert!(app.economic_calendar_due());
+        app.mark_economic_calendar_requested();
+        assert!(!app.economic_calendar_due());
+
+        // Hors ligne : le flux distant attend le retour du réseau, pas un fichier local
+        app.economic_calendar_requested_at = None;
+        app.set_online(false);
+        assert!(!app.economic_calendar_due());
+        app.config.economic_calendar.as_mut().unwrap().url = "~/macro.ics".to_string();
+        assert!(app.economic_calendar_due());
+    }
+
+    #[test]
+    fn test_market_overview_due() {
+        let mut app = App::new();
+        assert!(app.market_overview_due()); // Indices par défaut, jamais chargés
+
+        app.mark_market_overview_requested();
+        assert!(!app.market_overview_due());
+
+        // Bandeau désactivé dans la configuration
+        app.market_overview_requested_at = None;
+        app.config.market_overview.clear();
+        assert!(!app.market_overview_due());
+    }
+
+    #[test]
+    fn test_select_row() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple".to_string()),
+            WatchlistItem::new("MSFT".to_string(), "Microsoft".to_string()),
+        ]);
+        assert!(app.select_row(1));
+        assert_eq!(app.selected_index, 1);
+        assert!(!app.select_row(2));
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn test_tag_filter() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("AAPL".to_string(), "Apple".to_string()),
+            WatchlistItem::new("NVDA".to_string(), "Nvidia".to_string()),
+            WatchlistItem::new("KO".to_string(), "Coca-Cola".to_string()),
+        ]);
+        app.tags.set("NVDA", vec!["ai".to_string()]);
+        app.tags.set("KO", vec!["dividend".to_string()]);
+        app.tags.set("AAPL", vec!["ai".to_string(), "dividend".to_string()]);
+        app.selected_index = 1;
+
+        // Filtre "ai" : KO masqué, la sélection reste sur NVDA
+        app.cycle_tag_filter();
+        assert_eq!(app.tag_filter.as_deref(), Some("ai"));
+        assert_eq!(app.visible_rows(), vec![0, 1]);
+        assert_eq!(app.selected_index, 1);
+        app.navigate_down();
+        assert_eq!(app.selected_index, 1);
+
+        // Filtre "dividend" : NVDA masqué, la sélection passe sur AAPL
+        app.cycle_tag_filter();
+        assert_eq!(app.visible_rows(), vec![0, 2]);
+        assert_eq!(app.selected_index, 0);
+        app.navigate_down();
+        assert_eq!(app.selected_index, 2);
+        assert!(app.select_row(0));
+        assert_eq!(app.selected_index, 0);
+        assert!(!app.select_row(2));
+
+        // Retour à la watchlist complète
+        app.cycle_tag_filter();
+        assert_eq!(app.tag_filter, None);
+        assert_eq!(app.visible_rows().len(), 3);
+    }
+
+    #[test]
+    fn test_offline_suspends_refreshes() {
+        let mut app = App::new();
+        assert!(app.connectivity_due());
+        app.mark_connectivity_probed();
+        assert!(!app.connectivity_due());
+
+        // Perte du réseau : plus de rafraîchissement périodique
+        assert!(!app.set_online(false));
+        assert!(app.is_offline());
+        assert!(!app.market_overview_due());
+        assert!(!app.set_online(false));
+
+        // Retour du réseau : signalé une seule fois, indices rechargés
+        app.mark_market_overview_requested();
+        assert!(app.set_online(true));
+        assert!(!app.is_offline());
+        assert!(app.market_overview_due());
+        assert!(!app.set_online(true));
+    }
+
+    #[test]
+    fn test_toast_expiration() {
+        let mut app = App::new();
+        assert!(app.active_toast().is_none());
+
+        app.show_toast("Export terminé".to_string());
+        assert_eq!(app.active_toast().unwrap().message, "Export terminé");
+        assert!(!app.active_toast().unwrap().is_error);
+
+        // Simule une notification ancienne : tick() doit l'effacer
+        app.toast.as_mut().unwrap().created_at = Instant::now() - TOAST_DURATION;
+        app.tick();
+        assert!(app.toast.is_none());
+    }
+
+    #[test]
+    fn test_forex_menu_navigation_wraps() {
+        let mut app = App::new();
+        app.show_forex_menu();
+        assert!(app.is_on_forex_menu());
+        assert_eq!(app.selected_forex_pair().0, "EURUSD=X");
+
+        app.forex_menu_up();
+        assert_eq!(app.forex_menu_index, MAJOR_FOREX_PAIRS.len() - 1);
+        app.forex_menu_down();
+        assert_eq!(app.forex_menu_index, 0);
+    }
+
+    #[test]
+    fn test_quit_blocker_only_with_pending_work() {
+        let mut app = App::new();
+        assert_eq!(app.quit_blocker(), None);
+
+        app.start_loading(Some("Ajout de AAPL...".to_string()));
+        assert_eq!(app.quit_blocker().as_deref(), Some("Téléchargement en cours : Ajout de AAPL..."));
+        app.stop_loading();
+
+        app.unsaved_portfolio = true;
+        assert!(app.quit_blocker().is_some_and(|reason| reason.contains("non sauvegardé")));
+    }
+
+    #[test]
+    fn test_empty_watchlist_navigates_suggestions() {
+        let mut app = App::new();
+        assert_eq!(app.selected_suggestion(), Some(POPULAR_SYMBOLS[0]));
+
+        app.navigate_up();
+        assert_eq!(app.selected_suggestion(), Some(POPULAR_SYMBOLS[POPULAR_SYMBOLS.len() - 1]));
+        app.navigate_down();
+        app.navigate_down();
+        assert_eq!(app.selected_suggestion(), Some(POPULAR_SYMBOLS[1]));
+
+        let app = App::with_watchlist(vec![WatchlistItem::new("AAPL".to_string(), "Apple".to_string())]);
+        assert_eq!(app.selected_suggestion(), None);
+    }
+
+    #[test]
+    fn test_record_transaction_updates_position() {
+        let mut app = App::new();
+        app.record_transaction(Transaction::parse_input("AAPL", "+10 100").unwrap());
+        app.record_transaction(Transaction::parse_input("AAPL", "-4 120").unwrap());
+
+        assert_eq!(app.portfolio.position("AAPL").unwrap().quantity, 6.0);
+
+        let pnl = app.position_pnl("AAPL").unwrap();
+        assert_eq!(pnl.realized, 80.0);
+        assert!(pnl.unrealized.is_none()); // Pas de cotation dans la watchlist
+        assert!(app.position_pnl("MSFT").is_none());
+        assert!(app.portfolio.cash.is_empty());
+
+        // Un dépôt ouvre le suivi des liquidités en dollars : 1000 - 1000 + 480
+        app.record_cash_movement(CashMovement::parse_input("+1000").unwrap());
+        assert_eq!(app.portfolio.cash[0].amount, 480.0);
+        app.record_transaction(Transaction::parse_input("AAPL", "+1 80").unwrap());
+        assert_eq!(app.portfolio.cash[0].amount, 400.0);
+    }
+
+    #[test]
+    fn test_earnings_alert_fires_once() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let mut item = WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string());
+        item.earnings_date = Some(Utc.with_ymd_and_hms(2024, 5, 2, 20, 30, 0).unwrap());
+        let mut app = App::with_watchlist(vec![item]);
+        let today = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+
+        // Désactivée par défaut
+        app.check_earnings_alerts(today);
+        assert!(app.current_notification().is_none());
+
+        app.config.earnings_alert = true;
+        app.check_earnings_alerts(today);
+        app.check_earnings_alerts(today);
+        assert_eq!(app.notifications.len(), 1);
+
+        app.dismiss_notification();
+        app.check_earnings_alerts(today);
+        assert!(app.current_notification().is_none());
+    }
+
+    #[test]
+    fn test_snooze_notification_mutes_rule() {
+        use crate::models::{Interval, OHLCData, OHLC};
+        use chrono::Utc;
+
+        let mut data = OHLCData::with_interval("AAPL".to_string(), Interval::D1);
+        data.add_candle(OHLC::new(Utc::now(), 150.0, 150.0, 150.0, 150.0, 0));
+        let mut app = App::with_watchlist(vec![WatchlistItem::with_data("AAPL".to_string(), "Apple".to_string(), data)]);
+        app.add_alert_rule("AAPL", Condition::parse("price > 140").unwrap(), Recurrence::EveryTime, None);
+
+        assert_eq!(app.evaluate_alerts().len(), 1);
+        assert!(!app.take_alert_book_changes());
+
+        let until = app.snooze_notification().unwrap();
+        assert!(app.current_notification().is_none());
+        assert_eq!(app.alert_book.rules[0].snoozed_until, Some(until));
+        assert!(app.take_alert_book_changes());
+        assert!(!app.take_alert_book_changes());
+    }
+
+    #[test]
+    fn test_screener_results_for_current_tab_only() {
+        let quote = |symbol: &str| ScreenerQuote {
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            price: 10.0,
+            change_percent: 5.0,
+            volume: None,
+        };
+
+        let mut app = App::new();
+        app.show_screener();
+        app.set_screener_quotes(Screener::DayGainers, vec![quote("NVDA"), quote("AMD")]);
+        app.screener_down();
+        assert_eq!(app.selected_screener_quote().unwrap().symbol, "AMD");
+
+        // Réponse en retard d'un autre onglet : ignorée
+        app.switch_screener(true);
+        assert_eq!(app.screener, Screener::DayLosers);
+        app.set_screener_quotes(Screener::DayGainers, vec![quote("NVDA")]);
+        assert!(app.selected_screener_quote().is_none());
+    }
+
+    #[test]
+    fn test_backtest_strategy_cycle() {
+        let mut app = App::new();
+        app.show_backtest();
+        assert!(app.is_on_backtest());
+        assert!(app.backtest.is_none()); // Pas de ticker sélectionné
+
+        app.switch_backtest_strategy(false);
+        assert_eq!(app.backtest_strategy, PRESET_STRATEGIES.len() - 1);
+        app.switch_backtest_strategy(true);
+        assert_eq!(app.backtest_strategy, 0);
+    }
+
+    #[test]
+    fn test_measure_tool() {
+        use crate::models::{OHLCData, Timeframe, OHLC};
+        use chrono::{TimeZone, Utc};
+
+        let mut data = OHLCData::new("AAPL".to_string(), Interval::D1, Timeframe::TwoYears);
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        for i in 0..300 {
+            let price = 170.0 + i as f64;
+            data.add_candle(OHLC::new(start + chrono::Duration::days(i), price, price, price, price, 0));
+        }
+        let mut app = App::with_watchlist(vec![WatchlistItem::with_data("AAPL".to_string(), "Apple".to_string(), data)]);
+        app.show_chart();
+
+        app.toggle_measure();
+        assert_eq!(app.measure, Some(MeasureCursor { cursor: 299, anchor: None }));
+        assert!(app.measurement().is_none());
+
+        // Curseur borné au dernier chandelier et à la fenêtre affichée
+        app.move_measure_cursor(5);
+        assert_eq!(app.measure.unwrap().cursor, 299);
+        app.anchor_measure();
+        app.move_measure_cursor(-1000);
+        assert_eq!(app.measure.unwrap().cursor, measure::first_visible(300));
+
+        let measurement = app.measurement().unwrap();
+        assert_eq!(measurement.bars, 299 - measure::first_visible(300));
+
+        // Changer d'intervalle invalide la mesure
+        app.next_interval();
+        assert!(app.measure.is_none());
+    }
+
+    #[test]
+    fn test_annotation_input() {
+        use crate::models::{OHLCData, Timeframe, OHLC};
+        use chrono::{TimeZone, Utc};
+
+        let mut data = OHLCData::new("AAPL".to_string(), Interval::D1, Timeframe::OneMonth);
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        for i in 0..10 {
+            data.add_candle(OHLC::new(start + chrono::Duration::days(i), 1.0, 1.0, 1.0, 1.0, 0));
+        }
+        let mut app = App::with_watchlist(vec![WatchlistItem::with_data("AAPL".to_string(), "Apple".to_string(), data)]);
+        app.show_chart();
+
+        // Sans curseur de mesure : rien à annoter
+        assert!(!app.start_annotation_input());
+        assert!(app.is_on_chart());
+
+        // Annotation posée en 1h dans le dernier jour : modifiée, pas doublée
+        let intraday = start + chrono::Duration::days(9) + chrono::Duration::hours(15);
+        app.annotations.set("AAPL", intraday, "Résultats");
+        app.toggle_measure();
+        assert!(app.start_annotation_input());
+        assert_eq!(app.input_buffer, "Résultats");
+        assert_eq!(
+            app.input_purpose,
+            InputPurpose::AddAnnotation { symbol: "AAPL".to_string(), timestamp: intraday }
+        );
+
+        // Retour au graphique, curseur conservé
+        app.cancel_input();
+        assert!(app.is_on_chart());
+        assert!(app.measure.is_some());
+
+        // Droite de tendance : ancre sur le curseur refusée, puis tracée
+        // et retirée depuis une de ses extrémités
+        app.anchor_measure();
+        assert_eq!(app.toggle_trend_line(), None);
+        app.move_measure_cursor(-3);
+        assert_eq!(app.toggle_trend_line(), Some(TrendLineEdit::Added));
+        assert_eq!(app.annotations.trend_lines("AAPL").len(), 1);
+        app.measure = Some(MeasureCursor { cursor: 9, anchor: None });
+        assert_eq!(app.toggle_trend_line(), Some(TrendLineEdit::Removed(1)));
+        assert_eq!(app.toggle_trend_line(), Some(TrendLineEdit::Removed(0)));
+    }
 }
@@ -0,0 +1,231 @@
+// ============================================================================
+// Backtest - Test de stratégies simples sur l'historique chargé
+// ============================================================================
+// Rejoue une stratégie sur les chandelles d'un OHLCData, sans réseau :
+//
+//   SMA crossover : achat quand la SMA rapide passe au-dessus de la lente,
+//                   vente quand elle repasse en dessous
+//   RSI seuils    : achat quand le RSI passe sous le seuil de survente,
+//                   vente quand il dépasse le seuil de surachat
+//
+// Hypothèses volontairement simples :
+// - Long uniquement, tout le capital investi à chaque achat
+// - Exécution au prix de clôture de la chandelle du signal, sans frais
+// - Une position encore ouverte à la fin est valorisée à la dernière clôture
+// ============================================================================
+
+use chrono::{DateTime, Utc};
+
+use crate::models::indicators::{rsi, sma};
+use crate::models::OHLCData;
+
+/// Stratégie testée
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    /// Croisement de deux moyennes mobiles simples
+    SmaCrossover { fast: usize, slow: usize },
+
+    /// Seuils de survente / surachat du RSI
+    RsiThreshold { period: usize, oversold: f64, overbought: f64 },
+}
+
+/// Stratégies proposées dans la vue backtest (h / l pour changer)
+pub const PRESET_STRATEGIES: [Strategy; 3] = [
+    Strategy::SmaCrossover { fast: 20, slow: 50 },
+    Strategy::SmaCrossover { fast: 50, slow: 200 },
+    Strategy::RsiThreshold { period: 14, oversold: 30.0, overbought: 70.0 },
+];
+
+impl Strategy {
+    /// Libellé affiché (ex: "SMA 20/50", "RSI(14) 30/70")
+    pub fn label(&self) -> String {
+        match self {
+            Strategy::SmaCrossover { fast, slow } => format!("SMA {}/{}", fast, slow),
+            Strategy::RsiThreshold { period, oversold, overbought } => {
+                format!("RSI({}) {}/{}", period, oversold, overbought)
+            }
+        }
+    }
+
+    /// Signal de chaque chandelle : Some(true) = achat, Some(false) = vente
+    fn signals(&self, closes: &[f64]) -> Vec<Option<bool>> {
+        match *self {
+            Strategy::SmaCrossover { fast, slow } => {
+                let fast = sma(closes, fast);
+                let slow = sma(closes, slow);
+                let above = |i: usize| Some(fast[i]? > slow[i]?);
+
+                (0..closes.len())
+                    .map(|i| {
+                        let (previous, current) = (above(i.checked_sub(1)?)?, above(i)?);
+                        (previous != current).then_some(current)
+                    })
+                    .collect()
+            }
+            Strategy::RsiThreshold { period, oversold, overbought } => rsi(closes, period)
+                .into_iter()
+                .map(|value| match value? {
+                    value if value < oversold => Some(true),
+                    value if value > overbought => Some(false),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Un aller-retour achat → vente
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trade {
+    pub entry_time: DateTime<Utc>,
+    pub entry_price: f64,
+    pub exit_time: DateTime<Utc>,
+    pub exit_price: f64,
+
+    /// true si la position n'était pas encore vendue à la fin des données
+    pub open: bool,
+}
+
+impl Trade {
+    /// Rendement du trade en pourcentage
+    pub fn return_percent(&self) -> f64 {
+        (self.exit_price / self.entry_price - 1.0) * 100.0
+    }
+}
+
+/// Résultat d'un backtest
+#[derive(Debug, Clone, PartialEq)]
+pub struct BacktestResult {
+    pub strategy: Strategy,
+    pub trades: Vec<Trade>,
+
+    /// Rendement total de la stratégie en % (trades composés)
+    pub total_return: f64,
+
+    /// Rendement d'un simple achat-conservation sur la même période, en %
+    pub buy_and_hold: f64,
+
+    /// Valeur du capital à chaque chandelle (base 100)
+    pub equity_curve: Vec<f64>,
+}
+
+impl BacktestResult {
+    /// Part des trades gagnants en % (None sans trade)
+    pub fn win_rate(&self) -> Option<f64> {
+        if self.trades.is_empty() {
+            return None;
+        }
+        let winners = self.trades.iter().filter(|t| t.exit_price > t.entry_price).count();
+        Some(winners as f64 / self.trades.len() as f64 * 100.0)
+    }
+}
+
+/// Capital de départ de la courbe d'equity
+const INITIAL_EQUITY: f64 = 100.0;
+
+/// Exécute une stratégie sur des données historiques
+pub fn run(data: &OHLCData, strategy: Strategy) -> BacktestResult {
+    let closes = data.closes();
+    let signals = strategy.signals(&closes);
+
+    let mut trades = Vec::new();
+    let mut equity_curve = Vec::with_capacity(closes.len());
+    let mut cash = INITIAL_EQUITY;
+    // (index d'entrée, quantité détenue)
+    let mut position: Option<(usize, f64)> = None;
+
+    for (i, candle) in data.candles.iter().enumerate() {
+        match (signals[i], position) {
+            (Some(true), None) if candle.close > 0.0 => {
+                position = Some((i, cash / candle.close));
+                cash = 0.0;
+            }
+            (Some(false), Some((entry, quantity))) => {
+                cash = quantity * candle.close;
+                trades.push(trade(data, entry, i, false));
+                position = None;
+            }
+            _ => {}
+        }
+
+        equity_curve.push(match position {
+            Some((_, quantity)) => quantity * candle.close,
+            None => cash,
+        });
+    }
+
+    // Position encore ouverte : valorisée à la dernière clôture
+    if let Some((entry, _)) = position {
+        trades.push(trade(data, entry, data.candles.len() - 1, true));
+    }
+
+    let total_return = equity_curve
+        .last()
+        .map(|equity| (equity / INITIAL_EQUITY - 1.0) * 100.0)
+        .unwrap_or(0.0);
+    let buy_and_hold = match (closes.first(), closes.last()) {
+        (Some(first), Some(last)) if *first > 0.0 => (last / first - 1.0) * 100.0,
+        _ => 0.0,
+    };
+
+    BacktestResult {
+        strategy,
+        trades,
+        total_return,
+        buy_and_hold,
+        equity_curve,
+    }
+}
+
+/// Construit un trade entre deux index de chandelles
+fn trade(data: &OHLCData, entry: usize, exit: usize, open: bool) -> Trade {
+    Trade {
+        entry_time: data.candles[entry].timestamp,
+        entry_price: data.candles[entry].close,
+        exit_time: data.candles[exit].timestamp,
+        exit_price: data.candles[exit].close,
+        open,
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sma_crossover_round_trip() {
+        // Baisse, hausse (croisement haussier), puis baisse (croisement baissier)
+        let mut closes: Vec<f64> = (0..5).map(|i| 10.0 - i as f64).collect();
+        closes.extend((0..5).map(|i| 7.0 + 2.0 * i as f64));
+        closes.extend((0..5).map(|i| 13.0 - 3.0 * i as f64));
+        let data = OHLCData::from_closes(&closes);
+
+        let result = run(&data, Strategy::SmaCrossover { fast: 2, slow: 4 });
+        assert_eq!(result.trades.len(), 1);
+        assert!(!result.trades[0].open);
+        assert_eq!(result.equity_curve.len(), closes.len());
+
+        // Le capital final reflète le rendement du seul trade
+        let expected = result.trades[0].return_percent();
+        assert!((result.total_return - expected).abs() < 1e-9);
+        assert_eq!(result.win_rate(), Some(if expected > 0.0 { 100.0 } else { 0.0 }));
+    }
+
+    #[test]
+    fn test_open_position_marked_to_market() {
+        // Chute (RSI bas → achat) puis rebond sans signal de vente
+        let closes = [10.0, 9.0, 8.0, 7.0, 7.5];
+        let data = OHLCData::from_closes(&closes);
+
+        let result = run(&data, Strategy::RsiThreshold { period: 2, oversold: 30.0, overbought: 101.0 });
+        assert_eq!(result.trades.len(), 1);
+        assert!(result.trades[0].open);
+        assert_eq!(result.trades[0].exit_price, 7.5);
+        assert!((result.buy_and_hold - -25.0).abs() < 1e-9);
+        assert!(run(&OHLCData::from_closes(&[]), PRESET_STRATEGIES[0]).trades.is_empty());
+    }
+}
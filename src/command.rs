@@ -0,0 +1,61 @@
+// ============================================================================
+// Commandes saisies après ':'
+// ============================================================================
+// Actions rares, sans touche dédiée, tapées comme dans vim :
+//
+//   :loglevel                  affiche le filtre de logs actuel
+//   :loglevel debug            tous les logs debug+ (API comprise)
+//   :loglevel lazywallet::api=trace,info
+//
+// - Le filtre suit la syntaxe de RUST_LOG, appliqué sans redémarrer
+// - L'exécution est dans main.rs (elle touche au logging du binaire)
+// ============================================================================
+
+/// Commande reconnue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Filtre de logs à appliquer (None : afficher le filtre actuel)
+    LogLevel(Option<String>),
+}
+
+impl Command {
+    /// Analyse une saisie ("loglevel debug", ':' initial facultatif)
+    ///
+    /// Retourne un message d'erreur affichable si la commande est inconnue
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim().trim_start_matches(':');
+        let mut words = input.split_whitespace();
+        let name = words.next().unwrap_or_default();
+
+        match name.to_lowercase().as_str() {
+            "loglevel" | "log" => {
+                let filter: Vec<&str> = words.collect();
+                Ok(Command::LogLevel((!filter.is_empty()).then(|| filter.join(""))))
+            }
+            "" => Err("Commande vide (ex: :loglevel debug)".to_string()),
+            _ => Err(format!("Commande inconnue : \"{}\" (ex: :loglevel debug)", name)),
+        }
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_loglevel() {
+        assert_eq!(Command::parse("loglevel debug"), Ok(Command::LogLevel(Some("debug".to_string()))));
+        assert_eq!(Command::parse(":LogLevel  lazywallet=trace, info "), Ok(Command::LogLevel(Some("lazywallet=trace,info".to_string()))));
+        assert_eq!(Command::parse("log"), Ok(Command::LogLevel(None)));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_commands() {
+        assert!(Command::parse("").is_err());
+        assert_eq!(Command::parse("wq"), Err("Commande inconnue : \"wq\" (ex: :loglevel debug)".to_string()));
+    }
+}
@@ -0,0 +1,312 @@
+// ============================================================================
+// Configuration utilisateur
+// ============================================================================
+// Options lues au démarrage depuis ~/.config/lazywallet/config.json
+//
+// CONCEPT SERDE : #[serde(default)]
+// - Chaque champ absent du fichier prend sa valeur par défaut
+// - Un fichier partiel (ou absent) reste valide : on ne renseigne que ce
+//   que l'on veut changer
+//
+// Exemple :
+// {
+//   "cost_basis": "average_cost",
+//   "market_overview": ["^GSPC", "^NDX", "^FCHI"],
+//   "watchlist": ["AAPL", "MSFT", "BTC-USD"],
+//   "remote_watchlist": { "url": "https://intranet.corp/watchlist.txt", "refresh_minutes": 60 },
+//   "economic_calendar": { "url": "https://intranet.corp/macro.ics", "refresh_minutes": 360 },
+//   "earnings_alert": true,
+//   "fear_greed": true,
+//   "http_api": "127.0.0.1:8787",
+//   "alert_command": "notify-send \"{symbol} hit {price}\"",
+//   "alert_snooze_minutes": 60,
+//   "quiet_hours": { "start": "22:00", "end": "07:00" },
+//   "market_hours": true,
+//   "closed_refresh_minutes": 60,
+//   "auto_refresh_seconds": 60,
+//   "http": { "proxy": "http://proxy.corp:3128", "timeout_secs": 30 },
+//   "logging": { "dir": "~/logs/lazywallet", "retention_days": 14, "max_total_mb": 100 },
+//   "candle_store": true,
+//   "storage_format": "toml",
+//   "custom_instruments": [{ "symbol": "FONDS-X", "csv": "~/finance/fonds_x.csv" }],
+//   "watch_expressions": [{ "label": "Paire", "expression": "AAPL - 0.5 * MSFT" }],
+//   "day_range_bar": true,
+//   "heat_strip_days": 7,
+//   "relative_strength_days": 90,
+//   "ascii": false,
+//   "reduced_motion": false,
+//   "no_emoji": false,
+//   "privacy": false,
+//   "chart_margin_percent": 5.0,
+//   "benchmark": "SPY",
+//   "benchmarks": { "AIR.PA": "^FCHI", "NVDA": "QQQ" },
+//   "tick_rate_ms": 250,
+//   "max_candles": 5000
+// }
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::api::csv::CustomInstrument;
+use crate::api::economic_calendar::EconomicCalendar;
+use crate::api::http::HttpSettings;
+use crate::api::remote_watchlist::RemoteWatchlist;
+use crate::logging::LogSettings;
+use crate::models::cost_basis::CostBasisMethod;
+use crate::models::expression::WatchExpression;
+use crate::models::ohlc::DEFAULT_MAX_CANDLES;
+use crate::models::schedule::QuietHours;
+use crate::persist::StorageFormat;
+use crate::profile;
+
+/// Configuration de l'application
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Méthode de calcul du prix de revient ("fifo" ou "average_cost")
+    pub cost_basis: CostBasisMethod,
+
+    /// Indices affichés dans le bandeau "marchés" du dashboard (vide = masqué)
+    pub market_overview: Vec<String>,
+
+    /// Tickers chargés au démarrage et suivis par le mode démon
+    /// (vide = AAPL, TSLA, BTC-USD)
+    pub watchlist: Vec<String>,
+
+    /// Liste de symboles partagée, publiée à une URL et rechargée
+    /// périodiquement (voir api/remote_watchlist.rs)
+    pub remote_watchlist: Option<RemoteWatchlist>,
+
+    /// Flux des publications économiques (CPI, FOMC, ...) affichées sur le
+    /// dashboard le jour même (voir api/economic_calendar.rs)
+    pub economic_calendar: Option<EconomicCalendar>,
+
+    /// Alerte la veille de la publication des résultats d'une action suivie
+    pub earnings_alert: bool,
+
+    /// Affiche l'indice Fear & Greed crypto dans le header quand la
+    /// watchlist contient de la crypto (voir api/fear_greed.rs)
+    pub fear_greed: bool,
+
+    /// Commande lancée au déclenchement d'une alerte (voir alerts/command.rs)
+    /// Une règle peut la remplacer par sa propre commande
+    pub alert_command: Option<String>,
+
+    /// Durée pendant laquelle une règle se tait après un snooze depuis le
+    /// bandeau (touche [z]), en minutes
+    pub alert_snooze_minutes: u32,
+
+    /// Heures locales pendant lesquelles les alertes restent dans le
+    /// bandeau sans appeler de webhook ni de commande (None : jamais)
+    pub quiet_hours: Option<QuietHours>,
+
+    /// Ralentit le rafraîchissement automatique des tickers dont la place
+    /// est fermée (la crypto est toujours rafraîchie, voir models/schedule.rs)
+    pub market_hours: bool,
+
+    /// Place fermée : un rafraîchissement toutes les N minutes au plus
+    /// (0 = en pause jusqu'à la prochaine séance)
+    pub closed_refresh_minutes: u32,
+
+    /// Recharge les tickers de la watchlist toutes les N secondes, le ticker
+    /// sélectionné et les lignes visibles d'abord (0 = désactivé)
+    pub auto_refresh_seconds: u64,
+
+    /// Adresse d'écoute de l'API HTTP locale en lecture seule (voir server.rs)
+    /// Sans valeur : API désactivée
+    pub http_api: Option<String>,
+
+    /// Proxy et timeouts du client HTTP (voir api/http.rs)
+    pub http: HttpSettings,
+
+    /// Répertoire, rétention et taille maximale des logs (voir logging.rs)
+    pub logging: LogSettings,
+
+    /// Format des fichiers de données : "json" ou "toml" (trié, pour un
+    /// dépôt de dotfiles ; voir persist.rs)
+    pub storage_format: StorageFormat,
+
+    /// Conserve les bougies téléchargées dans une base SQLite locale
+    /// et ne télécharge ensuite que les nouvelles (voir store.rs)
+    pub candle_store: bool,
+
+    /// Instruments dont les bougies viennent d'un fichier CSV (voir api/csv.rs)
+    /// Ajoutés à la watchlist au démarrage
+    pub custom_instruments: Vec<CustomInstrument>,
+
+    /// Valeurs dérivées des cotations, affichées sous la watchlist
+    /// (voir models/expression.rs)
+    pub watch_expressions: Vec<WatchExpression>,
+
+    /// Ajoute à chaque ligne du dashboard une jauge du prix entre le plus
+    /// bas et le plus haut de la séance
+    pub day_range_bar: bool,
+
+    /// Ajoute à chaque ligne du dashboard une bande des variations des N
+    /// dernières séances (un bloc vert ou rouge par jour, 0 = masquée)
+    pub heat_strip_days: usize,
+
+    /// Ajoute à chaque ligne du dashboard la force relative vs l'indice de
+    /// référence sur N jours (rendement du ticker - celui de l'indice,
+    /// historiques D1, 0 = masquée)
+    pub relative_strength_days: usize,
+
+    /// Rendu en ASCII pur (bordures, chandeliers, flèches), comme `--ascii`
+    pub ascii: bool,
+
+    /// Aucun texte clignotant (gras à la place), comme `--reduced-motion`
+    pub reduced_motion: bool,
+
+    /// Pictogrammes remplacés par du texte simple (lecteurs d'écran, polices
+    /// sans emoji), comme `--no-emoji`
+    pub no_emoji: bool,
+
+    /// Démarre en mode discret : montants masqués, variations en % seulement
+    /// (touche [i] pour basculer)
+    pub privacy: bool,
+
+    /// Marge au-dessus et au-dessous des prix du graphique, en % de leur
+    /// amplitude (2 par défaut)
+    pub chart_margin_percent: f64,
+
+    /// Indice de référence superposé au graphique (touche [v])
+    pub benchmark: String,
+
+    /// Tickers dont le graphique superpose d'emblée un indice de référence,
+    /// avec l'indice de chacun (ex: "AIR.PA" → "^FCHI")
+    pub benchmarks: BTreeMap<String, String>,
+
+    /// Intervalle entre deux ticks de la boucle principale, en millisecondes
+    /// (expiration des notifications, rafraîchissements périodiques)
+    /// Le clavier est lu en continu, indépendamment de cette cadence
+    pub tick_rate_ms: u64,
+
+    /// Nombre maximal de bougies gardées en mémoire par série (les plus
+    /// récentes), historique local compris (250 au minimum)
+    pub max_candles: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            cost_basis: CostBasisMethod::default(),
+            market_overview: vec!["^GSPC".to_string(), "^NDX".to_string(), "^FCHI".to_string()],
+            watchlist: Vec::new(),
+            remote_watchlist: None,
+            economic_calendar: None,
+            earnings_alert: false,
+            fear_greed: true,
+            alert_command: None,
+            alert_snooze_minutes: 60,
+            quiet_hours: None,
+            market_hours: true,
+            closed_refresh_minutes: 60,
+            auto_refresh_seconds: 0,
+            http_api: None,
+            http: HttpSettings::default(),
+            logging: LogSettings::default(),
+            storage_format: StorageFormat::default(),
+            candle_store: false,
+            custom_instruments: Vec::new(),
+            watch_expressions: Vec::new(),
+            day_range_bar: false,
+            heat_strip_days: 0,
+            relative_strength_days: 0,
+            ascii: false,
+            reduced_motion: false,
+            no_emoji: false,
+            privacy: false,
+            chart_margin_percent: 2.0,
+            benchmark: "SPY".to_string(),
+            benchmarks: BTreeMap::new(),
+            tick_rate_ms: 250,
+            max_candles: DEFAULT_MAX_CANDLES,
+        }
+    }
+}
+
+impl Config {
+    /// Chemin par défaut du fichier de configuration
+    ///
+    /// - Linux : ~/.config/lazywallet/config.json
+    /// - Profil : ~/.config/lazywallet/profiles/<nom>/config.json
+    /// - Fallback : ./config.json
+    pub fn default_path() -> PathBuf {
+        profile::config_dir()
+            .map(|dir| dir.join("config.json"))
+            .unwrap_or_else(|| PathBuf::from("./config.json"))
+    }
+
+    /// Charge la configuration (fichier absent = valeurs par défaut)
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Échec de la lecture de {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Format invalide dans {}", path.display()))
+    }
+
+    /// Sauvegarde la configuration en JSON (crée le répertoire si besoin)
+    ///
+    /// Utilisé par la configuration guidée du premier lancement
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Échec de l'écriture de {}", path.display()))
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_config_uses_defaults() {
+        let config: Config = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, Config::default());
+
+        let config: Config = serde_json::from_str(r#"{ "cost_basis": "average_cost" }"#).unwrap();
+        assert_eq!(config.cost_basis, CostBasisMethod::AverageCost);
+        assert_eq!(config.market_overview, Config::default().market_overview);
+
+        let config: Config = serde_json::from_str(r#"{ "market_overview": [] }"#).unwrap();
+        assert!(config.market_overview.is_empty());
+
+        let config: Config = serde_json::from_str(r#"{ "http": { "proxy": "http://proxy:3128" } }"#).unwrap();
+        assert_eq!(config.http.proxy.as_deref(), Some("http://proxy:3128"));
+        assert_eq!(config.http.timeout_secs, HttpSettings::default().timeout_secs);
+
+        let config: Config = serde_json::from_str(r#"{ "remote_watchlist": { "url": "https://corp/list.txt" } }"#).unwrap();
+        assert_eq!(config.remote_watchlist.map(|remote| remote.refresh_minutes), Some(60));
+
+        let config: Config = serde_json::from_str(r#"{ "economic_calendar": { "url": "~/macro.ics" } }"#).unwrap();
+        assert_eq!(config.economic_calendar.map(|calendar| calendar.refresh_minutes), Some(360));
+    }
+
+    #[test]
+    fn test_save_round_trip() {
+        let dir = std::env::temp_dir().join(format!("lazywallet-config-{}", std::process::id()));
+        let path = dir.join("nested").join("config.json");
+        let config = Config { watchlist: vec!["AAPL".to_string()], auto_refresh_seconds: 30, ..Config::default() };
+
+        config.save(&path).unwrap();
+        assert_eq!(Config::load(&path).unwrap(), config);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
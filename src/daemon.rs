@@ -0,0 +1,322 @@
+// ============================================================================
+// Mode démon (sans TUI)
+// ============================================================================
+// `lazywallet --daemon [--interval SECONDES]` ne dessine rien : il recharge
+// périodiquement les cours de la watchlist, évalue les alertes (webhooks et
+// commandes compris) et écrit l'état courant dans un fichier JSON.
+// Pensé pour tourner sous systemd sur un serveur :
+//
+//   [Service]
+//   ExecStart=/usr/local/bin/lazywallet --daemon --interval 300
+//
+// Fichier d'état : ~/.local/share/lazywallet/state.json
+// {
+//   "updated_at": "2024-05-02T14:30:00Z",
+//   "quotes": [ { "symbol": "AAPL", "name": "Apple Inc.", "price": 168.42,
+//                 "change_percent": 1.25 } ],
+//   "notifications": [ "🔔 AAPL : RSI(14) < 30 (prix 168.42)" ]
+// }
+//
+// Heures de marché (config "market_hours") : un ticker dont la place est
+// fermée n'est rechargé qu'une fois après la clôture, puis toutes les
+// "closed_refresh_minutes" (la crypto ne ferme jamais)
+//
+// CONCEPT RUST : Une seule boucle async
+// - Sans boucle d'affichage, pas besoin de worker ni de channels
+// - Les fetchs sont faits en séquence, App n'est verrouillée que pour
+//   ranger les résultats
+//
+// CONCEPT : Arrêt (SIGINT / Ctrl+C, ou SIGTERM envoyé par systemctl stop)
+// - Les signaux sont écoutés dès le démarrage, par une seule tâche qui passe
+//   un watch à true : un signal reçu pendant un rafraîchissement n'est pas
+//   perdu
+// - Le rafraîchissement s'interrompt entre deux tickers, l'état est écrit,
+//   puis la boucle s'arrête
+// ============================================================================
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use serde::Serialize;
+use tokio::sync::watch;
+use tracing::{debug, error, info};
+
+use crate::alerts::command::spawn_alert_command;
+use crate::alerts::{AlertBook, AlertTrigger};
+use crate::api::webhook::post_alert;
+use crate::api::yahoo::{fetch_earnings_date, fetch_ticker_data};
+use crate::app::App;
+use crate::models::TickerType;
+use crate::profile;
+use crate::service::Quote;
+
+/// Intervalle par défaut entre deux rafraîchissements
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Nombre maximum d'alertes écrites dans le fichier d'état
+const MAX_NOTIFICATIONS: usize = 50;
+
+/// État écrit après chaque rafraîchissement
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DaemonState {
+    pub updated_at: DateTime<Utc>,
+    pub quotes: Vec<Quote>,
+
+    /// Dernières alertes déclenchées (la plus récente en dernier)
+    pub notifications: Vec<String>,
+}
+
+impl DaemonState {
+    /// Photographie l'état de l'application
+    pub fn from_app(app: &App, updated_at: DateTime<Utc>) -> Self {
+        let quotes = app.watchlist.iter().map(Quote::from_item).collect();
+
+        let skip = app.notifications.len().saturating_sub(MAX_NOTIFICATIONS);
+        let notifications = app
+            .notifications
+            .iter()
+            .skip(skip)
+            .map(|n| n.message.clone())
+            .collect();
+
+        Self { updated_at, quotes, notifications }
+    }
+
+    /// Chemin par défaut du fichier d'état
+    ///
+    /// - Linux : ~/.local/share/lazywallet/state.json
+    /// - Profil : ~/.local/share/lazywallet/profiles/<nom>/state.json
+    /// - Fallback : ./state.json
+    pub fn default_path() -> PathBuf {
+        profile::data_dir()
+            .map(|dir| dir.join("state.json"))
+            .unwrap_or_else(|| PathBuf::from("./state.json"))
+    }
+
+    /// Écrit l'état en JSON (crée le répertoire si besoin)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Échec de l'écriture de {}", path.display()))
+    }
+}
+
+/// Lit "--interval SECONDES" dans les arguments de la ligne de commande
+pub fn parse_interval(args: &[String]) -> Result<Duration> {
+    let Some(position) = args.iter().position(|arg| arg == "--interval") else {
+        return Ok(DEFAULT_INTERVAL);
+    };
+
+    let value = args.get(position + 1).context("--interval attend un nombre de secondes")?;
+    let seconds: u64 = value
+        .parse()
+        .with_context(|| format!("Intervalle invalide : \"{}\"", value))?;
+    if seconds == 0 {
+        anyhow::bail!("L'intervalle doit être d'au moins une seconde");
+    }
+
+    Ok(Duration::from_secs(seconds))
+}
+
+/// Boucle principale du démon (s'arrête sur Ctrl+C / SIGINT ou SIGTERM)
+///
+/// App est partagée avec l'API HTTP locale (voir server.rs) : le lock n'est
+/// jamais tenu pendant un appel réseau
+pub async fn run(app: Arc<Mutex<App>>, interval: Duration, state_path: &Path) -> Result<()> {
+    let mut shutdown = shutdown_signal()?;
+    let tickers = watchlist_tickers(&app);
+    info!(tickers = tickers.len(), interval_secs = interval.as_secs(), state = %state_path.display(), "Daemon started");
+
+    // Dates des prochains résultats (une fois au démarrage)
+    let stocks: Vec<&String> = tickers
+        .iter()
+        .filter(|(_, ticker_type)| *ticker_type == TickerType::Stock)
+        .map(|(symbol, _)| symbol)
+        .collect();
+    for symbol in stocks {
+        if *shutdown.borrow() {
+            break;
+        }
+        match fetch_earnings_date(symbol).await {
+            Ok(date) => {
+                let mut app_lock = app.lock().unwrap();
+                if let Some(item) = app_lock.watchlist.iter_mut().find(|item| &item.symbol == symbol) {
+                    item.earnings_date = date;
+                }
+            }
+            Err(e) => error!(ticker = %symbol, error = ?e, "Failed to fetch earnings date"),
+        }
+    }
+
+    // Dernier rechargement réussi de chaque ticker
+    let mut refreshed_at = HashMap::new();
+    loop {
+        refresh(&app, &mut refreshed_at, &shutdown).await;
+
+        let state = DaemonState::from_app(&app.lock().unwrap(), Utc::now());
+        if let Err(e) = state.save(state_path) {
+            error!(error = ?e, "Failed to write daemon state");
+        }
+
+        if *shutdown.borrow() {
+            break;
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = shutdown.changed() => break,
+        }
+    }
+
+    info!("Daemon stopping");
+    Ok(())
+}
+
+/// Écoute SIGINT et SIGTERM pour toute la durée du démon
+///
+/// Le watch passe à true au premier signal reçu
+#[cfg(unix)]
+fn shutdown_signal() -> Result<watch::Receiver<bool>> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut interrupt = signal(SignalKind::interrupt()).context("Échec de l'écoute de SIGINT")?;
+    let mut terminate = signal(SignalKind::terminate()).context("Échec de l'écoute de SIGTERM")?;
+    let (tx, rx) = watch::channel(false);
+    tokio::spawn(async move {
+        tokio::select! {
+            _ = interrupt.recv() => info!("SIGINT received, stopping daemon"),
+            _ = terminate.recv() => info!("SIGTERM received, stopping daemon"),
+        }
+        let _ = tx.send(true);
+    });
+    Ok(rx)
+}
+
+/// Écoute Ctrl+C pour toute la durée du démon (pas de SIGTERM hors Unix)
+#[cfg(not(unix))]
+fn shutdown_signal() -> Result<watch::Receiver<bool>> {
+    let (tx, rx) = watch::channel(false);
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            info!("Ctrl+C received, stopping daemon");
+            let _ = tx.send(true);
+        }
+    });
+    Ok(rx)
+}
+
+/// Symboles et types d'actif de la watchlist (copiés pour relâcher le lock)
+fn watchlist_tickers(app: &Mutex<App>) -> Vec<(String, TickerType)> {
+    app.lock().unwrap().watchlist.iter().map(|item| (item.symbol.clone(), item.ticker_type())).collect()
+}
+
+/// Recharge chaque ticker (sauf places fermées) puis évalue les alertes
+///
+/// Un arrêt demandé interrompt la boucle avant le ticker suivant
+async fn refresh(app: &Mutex<App>, refreshed_at: &mut HashMap<String, DateTime<Utc>>, shutdown: &watch::Receiver<bool>) {
+    for (symbol, ticker_type) in watchlist_tickers(app) {
+        if *shutdown.borrow() {
+            info!("Shutdown requested, refresh interrupted");
+            return;
+        }
+        let now = Utc::now();
+        if !app.lock().unwrap().refresh_due(&symbol, now, refreshed_at.get(&symbol).copied()) {
+            debug!(ticker = %symbol, "Market closed, refresh skipped");
+            continue;
+        }
+
+        match fetch_ticker_data(&symbol, ticker_type.default_interval()).await {
+            Ok((data, _)) => {
+                let mut app_lock = app.lock().unwrap();
+                if let Some(item) = app_lock.watchlist.iter_mut().find(|item| item.symbol == symbol) {
+                    item.update_data(data);
+                }
+                refreshed_at.insert(symbol, now);
+            }
+            Err(e) => error!(ticker = %symbol, error = ?e, "Failed to refresh ticker data"),
+        }
+    }
+
+    let triggers = {
+        let mut app_lock = app.lock().unwrap();
+        app_lock.check_earnings_alerts(Local::now().date_naive());
+        let triggers = app_lock.evaluate_alerts();
+
+        // Règle "once" déclenchée : désactivée dans alerts.json
+        if app_lock.take_alert_book_changes() {
+            if let Err(e) = app_lock.alert_book.save(&AlertBook::default_path()) {
+                error!(error = ?e, "Failed to save alerts");
+            }
+        }
+        triggers
+    };
+    for trigger in &triggers {
+        dispatch(trigger).await;
+    }
+}
+
+/// Journalise une alerte déclenchée, appelle son webhook et lance sa commande
+async fn dispatch(trigger: &AlertTrigger) {
+    info!(ticker = %trigger.symbol, condition = %trigger.condition, price = trigger.price, "Alert triggered");
+
+    if let Some(url) = trigger.webhook.as_ref().filter(|_| !crate::demo::is_enabled()) {
+        match post_alert(url, trigger).await {
+            Ok(()) => info!(ticker = %trigger.symbol, url = %url, "Alert webhook delivered"),
+            Err(e) => error!(ticker = %trigger.symbol, url = %url, error = ?e, "Failed to deliver alert webhook"),
+        }
+    }
+
+    if let Some(template) = &trigger.command {
+        // Thread dédié, sans l'attendre : un script lent ne retarde pas le
+        // rafraîchissement suivant (tué après COMMAND_TIMEOUT)
+        spawn_alert_command(template.clone(), trigger.clone(), |trigger, result| match result {
+            Ok(output) => info!(ticker = %trigger.symbol, output = %output, "Alert command executed"),
+            Err(e) => error!(ticker = %trigger.symbol, error = ?e, "Alert command failed"),
+        });
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::WatchlistItem;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval(&args(&["--daemon"])).unwrap(), DEFAULT_INTERVAL);
+        assert_eq!(
+            parse_interval(&args(&["--daemon", "--interval", "300"])).unwrap(),
+            Duration::from_secs(300)
+        );
+        assert!(parse_interval(&args(&["--interval"])).is_err());
+        assert!(parse_interval(&args(&["--interval", "0"])).is_err());
+        assert!(parse_interval(&args(&["--interval", "abc"])).is_err());
+    }
+
+    #[test]
+    fn test_state_from_app() {
+        let app = App::with_watchlist(vec![WatchlistItem::new("AAPL".to_string(), "Apple".to_string())]);
+        let state = DaemonState::from_app(&app, Utc::now());
+
+        assert_eq!(state.quotes.len(), 1);
+        assert_eq!(state.quotes[0].symbol, "AAPL");
+        assert!(state.quotes[0].price.is_none());
+        assert!(state.notifications.is_empty());
+    }
+}
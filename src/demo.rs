@@ -0,0 +1,302 @@
+// ============================================================================
+// Mode démo : données synthétiques sans réseau
+// ============================================================================
+// `lazywallet --demo` remplace tous les appels réseau (Yahoo, Binance) par
+// un générateur de marche aléatoire déterministe :
+//
+// - Mêmes symbole + intervalle → mêmes bougies à chaque lancement
+// - Un "tick" périodique fait évoluer la dernière bougie (prix vivants)
+// - Idéal pour les démos, les captures d'écran et les tests d'interface
+//
+// CONCEPT RUST : AtomicBool global
+// - Le mode est choisi une fois au démarrage puis lu partout (API, worker)
+// - Un atomique évite de faire passer un paramètre dans chaque fonction
+//
+// CONCEPT : PRNG maison (SplitMix64)
+// - Pas de dépendance externe pour quelques nombres pseudo-aléatoires
+// - La graine est un hash FNV-1a du symbole (stable d'une version à l'autre,
+//   contrairement au hasher de la bibliothèque standard)
+// ============================================================================
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveTime, Utc};
+
+use crate::models::crypto_stats::CryptoStats;
+use crate::models::fear_greed::FearGreed;
+use crate::models::order_book::{BookLevel, OrderBook};
+use crate::models::screener::{Screener, ScreenerQuote};
+use crate::models::{Interval, OHLCData, OHLC};
+
+/// Watchlist chargée en mode démo (si config.watchlist est vide)
+pub const DEMO_WATCHLIST: &[(&str, &str)] = &[
+    ("AAPL", "Apple Inc."),
+    ("MSFT", "Microsoft Corporation"),
+    ("TSLA", "Tesla, Inc."),
+    ("SPY", "SPDR S&P 500 ETF Trust"),
+    ("BTC-USD", "Bitcoin USD"),
+    ("ETH-USD", "Ethereum USD"),
+    ("EURUSD=X", "EUR/USD"),
+];
+
+/// Intervalle entre deux évolutions des prix
+pub const TICK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Nombre maximum de bougies générées par série
+const MAX_CANDLES: usize = 500;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Active le mode démo pour toute la durée du programme
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Le mode démo est-il actif ?
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// ============================================================================
+// Générateur pseudo-aléatoire
+// ============================================================================
+
+/// SplitMix64 : petit PRNG rapide et de bonne qualité
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Nombre uniforme dans [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Nombre uniforme dans [-1, 1)
+    fn next_signed(&mut self) -> f64 {
+        self.next_f64() * 2.0 - 1.0
+    }
+}
+
+/// Hash FNV-1a (stable) d'une chaîne
+fn fnv1a(text: &str) -> u64 {
+    text.bytes()
+        .fold(0xCBF2_9CE4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3))
+}
+
+/// Prix de départ plausible pour un symbole
+fn base_price(symbol: &str) -> f64 {
+    match symbol {
+        "AAPL" => 170.0,
+        "MSFT" => 410.0,
+        "TSLA" => 180.0,
+        "SPY" => 510.0,
+        "BTC-USD" => 64_000.0,
+        "ETH-USD" => 3_100.0,
+        "EURUSD=X" => 1.08,
+        "^GSPC" => 5_100.0,
+        "^NDX" => 17_800.0,
+        "^FCHI" => 8_000.0,
+        _ => 10.0 + (fnv1a(symbol) % 490) as f64,
+    }
+}
+
+/// Volatilité relative d'une bougie (crypto > actions > forex)
+fn volatility(symbol: &str, interval: Interval) -> f64 {
+    let daily = if symbol.ends_with("=X") {
+        0.004
+    } else if symbol.contains('-') {
+        0.03
+    } else {
+        0.015
+    };
+    // Volatilité proportionnelle à la racine de la durée
+    daily * (interval.minutes() as f64 / (24.0 * 60.0)).sqrt()
+}
+
+// ============================================================================
+// Séries synthétiques
+// ============================================================================
+
+/// Génère la série d'un symbole à un intervalle, terminée à `end`
+///
+/// Les prix ne dépendent que du symbole et de l'intervalle : seule la date
+/// des bougies suit `end`
+pub fn ticker_data_at(symbol: &str, interval: Interval, end: DateTime<Utc>) -> OHLCData {
+    let timeframe = interval.default_timeframe();
+    let step = chrono::Duration::minutes(interval.minutes());
+    let count = ((timeframe.to_days() as i64 * 24 * 60) / interval.minutes()).clamp(1, MAX_CANDLES as i64) as usize;
+
+    // Aligne la dernière bougie sur l'intervalle
+    let end_minutes = end.timestamp() / 60;
+    let last_start = end_minutes - end_minutes.rem_euclid(interval.minutes());
+    let first = DateTime::from_timestamp((last_start - interval.minutes() * (count as i64 - 1)) * 60, 0).unwrap_or(end);
+
+    let mut rng = Rng::new(fnv1a(symbol) ^ fnv1a(interval.label()));
+    let sigma = volatility(symbol, interval);
+    let mut data = OHLCData::new(symbol.to_string(), interval, timeframe);
+    let mut close = base_price(symbol);
+
+    for i in 0..count {
+        let open = close;
+        close = open * (1.0 + rng.next_signed() * sigma);
+        let high = open.max(close) * (1.0 + rng.next_f64() * sigma / 2.0);
+        let low = open.min(close) * (1.0 - rng.next_f64() * sigma / 2.0);
+        let volume = 1_000_000 + rng.next_u64() % 9_000_000;
+        data.add_candle(OHLC::new(first + step * i as i32, open, high, low, close, volume));
+    }
+
+    data
+}
+
+/// Génère la série d'un symbole terminée maintenant
+pub fn ticker_data(symbol: &str, interval: Interval) -> OHLCData {
+    ticker_data_at(symbol, interval, Utc::now())
+}
+
+/// Nom affiché d'un symbole de démo
+pub fn name(symbol: &str) -> Option<String> {
+    DEMO_WATCHLIST
+        .iter()
+        .find(|(demo_symbol, _)| *demo_symbol == symbol)
+        .map(|(_, name)| name.to_string())
+}
+
+/// Fait évoluer la dernière bougie d'un pas de marche aléatoire
+///
+/// `step` numérote les ticks : le même tick donne toujours le même pas
+pub fn tick(data: &mut OHLCData, step: u64) {
+    let sigma = volatility(&data.symbol, data.interval) / 4.0;
+    let mut rng = Rng::new(fnv1a(&data.symbol) ^ step.wrapping_mul(0x2545_F491_4F6C_DD1D));
+    let Some(last) = data.candles.last_mut() else {
+        return;
+    };
+
+    last.close *= 1.0 + rng.next_signed() * sigma;
+    last.high = last.high.max(last.close);
+    last.low = last.low.min(last.close);
+    last.volume += rng.next_u64() % 10_000;
+}
+
+/// Résultats synthétiques d'un screener (tirés de la watchlist de démo)
+pub fn screener_quotes(screener: Screener) -> Vec<ScreenerQuote> {
+    let mut quotes: Vec<ScreenerQuote> = DEMO_WATCHLIST
+        .iter()
+        .map(|(symbol, name)| {
+            let data = ticker_data(symbol, Interval::D1);
+            ScreenerQuote {
+                symbol: symbol.to_string(),
+                name: name.to_string(),
+                price: data.last().map(|c| c.close).unwrap_or_default(),
+                change_percent: data.daily_change_percent().unwrap_or_default(),
+                volume: data.last().map(|c| c.volume),
+            }
+        })
+        .collect();
+
+    match screener {
+        Screener::DayGainers => quotes.sort_by(|a, b| b.change_percent.total_cmp(&a.change_percent)),
+        Screener::DayLosers => quotes.sort_by(|a, b| a.change_percent.total_cmp(&b.change_percent)),
+        Screener::MostActives => quotes.sort_by_key(|quote| std::cmp::Reverse(quote.volume)),
+    }
+    quotes
+}
+
+/// Carnet d'ordres synthétique autour du dernier prix
+pub fn order_book(symbol: &str, depth: usize) -> OrderBook {
+    let price = ticker_data(symbol, Interval::default()).last().map(|c| c.close).unwrap_or(1.0);
+    let tick_size = price * 0.0001;
+    let mut rng = Rng::new(fnv1a(symbol) ^ Utc::now().timestamp() as u64);
+
+    let mut level = |side: f64, i: usize| BookLevel {
+        price: price + side * tick_size * (i + 1) as f64,
+        quantity: 0.05 + rng.next_f64() * 2.0,
+    };
+    let bids = (0..depth).map(|i| level(-1.0, i)).collect();
+    let asks = (0..depth).map(|i| level(1.0, i)).collect();
+
+    OrderBook::new(symbol.to_string(), bids, asks)
+}
+
+/// Statistiques de marché synthétiques d'une paire crypto
+///
+/// Offre fixe propre au symbole, capitalisation au dernier prix
+pub fn crypto_stats(symbol: &str) -> Option<CryptoStats> {
+    let (coin, _) = symbol.split_once('-')?;
+    let price = ticker_data(symbol, Interval::default()).last()?.close;
+    let mut rng = Rng::new(fnv1a(symbol));
+
+    let max_supply = 1e6 + rng.next_f64() * 1e9;
+    let circulating_supply = max_supply * (0.5 + rng.next_f64() * 0.5);
+    let market_cap = price * circulating_supply;
+    Some(CryptoStats {
+        coin_id: coin.to_lowercase(),
+        market_cap: Some(market_cap),
+        market_cap_rank: Some(1 + (rng.next_f64() * 50.0) as u32),
+        volume_24h: Some(market_cap * (0.01 + rng.next_f64() * 0.05)),
+        circulating_supply: Some(circulating_supply),
+        max_supply: Some(max_supply),
+        dominance: Some(rng.next_f64() * 50.0),
+        fetched_at: Utc::now(),
+    })
+}
+
+/// Indice Fear & Greed synthétique (le même toute la journée)
+pub fn fear_greed() -> FearGreed {
+    let published_at = Utc::now().date_naive().and_time(NaiveTime::MIN).and_utc();
+    let mut rng = Rng::new(fnv1a("fear_greed") ^ published_at.timestamp() as u64);
+    FearGreed { value: (rng.next_f64() * 100.0) as u8, published_at }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn closes(data: &OHLCData) -> Vec<f64> {
+        data.candles.iter().map(|c| c.close).collect()
+    }
+
+    #[test]
+    fn test_series_is_deterministic() {
+        let end = Utc.with_ymd_and_hms(2024, 5, 2, 14, 37, 0).unwrap();
+        let a = ticker_data_at("AAPL", Interval::M30, end);
+        let b = ticker_data_at("AAPL", Interval::M30, end);
+
+        assert_eq!(closes(&a), closes(&b));
+        assert_eq!(a.len(), MAX_CANDLES);
+        // Dernière bougie alignée sur la demi-heure
+        assert_eq!(a.last().unwrap().timestamp, Utc.with_ymd_and_hms(2024, 5, 2, 14, 30, 0).unwrap());
+        assert!(a.candles.iter().all(|c| c.low <= c.open.min(c.close) && c.high >= c.open.max(c.close)));
+
+        // Un autre symbole donne une autre série
+        assert_ne!(closes(&ticker_data_at("MSFT", Interval::M30, end)), closes(&a));
+    }
+
+    #[test]
+    fn test_tick_moves_last_close() {
+        let mut data = ticker_data("BTC-USD", Interval::H1);
+        let before = data.last().unwrap().close;
+        tick(&mut data, 1);
+        let after = data.last().unwrap().close;
+
+        assert_ne!(before, after);
+        let last = data.last().unwrap();
+        assert!(last.high >= last.close && last.low <= last.close);
+    }
+}
@@ -0,0 +1,75 @@
+// ============================================================================
+// Module : export
+// ============================================================================
+// Exporte les données de l'application hors du terminal (images, fichiers)
+// - png : rendu du graphique en chandeliers dans un fichier PNG
+// - tradingview : listes de symboles au format TradingView (import / export)
+// - quotes_csv : watchlist et cotations au format CSV (tableurs)
+// - text : graphique Unicode tel qu'affiché, en texte brut ou ANSI
+// ============================================================================
+
+#[cfg(feature = "tui")]
+pub mod png; // Export du graphique en image PNG (plotters)
+pub mod quotes_csv; // Watchlist et cotations en CSV
+#[cfg(feature = "tui")]
+pub mod text; // Graphique en chandeliers Unicode dans un fichier texte
+pub mod tradingview; // Listes de symboles TradingView (EXCHANGE:SYMBOL)
+
+use std::path::PathBuf;
+
+use chrono::Local;
+
+use crate::models::Interval;
+
+// Re-export des fonctions principales
+#[cfg(feature = "tui")]
+pub use png::export_chart_png;
+
+/// Retourne le répertoire d'export par défaut
+///
+/// - Linux : ~/.local/share/lazywallet/exports
+/// - Fallback : ./exports si le répertoire de données est introuvable
+pub fn export_dir() -> PathBuf {
+    dirs::data_dir()
+        .map(|dir| dir.join("lazywallet").join("exports"))
+        .unwrap_or_else(|| PathBuf::from("./exports"))
+}
+
+/// Construit un nom de fichier d'export unique
+///
+/// Format : `<SYMBOL>_<interval>_<YYYYmmdd-HHMMSS>.<extension>`
+/// Les caractères non alphanumériques du symbole sont remplacés par '_'
+/// (ex: "^GSPC" → "_GSPC", "EURUSD=X" → "EURUSD_X")
+pub fn export_file_name(symbol: &str, interval: Interval, extension: &str) -> String {
+    let safe_symbol: String = symbol
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+
+    format!(
+        "{}_{}_{}.{}",
+        safe_symbol,
+        interval.label(),
+        Local::now().format("%Y%m%d-%H%M%S"),
+        extension
+    )
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_file_name_sanitizes_symbol() {
+        let name = export_file_name("EURUSD=X", Interval::D1, "png");
+        assert!(name.starts_with("EURUSD_X_1d_"));
+        assert!(name.ends_with(".png"));
+
+        let name = export_file_name("BTC-USD", Interval::H1, "png");
+        assert!(name.starts_with("BTC-USD_1h_"));
+    }
+}
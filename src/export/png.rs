@@ -0,0 +1,225 @@
+// ============================================================================
+// Export PNG - Rendu du graphique en chandeliers avec plotters
+// ============================================================================
+// Dessine les chandeliers du ticker dans une image PNG (titre, axes,
+// moyennes mobiles) pour partager le graphique en dehors du terminal.
+//
+// CONCEPTS PLOTTERS :
+// 1. DrawingArea : surface de dessin (ici un bitmap en mémoire → fichier)
+// 2. ChartBuilder : construit un repère cartésien avec marges et labels
+// 3. Series : éléments dessinés (CandleStick, LineSeries)
+//
+// CHOIX : axe X indexé (0..n) plutôt que temporel
+// - Comme dans le terminal, les week-ends/nuits ne créent pas de trous
+// - Les labels de l'axe X sont retraduits en dates via un formatter
+// ============================================================================
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use plotters::prelude::*;
+use tracing::{debug, info};
+
+use crate::models::indicators::sma;
+use crate::models::OHLCData;
+
+/// Dimensions de l'image exportée (pixels)
+const IMAGE_WIDTH: u32 = 1280;
+const IMAGE_HEIGHT: u32 = 720;
+
+/// Nombre maximum de chandeliers exportés (même fenêtre que le rendu terminal)
+const MAX_EXPORTED_CANDLES: usize = 250;
+
+/// Couleurs (alignées sur le thème du terminal)
+const BACKGROUND: RGBColor = RGBColor(24, 24, 27);
+const TEXT: RGBColor = RGBColor(220, 220, 220);
+const GRID: RGBColor = RGBColor(60, 60, 66);
+const BULLISH: RGBColor = RGBColor(52, 208, 88);
+const BEARISH: RGBColor = RGBColor(234, 74, 90);
+const SMA_FAST_COLOR: RGBColor = RGBColor(80, 160, 255);
+const SMA_SLOW_COLOR: RGBColor = RGBColor(255, 190, 60);
+
+/// Périodes des moyennes mobiles dessinées sur le graphique
+const SMA_FAST_PERIOD: usize = 20;
+const SMA_SLOW_PERIOD: usize = 50;
+
+/// Exporte le graphique en chandeliers de `data` dans un fichier PNG
+///
+/// # Arguments
+/// * `data` - Données OHLC du ticker (les 250 dernières chandelles sont dessinées)
+/// * `title` - Titre affiché en haut de l'image (ex: "AAPL - Apple Inc.")
+/// * `path` - Chemin du fichier PNG à créer (le répertoire parent est créé si besoin)
+///
+/// CONCEPT : Travail CPU synchrone
+/// - Le rendu est fait de manière bloquante : à appeler depuis le worker
+///   thread pour ne pas figer l'interface
+pub fn export_chart_png(data: &OHLCData, title: &str, path: &Path) -> Result<()> {
+    if data.is_empty() {
+        anyhow::bail!("Aucune donnée à exporter pour {}", data.symbol);
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+    }
+
+    // Les moyennes mobiles sont calculées sur tout l'historique, puis découpées
+    // → elles sont définies dès le début de la fenêtre affichée
+    let closes = data.closes();
+    let sma_fast = sma(&closes, SMA_FAST_PERIOD);
+    let sma_slow = sma(&closes, SMA_SLOW_PERIOD);
+
+    let start = data.len().saturating_sub(MAX_EXPORTED_CANDLES);
+    let candles = &data.candles[start..];
+    let sma_fast = &sma_fast[start..];
+    let sma_slow = &sma_slow[start..];
+
+    // Bornes de prix avec une marge de 2% (comme le rendu terminal)
+    let min_price = candles.iter().fold(f64::INFINITY, |min, c| min.min(c.low));
+    let max_price = candles.iter().fold(f64::NEG_INFINITY, |max, c| max.max(c.high));
+    let margin = ((max_price - min_price) * 0.02).max(max_price.abs() * 0.001);
+    let y_range = (min_price - margin).max(0.0)..(max_price + margin);
+
+    debug!(path = %path.display(), candles = candles.len(), "Rendering chart to PNG");
+
+    let root = BitMapBackend::new(path, (IMAGE_WIDTH, IMAGE_HEIGHT)).into_drawing_area();
+    root.fill(&BACKGROUND)?;
+
+    let caption = format!(
+        "{}  ({}, {} chandeliers)",
+        title,
+        data.interval.label(),
+        candles.len()
+    );
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption(caption, ("sans-serif", 26).into_font().color(&TEXT))
+        .margin(20)
+        .x_label_area_size(40)
+        .y_label_area_size(80)
+        .build_cartesian_2d(0usize..candles.len(), y_range)?;
+
+    // Format des dates de l'axe X selon l'intervalle
+    let date_format = if data.interval.is_intraday() { "%d/%m %H:%M" } else { "%d/%m/%Y" };
+    let x_formatter = |index: &usize| {
+        candles
+            .get(*index)
+            .map(|c| c.timestamp.format(date_format).to_string())
+            .unwrap_or_default()
+    };
+    let y_formatter = |price: &f64| format!("{:.2}", price);
+
+    chart
+        .configure_mesh()
+        .bold_line_style(GRID)
+        .light_line_style(BACKGROUND)
+        .axis_style(TEXT)
+        .label_style(("sans-serif", 14).into_font().color(&TEXT))
+        .x_labels(8)
+        .y_labels(10)
+        .x_label_formatter(&x_formatter)
+        .y_label_formatter(&y_formatter)
+        .x_desc("Date")
+        .y_desc("Prix")
+        .draw()?;
+
+    // Largeur des chandeliers : ~70% de l'espace disponible par chandelle
+    let plot_width = IMAGE_WIDTH.saturating_sub(140);
+    let candle_width = ((plot_width as f64 / candles.len() as f64) * 0.7).clamp(1.0, 15.0) as u32;
+
+    chart.draw_series(candles.iter().enumerate().map(|(i, c)| {
+        CandleStick::new(
+            i,
+            c.open,
+            c.high,
+            c.low,
+            c.close,
+            BULLISH.filled(),
+            BEARISH.filled(),
+            candle_width,
+        )
+    }))?;
+
+    // Moyennes mobiles (seulement les points définis)
+    for (values, period, color) in [
+        (sma_fast, SMA_FAST_PERIOD, SMA_FAST_COLOR),
+        (sma_slow, SMA_SLOW_PERIOD, SMA_SLOW_COLOR),
+    ] {
+        let points: Vec<(usize, f64)> = values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|v| (i, v)))
+            .collect();
+
+        if points.is_empty() {
+            continue;
+        }
+
+        chart
+            .draw_series(LineSeries::new(points, color.stroke_width(2)))?
+            .label(format!("SMA {}", period))
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+    }
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .background_style(BACKGROUND.mix(0.8))
+        .border_style(GRID)
+        .label_font(("sans-serif", 14).into_font().color(&TEXT))
+        .draw()?;
+
+    root.present()
+        .with_context(|| format!("Échec de l'écriture du fichier {}", path.display()))?;
+
+    info!(path = %path.display(), "Chart exported to PNG");
+    Ok(())
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interval, Timeframe, OHLC};
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn sample_data(count: usize) -> OHLCData {
+        let mut data = OHLCData::new("TEST".to_string(), Interval::D1, Timeframe::OneYear);
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        for i in 0..count {
+            let open = 100.0 + (i as f64 * 0.3).sin() * 5.0;
+            let close = open + if i % 2 == 0 { 1.0 } else { -1.0 };
+            data.add_candle(OHLC::new(
+                base + Duration::days(i as i64),
+                open,
+                open.max(close) + 0.5,
+                open.min(close) - 0.5,
+                close,
+                1000,
+            ));
+        }
+        data
+    }
+
+    #[test]
+    fn test_export_chart_png_writes_file() {
+        let path = std::env::temp_dir().join(format!("lazywallet_test_{}.png", std::process::id()));
+        let result = export_chart_png(&sample_data(80), "TEST - Test Inc.", &path);
+
+        assert!(result.is_ok(), "{:?}", result);
+        let bytes = std::fs::read(&path).unwrap();
+        // Signature PNG
+        assert_eq!(&bytes[..4], &[0x89, b'P', b'N', b'G']);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_export_chart_png_empty_data() {
+        let data = OHLCData::new("EMPTY".to_string(), Interval::D1, Timeframe::OneYear);
+        let path = std::env::temp_dir().join("lazywallet_test_empty.png");
+        assert!(export_chart_png(&data, "EMPTY", &path).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+// ============================================================================
+// Watchlist au format CSV (cotations)
+// ============================================================================
+// Pour reprendre les cours dans un tableur : une ligne par ticker
+//
+//   symbol,name,price,currency,change_percent,volume,updated_at
+//   AAPL,Apple Inc.,271.49,USD,2.11,48213000,2026-10-14T20:00:00+00:00
+//   AIR.PA,Airbus SE,153.42,EUR,-0.35,1250000,2026-10-14T15:30:00+00:00
+//   TSLA,Tesla,,,,,
+//
+// - Prix arrondis à 6 décimales (zéros finaux retirés), sans symbole
+//   monétaire, séparateur décimal '.' : la devise a sa propre colonne
+// - Variation du jour en %, arrondie à 2 décimales
+// - Volume de la dernière séance, heure de la dernière bougie (RFC 3339)
+// - Ticker sans données : colonnes de cotation vides
+// - Champs contenant une virgule ou un guillemet entourés de guillemets
+//   (RFC 4180) : "Berkshire Hathaway, Inc."
+// ============================================================================
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::models::WatchlistItem;
+
+/// En-tête du fichier
+const HEADER: &str = "symbol,name,price,currency,change_percent,volume,updated_at";
+
+/// Échappe un champ CSV (guillemets si virgule, guillemet ou retour à la ligne)
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Nombre arrondi à 6 décimales, sans zéros finaux : 153.000000 → "153"
+fn format_number(value: f64) -> String {
+    let text = format!("{:.6}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Ligne CSV d'un item de la watchlist
+fn format_row(item: &WatchlistItem) -> String {
+    let quoted = item.has_data();
+    let currency = item.currency();
+    let price = item.current_price().map(format_number).unwrap_or_default();
+    let change = item.change_percent().map(|change| format!("{:.2}", change)).unwrap_or_default();
+    let volume = item.session_volume().map(|volume| volume.to_string()).unwrap_or_default();
+    let updated_at = item.last_ohlc().map(|candle| candle.timestamp.to_rfc3339()).unwrap_or_default();
+    let currency = if quoted { currency.code().to_string() } else { String::new() };
+
+    [item.symbol.as_str(), item.name.as_str(), &price, &currency, &change, &volume, &updated_at]
+        .iter()
+        .map(|field| escape(field))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Contenu CSV de la watchlist (en-tête compris)
+pub fn format_quotes(items: &[WatchlistItem]) -> String {
+    let mut content = String::from(HEADER);
+    content.push('\n');
+    for item in items {
+        content.push_str(&format_row(item));
+        content.push('\n');
+    }
+    content
+}
+
+/// Écrit la watchlist dans un fichier CSV (crée le répertoire si besoin)
+///
+/// Retourne le nombre de tickers exportés
+pub fn export_file(path: &Path, items: &[WatchlistItem]) -> Result<usize> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+    }
+
+    std::fs::write(path, format_quotes(items)).with_context(|| format!("Échec de l'écriture de {}", path.display()))?;
+    Ok(items.len())
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interval, OHLCData, OHLC};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_format_quotes() {
+        let time = Utc.with_ymd_and_hms(2026, 10, 14, 15, 30, 0).unwrap();
+        let mut data = OHLCData::with_interval("AIR.PA".to_string(), Interval::D1);
+        data.add_candle(OHLC::new(time, 150.0, 154.0, 149.0, 153.0, 1_250_000));
+        let airbus = WatchlistItem::with_data("AIR.PA".to_string(), "Airbus, SE".to_string(), data);
+        let tesla = WatchlistItem::new("TSLA".to_string(), "Tesla \"TSLA\"".to_string());
+
+        let content = format_quotes(&[airbus, tesla]);
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines[0], HEADER);
+        assert_eq!(lines[1], "AIR.PA,\"Airbus, SE\",153,EUR,2.00,1250000,2026-10-14T15:30:00+00:00");
+        assert_eq!(lines[2], "TSLA,\"Tesla \"\"TSLA\"\"\",,,,,");
+
+        assert_eq!(format_number(1.0762025705), "1.076203");
+        assert_eq!(format_number(0.0000123), "0.000012");
+    }
+}
@@ -0,0 +1,180 @@
+// ============================================================================
+// Export du graphique en texte (chandeliers Unicode)
+// ============================================================================
+// Le graphique est redessiné sur un terminal virtuel (TestBackend) de la
+// taille du terminal, puis écrit tel quel dans un fichier : titre, axes et
+// chandeliers, prêts à coller dans un chat ou des notes
+//
+// - Texte brut (.txt) : symboles seuls, espaces de fin retirés
+// - ANSI (.ans) : couleurs et attributs conservés en séquences SGR
+//   (ESC [ ... m), lisibles avec `cat` ou `less -R`
+//
+// CONCEPT : Caractères larges
+// - Un emoji occupe deux cellules du buffer, la seconde est un espace
+// - Elle est sautée à l'écriture : la ligne garde la largeur du terminal
+// ============================================================================
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use ratatui::{
+    backend::TestBackend,
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Modifier},
+    text::Span,
+    Terminal,
+};
+
+use crate::app::App;
+use crate::ui::candlestick_text::render_candlestick_chart;
+
+/// Code SGR d'une couleur (premier plan ; +10 pour le fond)
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let basic = |code: u8| Some((code + offset).to_string());
+    match color {
+        Color::Reset => None,
+        Color::Black => basic(30),
+        Color::Red => basic(31),
+        Color::Green => basic(32),
+        Color::Yellow => basic(33),
+        Color::Blue => basic(34),
+        Color::Magenta => basic(35),
+        Color::Cyan => basic(36),
+        Color::Gray => basic(37),
+        Color::DarkGray => basic(90),
+        Color::LightRed => basic(91),
+        Color::LightGreen => basic(92),
+        Color::LightYellow => basic(93),
+        Color::LightBlue => basic(94),
+        Color::LightMagenta => basic(95),
+        Color::LightCyan => basic(96),
+        Color::White => basic(97),
+        Color::Indexed(index) => Some(format!("{};5;{}", 38 + offset, index)),
+        Color::Rgb(r, g, b) => Some(format!("{};2;{};{};{}", 38 + offset, r, g, b)),
+    }
+}
+
+/// Séquence SGR du style d'une cellule (remise à zéro comprise)
+fn sgr(cell: &Cell) -> String {
+    const MODIFIERS: &[(Modifier, &str)] = &[
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ];
+
+    let mut codes = vec!["0".to_string()];
+    codes.extend(
+        MODIFIERS
+            .iter()
+            .filter(|(modifier, _)| cell.modifier.contains(*modifier))
+            .map(|(_, code)| code.to_string()),
+    );
+    codes.extend(color_code(cell.fg, false));
+    codes.extend(color_code(cell.bg, true));
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// Cellules affichées d'une rangée (seconde cellule des caractères larges sautée)
+fn visible_cells(row: &[Cell]) -> Vec<&Cell> {
+    let mut cells = Vec::with_capacity(row.len());
+    let mut skip = 0;
+    for cell in row {
+        if skip > 0 {
+            skip -= 1;
+            continue;
+        }
+        skip = Span::raw(cell.symbol()).width().saturating_sub(1);
+        cells.push(cell);
+    }
+    cells
+}
+
+/// Texte d'un buffer, une ligne par rangée
+///
+/// `ansi` : couleurs et attributs conservés en séquences SGR
+pub fn buffer_to_text(buffer: &Buffer, ansi: bool) -> String {
+    let width = buffer.area.width as usize;
+    let mut text = String::new();
+
+    for row in buffer.content.chunks(width.max(1)) {
+        let mut cells = visible_cells(row);
+        // Espaces de fin (sans fond coloré) retirés
+        while cells.last().is_some_and(|cell| cell.symbol() == " " && cell.bg == Color::Reset) {
+            cells.pop();
+        }
+
+        let mut previous = None;
+        for cell in &cells {
+            if ansi {
+                let style = sgr(cell);
+                if previous.as_ref() != Some(&style) {
+                    text.push_str(&style);
+                    previous = Some(style);
+                }
+            }
+            text.push_str(cell.symbol());
+        }
+        if ansi && previous.is_some() {
+            text.push_str("\x1b[0m");
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Dessine le graphique du ticker sélectionné sur un terminal virtuel
+pub fn render_chart_buffer(app: &App, width: u16, height: u16) -> Result<Buffer> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height))?;
+    terminal.draw(|frame| render_candlestick_chart(frame, app, Rect::new(0, 0, width, height)))?;
+    Ok(terminal.backend().buffer().clone())
+}
+
+/// Écrit le graphique du ticker sélectionné dans un fichier texte
+pub fn export_chart_text(app: &App, width: u16, height: u16, ansi: bool, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+    }
+
+    let buffer = render_chart_buffer(app, width, height)?;
+    std::fs::write(path, buffer_to_text(&buffer, ansi))
+        .with_context(|| format!("Échec de l'écriture de {}", path.display()))
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Style;
+    use crate::ui::snapshot::sample_item;
+
+    #[test]
+    fn test_buffer_to_text() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        buffer.set_string(0, 0, "▲ 📊 ok", Style::default().fg(Color::Green));
+        buffer.set_string(0, 1, "ab", Style::default());
+
+        // L'emoji compte pour deux colonnes : pas d'espace en trop
+        assert_eq!(buffer_to_text(&buffer, false), "▲ 📊 ok\nab\n");
+        assert_eq!(buffer_to_text(&buffer, true), "\x1b[0;32m▲ 📊 ok\x1b[0m\n\x1b[0mab\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_chart_text_has_title_and_axes() {
+        let app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 60)]);
+        let text = buffer_to_text(&render_chart_buffer(&app, 100, 30).unwrap(), false);
+        assert!(text.contains("AAPL"), "{}", text);
+        assert!(text.contains('┃') || text.contains('│'), "{}", text);
+        assert!(!text.contains('\x1b'));
+        assert_eq!(text.lines().count(), 30);
+    }
+}
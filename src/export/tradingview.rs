@@ -0,0 +1,251 @@
+// ============================================================================
+// Listes de symboles TradingView (import / export)
+// ============================================================================
+// TradingView importe et exporte ses listes au format texte
+// `EXCHANGE:SYMBOL`, séparées par des virgules ou des retours à la ligne :
+//
+//   ###Actions,NASDAQ:AAPL,EURONEXT:MC,LSE:VOD
+//   ###Crypto,COINBASE:BTCUSD
+//
+// Correspondance avec les symboles Yahoo :
+// - Actions : préfixe de la place ↔ suffixe Yahoo (LSE:VOD ↔ VOD.L,
+//   XETR:SAP ↔ SAP.DE) ; les places américaines n'ont pas de suffixe et
+//   la place n'est pas connue à l'export (NASDAQ, AMEX pour les ETF)
+// - Crypto : COINBASE:BTCUSD ↔ BTC-USD (toute plateforme crypto à l'import)
+// - Devises : FX:EURUSD ↔ EURUSD=X
+// - Indices : table des principaux (SP:SPX ↔ ^GSPC, ...)
+//
+// Les lignes "###Section" sont ignorées à l'import ; un symbole sans
+// équivalent est ignoré (et journalisé) à l'export
+// ============================================================================
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use crate::models::ticker::CRYPTO_QUOTES;
+use crate::models::TickerType;
+
+/// Places boursières : (préfixe TradingView, suffixe Yahoo)
+///
+/// Euronext regroupe Paris, Amsterdam et Bruxelles sous un seul préfixe :
+/// Paris est retenu à l'import
+const EXCHANGES: &[(&str, &str)] = &[
+    ("EURONEXT", ".PA"),
+    ("EURONEXT", ".AS"),
+    ("EURONEXT", ".BR"),
+    ("LSE", ".L"),
+    ("XETR", ".DE"),
+    ("FWB", ".F"),
+    ("SIX", ".SW"),
+    ("BME", ".MC"),
+    ("MIL", ".MI"),
+    ("OMXSTO", ".ST"),
+    ("OMXCOP", ".CO"),
+    ("OMXHEX", ".HE"),
+    ("OSL", ".OL"),
+    ("TSX", ".TO"),
+    ("TSXV", ".V"),
+    ("ASX", ".AX"),
+    ("HKEX", ".HK"),
+    ("TSE", ".T"),
+    ("NSE", ".NS"),
+    ("BSE", ".BO"),
+    ("KRX", ".KS"),
+    ("SGX", ".SI"),
+    ("TWSE", ".TW"),
+    ("JSE", ".JO"),
+    ("BMFBOVESPA", ".SA"),
+];
+
+/// Places américaines (symbole Yahoo sans suffixe)
+const US_EXCHANGES: &[&str] = &["NASDAQ", "NYSE", "AMEX", "ARCA", "NYSEARCA", "BATS", "OTC"];
+
+/// Plateformes crypto reconnues à l'import (COINBASE à l'export)
+const CRYPTO_EXCHANGES: &[&str] = &["COINBASE", "BINANCE", "BITSTAMP", "KRAKEN", "BYBIT", "OKX", "BITFINEX", "CRYPTO"];
+
+/// Sources de cotations de devises reconnues à l'import (FX à l'export)
+const FOREX_EXCHANGES: &[&str] = &["FX", "FX_IDC", "OANDA", "FOREXCOM", "SAXO"];
+
+/// Indices : (symbole TradingView, symbole Yahoo)
+const INDICES: &[(&str, &str)] = &[
+    ("SP:SPX", "^GSPC"),
+    ("NASDAQ:NDX", "^NDX"),
+    ("NASDAQ:IXIC", "^IXIC"),
+    ("DJ:DJI", "^DJI"),
+    ("TVC:RUT", "^RUT"),
+    ("TVC:VIX", "^VIX"),
+    ("EURONEXT:PX1", "^FCHI"),
+    ("XETR:DAX", "^GDAXI"),
+    ("TVC:UKX", "^FTSE"),
+    ("TVC:SX5E", "^STOXX50E"),
+    ("TVC:NI225", "^N225"),
+    ("TVC:HSI", "^HSI"),
+];
+
+/// Symbole TradingView d'un symbole Yahoo (None : pas d'équivalent)
+pub fn to_tradingview(symbol: &str) -> Option<String> {
+    let symbol = symbol.trim().to_uppercase();
+
+    if let Some((tradingview, _)) = INDICES.iter().find(|(_, yahoo)| *yahoo == symbol) {
+        return Some(tradingview.to_string());
+    }
+
+    match TickerType::from_symbol(&symbol) {
+        TickerType::Index => None,
+        TickerType::Forex => Some(format!("FX:{}", symbol.strip_suffix("=X")?)),
+        TickerType::Crypto => Some(format!("COINBASE:{}", symbol.replace('-', ""))),
+        ticker_type @ (TickerType::Stock | TickerType::ETF) => {
+            // Contrats à terme (GC=F), devises hors Yahoo... : pas d'équivalent
+            if symbol.contains('=') {
+                return None;
+            }
+            let suffixed = EXCHANGES
+                .iter()
+                .find_map(|(exchange, suffix)| Some((exchange, symbol.strip_suffix(suffix)?)));
+            match suffixed {
+                Some((exchange, ticker)) => Some(format!("{}:{}", exchange, ticker)),
+                None if symbol.contains('.') => None,
+                // Place américaine inconnue : TradingView corrige le préfixe à l'import
+                None if ticker_type == TickerType::ETF => Some(format!("AMEX:{}", symbol.replace('-', "."))),
+                None => Some(format!("NASDAQ:{}", symbol.replace('-', "."))),
+            }
+        }
+    }
+}
+
+/// Symbole Yahoo d'une entrée TradingView (None : pas d'équivalent)
+///
+/// Une entrée sans préfixe ("AAPL") est reprise telle quelle
+pub fn from_tradingview(entry: &str) -> Option<String> {
+    let entry = entry.trim().to_uppercase();
+    if entry.is_empty() {
+        return None;
+    }
+
+    if let Some((_, yahoo)) = INDICES.iter().find(|(tradingview, _)| *tradingview == entry) {
+        return Some(yahoo.to_string());
+    }
+
+    let Some((exchange, ticker)) = entry.split_once(':') else {
+        return Some(entry);
+    };
+    if ticker.is_empty() {
+        return None;
+    }
+
+    if US_EXCHANGES.contains(&exchange) {
+        // Yahoo note les classes d'actions avec un tiret (BRK.B → BRK-B)
+        return Some(ticker.replace('.', "-"));
+    }
+    if FOREX_EXCHANGES.contains(&exchange) {
+        return Some(format!("{}=X", ticker));
+    }
+    if CRYPTO_EXCHANGES.contains(&exchange) {
+        // Suffixes les plus longs d'abord (USDT avant USD)
+        let mut quotes = CRYPTO_QUOTES.to_vec();
+        quotes.sort_by_key(|quote| std::cmp::Reverse(quote.len()));
+        return quotes.iter().find_map(|quote| {
+            let base = ticker.strip_suffix(quote).filter(|base| !base.is_empty())?;
+            Some(format!("{}-{}", base, quote))
+        });
+    }
+
+    EXCHANGES
+        .iter()
+        .find(|(prefix, _)| *prefix == exchange)
+        .map(|(_, suffix)| format!("{}{}", ticker, suffix))
+}
+
+/// Lit une liste TradingView : symboles Yahoo, sans doublon, dans l'ordre
+pub fn parse_list(content: &str) -> Vec<String> {
+    let mut symbols: Vec<String> = Vec::new();
+
+    for entry in content.split([',', '\n', '\r']) {
+        let entry = entry.trim();
+        if entry.is_empty() || entry.starts_with("###") {
+            continue;
+        }
+        match from_tradingview(entry) {
+            Some(symbol) if !symbols.contains(&symbol) => symbols.push(symbol),
+            Some(_) => {}
+            None => debug!(entry = %entry, "No Yahoo equivalent for TradingView symbol, skipped"),
+        }
+    }
+    symbols
+}
+
+/// Construit une liste TradingView (une entrée par ligne)
+pub fn format_list(symbols: &[String]) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for symbol in symbols {
+        match to_tradingview(symbol) {
+            Some(entry) => lines.push(entry),
+            None => debug!(ticker = %symbol, "No TradingView equivalent, skipped from export"),
+        }
+    }
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// Importe un fichier de liste TradingView
+pub fn import_file(path: &Path) -> Result<Vec<String>> {
+    let content =
+        std::fs::read_to_string(path).with_context(|| format!("Échec de la lecture de {}", path.display()))?;
+    Ok(parse_list(&content))
+}
+
+/// Exporte des symboles vers un fichier de liste TradingView
+///
+/// Retourne le nombre de symboles exportés (crée le répertoire si besoin)
+pub fn export_file(path: &Path, symbols: &[String]) -> Result<usize> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+    }
+
+    let content = format_list(symbols);
+    std::fs::write(path, &content).with_context(|| format!("Échec de l'écriture de {}", path.display()))?;
+    Ok(content.lines().count())
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_tradingview() {
+        assert_eq!(to_tradingview("AAPL").as_deref(), Some("NASDAQ:AAPL"));
+        assert_eq!(to_tradingview("BRK-B").as_deref(), Some("NASDAQ:BRK.B"));
+        assert_eq!(to_tradingview("SPY").as_deref(), Some("AMEX:SPY"));
+        assert_eq!(to_tradingview("MC.PA").as_deref(), Some("EURONEXT:MC"));
+        assert_eq!(to_tradingview("VOD.L").as_deref(), Some("LSE:VOD"));
+        assert_eq!(to_tradingview("BTC-USD").as_deref(), Some("COINBASE:BTCUSD"));
+        assert_eq!(to_tradingview("EURUSD=X").as_deref(), Some("FX:EURUSD"));
+        assert_eq!(to_tradingview("^GSPC").as_deref(), Some("SP:SPX"));
+        assert_eq!(to_tradingview("^XYZ"), None);
+        assert_eq!(to_tradingview("GC=F"), None);
+        assert_eq!(to_tradingview("ABC.XX"), None);
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let content = "###Actions,NASDAQ:AAPL,EURONEXT:MC,NYSE:BRK.B\n\
+                       ###Crypto,BINANCE:BTCUSDT,COINBASE:ETHUSD\n\
+                       FX:EURUSD,SP:SPX,XETR:SAP,MSFT,UNKNOWN:XYZ,NASDAQ:AAPL\n";
+        assert_eq!(
+            parse_list(content),
+            vec!["AAPL", "MC.PA", "BRK-B", "BTC-USDT", "ETH-USD", "EURUSD=X", "^GSPC", "SAP.DE", "MSFT"]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let symbols: Vec<String> = ["AAPL", "SAP.DE", "ETH-EUR", "GBPUSD=X", "^FCHI"].map(String::from).to_vec();
+        assert_eq!(parse_list(&format_list(&symbols)), symbols);
+    }
+}
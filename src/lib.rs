@@ -1,10 +1,38 @@
 // ============================================================================
 // LazyWallet - Library
 // ============================================================================
-// Expose les modules publics pour les exemples et tests
+// Expose les modules publics pour les exemples, les tests et les programmes
+// qui embarquent la couche données (voir service.rs)
+//
+// CONCEPT CARGO : Features
+// - "tui" (par défaut) : ui (ratatui, crossterm) et export PNG (plotters)
+// - Sans "tui" : modèles, API, alertes, WatchlistService, démon et API HTTP
+// - "store" (par défaut) : historique local des bougies (SQLite)
+//
+//   [dependencies]
+//   lazywallet = { version = "0.1", default-features = false }
 // ============================================================================
 
 pub mod api;       // API Yahoo Finance
 pub mod models;    // Structures de données
 pub mod app;       // État de l'application
+#[cfg(feature = "tui")]
 pub mod ui;        // Interface utilisateur
+pub mod export;    // Export des données (PNG, ...)
+pub mod config;    // Configuration utilisateur (config.json)
+pub mod profile;   // Profils isolés (--profile NOM)
+pub mod alerts;    // Alertes persistantes (résultats, ...)
+pub mod backtest;  // Backtest de stratégies simples (hors ligne)
+pub mod daemon;    // Mode démon sans TUI (--daemon)
+pub mod server;    // API HTTP locale en lecture seule (config "http_api")
+pub mod service;   // WatchlistService : couche données sans TUI
+pub mod demo;      // Mode démo : données synthétiques sans réseau (--demo)
+#[cfg(feature = "store")]
+pub mod store;     // Historique local des bougies (config "candle_store")
+pub mod queue;     // File de commandes priorisée (worker de la TUI)
+pub mod onboarding; // Configuration guidée du premier lancement
+pub mod command;   // Commandes saisies après ':' (:loglevel, ...)
+pub mod logging;   // Fichiers de logs : emplacement et rétention
+pub mod persist;   // Format des fichiers de données (JSON ou TOML trié)
+
+pub use service::{Quote, WatchlistService};
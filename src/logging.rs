@@ -0,0 +1,174 @@
+// ============================================================================
+// Fichiers de logs : emplacement et rétention
+// ============================================================================
+// Un fichier par jour (rotation de tracing-appender) :
+//
+//   ~/.local/share/lazywallet/logs/lazywallet.log.2024-01-15
+//   ~/.local/share/lazywallet/logs/lazywallet.log.2024-01-16
+//
+// Réglages (section "logging" de config.json) :
+//   "logging": { "dir": "~/logs/lazywallet", "retention_days": 14, "max_total_mb": 100 }
+//
+// - Au démarrage, les fichiers plus vieux que retention_days sont supprimés,
+//   puis les plus anciens tant que le total dépasse max_total_mb
+// - Le fichier du jour n'est jamais supprimé (il est en cours d'écriture)
+// - Le subscriber tracing est créé dans main.rs (binaire TUI)
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::api::csv::expand_home;
+use crate::profile;
+
+/// Préfixe des fichiers de logs (suffixe : date de la rotation)
+pub const FILE_PREFIX: &str = "lazywallet.log";
+
+/// Réglages des fichiers de logs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogSettings {
+    /// Répertoire des logs ("~/" accepté)
+    /// Sans valeur : répertoire de données du profil (voir default_dir)
+    pub dir: Option<PathBuf>,
+
+    /// Nombre de jours de logs gardés, jour courant compris (0 : sans limite)
+    pub retention_days: u32,
+
+    /// Taille maximale de l'ensemble des fichiers, en Mo (0 : sans limite)
+    pub max_total_mb: u64,
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            dir: None,
+            retention_days: 14,
+            max_total_mb: 100,
+        }
+    }
+}
+
+impl LogSettings {
+    /// Répertoire effectif des logs
+    pub fn directory(&self) -> PathBuf {
+        match &self.dir {
+            Some(dir) => expand_home(dir),
+            None => default_dir(),
+        }
+    }
+}
+
+/// Répertoire par défaut des logs
+///
+/// - Linux : ~/.local/share/lazywallet/logs (ou .../profiles/NOM/logs)
+/// - Fallback : ./logs
+pub fn default_dir() -> PathBuf {
+    profile::data_dir()
+        .map(|dir| dir.join("logs"))
+        .unwrap_or_else(|| PathBuf::from("./logs"))
+}
+
+/// Supprime les fichiers de logs au-delà de la rétention et de la taille
+/// maximale
+///
+/// Seuls les fichiers "lazywallet.log.AAAA-MM-JJ" sont concernés : les autres
+/// fichiers du répertoire ne sont jamais touchés
+/// Retourne les fichiers supprimés
+pub fn cleanup(dir: &Path, settings: &LogSettings, today: NaiveDate) -> Result<Vec<PathBuf>> {
+    let entries = std::fs::read_dir(dir).with_context(|| format!("Échec de la lecture de {}", dir.display()))?;
+
+    // (date, taille, chemin), du plus récent au plus ancien
+    let mut files: Vec<(NaiveDate, u64, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let date = rotation_date(&entry.file_name().to_string_lossy())?;
+            let size = entry.metadata().ok().filter(|metadata| metadata.is_file())?.len();
+            Some((date, size, entry.path()))
+        })
+        .collect();
+    files.sort_by_key(|file| std::cmp::Reverse(file.0));
+
+    let oldest_kept = match settings.retention_days {
+        0 => None,
+        days => today.checked_sub_days(chrono::Days::new(days as u64 - 1)),
+    };
+    let max_bytes = settings.max_total_mb.saturating_mul(1024 * 1024);
+
+    let mut removed = Vec::new();
+    let mut total = 0u64;
+    for (date, size, path) in files {
+        total += size;
+        let expired = oldest_kept.is_some_and(|oldest| date < oldest);
+        let over_size = max_bytes > 0 && total > max_bytes;
+        if date < today && (expired || over_size) {
+            std::fs::remove_file(&path).with_context(|| format!("Échec de la suppression de {}", path.display()))?;
+            removed.push(path);
+        }
+    }
+    Ok(removed)
+}
+
+/// Date d'un fichier de la rotation ("lazywallet.log.2024-01-15")
+fn rotation_date(file_name: &str) -> Option<NaiveDate> {
+    let date = file_name.strip_prefix(FILE_PREFIX)?.strip_prefix('.')?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_rotation_date() {
+        assert_eq!(rotation_date("lazywallet.log.2024-01-15"), Some(date(15)));
+        assert_eq!(rotation_date("lazywallet.log"), None);
+        assert_eq!(rotation_date("other.log.2024-01-15"), None);
+        assert_eq!(rotation_date("lazywallet.log.backup"), None);
+    }
+
+    #[test]
+    fn test_cleanup_applies_retention_and_size_cap() {
+        let dir = std::env::temp_dir().join(format!("lazywallet-logs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let write = |name: &str, size: usize| std::fs::write(dir.join(name), vec![b'x'; size]).unwrap();
+        for day in 1..=10 {
+            write(&format!("lazywallet.log.2024-01-{:02}", day), 400 * 1024);
+        }
+        write("notes.txt", 10);
+        let remaining = || {
+            let mut names: Vec<String> =
+                std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned()).collect();
+            names.sort();
+            names
+        };
+
+        // Rétention : 7 jours, jour courant compris (le 10)
+        let settings = LogSettings { dir: None, retention_days: 7, max_total_mb: 0 };
+        assert_eq!(cleanup(&dir, &settings, date(10)).unwrap().len(), 3);
+        assert_eq!(remaining()[0], "lazywallet.log.2024-01-04");
+
+        // Taille : 1 Mo garde les deux plus récents (800 Ko)
+        let settings = LogSettings { dir: None, retention_days: 0, max_total_mb: 1 };
+        cleanup(&dir, &settings, date(10)).unwrap();
+        assert_eq!(remaining(), ["lazywallet.log.2024-01-09", "lazywallet.log.2024-01-10", "notes.txt"]);
+
+        // Le fichier du jour reste, même au-delà de la taille maximale
+        write("lazywallet.log.2024-01-10", 2 * 1024 * 1024);
+        cleanup(&dir, &settings, date(10)).unwrap();
+        assert_eq!(remaining(), ["lazywallet.log.2024-01-10", "notes.txt"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -11,22 +11,71 @@
 // 4. RAII : restauration automatique du terminal avec Drop
 // ============================================================================
 
-use std::io;
+use std::collections::{BTreeSet, HashMap};
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, mpsc};
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use tracing::{debug, error, info};
-
-use lazywallet::api::yahoo::fetch_ticker_data;
-use lazywallet::app::App;
-use lazywallet::models::{Interval, OHLCData, WatchlistItem};
-use lazywallet::ui::{events::EventHandler, render};
+use tracing::{debug, error, info, warn};
+
+use lazywallet::api::binance::{fetch_order_book, DEFAULT_DEPTH};
+use lazywallet::api::connectivity;
+use lazywallet::api::csv::{self, CustomInstrument};
+use lazywallet::api::coingecko;
+use lazywallet::api::economic_calendar;
+use lazywallet::api::fear_greed;
+use lazywallet::api::health;
+use lazywallet::api::http;
+use lazywallet::api::metrics;
+use lazywallet::api::recording;
+use lazywallet::api::remote_watchlist::{self, RemoteWatchlist};
+use lazywallet::api::webhook::post_alert;
+use lazywallet::api::yahoo::dividends::fetch_dividends;
+use lazywallet::api::yahoo::financials::fetch_financials;
+use lazywallet::api::yahoo::holders::fetch_holders;
+use lazywallet::api::yahoo::{fetch_analyst_ratings, fetch_earnings_date, fetch_quote_type, fetch_screener, fetch_ticker_data};
+use lazywallet::alerts::command::spawn_alert_command;
+use lazywallet::alerts::condition::Condition;
+use lazywallet::alerts::{AlertBook, AlertTrigger, Recurrence};
+use lazywallet::app::{App, InputPurpose, TrendLineEdit, MAX_COMPARED};
+use lazywallet::command::Command;
+use lazywallet::config::Config;
+use lazywallet::daemon::{self, DaemonState};
+use lazywallet::logging;
+use lazywallet::demo;
+use lazywallet::persist::{self, StorageFormat};
+use lazywallet::profile;
+use lazywallet::server;
+use lazywallet::export::{export_chart_png, export_dir, export_file_name, quotes_csv, text, tradingview};
+use lazywallet::models::analyst::AnalystRatings;
+use lazywallet::models::dividends::DividendInfo;
+use lazywallet::models::economic_calendar::EconomicEvent;
+use lazywallet::models::financials::Financials;
+use lazywallet::models::holders::Holders;
+use lazywallet::models::annotations::Annotations;
+use lazywallet::onboarding::Onboarding;
+use lazywallet::models::currency::Currency;
+use lazywallet::models::crypto_stats::CryptoStats;
+use lazywallet::models::fear_greed::FearGreed;
+use lazywallet::models::journal::{CashMovement, Journal, Transaction};
+use lazywallet::models::ohlc;
+use lazywallet::models::order_book::OrderBook;
+use lazywallet::models::portfolio::Portfolio;
+use lazywallet::models::screener::{Screener, ScreenerQuote};
+use lazywallet::models::notes::Notes;
+use lazywallet::models::saved_watchlist::SavedWatchlist;
+use lazywallet::models::tags::{parse_tags, Tags};
+use lazywallet::models::{Interval, OHLCData, TickerType, WatchlistItem};
+use lazywallet::queue::{self, Prioritized, Priority, QueueReceiver, QueueSender};
+use lazywallet::ui::{accessibility, ascii, candlestick_text, clipboard, events::EventHandler, format, onboarding::render_onboarding, render};
 
 // ============================================================================
 // AppCommand : Commandes pour le worker thread
@@ -34,7 +83,10 @@ use lazywallet::ui::{events::EventHandler, render};
 // CONCEPT RUST : Command pattern avec channels
 // - L'event loop envoie des commandes au worker thread
 // - Le worker thread exécute les tâches async (fetch API)
-// - Communication via mpsc channels (multi-producer, single-consumer)
+// - Commandes : file priorisée (voir queue.rs), résultats : canal mpsc
+// - Priorité : touche de l'utilisateur > rafraîchissement de ce qui est
+//   affiché > préchargement ; un rechargement en file est remplacé par le
+//   suivant du même ticker
 // ============================================================================
 
 /// Commandes envoyées au worker thread pour exécuter des tâches async
@@ -44,11 +96,13 @@ enum AppCommand {
     /// CONCEPT : Background data loading
     /// - symbol: ticker à recharger (ex: "AAPL")
     /// - interval: nouvel intervalle (ex: Interval::M15)
-    /// - index: position dans la watchlist
+    /// - request_id: identifiant de la demande (voir App::begin_load)
+    /// - silent: rafraîchissement automatique, sans indicateur de chargement
     ReloadTickerData {
         symbol: String,
         interval: Interval,
-        index: usize,
+        request_id: u64,
+        silent: bool,
     },
 
     /// Ajouter un nouveau ticker à la watchlist
@@ -58,14 +112,176 @@ enum AppCommand {
     AddTicker {
         symbol: String,
     },
+
+    /// Charger les données d'un ticker pour un intervalle secondaire
+    /// CONCEPT : Cache par intervalle (vue multi-timeframe)
+    /// - Les données sont stockées dans WatchlistItem::interval_cache
+    /// - Les données principales (item.data) ne sont pas modifiées
+    LoadIntervalData {
+        symbol: String,
+        interval: Interval,
+    },
+
+    /// Charger un indice de référence superposé au graphique (touche v)
+    /// - Les données sont stockées dans App::benchmark_cache
+    LoadBenchmark {
+        symbol: String,
+        interval: Interval,
+    },
+
+    /// Charger les indices du bandeau "marchés" (dashboard)
+    /// - symbols: indices configurés (ex: "^GSPC")
+    /// - Rafraîchi périodiquement depuis l'event loop
+    LoadMarketOverview {
+        symbols: Vec<String>,
+    },
+
+    /// Charger l'indice Fear & Greed crypto (header du dashboard)
+    /// - Une fois par jour, la valeur est gardée en cache sur disque
+    FetchFearGreed,
+
+    /// Charger les statistiques de marché CoinGecko d'une crypto (fiche
+    /// détaillée) - rafraîchies périodiquement, gardées en cache sur disque
+    FetchCryptoStats {
+        symbol: String,
+    },
+
+    /// Recharger la liste distante de la watchlist (config.remote_watchlist)
+    /// - Les symboles ajoutés ou retirés sont appliqués à la réception
+    FetchRemoteWatchlist {
+        url: String,
+    },
+
+    /// Recharger le calendrier économique (config.economic_calendar)
+    FetchEconomicCalendar {
+        url: String,
+    },
+
+    /// Récupérer le carnet d'ordres d'une crypto (Binance)
+    /// CONCEPT : Polling périodique depuis l'event loop
+    /// - Envoyé toutes les ~2s tant que le panneau est visible
+    FetchOrderBook {
+        symbol: String,
+    },
+
+    /// Exporter le graphique d'un ticker en image PNG
+    /// CONCEPT : Rendu CPU dans le worker
+    /// - symbol: ticker à exporter, les données sont lues depuis App
+    /// - Le rendu ne bloque pas l'UI
+    ExportChart {
+        symbol: String,
+    },
+
+    /// Récupérer la date de publication des prochains résultats (actions)
+    FetchEarnings {
+        symbol: String,
+    },
+
+    /// Récupérer les recommandations des analystes et l'objectif de cours
+    /// (actions, à l'ouverture de la fiche détaillée)
+    FetchAnalystRatings {
+        symbol: String,
+    },
+
+    /// Récupérer les transactions des initiés et les détenteurs
+    /// institutionnels (actions, à l'ouverture du panneau [h])
+    FetchHolders {
+        symbol: String,
+    },
+
+    /// Récupérer le chiffre d'affaires, le résultat net et le free cash flow
+    /// publiés (actions, à l'ouverture de la fiche détaillée)
+    FetchFinancials {
+        symbol: String,
+    },
+
+    /// Récupérer les dates et le montant du prochain dividende (actions et
+    /// ETF, à l'ouverture du calendrier des dividendes)
+    FetchDividends {
+        symbol: String,
+    },
+
+    /// Charger les résultats d'un screener prédéfini (vue découverte)
+    LoadScreener {
+        screener: Screener,
+    },
+
+    /// Envoyer une alerte déclenchée à son webhook (POST JSON)
+    SendWebhook {
+        url: String,
+        trigger: AlertTrigger,
+    },
+
+    /// Lancer la commande d'une alerte déclenchée (sortie journalisée)
+    RunAlertCommand {
+        template: String,
+        trigger: AlertTrigger,
+    },
+
+    /// Sonder le réseau (envoyé périodiquement, plus souvent hors ligne)
+    ProbeConnectivity,
+
+    /// Arrêter le worker (fermeture de l'application)
+    /// Les commandes encore en file sont abandonnées, sauf les alertes
+    Shutdown,
+}
+
+impl AppCommand {
+    /// Vérifie si la commande est exécutée même pendant la fermeture
+    ///
+    /// Une alerte déjà déclenchée est livrée (webhook, commande) ; un
+    /// chargement dont personne n'affichera le résultat est abandonné
+    fn runs_on_shutdown(&self) -> bool {
+        matches!(self, AppCommand::SendWebhook { .. } | AppCommand::RunAlertCommand { .. } | AppCommand::Shutdown)
+    }
+}
+
+impl Prioritized for AppCommand {
+    /// Priorité par défaut (un préchargement D1 impose Background à l'envoi)
+    fn priority(&self) -> Priority {
+        match self {
+            AppCommand::ReloadTickerData { silent: true, .. }
+            | AppCommand::LoadMarketOverview { .. }
+            | AppCommand::FetchOrderBook { .. }
+            | AppCommand::ProbeConnectivity => Priority::AutoRefresh,
+            AppCommand::FetchFearGreed
+            | AppCommand::FetchRemoteWatchlist { .. }
+            | AppCommand::FetchEconomicCalendar { .. }
+            | AppCommand::FetchEarnings { .. }
+            | AppCommand::FetchDividends { .. } => Priority::Background,
+            _ => Priority::User,
+        }
+    }
+
+    /// Un nouveau chargement des mêmes données remplace celui encore en file
+    fn supersede_key(&self) -> Option<String> {
+        match self {
+            AppCommand::ReloadTickerData { symbol, .. } => Some(format!("reload:{}", symbol)),
+            AppCommand::LoadIntervalData { symbol, interval } => Some(format!("interval:{}:{}", symbol, interval.to_yahoo_string())),
+            AppCommand::LoadBenchmark { symbol, interval } => Some(format!("benchmark:{}:{}", symbol, interval.to_yahoo_string())),
+            AppCommand::LoadMarketOverview { .. } => Some("market_overview".to_string()),
+            AppCommand::FetchOrderBook { .. } => Some("order_book".to_string()),
+            AppCommand::FetchEarnings { symbol } => Some(format!("earnings:{}", symbol)),
+            AppCommand::FetchAnalystRatings { symbol } => Some(format!("analyst:{}", symbol)),
+            AppCommand::FetchHolders { symbol } => Some(format!("holders:{}", symbol)),
+            AppCommand::FetchCryptoStats { symbol } => Some(format!("crypto_stats:{}", symbol)),
+            AppCommand::FetchFinancials { symbol } => Some(format!("financials:{}", symbol)),
+            AppCommand::FetchDividends { symbol } => Some(format!("dividends:{}", symbol)),
+            AppCommand::LoadScreener { .. } => Some("screener".to_string()),
+            AppCommand::ProbeConnectivity => Some("probe".to_string()),
+            _ => None,
+        }
+    }
 }
 
 /// Résultats renvoyés par le worker thread
 #[derive(Debug)]
 enum AppResult {
     /// Données d'un ticker rechargées avec succès
+    /// Adressées par symbole : la watchlist a pu changer entre temps
     TickerDataLoaded {
-        index: usize,
+        symbol: String,
+        request_id: u64,
         data: OHLCData,
     },
 
@@ -73,13 +289,14 @@ enum AppResult {
     TickerAdded {
         symbol: String,
         name: String,
+        ticker_type: TickerType,
         data: OHLCData,
     },
 
     /// Erreur lors du chargement
     LoadError {
-        index: usize,
         symbol: String,
+        request_id: u64,
         error: String,
     },
 
@@ -88,6 +305,137 @@ enum AppResult {
         symbol: String,
         error: String,
     },
+
+    /// Données d'un intervalle secondaire chargées
+    IntervalDataLoaded {
+        symbol: String,
+        data: OHLCData,
+    },
+
+    /// Erreur lors du chargement d'un intervalle secondaire
+    IntervalLoadError {
+        symbol: String,
+        interval: Interval,
+        error: String,
+    },
+
+    /// Indice de référence chargé
+    BenchmarkLoaded {
+        data: OHLCData,
+    },
+
+    /// Indices du bandeau "marchés" chargés (ceux en erreur sont absents)
+    MarketOverviewLoaded {
+        items: Vec<WatchlistItem>,
+    },
+
+    /// Indice Fear & Greed chargé
+    FearGreedLoaded {
+        index: FearGreed,
+    },
+
+    /// Statistiques de marché d'une crypto chargées
+    CryptoStatsLoaded {
+        symbol: String,
+        stats: CryptoStats,
+    },
+
+    /// Liste distante de la watchlist reçue
+    RemoteWatchlistLoaded {
+        symbols: Vec<String>,
+    },
+
+    /// Calendrier économique reçu (remplace le précédent)
+    EconomicCalendarLoaded {
+        events: Vec<EconomicEvent>,
+    },
+
+    /// Carnet d'ordres reçu
+    OrderBookLoaded {
+        book: OrderBook,
+    },
+
+    /// Erreur lors de la récupération du carnet d'ordres
+    OrderBookError {
+        symbol: String,
+        error: String,
+    },
+
+    /// Graphique exporté avec succès
+    ChartExported {
+        path: std::path::PathBuf,
+    },
+
+    /// Erreur lors de l'export du graphique
+    ExportError {
+        symbol: String,
+        error: String,
+    },
+
+    /// Date des prochains résultats reçue (None si non annoncée)
+    EarningsLoaded {
+        symbol: String,
+        date: Option<chrono::DateTime<chrono::Utc>>,
+    },
+
+    /// Recommandations des analystes reçues (action suivie par des analystes)
+    AnalystRatingsLoaded {
+        symbol: String,
+        ratings: AnalystRatings,
+    },
+
+    /// Initiés et institutionnels reçus (vides si Yahoo n'a aucune donnée)
+    HoldersLoaded {
+        symbol: String,
+        holders: Holders,
+    },
+
+    /// Erreur lors de la récupération des initiés et institutionnels
+    HoldersError {
+        symbol: String,
+        error: String,
+    },
+
+    /// Comptes publiés reçus (vides si Yahoo n'en a aucun)
+    FinancialsLoaded {
+        symbol: String,
+        financials: Financials,
+    },
+
+    /// Prochain dividende reçu (vide si le ticker n'en verse pas)
+    DividendsLoaded {
+        symbol: String,
+        dividends: DividendInfo,
+    },
+
+    /// Résultats d'un screener chargés
+    ScreenerLoaded {
+        screener: Screener,
+        quotes: Vec<ScreenerQuote>,
+    },
+
+    /// Erreur lors du chargement d'un screener
+    ScreenerError {
+        screener: Screener,
+        error: String,
+    },
+
+    /// Erreur lors de l'appel d'un webhook d'alerte
+    WebhookError {
+        symbol: String,
+        error: String,
+    },
+
+    /// Erreur lors de l'exécution de la commande d'une alerte
+    AlertCommandError {
+        symbol: String,
+        error: String,
+    },
+
+    /// Résultat de la sonde réseau
+    ConnectivityChecked {
+        online: bool,
+    },
 }
 
 // ============================================================================
@@ -100,6 +448,11 @@ enum AppResult {
 // - Rotation quotidienne automatique des logs
 // ============================================================================
 
+/// Filtre de logs actif, remplaçable à chaud (voir set_log_filter)
+static LOG_FILTER: std::sync::OnceLock<
+    tracing_subscriber::reload::Handle<tracing_subscriber::EnvFilter, tracing_subscriber::Registry>,
+> = std::sync::OnceLock::new();
+
 /// Initialise le système de logging vers fichier
 ///
 /// CONCEPT RUST : Tracing subscriber
@@ -108,39 +461,57 @@ enum AppResult {
 /// - EnvFilter : filtre par niveau (RUST_LOG env var)
 /// - RollingFileAppender : rotation automatique
 ///
-/// Les logs sont écrits dans :
-/// - Linux/WSL : ~/.local/share/lazywallet/logs/lazywallet.log
-/// - macOS : ~/Library/Application Support/lazywallet/logs/lazywallet.log
-/// - Windows : C:\Users\<user>\AppData\Local\lazywallet\logs\lazywallet.log
+/// Les logs sont écrits dans (sauf "logging.dir" dans config.json) :
+/// - Linux/WSL : ~/.local/share/lazywallet/logs/lazywallet.log.AAAA-MM-JJ
+/// - macOS : ~/Library/Application Support/lazywallet/logs/
+/// - Windows : C:\Users\<user>\AppData\Roaming\lazywallet\logs\
+///
+/// Les anciens fichiers sont supprimés au démarrage (voir logging.rs)
 ///
 /// # Utilisation
 /// ```bash
 /// # Voir les logs en temps réel
-/// tail -f ~/.local/share/lazywallet/logs/lazywallet.log
+/// tail -f ~/.local/share/lazywallet/logs/lazywallet.log.*
 ///
 /// # Contrôler le niveau de log
 /// RUST_LOG=debug cargo run
 /// RUST_LOG=lazywallet=trace cargo run
 /// ```
-fn init_logging() -> Result<()> {
+fn init_logging(settings: &logging::LogSettings) -> Result<()> {
     use tracing_appender::rolling::{RollingFileAppender, Rotation};
-    use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+    use tracing_subscriber::{layer::SubscriberExt, reload, util::SubscriberInitExt, EnvFilter};
 
-    let log_dir = std::path::PathBuf::from("./logs");
+    let log_dir = settings.directory();
 
     // Crée le répertoire s'il n'existe pas
-    std::fs::create_dir_all(&log_dir).context("Échec de la création du répertoire de logs")?;
+    std::fs::create_dir_all(&log_dir)
+        .with_context(|| format!("Échec de la création du répertoire de logs {}", log_dir.display()))?;
 
     // Configure la rotation quotidienne des logs
     // CONCEPT : Log rotation
     // - Rotation::DAILY : nouveau fichier chaque jour
     // - Ancien format : lazywallet.log.2024-01-15
     // - Évite que les logs deviennent trop gros
-    let file_appender = RollingFileAppender::new(Rotation::DAILY, log_dir.clone(), "lazywallet.log");
+    let file_appender = RollingFileAppender::new(Rotation::DAILY, log_dir.clone(), logging::FILE_PREFIX);
+
+    // Filtre les logs par niveau
+    // CONCEPT : EnvFilter
+    // - RUST_LOG=debug : tous les logs debug+
+    // - RUST_LOG=lazywallet=trace : trace pour lazywallet, info pour le reste
+    // - Par défaut : debug pour lazywallet, info pour les dépendances
+    //
+    // CONCEPT : reload::Layer
+    // - Enveloppe le filtre : le Handle le remplace pendant l'exécution
+    //   (commande :loglevel), sans redémarrer avec RUST_LOG
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| "lazywallet=debug,info".into());
+    let (filter, handle) = reload::Layer::new(filter);
+    let _ = LOG_FILTER.set(handle);
 
     // Configure le subscriber (receveur de logs)
     // CONCEPT : Builder pattern avec layers
+    // - Filtre en premier : il s'applique à la couche d'écriture qui suit
     tracing_subscriber::registry()
+        .with(filter)
         .with(
             tracing_subscriber::fmt::layer()
                 .with_writer(file_appender) // Écrit dans le fichier
@@ -149,22 +520,38 @@ fn init_logging() -> Result<()> {
                 .with_thread_ids(true) // Inclut l'ID du thread (utile pour async)
                 .with_line_number(true) // Inclut le numéro de ligne
         )
-        .with(
-            // Filtre les logs par niveau
-            // CONCEPT : EnvFilter
-            // - RUST_LOG=debug : tous les logs debug+
-            // - RUST_LOG=lazywallet=trace : trace pour lazywallet, info pour le reste
-            // - Par défaut : debug pour lazywallet, info pour les dépendances
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "lazywallet=debug,info".into()),
-        )
         .init();
 
     // Premier log : confirme que le logging est initialisé
     info!(?log_dir, "Logging initialisé");
+
+    // Rétention : anciens fichiers supprimés (le jour courant est gardé)
+    match logging::cleanup(&log_dir, settings, chrono::Utc::now().date_naive()) {
+        Ok(removed) if !removed.is_empty() => info!(removed = removed.len(), "Old log files removed"),
+        Ok(_) => {}
+        Err(e) => warn!(error = ?e, "Failed to clean up old log files"),
+    }
     Ok(())
 }
 
+/// Remplace le filtre de logs (syntaxe RUST_LOG, ex: "debug",
+/// "lazywallet::api=trace,info")
+///
+/// Retourne le filtre précédent
+fn set_log_filter(directives: &str) -> Result<String> {
+    let filter = tracing_subscriber::EnvFilter::try_new(directives)
+        .map_err(|e| anyhow::anyhow!("Filtre invalide \"{}\" : {}", directives, e))?;
+    let handle = LOG_FILTER.get().context("Logging non initialisé")?;
+    let previous = current_log_filter().unwrap_or_default();
+    handle.reload(filter).context("Échec du changement de filtre")?;
+    Ok(previous)
+}
+
+/// Filtre de logs actif (None si le logging n'est pas initialisé)
+fn current_log_filter() -> Option<String> {
+    LOG_FILTER.get()?.with_current(|filter| filter.to_string()).ok()
+}
+
 // ============================================================================
 // Point d'entrée du programme
 // ============================================================================
@@ -180,54 +567,311 @@ fn main() -> Result<()> {
     // - .block_on() : exécute une future de manière bloquante
     // - Permet de combiner async (API) et sync (TUI)
 
+    // Arguments de la ligne de commande
+    // (--daemon [--interval SECONDES], --demo, --record [DIR], --replay [DIR], --ascii,
+    //  --reduced-motion, --no-emoji, --import-tradingview FICHIER, --export-csv [FICHIER], --profile NOM)
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let daemon = args.iter().any(|arg| arg == "--daemon");
+
+    // Profil : répertoires de configuration séparés (avant tout chargement)
+    let profile_name = profile::parse_args(&args);
+    if let Some(name) = profile_name {
+        profile::set(name)?;
+    }
+
+    // Lit la configuration (elle peut définir la watchlist et l'emplacement
+    // des logs) ; une erreur est journalisée une fois le logging prêt
+    let config_path = Config::default_path();
+    let loaded = Config::load(&config_path);
+
     // Initialize logging FIRST
     // CONCEPT : Logging avant tout le reste
+    // - Seule la configuration est lue avant : elle choisit le répertoire
     // - Si init échoue, on affiche l'erreur et continue quand même
     // - Permet d'avoir des logs pour tout le reste du programme
-    init_logging().unwrap_or_else(|e| {
+    let log_settings = loaded.as_ref().map(|config| config.logging.clone()).unwrap_or_default();
+    init_logging(&log_settings).unwrap_or_else(|e| {
         eprintln!("⚠️  Warning: Failed to initialize logging: {}", e);
         eprintln!("   Continuing without logging...");
     });
 
     info!("LazyWallet starting up");
+    if let Some(name) = profile_name {
+        info!(profile = %name, "Using profile");
+    }
+    if args.iter().any(|arg| arg == "--demo") {
+        info!("Demo mode: synthetic data, no network");
+        demo::enable();
+    }
+    if let Some(mode) = recording::parse_args(&args) {
+        info!(?mode, "Yahoo responses record/replay enabled");
+        recording::set_mode(mode);
+    }
+
+    let mut config = loaded.unwrap_or_else(|e| {
+        warn!(path = %config_path.display(), error = ?e, "Failed to load config, using defaults");
+        Config::default()
+    });
+
+    // Premier lancement (pas de config.json) : configuration guidée, écrite
+    // avant le chargement des données (options de la ligne de commande
+    // appliquées ensuite)
+    let interactive = !daemon && !demo::is_enabled() && !args.iter().any(|arg| arg == "--export-csv");
+    if interactive && !config_path.exists() && io::stdout().is_terminal() {
+        info!(path = %config_path.display(), "No config file, starting guided setup");
+        match run_onboarding(&config_path)? {
+            Some(chosen) => config = chosen,
+            None => {
+                info!("Guided setup interrupted, exiting");
+                return Ok(());
+            }
+        }
+    }
+    if args.iter().any(|arg| arg == "--ascii") {
+        config.ascii = true;
+    }
+    if args.iter().any(|arg| arg == "--reduced-motion") {
+        config.reduced_motion = true;
+    }
+    if args.iter().any(|arg| arg == "--no-emoji") {
+        config.no_emoji = true;
+    }
+    format::set_privacy(config.privacy);
+    persist::set_format(config.storage_format);
+    ohlc::set_max_candles(config.max_candles);
+    if let Err(e) = http::configure(&config.http) {
+        warn!(error = ?e, "Invalid HTTP settings, using defaults");
+    }
+    csv::configure(&config.custom_instruments);
+    #[cfg(feature = "store")]
+    if config.candle_store {
+        let store_path = lazywallet::store::CandleStore::default_path();
+        match lazywallet::store::enable(&store_path) {
+            Ok(()) => info!(path = %store_path.display(), "Candle store enabled"),
+            Err(e) => warn!(path = %store_path.display(), error = ?e, "Failed to open candle store, fetching full history"),
+        }
+    }
+
+    // Watchlist modifiée dans la TUI (format TOML) : remplace config.watchlist
+    // et donne le type d'actif détecté à l'ajout de chaque ticker
+    let mut ticker_types = HashMap::new();
+    if config.storage_format == StorageFormat::Toml {
+        let saved_path = SavedWatchlist::default_path();
+        match SavedWatchlist::load(&saved_path) {
+            Ok(Some(saved)) => {
+                info!(path = %saved_path.display(), tickers = saved.tickers.len(), "Saved watchlist loaded");
+                config.watchlist = saved.symbols();
+                ticker_types = saved.ticker_types();
+            }
+            Ok(None) => {}
+            Err(e) => warn!(path = %saved_path.display(), error = ?e, "Failed to load saved watchlist, using config"),
+        }
+    }
+
+    // Liste TradingView importée : ajoutée à la watchlist de la session
+    // (sans watchlist configurée, elle remplace les tickers par défaut)
+    if let Some(path) = args.iter().position(|arg| arg == "--import-tradingview").and_then(|i| args.get(i + 1)) {
+        let imported = tradingview::import_file(std::path::Path::new(path))?;
+        info!(path = %path, imported = imported.len(), "TradingView list imported");
+        for symbol in imported {
+            if !config.watchlist.contains(&symbol) {
+                config.watchlist.push(symbol);
+            }
+        }
+    }
 
     // Charge les données de la watchlist (appels API async)
     info!("📊 Chargement des données...\n");
 
     let runtime = tokio::runtime::Runtime::new()?;
-    let watchlist = runtime.block_on(load_watchlist_data())?;
+
+    // Liste distante : ses symboles s'ajoutent à ceux de config.watchlist
+    // (sans watchlist configurée, elle remplace les tickers par défaut)
+    let remote_symbols = match &config.remote_watchlist {
+        Some(remote) if !demo::is_enabled() => runtime.block_on(load_remote_watchlist(remote)),
+        _ => Vec::new(),
+    };
+    let mut symbols = config.watchlist.clone();
+    for symbol in &remote_symbols {
+        if !symbols.contains(symbol) {
+            symbols.push(symbol.clone());
+        }
+    }
+    let watchlist = runtime.block_on(load_watchlist_data(&symbols, &ticker_types, &config.custom_instruments))?;
 
     info!("✅ Données chargées !\n");
 
-    // Setup du terminal en mode TUI
-    debug!("Setting up terminal");
-    let mut terminal = setup_terminal()?;
+    // Export CSV des cotations puis sortie, sans lancer le TUI
+    if let Some(i) = args.iter().position(|arg| arg == "--export-csv") {
+        let path = args
+            .get(i + 1)
+            .filter(|arg| !arg.starts_with("--"))
+            .map(std::path::PathBuf::from)
+            .unwrap_or_else(quotes_csv_path);
+        let exported = quotes_csv::export_file(&path, &watchlist)?;
+        info!(path = %path.display(), exported, "Watchlist quotes exported as CSV");
+        println!("{}", path.display());
+        return Ok(());
+    }
 
     // Crée l'état de l'application avec les données chargées
+    let mut app = App::with_watchlist(watchlist);
+    app.config = config;
+    if app.config.remote_watchlist.is_some() {
+        app.remote_watchlist = remote_symbols;
+        app.mark_remote_watchlist_requested();
+    }
+    if app.watchlist.iter().any(|item| item.has_data()) {
+        app.last_refresh = Some(chrono::Local::now());
+    }
+
+    // Charge le journal des transactions
+    let journal_path = Journal::default_path();
+    app.journal = Journal::load(&journal_path).unwrap_or_else(|e| {
+        warn!(path = %journal_path.display(), error = ?e, "Failed to load journal, starting empty");
+        Journal::default()
+    });
+    info!(transactions = app.journal.transactions.len(), cost_basis = ?app.config.cost_basis, "Journal loaded");
+
+    // Charge les positions détenues (fichier absent = portefeuille vide)
+    let portfolio_path = Portfolio::default_path();
+    app.portfolio = Portfolio::load(&portfolio_path).unwrap_or_else(|e| {
+        warn!(path = %portfolio_path.display(), error = ?e, "Failed to load portfolio, starting empty");
+        Portfolio::default()
+    });
+    info!(positions = app.portfolio.positions.len(), currencies = app.portfolio.cash.len(), "Portfolio loaded");
+
+    // Charge les tags des tickers (fichier absent = aucun tag)
+    let tags_path = Tags::default_path();
+    app.tags = Tags::load(&tags_path).unwrap_or_else(|e| {
+        warn!(path = %tags_path.display(), error = ?e, "Failed to load tags, starting without");
+        Tags::default()
+    });
+    info!(tagged = app.tags.tags.len(), "Tags loaded");
+
+    // Charge les annotations des graphiques (fichier absent = aucune annotation)
+    let annotations_path = Annotations::default_path();
+    app.annotations = Annotations::load(&annotations_path).unwrap_or_else(|e| {
+        warn!(path = %annotations_path.display(), error = ?e, "Failed to load annotations, starting without");
+        Annotations::default()
+    });
+    info!(annotated = app.annotations.annotations.len(), "Annotations loaded");
+
+    // Charge les notes des tickers (fichier absent = aucune note)
+    let notes_path = Notes::default_path();
+    app.notes = Notes::load(&notes_path).unwrap_or_else(|e| {
+        warn!(path = %notes_path.display(), error = ?e, "Failed to load notes, starting without");
+        Notes::default()
+    });
+    info!(noted = app.notes.notes.len(), "Notes loaded");
+
+    // Charge les règles d'alerte et les évalue une première fois
+    let alerts_path = AlertBook::default_path();
+    app.alert_book = AlertBook::load(&alerts_path).unwrap_or_else(|e| {
+        warn!(path = %alerts_path.display(), error = ?e, "Failed to load alerts, starting empty");
+        AlertBook::default()
+    });
+    info!(rules = app.alert_book.rules.len(), "Alert rules loaded");
+
+    // Derniers indice Fear & Greed et statistiques CoinGecko connus : jauge
+    // et fiche des cryptos affichées sans attendre le réseau
+    if !demo::is_enabled() {
+        app.fear_greed = fear_greed::cache_path().and_then(|path| fear_greed::load_cache(&path));
+        app.crypto_stats = coingecko::cache_path().map(|path| coingecko::load_cache(&path)).unwrap_or_default();
+    }
+
     // CONCEPT RUST : Arc<Mutex<>> pour partage entre threads
     // - Arc : Reference counting pour ownership partagé
     // - Mutex : Protection contre les data races
-    // - Permet au worker thread et à l'UI d'accéder à App
-    let app = Arc::new(Mutex::new(App::with_watchlist(watchlist)));
+    // - Permet au worker thread, à l'API HTTP et à l'UI d'accéder à App
+    let http_api = app.config.http_api.clone();
+    let app = Arc::new(Mutex::new(app));
+
+    // API HTTP locale (optionnelle) : tâche de fond sur le runtime tokio
+    if let Some(address) = http_api {
+        let app = app.clone();
+        runtime.spawn(async move {
+            if let Err(e) = server::serve(&address, app).await {
+                error!(error = ?e, "HTTP API stopped");
+            }
+        });
+    }
+
+    // Mode démon : pas de terminal, boucle de rafraîchissement seule
+    if daemon {
+        let interval = daemon::parse_interval(&args)?;
+        return runtime.block_on(daemon::run(app, interval, &DaemonState::default_path()));
+    }
+
+    // Setup du terminal en mode TUI
+    debug!("Setting up terminal");
+    let mut terminal = setup_terminal()?;
 
     // Crée les channels pour communication avec le worker
     // CONCEPT RUST : mpsc channels
     // - (sender, receiver) : canal unidirectionnel
-    // - command_tx/rx : pour envoyer des commandes au worker
+    // - command_tx/rx : pour envoyer des commandes au worker (file priorisée)
     // - result_tx/rx : pour recevoir les résultats du worker
-    let (command_tx, command_rx) = mpsc::channel::<AppCommand>();
+    let (command_tx, command_rx) = queue::channel::<AppCommand>();
     let (result_tx, result_rx) = mpsc::channel::<AppResult>();
 
     // Lance le worker thread en arrière-plan
     info!("Spawning background worker thread");
-    spawn_background_worker(command_rx, result_tx, app.clone());
+    let stopping = Arc::new(AtomicBool::new(false));
+    let worker = spawn_background_worker(command_rx, result_tx, app.clone(), stopping.clone());
+
+    // Première évaluation des alertes (le worker peut déjà appeler les webhooks)
+    {
+        let mut app_lock = app.lock().unwrap();
+        let triggers = app_lock.evaluate_alerts();
+        dispatch_alert_triggers(&triggers, &command_tx);
+    }
 
-    // Crée le gestionnaire d'événements
-    let events = EventHandler::new();
+    // Force relative du dashboard : historiques D1 des tickers et des indices
+    {
+        let app_lock = app.lock().unwrap();
+        if app_lock.config.relative_strength_days > 0 {
+            request_relative_strength_data(&app_lock, &command_tx);
+        }
+    }
+
+    // Dates des prochains résultats des actions (chargées en arrière-plan)
+    {
+        let app_lock = app.lock().unwrap();
+        for item in app_lock.watchlist.iter().filter(|item| item.ticker_type() == TickerType::Stock) {
+            let _ = command_tx.send(AppCommand::FetchEarnings { symbol: item.symbol.clone() });
+        }
+    }
+
+    // Crée le gestionnaire d'événements (thread d'entrée + ticks)
+    let tick_rate = std::time::Duration::from_millis(app.lock().unwrap().config.tick_rate_ms.clamp(16, 5_000));
+    let events = EventHandler::new(tick_rate);
+
+    // SIGINT venu d'ailleurs (kill -INT, ...) : en raw mode, Ctrl+C arrive
+    // comme une touche ; dans les deux cas la boucle s'arrête proprement
+    // après le rendu en cours
+    {
+        let app = app.clone();
+        runtime.spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                info!("SIGINT received, quitting");
+                app.lock().unwrap().quit();
+            }
+        });
+    }
 
     // Exécute l'event loop
     info!("Starting event loop");
-    let result = run(&mut terminal, app.clone(), &events, command_tx, result_rx);
+    let result = run(&mut terminal, app.clone(), &events, command_tx.clone(), result_rx);
+
+    // Fermeture : le worker termine sa requête en cours (attente bornée), puis
+    // les changements en attente sont sauvegardés, terminal encore en place
+    shutdown_worker(worker, &stopping, &command_tx);
+    {
+        let mut app_lock = app.lock().unwrap();
+        save_alert_book_changes(&mut app_lock, &AlertBook::default_path());
+        retry_unsaved_portfolio(&mut app_lock, &Journal::default_path(), &Portfolio::default_path());
+    }
 
     // Restaure le terminal (même en cas d'erreur)
     debug!("Restoring terminal");
@@ -250,21 +894,67 @@ fn main() -> Result<()> {
 // - Retourne une Future<Output = Result<Vec<WatchlistItem>>>
 // ============================================================================
 
+/// Liste distante au démarrage : téléchargée, sinon lue depuis le cache
+///
+/// Une liste injoignable n'empêche pas le démarrage : la dernière version
+/// reçue est utilisée (vide s'il n'y en a pas)
+async fn load_remote_watchlist(remote: &RemoteWatchlist) -> Vec<String> {
+    let cache_path = remote_watchlist::cache_path();
+    match remote_watchlist::fetch_symbols(&remote.url).await {
+        Ok(symbols) => {
+            info!(url = %remote.url, symbols = symbols.len(), "Remote watchlist loaded");
+            if let Some(path) = &cache_path {
+                if let Err(e) = remote_watchlist::save_cache(path, &symbols) {
+                    warn!(path = %path.display(), error = ?e, "Failed to cache remote watchlist");
+                }
+            }
+            symbols
+        }
+        Err(e) => {
+            let symbols = cache_path.map(|path| remote_watchlist::load_cache(&path)).unwrap_or_default();
+            warn!(url = %remote.url, error = ?e, cached = symbols.len(), "Failed to fetch remote watchlist, using the cached list");
+            symbols
+        }
+    }
+}
+
 /// Charge les données de la watchlist depuis Yahoo Finance
 ///
 /// CONCEPT RUST : Async/await et gestion d'erreurs
 /// - async fn : fonction qui retourne une Future
 /// - .await : suspend jusqu'à résolution
 /// - ? : propage les erreurs
-async fn load_watchlist_data() -> Result<Vec<WatchlistItem>> {
+///
+/// # Arguments
+/// * `symbols` - Tickers de la configuration (vide = liste par défaut)
+async fn load_watchlist_data(
+    symbols: &[String],
+    ticker_types: &HashMap<String, TickerType>,
+    custom: &[CustomInstrument],
+) -> Result<Vec<WatchlistItem>> {
     // Définit les tickers à charger
-    // CONCEPT RUST : Array de tuples
+    // CONCEPT RUST : Vec de tuples
     // - (symbol, name) pour chaque ticker
-    let tickers = [
-        ("AAPL", "Apple Inc."),
-        ("TSLA", "Tesla"),
-        ("BTC-USD", "Bitcoin USD"),
-    ];
+    // - Le nom d'un ticker configuré est remplacé par celui de Yahoo
+    let mut tickers: Vec<(&str, &str)> = if symbols.is_empty() && demo::is_enabled() {
+        demo::DEMO_WATCHLIST.to_vec()
+    } else if symbols.is_empty() {
+        vec![
+            ("AAPL", "Apple Inc."),
+            ("TSLA", "Tesla"),
+            ("BTC-USD", "Bitcoin USD"),
+        ]
+    } else {
+        symbols.iter().map(|symbol| (symbol.as_str(), symbol.as_str())).collect()
+    };
+
+    // Instruments personnalisés (fichiers CSV) pas encore dans la liste
+    for instrument in custom {
+        if tickers.iter().all(|(symbol, _)| *symbol != instrument.symbol) {
+            let name = instrument.name.as_deref().unwrap_or(&instrument.symbol);
+            tickers.push((&instrument.symbol, name));
+        }
+    }
 
     let mut watchlist = Vec::new();
 
@@ -275,33 +965,32 @@ async fn load_watchlist_data() -> Result<Vec<WatchlistItem>> {
         info!("  [{}/{}] Chargement de {}...", i + 1, tickers.len(), symbol);
 
         // Appel API pour récupérer les données
-        // Utilise l'intervalle par défaut (30m)
+        // Utilise l'intervalle du type d'actif (15m crypto, 1d actions) :
+        // celui de la watchlist sauvegardée, sinon déduit du symbole
         // Le timeframe est déterminé automatiquement par l'intervalle
-        match fetch_ticker_data(symbol, Interval::default()).await {
+        let ticker_type = ticker_types.get(symbol).copied().unwrap_or_else(|| TickerType::from_symbol(symbol));
+        let mut item = match fetch_ticker_data(symbol, ticker_type.default_interval()).await {
             Ok((data, long_name)) => {
                 // Succès : crée un WatchlistItem avec les données
                 // Utilise le long_name de Yahoo si disponible, sinon le nom fourni
                 let display_name = long_name.unwrap_or_else(|| name.to_string());
                 info!(ticker = %symbol, candles = data.len(), long_name = %display_name, "Ticker data fetched successfully");
-                watchlist.push(WatchlistItem::with_data(
-                    symbol.to_string(),
-                    display_name,
-                    data,
-                ));
                 info!("    ✓ OK");
+                WatchlistItem::with_data(symbol.to_string(), display_name, data)
             }
             Err(e) => {
                 // Erreur : affiche et crée un item sans données
                 error!(ticker = %symbol, error = ?e, "Failed to fetch ticker data");
-                watchlist.push(WatchlistItem::new(
-                    symbol.to_string(),
-                    name.to_string(),
-                ));
+                let mut item = WatchlistItem::new(symbol.to_string(), name.to_string());
+                item.load_error = Some(e.to_string());
+                item
             }
-        }
+        };
+        item.ticker_type = ticker_type;
+        watchlist.push(item);
 
-        // Petit délai entre les requêtes (rate limiting)
-        if i < tickers.len() - 1 {
+        // Petit délai entre les requêtes (rate limiting, inutile en démo et pour un CSV)
+        if i < tickers.len() - 1 && !demo::is_enabled() && csv::instrument(symbol).is_none() {
             tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
         }
     }
@@ -327,14 +1016,16 @@ async fn load_watchlist_data() -> Result<Vec<WatchlistItem>> {
 /// - mpsc channels : communication inter-thread
 ///
 /// # Arguments
-/// * `command_rx` - Receiver pour recevoir les commandes
+/// * `command_rx` - File priorisée des commandes
 /// * `result_tx` - Sender pour envoyer les résultats
 /// * `app` - Arc<Mutex<App>> pour accéder à l'état partagé
+/// * `stopping` - Levé à la fermeture : les commandes en file sont abandonnées
 fn spawn_background_worker(
-    command_rx: mpsc::Receiver<AppCommand>,
+    command_rx: QueueReceiver<AppCommand>,
     result_tx: mpsc::Sender<AppResult>,
     app: Arc<Mutex<App>>,
-) {
+    stopping: Arc<AtomicBool>,
+) -> std::thread::JoinHandle<()> {
     std::thread::spawn(move || {
         // Crée un runtime tokio pour ce thread
         // CONCEPT : Runtime per-thread
@@ -344,18 +1035,26 @@ fn spawn_background_worker(
 
         // Boucle de traitement des commandes
         // CONCEPT : Command processing loop
-        // - Attend une commande sur command_rx
+        // - Attend une commande sur command_rx (la plus prioritaire d'abord)
         // - Traite la commande de manière async
         // - Envoie le résultat sur result_tx
         loop {
             match command_rx.recv() {
+                Ok(command) if stopping.load(Ordering::SeqCst) && !command.runs_on_shutdown() => {
+                    debug!(?command, "Dropping queued command on shutdown");
+                }
                 Ok(command) => {
                     info!(?command, "Worker received command");
 
                     match command {
-                        AppCommand::ReloadTickerData { symbol, interval, index } => {
+                        AppCommand::Shutdown => {
+                            info!("Worker thread exiting (shutdown requested)");
+                            break;
+                        }
+
+                        AppCommand::ReloadTickerData { symbol, interval, request_id, silent } => {
                             // Active l'indicateur de chargement
-                            {
+                            if !silent {
                                 let mut app_lock = app.lock().unwrap();
                                 app_lock.start_loading(Some(format!(
                                     "Chargement {} avec intervalle {}...",
@@ -375,20 +1074,40 @@ fn spawn_background_worker(
                             match result {
                                 Ok((data, long_name)) => {
                                     info!(ticker = %symbol, interval = %interval.label(), candles = data.len(), long_name = ?long_name, "Data loaded successfully");
-                                    let _ = result_tx.send(AppResult::TickerDataLoaded { index, data });
+                                    let _ = result_tx.send(AppResult::TickerDataLoaded { symbol: symbol.clone(), request_id, data });
                                 }
                                 Err(e) => {
                                     error!(ticker = %symbol, error = ?e, "Failed to load ticker data");
-                                    let _ = result_tx.send(AppResult::LoadError {
-                                        index,
-                                        symbol: symbol.clone(),
-                                        error: e.to_string(),
-                                    });
+
+                                    // Hors ligne : reconstruit l'intervalle depuis des données plus fines
+                                    let mut app_lock = app.lock().unwrap();
+                                    let fallback = app_lock
+                                        .watchlist
+                                        .iter()
+                                        .find(|item| item.symbol == symbol)
+                                        .and_then(|item| item.resampled_for(interval));
+                                    match fallback {
+                                        Some(data) => {
+                                            warn!(ticker = %symbol, interval = %interval.label(), "Using locally resampled data");
+                                            app_lock.show_toast(format!(
+                                                "Hors ligne : {} reconstruit localement",
+                                                interval.label()
+                                            ));
+                                            let _ = result_tx.send(AppResult::TickerDataLoaded { symbol: symbol.clone(), request_id, data });
+                                        }
+                                        None => {
+                                            let _ = result_tx.send(AppResult::LoadError {
+                                                symbol: symbol.clone(),
+                                                request_id,
+                                                error: e.to_string(),
+                                            });
+                                        }
+                                    }
                                 }
                             }
 
                             // Désactive l'indicateur de chargement
-                            {
+                            if !silent {
                                 let mut app_lock = app.lock().unwrap();
                                 app_lock.stop_loading();
                             }
@@ -404,19 +1123,32 @@ fn spawn_background_worker(
                                 )));
                             }
 
-                            // Fetch les données avec l'intervalle par défaut
+                            // Type d'actif selon Yahoo (quoteType), sinon déduit du symbole
+                            // Un échec n'empêche pas l'ajout
+                            let ticker_type = match runtime.block_on(fetch_quote_type(&symbol)) {
+                                Ok(Some(ticker_type)) => ticker_type,
+                                Ok(None) => TickerType::from_symbol(&symbol),
+                                Err(e) => {
+                                    warn!(ticker = %symbol, error = %e, "Failed to fetch quote type, guessing from the symbol");
+                                    TickerType::from_symbol(&symbol)
+                                }
+                            };
+
+                            // Fetch les données avec l'intervalle du type d'actif
+                            let interval = ticker_type.default_interval();
                             let result = runtime.block_on(async {
-                                fetch_ticker_data(&symbol, Interval::default()).await
+                                fetch_ticker_data(&symbol, interval).await
                             });
 
                             match result {
                                 Ok((data, long_name)) => {
-                                    info!(ticker = %symbol, candles = data.len(), long_name = ?long_name, "Ticker added successfully");
+                                    info!(ticker = %symbol, ticker_type = ?ticker_type, candles = data.len(), long_name = ?long_name, "Ticker added successfully");
                                     // Utilise le long_name de Yahoo, sinon fallback sur le symbol
                                     let name = long_name.unwrap_or_else(|| symbol.clone());
                                     let _ = result_tx.send(AppResult::TickerAdded {
                                         symbol: symbol.clone(),
                                         name,
+                                        ticker_type,
                                         data,
                                     });
                                 }
@@ -435,24 +1167,410 @@ fn spawn_background_worker(
                                 app_lock.stop_loading();
                             }
                         }
-                    }
-                }
-                Err(_) => {
-                    // Channel fermé, on quitte
-                    info!("Worker thread exiting (channel closed)");
-                    break;
-                }
-            }
-        }
-    });
-}
 
-// ============================================================================
-// Event Loop Principal
-// ============================================================================
-// CONCEPT : Game Loop / Event Loop Pattern
-// - Loop infinie : while app.is_running()
-// - À chaque itération :
+                        AppCommand::LoadIntervalData { symbol, interval } => {
+                            {
+                                let mut app_lock = app.lock().unwrap();
+                                app_lock.start_loading(Some(format!(
+                                    "Chargement {} en {}...",
+                                    symbol,
+                                    interval.label()
+                                )));
+                            }
+
+                            let result = runtime.block_on(async {
+                                fetch_ticker_data(&symbol, interval).await
+                            });
+
+                            match result {
+                                Ok((data, _)) => {
+                                    info!(ticker = %symbol, interval = %interval.label(), candles = data.len(), "Secondary interval data loaded");
+                                    let _ = result_tx.send(AppResult::IntervalDataLoaded { symbol, data });
+                                }
+                                Err(e) => {
+                                    error!(ticker = %symbol, interval = %interval.label(), error = ?e, "Failed to load secondary interval data");
+                                    let _ = result_tx.send(AppResult::IntervalLoadError {
+                                        symbol,
+                                        interval,
+                                        error: e.to_string(),
+                                    });
+                                }
+                            }
+
+                            {
+                                let mut app_lock = app.lock().unwrap();
+                                app_lock.stop_loading();
+                            }
+                        }
+
+                        AppCommand::LoadBenchmark { symbol, interval } => {
+                            let result = runtime.block_on(async {
+                                fetch_ticker_data(&symbol, interval).await
+                            });
+
+                            match result {
+                                Ok((data, _)) => {
+                                    info!(ticker = %symbol, interval = %interval.label(), candles = data.len(), "Benchmark data loaded");
+                                    let _ = result_tx.send(AppResult::BenchmarkLoaded { data });
+                                }
+                                Err(e) => {
+                                    error!(ticker = %symbol, interval = %interval.label(), error = ?e, "Failed to load benchmark data");
+                                    let _ = result_tx.send(AppResult::IntervalLoadError {
+                                        symbol,
+                                        interval,
+                                        error: e.to_string(),
+                                    });
+                                }
+                            }
+                        }
+
+                        AppCommand::LoadMarketOverview { symbols } => {
+                            // Rafraîchissement silencieux : pas d'indicateur de chargement
+                            // D1 : la dernière chandelle donne directement la variation du jour
+                            let items = runtime.block_on(async {
+                                let mut items = Vec::new();
+                                for symbol in &symbols {
+                                    match fetch_ticker_data(symbol, Interval::D1).await {
+                                        Ok((data, long_name)) => {
+                                            let name = long_name.unwrap_or_else(|| symbol.clone());
+                                            items.push(WatchlistItem::with_data(symbol.clone(), name, data));
+                                        }
+                                        Err(e) => {
+                                            error!(ticker = %symbol, error = ?e, "Failed to fetch market overview index");
+                                        }
+                                    }
+                                }
+                                items
+                            });
+
+                            debug!(loaded = items.len(), requested = symbols.len(), "Market overview loaded");
+                            let _ = result_tx.send(AppResult::MarketOverviewLoaded { items });
+                        }
+
+                        AppCommand::FetchFearGreed => {
+                            // Information secondaire : une erreur est seulement journalisée
+                            match runtime.block_on(fear_greed::fetch_fear_greed()) {
+                                Ok(index) => {
+                                    debug!(value = index.value, "Fear & Greed index loaded");
+                                    if !demo::is_enabled() {
+                                        if let Some(path) = fear_greed::cache_path() {
+                                            if let Err(e) = fear_greed::save_cache(&path, &index) {
+                                                warn!(path = %path.display(), error = ?e, "Failed to cache Fear & Greed index");
+                                            }
+                                        }
+                                    }
+                                    let _ = result_tx.send(AppResult::FearGreedLoaded { index });
+                                }
+                                Err(e) => {
+                                    warn!(error = ?e, "Failed to fetch Fear & Greed index");
+                                }
+                            }
+                        }
+
+                        AppCommand::FetchCryptoStats { symbol } => {
+                            // Information secondaire : la fiche garde les dernières valeurs en cas d'erreur
+                            match runtime.block_on(coingecko::fetch_crypto_stats(&symbol)) {
+                                Ok(Some(stats)) => {
+                                    debug!(ticker = %symbol, coin = %stats.coin_id, market_cap = ?stats.market_cap, "Crypto market stats loaded");
+                                    if !demo::is_enabled() {
+                                        if let Some(path) = coingecko::cache_path() {
+                                            if let Err(e) = coingecko::update_cache(&path, &symbol, &stats) {
+                                                warn!(path = %path.display(), error = ?e, "Failed to cache crypto market stats");
+                                            }
+                                        }
+                                    }
+                                    let _ = result_tx.send(AppResult::CryptoStatsLoaded { symbol, stats });
+                                }
+                                Ok(None) => debug!(ticker = %symbol, "Coin unknown to CoinGecko"),
+                                Err(e) => {
+                                    warn!(ticker = %symbol, error = ?e, "Failed to fetch crypto market stats");
+                                }
+                            }
+                        }
+
+                        AppCommand::FetchRemoteWatchlist { url } => {
+                            // Erreur journalisée : la watchlist actuelle reste suivie
+                            match runtime.block_on(remote_watchlist::fetch_symbols(&url)) {
+                                Ok(symbols) => {
+                                    debug!(symbols = symbols.len(), "Remote watchlist loaded");
+                                    if let Some(path) = remote_watchlist::cache_path() {
+                                        if let Err(e) = remote_watchlist::save_cache(&path, &symbols) {
+                                            warn!(path = %path.display(), error = ?e, "Failed to cache remote watchlist");
+                                        }
+                                    }
+                                    let _ = result_tx.send(AppResult::RemoteWatchlistLoaded { symbols });
+                                }
+                                Err(e) => {
+                                    warn!(url = %url, error = ?e, "Failed to fetch remote watchlist");
+                                }
+                            }
+                        }
+
+                        AppCommand::FetchEconomicCalendar { url } => {
+                            // Le bandeau garde les événements précédents en cas d'erreur
+                            match runtime.block_on(economic_calendar::fetch_events(&url)) {
+                                Ok(events) => {
+                                    debug!(events = events.len(), "Economic calendar loaded");
+                                    let _ = result_tx.send(AppResult::EconomicCalendarLoaded { events });
+                                }
+                                Err(e) => {
+                                    warn!(url = %url, error = ?e, "Failed to fetch economic calendar");
+                                }
+                            }
+                        }
+
+                        AppCommand::FetchOrderBook { symbol } => {
+                            // Pas d'indicateur de chargement : rafraîchissement silencieux
+                            let result = runtime.block_on(async {
+                                fetch_order_book(&symbol, DEFAULT_DEPTH).await
+                            });
+
+                            match result {
+                                Ok(book) => {
+                                    debug!(ticker = %symbol, bids = book.bids.len(), asks = book.asks.len(), "Order book loaded");
+                                    let _ = result_tx.send(AppResult::OrderBookLoaded { book });
+                                }
+                                Err(e) => {
+                                    error!(ticker = %symbol, error = ?e, "Failed to fetch order book");
+                                    let _ = result_tx.send(AppResult::OrderBookError {
+                                        symbol,
+                                        error: e.to_string(),
+                                    });
+                                }
+                            }
+                        }
+
+                        AppCommand::FetchEarnings { symbol } => {
+                            // Information secondaire : une erreur est seulement journalisée
+                            match runtime.block_on(fetch_earnings_date(&symbol)) {
+                                Ok(date) => {
+                                    debug!(ticker = %symbol, date = ?date, "Earnings date loaded");
+                                    let _ = result_tx.send(AppResult::EarningsLoaded { symbol, date });
+                                }
+                                Err(e) => {
+                                    warn!(ticker = %symbol, error = ?e, "Failed to fetch earnings date");
+                                }
+                            }
+                        }
+
+                        AppCommand::FetchAnalystRatings { symbol } => {
+                            // Information secondaire : la fiche affiche "—" en cas d'erreur
+                            match runtime.block_on(fetch_analyst_ratings(&symbol)) {
+                                Ok(Some(ratings)) => {
+                                    debug!(ticker = %symbol, total = ratings.total(), target = ?ratings.target_mean, "Analyst ratings loaded");
+                                    let _ = result_tx.send(AppResult::AnalystRatingsLoaded { symbol, ratings });
+                                }
+                                Ok(None) => debug!(ticker = %symbol, "No analyst coverage"),
+                                Err(e) => {
+                                    warn!(ticker = %symbol, error = ?e, "Failed to fetch analyst ratings");
+                                }
+                            }
+                        }
+
+                        AppCommand::FetchHolders { symbol } => {
+                            match runtime.block_on(fetch_holders(&symbol)) {
+                                Ok(holders) => {
+                                    debug!(
+                                        ticker = %symbol,
+                                        insiders = holders.insider_transactions.len(),
+                                        institutions = holders.institutions.len(),
+                                        "Holders loaded"
+                                    );
+                                    let _ = result_tx.send(AppResult::HoldersLoaded { symbol, holders });
+                                }
+                                Err(e) => {
+                                    warn!(ticker = %symbol, error = ?e, "Failed to fetch holders");
+                                    let _ = result_tx.send(AppResult::HoldersError {
+                                        symbol,
+                                        error: e.to_string(),
+                                    });
+                                }
+                            }
+                        }
+
+                        AppCommand::FetchFinancials { symbol } => {
+                            // Information secondaire : le panneau reste en chargement en cas d'erreur
+                            match runtime.block_on(fetch_financials(&symbol)) {
+                                Ok(financials) => {
+                                    debug!(
+                                        ticker = %symbol,
+                                        annual = financials.annual.dates().len(),
+                                        quarterly = financials.quarterly.dates().len(),
+                                        "Financial statements loaded"
+                                    );
+                                    let _ = result_tx.send(AppResult::FinancialsLoaded { symbol, financials });
+                                }
+                                Err(e) => {
+                                    warn!(ticker = %symbol, error = ?e, "Failed to fetch financial statements");
+                                }
+                            }
+                        }
+
+                        AppCommand::FetchDividends { symbol } => {
+                            // Information secondaire : le ticker reste absent du calendrier en cas d'erreur
+                            match runtime.block_on(fetch_dividends(&symbol)) {
+                                Ok(dividends) => {
+                                    debug!(ticker = %symbol, ex_date = ?dividends.ex_date, amount = ?dividends.amount, "Dividend calendar loaded");
+                                    let _ = result_tx.send(AppResult::DividendsLoaded { symbol, dividends });
+                                }
+                                Err(e) => {
+                                    warn!(ticker = %symbol, error = ?e, "Failed to fetch dividend calendar");
+                                }
+                            }
+                        }
+
+                        AppCommand::LoadScreener { screener } => {
+                            let result = runtime.block_on(fetch_screener(screener));
+
+                            match result {
+                                Ok(quotes) => {
+                                    info!(screener = screener.scr_id(), quotes = quotes.len(), "Screener loaded");
+                                    let _ = result_tx.send(AppResult::ScreenerLoaded { screener, quotes });
+                                }
+                                Err(e) => {
+                                    error!(screener = screener.scr_id(), error = ?e, "Failed to load screener");
+                                    let _ = result_tx.send(AppResult::ScreenerError {
+                                        screener,
+                                        error: e.to_string(),
+                                    });
+                                }
+                            }
+
+                            {
+                                let mut app_lock = app.lock().unwrap();
+                                app_lock.stop_loading();
+                            }
+                        }
+
+                        AppCommand::SendWebhook { url, trigger } if demo::is_enabled() => {
+                            info!(ticker = %trigger.symbol, url = %url, "Demo mode: alert webhook skipped");
+                        }
+
+                        AppCommand::SendWebhook { url, trigger } => {
+                            match runtime.block_on(post_alert(&url, &trigger)) {
+                                Ok(()) => {
+                                    info!(ticker = %trigger.symbol, url = %url, "Alert webhook delivered");
+                                }
+                                Err(e) => {
+                                    error!(ticker = %trigger.symbol, url = %url, error = ?e, "Failed to deliver alert webhook");
+                                    let _ = result_tx.send(AppResult::WebhookError {
+                                        symbol: trigger.symbol,
+                                        error: e.to_string(),
+                                    });
+                                }
+                            }
+                        }
+
+                        AppCommand::RunAlertCommand { template, trigger } => {
+                            // Thread dédié : un script lent ne retarde pas les téléchargements
+                            let result_tx = result_tx.clone();
+                            let command = template.clone();
+                            spawn_alert_command(template, trigger, move |trigger, result| match result {
+                                Ok(output) => {
+                                    info!(ticker = %trigger.symbol, command = %command, output = %output, "Alert command executed");
+                                }
+                                Err(e) => {
+                                    error!(ticker = %trigger.symbol, command = %command, error = ?e, "Alert command failed");
+                                    let _ = result_tx.send(AppResult::AlertCommandError {
+                                        symbol: trigger.symbol.clone(),
+                                        error: e.to_string(),
+                                    });
+                                }
+                            });
+                        }
+
+                        AppCommand::ProbeConnectivity => {
+                            let online = runtime.block_on(connectivity::probe());
+                            debug!(online, "Connectivity probed");
+                            let _ = result_tx.send(AppResult::ConnectivityChecked { online });
+                        }
+
+                        AppCommand::ExportChart { symbol } => {
+                            // Copie les données sous lock, puis relâche le lock pendant le rendu
+                            let snapshot = {
+                                let app_lock = app.lock().unwrap();
+                                app_lock
+                                    .watchlist
+                                    .iter()
+                                    .find(|item| item.symbol == symbol)
+                                    .and_then(|item| {
+                                        item.data
+                                            .clone()
+                                            .map(|data| (format!("{} - {}", item.symbol, item.name), data))
+                                    })
+                            };
+
+                            let result = match snapshot {
+                                Some((title, data)) => {
+                                    // Rendu synchrone : bloque le worker, pas l'UI
+                                    let path = export_dir()
+                                        .join(export_file_name(&symbol, data.interval, "png"));
+                                    export_chart_png(&data, &title, &path).map(|_| path)
+                                }
+                                None => Err(anyhow::anyhow!("Pas de données pour {}", symbol)),
+                            };
+
+                            match result {
+                                Ok(path) => {
+                                    let _ = result_tx.send(AppResult::ChartExported { path });
+                                }
+                                Err(e) => {
+                                    error!(ticker = %symbol, error = ?e, "Failed to export chart");
+                                    let _ = result_tx.send(AppResult::ExportError {
+                                        symbol: symbol.clone(),
+                                        error: e.to_string(),
+                                    });
+                                }
+                            }
+                        }
+                    }
+                }
+                Err(_) => {
+                    // Channel fermé, on quitte
+                    info!("Worker thread exiting (channel closed)");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+/// Attente maximale de la requête en cours du worker à la fermeture
+const SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Arrête le worker : abandonne les commandes en file et attend la fin de la
+/// requête en cours, au plus SHUTDOWN_TIMEOUT
+///
+/// CONCEPT : Arrêt coopératif
+/// - Un thread ne se tue pas de l'extérieur : on lui demande de s'arrêter
+///   (drapeau + commande Shutdown, vue après les commandes déjà en file)
+/// - Une requête HTTP bloquée ne retarde pas la fermeture au-delà du délai :
+///   le thread est alors abandonné, comme avant
+fn shutdown_worker(worker: std::thread::JoinHandle<()>, stopping: &AtomicBool, command_tx: &QueueSender<AppCommand>) {
+    info!("Stopping background worker");
+    stopping.store(true, Ordering::SeqCst);
+    let _ = command_tx.send(AppCommand::Shutdown);
+
+    let deadline = std::time::Instant::now() + SHUTDOWN_TIMEOUT;
+    while !worker.is_finished() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+
+    if worker.is_finished() {
+        if worker.join().is_err() {
+            error!("Background worker panicked");
+        }
+    } else {
+        warn!(timeout_secs = SHUTDOWN_TIMEOUT.as_secs(), "Background worker still busy, abandoning in-flight request");
+    }
+}
+
+// ============================================================================
+// Event Loop Principal
+// ============================================================================
+// CONCEPT : Game Loop / Event Loop Pattern
+// - Loop infinie : while app.is_running()
+// - À chaque itération :
 //   1. Traiter les événements (input)
 //   2. Mettre à jour l'état (update)
 //   3. Dessiner l'interface (render)
@@ -471,9 +1589,13 @@ fn run(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: Arc<Mutex<App>>,
     events: &EventHandler,
-    command_tx: mpsc::Sender<AppCommand>,
+    command_tx: QueueSender<AppCommand>,
     result_rx: mpsc::Receiver<AppResult>,
 ) -> Result<()> {
+    // Mode démo : numéro et instant du dernier tick des prix synthétiques
+    let mut demo_step: u64 = 0;
+    let mut demo_ticked_at = std::time::Instant::now();
+
     // Loop infinie jusqu'à ce que app.running devienne false
     loop {
         // Vérifie si l'app est toujours en cours d'exécution
@@ -498,27 +1620,176 @@ fn run(
         match result_rx.try_recv() {
             Ok(result) => {
                 match result {
-                    AppResult::TickerDataLoaded { index, data } => {
+                    AppResult::TickerDataLoaded { symbol, request_id, data } => {
                         let mut app_lock = app.lock().unwrap();
-                        if let Some(item) = app_lock.watchlist.get_mut(index) {
-                            info!(ticker = %item.symbol, interval = %data.interval.label(), candles = data.len(), "Updating watchlist item with new data");
-                            item.data = Some(data);
+                        match app_lock.resolve_load(&symbol, request_id) {
+                            Some(item) => {
+                                info!(ticker = %symbol, interval = %data.interval.label(), candles = data.len(), "Updating watchlist item with new data");
+                                item.update_data(data);
+                                item.load_error = None;
+                            }
+                            None => debug!(ticker = %symbol, request_id, "Ignoring stale ticker data"),
                         }
+                        app_lock.last_refresh = Some(chrono::Local::now());
+                        let triggers = app_lock.evaluate_alerts();
+                        dispatch_alert_triggers(&triggers, &command_tx);
                     }
-                    AppResult::LoadError { index: _, symbol, error } => {
-                        error!(ticker = %symbol, error = %error, "Failed to load ticker data");
-                        // Optionally: show error to user via app state
+                    AppResult::LoadError { symbol, request_id, error } => {
+                        error!(ticker = %symbol, request_id, error = %error, "Failed to load ticker data");
+                        // Garde l'erreur sur l'item : la ligne et le graphique proposent [r]
+                        let mut app_lock = app.lock().unwrap();
+                        if let Some(item) = app_lock.resolve_load(&symbol, request_id) {
+                            item.load_error = Some(error);
+                        }
                     }
-                    AppResult::TickerAdded { symbol, name, data } => {
+                    AppResult::TickerAdded { symbol, name, ticker_type, data } => {
                         let mut app_lock = app.lock().unwrap();
                         info!(ticker = %symbol, candles = data.len(), "Adding ticker to watchlist");
-                        // Crée un nouveau WatchlistItem avec les données
-                        let item = WatchlistItem::with_data(symbol, name, data);
+                        // Crée un nouveau WatchlistItem avec les données et le type détecté
+                        let mut item = WatchlistItem::with_data(symbol, name, data);
+                        item.ticker_type = ticker_type;
+                        if item.ticker_type() == TickerType::Stock {
+                            let _ = command_tx.send(AppCommand::FetchEarnings { symbol: item.symbol.clone() });
+                        }
                         app_lock.watchlist.push(item);
+                        save_watchlist(&mut app_lock);
+                        app_lock.last_refresh = Some(chrono::Local::now());
+                        let triggers = app_lock.evaluate_alerts();
+                        dispatch_alert_triggers(&triggers, &command_tx);
                     }
                     AppResult::AddError { symbol, error } => {
                         error!(ticker = %symbol, error = %error, "Failed to add ticker");
-                        // Optionally: show error to user via app state
+                        report_add_error(&mut app.lock().unwrap(), &symbol, &error);
+                    }
+                    AppResult::IntervalDataLoaded { symbol, data } => {
+                        let mut app_lock = app.lock().unwrap();
+                        if let Some(item) = app_lock.watchlist.iter_mut().find(|item| item.symbol == symbol) {
+                            item.cache_data(data);
+                        }
+                    }
+                    AppResult::IntervalLoadError { symbol, interval, error } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.show_error_toast(format!(
+                            "Échec du chargement de {} en {} : {}",
+                            symbol,
+                            interval.label(),
+                            error
+                        ));
+                    }
+                    AppResult::BenchmarkLoaded { data } => {
+                        app.lock().unwrap().cache_benchmark(data);
+                    }
+                    AppResult::MarketOverviewLoaded { items } => {
+                        let mut app_lock = app.lock().unwrap();
+                        // Garde les données précédentes si tout a échoué (réseau coupé)
+                        if !items.is_empty() {
+                            app_lock.market_overview = items;
+                        }
+                    }
+                    AppResult::FearGreedLoaded { index } => {
+                        app.lock().unwrap().fear_greed = Some(index);
+                    }
+                    AppResult::CryptoStatsLoaded { symbol, stats } => {
+                        app.lock().unwrap().crypto_stats.insert(symbol, stats);
+                    }
+                    AppResult::RemoteWatchlistLoaded { symbols } => {
+                        let mut app_lock = app.lock().unwrap();
+                        let before = app_lock.watchlist.len();
+                        let added = app_lock.apply_remote_watchlist(symbols);
+                        let removed = before - app_lock.watchlist.len();
+                        if !added.is_empty() || removed > 0 {
+                            info!(added = ?added, removed, "Remote watchlist changed");
+                        }
+                        for symbol in added {
+                            let _ = command_tx.send(AppCommand::AddTicker { symbol });
+                        }
+                    }
+                    AppResult::EconomicCalendarLoaded { events } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.economic_events = events;
+                    }
+                    AppResult::OrderBookLoaded { book } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.order_book = Some(book);
+                    }
+                    AppResult::OrderBookError { symbol, error } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.show_error_toast(format!("Carnet d'ordres {} indisponible : {}", symbol, error));
+                    }
+                    AppResult::ChartExported { path } => {
+                        let mut app_lock = app.lock().unwrap();
+                        info!(path = %path.display(), "Chart exported");
+                        app_lock.show_toast(format!("Graphique exporté : {}", path.display()));
+                    }
+                    AppResult::ExportError { symbol, error } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.show_error_toast(format!("Échec de l'export de {} : {}", symbol, error));
+                    }
+                    AppResult::EarningsLoaded { symbol, date } => {
+                        let mut app_lock = app.lock().unwrap();
+                        if let Some(item) = app_lock.watchlist.iter_mut().find(|item| item.symbol == symbol) {
+                            item.earnings_date = date;
+                        }
+                    }
+                    AppResult::AnalystRatingsLoaded { symbol, ratings } => {
+                        let mut app_lock = app.lock().unwrap();
+                        if let Some(item) = app_lock.watchlist.iter_mut().find(|item| item.symbol == symbol) {
+                            item.analyst_ratings = Some(ratings);
+                        }
+                    }
+                    AppResult::HoldersLoaded { symbol, holders } => {
+                        let mut app_lock = app.lock().unwrap();
+                        if let Some(item) = app_lock.watchlist.iter_mut().find(|item| item.symbol == symbol) {
+                            item.holders = Some(holders);
+                        }
+                    }
+                    AppResult::FinancialsLoaded { symbol, financials } => {
+                        let mut app_lock = app.lock().unwrap();
+                        if let Some(item) = app_lock.watchlist.iter_mut().find(|item| item.symbol == symbol) {
+                            item.financials = Some(financials);
+                        }
+                    }
+                    AppResult::DividendsLoaded { symbol, dividends } => {
+                        let mut app_lock = app.lock().unwrap();
+                        if let Some(item) = app_lock.watchlist.iter_mut().find(|item| item.symbol == symbol) {
+                            item.dividends = Some(dividends);
+                        }
+                    }
+                    AppResult::HoldersError { symbol, error } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.show_error_toast(format!("Échec du chargement des détenteurs de {} : {}", symbol, error));
+                    }
+                    AppResult::ScreenerLoaded { screener, quotes } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.set_screener_quotes(screener, quotes);
+                    }
+                    AppResult::WebhookError { symbol, error } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.show_error_toast(format!("Webhook de l'alerte {} en échec : {}", symbol, error));
+                    }
+                    AppResult::AlertCommandError { symbol, error } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.show_error_toast(format!("Commande de l'alerte {} en échec : {}", symbol, error));
+                    }
+                    AppResult::ScreenerError { screener, error } => {
+                        let mut app_lock = app.lock().unwrap();
+                        app_lock.show_error_toast(format!("Screener {} indisponible : {}", screener.label(), error));
+                    }
+                    AppResult::ConnectivityChecked { online } => {
+                        let mut app_lock = app.lock().unwrap();
+                        let was_offline = app_lock.is_offline();
+                        if app_lock.set_online(online) {
+                            // Retour du réseau : la watchlist en cache est rechargée
+                            info!("Connectivity restored, reloading watchlist");
+                            app_lock.show_toast("Connexion rétablie".to_string());
+                            let reloads: Vec<(String, Interval)> =
+                                app_lock.watchlist.iter().map(|item| (item.symbol.clone(), item.interval())).collect();
+                            for (symbol, interval) in reloads {
+                                request_reload(&mut app_lock, &command_tx, symbol, interval);
+                            }
+                        } else if !online && !was_offline {
+                            warn!("Connectivity lost, pausing periodic refreshes");
+                        }
                     }
                 }
             }
@@ -543,19 +1814,38 @@ fn run(
             terminal.draw(|frame| {
                 let app_lock = app_clone.lock().unwrap();
                 render(frame, &app_lock);
+
+                // Accessibilité puis mode ASCII : conversions une fois l'écran dessiné
+                // (pictogrammes d'abord : leur équivalent texte prime sur "*")
+                if app_lock.config.no_emoji {
+                    accessibility::strip_emoji(frame.buffer_mut());
+                }
+                if app_lock.config.reduced_motion {
+                    accessibility::reduce_motion(frame.buffer_mut());
+                }
+                if app_lock.config.ascii {
+                    ascii::to_ascii(frame.buffer_mut());
+                }
             })?;
         }
 
         // ========================================
         // 2. INPUT : Traite les événements
         // ========================================
+        // Attend le prochain événement (au plus un tick), puis traite ceux
+        // arrivés entre-temps : une rafale de touches ne coûte qu'un rendu
         match events.next() {
             Ok(event) => {
                 let mut app_lock = app.lock().unwrap();
                 handle_event(&mut app_lock, event, &command_tx);
+                while let Some(event) = events.try_next() {
+                    handle_event(&mut app_lock, event, &command_tx);
+                }
             }
-            Err(_) => {
-                // Erreur lors de la lecture d'événement
+            Err(e) => {
+                // Thread d'entrée arrêté : plus aucune touche ne peut arriver
+                error!(error = ?e, "Input thread stopped");
+                return Err(e);
             }
         }
 
@@ -565,6 +1855,89 @@ fn run(
         {
             let mut app_lock = app.lock().unwrap();
             app_lock.tick();
+
+            // Alerte la veille des résultats (si activée dans la config)
+            app_lock.check_earnings_alerts(chrono::Local::now().date_naive());
+
+            // Règles "once" déclenchées depuis le dernier tick : alerts.json à jour
+            save_alert_book_changes(&mut app_lock, &AlertBook::default_path());
+
+            // Santé des fournisseurs (segment du header)
+            app_lock.provider_health = health::snapshot();
+            // Métriques des téléchargements (overlay F12)
+            if app_lock.debug_overlay {
+                app_lock.fetch_metrics = metrics::snapshot();
+            }
+
+            // Sonde réseau : hors ligne, les rafraîchissements périodiques sont suspendus
+            if app_lock.connectivity_due() {
+                let _ = command_tx.send(AppCommand::ProbeConnectivity);
+                app_lock.mark_connectivity_probed();
+            }
+
+            // Rafraîchissement automatique : un ticker à la fois, le plus en vue d'abord
+            if let Some((symbol, interval, request_id)) = app_lock.start_auto_refresh(chrono::Utc::now()) {
+                debug!(ticker = %symbol, interval = %interval.label(), "Auto-refreshing ticker");
+                let _ = command_tx.send(AppCommand::ReloadTickerData { symbol, interval, request_id, silent: true });
+            }
+
+            // Rafraîchissement périodique du bandeau des indices
+            if app_lock.market_overview_due() {
+                let symbols = app_lock.config.market_overview.clone();
+                let _ = command_tx.send(AppCommand::LoadMarketOverview { symbols });
+                app_lock.mark_market_overview_requested();
+            }
+
+            // Indice Fear & Greed : une fois par jour, watchlist avec de la crypto
+            if app_lock.fear_greed_due() {
+                let _ = command_tx.send(AppCommand::FetchFearGreed);
+                app_lock.mark_fear_greed_requested();
+            }
+
+            // Statistiques CoinGecko : fiche détaillée d'une crypto, toutes les 5 minutes
+            if app_lock.crypto_stats_due() {
+                if let Some(item) = app_lock.selected_item() {
+                    let _ = command_tx.send(AppCommand::FetchCryptoStats { symbol: item.symbol.clone() });
+                }
+                app_lock.mark_crypto_stats_requested();
+            }
+
+            // Liste distante de la watchlist : toutes les refresh_minutes
+            if !demo::is_enabled() && app_lock.remote_watchlist_due() {
+                if let Some(remote) = &app_lock.config.remote_watchlist {
+                    let _ = command_tx.send(AppCommand::FetchRemoteWatchlist { url: remote.url.clone() });
+                }
+                app_lock.mark_remote_watchlist_requested();
+            }
+
+            // Calendrier économique : toutes les refresh_minutes
+            if !demo::is_enabled() && app_lock.economic_calendar_due() {
+                if let Some(calendar) = &app_lock.config.economic_calendar {
+                    let _ = command_tx.send(AppCommand::FetchEconomicCalendar { url: calendar.url.clone() });
+                }
+                app_lock.mark_economic_calendar_requested();
+            }
+
+            // Rafraîchissement périodique du carnet d'ordres
+            if app_lock.order_book_due() {
+                if let Some(item) = app_lock.selected_item() {
+                    let _ = command_tx.send(AppCommand::FetchOrderBook { symbol: item.symbol.clone() });
+                }
+                app_lock.mark_order_book_requested();
+            }
+
+            // Mode démo : les prix synthétiques évoluent à intervalle régulier
+            if demo::is_enabled() && demo_ticked_at.elapsed() >= demo::TICK_INTERVAL {
+                demo_step += 1;
+                demo_ticked_at = std::time::Instant::now();
+                for item in app_lock.watchlist.iter_mut() {
+                    if let Some(data) = item.data.as_mut() {
+                        demo::tick(data, demo_step);
+                    }
+                }
+                let triggers = app_lock.evaluate_alerts();
+                dispatch_alert_triggers(&triggers, &command_tx);
+            }
         }
     }
 
@@ -586,50 +1959,98 @@ fn run(
 /// - Combinaison de conditions pour gérer différents contextes
 /// - Navigation contextuelle selon l'écran actuel
 /// - command_tx : pour envoyer des commandes au worker thread
-fn handle_event(app: &mut App, event: lazywallet::ui::events::Event, command_tx: &mpsc::Sender<AppCommand>) {
+fn handle_event(app: &mut App, event: lazywallet::ui::events::Event, command_tx: &QueueSender<AppCommand>) {
     // Importe les helpers pour vérifier les événements
     use lazywallet::ui::events::{
-        get_char_from_event, is_add_event, is_backspace_event, is_delete_event, is_down_event,
-        is_enter_event, is_escape_event, is_next_interval_event, is_previous_interval_event,
-        is_quit_event, is_space_event, is_ticker_char_event, is_up_event, Event,
+        get_char_from_event, is_add_event, is_command_event, is_allocation_event, is_annotation_event, is_annotations_panel_event, is_trend_line_event, is_backspace_event, is_backtest_event, is_benchmark_event, is_compare_event,
+        is_csv_export_event, is_debug_overlay_event, is_delete_event, is_detail_event, is_dismiss_event, is_dividends_event, is_down_event, is_enter_event, is_escape_event, is_export_event,
+        is_forex_menu_event, is_holders_event, is_interrupt_event, is_leaderboard_event, is_measure_event, is_new_alert_event, is_next_interval_event, is_next_secondary_interval_event,
+        is_note_event, is_order_book_event, is_position_event, is_privacy_event, is_previous_interval_event, is_previous_secondary_interval_event,
+        is_quit_event, is_retry_event, is_returns_event, is_screener_event, is_session_separator_event, is_session_stats_event, is_snooze_event, is_space_event,
+        is_split_view_event, is_statement_period_event, is_tag_filter_event, is_tags_event, is_text_char_event, is_ticker_char_event,
+        is_transaction_event, is_up_event, is_y_axis_lock_event, measure_step_event, quick_select_event, text_export_event, yank_event, Event,
     };
 
     match event {
-        Event::Key(_) if is_quit_event(&event) => {
-            // Touche 'q' : quit confirmation two-step
-            // CONCEPT : Two-step confirmation pour éviter les quits accidentels
-            // - Première pression : active confirm_quit
-            // - Deuxième pression : quit réel
-            if app.is_awaiting_quit_confirmation() {
+        // Ctrl+C : quitter tout de suite, sans confirmation, par la même
+        // fermeture que 'q' (worker arrêté, sauvegardes, terminal restauré)
+        Event::Key(_) if is_interrupt_event(&event) => {
+            info!("User pressed Ctrl+C, quitting");
+            app.quit();
+        }
+
+        // Popup de confirmation affiché (quitter, supprimer) : modal
+        // CONCEPT : Two-step confirmation pour éviter les actions accidentelles
+        // - Entrée ou la même touche : confirme
+        // - Toute autre touche (Échap, ...) : annule, sans autre effet
+        Event::Key(_) if app.is_awaiting_quit_confirmation() => {
+            if is_enter_event(&event) || is_quit_event(&event) {
                 info!("User confirmed quit");
                 app.quit();
             } else {
-                info!("User requested quit (awaiting confirmation)");
-                app.request_quit();
-            }
-        }
-
-        // 'd' : supprimer le ticker sélectionné (seulement sur Dashboard)
-        Event::Key(_) if is_delete_event(&event) && app.is_on_dashboard() => {
-            // CONCEPT : Two-step delete confirmation (Vim-like)
-            // - Première pression : demande confirmation
-            // - Deuxième pression : suppression réelle
-            if !app.watchlist.is_empty() {
-                if app.is_awaiting_delete_confirmation() {
-                    // Deuxième pression : on supprime
-                    let symbol = app.watchlist.get(app.selected_index)
-                        .map(|item| item.symbol.clone())
-                        .unwrap_or_default();
-                    info!(ticker = %symbol, "User confirmed delete");
-                    app.delete_selected();
-                } else {
-                    // Première pression : on demande confirmation
-                    info!("User requested delete (awaiting confirmation)");
-                    app.request_delete();
+                debug!("User cancelled quit");
+                app.cancel_quit();
+            }
+        }
+        Event::Key(_) if app.is_awaiting_delete_confirmation() => {
+            if is_enter_event(&event) || is_delete_event(&event) {
+                let symbol = app.watchlist.get(app.selected_index)
+                    .map(|item| item.symbol.clone())
+                    .unwrap_or_default();
+                info!(ticker = %symbol, "User confirmed delete");
+                app.delete_selected();
+                save_watchlist(app);
+            } else {
+                debug!("User cancelled delete");
+                app.cancel_delete();
+            }
+        }
+
+        // 'q' : quitter (hors saisie), confirmation seulement si du travail
+        // serait perdu (téléchargement en cours, sauvegarde en échec)
+        Event::Key(_) if is_quit_event(&event) && !app.is_in_input_mode() => {
+            match app.quit_blocker() {
+                Some(reason) => {
+                    info!(reason = %reason, "User requested quit with pending work (awaiting confirmation)");
+                    app.request_quit();
+                }
+                None => {
+                    info!("User quit");
+                    app.quit();
                 }
             }
         }
 
+        // F12 : overlay de debug (métriques des téléchargements, tous les écrans)
+        Event::Key(_) if is_debug_overlay_event(&event) => {
+            app.debug_overlay = !app.debug_overlay;
+            app.fetch_metrics = metrics::snapshot();
+            debug!(visible = app.debug_overlay, "User toggled debug overlay");
+        }
+
+        // ':' : saisie d'une commande (tous les écrans sauf la saisie)
+        Event::Key(_) if is_command_event(&event) && !app.is_in_input_mode() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            debug!("User opened command input");
+            app.start_command_input();
+        }
+
+        // 'i' : mode discret (tous les écrans sauf la saisie)
+        Event::Key(_) if is_privacy_event(&event) && !app.is_in_input_mode() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            let enabled = !format::privacy();
+            format::set_privacy(enabled);
+            info!(enabled, "User toggled privacy mode");
+        }
+
+        // 'd' : supprimer le ticker sélectionné (après confirmation dans le popup)
+        Event::Key(_) if is_delete_event(&event) && app.is_on_dashboard() && !app.watchlist.is_empty() => {
+            info!("User requested delete (awaiting confirmation)");
+            app.request_delete();
+        }
+
         // 'a' : ajouter un ticker (seulement sur Dashboard)
         Event::Key(_) if is_add_event(&event) && app.is_on_dashboard() => {
             // CONCEPT : Enter input mode (Vim-like)
@@ -639,6 +2060,373 @@ fn handle_event(app: &mut App, event: lazywallet::ui::events::Event, command_tx:
             app.start_input("Add ticker: ".to_string());
         }
 
+        // 'p' : saisir la quantité détenue du ticker sélectionné (seulement sur Dashboard)
+        Event::Key(_) if is_position_event(&event) && app.is_on_dashboard() && !app.watchlist.is_empty() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User requested position input");
+            app.start_position_input();
+        }
+
+        // 't' : saisir une transaction sur le ticker sélectionné (seulement sur Dashboard)
+        Event::Key(_) if is_transaction_event(&event) && app.is_on_dashboard() && !app.watchlist.is_empty() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User requested transaction input");
+            app.start_transaction_input();
+        }
+
+        // 'n' : nouvelle alerte sur indicateur pour le ticker sélectionné
+        Event::Key(_) if is_new_alert_event(&event) && app.is_on_dashboard() && !app.watchlist.is_empty() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User requested alert input");
+            app.start_alert_input();
+        }
+
+        // 'c' : tags du ticker sélectionné
+        Event::Key(_) if is_tags_event(&event) && app.is_on_dashboard() && !app.watchlist.is_empty() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User requested tags input");
+            app.start_tags_input();
+        }
+
+        // 'C' : filtre par tag suivant (tous → dividend → long → ... → tous)
+        Event::Key(_) if is_tag_filter_event(&event) && app.is_on_dashboard() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            app.cycle_tag_filter();
+            info!(filter = ?app.tag_filter, "User changed tag filter");
+        }
+
+        // 'f' : menu d'ajout rapide des paires de devises (depuis le Dashboard)
+        Event::Key(_) if is_forex_menu_event(&event) && app.is_on_dashboard() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User opened forex quick-add menu");
+            app.show_forex_menu();
+        }
+
+        // Menu forex : navigation, ajout (Enter) et fermeture (ESC / f)
+        Event::Key(_) if is_up_event(&event) && app.is_on_forex_menu() => {
+            app.forex_menu_up();
+        }
+        Event::Key(_) if is_down_event(&event) && app.is_on_forex_menu() => {
+            app.forex_menu_down();
+        }
+        Event::Key(_) if is_enter_event(&event) && app.is_on_forex_menu() => {
+            let (symbol, label) = app.selected_forex_pair();
+            app.show_dashboard();
+            if app.is_in_watchlist(symbol) {
+                app.show_error_toast(format!("{} est déjà dans la watchlist", label));
+            } else {
+                info!(ticker = %symbol, "User added forex pair from quick-add menu");
+                let _ = command_tx.send(AppCommand::AddTicker { symbol: symbol.to_string() });
+            }
+        }
+        Event::Key(_) if (is_escape_event(&event) || is_forex_menu_event(&event)) && app.is_on_forex_menu() => {
+            app.cancel_quit();
+            app.show_dashboard();
+        }
+
+        // 's' : vue découverte (screeners prédéfinis, depuis le Dashboard)
+        Event::Key(_) if is_screener_event(&event) && app.is_on_dashboard() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!(screener = app.screener.scr_id(), "User opened screener view");
+            app.show_screener();
+            request_screener(app, command_tx);
+        }
+
+        // Vue découverte : navigation, changement de liste, ajout (Enter) et retour
+        Event::Key(_) if is_up_event(&event) && app.is_on_screener() => {
+            app.screener_up();
+        }
+        Event::Key(_) if is_down_event(&event) && app.is_on_screener() => {
+            app.screener_down();
+        }
+        Event::Key(_)
+            if (is_next_interval_event(&event) || is_previous_interval_event(&event)) && app.is_on_screener() =>
+        {
+            app.switch_screener(is_next_interval_event(&event));
+            request_screener(app, command_tx);
+        }
+        Event::Key(_) if is_enter_event(&event) && app.is_on_screener() => {
+            if let Some(symbol) = app.selected_screener_quote().map(|quote| quote.symbol.clone()) {
+                if app.is_in_watchlist(&symbol) {
+                    app.show_error_toast(format!("{} est déjà dans la watchlist", symbol));
+                } else {
+                    info!(ticker = %symbol, "User added ticker from screener");
+                    let _ = command_tx.send(AppCommand::AddTicker { symbol: symbol.clone() });
+                    app.show_toast(format!("Ajout de {} à la watchlist...", symbol));
+                }
+            }
+        }
+        Event::Key(_) if (is_escape_event(&event) || is_screener_event(&event)) && app.is_on_screener() => {
+            app.cancel_quit();
+            app.show_dashboard();
+        }
+
+        // 'v' : tableau des rendements (depuis le Dashboard)
+        Event::Key(_) if is_returns_event(&event) && app.is_on_dashboard() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User opened returns view");
+            app.show_returns();
+
+            request_daily_data(app, command_tx, Priority::User);
+        }
+
+        // 'v', ESC ou SPACE : retour au dashboard depuis la vue rendements
+        Event::Key(_)
+            if (is_returns_event(&event) || is_escape_event(&event) || is_space_event(&event))
+                && app.is_on_returns() =>
+        {
+            app.cancel_quit();
+            app.show_dashboard();
+        }
+
+        // 'L' : classement de la watchlist par rendement (depuis le Dashboard)
+        Event::Key(_) if is_leaderboard_event(&event) && app.is_on_dashboard() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User opened leaderboard view");
+            app.show_leaderboard();
+            request_daily_data(app, command_tx, Priority::User);
+        }
+
+        // Classement : navigation, période (h / l), graphique (Enter) et retour
+        Event::Key(_) if is_up_event(&event) && app.is_on_leaderboard() => {
+            app.leaderboard_up();
+        }
+        Event::Key(_) if is_down_event(&event) && app.is_on_leaderboard() => {
+            app.leaderboard_down();
+        }
+        Event::Key(_)
+            if (is_next_interval_event(&event) || is_previous_interval_event(&event)) && app.is_on_leaderboard() =>
+        {
+            app.switch_leaderboard_period(is_next_interval_event(&event));
+        }
+        Event::Key(_) if is_enter_event(&event) && app.is_on_leaderboard() => {
+            app.cancel_quit();
+            if app.select_leaderboard_entry() {
+                info!(ticker = %app.watchlist[app.selected_index].symbol, "User opened chart from leaderboard");
+                app.show_chart();
+                request_secondary_data(app, command_tx);
+                request_benchmark_data(app, command_tx);
+            }
+        }
+        Event::Key(_) if (is_leaderboard_event(&event) || is_escape_event(&event)) && app.is_on_leaderboard() => {
+            app.cancel_quit();
+            app.show_dashboard();
+        }
+
+        // '$' : calendrier des dividendes de la watchlist (depuis le Dashboard)
+        Event::Key(_) if is_dividends_event(&event) && app.is_on_dashboard() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User opened dividend calendar");
+            app.show_dividends();
+            for symbol in app.dividends_to_load() {
+                let _ = command_tx.send(AppCommand::FetchDividends { symbol });
+            }
+        }
+
+        // Calendrier des dividendes : navigation, graphique (Enter) et retour
+        Event::Key(_) if is_up_event(&event) && app.is_on_dividends() => {
+            app.dividends_up();
+        }
+        Event::Key(_) if is_down_event(&event) && app.is_on_dividends() => {
+            app.dividends_down();
+        }
+        Event::Key(_) if is_enter_event(&event) && app.is_on_dividends() => {
+            app.cancel_quit();
+            if app.select_dividend_entry() {
+                info!(ticker = %app.watchlist[app.selected_index].symbol, "User opened chart from dividend calendar");
+                app.show_chart();
+                request_secondary_data(app, command_tx);
+                request_benchmark_data(app, command_tx);
+            }
+        }
+        Event::Key(_) if (is_dividends_event(&event) || is_escape_event(&event)) && app.is_on_dividends() => {
+            app.cancel_quit();
+            app.show_dashboard();
+        }
+
+        // 'm' : comparaison de plusieurs tickers (depuis le Dashboard)
+        Event::Key(_) if is_compare_event(&event) && app.is_on_dashboard() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User opened compare view");
+            app.show_compare();
+            request_daily_data(app, command_tx, Priority::User);
+        }
+
+        // Comparaison : navigation, sélection (Espace), période (h / l),
+        // graphique (Enter) et retour
+        Event::Key(_) if is_up_event(&event) && app.is_on_compare() => {
+            app.compare_up();
+        }
+        Event::Key(_) if is_down_event(&event) && app.is_on_compare() => {
+            app.compare_down();
+        }
+        Event::Key(_) if is_space_event(&event) && app.is_on_compare() => {
+            let toggled = app.toggle_compare_selection();
+            if !toggled {
+                app.show_error_toast(format!("{} tickers au plus dans la comparaison", MAX_COMPARED));
+            }
+        }
+        Event::Key(_)
+            if (is_next_interval_event(&event) || is_previous_interval_event(&event)) && app.is_on_compare() =>
+        {
+            app.switch_compare_period(is_next_interval_event(&event));
+        }
+        Event::Key(_) if is_enter_event(&event) && app.is_on_compare() => {
+            app.cancel_quit();
+            if app.select_compare_entry() {
+                info!(ticker = %app.watchlist[app.selected_index].symbol, "User opened chart from compare view");
+                app.show_chart();
+                request_secondary_data(app, command_tx);
+                request_benchmark_data(app, command_tx);
+            }
+        }
+        Event::Key(_) if (is_compare_event(&event) || is_escape_event(&event)) && app.is_on_compare() => {
+            app.cancel_quit();
+            app.show_dashboard();
+        }
+
+        // 'I' : fiche détaillée du ticker sélectionné (depuis le Dashboard)
+        Event::Key(_) if is_detail_event(&event) && app.is_on_dashboard() && !app.watchlist.is_empty() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            app.show_detail();
+
+            // Fourchette 52 semaines et petit graphique : historique 1w
+            // Bêta : historiques 1d du ticker et de son indice de référence
+            if let Some(item) = app.selected_item() {
+                info!(ticker = %item.symbol, "User opened detail view");
+                for interval in [Interval::W1, Interval::D1] {
+                    if item.data_for(interval).is_none() {
+                        let _ = command_tx.send(AppCommand::LoadIntervalData {
+                            symbol: item.symbol.clone(),
+                            interval,
+                        });
+                    }
+                }
+
+                let benchmark = app.benchmark_symbol(&item.symbol);
+                if !benchmark.eq_ignore_ascii_case(&item.symbol) && app.benchmark_data(benchmark, Interval::D1).is_none() {
+                    let _ = command_tx.send(AppCommand::LoadBenchmark {
+                        symbol: benchmark.to_string(),
+                        interval: Interval::D1,
+                    });
+                }
+
+                // Analystes et comptes : actions seulement, tant qu'ils ne sont pas chargés
+                if item.ticker_type() == TickerType::Stock && item.analyst_ratings.is_none() {
+                    let _ = command_tx.send(AppCommand::FetchAnalystRatings { symbol: item.symbol.clone() });
+                }
+                if item.ticker_type() == TickerType::Stock && item.financials.is_none() {
+                    let _ = command_tx.send(AppCommand::FetchFinancials { symbol: item.symbol.clone() });
+                }
+            }
+        }
+
+        // Fiche détaillée : Enter ouvre le graphique, n édite la note
+        Event::Key(_) if is_enter_event(&event) && app.is_on_detail() => {
+            app.cancel_quit();
+            app.show_chart();
+            request_secondary_data(app, command_tx);
+            request_benchmark_data(app, command_tx);
+        }
+        Event::Key(_) if is_note_event(&event) && app.is_on_detail() => {
+            app.cancel_quit();
+            app.start_note_input();
+        }
+
+        // 'h' : affiche/masque les initiés et institutionnels (fiche détaillée, actions uniquement)
+        Event::Key(_) if is_holders_event(&event) && app.is_on_detail() => {
+            app.cancel_quit();
+            let Some(item) = app.selected_item().filter(|item| item.ticker_type() == TickerType::Stock) else {
+                app.show_error_toast("Initiés et institutionnels disponibles uniquement pour les actions".to_string());
+                return;
+            };
+            // Chargés une fois par session (déclarations trimestrielles)
+            if item.holders.is_none() {
+                let _ = command_tx.send(AppCommand::FetchHolders { symbol: item.symbol.clone() });
+            }
+            app.toggle_holders();
+            info!(visible = app.holders_visible, "User toggled holders panel");
+        }
+
+        // 'f' : bascule les comptes entre annuels et trimestriels (fiche détaillée)
+        Event::Key(_) if is_statement_period_event(&event) && app.is_on_detail() => {
+            app.cancel_quit();
+            app.toggle_statement_period();
+            info!(period = ?app.statement_period, "User switched financial statements period");
+        }
+
+        // 'I', ESC ou SPACE : retour au dashboard depuis la fiche détaillée
+        Event::Key(_)
+            if (is_detail_event(&event) || is_escape_event(&event) || is_space_event(&event)) && app.is_on_detail() =>
+        {
+            app.cancel_quit();
+            app.show_dashboard();
+        }
+
+        // 'w' : vue répartition du portefeuille (depuis le Dashboard)
+        Event::Key(_) if is_allocation_event(&event) && app.is_on_dashboard() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            info!("User opened allocation view");
+            app.show_allocation();
+        }
+
+        // 't' : saisir un dépôt ou un retrait de liquidités (depuis la vue répartition)
+        Event::Key(_) if is_transaction_event(&event) && app.is_on_allocation() => {
+            app.cancel_quit();
+            info!("User requested cash movement input");
+            app.start_cash_input();
+        }
+
+        // 'w', ESC ou SPACE : retour au dashboard depuis la vue répartition
+        Event::Key(_)
+            if (is_allocation_event(&event) || is_escape_event(&event) || is_space_event(&event))
+                && app.is_on_allocation() =>
+        {
+            app.cancel_quit();
+            debug!("User returned to dashboard");
+            app.show_dashboard();
+        }
+
+        // 'x' : fermer l'alerte affichée dans le bandeau (depuis le Dashboard)
+        Event::Key(_) if is_dismiss_event(&event) && app.is_on_dashboard() && app.current_notification().is_some() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            app.dismiss_notification();
+        }
+
+        // 'z' : snooze de la règle de l'alerte affichée (depuis le Dashboard)
+        Event::Key(_)
+            if is_snooze_event(&event)
+                && app.is_on_dashboard()
+                && app.current_notification().is_some_and(|notification| notification.rule.is_some()) =>
+        {
+            app.cancel_quit();
+            app.cancel_delete();
+            let symbol = app.current_notification().map(|notification| notification.symbol.clone());
+            if let (Some(until), Some(symbol)) = (app.snooze_notification(), symbol) {
+                info!(ticker = %symbol, until = %until, "User snoozed alert rule");
+                app.show_toast(format!(
+                    "Alerte {} en pause jusqu'à {}",
+                    symbol,
+                    until.with_timezone(&chrono::Local).format("%H:%M")
+                ));
+            }
+            save_alert_book_changes(app, &AlertBook::default_path());
+        }
+
         // Navigation dans la watchlist (seulement sur Dashboard)
         Event::Key(_) if is_up_event(&event) && app.is_on_dashboard() => {
             app.cancel_quit(); // Annule les confirmations si actives
@@ -653,6 +2441,30 @@ fn handle_event(app: &mut App, event: lazywallet::ui::events::Event, command_tx:
             app.navigate_down();
         }
 
+        // 1 à 9 : sélection directe d'une ligne (Shift : ouvre aussi le graphique)
+        Event::Key(_) if app.is_on_dashboard() && quick_select_event(&event).is_some() => {
+            app.cancel_quit(); // Annule les confirmations si actives
+            app.cancel_delete();
+            if let Some((index, open)) = quick_select_event(&event) {
+                if app.select_row(index) && open {
+                    info!(ticker = %app.watchlist[index].symbol, "User opened chart view");
+                    app.show_chart();
+                    request_secondary_data(app, command_tx);
+                    request_benchmark_data(app, command_tx);
+                }
+            }
+        }
+
+        // Enter sur une watchlist vide : ajoute le symbole populaire sélectionné
+        Event::Key(_) if is_enter_event(&event) && app.is_on_dashboard() && app.selected_suggestion().is_some() => {
+            app.cancel_quit();
+            if let Some((symbol, _)) = app.selected_suggestion() {
+                info!(ticker = %symbol, "User added suggested ticker from empty watchlist");
+                let _ = command_tx.send(AppCommand::AddTicker { symbol: symbol.to_string() });
+                app.show_toast(format!("Ajout de {} à la watchlist...", symbol));
+            }
+        }
+
         // Enter : afficher le graphique du ticker sélectionné
         Event::Key(_) if is_enter_event(&event) && app.is_on_dashboard() => {
             app.cancel_quit(); // Annule les confirmations si actives
@@ -663,6 +2475,40 @@ fn handle_event(app: &mut App, event: lazywallet::ui::events::Event, command_tx:
                 info!(ticker = %item.symbol, "User opened chart view");
             }
             app.show_chart();
+            request_secondary_data(app, command_tx);
+            request_benchmark_data(app, command_tx);
+        }
+
+        // 'u' : outil de mesure (curseur ← / →, Enter pour ancrer, ESC ou u pour quitter)
+        Event::Key(_) if is_measure_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            app.toggle_measure();
+            debug!(active = app.measure.is_some(), "User toggled measure tool");
+        }
+        Event::Key(_) if app.is_on_chart() && app.measure.is_some() && measure_step_event(&event).is_some() => {
+            app.move_measure_cursor(measure_step_event(&event).unwrap_or_default());
+        }
+        Event::Key(_) if is_enter_event(&event) && app.is_on_chart() && app.measure.is_some() => {
+            app.anchor_measure();
+        }
+        Event::Key(_) if is_escape_event(&event) && app.is_on_chart() && app.measure.is_some() => {
+            app.cancel_quit();
+            app.measure = None;
+        }
+
+        // 'y' : verrouille l'échelle de l'axe Y sur les bornes affichées (ou la libère)
+        Event::Key(_) if is_y_axis_lock_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            app.y_axis_lock = match app.y_axis_lock {
+                Some(_) => None,
+                None => app
+                    .selected_item()
+                    .and_then(|item| item.data.as_ref())
+                    .and_then(|data| {
+                        candlestick_text::visible_price_bounds(&data.candles, app.config.chart_margin_percent)
+                    }),
+            };
+            info!(bounds = ?app.y_axis_lock, "User toggled y-axis lock");
         }
 
         // ESC ou SPACE : retour au dashboard depuis ChartView
@@ -684,15 +2530,46 @@ fn handle_event(app: &mut App, event: lazywallet::ui::events::Event, command_tx:
             app.cancel_input();
         }
 
-        // Enter : valider le mode input et ajouter le ticker
+        // Enter : valider le mode input (ajout de ticker ou position)
         Event::Key(_) if is_enter_event(&event) && app.is_in_input_mode() => {
-            let symbol = app.submit_input().trim().to_uppercase();
-            if !symbol.is_empty() {
-                info!(ticker = %symbol, "User submitted ticker for adding");
-                // Envoie la commande au worker pour ajouter le ticker
-                let _ = command_tx.send(AppCommand::AddTicker { symbol });
-            } else {
-                debug!("Empty ticker symbol, ignoring");
+            let purpose = app.input_purpose.clone();
+            let value = app.submit_input();
+
+            match purpose {
+                InputPurpose::AddTicker => {
+                    let symbol = value.trim().to_uppercase();
+                    if !symbol.is_empty() {
+                        info!(ticker = %symbol, "User submitted ticker for adding");
+                        // Envoie la commande au worker pour ajouter le ticker
+                        let _ = command_tx.send(AppCommand::AddTicker { symbol });
+                    } else {
+                        debug!("Empty ticker symbol, ignoring");
+                    }
+                }
+                InputPurpose::SetPosition { symbol } => {
+                    submit_position(app, &symbol, value.trim());
+                }
+                InputPurpose::RecordTransaction { symbol } => {
+                    submit_transaction(app, &symbol, value.trim());
+                }
+                InputPurpose::RecordCash => {
+                    submit_cash_movement(app, value.trim());
+                }
+                InputPurpose::AddAlert { symbol } => {
+                    submit_alert(app, &symbol, value.trim(), command_tx);
+                }
+                InputPurpose::SetTags { symbol } => {
+                    submit_tags(app, &symbol, &value);
+                }
+                InputPurpose::SetNote { symbol } => {
+                    submit_note(app, &symbol, &value);
+                }
+                InputPurpose::AddAnnotation { symbol, timestamp } => {
+                    submit_annotation(app, &symbol, timestamp, &value);
+                }
+                InputPurpose::Command { .. } => {
+                    run_command(app, &value);
+                }
             }
         }
 
@@ -702,26 +2579,51 @@ fn handle_event(app: &mut App, event: lazywallet::ui::events::Event, command_tx:
         }
 
         // Caractères : ajouter au buffer
-        Event::Key(_) if is_ticker_char_event(&event) && app.is_in_input_mode() => {
+        // (saisie libre pour les transactions, alertes, tags et notes : espaces et signes autorisés)
+        Event::Key(_)
+            if app.is_in_input_mode()
+                && if matches!(
+                    app.input_purpose,
+                    InputPurpose::RecordTransaction { .. }
+                        | InputPurpose::RecordCash
+                        | InputPurpose::AddAlert { .. }
+                        | InputPurpose::SetTags { .. }
+                        | InputPurpose::SetNote { .. }
+                        | InputPurpose::AddAnnotation { .. }
+                        | InputPurpose::Command { .. }
+                ) {
+                    is_text_char_event(&event)
+                } else {
+                    is_ticker_char_event(&event)
+                } =>
+        {
             if let Some(c) = get_char_from_event(&event) {
                 app.append_char(c);
             }
         }
 
-        // 'l' : intervalle suivant (seulement sur ChartView)
+        // 'r' : réessaie le chargement échoué de l'item sélectionné
+        Event::Key(_) if is_retry_event(&event) && (app.is_on_dashboard() || app.is_on_chart()) => {
+            app.cancel_quit();
+            app.cancel_delete();
+            if let Some(symbol) = app.retry_selected() {
+                info!(ticker = %symbol, "User retried failed load");
+                let interval = app.current_interval;
+                request_reload(app, command_tx, symbol, interval);
+            }
+        }
+
         Event::Key(_) if is_next_interval_event(&event) && app.is_on_chart() => {
             app.cancel_quit(); // Annule la confirmation de quit si active
             app.next_interval();
             info!(interval = %app.current_interval.label(), "User changed to next interval");
 
             // Envoie la commande de rechargement au worker
-            if let Some(item) = app.watchlist.get(app.selected_index) {
-                let _ = command_tx.send(AppCommand::ReloadTickerData {
-                    symbol: item.symbol.clone(),
-                    interval: app.current_interval,
-                    index: app.selected_index,
-                });
+            if let Some(symbol) = app.selected_item().map(|item| item.symbol.clone()) {
+                let interval = app.current_interval;
+                request_reload(app, command_tx, symbol, interval);
             }
+            request_benchmark_data(app, command_tx);
         }
 
         // 'h' : intervalle précédent (seulement sur ChartView)
@@ -731,12 +2633,194 @@ fn handle_event(app: &mut App, event: lazywallet::ui::events::Event, command_tx:
             info!(interval = %app.current_interval.label(), "User changed to previous interval");
 
             // Envoie la commande de rechargement au worker
+            if let Some(symbol) = app.selected_item().map(|item| item.symbol.clone()) {
+                let interval = app.current_interval;
+                request_reload(app, command_tx, symbol, interval);
+            }
+            request_benchmark_data(app, command_tx);
+        }
+
+        // 'g' : active/désactive les séparateurs de séance (seulement sur ChartView)
+        Event::Key(_) if is_session_separator_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            app.toggle_session_separators();
+            info!(enabled = app.session_separators, "User toggled session separators");
+        }
+
+        // 'm' : active/désactive la vue multi-timeframe (seulement sur ChartView)
+        Event::Key(_) if is_split_view_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            app.toggle_split_view();
+            info!(split_view = app.split_view, secondary = %app.secondary_interval.label(), "User toggled split view");
+            request_secondary_data(app, command_tx);
+        }
+
+        // 'L' / 'H' : change l'intervalle du graphique secondaire
+        Event::Key(_) if is_next_secondary_interval_event(&event) && app.is_on_chart() && app.split_view => {
+            app.cancel_quit();
+            app.next_secondary_interval();
+            info!(interval = %app.secondary_interval.label(), "User changed secondary interval");
+            request_secondary_data(app, command_tx);
+        }
+        Event::Key(_) if is_previous_secondary_interval_event(&event) && app.is_on_chart() && app.split_view => {
+            app.cancel_quit();
+            app.previous_secondary_interval();
+            info!(interval = %app.secondary_interval.label(), "User changed secondary interval");
+            request_secondary_data(app, command_tx);
+        }
+
+        // 'v' : superpose l'indice de référence au graphique (ou le masque)
+        Event::Key(_) if is_benchmark_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            if let Some(enabled) = app.toggle_benchmark() {
+                info!(enabled, "User toggled benchmark overlay");
+                request_benchmark_data(app, command_tx);
+            }
+        }
+
+        // 'o' : affiche/masque le carnet d'ordres (ChartView, crypto uniquement)
+        Event::Key(_) if is_order_book_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            if app.selected_has_order_book() {
+                app.toggle_order_book();
+                info!(visible = app.order_book_visible, "User toggled order book panel");
+            } else {
+                app.show_error_toast("Carnet d'ordres disponible uniquement pour les cryptos".to_string());
+            }
+        }
+
+        // 's' : affiche/masque les statistiques de séance (ChartView, intraday uniquement)
+        Event::Key(_) if is_session_stats_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            if app.current_interval.is_intraday() {
+                app.toggle_session_stats();
+                info!(visible = app.session_stats_visible, "User toggled session stats panel");
+                // Journalier du ticker : moyenne d'amplitude sur 20 séances
+                if let Some(item) = app.selected_item().filter(|item| item.data_for(Interval::D1).is_none()) {
+                    let _ = command_tx.send(AppCommand::LoadIntervalData {
+                        symbol: item.symbol.clone(),
+                        interval: Interval::D1,
+                    });
+                }
+            } else {
+                app.show_error_toast("Statistiques de séance disponibles uniquement en intraday".to_string());
+            }
+        }
+
+        // 'n' : annoter le chandelier sous le curseur de mesure (ChartView)
+        Event::Key(_) if is_annotation_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            if app.start_annotation_input() {
+                info!("User requested annotation input");
+            } else {
+                app.show_error_toast("Placez le curseur sur un chandelier avec [u] pour l'annoter".to_string());
+            }
+        }
+
+        // 'd' : trace ou retire la droite de tendance de l'ancre au curseur (ChartView)
+        Event::Key(_) if is_trend_line_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            let message = match app.toggle_trend_line() {
+                Some(TrendLineEdit::Added) => "Droite de tendance tracée".to_string(),
+                Some(TrendLineEdit::Removed(0)) => {
+                    app.show_toast("Aucune droite sur ce chandelier ([Enter] pour ancrer)".to_string());
+                    return;
+                }
+                Some(TrendLineEdit::Removed(count)) => format!("{} droite(s) de tendance retirée(s)", count),
+                None => {
+                    app.show_error_toast("Ancrez l'outil de mesure [u] puis déplacez le curseur pour tracer une droite".to_string());
+                    return;
+                }
+            };
+            info!(message = %message, "User edited trend lines");
+            match app.annotations.save(&Annotations::default_path()) {
+                Ok(()) => app.show_toast(message),
+                Err(e) => {
+                    error!(error = ?e, "Failed to save trend lines");
+                    app.show_error_toast(format!("Échec de la sauvegarde de la droite : {}", e));
+                }
+            }
+        }
+
+        // 'a' : affiche/masque le panneau des annotations (ChartView)
+        Event::Key(_) if is_annotations_panel_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            app.toggle_annotations();
+            info!(visible = app.annotations_visible, "User toggled annotations panel");
+        }
+
+        // 'b' : backtest de la stratégie courante sur les données du graphique
+        Event::Key(_) if is_backtest_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
+            info!("User opened backtest view");
+            app.show_backtest();
+        }
+
+        // Vue backtest : h / l change de stratégie, b / ESC revient au graphique
+        Event::Key(_)
+            if (is_next_interval_event(&event) || is_previous_interval_event(&event)) && app.is_on_backtest() =>
+        {
+            app.switch_backtest_strategy(is_next_interval_event(&event));
+        }
+        Event::Key(_) if (is_backtest_event(&event) || is_escape_event(&event)) && app.is_on_backtest() => {
+            app.cancel_quit();
+            app.show_chart();
+        }
+
+        // 'y' / 'Y' : copie le symbole et le prix / la ligne du ticker sélectionné
+        Event::Key(_) if yank_event(&event).is_some() && app.is_on_dashboard() && app.selected_item().is_some() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            let whole_row = yank_event(&event) == Some(true);
+            let text = app.selected_item().map(|item| match whole_row {
+                true => clipboard::row_text(item),
+                false => clipboard::quote_text(item),
+            });
+            if let Some(text) = text {
+                match clipboard::copy(&text) {
+                    Ok(()) => {
+                        debug!(text = %text, "Copied to clipboard");
+                        app.show_toast(format!("Copié : {}", text));
+                    }
+                    Err(e) => {
+                        error!(error = ?e, "Failed to copy to clipboard");
+                        app.show_error_toast(format!("Échec de la copie : {}", e));
+                    }
+                }
+            }
+        }
+
+        // 'E' sur le dashboard : exporte la watchlist et ses cotations en CSV
+        Event::Key(_) if is_csv_export_event(&event) && app.is_on_dashboard() && !app.watchlist.is_empty() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            export_quotes_csv(app);
+        }
+
+        // 'e' : exporter le graphique en PNG (seulement sur ChartView)
+        // 'e' sur le dashboard : exporte la watchlist en liste TradingView
+        Event::Key(_) if is_export_event(&event) && app.is_on_dashboard() && !app.watchlist.is_empty() => {
+            app.cancel_quit();
+            app.cancel_delete();
+            export_tradingview_list(app);
+        }
+
+        // 't' / 'T' : exporter le graphique affiché en texte brut / ANSI
+        Event::Key(_) if text_export_event(&event).is_some() && app.is_on_chart() => {
+            app.cancel_quit();
+            let ansi = text_export_event(&event) == Some(true);
+            export_chart_text(app, ansi);
+        }
+
+        Event::Key(_) if is_export_event(&event) && app.is_on_chart() => {
+            app.cancel_quit();
             if let Some(item) = app.watchlist.get(app.selected_index) {
-                let _ = command_tx.send(AppCommand::ReloadTickerData {
-                    symbol: item.symbol.clone(),
-                    interval: app.current_interval,
-                    index: app.selected_index,
-                });
+                if item.has_data() {
+                    let symbol = item.symbol.clone();
+                    info!(ticker = %symbol, "User requested PNG export");
+                    let _ = command_tx.send(AppCommand::ExportChart { symbol: symbol.clone() });
+                    app.show_toast(format!("Export de {} en cours...", symbol));
+                }
             }
         }
 
@@ -756,6 +2840,443 @@ fn handle_event(app: &mut App, event: lazywallet::ui::events::Event, command_tx:
     }
 }
 
+/// Enregistre la quantité saisie pour un symbole et sauvegarde le portefeuille
+///
+/// CONCEPT : Validation de la saisie
+/// - "0" supprime la position
+/// - Une valeur non numérique est refusée avec un toast d'erreur
+fn submit_position(app: &mut App, symbol: &str, input: &str) {
+    let quantity = match input.replace(',', ".").parse::<f64>() {
+        Ok(quantity) if quantity.is_finite() && quantity >= 0.0 => quantity,
+        _ => {
+            app.show_error_toast(format!("Quantité invalide : \"{}\"", input));
+            return;
+        }
+    };
+
+    info!(ticker = %symbol, quantity, "User set position");
+    app.portfolio.set_position(symbol, quantity);
+
+    let saved = app.portfolio.save(&Portfolio::default_path());
+    app.unsaved_portfolio = saved.is_err();
+    match saved {
+        Ok(()) if quantity > 0.0 => app.show_toast(format!("Position {} : {}", symbol, quantity)),
+        Ok(()) => app.show_toast(format!("Position {} supprimée", symbol)),
+        Err(e) => {
+            error!(error = ?e, "Failed to save portfolio");
+            app.show_error_toast(format!("Échec de la sauvegarde du portefeuille : {}", e));
+        }
+    }
+}
+
+/// Exporte la watchlist au format TradingView dans le répertoire d'export
+fn export_tradingview_list(app: &mut App) {
+    let symbols: Vec<String> = app.watchlist.iter().map(|item| item.symbol.clone()).collect();
+    let path = export_dir().join(format!("watchlist_tradingview_{}.txt", chrono::Local::now().format("%Y%m%d-%H%M%S")));
+
+    match tradingview::export_file(&path, &symbols) {
+        Ok(exported) => {
+            info!(path = %path.display(), exported, total = symbols.len(), "Watchlist exported for TradingView");
+            let skipped = symbols.len() - exported;
+            let note = if skipped > 0 { format!(" ({} sans équivalent)", skipped) } else { String::new() };
+            app.show_toast(format!("Liste TradingView exportée : {}{}", path.display(), note));
+        }
+        Err(e) => {
+            error!(error = ?e, "Failed to export TradingView list");
+            app.show_error_toast(format!("Échec de l'export de la liste : {}", e));
+        }
+    }
+}
+
+/// Exporte le graphique affiché dans un fichier texte, à la taille du terminal
+fn export_chart_text(app: &mut App, ansi: bool) {
+    let Some(item) = app.selected_item().filter(|item| item.has_data()) else {
+        return;
+    };
+    let extension = if ansi { "ans" } else { "txt" };
+    let path = export_dir().join(export_file_name(&item.symbol, app.current_interval, extension));
+    let (width, height) = crossterm::terminal::size().unwrap_or((120, 40));
+
+    match text::export_chart_text(app, width, height, ansi, &path) {
+        Ok(()) => {
+            info!(path = %path.display(), ansi, "Chart exported as text");
+            app.show_toast(format!("Graphique exporté : {}", path.display()));
+        }
+        Err(e) => {
+            error!(error = ?e, "Failed to export chart as text");
+            app.show_error_toast(format!("Échec de l'export texte : {}", e));
+        }
+    }
+}
+
+/// Chemin par défaut de l'export CSV des cotations (répertoire d'export)
+fn quotes_csv_path() -> std::path::PathBuf {
+    export_dir().join(format!("watchlist_{}.csv", chrono::Local::now().format("%Y%m%d-%H%M%S")))
+}
+
+/// Exporte la watchlist et ses cotations en CSV dans le répertoire d'export
+fn export_quotes_csv(app: &mut App) {
+    let path = quotes_csv_path();
+
+    match quotes_csv::export_file(&path, &app.watchlist) {
+        Ok(exported) => {
+            info!(path = %path.display(), exported, "Watchlist quotes exported as CSV");
+            app.show_toast(format!("Cotations exportées : {}", path.display()));
+        }
+        Err(e) => {
+            error!(error = ?e, "Failed to export watchlist quotes");
+            app.show_error_toast(format!("Échec de l'export CSV : {}", e));
+        }
+    }
+}
+
+/// Remplace les tags d'un ticker et sauvegarde tags.json
+fn submit_tags(app: &mut App, symbol: &str, input: &str) {
+    let tags = parse_tags(input);
+    info!(ticker = %symbol, tags = ?tags, "User set tags");
+    app.tags.set(symbol, tags);
+
+    match app.tags.save(&Tags::default_path()) {
+        Ok(()) if app.tags.of(symbol).is_empty() => app.show_toast(format!("Tags de {} supprimés", symbol)),
+        Ok(()) => app.show_toast(format!("Tags de {} : {}", symbol, app.tags.of(symbol).join(", "))),
+        Err(e) => {
+            error!(error = ?e, "Failed to save tags");
+            app.show_error_toast(format!("Échec de la sauvegarde des tags : {}", e));
+        }
+    }
+}
+
+/// Exécute une commande saisie après ':' (voir command.rs)
+fn run_command(app: &mut App, input: &str) {
+    match Command::parse(input) {
+        Ok(Command::LogLevel(Some(directives))) => match set_log_filter(&directives) {
+            Ok(previous) => {
+                info!(previous = %previous, filter = %directives, "Log filter changed");
+                app.show_toast(format!("Filtre de logs : {}", directives));
+            }
+            Err(e) => {
+                warn!(filter = %directives, error = ?e, "Failed to change log filter");
+                app.show_error_toast(e.to_string());
+            }
+        },
+        Ok(Command::LogLevel(None)) => {
+            let current = current_log_filter().unwrap_or_else(|| "aucun".to_string());
+            app.show_toast(format!("Filtre de logs : {}", current));
+        }
+        Err(message) => app.show_error_toast(message),
+    }
+}
+
+/// Sauvegarde la watchlist locale dans watchlist.toml (format TOML seulement)
+///
+/// Les symboles locaux deviennent config.watchlist : ceux de la liste
+/// distante et les instruments CSV en sont exclus (ils viennent de config.json)
+fn save_watchlist(app: &mut App) {
+    if app.config.storage_format != StorageFormat::Toml {
+        return;
+    }
+
+    let tickers: Vec<(String, TickerType)> = app
+        .watchlist
+        .iter()
+        .filter(|item| app.config.watchlist.contains(&item.symbol) || !app.remote_watchlist.contains(&item.symbol))
+        .filter(|item| !app.config.custom_instruments.iter().any(|instrument| instrument.symbol == item.symbol))
+        .map(|item| (item.symbol.clone(), item.ticker_type()))
+        .collect();
+    match SavedWatchlist::from_tickers(&tickers).save(&SavedWatchlist::default_path()) {
+        Ok(()) => app.config.watchlist = tickers.into_iter().map(|(symbol, _)| symbol).collect(),
+        Err(e) => {
+            error!(error = ?e, "Failed to save watchlist");
+            app.show_error_toast(format!("Échec de la sauvegarde de la watchlist : {}", e));
+        }
+    }
+}
+
+/// Enregistre la note saisie et sauvegarde notes.json
+fn submit_note(app: &mut App, symbol: &str, input: &str) {
+    info!(ticker = %symbol, "User set note");
+    app.notes.set(symbol, input);
+
+    match app.notes.save(&Notes::default_path()) {
+        Ok(()) if app.notes.of(symbol).is_none() => app.show_toast(format!("Note de {} supprimée", symbol)),
+        Ok(()) => app.show_toast(format!("Note de {} enregistrée", symbol)),
+        Err(e) => {
+            error!(error = ?e, "Failed to save notes");
+            app.show_error_toast(format!("Échec de la sauvegarde de la note : {}", e));
+        }
+    }
+}
+
+/// Enregistre l'annotation saisie et sauvegarde annotations.json
+fn submit_annotation(app: &mut App, symbol: &str, timestamp: DateTime<Utc>, input: &str) {
+    info!(ticker = %symbol, timestamp = %timestamp, "User set annotation");
+    app.annotations.set(symbol, timestamp, input);
+    let removed = input.trim().is_empty();
+
+    match app.annotations.save(&Annotations::default_path()) {
+        Ok(()) if removed => app.show_toast(format!("Annotation de {} supprimée", symbol)),
+        Ok(()) => app.show_toast(format!("Annotation de {} enregistrée", symbol)),
+        Err(e) => {
+            error!(error = ?e, "Failed to save annotations");
+            app.show_error_toast(format!("Échec de la sauvegarde de l'annotation : {}", e));
+        }
+    }
+}
+
+/// Enregistre une transaction saisie et sauvegarde journal et portefeuille
+fn submit_transaction(app: &mut App, symbol: &str, input: &str) {
+    let transaction = match Transaction::parse_input(symbol, input) {
+        Ok(transaction) => transaction,
+        Err(e) => {
+            app.show_error_toast(e.to_string());
+            return;
+        }
+    };
+
+    info!(ticker = %symbol, kind = ?transaction.kind, quantity = transaction.quantity, price = transaction.price, "User recorded transaction");
+    app.record_transaction(transaction);
+
+    let saved = app
+        .journal
+        .save(&Journal::default_path())
+        .and_then(|_| app.portfolio.save(&Portfolio::default_path()));
+    app.unsaved_portfolio = saved.is_err();
+
+    match saved {
+        Ok(()) => {
+            let quantity = app.portfolio.position(symbol).map(|p| p.quantity).unwrap_or(0.0);
+            app.show_toast(format!("Transaction enregistrée : {} détenus {}", symbol, quantity));
+        }
+        Err(e) => {
+            error!(error = ?e, "Failed to save journal");
+            app.show_error_toast(format!("Échec de la sauvegarde du journal : {}", e));
+        }
+    }
+}
+
+/// Enregistre un dépôt ou un retrait saisi et sauvegarde journal et portefeuille
+fn submit_cash_movement(app: &mut App, input: &str) {
+    let movement = match CashMovement::parse_input(input) {
+        Ok(movement) => movement,
+        Err(e) => {
+            app.show_error_toast(e.to_string());
+            return;
+        }
+    };
+
+    info!(currency = %movement.currency, amount = movement.amount, "User recorded cash movement");
+    let currency = movement.currency.clone();
+    app.record_cash_movement(movement);
+
+    let saved = app
+        .journal
+        .save(&Journal::default_path())
+        .and_then(|_| app.portfolio.save(&Portfolio::default_path()));
+    app.unsaved_portfolio = saved.is_err();
+
+    match saved {
+        Ok(()) => {
+            let balance = app
+                .portfolio
+                .cash
+                .iter()
+                .find(|balance| balance.currency == currency)
+                .map_or(0.0, |balance| balance.amount);
+            app.show_toast(format!(
+                "Liquidités enregistrées : solde {}",
+                format::format_price_in(TickerType::Stock, &Currency::new(&currency), balance)
+            ));
+        }
+        Err(e) => {
+            error!(error = ?e, "Failed to save journal");
+            app.show_error_toast(format!("Échec de la sauvegarde du journal : {}", e));
+        }
+    }
+}
+
+/// Enregistre une règle d'alerte saisie et sauvegarde alerts.json
+///
+/// Format : "condition [once | rearm 3%]" ou "condition [...] | https://webhook"
+fn submit_alert(app: &mut App, symbol: &str, input: &str, command_tx: &QueueSender<AppCommand>) {
+    let (expression, webhook) = match input.split_once('|') {
+        Some((expression, url)) => (expression, Some(url.trim().to_string())),
+        None => (input, None),
+    };
+
+    let (expression, recurrence) = match Recurrence::split_input(expression) {
+        Ok(split) => split,
+        Err(e) => {
+            app.show_error_toast(e.to_string());
+            return;
+        }
+    };
+
+    if let Some(url) = &webhook {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            app.show_error_toast(format!("URL de webhook invalide : \"{}\"", url));
+            return;
+        }
+    }
+
+    let condition = match Condition::parse(expression) {
+        Ok(condition) => condition,
+        Err(e) => {
+            app.show_error_toast(e.to_string());
+            return;
+        }
+    };
+
+    info!(ticker = %symbol, condition = %condition, recurrence = %recurrence, webhook = ?webhook, "User added alert rule");
+    let label = match recurrence {
+        Recurrence::EveryTime => condition.to_string(),
+        _ => format!("{} ({})", condition, recurrence),
+    };
+    app.add_alert_rule(symbol, condition, recurrence, webhook);
+
+    match app.alert_book.save(&AlertBook::default_path()) {
+        Ok(()) => app.show_toast(format!("Alerte {} : {}", symbol, label)),
+        Err(e) => {
+            error!(error = ?e, "Failed to save alerts");
+            app.show_error_toast(format!("Échec de la sauvegarde des alertes : {}", e));
+        }
+    }
+
+    let triggers = app.evaluate_alerts();
+    dispatch_alert_triggers(&triggers, command_tx);
+}
+
+/// Affiche l'échec d'un ajout de ticker
+///
+/// Message de Yahoo tel quel (symbole inconnu ou délisté, ...)
+fn report_add_error(app: &mut App, symbol: &str, error: &str) {
+    app.show_error_toast(format!("Impossible d'ajouter {} : {}", symbol, error));
+}
+
+/// Sauvegarde alerts.json si des règles ont changé (règle "once" désactivée,
+/// snooze)
+fn save_alert_book_changes(app: &mut App, path: &std::path::Path) {
+    if !app.take_alert_book_changes() {
+        return;
+    }
+    if let Err(e) = app.alert_book.save(path) {
+        error!(error = ?e, "Failed to save alerts");
+        app.show_error_toast(format!("Échec de la sauvegarde des alertes : {}", e));
+    }
+}
+
+/// Réessaie la sauvegarde du journal et du portefeuille restée en échec
+/// (à la fermeture)
+fn retry_unsaved_portfolio(app: &mut App, journal_path: &std::path::Path, portfolio_path: &std::path::Path) {
+    if !app.unsaved_portfolio {
+        return;
+    }
+    let saved = app
+        .journal
+        .save(journal_path)
+        .and_then(|_| app.portfolio.save(portfolio_path));
+    match saved {
+        Ok(()) => {
+            info!("Journal and portfolio saved on exit");
+            app.unsaved_portfolio = false;
+        }
+        Err(e) => error!(error = ?e, "Failed to save journal and portfolio on exit"),
+    }
+}
+
+/// Journalise les alertes déclenchées et demande l'appel de leurs webhooks
+/// et l'exécution de leurs commandes
+fn dispatch_alert_triggers(triggers: &[AlertTrigger], command_tx: &QueueSender<AppCommand>) {
+    for trigger in triggers {
+        info!(ticker = %trigger.symbol, condition = %trigger.condition, price = trigger.price, "Alert triggered");
+        if let Some(url) = &trigger.webhook {
+            let _ = command_tx.send(AppCommand::SendWebhook {
+                url: url.clone(),
+                trigger: trigger.clone(),
+            });
+        }
+        if let Some(template) = &trigger.command {
+            let _ = command_tx.send(AppCommand::RunAlertCommand {
+                template: template.clone(),
+                trigger: trigger.clone(),
+            });
+        }
+    }
+}
+
+/// Demande le chargement du graphique secondaire si la vue multi-timeframe est active
+///
+/// CONCEPT : Cache par intervalle
+/// - Ne fetch que si l'intervalle n'est pas déjà en cache pour ce ticker
+fn request_secondary_data(app: &App, command_tx: &QueueSender<AppCommand>) {
+    if !app.split_view {
+        return;
+    }
+
+    if let Some(item) = app.selected_item() {
+        if item.data_for(app.secondary_interval).is_none() {
+            let _ = command_tx.send(AppCommand::LoadIntervalData {
+                symbol: item.symbol.clone(),
+                interval: app.secondary_interval,
+            });
+        }
+    }
+}
+
+/// Demande l'historique D1 des tickers qui ne l'ont pas encore
+///
+/// Rendements et classement se calculent sur les clôtures journalières
+/// - priority: User pour une vue ouverte, Background pour un préchargement
+fn request_daily_data(app: &App, command_tx: &QueueSender<AppCommand>, priority: Priority) {
+    for item in app.watchlist.iter().filter(|item| item.data_for(Interval::D1).is_none()) {
+        let _ = command_tx.send_with_priority(priority, AppCommand::LoadIntervalData {
+            symbol: item.symbol.clone(),
+            interval: Interval::D1,
+        });
+    }
+}
+
+/// Demande au worker le rechargement d'un ticker (résultat adressé par symbole)
+fn request_reload(app: &mut App, command_tx: &QueueSender<AppCommand>, symbol: String, interval: Interval) {
+    let request_id = app.begin_load(&symbol);
+    let _ = command_tx.send(AppCommand::ReloadTickerData { symbol, interval, request_id, silent: false });
+}
+
+/// Demande les historiques D1 manquants pour la colonne de force relative
+///
+/// CONCEPT : Indices dédoublonnés
+/// - Plusieurs tickers partagent souvent le même indice : un seul chargement
+/// - Un indice déjà dans la watchlist est couvert par request_daily_data()
+fn request_relative_strength_data(app: &App, command_tx: &QueueSender<AppCommand>) {
+    request_daily_data(app, command_tx, Priority::Background);
+
+    let benchmarks: BTreeSet<&str> = app
+        .watchlist
+        .iter()
+        .map(|item| app.benchmark_symbol(&item.symbol))
+        .filter(|benchmark| !app.watchlist.iter().any(|item| item.symbol == *benchmark))
+        .filter(|benchmark| app.benchmark_data(benchmark, Interval::D1).is_none())
+        .collect();
+    for benchmark in benchmarks {
+        let _ = command_tx.send_with_priority(Priority::Background, AppCommand::LoadBenchmark {
+            symbol: benchmark.to_string(),
+            interval: Interval::D1,
+        });
+    }
+}
+
+/// Demande le chargement de l'indice de référence du graphique s'il manque
+fn request_benchmark_data(app: &App, command_tx: &QueueSender<AppCommand>) {
+    if let Some((symbol, interval)) = app.missing_benchmark() {
+        let _ = command_tx.send(AppCommand::LoadBenchmark { symbol, interval });
+    }
+}
+
+/// Demande au worker les résultats du screener affiché
+fn request_screener(app: &mut App, command_tx: &QueueSender<AppCommand>) {
+    app.start_loading(Some(format!("Chargement du screener {}...", app.screener.label())));
+    let _ = command_tx.send(AppCommand::LoadScreener { screener: app.screener });
+}
+
 // ============================================================================
 // Setup et restauration du terminal
 // ============================================================================
@@ -802,6 +3323,50 @@ fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
     Terminal::new(backend).map_err(|e| e.into())
 }
 
+/// Configuration guidée du premier lancement (voir onboarding.rs)
+///
+/// Le fichier est écrit même après Échap (configuration par défaut) : la
+/// question n'est posée qu'une fois
+/// Retourne None après Ctrl+C (rien n'est écrit, l'application se ferme)
+fn run_onboarding(config_path: &std::path::Path) -> Result<Option<Config>> {
+    use lazywallet::ui::events::{
+        is_backspace_event, is_down_event, is_enter_event, is_escape_event, is_interrupt_event, is_space_event, is_up_event,
+    };
+
+    let mut terminal = setup_terminal()?;
+    let events = EventHandler::default();
+    let mut onboarding = Onboarding::default();
+
+    let config = loop {
+        terminal.draw(|frame| render_onboarding(frame, &onboarding, frame.size()))?;
+
+        let event = events.next()?;
+        if is_interrupt_event(&event) {
+            restore_terminal(&mut terminal)?;
+            return Ok(None);
+        } else if is_escape_event(&event) {
+            break Config::default();
+        } else if is_up_event(&event) {
+            onboarding.move_cursor(false);
+        } else if is_down_event(&event) {
+            onboarding.move_cursor(true);
+        } else if is_space_event(&event) {
+            onboarding.toggle();
+        } else if is_backspace_event(&event) {
+            onboarding.go_back();
+        } else if is_enter_event(&event) && onboarding.advance() {
+            break onboarding.into_config();
+        }
+    };
+    restore_terminal(&mut terminal)?;
+
+    match config.save(config_path) {
+        Ok(()) => info!(path = %config_path.display(), watchlist = config.watchlist.len(), "Initial config written"),
+        Err(e) => warn!(path = %config_path.display(), error = ?e, "Failed to write initial config"),
+    }
+    Ok(Some(config))
+}
+
 /// Restaure le terminal à son état normal
 ///
 /// CONCEPT : Cleanup et RAII
@@ -862,3 +3427,63 @@ fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Re
 // - Rafraîchissement automatique
 //
 // ============================================================================
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lazywallet::alerts::AlertRule;
+    use lazywallet::api::yahoo::ChartError;
+
+    #[test]
+    fn test_add_error_shows_yahoo_description() {
+        let mut app = App::with_watchlist(Vec::new());
+
+        // Erreur telle que le worker la transmet (anyhow → texte)
+        let error = anyhow::Error::from(ChartError::Api {
+            code: "Not Found".to_string(),
+            description: "No data found, symbol may be delisted".to_string(),
+        });
+        report_add_error(&mut app, "XYZ", &error.to_string());
+
+        let toast = app.active_toast().unwrap();
+        assert!(toast.is_error);
+        assert_eq!(
+            toast.message,
+            "Impossible d'ajouter XYZ : Yahoo Finance : No data found, symbol may be delisted (Not Found)"
+        );
+    }
+
+    #[test]
+    fn test_shutdown_flushes_pending_changes() {
+        let dir = std::env::temp_dir().join(format!("lazywallet-shutdown-{}", std::process::id()));
+        let alerts_path = dir.join("alerts.json");
+        let journal_path = dir.join("journal.json");
+        let portfolio_path = dir.join("portfolio.json");
+
+        // Règle "once" désactivée et portefeuille dont la sauvegarde a échoué
+        let mut app = App::with_watchlist(Vec::new());
+        app.alert_book.rules.push(AlertRule::new("AAPL", Condition::parse("price > 140").unwrap()));
+        app.alert_book_dirty = true;
+        app.portfolio.set_position("AAPL", 10.0);
+        app.unsaved_portfolio = true;
+
+        save_alert_book_changes(&mut app, &alerts_path);
+        retry_unsaved_portfolio(&mut app, &journal_path, &portfolio_path);
+
+        assert_eq!(AlertBook::load(&alerts_path).unwrap().rules.len(), 1);
+        assert_eq!(Portfolio::load(&portfolio_path).unwrap().position("AAPL").unwrap().quantity, 10.0);
+        assert!(journal_path.exists());
+        assert!(!app.take_alert_book_changes());
+        assert!(!app.unsaved_portfolio);
+
+        // Plus rien en attente : les fichiers ne sont pas réécrits
+        std::fs::remove_dir_all(&dir).unwrap();
+        save_alert_book_changes(&mut app, &alerts_path);
+        retry_unsaved_portfolio(&mut app, &journal_path, &portfolio_path);
+        assert!(!dir.exists());
+    }
+}
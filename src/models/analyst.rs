@@ -0,0 +1,96 @@
+// ============================================================================
+// Structure : AnalystRatings
+// ============================================================================
+// Recommandations des analystes et objectif de cours d'une action
+// (quoteSummary de Yahoo, modules recommendationTrend et financialData) :
+//
+//   Analystes   Achat · 12 achat fort · 20 achat · 8 conserver · 1 vente (41)
+//   Objectif    $250.00 (180.00 – 300.00) · +12.3% vs cours
+//
+// - Répartition du mois en cours (période "0m" de recommendationTrend)
+// - Objectif : moyenne des objectifs publiés, avec le plus bas et le plus haut
+// ============================================================================
+
+/// Recommandations et objectif de cours d'une action
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnalystRatings {
+    pub strong_buy: u32,
+    pub buy: u32,
+    pub hold: u32,
+    pub sell: u32,
+    pub strong_sell: u32,
+
+    /// Consensus de Yahoo ("strong_buy", "buy", "hold", "underperform", "sell")
+    pub recommendation: Option<String>,
+
+    /// Objectif de cours moyen (devise de cotation)
+    pub target_mean: Option<f64>,
+    pub target_low: Option<f64>,
+    pub target_high: Option<f64>,
+
+    /// Nombre d'analystes ayant publié un objectif
+    pub analysts: Option<u32>,
+}
+
+impl AnalystRatings {
+    /// Nombre total de recommandations du mois
+    pub fn total(&self) -> u32 {
+        self.strong_buy + self.buy + self.hold + self.sell + self.strong_sell
+    }
+
+    /// Indique si Yahoo n'a ni recommandation ni objectif (action non suivie)
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0 && self.target_mean.is_none()
+    }
+
+    /// Potentiel de l'objectif moyen vs le cours, en % (négatif : baisse)
+    pub fn upside_percent(&self, price: f64) -> Option<f64> {
+        let target = self.target_mean?;
+        (price > 0.0).then(|| (target / price - 1.0) * 100.0)
+    }
+
+    /// Libellé du consensus ("Achat fort", "Conserver", ...)
+    pub fn consensus_label(&self) -> Option<&'static str> {
+        let label = match self.recommendation.as_deref()? {
+            "strong_buy" => "Achat fort",
+            "buy" => "Achat",
+            "hold" => "Conserver",
+            "underperform" => "Sous-performance",
+            "sell" => "Vente",
+            "strong_sell" => "Vente forte",
+            _ => return None,
+        };
+        Some(label)
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upside_and_consensus() {
+        let ratings = AnalystRatings {
+            strong_buy: 12,
+            buy: 20,
+            hold: 8,
+            sell: 1,
+            recommendation: Some("buy".to_string()),
+            target_mean: Some(250.0),
+            ..AnalystRatings::default()
+        };
+        assert_eq!(ratings.total(), 41);
+        assert!(!ratings.is_empty());
+        assert_eq!(ratings.consensus_label(), Some("Achat"));
+        assert!((ratings.upside_percent(200.0).unwrap() - 25.0).abs() < 1e-9);
+        assert!((ratings.upside_percent(312.5).unwrap() + 20.0).abs() < 1e-9);
+        assert_eq!(ratings.upside_percent(0.0), None);
+
+        assert!(AnalystRatings::default().is_empty());
+        assert_eq!(AnalystRatings::default().upside_percent(100.0), None);
+    }
+}
@@ -0,0 +1,316 @@
+// ============================================================================
+// Structure : Annotations du graphique
+// ============================================================================
+// Courts textes attachés à un chandelier ("cassure des 180", "résultats
+// T3"), posés avec le curseur de l'outil de mesure, marqués d'un ◆ au-dessus
+// du chandelier et listés dans un panneau à droite du graphique
+//
+// Droites de tendance tracées entre l'ancre et le curseur de l'outil de
+// mesure (clôtures des deux chandeliers), prolongées vers la droite
+//
+// Fichier : ~/.config/lazywallet/annotations.json
+// {
+//   "annotations": {
+//     "AAPL": [ { "timestamp": "2024-01-05T14:30:00Z", "text": "Cassure des 180" } ]
+//   },
+//   "trend_lines": {
+//     "AAPL": [ { "start": "2024-01-02T00:00:00Z", "start_price": 185.6,
+//                 "end": "2024-02-01T00:00:00Z", "end_price": 186.9 } ]
+//   }
+// }
+//
+// CONCEPT : Ancrage sur l'heure, pas sur l'index
+// - L'annotation garde l'heure d'ouverture du chandelier annoté : elle
+//   survit au rechargement des données et au changement d'intervalle
+// - À un autre intervalle, elle se place sur le chandelier qui contient
+//   cette heure (une annotation 1h apparaît sur le chandelier 1d du jour)
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Interval, OHLC};
+use crate::profile;
+
+/// Longueur maximale d'une annotation (en caractères)
+pub const MAX_ANNOTATION_LENGTH: usize = 80;
+
+/// Un texte attaché à un chandelier
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    /// Heure d'ouverture du chandelier annoté
+    pub timestamp: DateTime<Utc>,
+
+    /// Texte de l'annotation
+    pub text: String,
+}
+
+/// Une droite de tendance entre deux chandeliers (start < end)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrendLine {
+    /// Heure d'ouverture du premier chandelier
+    pub start: DateTime<Utc>,
+    pub start_price: f64,
+
+    /// Heure d'ouverture du second chandelier
+    pub end: DateTime<Utc>,
+    pub end_price: f64,
+}
+
+/// Droite de tendance placée sur une série (index dans la série complète)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnchoredLine {
+    pub start: usize,
+    pub start_price: f64,
+    pub end: usize,
+    pub end_price: f64,
+}
+
+impl TrendLine {
+    /// Droite entre deux instants, remise dans l'ordre chronologique
+    pub fn new(a: (DateTime<Utc>, f64), b: (DateTime<Utc>, f64)) -> Self {
+        let ((start, start_price), (end, end_price)) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+        Self { start, start_price, end, end_price }
+    }
+
+    /// Place la droite sur une série
+    ///
+    /// None si un des deux chandeliers est hors de la série, ou si les deux
+    /// tombent dans le même chandelier (droite 1h vue en 1w)
+    pub fn anchor(&self, candles: &[OHLC], interval: Interval) -> Option<AnchoredLine> {
+        let start = candle_index(candles, interval, self.start)?;
+        let end = candle_index(candles, interval, self.end)?;
+        (start < end).then_some(AnchoredLine {
+            start,
+            start_price: self.start_price,
+            end,
+            end_price: self.end_price,
+        })
+    }
+}
+
+impl AnchoredLine {
+    /// Prix de la droite au chandelier `index` (prolongée au-delà des deux points)
+    ///
+    /// CONCEPT : Pente en barres, pas en temps
+    /// - Comme sur les plateformes de trading, la droite avance d'un pas par
+    ///   chandelier : les nuits et week-ends sans cotation ne la déforment pas
+    pub fn price_at(&self, index: usize) -> f64 {
+        let slope = (self.end_price - self.start_price) / (self.end - self.start) as f64;
+        self.start_price + slope * (index as f64 - self.start as f64)
+    }
+}
+
+/// Annotations et droites de tendance de chaque symbole (triées par date)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Annotations {
+    #[serde(default)]
+    pub annotations: BTreeMap<String, Vec<Annotation>>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub trend_lines: BTreeMap<String, Vec<TrendLine>>,
+}
+
+/// Index du chandelier qui contient `timestamp` (None hors de la série)
+///
+/// Un chandelier couvre [ouverture, ouverture + intervalle[
+pub fn candle_index(candles: &[OHLC], interval: Interval, timestamp: DateTime<Utc>) -> Option<usize> {
+    let index = candles.partition_point(|candle| candle.timestamp <= timestamp).checked_sub(1)?;
+    let end = candles[index].timestamp + Duration::minutes(interval.minutes());
+    (timestamp < end).then_some(index)
+}
+
+impl Annotations {
+    /// Chemin par défaut du fichier d'annotations
+    ///
+    /// - Linux : ~/.config/lazywallet/annotations.json
+    /// - Profil : ~/.config/lazywallet/profiles/<nom>/annotations.json
+    /// - Fallback : ./annotations.json
+    pub fn default_path() -> PathBuf {
+        profile::config_dir()
+            .map(|dir| dir.join("annotations.json"))
+            .unwrap_or_else(|| PathBuf::from("./annotations.json"))
+    }
+
+    /// Charge les annotations depuis un fichier JSON (fichier absent = aucune)
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Échec de la lecture de {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Format invalide dans {}", path.display()))
+    }
+
+    /// Sauvegarde les annotations en JSON (crée le répertoire si besoin)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Échec de l'écriture de {}", path.display()))
+    }
+
+    /// Annotations d'un symbole, de la plus ancienne à la plus récente
+    pub fn of(&self, symbol: &str) -> &[Annotation] {
+        self.annotations.get(symbol).map_or(&[], Vec::as_slice)
+    }
+
+    /// Première annotation d'un symbole comprise dans [start, end[
+    pub fn within(&self, symbol: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Option<&Annotation> {
+        self.of(symbol)
+            .iter()
+            .find(|annotation| annotation.timestamp >= start && annotation.timestamp < end)
+    }
+
+    /// Remplace l'annotation d'un symbole à une heure donnée
+    ///
+    /// - Texte vide : annotation supprimée
+    /// - Texte tronqué à MAX_ANNOTATION_LENGTH caractères
+    pub fn set(&mut self, symbol: &str, timestamp: DateTime<Utc>, text: &str) {
+        let text: String = text.trim().chars().take(MAX_ANNOTATION_LENGTH).collect();
+        let list = self.annotations.entry(symbol.to_string()).or_default();
+        list.retain(|annotation| annotation.timestamp != timestamp);
+
+        if !text.is_empty() {
+            let index = list.partition_point(|annotation| annotation.timestamp < timestamp);
+            list.insert(index, Annotation { timestamp, text });
+        }
+        if list.is_empty() {
+            self.annotations.remove(symbol);
+        }
+    }
+
+    /// Droites de tendance d'un symbole
+    pub fn trend_lines(&self, symbol: &str) -> &[TrendLine] {
+        self.trend_lines.get(symbol).map_or(&[], Vec::as_slice)
+    }
+
+    /// Ajoute une droite, ou la retire si elle relie déjà ces deux instants
+    ///
+    /// Retourne true si la droite a été ajoutée
+    pub fn toggle_trend_line(&mut self, symbol: &str, line: TrendLine) -> bool {
+        let list = self.trend_lines.entry(symbol.to_string()).or_default();
+        let before = list.len();
+        list.retain(|existing| (existing.start, existing.end) != (line.start, line.end));
+
+        let added = list.len() == before;
+        if added {
+            list.push(line);
+        }
+        if list.is_empty() {
+            self.trend_lines.remove(symbol);
+        }
+        added
+    }
+
+    /// Retire les droites d'un symbole dont une extrémité est dans [start, end[
+    ///
+    /// Retourne le nombre de droites retirées
+    pub fn remove_trend_lines_at(&mut self, symbol: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> usize {
+        let Some(list) = self.trend_lines.get_mut(symbol) else {
+            return 0;
+        };
+        let before = list.len();
+        let touches = |timestamp: DateTime<Utc>| timestamp >= start && timestamp < end;
+        list.retain(|line| !touches(line.start) && !touches(line.end));
+
+        let removed = before - list.len();
+        if list.is_empty() {
+            self.trend_lines.remove(symbol);
+        }
+        removed
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_set_and_clear_annotations() {
+        let mut annotations = Annotations::default();
+        annotations.set("AAPL", at(5, 14), "  Cassure des 180  ");
+        annotations.set("AAPL", at(2, 14), "Résultats");
+        annotations.set("AAPL", at(5, 14), "Cassure confirmée");
+
+        let texts: Vec<&str> = annotations.of("AAPL").iter().map(|a| a.text.as_str()).collect();
+        assert_eq!(texts, vec!["Résultats", "Cassure confirmée"]);
+        assert_eq!(annotations.within("AAPL", at(5, 0), at(6, 0)).unwrap().text, "Cassure confirmée");
+        assert!(annotations.within("AAPL", at(3, 0), at(5, 0)).is_none());
+
+        annotations.set("MSFT", at(1, 0), &"x".repeat(200));
+        assert_eq!(annotations.of("MSFT")[0].text.len(), MAX_ANNOTATION_LENGTH);
+
+        annotations.set("AAPL", at(2, 14), " ");
+        annotations.set("AAPL", at(5, 14), "");
+        assert!(annotations.of("AAPL").is_empty());
+        assert!(!annotations.annotations.contains_key("AAPL"));
+    }
+
+    #[test]
+    fn test_candle_index() {
+        let candles: Vec<OHLC> = [1, 2, 3, 4]
+            .iter()
+            .map(|day| OHLC::new(at(*day, 0), 1.0, 1.0, 1.0, 1.0, 0))
+            .collect();
+
+        assert_eq!(candle_index(&candles, Interval::D1, at(1, 0)), Some(0));
+        // Annotation posée en 1h : sur le chandelier 1d du jour
+        assert_eq!(candle_index(&candles, Interval::D1, at(3, 15)), Some(2));
+        assert_eq!(candle_index(&candles, Interval::D1, at(4, 23)), Some(3));
+        assert_eq!(candle_index(&candles, Interval::D1, at(5, 1)), None);
+        assert_eq!(candle_index(&candles, Interval::H1, at(2, 3)), None);
+        assert_eq!(candle_index(&[], Interval::D1, at(2, 3)), None);
+    }
+
+    #[test]
+    fn test_trend_lines() {
+        let candles: Vec<OHLC> = (1..=10)
+            .map(|day| OHLC::new(at(day, 0), 1.0, 1.0, 1.0, 1.0, 0))
+            .collect();
+
+        // Points donnés dans le désordre : remis dans l'ordre
+        let line = TrendLine::new((at(5, 0), 120.0), (at(3, 0), 100.0));
+        assert_eq!((line.start, line.end), (at(3, 0), at(5, 0)));
+
+        // Pente de 10 par chandelier, prolongée au-delà des deux points
+        let anchored = line.anchor(&candles, Interval::D1).unwrap();
+        assert_eq!((anchored.start, anchored.end), (2, 4));
+        assert!((anchored.price_at(4) - 120.0).abs() < 1e-9);
+        assert!((anchored.price_at(9) - 170.0).abs() < 1e-9);
+
+        // Les deux points dans le même chandelier : pas de droite
+        let same_day = TrendLine::new((at(3, 1), 100.0), (at(3, 9), 120.0));
+        assert!(same_day.anchor(&candles, Interval::D1).is_none());
+
+        let mut annotations = Annotations::default();
+        assert!(annotations.toggle_trend_line("AAPL", line));
+        assert!(annotations.toggle_trend_line("AAPL", TrendLine::new((at(1, 0), 90.0), (at(2, 0), 95.0))));
+        assert_eq!(annotations.trend_lines("AAPL").len(), 2);
+        // Même paire de chandeliers : retirée
+        assert!(!annotations.toggle_trend_line("AAPL", line));
+        assert_eq!(annotations.trend_lines("AAPL").len(), 1);
+
+        assert_eq!(annotations.remove_trend_lines_at("AAPL", at(2, 0), at(3, 0)), 1);
+        assert!(annotations.trend_lines.is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+// ============================================================================
+// Bêta et corrélation vs un indice de référence
+// ============================================================================
+// Mesure à quel point un ticker suit son indice (config "benchmark" /
+// "benchmarks"), à partir des rendements journaliers des clôtures D1 :
+//
+//   Bêta 1.24 · corrélation 0.81 vs SPY (252 séances)
+//
+// CONCEPT : Bêta
+// - bêta = covariance(ticker, indice) / variance(indice)
+// - 1.0 : bouge comme l'indice ; 2.0 : deux fois plus ; < 0 : à l'inverse
+//
+// CONCEPT : Corrélation (Pearson)
+// - covariance / (écart-type ticker × écart-type indice), entre -1 et 1
+// - Proche de 1 : le bêta est représentatif ; proche de 0 : l'indice
+//   explique peu les mouvements du ticker
+//
+// CONCEPT : Alignement des séances
+// - Seules les dates de séance communes aux deux séries comptent (places
+//   aux jours fériés différents, AIR.PA vs SPY par exemple)
+// - Rendement = clôture / clôture de la date commune précédente - 1
+// - Une année de séances au plus ; trop peu de points : pas de valeur
+// ============================================================================
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+
+use crate::models::schedule;
+use crate::models::OHLCData;
+
+/// Nombre maximal de rendements pris en compte (une année de séances)
+pub const BETA_WINDOW: usize = 252;
+
+/// Nombre minimal de rendements pour une estimation
+const MIN_SAMPLES: usize = 20;
+
+/// Bêta et corrélation d'un ticker vs un indice
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BetaStats {
+    pub beta: f64,
+    pub correlation: f64,
+
+    /// Nombre de rendements journaliers utilisés
+    pub samples: usize,
+}
+
+/// Clôtures indexées par date de séance
+fn closes_by_date(data: &OHLCData) -> Vec<(NaiveDate, f64)> {
+    data.candles
+        .iter()
+        .map(|candle| (schedule::session_date(&data.symbol, candle.timestamp), candle.close))
+        .collect()
+}
+
+/// Rendements journaliers appariés (ticker, indice) sur les dates communes
+fn paired_returns(ticker: &OHLCData, benchmark: &OHLCData) -> Vec<(f64, f64)> {
+    let benchmark: HashMap<NaiveDate, f64> = closes_by_date(benchmark).into_iter().collect();
+    let common: Vec<(f64, f64)> = closes_by_date(ticker)
+        .into_iter()
+        .filter_map(|(date, close)| benchmark.get(&date).map(|bench| (close, *bench)))
+        .collect();
+
+    common
+        .windows(2)
+        .filter(|pair| pair[0].0 != 0.0 && pair[0].1 != 0.0)
+        .map(|pair| (pair[1].0 / pair[0].0 - 1.0, pair[1].1 / pair[0].1 - 1.0))
+        .collect()
+}
+
+/// Calcule le bêta et la corrélation à partir des séries D1
+///
+/// None si moins de MIN_SAMPLES rendements communs, ou indice sans variation
+pub fn beta_stats(ticker: &OHLCData, benchmark: &OHLCData) -> Option<BetaStats> {
+    let returns = paired_returns(ticker, benchmark);
+    let returns = &returns[returns.len().saturating_sub(BETA_WINDOW)..];
+    if returns.len() < MIN_SAMPLES {
+        return None;
+    }
+
+    let n = returns.len() as f64;
+    let mean_ticker = returns.iter().map(|(ticker, _)| ticker).sum::<f64>() / n;
+    let mean_bench = returns.iter().map(|(_, bench)| bench).sum::<f64>() / n;
+
+    let (mut covariance, mut var_ticker, mut var_bench) = (0.0, 0.0, 0.0);
+    for (ticker, bench) in returns {
+        covariance += (ticker - mean_ticker) * (bench - mean_bench);
+        var_ticker += (ticker - mean_ticker).powi(2);
+        var_bench += (bench - mean_bench).powi(2);
+    }
+    if var_bench == 0.0 {
+        return None;
+    }
+
+    let correlation = if var_ticker > 0.0 { covariance / (var_ticker * var_bench).sqrt() } else { 0.0 };
+    Some(BetaStats {
+        beta: covariance / var_bench,
+        correlation,
+        samples: returns.len(),
+    })
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interval, OHLC};
+    use chrono::{Duration, TimeZone, Utc};
+
+    /// Série D1 à partir de rendements journaliers (clôtures à 21:00 UTC)
+    fn series(symbol: &str, returns: &[f64], skip: Option<usize>) -> OHLCData {
+        let start = Utc.with_ymd_and_hms(2024, 1, 2, 21, 0, 0).unwrap();
+        let mut data = OHLCData::with_interval(symbol.to_string(), Interval::D1);
+        let mut close = 100.0;
+        for (i, r) in std::iter::once(&0.0).chain(returns).enumerate() {
+            close *= 1.0 + r;
+            if Some(i) != skip {
+                data.add_candle(OHLC::new(start + Duration::days(i as i64), close, close, close, close, 0));
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_beta_stats() {
+        let market: Vec<f64> = (0..60).map(|i| ((i as f64) * 0.7).sin() * 0.01).collect();
+        let bench = series("SPY", &market, None);
+
+        // Deux fois les mouvements de l'indice : bêta 2, corrélation 1
+        let doubled: Vec<f64> = market.iter().map(|r| r * 2.0).collect();
+        let stats = beta_stats(&series("TQQQ", &doubled, None), &bench).unwrap();
+        assert!((stats.beta - 2.0).abs() < 1e-9, "{:?}", stats);
+        assert!((stats.correlation - 1.0).abs() < 1e-9, "{:?}", stats);
+        assert_eq!(stats.samples, 60);
+
+        // À l'inverse de l'indice
+        let inverse: Vec<f64> = market.iter().map(|r| -r).collect();
+        let stats = beta_stats(&series("SH", &inverse, None), &bench).unwrap();
+        assert!((stats.beta + 1.0).abs() < 1e-9 && (stats.correlation + 1.0).abs() < 1e-9);
+
+        // Une séance absente du ticker : date ignorée dans l'indice aussi
+        let stats = beta_stats(&series("TQQQ", &doubled, Some(10)), &bench).unwrap();
+        assert_eq!(stats.samples, 59);
+        assert!(stats.correlation > 0.99);
+
+        // Historique trop court
+        assert!(beta_stats(&series("NEW", &doubled[..5], None), &bench).is_none());
+    }
+}
@@ -0,0 +1,240 @@
+// ============================================================================
+// Prix de revient et plus/moins-values (P&L)
+// ============================================================================
+// Rejoue le journal de transactions d'un ticker pour calculer :
+// - la quantité restante et son prix de revient
+// - le P&L réalisé (ventes déjà effectuées)
+// - le P&L latent (quantité restante valorisée au prix actuel)
+//
+// Deux méthodes de calcul (choisies dans la configuration) :
+//
+// FIFO (First In, First Out) : une vente consomme les lots les plus anciens
+//   Achat 10 @ 100, Achat 10 @ 120, Vente 10 @ 130
+//   → réalisé = 10 × (130 - 100) = +300, reste 10 @ 120
+//
+// Coût moyen pondéré : un seul prix de revient moyen, recalculé à chaque achat
+//   Mêmes transactions → moyen = 110, réalisé = 10 × (130 - 110) = +200,
+//   reste 10 @ 110
+// ============================================================================
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::journal::{Journal, Transaction, TransactionKind};
+
+/// Méthode de calcul du prix de revient
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CostBasisMethod {
+    /// Premier entré, premier sorti
+    #[default]
+    Fifo,
+
+    /// Coût moyen pondéré
+    AverageCost,
+}
+
+impl CostBasisMethod {
+    /// Libellé court affiché dans l'interface
+    pub fn label(&self) -> &'static str {
+        match self {
+            CostBasisMethod::Fifo => "FIFO",
+            CostBasisMethod::AverageCost => "Coût moyen",
+        }
+    }
+}
+
+/// P&L d'une position
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionPnl {
+    pub symbol: String,
+
+    /// Quantité restante
+    pub quantity: f64,
+
+    /// Prix de revient total de la quantité restante
+    pub cost_basis: f64,
+
+    /// Plus/moins-value réalisée par les ventes
+    pub realized: f64,
+
+    /// Plus/moins-value latente (None sans prix actuel)
+    pub unrealized: Option<f64>,
+}
+
+impl PositionPnl {
+    /// Prix de revient unitaire de la quantité restante
+    pub fn average_cost(&self) -> Option<f64> {
+        (self.quantity > 0.0).then(|| self.cost_basis / self.quantity)
+    }
+}
+
+/// P&L agrégé sur toutes les positions
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PnlTotals {
+    pub realized: f64,
+    pub unrealized: f64,
+}
+
+/// Rejoue les transactions d'un ticker (ordre chronologique) avec la méthode choisie
+///
+/// Une vente supérieure à la quantité détenue n'est comptée que sur la quantité
+/// détenue (pas de vente à découvert)
+pub fn position_pnl<'a>(
+    symbol: &str,
+    transactions: impl IntoIterator<Item = &'a Transaction>,
+    method: CostBasisMethod,
+    current_price: Option<f64>,
+) -> PositionPnl {
+    let (quantity, cost_basis, realized) = match method {
+        CostBasisMethod::Fifo => replay_fifo(transactions),
+        CostBasisMethod::AverageCost => replay_average(transactions),
+    };
+
+    PositionPnl {
+        symbol: symbol.to_string(),
+        quantity,
+        cost_basis,
+        realized,
+        unrealized: current_price.map(|price| quantity * price - cost_basis),
+    }
+}
+
+/// FIFO : file de lots (quantité, prix), les ventes consomment le début de la file
+///
+/// Retourne (quantité restante, prix de revient restant, réalisé)
+fn replay_fifo<'a>(transactions: impl IntoIterator<Item = &'a Transaction>) -> (f64, f64, f64) {
+    let mut lots: VecDeque<(f64, f64)> = VecDeque::new();
+    let mut realized = 0.0;
+
+    for transaction in transactions {
+        match transaction.kind {
+            TransactionKind::Buy => lots.push_back((transaction.quantity, transaction.price)),
+            TransactionKind::Sell => {
+                let mut remaining = transaction.quantity;
+                while remaining > 0.0 {
+                    let Some(lot) = lots.front_mut() else { break };
+                    let consumed = remaining.min(lot.0);
+                    realized += consumed * (transaction.price - lot.1);
+                    lot.0 -= consumed;
+                    remaining -= consumed;
+                    if lot.0 <= f64::EPSILON {
+                        lots.pop_front();
+                    }
+                }
+            }
+        }
+    }
+
+    let quantity = lots.iter().map(|(q, _)| q).sum();
+    let cost_basis = lots.iter().map(|(q, p)| q * p).sum();
+    (quantity, cost_basis, realized)
+}
+
+/// Coût moyen : un seul prix moyen, inchangé par les ventes
+///
+/// Retourne (quantité restante, prix de revient restant, réalisé)
+fn replay_average<'a>(transactions: impl IntoIterator<Item = &'a Transaction>) -> (f64, f64, f64) {
+    let mut quantity = 0.0;
+    let mut average = 0.0;
+    let mut realized = 0.0;
+
+    for transaction in transactions {
+        match transaction.kind {
+            TransactionKind::Buy => {
+                let total = quantity + transaction.quantity;
+                average = (average * quantity + transaction.price * transaction.quantity) / total;
+                quantity = total;
+            }
+            TransactionKind::Sell => {
+                let sold = transaction.quantity.min(quantity);
+                realized += sold * (transaction.price - average);
+                quantity -= sold;
+            }
+        }
+    }
+
+    (quantity, quantity * average, realized)
+}
+
+impl Journal {
+    /// P&L de chaque ticker du journal
+    ///
+    /// # Arguments
+    /// * `price_of` - Prix actuel d'un symbole (None si non coté)
+    pub fn pnl(&self, method: CostBasisMethod, price_of: impl Fn(&str) -> Option<f64>) -> Vec<PositionPnl> {
+        self.symbols()
+            .into_iter()
+            .map(|symbol| position_pnl(symbol, self.for_symbol(symbol), method, price_of(symbol)))
+            .collect()
+    }
+}
+
+/// Agrège le P&L de plusieurs positions (les latents inconnus comptent pour 0)
+pub fn pnl_totals(positions: &[PositionPnl]) -> PnlTotals {
+    positions.iter().fold(PnlTotals::default(), |totals, p| PnlTotals {
+        realized: totals.realized + p.realized,
+        unrealized: totals.unrealized + p.unrealized.unwrap_or(0.0),
+    })
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn journal(inputs: &[&str]) -> Journal {
+        let mut journal = Journal::default();
+        for input in inputs {
+            journal.transactions.push(Transaction::parse_input("AAPL", input).unwrap());
+        }
+        journal
+    }
+
+    #[test]
+    fn test_fifo() {
+        let journal = journal(&["+10 100", "+10 120", "-10 130"]);
+        let pnl = position_pnl("AAPL", &journal.transactions, CostBasisMethod::Fifo, Some(140.0));
+
+        assert_eq!(pnl.quantity, 10.0);
+        assert_eq!(pnl.realized, 300.0);
+        assert_eq!(pnl.average_cost(), Some(120.0));
+        assert_eq!(pnl.unrealized, Some(200.0));
+    }
+
+    #[test]
+    fn test_average_cost() {
+        let journal = journal(&["+10 100", "+10 120", "-10 130"]);
+        let pnl = position_pnl("AAPL", &journal.transactions, CostBasisMethod::AverageCost, Some(140.0));
+
+        assert_eq!(pnl.quantity, 10.0);
+        assert_eq!(pnl.realized, 200.0);
+        assert_eq!(pnl.average_cost(), Some(110.0));
+        assert_eq!(pnl.unrealized, Some(300.0));
+    }
+
+    #[test]
+    fn test_oversell_is_capped() {
+        let journal = journal(&["+5 100", "-8 110"]);
+        for method in [CostBasisMethod::Fifo, CostBasisMethod::AverageCost] {
+            let pnl = position_pnl("AAPL", &journal.transactions, method, None);
+            assert_eq!(pnl.quantity, 0.0);
+            assert_eq!(pnl.realized, 50.0);
+            assert!(pnl.unrealized.is_none());
+        }
+    }
+
+    #[test]
+    fn test_pnl_totals() {
+        let journal = journal(&["+10 100", "-5 110"]);
+        let positions = journal.pnl(CostBasisMethod::Fifo, |_| Some(120.0));
+        let totals = pnl_totals(&positions);
+
+        assert_eq!(totals.realized, 50.0);
+        assert_eq!(totals.unrealized, 100.0);
+    }
+}
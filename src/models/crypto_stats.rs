@@ -0,0 +1,91 @@
+// ============================================================================
+// Structure : CryptoStats
+// ============================================================================
+// Statistiques de marché d'une crypto (CoinGecko, voir api/coingecko.rs) :
+//
+//   Capitalisation  $1.35T · rang #1
+//   Volume 24h      $32.1B
+//   En circulation  19.8M BTC (94% de 21M)
+//   Dominance       52.3% du marché crypto
+//
+// Montants dans la devise de cotation de la paire (BTC-EUR : euros)
+//
+// CONCEPT : Rafraîchissement et cache
+// - Rechargées toutes les REFRESH tant que la fiche détaillée est ouverte
+// - Gardées dans ~/.local/share/lazywallet/crypto_stats.json : la fiche
+//   s'affiche aussitôt au démarrage suivant
+// ============================================================================
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Intervalle entre deux rechargements (cadence du bandeau des indices)
+pub const REFRESH: Duration = Duration::from_secs(300);
+
+/// Statistiques de marché d'une crypto
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CryptoStats {
+    /// Identifiant CoinGecko ("bitcoin")
+    pub coin_id: String,
+
+    pub market_cap: Option<f64>,
+    pub market_cap_rank: Option<u32>,
+    pub volume_24h: Option<f64>,
+
+    /// Offre en circulation, en unités de la crypto
+    pub circulating_supply: Option<f64>,
+
+    /// Offre maximale (None : pas de plafond, comme l'ether)
+    pub max_supply: Option<f64>,
+
+    /// Part de la capitalisation totale du marché crypto, en %
+    pub dominance: Option<f64>,
+
+    /// Date du chargement (fraîcheur du cache)
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CryptoStats {
+    /// Vérifie si les statistiques datent de plus de REFRESH
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        chrono::Duration::from_std(REFRESH).is_ok_and(|refresh| now - self.fetched_at >= refresh)
+    }
+
+    /// Part de l'offre maximale déjà en circulation, en %
+    pub fn supply_percent(&self) -> Option<f64> {
+        let max = self.max_supply.filter(|max| *max > 0.0)?;
+        Some(self.circulating_supply? / max * 100.0)
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_staleness_and_supply() {
+        let now = Utc::now();
+        let mut stats = CryptoStats {
+            coin_id: "bitcoin".to_string(),
+            market_cap: Some(1.35e12),
+            market_cap_rank: Some(1),
+            volume_24h: Some(3.21e10),
+            circulating_supply: Some(19_740_000.0),
+            max_supply: Some(21_000_000.0),
+            dominance: Some(52.3),
+            fetched_at: now - chrono::Duration::minutes(2),
+        };
+        assert!(!stats.is_stale(now));
+        assert!(stats.is_stale(now + chrono::Duration::minutes(3)));
+        assert!((stats.supply_percent().unwrap() - 94.0).abs() < 1e-9);
+
+        stats.max_supply = None;
+        assert_eq!(stats.supply_percent(), None);
+    }
+}
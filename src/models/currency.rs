@@ -0,0 +1,208 @@
+// ============================================================================
+// Devise de cotation
+// ============================================================================
+// Yahoo donne la devise de chaque instrument dans les métadonnées du
+// graphique (meta.currency) : "USD", "EUR", "GBp", "JPY"...
+//
+//   USD : "$271.49"     EUR : "153.42 €"     GBp : "1234.50p"
+//   JPY : "¥2345"       CHF : "98.10 CHF"    inconnue : "45.20 SEK"
+//
+// CONCEPT : Symbole avant ou après le montant
+// - Préfixe pour le dollar, la livre, le yen ; suffixe pour l'euro, le franc
+// - "GBp" (pence, Bourse de Londres) est distinct de "GBP" (livres)
+// - Yen et won n'ont pas de centimes : 0 décimale
+//
+// Devise absente (cache, CSV, démo) : déduite du suffixe du symbole
+// (AIR.PA → EUR, VOD.L → GBp, 7203.T → JPY, BTC-EUR → EUR), dollar sinon
+// ============================================================================
+
+use crate::models::TickerType;
+
+/// Affichage d'une devise : texte avant, texte après, décimales
+struct CurrencyFormat {
+    code: &'static str,
+    prefix: &'static str,
+    suffix: &'static str,
+    decimals: usize,
+}
+
+const fn currency(code: &'static str, prefix: &'static str, suffix: &'static str, decimals: usize) -> CurrencyFormat {
+    CurrencyFormat { code, prefix, suffix, decimals }
+}
+
+/// Devises connues (code Yahoo, sensible à la casse pour GBp)
+const CURRENCIES: &[CurrencyFormat] = &[
+    currency("USD", "$", "", 2),
+    currency("EUR", "", " €", 2),
+    currency("GBP", "£", "", 2),
+    currency("GBp", "", "p", 2),
+    currency("GBX", "", "p", 2),
+    currency("JPY", "¥", "", 0),
+    currency("CNY", "CN¥", "", 2),
+    currency("KRW", "₩", "", 0),
+    currency("INR", "₹", "", 2),
+    currency("CAD", "CA$", "", 2),
+    currency("AUD", "A$", "", 2),
+    currency("HKD", "HK$", "", 2),
+    currency("CHF", "", " CHF", 2),
+    currency("SEK", "", " kr", 2),
+    currency("NOK", "", " kr", 2),
+    currency("DKK", "", " kr", 2),
+];
+
+/// Suffixes de place boursière Yahoo et devise de cotation
+const EXCHANGE_SUFFIXES: &[(&str, &str)] = &[
+    (".PA", "EUR"),
+    (".DE", "EUR"),
+    (".F", "EUR"),
+    (".AS", "EUR"),
+    (".BR", "EUR"),
+    (".MI", "EUR"),
+    (".MC", "EUR"),
+    (".LS", "EUR"),
+    (".L", "GBp"),
+    (".T", "JPY"),
+    (".SW", "CHF"),
+    (".TO", "CAD"),
+    (".AX", "AUD"),
+    (".HK", "HKD"),
+    (".KS", "KRW"),
+    (".NS", "INR"),
+    (".ST", "SEK"),
+    (".OL", "NOK"),
+    (".CO", "DKK"),
+];
+
+/// Devise de cotation d'un instrument
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Currency {
+    code: String,
+}
+
+impl Default for Currency {
+    /// Dollar américain (devise de la plupart des tickers Yahoo)
+    fn default() -> Self {
+        Self::new("USD")
+    }
+}
+
+impl Currency {
+    /// Devise à partir de son code Yahoo ("EUR", "GBp")
+    pub fn new(code: &str) -> Self {
+        Self { code: code.trim().to_string() }
+    }
+
+    /// Devise déduite du suffixe de place du symbole (dollar sinon)
+    ///
+    /// Crypto : devise de cotation de la paire (BTC-EUR → EUR)
+    pub fn from_symbol(symbol: &str) -> Self {
+        let symbol = symbol.trim().to_uppercase();
+        if TickerType::from_symbol(&symbol) == TickerType::Crypto {
+            if let Some((_, quote)) = symbol.split_once('-') {
+                return Self::new(quote);
+            }
+        }
+        EXCHANGE_SUFFIXES
+            .iter()
+            .find(|(suffix, _)| symbol.ends_with(suffix))
+            .map(|(_, code)| Self::new(code))
+            .unwrap_or_default()
+    }
+
+    /// Code de la devise ("USD", "EUR", "GBp")
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Unité principale et facteur de conversion : pence → livres (0.01)
+    ///
+    /// Les soldes de liquidités se tiennent dans l'unité principale
+    pub fn main_unit(&self) -> (Currency, f64) {
+        match self.code.as_str() {
+            "GBp" | "GBX" => (Self::new("GBP"), 0.01),
+            _ => (self.clone(), 1.0),
+        }
+    }
+
+    /// Format connu de la devise (None si devise inconnue)
+    fn format(&self) -> Option<&'static CurrencyFormat> {
+        CURRENCIES.iter().find(|format| format.code == self.code)
+    }
+
+    /// Nombre de décimales d'un prix (0 pour le yen et le won, 2 sinon)
+    pub fn decimals(&self) -> usize {
+        self.format().map_or(2, |format| format.decimals)
+    }
+
+    /// Place le symbole autour d'un montant déjà formaté : "271.49" → "271.49 €"
+    ///
+    /// Devise inconnue : code en suffixe ("45.20 SEK")
+    pub fn wrap(&self, amount: &str) -> String {
+        match self.format() {
+            Some(format) => format!("{}{}{}", format.prefix, amount, format.suffix),
+            None => format!("{} {}", amount, self.code),
+        }
+    }
+}
+
+/// Taux de conversion d'une devise en dollars d'après les cotations des paires
+///
+/// EURUSD=X donne des dollars par euro ; USDEUR=X et EUR=X sont inversées
+///
+/// # Arguments
+/// * `price_of` - Cours d'un symbole (None s'il n'est pas coté)
+pub fn usd_rate(code: &str, price_of: impl Fn(&str) -> Option<f64>) -> Option<f64> {
+    if code == "USD" {
+        return Some(1.0);
+    }
+
+    let price = |symbol: String| price_of(&symbol).filter(|price| *price > 0.0);
+    price(format!("{}USD=X", code))
+        .or_else(|| price(format!("USD{}=X", code)).map(|price| 1.0 / price))
+        .or_else(|| price(format!("{}=X", code)).map(|price| 1.0 / price))
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_wrap_and_decimals() {
+        assert_eq!(Currency::default().wrap("271.49"), "$271.49");
+        assert_eq!(Currency::new("EUR").wrap("153.42"), "153.42 €");
+        assert_eq!(Currency::new("GBp").wrap("1234.50"), "1234.50p");
+        assert_eq!(Currency::new("GBP").wrap("12.35"), "£12.35");
+        assert_eq!(Currency::new("XYZ").wrap("45.20"), "45.20 XYZ");
+
+        assert_eq!(Currency::new("JPY").decimals(), 0);
+        assert_eq!(Currency::new("EUR").decimals(), 2);
+        assert_eq!(Currency::new("XYZ").decimals(), 2);
+    }
+
+    #[test]
+    fn test_currency_from_symbol() {
+        assert_eq!(Currency::from_symbol("AIR.PA").code(), "EUR");
+        assert_eq!(Currency::from_symbol("vod.l").code(), "GBp");
+        assert_eq!(Currency::from_symbol("7203.T").code(), "JPY");
+        assert_eq!(Currency::from_symbol("AAPL").code(), "USD");
+        assert_eq!(Currency::from_symbol("BTC-USD").code(), "USD");
+        assert_eq!(Currency::from_symbol("ETH-EUR").code(), "EUR");
+
+        assert_eq!(Currency::new("GBp").main_unit(), (Currency::new("GBP"), 0.01));
+        assert_eq!(Currency::new("EUR").main_unit(), (Currency::new("EUR"), 1.0));
+
+        let price_of = |symbol: &str| match symbol {
+            "EURUSD=X" => Some(1.25),
+            "JPY=X" => Some(150.0),
+            _ => None,
+        };
+        assert_eq!(usd_rate("USD", price_of), Some(1.0));
+        assert_eq!(usd_rate("EUR", price_of), Some(1.25));
+        assert_eq!(usd_rate("JPY", price_of), Some(1.0 / 150.0));
+        assert_eq!(usd_rate("CHF", price_of), None);
+    }
+}
@@ -0,0 +1,162 @@
+// ============================================================================
+// Structure : DividendInfo et calendrier des dividendes
+// ============================================================================
+// Prochains détachements et paiements de dividendes de la watchlist
+// (quoteSummary de Yahoo, voir api/yahoo/dividends.rs) :
+//
+//   13/06/2024  Détachement  KO       0.49 $   100 titres   $48.50
+//   01/07/2024  Paiement     KO       0.49 $   100 titres   $48.50
+//   04/07/2024  Détachement  TTE.PA   0.79 €          —          —
+//
+// - Seules les dates à venir (aujourd'hui compris) sont gardées
+// - Montant reçu : dernier dividende par action × quantité détenue, converti
+//   en dollars comme la valeur du portefeuille (voir portfolio::to_usd)
+// ============================================================================
+
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+
+use crate::models::currency::Currency;
+use crate::models::portfolio::{to_usd, Portfolio};
+use crate::models::WatchlistItem;
+
+/// Dernier dividende annoncé d'un ticker
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DividendInfo {
+    /// Date de détachement (il faut détenir le titre la veille)
+    pub ex_date: Option<NaiveDate>,
+
+    /// Date de paiement
+    pub payment_date: Option<NaiveDate>,
+
+    /// Montant par action, dans la devise de cotation
+    pub amount: Option<f64>,
+}
+
+/// Nature d'une date du calendrier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DividendEvent {
+    ExDividend,
+    Payment,
+}
+
+impl DividendEvent {
+    /// Libellé affiché dans le calendrier
+    pub fn label(self) -> &'static str {
+        match self {
+            DividendEvent::ExDividend => "Détachement",
+            DividendEvent::Payment => "Paiement",
+        }
+    }
+}
+
+/// Une ligne du calendrier des dividendes
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpcomingDividend {
+    pub symbol: String,
+    pub date: NaiveDate,
+    pub event: DividendEvent,
+
+    /// Montant par action (devise de cotation)
+    pub amount: Option<f64>,
+    pub currency: Currency,
+
+    /// Quantité détenue (None sans position)
+    pub quantity: Option<f64>,
+
+    /// Montant à percevoir, en dollars (position et montant connus)
+    pub total_usd: Option<f64>,
+}
+
+/// Détachements et paiements à venir de la watchlist, par date puis symbole
+pub fn upcoming_dividends(watchlist: &[WatchlistItem], portfolio: &Portfolio, today: NaiveDate) -> Vec<UpcomingDividend> {
+    let mut entries: Vec<UpcomingDividend> = watchlist
+        .iter()
+        .filter_map(|item| Some((item, item.dividends.as_ref()?)))
+        .flat_map(|(item, info)| {
+            let quantity = portfolio.position(&item.symbol).map(|position| position.quantity);
+            let total_usd = match (info.amount, quantity) {
+                (Some(amount), Some(quantity)) => Some(to_usd(amount * quantity, &item.currency(), watchlist)),
+                _ => None,
+            };
+            [(info.ex_date, DividendEvent::ExDividend), (info.payment_date, DividendEvent::Payment)]
+                .into_iter()
+                .filter_map(move |(date, event)| {
+                    Some(UpcomingDividend {
+                        symbol: item.symbol.clone(),
+                        date: date.filter(|date| *date >= today)?,
+                        event,
+                        amount: info.amount,
+                        currency: item.currency(),
+                        quantity,
+                        total_usd,
+                    })
+                })
+        })
+        .collect();
+
+    entries.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.symbol.cmp(&b.symbol)));
+    entries
+}
+
+/// Total à percevoir en dollars (chaque dividende compté une fois, même s'il
+/// apparaît au détachement et au paiement)
+pub fn expected_income(entries: &[UpcomingDividend]) -> f64 {
+    let mut seen = HashSet::new();
+    entries
+        .iter()
+        .filter(|entry| seen.insert(entry.symbol.as_str()))
+        .filter_map(|entry| entry.total_usd)
+        .sum()
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    fn item(symbol: &str, info: DividendInfo) -> WatchlistItem {
+        let mut item = WatchlistItem::new(symbol.to_string(), symbol.to_string());
+        item.dividends = Some(info);
+        item
+    }
+
+    #[test]
+    fn test_upcoming_dividends_sorted_with_position_amounts() {
+        let watchlist = vec![
+            item("KO", DividendInfo { ex_date: Some(date(6, 13)), payment_date: Some(date(7, 1)), amount: Some(0.485) }),
+            item("TTE.PA", DividendInfo { ex_date: Some(date(6, 20)), payment_date: None, amount: Some(0.79) }),
+            // Détachement passé, paiement à venir ; puis dividende entièrement passé
+            item("MSFT", DividendInfo { ex_date: Some(date(5, 15)), payment_date: Some(date(6, 13)), amount: Some(0.75) }),
+            item("AAPL", DividendInfo { ex_date: Some(date(5, 10)), payment_date: Some(date(5, 16)), amount: Some(0.25) }),
+            WatchlistItem::new("BTC-USD".to_string(), "Bitcoin".to_string()),
+        ];
+        let mut portfolio = Portfolio::default();
+        portfolio.set_position("KO", 100.0);
+        portfolio.set_position("MSFT", 10.0);
+
+        let entries = upcoming_dividends(&watchlist, &portfolio, date(6, 1));
+        let rows: Vec<(&str, DividendEvent)> = entries.iter().map(|entry| (entry.symbol.as_str(), entry.event)).collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("KO", DividendEvent::ExDividend),
+                ("MSFT", DividendEvent::Payment),
+                ("TTE.PA", DividendEvent::ExDividend),
+                ("KO", DividendEvent::Payment),
+            ]
+        );
+        assert_eq!(entries[0].total_usd, Some(48.5));
+        assert_eq!(entries[2].quantity, None);
+        assert_eq!(entries[2].currency.code(), "EUR");
+        assert!((expected_income(&entries) - 56.0).abs() < 1e-9);
+    }
+}
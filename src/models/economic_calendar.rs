@@ -0,0 +1,100 @@
+// ============================================================================
+// Structure : EconomicEvent
+// ============================================================================
+// Publications économiques majeures (CPI, FOMC, NFP, ...) lues depuis un flux
+// ICS ou JSON configuré (voir api/economic_calendar.rs), affichées sur le
+// dashboard le jour même :
+//
+//   📅 14:30 US CPI dans 2h 15min  │  20:00 US FOMC dans 7h 45min
+//
+// CONCEPT : Heures en UTC
+// - Les heures du flux sont converties en UTC à la lecture ; le jour et
+//   l'heure affichés sont ceux du fuseau local
+// - Un événement "toute la journée" (jour férié, sommet) n'a pas d'heure :
+//   pas de compte à rebours
+// ============================================================================
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+
+/// Un événement du calendrier économique
+#[derive(Debug, Clone, PartialEq)]
+pub struct EconomicEvent {
+    /// Intitulé ("CPI", "FOMC rate decision")
+    pub title: String,
+
+    /// Pays ou zone concernée ("US", "EU"), si le flux le précise
+    pub country: Option<String>,
+
+    /// Heure de publication (minuit local pour un événement sur la journée)
+    pub at: DateTime<Utc>,
+
+    /// Événement sur toute la journée, sans heure
+    pub all_day: bool,
+}
+
+impl EconomicEvent {
+    /// Intitulé affiché, précédé du pays : "US CPI"
+    pub fn label(&self) -> String {
+        match &self.country {
+            Some(country) => format!("{} {}", country, self.title),
+            None => self.title.clone(),
+        }
+    }
+
+    /// Jour de l'événement, dans le fuseau local
+    pub fn local_date(&self) -> NaiveDate {
+        self.at.with_timezone(&Local).date_naive()
+    }
+
+    /// Temps restant avant la publication (None : passée ou sans heure)
+    pub fn countdown(&self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        (!self.all_day && self.at > now).then(|| self.at - now)
+    }
+}
+
+/// Événements d'un jour local, dans l'ordre chronologique
+pub fn events_on(events: &[EconomicEvent], date: NaiveDate) -> Vec<&EconomicEvent> {
+    let mut day: Vec<&EconomicEvent> = events.iter().filter(|event| event.local_date() == date).collect();
+    day.sort_by_key(|event| (!event.all_day, event.at));
+    day
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Heure locale du 12 juin 2024, en UTC
+    fn local(hour: u32, minute: u32) -> DateTime<Utc> {
+        let naive = NaiveDate::from_ymd_opt(2024, 6, 12).unwrap().and_hms_opt(hour, minute, 0).unwrap();
+        naive.and_local_timezone(Local).single().unwrap().with_timezone(&Utc)
+    }
+
+    fn event(title: &str, at: DateTime<Utc>, all_day: bool) -> EconomicEvent {
+        EconomicEvent { title: title.to_string(), country: Some("US".to_string()), at, all_day }
+    }
+
+    #[test]
+    fn test_events_of_the_day_and_countdown() {
+        let events = vec![
+            event("FOMC", local(20, 0), false),
+            event("CPI", local(14, 30), false),
+            event("Juneteenth", local(0, 0), true),
+            event("NFP", local(14, 30) + chrono::Duration::days(2), false),
+        ];
+
+        let day: Vec<String> = events_on(&events, local(12, 0).with_timezone(&Local).date_naive())
+            .iter()
+            .map(|event| event.label())
+            .collect();
+        assert_eq!(day, ["US Juneteenth", "US CPI", "US FOMC"]);
+
+        let now = local(12, 15);
+        assert_eq!(events[1].countdown(now), Some(chrono::Duration::minutes(135)));
+        assert_eq!(events[1].countdown(local(15, 0)), None);
+        assert_eq!(events[2].countdown(local(0, 0) - chrono::Duration::hours(1)), None);
+    }
+}
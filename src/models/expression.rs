@@ -0,0 +1,377 @@
+// ============================================================================
+// Expressions de surveillance : valeurs dérivées des cotations
+// ============================================================================
+// Petit langage arithmétique sur les cours de la watchlist, affiché en
+// lignes synthétiques sous la watchlist du dashboard :
+//
+//   AAPL - 0.5 * MSFT          écart d'une paire
+//   (GC=F / SI=F)              ratio or / argent
+//   BTC-USD in EUR             cours converti en euros
+//   AIR.PA + 2 * SAF.PA in USD panier converti en dollars
+//
+// Opérandes   : nombre, symbole de la watchlist, ( expression )
+// Opérateurs  : + - * / (priorités usuelles), - unaire
+// Conversion  : "in XXX" en fin d'expression, chaque cours est converti
+//               dans la devise XXX avant le calcul (paires de change de la
+//               watchlist, voir currency::usd_rate)
+//
+// CONCEPT : Symboles et soustraction
+// - Un symbole peut contenir '-' (BTC-USD) : "AAPL-MSFT" est lu comme un
+//   seul symbole, la soustraction s'écrit avec des espaces ("AAPL - MSFT")
+//
+// CONCEPT : Descente récursive
+// - Une fonction par niveau de priorité : expression (+ -), terme (* /),
+//   facteur (- unaire, nombre, symbole, parenthèses)
+// - Chaque fonction consomme les jetons de son niveau et délègue le reste
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::models::currency::{usd_rate, Currency};
+use crate::models::WatchlistItem;
+
+/// Expression de surveillance déclarée dans config.json
+///
+///   "watch_expressions": [{ "label": "Paire", "expression": "AAPL - 0.5 * MSFT" }]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchExpression {
+    /// Libellé affiché (défaut : l'expression)
+    #[serde(default)]
+    pub label: Option<String>,
+
+    /// Texte de l'expression
+    pub expression: String,
+}
+
+/// Valeur d'une expression de surveillance
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchValue {
+    /// Valeur avec les cours actuels
+    pub value: f64,
+
+    /// Variation par rapport à la valeur avec les clôtures de la veille
+    pub change_percent: Option<f64>,
+
+    /// Devise de conversion ("in EUR"), None sans conversion
+    pub currency: Option<Currency>,
+}
+
+/// Opérateur binaire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+/// Arbre d'une expression
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Number(f64),
+    Quote(String),
+    Negate(Box<Node>),
+    Binary(Box<Node>, Operator, Box<Node>),
+}
+
+/// Jeton de la saisie
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Symbol(String),
+    Operator(Operator),
+    Open,
+    Close,
+    In,
+}
+
+/// Expression analysée, prête à être évaluée
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    root: Node,
+    currency: Option<Currency>,
+}
+
+/// Découpe la saisie en jetons
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        let token = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '+' => Token::Operator(Operator::Add),
+            '-' => Token::Operator(Operator::Subtract),
+            '*' => Token::Operator(Operator::Multiply),
+            '/' => Token::Operator(Operator::Divide),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            c if c.is_ascii_alphanumeric() || c == '^' || c == '.' => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '=' | '^')) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                // "inf" ou "nan" sont des symboles, pas des nombres
+                let numeric = word.starts_with(|c: char| c.is_ascii_digit() || c == '.');
+                tokens.push(match word.parse::<f64>() {
+                    Ok(number) if numeric => Token::Number(number),
+                    _ if word.eq_ignore_ascii_case("in") => Token::In,
+                    _ => Token::Symbol(word.to_uppercase()),
+                });
+                continue;
+            }
+            other => anyhow::bail!("Caractère inattendu : '{}'", other),
+        };
+        tokens.push(token);
+        chars.next();
+    }
+    Ok(tokens)
+}
+
+/// Analyseur par descente récursive sur la liste des jetons
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    /// expression = terme (('+' | '-') terme)*
+    fn expression(&mut self) -> Result<Node> {
+        let mut node = self.term()?;
+        while let Some(Token::Operator(operator @ (Operator::Add | Operator::Subtract))) = self.peek().cloned() {
+            self.next();
+            node = Node::Binary(Box::new(node), operator, Box::new(self.term()?));
+        }
+        Ok(node)
+    }
+
+    /// terme = facteur (('*' | '/') facteur)*
+    fn term(&mut self) -> Result<Node> {
+        let mut node = self.factor()?;
+        while let Some(Token::Operator(operator @ (Operator::Multiply | Operator::Divide))) = self.peek().cloned() {
+            self.next();
+            node = Node::Binary(Box::new(node), operator, Box::new(self.factor()?));
+        }
+        Ok(node)
+    }
+
+    /// facteur = '-' facteur | nombre | symbole | '(' expression ')'
+    fn factor(&mut self) -> Result<Node> {
+        match self.next() {
+            Some(Token::Operator(Operator::Subtract)) => Ok(Node::Negate(Box::new(self.factor()?))),
+            Some(Token::Number(number)) => Ok(Node::Number(number)),
+            Some(Token::Symbol(symbol)) => Ok(Node::Quote(symbol)),
+            Some(Token::Open) => {
+                let node = self.expression()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(node),
+                    _ => anyhow::bail!("Parenthèse fermante manquante"),
+                }
+            }
+            Some(_) => anyhow::bail!("Nombre ou symbole attendu"),
+            None => anyhow::bail!("Expression incomplète"),
+        }
+    }
+}
+
+impl Expression {
+    /// Parse une expression du type "AAPL - 0.5 * MSFT" ou "BTC-USD in EUR"
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut parser = Parser { tokens: tokenize(input)?, position: 0 };
+        let root = parser.expression()?;
+
+        let currency = match parser.next() {
+            None => None,
+            Some(Token::In) => match (parser.next(), parser.next()) {
+                (Some(Token::Symbol(code)), None) if code.len() == 3 => Some(Currency::new(&code)),
+                _ => anyhow::bail!("Devise attendue après \"in\" (ex: in EUR)"),
+            },
+            Some(_) => anyhow::bail!("Opérateur attendu entre deux termes"),
+        };
+        Ok(Self { root, currency })
+    }
+
+    /// Devise de conversion ("in EUR")
+    pub fn currency(&self) -> Option<&Currency> {
+        self.currency.as_ref()
+    }
+
+    /// Évalue l'expression
+    ///
+    /// # Arguments
+    /// * `quote` - Cours d'un symbole et sa devise (None s'il n'est pas coté)
+    pub fn evaluate(&self, quote: &dyn Fn(&str) -> Option<(f64, Currency)>) -> Result<f64> {
+        let value = self.evaluate_node(&self.root, quote)?;
+        if !value.is_finite() {
+            anyhow::bail!("Résultat non défini");
+        }
+        Ok(value)
+    }
+
+    fn evaluate_node(&self, node: &Node, quote: &dyn Fn(&str) -> Option<(f64, Currency)>) -> Result<f64> {
+        match node {
+            Node::Number(number) => Ok(*number),
+            Node::Quote(symbol) => {
+                let (price, currency) = quote(symbol).with_context(|| format!("{} non coté", symbol))?;
+                match &self.currency {
+                    Some(target) => self.convert(price, &currency, target, quote),
+                    None => Ok(price),
+                }
+            }
+            Node::Negate(node) => Ok(-self.evaluate_node(node, quote)?),
+            Node::Binary(left, operator, right) => {
+                let (left, right) = (self.evaluate_node(left, quote)?, self.evaluate_node(right, quote)?);
+                match operator {
+                    Operator::Add => Ok(left + right),
+                    Operator::Subtract => Ok(left - right),
+                    Operator::Multiply => Ok(left * right),
+                    Operator::Divide if right == 0.0 => anyhow::bail!("Division par zéro"),
+                    Operator::Divide => Ok(left / right),
+                }
+            }
+        }
+    }
+
+    /// Convertit un cours dans la devise cible en passant par le dollar
+    fn convert(
+        &self,
+        price: f64,
+        currency: &Currency,
+        target: &Currency,
+        quote: &dyn Fn(&str) -> Option<(f64, Currency)>,
+    ) -> Result<f64> {
+        let (unit, factor) = currency.main_unit();
+        let price_of = |symbol: &str| quote(symbol).map(|(price, _)| price);
+        let rate = |code: &str| {
+            usd_rate(code, price_of).with_context(|| format!("Taux {}/USD inconnu (ajoutez {}USD=X)", code, code))
+        };
+        Ok(price * factor * rate(unit.code())? / rate(target.code())?)
+    }
+}
+
+impl WatchExpression {
+    /// Libellé affiché : celui de la configuration, sinon l'expression
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.expression)
+    }
+
+    /// Valeur et variation du jour avec les cotations de la watchlist
+    ///
+    /// CONCEPT : Variation d'une valeur dérivée
+    /// - L'expression est évaluée une seconde fois avec les clôtures de la
+    ///   veille (taux de change compris)
+    /// - Variation = (valeur - veille) / |veille|, indéfinie si la veille est nulle
+    pub fn evaluate(&self, watchlist: &[WatchlistItem]) -> Result<WatchValue> {
+        let expression = Expression::parse(&self.expression)?;
+        let find = |symbol: &str| watchlist.iter().find(|item| item.symbol == symbol);
+
+        let current = |symbol: &str| {
+            let item = find(symbol)?;
+            Some((item.current_price()?, item.currency()))
+        };
+        let previous = |symbol: &str| {
+            let item = find(symbol)?;
+            let price = item.current_price()?;
+            let change = item.change_percent().unwrap_or(0.0);
+            Some((price / (1.0 + change / 100.0), item.currency()))
+        };
+
+        let value = expression.evaluate(&current)?;
+        let change_percent = expression
+            .evaluate(&previous)
+            .ok()
+            .filter(|previous| previous.abs() > 1e-9)
+            .map(|previous| (value - previous) / previous.abs() * 100.0);
+
+        Ok(WatchValue {
+            value,
+            change_percent,
+            currency: expression.currency().cloned(),
+        })
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quotes(symbol: &str) -> Option<(f64, Currency)> {
+        match symbol {
+            "AAPL" => Some((200.0, Currency::default())),
+            "MSFT" => Some((100.0, Currency::default())),
+            "BTC-USD" => Some((50_000.0, Currency::default())),
+            "AIR.PA" => Some((150.0, Currency::new("EUR"))),
+            "EURUSD=X" => Some((1.25, Currency::default())),
+            _ => None,
+        }
+    }
+
+    fn eval(input: &str) -> Result<f64> {
+        Expression::parse(input)?.evaluate(&quotes)
+    }
+
+    #[test]
+    fn test_parse_and_evaluate() {
+        assert_eq!(eval("AAPL - 0.5 * MSFT").unwrap(), 150.0);
+        assert_eq!(eval("(AAPL - MSFT) / 2").unwrap(), 50.0);
+        assert_eq!(eval("-aapl + 2*MSFT").unwrap(), 0.0);
+        assert_eq!(eval("BTC-USD / 1000").unwrap(), 50.0);
+        assert_eq!(eval("BTC-USD in EUR").unwrap(), 40_000.0);
+        assert_eq!(eval("AIR.PA in usd").unwrap(), 187.5);
+
+        assert!(eval("AAPL-MSFT").unwrap_err().to_string().contains("AAPL-MSFT non coté"));
+        assert!(eval("AAPL / (MSFT - 100)").is_err());
+        assert!(eval("AAPL in CHF").unwrap_err().to_string().contains("CHFUSD=X"));
+        assert!(Expression::parse("AAPL MSFT").is_err());
+        assert!(Expression::parse("(AAPL + 1").is_err());
+        assert!(Expression::parse("AAPL +").is_err());
+        assert!(Expression::parse("AAPL in").is_err());
+        assert!(Expression::parse("AAPL % 2").is_err());
+    }
+
+    #[test]
+    fn test_watch_expression_change() {
+        use crate::models::{Interval, OHLCData, OHLC};
+        use chrono::Utc;
+
+        let item = |symbol: &str, open: f64, close: f64| {
+            let mut data = OHLCData::with_interval(symbol.to_string(), Interval::D1);
+            data.add_candle(OHLC::new(Utc::now(), open, open.max(close), open.min(close), close, 0));
+            WatchlistItem::with_data(symbol.to_string(), symbol.to_string(), data)
+        };
+        let watchlist = vec![item("AAPL", 100.0, 110.0), item("MSFT", 100.0, 100.0)];
+
+        let spread = WatchExpression { label: None, expression: "AAPL - MSFT".to_string() };
+        let value = spread.evaluate(&watchlist).unwrap();
+        assert_eq!(spread.label(), "AAPL - MSFT");
+        assert!((value.value - 10.0).abs() < 1e-9);
+        assert!(value.change_percent.is_none()); // Veille : 0
+        assert!(value.currency.is_none());
+
+        let ratio = WatchExpression { label: Some("Ratio".to_string()), expression: "AAPL / MSFT".to_string() };
+        let value = ratio.evaluate(&watchlist).unwrap();
+        assert!((value.change_percent.unwrap() - 10.0).abs() < 1e-9);
+    }
+}
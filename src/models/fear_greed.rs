@@ -0,0 +1,69 @@
+// ============================================================================
+// Structure : FearGreed
+// ============================================================================
+// Indice "Fear & Greed" du marché crypto (alternative.me), de 0 à 100 :
+//
+//   0 – 24    Peur extrême
+//   25 – 44   Peur
+//   45 – 55   Neutre
+//   56 – 75   Avidité
+//   76 – 100  Avidité extrême
+//
+// - Publié une fois par jour (vers minuit UTC) : inutile de le recharger
+//   plus souvent
+// - Le libellé est déduit de la valeur (en français), pas de la réponse
+// ============================================================================
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Valeur publiée de l'indice
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FearGreed {
+    /// Valeur de 0 (peur extrême) à 100 (avidité extrême)
+    pub value: u8,
+
+    /// Date de publication de la valeur
+    pub published_at: DateTime<Utc>,
+}
+
+impl FearGreed {
+    /// Libellé de la zone de la valeur
+    pub fn label(&self) -> &'static str {
+        match self.value {
+            0..=24 => "Peur extrême",
+            25..=44 => "Peur",
+            45..=55 => "Neutre",
+            56..=75 => "Avidité",
+            _ => "Avidité extrême",
+        }
+    }
+
+    /// Vérifie si une valeur plus récente est publiée (un jour après)
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        now - self.published_at >= Duration::days(1)
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_label_and_staleness() {
+        let published_at = Utc.with_ymd_and_hms(2026, 10, 14, 0, 0, 0).unwrap();
+        let index = |value| FearGreed { value, published_at };
+        assert_eq!(index(10).label(), "Peur extrême");
+        assert_eq!(index(44).label(), "Peur");
+        assert_eq!(index(50).label(), "Neutre");
+        assert_eq!(index(76).label(), "Avidité extrême");
+
+        assert!(!index(50).is_stale(published_at + Duration::hours(23)));
+        assert!(index(50).is_stale(published_at + Duration::hours(24)));
+    }
+}
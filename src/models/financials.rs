@@ -0,0 +1,159 @@
+// ============================================================================
+// Structure : Financials
+// ============================================================================
+// Historique des comptes d'une action (séries fundamentals-timeseries de
+// Yahoo, voir api/yahoo/financials.rs) :
+//
+// - Chiffre d'affaires (TotalRevenue)
+// - Résultat net (NetIncome)
+// - Free cash flow (FreeCashFlow)
+//
+// CONCEPT : Annuel et trimestriel
+// - Yahoo publie 4 exercices annuels et les 5 derniers trimestres
+// - La fiche détaillée bascule de l'un à l'autre (touche f)
+// ============================================================================
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::models::Currency;
+
+/// Périodicité des comptes affichés
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatementPeriod {
+    #[default]
+    Annual,
+    Quarterly,
+}
+
+impl StatementPeriod {
+    /// Préfixe des séries Yahoo ("annualTotalRevenue", "quarterlyNetIncome")
+    pub fn yahoo_prefix(self) -> &'static str {
+        match self {
+            StatementPeriod::Annual => "annual",
+            StatementPeriod::Quarterly => "quarterly",
+        }
+    }
+
+    /// Libellé du titre du panneau
+    pub fn label(self) -> &'static str {
+        match self {
+            StatementPeriod::Annual => "annuels",
+            StatementPeriod::Quarterly => "trimestriels",
+        }
+    }
+
+    /// Périodicité suivante (touche f)
+    pub fn toggle(self) -> Self {
+        match self {
+            StatementPeriod::Annual => StatementPeriod::Quarterly,
+            StatementPeriod::Quarterly => StatementPeriod::Annual,
+        }
+    }
+
+    /// Libellé court d'une clôture : "2024" ou "T3 24"
+    pub fn period_label(self, date: NaiveDate) -> String {
+        match self {
+            StatementPeriod::Annual => date.format("%Y").to_string(),
+            StatementPeriod::Quarterly => format!("T{} {}", (date.month() - 1) / 3 + 1, date.format("%y")),
+        }
+    }
+}
+
+/// Valeur d'un poste à la date de clôture d'une période
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatementValue {
+    pub date: NaiveDate,
+    pub value: f64,
+}
+
+/// Les trois postes d'une périodicité, triés par date croissante
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Statements {
+    pub revenue: Vec<StatementValue>,
+    pub net_income: Vec<StatementValue>,
+    pub free_cash_flow: Vec<StatementValue>,
+}
+
+impl Statements {
+    /// Indique si aucun poste n'a de valeur
+    pub fn is_empty(&self) -> bool {
+        self.revenue.is_empty() && self.net_income.is_empty() && self.free_cash_flow.is_empty()
+    }
+
+    /// Dates de clôture présentes dans au moins un poste, croissantes
+    pub fn dates(&self) -> Vec<NaiveDate> {
+        let mut dates: Vec<NaiveDate> = [&self.revenue, &self.net_income, &self.free_cash_flow]
+            .iter()
+            .flat_map(|values| values.iter().map(|value| value.date))
+            .collect();
+        dates.sort();
+        dates.dedup();
+        dates
+    }
+
+    /// Trie chaque poste par date croissante
+    pub fn sort(&mut self) {
+        for values in [&mut self.revenue, &mut self.net_income, &mut self.free_cash_flow] {
+            values.sort_by_key(|value| value.date);
+        }
+    }
+}
+
+/// Valeur d'un poste à une date (None si non publiée)
+pub fn value_at(values: &[StatementValue], date: NaiveDate) -> Option<f64> {
+    values.iter().find(|value| value.date == date).map(|value| value.value)
+}
+
+/// Comptes annuels et trimestriels d'une action
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Financials {
+    /// Devise des comptes (peut différer de la devise de cotation)
+    pub currency: Currency,
+
+    pub annual: Statements,
+    pub quarterly: Statements,
+}
+
+impl Financials {
+    /// Indique si Yahoo n'a publié aucun compte (ETF, action peu suivie)
+    pub fn is_empty(&self) -> bool {
+        self.annual.is_empty() && self.quarterly.is_empty()
+    }
+
+    /// Comptes d'une périodicité
+    pub fn statements(&self, period: StatementPeriod) -> &Statements {
+        match period {
+            StatementPeriod::Annual => &self.annual,
+            StatementPeriod::Quarterly => &self.quarterly,
+        }
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(year: i32, month: u32, value: f64) -> StatementValue {
+        StatementValue { date: NaiveDate::from_ymd_opt(year, month, 30).unwrap(), value }
+    }
+
+    #[test]
+    fn test_dates_and_period_labels() {
+        let statements = Statements {
+            revenue: vec![value(2023, 9, 383.0), value(2024, 9, 391.0)],
+            net_income: vec![value(2024, 9, 93.7)],
+            free_cash_flow: vec![value(2022, 9, 111.4), value(2024, 9, 108.8)],
+        };
+        let dates = statements.dates();
+        assert_eq!(dates.len(), 3);
+        assert_eq!(StatementPeriod::Annual.period_label(dates[0]), "2022");
+        assert_eq!(StatementPeriod::Quarterly.period_label(dates[2]), "T3 24");
+        assert_eq!(value_at(&statements.net_income, dates[2]), Some(93.7));
+        assert_eq!(value_at(&statements.net_income, dates[1]), None);
+        assert_eq!(StatementPeriod::Annual.toggle(), StatementPeriod::Quarterly);
+    }
+}
@@ -0,0 +1,115 @@
+// ============================================================================
+// Structure : Holders
+// ============================================================================
+// Activité des initiés et principaux détenteurs institutionnels d'une action
+// (quoteSummary de Yahoo, modules insiderTransactions et
+// institutionOwnership, voir api/yahoo/holders.rs)
+//
+// - Transactions des dirigeants : les plus récentes d'abord
+// - Institutionnels : par part du capital décroissante
+// ============================================================================
+
+use chrono::NaiveDate;
+
+/// Nature d'une transaction d'initié, déduite de son libellé Yahoo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsiderKind {
+    /// "Purchase at price 170.00 per share."
+    Buy,
+    /// "Sale at price 223.33 - 231.31 per share."
+    Sale,
+    /// Attribution gratuite, exercice d'options, don, ...
+    Other,
+}
+
+impl InsiderKind {
+    /// Déduit la nature du libellé ("Sale at price ...")
+    pub fn from_text(text: &str) -> Self {
+        let text = text.to_lowercase();
+        if text.starts_with("sale") {
+            InsiderKind::Sale
+        } else if text.starts_with("purchase") || text.starts_with("buy") {
+            InsiderKind::Buy
+        } else {
+            InsiderKind::Other
+        }
+    }
+
+    /// Libellé court affiché dans le panneau
+    pub fn label(self) -> &'static str {
+        match self {
+            InsiderKind::Buy => "Achat",
+            InsiderKind::Sale => "Vente",
+            InsiderKind::Other => "Autre",
+        }
+    }
+}
+
+/// Transaction déclarée par un dirigeant ou un administrateur
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsiderTransaction {
+    /// Déclarant ("COOK TIMOTHY D")
+    pub name: String,
+
+    /// Fonction ("Chief Executive Officer")
+    pub relation: String,
+
+    pub date: NaiveDate,
+    pub kind: InsiderKind,
+    pub shares: f64,
+
+    /// Montant de la transaction (None : attribution sans prix)
+    pub value: Option<f64>,
+}
+
+/// Détenteur institutionnel (fonds, gérant d'actifs)
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstitutionalHolder {
+    pub organization: String,
+
+    /// Part du capital détenue, en % (8.77 pour 8,77 %)
+    pub percent_held: f64,
+
+    pub shares: f64,
+
+    /// Date de la déclaration (trimestrielle)
+    pub report_date: Option<NaiveDate>,
+}
+
+/// Initiés et institutionnels d'une action
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Holders {
+    pub insider_transactions: Vec<InsiderTransaction>,
+    pub institutions: Vec<InstitutionalHolder>,
+}
+
+impl Holders {
+    /// Indique si Yahoo n'a aucune donnée (ETF, action peu suivie)
+    pub fn is_empty(&self) -> bool {
+        self.insider_transactions.is_empty() && self.institutions.is_empty()
+    }
+
+    /// Trie les transactions (plus récentes d'abord) et les institutionnels
+    /// (plus grosse part d'abord)
+    pub fn sort(&mut self) {
+        self.insider_transactions.sort_by_key(|transaction| std::cmp::Reverse(transaction.date));
+        self.institutions.sort_by(|a, b| b.percent_held.total_cmp(&a.percent_held));
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insider_kind_from_text() {
+        assert_eq!(InsiderKind::from_text("Sale at price 223.33 - 231.31 per share."), InsiderKind::Sale);
+        assert_eq!(InsiderKind::from_text("Purchase at price 170.00 per share."), InsiderKind::Buy);
+        assert_eq!(InsiderKind::from_text("Stock Award(Grant) at price 0.00 per share."), InsiderKind::Other);
+        assert_eq!(InsiderKind::from_text(""), InsiderKind::Other);
+    }
+}
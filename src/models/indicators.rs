@@ -0,0 +1,109 @@
+// ============================================================================
+// Indicateurs techniques
+// ============================================================================
+// Calculs d'indicateurs sur une série de prix (moyennes mobiles, etc.)
+//
+// CONCEPTS RUST :
+// 1. Slices (&[f64]) : on travaille sur des vues, sans copier les données
+// 2. Vec<Option<f64>> : une valeur par chandelle, None tant que la période
+//    n'est pas encore "remplie" (indicateur pas encore défini)
+// ============================================================================
+
+/// Moyenne mobile simple (SMA) sur `period` valeurs
+///
+/// CONCEPT : Fenêtre glissante
+/// - On maintient la somme de la fenêtre au lieu de la recalculer
+/// - O(n) au lieu de O(n × period)
+///
+/// Retourne un Vec de même longueur que `values`, avec None pour les
+/// `period - 1` premières positions.
+pub fn sma(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut result = vec![None; values.len()];
+    if period == 0 || values.len() < period {
+        return result;
+    }
+
+    let mut sum: f64 = values[..period].iter().sum();
+    result[period - 1] = Some(sum / period as f64);
+
+    for i in period..values.len() {
+        sum += values[i] - values[i - period];
+        result[i] = Some(sum / period as f64);
+    }
+
+    result
+}
+
+/// Relative Strength Index (RSI) sur `period` variations, lissage de Wilder
+///
+/// CONCEPT : Moyennes lissées des hausses et des baisses
+/// - Première moyenne : moyenne simple des `period` premières variations
+/// - Ensuite : moyenne = (moyenne × (period - 1) + variation) / period
+/// - RSI = 100 - 100 / (1 + hausse moyenne / baisse moyenne)
+///
+/// Retourne un Vec de même longueur que `values`, avec None pour les
+/// `period` premières positions (il faut period + 1 prix pour period variations)
+pub fn rsi(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut result = vec![None; values.len()];
+    if period == 0 || values.len() <= period {
+        return result;
+    }
+
+    let change = |i: usize| values[i] - values[i - 1];
+    let value = |gain: f64, loss: f64| {
+        if loss == 0.0 {
+            100.0
+        } else {
+            100.0 - 100.0 / (1.0 + gain / loss)
+        }
+    };
+
+    let mut avg_gain = (1..=period).map(|i| change(i).max(0.0)).sum::<f64>() / period as f64;
+    let mut avg_loss = (1..=period).map(|i| (-change(i)).max(0.0)).sum::<f64>() / period as f64;
+    result[period] = Some(value(avg_gain, avg_loss));
+
+    for (i, slot) in result.iter_mut().enumerate().skip(period + 1) {
+        avg_gain = (avg_gain * (period - 1) as f64 + change(i).max(0.0)) / period as f64;
+        avg_loss = (avg_loss * (period - 1) as f64 + (-change(i)).max(0.0)) / period as f64;
+        *slot = Some(value(avg_gain, avg_loss));
+    }
+
+    result
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sma_basic() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let result = sma(&values, 3);
+        assert_eq!(result, vec![None, None, Some(2.0), Some(3.0), Some(4.0)]);
+    }
+
+    #[test]
+    fn test_sma_period_too_long() {
+        let values = [1.0, 2.0];
+        assert_eq!(sma(&values, 5), vec![None, None]);
+        assert_eq!(sma(&values, 0), vec![None, None]);
+    }
+
+    #[test]
+    fn test_rsi() {
+        // Hausse continue : RSI à 100
+        let rising: Vec<f64> = (0..20).map(|i| i as f64).collect();
+        let result = rsi(&rising, 14);
+        assert!(result[13].is_none());
+        assert_eq!(result[14], Some(100.0));
+
+        // Alternance +1 / -1 : hausses et baisses moyennes égales → 50
+        let zigzag: Vec<f64> = (0..15).map(|i| (i % 2) as f64).collect();
+        let value = rsi(&zigzag, 14)[14].unwrap();
+        assert!((value - 50.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,332 @@
+// ============================================================================
+// Structure : Journal de transactions
+// ============================================================================
+// Historique des achats et ventes saisis par l'utilisateur, base du calcul
+// du prix de revient et des plus/moins-values (voir cost_basis.rs), et des
+// dépôts / retraits de liquidités du compte
+//
+// CONCEPTS RUST :
+// 1. Enum + serde : "kind": "buy" / "sell" dans le fichier JSON
+// 2. Parsing de saisie utilisateur avec Result (message d'erreur lisible)
+//
+// Fichier : ~/.config/lazywallet/journal.json
+// {
+//   "transactions": [
+//     { "symbol": "AAPL", "kind": "buy", "quantity": 10.0, "price": 150.0,
+//       "date": "2024-01-15T14:30:00Z" }
+//   ],
+//   "cash": [
+//     { "currency": "USD", "amount": 2000.0, "date": "2024-01-10T09:00:00Z" }
+//   ]
+// }
+//
+// CONCEPT : Solde de liquidités par devise
+// - Solde = dépôts - retraits - achats + ventes réglés dans la devise
+// - La devise de règlement d'une transaction est celle du ticker
+//   (AIR.PA → EUR, VOD.L en pence → GBP)
+// - Une devise n'a de solde que si au moins un dépôt ou retrait y a été
+//   saisi : un journal sans mouvement de liquidités ne crée pas de solde
+//   négatif pour les achats passés
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::currency::Currency;
+use crate::profile;
+
+/// Sens d'une transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionKind {
+    Buy,
+    Sell,
+}
+
+/// Une transaction (achat ou vente) sur un ticker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transaction {
+    /// Symbole du ticker (ex: "AAPL")
+    pub symbol: String,
+
+    /// Achat ou vente
+    pub kind: TransactionKind,
+
+    /// Quantité échangée (toujours positive)
+    pub quantity: f64,
+
+    /// Prix unitaire d'exécution
+    pub price: f64,
+
+    /// Date de la transaction
+    pub date: DateTime<Utc>,
+}
+
+impl Transaction {
+    /// Parse une saisie utilisateur de la forme "+10 150.5" ou "-5 180"
+    ///
+    /// - "+" ou pas de signe : achat, "-" : vente
+    /// - Le séparateur décimal peut être ',' ou '.'
+    /// - La date est l'instant de la saisie
+    pub fn parse_input(symbol: &str, input: &str) -> Result<Self> {
+        let mut parts = input.split_whitespace();
+        let (Some(quantity), Some(price), None) = (parts.next(), parts.next(), parts.next()) else {
+            anyhow::bail!("Format attendu : +quantité prix (achat) ou -quantité prix (vente)");
+        };
+
+        let (kind, quantity) = match quantity.strip_prefix('-') {
+            Some(rest) => (TransactionKind::Sell, rest),
+            None => (TransactionKind::Buy, quantity.trim_start_matches('+')),
+        };
+
+        let quantity: f64 = quantity
+            .replace(',', ".")
+            .parse()
+            .with_context(|| format!("Quantité invalide : \"{}\"", quantity))?;
+        let price: f64 = price
+            .replace(',', ".")
+            .parse()
+            .with_context(|| format!("Prix invalide : \"{}\"", price))?;
+
+        let valid = quantity.is_finite() && quantity > 0.0 && price.is_finite() && price >= 0.0;
+        if !valid {
+            anyhow::bail!("La quantité doit être positive et le prix non négatif");
+        }
+
+        Ok(Self {
+            symbol: symbol.to_string(),
+            kind,
+            quantity,
+            price,
+            date: Utc::now(),
+        })
+    }
+
+    /// Quantité signée (+ achat, - vente)
+    pub fn signed_quantity(&self) -> f64 {
+        match self.kind {
+            TransactionKind::Buy => self.quantity,
+            TransactionKind::Sell => -self.quantity,
+        }
+    }
+}
+
+/// Un dépôt (montant positif) ou un retrait (négatif) de liquidités
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CashMovement {
+    /// Code de la devise (ex: "EUR")
+    pub currency: String,
+
+    /// Montant signé (+ dépôt, - retrait)
+    pub amount: f64,
+
+    /// Date du mouvement
+    pub date: DateTime<Utc>,
+}
+
+impl CashMovement {
+    /// Parse une saisie utilisateur de la forme "+1000 EUR" ou "-250,5 usd"
+    ///
+    /// - "+" ou pas de signe : dépôt, "-" : retrait
+    /// - Devise absente : dollar
+    /// - La date est l'instant de la saisie
+    pub fn parse_input(input: &str) -> Result<Self> {
+        let mut parts = input.split_whitespace();
+        let (Some(amount), currency, None) = (parts.next(), parts.next(), parts.next()) else {
+            anyhow::bail!("Format attendu : +montant devise (dépôt) ou -montant devise (retrait)");
+        };
+
+        let amount: f64 = amount
+            .trim_start_matches('+')
+            .replace(',', ".")
+            .parse()
+            .with_context(|| format!("Montant invalide : \"{}\"", amount))?;
+        if !amount.is_finite() || amount == 0.0 {
+            anyhow::bail!("Le montant doit être non nul");
+        }
+
+        let currency = currency.unwrap_or("USD").to_uppercase();
+        if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_alphabetic()) {
+            anyhow::bail!("Devise invalide : \"{}\" (code à 3 lettres, ex: EUR)", currency);
+        }
+
+        Ok(Self {
+            currency,
+            amount,
+            date: Utc::now(),
+        })
+    }
+}
+
+/// Journal de toutes les transactions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Journal {
+    #[serde(default)]
+    pub transactions: Vec<Transaction>,
+
+    /// Dépôts et retraits de liquidités (dans l'ordre chronologique)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cash: Vec<CashMovement>,
+}
+
+impl Journal {
+    /// Chemin par défaut du journal
+    ///
+    /// - Linux : ~/.config/lazywallet/journal.json
+    /// - Profil : ~/.config/lazywallet/profiles/<nom>/journal.json
+    /// - Fallback : ./journal.json
+    pub fn default_path() -> PathBuf {
+        profile::config_dir()
+            .map(|dir| dir.join("journal.json"))
+            .unwrap_or_else(|| PathBuf::from("./journal.json"))
+    }
+
+    /// Charge le journal depuis un fichier JSON (absent = journal vide)
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Échec de la lecture de {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Format invalide dans {}", path.display()))
+    }
+
+    /// Sauvegarde le journal en JSON (crée le répertoire si besoin)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Échec de l'écriture de {}", path.display()))
+    }
+
+    /// Ajoute une transaction (le journal reste trié par date)
+    pub fn record(&mut self, transaction: Transaction) {
+        let index = self
+            .transactions
+            .partition_point(|t| t.date <= transaction.date);
+        self.transactions.insert(index, transaction);
+    }
+
+    /// Ajoute un dépôt ou un retrait (les mouvements restent triés par date)
+    pub fn record_cash(&mut self, movement: CashMovement) {
+        let index = self.cash.partition_point(|m| m.date <= movement.date);
+        self.cash.insert(index, movement);
+    }
+
+    /// Solde de liquidités de chaque devise ayant un dépôt ou un retrait
+    ///
+    /// Ordre de première apparition ; un solde peut être négatif (découvert)
+    pub fn cash_balances(&self) -> Vec<(String, f64)> {
+        let mut balances: Vec<(String, f64)> = Vec::new();
+        for movement in &self.cash {
+            match balances.iter_mut().find(|(currency, _)| *currency == movement.currency) {
+                Some((_, balance)) => *balance += movement.amount,
+                None => balances.push((movement.currency.clone(), movement.amount)),
+            }
+        }
+
+        for transaction in &self.transactions {
+            let (unit, factor) = Currency::from_symbol(&transaction.symbol).main_unit();
+            if let Some((_, balance)) = balances.iter_mut().find(|(currency, _)| currency == unit.code()) {
+                *balance -= transaction.signed_quantity() * transaction.price * factor;
+            }
+        }
+        balances
+    }
+
+    /// Transactions d'un symbole, dans l'ordre chronologique
+    pub fn for_symbol<'a>(&'a self, symbol: &'a str) -> impl Iterator<Item = &'a Transaction> + 'a {
+        self.transactions.iter().filter(move |t| t.symbol == symbol)
+    }
+
+    /// Symboles présents dans le journal (ordre de première apparition)
+    pub fn symbols(&self) -> Vec<&str> {
+        let mut symbols: Vec<&str> = Vec::new();
+        for transaction in &self.transactions {
+            if !symbols.contains(&transaction.symbol.as_str()) {
+                symbols.push(&transaction.symbol);
+            }
+        }
+        symbols
+    }
+
+    /// Quantité nette détenue d'après le journal (jamais négative)
+    pub fn net_quantity(&self, symbol: &str) -> f64 {
+        self.for_symbol(symbol)
+            .map(Transaction::signed_quantity)
+            .sum::<f64>()
+            .max(0.0)
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let buy = Transaction::parse_input("AAPL", "+10 150,5").unwrap();
+        assert_eq!(buy.kind, TransactionKind::Buy);
+        assert_eq!(buy.quantity, 10.0);
+        assert_eq!(buy.price, 150.5);
+
+        let sell = Transaction::parse_input("AAPL", "-2.5 180").unwrap();
+        assert_eq!(sell.kind, TransactionKind::Sell);
+        assert_eq!(sell.signed_quantity(), -2.5);
+
+        // Sans signe : achat
+        assert_eq!(Transaction::parse_input("AAPL", "3 100").unwrap().kind, TransactionKind::Buy);
+
+        assert!(Transaction::parse_input("AAPL", "10").is_err());
+        assert!(Transaction::parse_input("AAPL", "abc 10").is_err());
+        assert!(Transaction::parse_input("AAPL", "0 10").is_err());
+    }
+
+    #[test]
+    fn test_net_quantity() {
+        let mut journal = Journal::default();
+        journal.record(Transaction::parse_input("AAPL", "+10 100").unwrap());
+        journal.record(Transaction::parse_input("AAPL", "-4 120").unwrap());
+        journal.record(Transaction::parse_input("MSFT", "+1 300").unwrap());
+
+        assert_eq!(journal.net_quantity("AAPL"), 6.0);
+        assert_eq!(journal.symbols(), vec!["AAPL", "MSFT"]);
+    }
+
+    #[test]
+    fn test_cash_balances() {
+        let deposit = CashMovement::parse_input("+2000 usd").unwrap();
+        assert_eq!((deposit.currency.as_str(), deposit.amount), ("USD", 2000.0));
+        assert_eq!(CashMovement::parse_input("-250,5").unwrap().amount, -250.5);
+        assert!(CashMovement::parse_input("0 EUR").is_err());
+        assert!(CashMovement::parse_input("100 EURO").is_err());
+        assert!(CashMovement::parse_input("100 EUR x").is_err());
+
+        let mut journal = Journal::default();
+        journal.record(Transaction::parse_input("AAPL", "+10 100").unwrap());
+        journal.record(Transaction::parse_input("VOD.L", "+100 80").unwrap());
+        // Aucun mouvement de liquidités : aucun solde
+        assert!(journal.cash_balances().is_empty());
+
+        journal.record_cash(deposit);
+        journal.record_cash(CashMovement::parse_input("+500 GBP").unwrap());
+        journal.record(Transaction::parse_input("AAPL", "-4 150").unwrap());
+        journal.record(Transaction::parse_input("AIR.PA", "+1 150").unwrap());
+
+        // USD : 2000 - 10 × 100 + 4 × 150 ; GBP : 500 - 100 × 80p ; EUR non suivi
+        assert_eq!(journal.cash_balances(), vec![("USD".to_string(), 1600.0), ("GBP".to_string(), 420.0)]);
+    }
+}
@@ -0,0 +1,101 @@
+// ============================================================================
+// Outil de mesure entre deux chandeliers
+// ============================================================================
+// Comme la règle de TradingView : un chandelier d'ancrage, un chandelier
+// sous le curseur, et l'écart entre les deux :
+//
+//   📏 +5.23% (+$8.40) · 12 barres · 16j 6h
+//
+// CONCEPT : Clôture à clôture
+// - Variation = clôture du curseur - clôture de l'ancre
+// - L'ancre peut être après le curseur : la mesure se lit alors à rebours
+//   (l'ancre reste la référence)
+// - Barres = nombre d'intervalles entre les deux chandeliers
+// ============================================================================
+
+use chrono::Duration;
+
+use crate::models::OHLC;
+
+/// Nombre maximal de chandeliers affichés par le graphique (les plus récents)
+///
+/// Le curseur de mesure ne sort pas de cette fenêtre
+pub const MAX_VISIBLE_CANDLES: usize = 250;
+
+/// Écart mesuré entre deux chandeliers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    /// Variation absolue de la clôture
+    pub change: f64,
+
+    /// Variation en pourcentage (None si la clôture d'ancrage est nulle)
+    pub change_percent: Option<f64>,
+
+    /// Nombre de barres entre l'ancre et le curseur
+    pub bars: usize,
+
+    /// Temps écoulé entre les deux chandeliers
+    pub elapsed: Duration,
+}
+
+/// Mesure l'écart entre le chandelier `anchor` et le chandelier `cursor`
+///
+/// None si un des index sort de la série
+pub fn measure(candles: &[OHLC], anchor: usize, cursor: usize) -> Option<Measurement> {
+    let from = candles.get(anchor)?;
+    let to = candles.get(cursor)?;
+
+    let change = to.close - from.close;
+    Some(Measurement {
+        change,
+        change_percent: (from.close != 0.0).then(|| change / from.close * 100.0),
+        bars: anchor.abs_diff(cursor),
+        elapsed: (to.timestamp - from.timestamp).abs(),
+    })
+}
+
+/// Première position accessible au curseur (début de la fenêtre affichée)
+pub fn first_visible(len: usize) -> usize {
+    len.saturating_sub(MAX_VISIBLE_CANDLES)
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn candle(day: u32, close: f64) -> OHLC {
+        let timestamp = Utc.with_ymd_and_hms(2024, 3, day, 14, 30, 0).unwrap();
+        OHLC::new(timestamp, close, close, close, close, 0)
+    }
+
+    #[test]
+    fn test_measure_between_candles() {
+        let candles = vec![candle(1, 100.0), candle(4, 104.0), candle(5, 110.0)];
+
+        let forward = measure(&candles, 0, 2).unwrap();
+        assert_eq!(forward.change, 10.0);
+        assert_eq!(forward.change_percent, Some(10.0));
+        assert_eq!(forward.bars, 2);
+        assert_eq!(forward.elapsed, Duration::days(4));
+
+        // Curseur avant l'ancre : l'ancre reste la référence
+        let backward = measure(&candles, 2, 0).unwrap();
+        assert_eq!(backward.change, -10.0);
+        assert_eq!(backward.bars, 2);
+        assert_eq!(backward.elapsed, Duration::days(4));
+
+        assert_eq!(measure(&candles, 1, 1).unwrap().bars, 0);
+        assert!(measure(&candles, 0, 3).is_none());
+    }
+
+    #[test]
+    fn test_first_visible() {
+        assert_eq!(first_visible(30), 0);
+        assert_eq!(first_visible(MAX_VISIBLE_CANDLES + 40), 40);
+    }
+}
@@ -11,10 +11,36 @@
 pub mod ticker;         // Déclaration du module ticker (fichier ticker.rs)
 pub mod ohlc;           // Déclaration du module ohlc (fichier ohlc.rs)
 pub mod watchlist_item; // Déclaration du module watchlist_item (fichier watchlist_item.rs)
+pub mod indicators;     // Indicateurs techniques (SMA, ...)
+pub mod order_book;     // Carnet d'ordres (profondeur de marché crypto)
+pub mod portfolio;      // Positions détenues et résumé du portefeuille
+pub mod journal;        // Journal des transactions (achats / ventes)
+pub mod cost_basis;     // Prix de revient (FIFO, coût moyen) et P&L
+pub mod screener;       // Screeners prédéfinis (hausses, baisses, plus actifs)
+pub mod returns;        // Rendements par période (1D ... 1Y, YTD)
+pub mod tags;           // Tags libres des tickers (filtre du dashboard)
+pub mod notes;          // Note libre par ticker (fiche détaillée)
+pub mod measure;        // Mesure entre deux chandeliers (variation, barres, durée)
+pub mod validation;     // Validation des bougies reçues (réparer ou rejeter)
+pub mod currency;       // Devise de cotation (symbole, décimales)
+pub mod schedule;       // Séances des places boursières et heures calmes
+pub mod fear_greed;     // Indice Fear & Greed du marché crypto
+pub mod session_stats;  // Statistiques de la séance intraday (VWAP, amplitude)
+pub mod beta;           // Bêta et corrélation vs un indice de référence
+pub mod expression;     // Expressions de surveillance ("AAPL - 0.5 * MSFT")
+pub mod annotations;    // Annotations des graphiques (texte ancré sur un chandelier)
+pub mod analyst;        // Recommandations des analystes et objectif de cours
+pub mod saved_watchlist; // Watchlist modifiée dans la TUI (storage_format "toml")
+pub mod holders;        // Transactions des initiés et détenteurs institutionnels
+pub mod financials;     // Chiffre d'affaires, résultat net et free cash flow publiés
+pub mod crypto_stats;   // Capitalisation, volume 24h, offre et dominance d'une crypto
+pub mod dividends;      // Prochains détachements et paiements de dividendes
+pub mod economic_calendar; // Publications économiques du jour (CPI, FOMC, NFP)
 
 // Re-export des structures principales pour simplifier les imports
 // Au lieu de : use lazywallet::models::ticker::Ticker;
 // On peut faire : use lazywallet::models::Ticker;
-pub use ticker::Ticker;
-pub use ohlc::{Interval, LabelStrategy, OHLC, OHLCData, Timeframe};
+pub use ticker::{Ticker, TickerType};
+pub use ohlc::{DataGap, Interval, LabelStrategy, OHLC, OHLCData, Timeframe};
 pub use watchlist_item::WatchlistItem;
+pub use currency::Currency;
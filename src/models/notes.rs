@@ -0,0 +1,91 @@
+// ============================================================================
+// Structure : Notes
+// ============================================================================
+// Note libre par ticker ("thèse : croissance du cloud, revoir après les
+// résultats"), affichée sur la fiche détaillée
+//
+// Fichier : ~/.config/lazywallet/notes.json
+// {
+//   "notes": { "AAPL": "Thèse : services > 25% du CA" }
+// }
+//
+// Avec "storage_format": "toml" : notes.toml, une ligne par symbole (trié)
+//   [notes]
+//   AAPL = "Thèse : services > 25% du CA"
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::persist;
+use crate::profile;
+
+/// Note de chaque symbole
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Notes {
+    #[serde(default)]
+    pub notes: BTreeMap<String, String>,
+}
+
+impl Notes {
+    /// Chemin par défaut du fichier de notes
+    ///
+    /// - Linux : ~/.config/lazywallet/notes.json (notes.toml en TOML)
+    /// - Profil : ~/.config/lazywallet/profiles/<nom>/notes.json
+    /// - Fallback : ./notes.json
+    pub fn default_path() -> PathBuf {
+        let name = persist::file_name("notes");
+        profile::config_dir()
+            .map(|dir| dir.join(&name))
+            .unwrap_or_else(|| PathBuf::from(".").join(name))
+    }
+
+    /// Charge les notes (fichier absent = aucune note)
+    pub fn load(path: &Path) -> Result<Self> {
+        persist::load(path)
+    }
+
+    /// Sauvegarde les notes (crée le répertoire si besoin)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        persist::save(path, self).map(|_| ())
+    }
+
+    /// Note d'un symbole (None si aucune)
+    pub fn of(&self, symbol: &str) -> Option<&str> {
+        self.notes.get(symbol).map(String::as_str)
+    }
+
+    /// Remplace la note d'un symbole (texte vide : note supprimée)
+    pub fn set(&mut self, symbol: &str, note: &str) {
+        let note = note.trim();
+        if note.is_empty() {
+            self.notes.remove(symbol);
+        } else {
+            self.notes.insert(symbol.to_string(), note.to_string());
+        }
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_clear_note() {
+        let mut notes = Notes::default();
+        notes.set("AAPL", "  Thèse : services  ");
+        assert_eq!(notes.of("AAPL"), Some("Thèse : services"));
+        assert_eq!(notes.of("MSFT"), None);
+
+        notes.set("AAPL", " ");
+        assert_eq!(notes.of("AAPL"), None);
+        assert!(notes.notes.is_empty());
+    }
+}
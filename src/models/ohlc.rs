@@ -9,9 +9,14 @@
 // 3. u64 : unsigned 64 bits pour le volume (toujours positif)
 // ============================================================================
 
-use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use chrono::{DateTime, Datelike, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::models::measure::MAX_VISIBLE_CANDLES;
+use crate::models::schedule::{self, Exchange};
+
 /// Période de temps pour les données OHLC
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Timeframe {
@@ -39,6 +44,8 @@ pub enum Timeframe {
     TwoYears,
     /// 5 ans (1825 jours)
     FiveYears,
+    /// 10 ans (3650 jours)
+    TenYears,
 }
 
 impl Timeframe {
@@ -57,6 +64,7 @@ impl Timeframe {
             Timeframe::OneYear => 365,
             Timeframe::TwoYears => 730,
             Timeframe::FiveYears => 1825,
+            Timeframe::TenYears => 3650,
         }
     }
 
@@ -75,6 +83,7 @@ impl Timeframe {
             Timeframe::OneYear => "1Y",
             Timeframe::TwoYears => "2Y",
             Timeframe::FiveYears => "5Y",
+            Timeframe::TenYears => "10Y",
         }
     }
 }
@@ -90,8 +99,12 @@ impl Timeframe {
 /// - M5 (5 minutes) → affiche 7 jours
 /// - M30 (30 minutes) → affiche 14 jours
 /// - D1 (1 jour) → affiche 6 mois
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Interval {
+    /// 1 minute
+    M1,
+    /// 2 minutes
+    M2,
     /// 5 minutes
     M5,
     /// 15 minutes
@@ -100,12 +113,18 @@ pub enum Interval {
     M30,
     /// 1 heure
     H1,
+    /// 90 minutes
+    M90,
     /// 4 heures
     H4,
     /// 1 jour (daily)
     D1,
     /// 1 semaine (weekly)
     W1,
+    /// 1 mois (monthly)
+    MN1,
+    /// 3 mois (trimestre)
+    MN3,
 }
 
 /// Stratégie d'affichage des labels sur l'axe X
@@ -164,26 +183,100 @@ impl Interval {
     /// - Pas d'allocation, très efficace
     pub fn to_yahoo_string(&self) -> &'static str {
         match self {
+            Interval::M1 => "1m",
+            Interval::M2 => "2m",
             Interval::M5 => "5m",
             Interval::M15 => "15m",
             Interval::M30 => "30m",
             Interval::H1 => "1h",
-            Interval::H4 => "4h",
+            Interval::M90 => "90m",
+            // Yahoo n'accepte pas "4h" : bougies 1h agrégées localement
+            Interval::H4 => "1h",
             Interval::D1 => "1d",
             Interval::W1 => "1wk",
+            Interval::MN1 => "1mo",
+            Interval::MN3 => "3mo",
+        }
+    }
+
+    /// Début de la tranche de cet intervalle contenant `timestamp`
+    ///
+    /// - Intraday et 1d : multiples de la durée depuis minuit UTC
+    /// - 1w : lundi 00:00 UTC (l'epoch Unix tombe un jeudi, un simple modulo
+    ///   donnerait des semaines du jeudi au mercredi)
+    /// - 1mo / 3mo : premier jour du mois / du trimestre (durée variable)
+    pub fn bucket_start(&self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        let day_start = timestamp.date_naive().and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+
+        match self {
+            Interval::W1 => {
+                day_start - chrono::Duration::days(timestamp.weekday().num_days_from_monday() as i64)
+            }
+            Interval::MN1 | Interval::MN3 => {
+                let months = if *self == Interval::MN3 { 3 } else { 1 };
+                let month = timestamp.month0() - timestamp.month0() % months + 1;
+                timestamp
+                    .date_naive()
+                    .with_day(1)
+                    .and_then(|date| date.with_month(month))
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .map(|date| date.and_utc())
+                    .unwrap_or(day_start)
+            }
+            _ => {
+                let minutes = (timestamp - day_start).num_minutes();
+                day_start + chrono::Duration::minutes(minutes - minutes % self.minutes())
+            }
+        }
+    }
+
+    /// Intervalle réellement demandé à Yahoo Finance
+    ///
+    /// Différent de self quand Yahoo ne fournit pas l'intervalle : les
+    /// bougies reçues sont ensuite regroupées avec OHLCData::resample()
+    pub fn source_interval(&self) -> Interval {
+        match self {
+            Interval::H4 => Interval::H1,
+            other => *other,
         }
     }
 
     /// Retourne le label court pour l'affichage
     pub fn label(&self) -> &'static str {
         match self {
+            Interval::M1 => "1m",
+            Interval::M2 => "2m",
             Interval::M5 => "5m",
             Interval::M15 => "15m",
             Interval::M30 => "30m",
             Interval::H1 => "1h",
+            Interval::M90 => "90m",
             Interval::H4 => "4h",
             Interval::D1 => "1d",
             Interval::W1 => "1w",
+            Interval::MN1 => "1mo",
+            Interval::MN3 => "3mo",
+        }
+    }
+
+    /// Durée d'une chandelle en minutes
+    ///
+    /// 1mo / 3mo : durée maximale (31 jours par mois), un écart d'un mois
+    /// court n'est donc jamais pris pour un trou
+    pub fn minutes(&self) -> i64 {
+        match self {
+            Interval::M1 => 1,
+            Interval::M2 => 2,
+            Interval::M5 => 5,
+            Interval::M15 => 15,
+            Interval::M30 => 30,
+            Interval::H1 => 60,
+            Interval::M90 => 90,
+            Interval::H4 => 4 * 60,
+            Interval::D1 => 24 * 60,
+            Interval::W1 => 7 * 24 * 60,
+            Interval::MN1 => 31 * 24 * 60,
+            Interval::MN3 => 3 * 31 * 24 * 60,
         }
     }
 
@@ -195,25 +288,35 @@ impl Interval {
     /// - Objectif : 300-500 chandeliers de l'API, affichage des 250 derniers
     ///
     /// Calculs optimisés :
+    /// - 1m : 3j → actions: ~390 à ~1170, crypto: ~4320 (1j serait vide le
+    ///   week-end, un jour férié ou avant l'ouverture)
+    /// - 2m : 3j → actions: ~585, crypto: ~2160
     /// - 5m : 3j → actions: ~234, crypto: ~864
     /// - 15m : 14j → actions: ~364, crypto: ~1344
     /// - 30m : 30j → actions: ~390, crypto: ~1440
     /// - 1h : 30j → actions: ~195, crypto: ~720
-    /// - 4h : 60j (max API) → actions: ~98, crypto: ~360
+    /// - 90m : 60j → actions: ~180, crypto: ~960
+    /// - 4h : 1 an de bougies 1h regroupées par 4 → actions: ~500, crypto: ~2190
     /// - 1d : 2 ans → ~504 jours de trading
     /// - 1w : 5 ans → ~260 semaines
+    /// - 1mo : 10 ans → 120 mois ; 3mo : 10 ans → 40 trimestres
     ///
     /// Limitations Yahoo Finance :
     /// - Intraday (<1d) : max 60 jours
+    /// - 1m : max 7 jours
     pub fn default_timeframe(&self) -> Timeframe {
         match self {
+            Interval::M1 => Timeframe::ThreeDay,
+            Interval::M2 => Timeframe::ThreeDay,
             Interval::M5 => Timeframe::OneWeek,
             Interval::M15 => Timeframe::TwoWeeks,
             Interval::M30 => Timeframe::OneMonth,
             Interval::H1 => Timeframe::SixMonths,
+            Interval::M90 => Timeframe::TwoMonths,
             Interval::H4 => Timeframe::OneYear,
             Interval::D1 => Timeframe::TwoYears,
             Interval::W1 => Timeframe::FiveYears,
+            Interval::MN1 | Interval::MN3 => Timeframe::TenYears,
         }
     }
 
@@ -221,13 +324,15 @@ impl Interval {
     ///
     /// CONCEPT : Labels intelligents inspirés de Yahoo Finance
     /// - Chaque intervalle a une stratégie adaptée (heures rondes, jours, etc.)
-    /// - M5 : labels toutes les heures (09:00, 10:00, 11:00, ...)
+    /// - M1 / M2 / M5 : labels toutes les heures (09:00, 10:00, 11:00, ...)
     /// - M15 : labels toutes les 3h (09:00, 12:00, 15:00, ...)
     /// - M30 : labels toutes les 6h (00:00, 06:00, 12:00, 18:00)
     /// - H1 : labels tous les 2 jours (01/01, 03/01, 05/01, ...)
+    /// - M90 : labels tous les 3 jours
     /// - H4 : labels tous les mois (01/01, 01/02
     /// - D1 : labels tous les 2 mois (01/01, 01/03, 01/05, ...)
-    /// - W1 : labels tous les ans (Jan, Feb, Mar, ...)
+    /// - W1 / MN1 : labels tous les ans (2023, 2024, ...)
+    /// - MN3 : labels tous les 2 ans
     ///
     /// Structure à 3 lignes :
     /// - Ligne 1 : tick marks │
@@ -235,7 +340,7 @@ impl Interval {
     /// - Ligne 3 : dates
     pub fn x_axis_format(&self) -> AxisFormats {
         match self {
-            Interval::M5 => AxisFormats {
+            Interval::M1 | Interval::M2 | Interval::M5 => AxisFormats {
                 time_format: Some("%H:%M"),
                 date_format: "%d/%m",
                 label_strategy: LabelStrategy::RoundHours { interval_hours: 1 },
@@ -255,6 +360,11 @@ impl Interval {
                 date_format: "%d/%m",
                 label_strategy: LabelStrategy::RegularDays { interval_days: 2 },
             },
+            Interval::M90 => AxisFormats {
+                time_format: None,
+                date_format: "%d/%m",
+                label_strategy: LabelStrategy::RegularDays { interval_days: 3 },
+            },
             Interval::H4 => AxisFormats {
                 time_format: None,
                 date_format: "%b", // Month only
@@ -265,62 +375,104 @@ impl Interval {
                 date_format: "%b", // Month only
                 label_strategy: LabelStrategy::RegularMonths { interval_months: 1 },
             },
-            Interval::W1 => AxisFormats {
+            Interval::W1 | Interval::MN1 => AxisFormats {
                 time_format: None,
                 date_format: "%Y", // Year only
                 label_strategy: LabelStrategy::RegularYears { interval_years: 1 },
             },
+            Interval::MN3 => AxisFormats {
+                time_format: None,
+                date_format: "%Y",
+                label_strategy: LabelStrategy::RegularYears { interval_years: 2 },
+            },
         }
     }
 
     /// Retourne true si l'intervalle est intraday (affiche les heures)
     ///
     /// CONCEPT : Helper pour déterminer le type d'affichage
-    /// - Intraday : M5, M15, M30, H1, H4 (plusieurs chandelles par jour)
-    /// - Long terme : D1, W1 (une chandelle = un jour ou plus)
+    /// - Intraday : M1 ... H4 (plusieurs chandelles par jour)
+    /// - Long terme : D1, W1, MN1, MN3 (une chandelle = un jour ou plus)
     pub fn is_intraday(&self) -> bool {
-        matches!(
-            self,
-            Interval::M5 | Interval::M15 | Interval::M30 | Interval::H1 | Interval::H4
-        )
+        self.minutes() < 24 * 60
     }
 
     /// Retourne tous les intervalles disponibles (pour UI de sélection)
+    ///
+    /// Du plus fin au plus long (ordre du cycle de next / previous)
     pub fn all() -> Vec<Interval> {
         vec![
+            Interval::M1,
+            Interval::M2,
             Interval::M5,
             Interval::M15,
             Interval::M30,
             Interval::H1,
+            Interval::M90,
             Interval::H4,
             Interval::D1,
             Interval::W1,
+            Interval::MN1,
+            Interval::MN3,
         ]
     }
 
     /// Retourne l'intervalle suivant (cycle)
     pub fn next(&self) -> Interval {
         match self {
+            Interval::M1 => Interval::M2,
+            Interval::M2 => Interval::M5,
             Interval::M5 => Interval::M15,
             Interval::M15 => Interval::M30,
             Interval::M30 => Interval::H1,
-            Interval::H1 => Interval::H4,
+            Interval::H1 => Interval::M90,
+            Interval::M90 => Interval::H4,
             Interval::H4 => Interval::D1,
             Interval::D1 => Interval::W1,
-            Interval::W1 => Interval::M5, // Boucle
+            Interval::W1 => Interval::MN1,
+            Interval::MN1 => Interval::MN3,
+            Interval::MN3 => Interval::M1, // Boucle
+        }
+    }
+
+    /// Retourne l'intervalle plus fin associé (vue multi-timeframe)
+    ///
+    /// CONCEPT : Analyse top-down
+    /// - Le graphique du haut montre la tendance (ex: 1d)
+    /// - Celui du bas montre le détail (ex: 1h)
+    /// - M1 étant le plus fin disponible, on l'associe à M5
+    pub fn finer(&self) -> Interval {
+        match self {
+            Interval::M1 => Interval::M5,
+            Interval::M2 => Interval::M1,
+            Interval::M5 => Interval::M15,
+            Interval::M15 => Interval::M5,
+            Interval::M30 => Interval::M5,
+            Interval::H1 => Interval::M15,
+            Interval::M90 => Interval::M15,
+            Interval::H4 => Interval::H1,
+            Interval::D1 => Interval::H1,
+            Interval::W1 => Interval::D1,
+            Interval::MN1 => Interval::W1,
+            Interval::MN3 => Interval::MN1,
         }
     }
 
     /// Retourne l'intervalle précédent (cycle)
     pub fn previous(&self) -> Interval {
         match self {
-            Interval::M5 => Interval::W1, // Boucle
+            Interval::M1 => Interval::MN3, // Boucle
+            Interval::M2 => Interval::M1,
+            Interval::M5 => Interval::M2,
             Interval::M15 => Interval::M5,
             Interval::M30 => Interval::M15,
             Interval::H1 => Interval::M30,
-            Interval::H4 => Interval::H1,
+            Interval::M90 => Interval::H1,
+            Interval::H4 => Interval::M90,
             Interval::D1 => Interval::H4,
             Interval::W1 => Interval::D1,
+            Interval::MN1 => Interval::W1,
+            Interval::MN3 => Interval::MN1,
         }
     }
 }
@@ -416,6 +568,56 @@ impl OHLC {
     }
 }
 
+/// Trou dans une série : bougies attendues mais absentes
+///
+/// Exemple : une bougie 1h à 10:00 suivie d'une bougie à 13:00 → 2 bougies
+/// manquantes (11:00 et 12:00), suspension de cotation ou trou de l'API
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataGap {
+    /// Index de la première bougie après le trou
+    pub index: usize,
+
+    /// Nombre de bougies manquantes
+    pub missing: usize,
+}
+
+// ============================================================================
+// Taille maximale d'une série
+// ============================================================================
+// L'historique local (voir store.rs) grandit à chaque rafraîchissement : sans
+// limite, une série 5m suivie des mois garderait des centaines de milliers de
+// bougies en mémoire, relues et recalculées (indicateurs) à chaque fois
+//
+// CONCEPT : Éviction par blocs
+// - Les bougies restent dans un Vec contigu : &data.candles reste une slice
+//   pour le graphique et les indicateurs
+// - Retirer le début d'un Vec décale toute la série (O(n)) : la série peut
+//   dépasser la limite de 10 %, puis les plus anciennes partent d'un coup
+//   (coût amorti O(1) par bougie ajoutée)
+// ============================================================================
+
+/// Nombre maximal de bougies d'une série par défaut
+pub const DEFAULT_MAX_CANDLES: usize = 5_000;
+
+static MAX_CANDLES: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_CANDLES);
+
+/// Choisit le nombre maximal de bougies gardées par série (au démarrage)
+///
+/// Jamais moins que la fenêtre du graphique (MAX_VISIBLE_CANDLES)
+pub fn set_max_candles(max: usize) {
+    MAX_CANDLES.store(max.max(MAX_VISIBLE_CANDLES), Ordering::Relaxed);
+}
+
+/// Nombre maximal de bougies gardées par série
+pub fn max_candles() -> usize {
+    MAX_CANDLES.load(Ordering::Relaxed)
+}
+
+/// Dépassement toléré avant l'éviction d'un bloc de bougies
+fn eviction_slack(max: usize) -> usize {
+    (max / 10).max(1)
+}
+
 /// Collection de chandelles OHLC pour un ticker
 ///
 /// CONCEPT RUST : Vec<T>
@@ -434,11 +636,16 @@ pub struct OHLCData {
     pub timeframe: Timeframe,
 
     /// Liste des chandelles, triées par timestamp croissant
+    /// (les plus récentes seulement, voir max_candles)
     /// CONCEPT RUST : Ownership
     /// - OHLCData possède le Vec
     /// - Le Vec possède tous les OHLC
     /// - Quand OHLCData est drop, tout est libéré automatiquement
     pub candles: Vec<OHLC>,
+
+    /// Code de la devise de cotation ("USD", "EUR", "GBp"), None si inconnue
+    #[serde(default)]
+    pub currency: Option<String>,
 }
 
 impl OHLCData {
@@ -449,6 +656,7 @@ impl OHLCData {
             interval,
             timeframe,
             candles: Vec::new(),
+            currency: None,
         }
     }
 
@@ -467,8 +675,179 @@ impl OHLCData {
     /// CONCEPT RUST : mut self
     /// - Méthode qui modifie l'objet
     /// - Nécessite que l'appelant ait une référence mutable
+    ///
+    /// Au-delà de max_candles (+ 10 %), les plus anciennes sont retirées
     pub fn add_candle(&mut self, candle: OHLC) {
+        self.add_candle_bounded(candle, max_candles());
+    }
+
+    /// Ajoute une chandelle en gardant au plus `max` bougies (+ 10 %)
+    pub fn add_candle_bounded(&mut self, candle: OHLC, max: usize) {
         self.candles.push(candle);
+        if self.candles.len() > max + eviction_slack(max) {
+            self.keep_recent(max);
+        }
+    }
+
+    /// Remplace toutes les chandelles (triées), en gardant les max_candles
+    /// plus récentes
+    pub fn set_candles(&mut self, candles: Vec<OHLC>) {
+        self.candles = candles;
+        self.keep_recent(max_candles());
+    }
+
+    /// Fusionne une série rechargée dans celle-ci
+    ///
+    /// CONCEPT : Une bougie par tranche
+    /// - Rafraîchissement automatique et rechargement manuel se chevauchent :
+    ///   une bougie déjà connue peut revenir dans la nouvelle série
+    /// - Deux bougies de la même tranche (voir Interval::bucket_start) sont la
+    ///   même bougie : la plus récente reçue remplace l'autre, y compris la
+    ///   dernière bougie encore en formation (datée de la dernière cotation)
+    /// - Résultat trié par date, sans doublon, borné par max_candles
+    ///
+    /// Autre symbole ou autre intervalle : `new` remplace simplement la série
+    pub fn merge(&mut self, new: OHLCData) {
+        if new.symbol != self.symbol || new.interval != self.interval {
+            *self = new;
+            return;
+        }
+
+        let interval = self.interval;
+        self.timeframe = new.timeframe;
+        if new.currency.is_some() {
+            self.currency = new.currency;
+        }
+
+        // Tri stable : à tranche égale, les anciennes bougies puis les nouvelles
+        // (dans leur ordre d'arrivée), la dernière reçue l'emporte
+        let mut candles = std::mem::take(&mut self.candles);
+        candles.extend(new.candles);
+        candles.sort_by_cached_key(|candle| interval.bucket_start(candle.timestamp));
+
+        let mut merged: Vec<OHLC> = Vec::with_capacity(candles.len());
+        for candle in candles {
+            match merged.last_mut() {
+                Some(last) if interval.bucket_start(last.timestamp) == interval.bucket_start(candle.timestamp) => {
+                    *last = candle;
+                }
+                _ => merged.push(candle),
+            }
+        }
+        self.set_candles(merged);
+    }
+
+    /// Ne garde que les `count` chandelles les plus récentes
+    pub fn keep_recent(&mut self, count: usize) {
+        let excess = self.candles.len().saturating_sub(count);
+        self.candles.drain(..excess);
+    }
+
+    /// Les `count` chandelles les plus récentes (toutes s'il y en a moins)
+    pub fn recent(&self, count: usize) -> &[OHLC] {
+        &self.candles[self.candles.len().saturating_sub(count)..]
+    }
+
+    /// Prix de clôture de toutes les chandelles (entrée des indicateurs)
+    pub fn closes(&self) -> Vec<f64> {
+        self.candles.iter().map(|candle| candle.close).collect()
+    }
+
+    /// Regroupe les chandelles dans un intervalle plus long (5m → 15m, 1h → 4h, 1h → 1d...)
+    ///
+    /// CONCEPT : Agrégation OHLC
+    /// - Les bougies sont réparties dans des tranches de l'intervalle cible
+    ///   (voir Interval::bucket_start)
+    /// - open = premier open, close = dernier close, high / low = extrêmes,
+    ///   volume = somme
+    /// - La date d'une bougie agrégée est celle de sa première bougie source
+    ///   (ouverture réelle de la séance, pas le début théorique de la tranche)
+    ///
+    /// CONCEPT : Frontières de séance
+    /// - Les tranches intraday divisent la journée UTC : une bougie agrégée ne
+    ///   mélange jamais la fin d'une séance et le début de la suivante
+    /// - Une tranche sans bougie source (nuit, week-end) ne produit rien
+    ///
+    /// # Retourne
+    /// * `None` si `target` n'est pas un multiple de l'intervalle des données
+    ///   (impossible d'agréger 1h en 45m, ou de "désagréger" 1d en 1h)
+    pub fn resample(&self, target: Interval) -> Option<OHLCData> {
+        if target.minutes() % self.interval.minutes() != 0 {
+            return None;
+        }
+
+        let mut resampled = OHLCData::new(self.symbol.clone(), target, self.timeframe);
+        resampled.currency = self.currency.clone();
+        let mut current_bucket = None;
+
+        for candle in &self.candles {
+            let bucket = target.bucket_start(candle.timestamp);
+
+            match resampled.candles.last_mut() {
+                Some(last) if current_bucket == Some(bucket) => {
+                    last.high = last.high.max(candle.high);
+                    last.low = last.low.min(candle.low);
+                    last.close = candle.close;
+                    last.volume += candle.volume;
+                }
+                _ => {
+                    resampled.add_candle(candle.clone());
+                    current_bucket = Some(bucket);
+                }
+            }
+        }
+
+        Some(resampled)
+    }
+
+    /// Détecte les bougies manquantes en comparant les dates attendues et réelles
+    ///
+    /// CONCEPT : Trou vs fermeture du marché
+    /// - `continuous` (crypto, 24h/24) : tout écart supérieur à l'intervalle
+    ///   est un trou
+    /// - Sinon, seuls les écarts intraday au sein d'une même journée UTC
+    ///   comptent : la nuit, le week-end et les jours fériés sont normaux
+    /// - Journalier : une séance manque si la place était ouverte ce jour-là
+    ///   (calendrier des fériés, voir models/schedule.rs) ; sans calendrier
+    ///   fiable, jamais de trou
+    pub fn gaps(&self, continuous: bool) -> Vec<DataGap> {
+        let step = self.interval.minutes() * 60;
+        if !continuous && !self.interval.is_intraday() {
+            return match schedule::session_calendar(&self.symbol) {
+                Some(exchange) if self.interval == Interval::D1 => self.missing_sessions(&exchange),
+                _ => Vec::new(),
+            };
+        }
+
+        self.candles
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| continuous || pair[0].timestamp.date_naive() == pair[1].timestamp.date_naive())
+            .filter_map(|(i, pair)| {
+                let elapsed = (pair[1].timestamp - pair[0].timestamp).num_seconds();
+                let missing = (elapsed / step - 1).max(0) as usize;
+                (missing > 0).then_some(DataGap { index: i + 1, missing })
+            })
+            .collect()
+    }
+
+    /// Séances journalières manquantes entre deux bougies consécutives
+    fn missing_sessions(&self, exchange: &Exchange) -> Vec<DataGap> {
+        self.candles
+            .windows(2)
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                let first = exchange.session_date(pair[0].timestamp);
+                let last = exchange.session_date(pair[1].timestamp);
+                let missing = first
+                    .iter_days()
+                    .skip(1)
+                    .take_while(|date| *date < last)
+                    .filter(|date| exchange.is_trading_day(*date))
+                    .count();
+                (missing > 0).then_some(DataGap { index: i + 1, missing })
+            })
+            .collect()
     }
 
     /// Retourne le nombre de chandelles
@@ -531,44 +910,152 @@ impl OHLCData {
     /// - Gère les marchés fermés (utilise la dernière journée disponible)
     ///
     /// Algorithme :
-    /// 1. Si D1 ou W1 : chaque chandelle = 1 jour/semaine → utiliser change_percent()
+    /// 1. Si D1 ou W1 : chaque chandelle = 1 jour/semaine → open/close de la chandelle
     /// 2. Si intraday : trouver toutes les chandelles du dernier jour
     /// 3. Calculer : ((close_du_jour - open_du_jour) / open_du_jour) * 100
     pub fn daily_change_percent(&self) -> Option<f64> {
-        if self.candles.is_empty() {
+        let (day_open, day_close) = self.daily_open_close()?;
+
+        if day_open == 0.0 {
+            return None;
+        }
+
+        Some(((day_close - day_open) / day_open) * 100.0)
+    }
+
+    /// Variation journalière absolue (close_du_jour - open_du_jour)
+    ///
+    /// Utilisée pour les variations en pips (forex) ou en points (indices)
+    pub fn daily_change(&self) -> Option<f64> {
+        let (day_open, day_close) = self.daily_open_close()?;
+        Some(day_close - day_open)
+    }
+
+    /// Plus bas et plus haut de la dernière séance
+    ///
+    /// - Intraday : extrêmes des chandelles de la dernière journée (UTC)
+    /// - 1d / 1w : extrêmes de la dernière chandelle
+    pub fn session_range(&self) -> Option<(f64, f64)> {
+        let last = self.last()?;
+        if !self.interval.is_intraday() {
+            return Some((last.low, last.high));
+        }
+
+        let last_date = last.timestamp.date_naive();
+        let session = self.candles.iter().rev().take_while(|c| c.timestamp.date_naive() == last_date);
+        Some(session.fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), c| (low.min(c.low), high.max(c.high))))
+    }
+
+    /// Plus bas et plus haut des chandelles depuis `since` (ex: 52 semaines)
+    ///
+    /// None si aucune chandelle n'est assez récente
+    pub fn range_since(&self, since: DateTime<Utc>) -> Option<(f64, f64)> {
+        let recent = self.candles.iter().filter(|c| c.timestamp >= since);
+        recent.fold(None, |range, c| match range {
+            Some((low, high)) => Some((c.low.min(low), c.high.max(high))),
+            None => Some((c.low, c.high)),
+        })
+    }
+
+    /// Volume de la dernière séance (0 si le fournisseur n'en donne pas, ex: forex)
+    pub fn session_volume(&self) -> Option<u64> {
+        self.session_volumes().last().copied()
+    }
+
+    /// Volume moyen des séances précédant la dernière
+    ///
+    /// None s'il n'y a qu'une séance (pas de point de comparaison)
+    pub fn average_session_volume(&self) -> Option<u64> {
+        let volumes = self.session_volumes();
+        let (_, previous) = volumes.split_last()?;
+        if previous.is_empty() {
             return None;
         }
+        Some(previous.iter().sum::<u64>() / previous.len() as u64)
+    }
+
+    /// Volume échangé par séance, de la plus ancienne à la plus récente
+    ///
+    /// - Intraday : somme des chandelles d'une même journée (UTC)
+    /// - 1d / 1w : chaque chandelle est déjà une séance
+    fn session_volumes(&self) -> Vec<u64> {
+        let intraday = self.interval.is_intraday();
+        let mut volumes: Vec<u64> = Vec::new();
+        let mut current_date = None;
+
+        for candle in &self.candles {
+            let date = candle.timestamp.date_naive();
+            match volumes.last_mut() {
+                Some(total) if intraday && current_date == Some(date) => *total += candle.volume,
+                _ => {
+                    volumes.push(candle.volume);
+                    current_date = Some(date);
+                }
+            }
+        }
+        volumes
+    }
+
+    /// Variation en % de chaque séance (open → close), de la plus ancienne à
+    /// la plus récente
+    ///
+    /// - Intraday : open de la première chandelle d'une journée (UTC), close
+    ///   de la dernière
+    /// - 1d / 1w : chaque chandelle est déjà une séance
+    pub fn session_changes(&self) -> Vec<f64> {
+        let intraday = self.interval.is_intraday();
+        let mut sessions: Vec<(f64, f64)> = Vec::new();
+        let mut current_date = None;
+
+        for candle in &self.candles {
+            let date = candle.timestamp.date_naive();
+            match sessions.last_mut() {
+                Some((_, close)) if intraday && current_date == Some(date) => *close = candle.close,
+                _ => {
+                    sessions.push((candle.open, candle.close));
+                    current_date = Some(date);
+                }
+            }
+        }
+
+        sessions
+            .into_iter()
+            .map(|(open, close)| if open == 0.0 { 0.0 } else { (close - open) / open * 100.0 })
+            .collect()
+    }
+
+    /// Open et close de la dernière journée disponible
+    fn daily_open_close(&self) -> Option<(f64, f64)> {
+        let last_candle = self.last()?;
 
         // Pour les intervalles daily et weekly, la chandelle représente déjà une journée/semaine
         if matches!(self.interval, Interval::D1 | Interval::W1) {
-            return self.last().map(|c| c.change_percent());
+            return Some((last_candle.open, last_candle.close));
         }
 
         // Pour les intervalles intraday (M5, M15, M30, H1, H4)
-        // Trouver toutes les chandelles du dernier jour disponible
-        let last_candle = self.last()?;
+        // Open de la première chandelle du dernier jour disponible, Close de la dernière
         let last_date = last_candle.timestamp.date_naive();
-
-        // Filtrer les chandelles du même jour
-        let day_candles: Vec<&OHLC> = self
+        let day_open = self
             .candles
             .iter()
-            .filter(|c| c.timestamp.date_naive() == last_date)
-            .collect();
-
-        if day_candles.is_empty() {
-            return None;
-        }
+            .find(|c| c.timestamp.date_naive() == last_date)?
+            .open;
 
-        // Open de la première chandelle du jour, Close de la dernière
-        let day_open = day_candles.first()?.open;
-        let day_close = day_candles.last()?.close;
+        Some((day_open, last_candle.close))
+    }
+}
 
-        if day_open == 0.0 {
-            return None;
+#[cfg(test)]
+impl OHLCData {
+    /// Série journalière "TEST" d'une bougie plate par clôture (tests)
+    pub(crate) fn from_closes(closes: &[f64]) -> Self {
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let mut data = OHLCData::with_interval("TEST".to_string(), Interval::D1);
+        for (i, close) in closes.iter().enumerate() {
+            data.add_candle(OHLC::new(start + chrono::Duration::days(i as i64), *close, *close, *close, *close, 0));
         }
-
-        Some(((day_close - day_open) / day_open) * 100.0)
+        data
     }
 }
 
@@ -581,6 +1068,60 @@ mod tests {
     use super::*;
     use chrono::Utc;
 
+    #[test]
+    fn test_merge_dedups_and_replaces_forming_candle() {
+        use chrono::TimeZone;
+        let at = |hour: u32, minute: u32| Utc.with_ymd_and_hms(2024, 3, 4, hour, minute, 0).unwrap();
+        let series = |candles: &[(u32, u32, f64)]| {
+            let mut data = OHLCData::new("AAPL".to_string(), Interval::H1, Timeframe::OneWeek);
+            for &(hour, minute, close) in candles {
+                data.add_candle(OHLC::new(at(hour, minute), close, close, close, close, 1));
+            }
+            data
+        };
+
+        // Bougie de 15h encore en formation, datée de la dernière cotation (15:47)
+        let mut data = series(&[(13, 30, 1.0), (14, 30, 2.0), (15, 47, 3.0)]);
+        // Rechargement qui chevauche : 14:30 revient, 15:30 achève la bougie de 15h
+        data.merge(series(&[(16, 30, 5.0), (14, 30, 2.5), (15, 30, 4.0)]));
+
+        let closes = |data: &OHLCData| data.candles.iter().map(|c| (c.timestamp, c.close)).collect::<Vec<_>>();
+        let expected = vec![(at(13, 30), 1.0), (at(14, 30), 2.5), (at(15, 30), 4.0), (at(16, 30), 5.0)];
+        assert_eq!(closes(&data), expected);
+
+        // Merge identique : rien ne change
+        data.merge(series(&[(13, 30, 1.0), (14, 30, 2.5), (15, 30, 4.0), (16, 30, 5.0)]));
+        assert_eq!(closes(&data), expected);
+
+        // Autre intervalle : remplacement
+        let mut daily = OHLCData::new("AAPL".to_string(), Interval::D1, Timeframe::SixMonths);
+        daily.add_candle(OHLC::new(at(0, 0), 9.0, 9.0, 9.0, 9.0, 1));
+        data.merge(daily);
+        assert_eq!((data.interval, data.len()), (Interval::D1, 1));
+    }
+
+    #[test]
+    fn test_candle_limit_evicts_oldest_in_chunks() {
+        let start = Utc::now();
+        let mut data = OHLCData::new("TEST".to_string(), Interval::H1, Timeframe::OneWeek);
+        for i in 0..110 {
+            data.add_candle_bounded(OHLC::new(start + chrono::Duration::hours(i), 1.0, 1.0, 1.0, i as f64, 0), 100);
+        }
+        // 110 = 100 + 10 % : pas encore d'éviction
+        assert_eq!(data.len(), 110);
+
+        data.add_candle_bounded(OHLC::new(start + chrono::Duration::hours(110), 1.0, 1.0, 1.0, 110.0, 0), 100);
+        assert_eq!(data.len(), 100);
+        assert_eq!(data.candles[0].close, 11.0);
+        assert_eq!(data.last().unwrap().close, 110.0);
+        assert_eq!(data.closes().len(), 100);
+
+        assert_eq!(data.recent(3).iter().map(|c| c.close).collect::<Vec<_>>(), vec![108.0, 109.0, 110.0]);
+        assert_eq!(data.recent(500).len(), 100);
+        data.keep_recent(5);
+        assert_eq!(data.candles[0].close, 106.0);
+    }
+
     #[test]
     fn test_ohlc_bullish() {
         let ohlc = OHLC::new(Utc::now(), 100.0, 110.0, 95.0, 105.0, 1000);
@@ -621,20 +1162,198 @@ mod tests {
         assert_eq!(Interval::H1.to_yahoo_string(), "1h");
         assert_eq!(Interval::D1.to_yahoo_string(), "1d");
         assert_eq!(Interval::W1.to_yahoo_string(), "1wk");
+        assert_eq!(Interval::M1.to_yahoo_string(), "1m");
+        assert_eq!(Interval::M90.to_yahoo_string(), "90m");
+        assert_eq!(Interval::MN3.to_yahoo_string(), "3mo");
+
+        // 4h n'existe pas chez Yahoo : demandé en 1h
+        assert_eq!(Interval::H4.to_yahoo_string(), "1h");
+        assert_eq!(Interval::H4.source_interval(), Interval::H1);
+        assert_eq!(Interval::D1.source_interval(), Interval::D1);
+    }
+
+    #[test]
+    fn test_resample_hourly_to_4h() {
+        use chrono::TimeZone;
+
+        // Séance US : 13:30 → 19:30 UTC en bougies 1h
+        let mut hourly = OHLCData::new("AAPL".to_string(), Interval::H1, Timeframe::OneYear);
+        let start = Utc.with_ymd_and_hms(2024, 5, 2, 13, 30, 0).unwrap();
+        for i in 0..7 {
+            let open = 100.0 + i as f64;
+            let timestamp = start + chrono::Duration::hours(i);
+            hourly.add_candle(OHLC::new(timestamp, open, open + 2.0, open - 1.0, open + 1.0, 10));
+        }
+
+        let h4 = hourly.resample(Interval::H4).unwrap();
+        assert_eq!(h4.interval, Interval::H4);
+        assert_eq!(h4.timeframe, Timeframe::OneYear);
+        assert_eq!(h4.len(), 2);
+
+        // Tranche 12:00-16:00 : 13:30, 14:30, 15:30
+        let first = &h4.candles[0];
+        assert_eq!(first.timestamp, start);
+        assert_eq!((first.open, first.high, first.low, first.close), (100.0, 104.0, 99.0, 103.0));
+        assert_eq!(first.volume, 30);
+
+        // Tranche 16:00-20:00 : 16:30 → 19:30
+        let second = &h4.candles[1];
+        assert_eq!(second.timestamp, start + chrono::Duration::hours(3));
+        assert_eq!((second.open, second.close), (103.0, 107.0));
+        assert_eq!(second.volume, 40);
+    }
+
+    #[test]
+    fn test_resample_to_day_and_week() {
+        use chrono::TimeZone;
+
+        // Deux séances (jeudi et vendredi) puis le lundi suivant, en bougies 1h
+        let mut hourly = OHLCData::new("AAPL".to_string(), Interval::H1, Timeframe::SixMonths);
+        for day in [2, 3, 6] {
+            for hour in 14..20 {
+                let timestamp = Utc.with_ymd_and_hms(2024, 5, day, hour, 30, 0).unwrap();
+                let open = day as f64 * 10.0 + hour as f64;
+                hourly.add_candle(OHLC::new(timestamp, open, open + 1.0, open - 1.0, open + 0.5, 1));
+            }
+        }
+
+        let daily = hourly.resample(Interval::D1).unwrap();
+        assert_eq!(daily.len(), 3);
+        assert_eq!((daily.candles[0].open, daily.candles[0].close), (34.0, 39.5));
+        assert_eq!(daily.candles[0].volume, 6);
+
+        // Semaine du lundi 29 avril (jeudi + vendredi), puis celle du 6 mai
+        let weekly = daily.resample(Interval::W1).unwrap();
+        assert_eq!(weekly.len(), 2);
+        assert_eq!(weekly.candles[0].volume, 12);
+        assert_eq!(weekly.candles[1].timestamp, Utc.with_ymd_and_hms(2024, 5, 6, 14, 30, 0).unwrap());
+
+        // Agréger vers un intervalle plus fin est impossible
+        assert!(daily.resample(Interval::H1).is_none());
+    }
+
+    #[test]
+    fn test_resample_to_months() {
+        use chrono::TimeZone;
+
+        // Séances du 29 janvier au 2 février 2024, puis du 1er mars
+        let mut daily = OHLCData::new("AAPL".to_string(), Interval::D1, Timeframe::FiveYears);
+        for (month, day) in [(1, 29), (1, 30), (1, 31), (2, 1), (2, 2), (3, 1)] {
+            let timestamp = Utc.with_ymd_and_hms(2024, month, day, 14, 30, 0).unwrap();
+            let open = month as f64 * 100.0 + day as f64;
+            daily.add_candle(OHLC::new(timestamp, open, open + 1.0, open - 1.0, open + 0.5, 1));
+        }
+
+        let monthly = daily.resample(Interval::MN1).unwrap();
+        assert_eq!(monthly.len(), 3);
+        assert_eq!((monthly.candles[0].open, monthly.candles[0].close), (129.0, 131.5));
+        assert_eq!(monthly.candles[1].volume, 2);
+
+        // Février (29 jours) entre deux mois : pas un trou, même en continu
+        assert!(monthly.gaps(true).is_empty());
+
+        // Janvier à mars : un seul trimestre
+        let quarterly = monthly.resample(Interval::MN3).unwrap();
+        assert_eq!(quarterly.len(), 1);
+        assert_eq!(quarterly.candles[0].volume, 6);
+        assert!(monthly.resample(Interval::D1).is_none());
+    }
+
+    #[test]
+    fn test_gaps() {
+        use chrono::TimeZone;
+
+        let start = Utc.with_ymd_and_hms(2024, 3, 4, 14, 30, 0).unwrap();
+        let mut data = OHLCData::with_interval("AAPL".to_string(), Interval::H1);
+        // 14:30, 15:30, (16:30 et 17:30 manquent), 18:30, puis le lendemain
+        for hours in [0, 1, 4, 24] {
+            data.add_candle(OHLC::new(start + chrono::Duration::hours(hours), 1.0, 1.0, 1.0, 1.0, 0));
+        }
+
+        // Action : la nuit n'est pas un trou
+        assert_eq!(data.gaps(false), vec![DataGap { index: 2, missing: 2 }]);
+
+        // Crypto : la nuit manquante en est un
+        assert_eq!(
+            data.gaps(true),
+            vec![DataGap { index: 2, missing: 2 }, DataGap { index: 3, missing: 19 }]
+        );
+
+        // Journalier d'une action : deux séances consécutives
+        let daily = data.resample(Interval::D1).unwrap();
+        assert!(daily.gaps(false).is_empty());
+
+        // Journalier : week-end et 4 juillet normaux, mardi 9 manquant
+        let mut daily = OHLCData::with_interval("AAPL".to_string(), Interval::D1);
+        for day in [3, 5, 8, 10] {
+            let open = Utc.with_ymd_and_hms(2024, 7, day, 13, 30, 0).unwrap();
+            daily.add_candle(OHLC::new(open, 1.0, 1.0, 1.0, 1.0, 0));
+        }
+        assert_eq!(daily.gaps(false), vec![DataGap { index: 3, missing: 1 }]);
+
+        // Place sans calendrier complet : jamais de trou
+        daily.symbol = "0700.HK".to_string();
+        assert!(daily.gaps(false).is_empty());
+    }
+
+    #[test]
+    fn test_bucket_start() {
+        use chrono::TimeZone;
+
+        let timestamp = Utc.with_ymd_and_hms(2024, 5, 2, 14, 37, 0).unwrap(); // jeudi
+        assert_eq!(Interval::M15.bucket_start(timestamp), Utc.with_ymd_and_hms(2024, 5, 2, 14, 30, 0).unwrap());
+        assert_eq!(Interval::H4.bucket_start(timestamp), Utc.with_ymd_and_hms(2024, 5, 2, 12, 0, 0).unwrap());
+        assert_eq!(Interval::D1.bucket_start(timestamp), Utc.with_ymd_and_hms(2024, 5, 2, 0, 0, 0).unwrap());
+        assert_eq!(Interval::W1.bucket_start(timestamp), Utc.with_ymd_and_hms(2024, 4, 29, 0, 0, 0).unwrap());
+        assert_eq!(Interval::M90.bucket_start(timestamp), Utc.with_ymd_and_hms(2024, 5, 2, 13, 30, 0).unwrap());
+        assert_eq!(Interval::MN1.bucket_start(timestamp), Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap());
+        assert_eq!(Interval::MN3.bucket_start(timestamp), Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap());
+        let december = Utc.with_ymd_and_hms(2024, 12, 31, 23, 0, 0).unwrap();
+        assert_eq!(Interval::MN3.bucket_start(december), Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap());
     }
 
     #[test]
     fn test_interval_default_timeframe() {
         assert_eq!(Interval::M30.default_timeframe(), Timeframe::OneMonth);
-        assert_eq!(Interval::D1.default_timeframe(), Timeframe::SixMonths);
-        assert_eq!(Interval::W1.default_timeframe(), Timeframe::TwoYears);
+        assert_eq!(Interval::D1.default_timeframe(), Timeframe::TwoYears);
+        assert_eq!(Interval::W1.default_timeframe(), Timeframe::FiveYears);
+        assert_eq!(Interval::M1.default_timeframe(), Timeframe::ThreeDay);
+        assert_eq!(Interval::MN1.default_timeframe(), Timeframe::TenYears);
+        // Yahoo refuse les minutes au-delà de 60 jours (1h et 4h : 2 ans)
+        for interval in [Interval::M1, Interval::M2, Interval::M5, Interval::M15, Interval::M30, Interval::M90] {
+            assert!(interval.default_timeframe().to_days() <= 60, "{:?}", interval);
+        }
+        // 1m : la séance du vendredi reste dans la fenêtre un lundi matin,
+        // dans la limite de 7 jours de Yahoo
+        assert!((3..=7).contains(&Interval::M1.default_timeframe().to_days()));
     }
 
     #[test]
     fn test_interval_cycle() {
         assert_eq!(Interval::M5.next(), Interval::M15);
-        assert_eq!(Interval::M5.previous(), Interval::W1);
-        assert_eq!(Interval::W1.next(), Interval::M5); // Boucle
+        assert_eq!(Interval::M5.previous(), Interval::M2);
+        assert_eq!(Interval::H1.next(), Interval::M90);
+        assert_eq!(Interval::W1.next(), Interval::MN1);
+        assert_eq!(Interval::MN3.next(), Interval::M1); // Boucle
+        assert_eq!(Interval::M1.previous(), Interval::MN3);
+
+        // Le cycle suit all(), du plus fin au plus long
+        let all = Interval::all();
+        for pair in all.windows(2) {
+            assert_eq!(pair[0].next(), pair[1]);
+            assert_eq!(pair[1].previous(), pair[0]);
+            assert!(pair[0].minutes() < pair[1].minutes());
+        }
+    }
+
+    #[test]
+    fn test_interval_finer() {
+        assert_eq!(Interval::D1.finer(), Interval::H1);
+        assert_eq!(Interval::W1.finer(), Interval::D1);
+        // Jamais le même intervalle que le graphique principal
+        for interval in Interval::all() {
+            assert_ne!(interval.finer(), interval);
+        }
     }
 
     #[test]
@@ -642,7 +1361,7 @@ mod tests {
         let data = OHLCData::with_interval("BTC-USD".to_string(), Interval::H1);
         assert_eq!(data.symbol, "BTC-USD");
         assert_eq!(data.interval, Interval::H1);
-        assert_eq!(data.timeframe, Timeframe::OneMonth); // Default pour H1
+        assert_eq!(data.timeframe, Timeframe::SixMonths); // Default pour H1
     }
 
     #[test]
@@ -656,6 +1375,7 @@ mod tests {
         let change = data.daily_change_percent();
         assert!(change.is_some());
         assert_eq!(change.unwrap(), 5.0);
+        assert_eq!(data.daily_change(), Some(5.0));
     }
 
     #[test]
@@ -730,5 +1450,48 @@ mod tests {
         assert!(change.is_some());
         let change_value = change.unwrap();
         assert!((change_value - 4.545454).abs() < 0.001); // Vérification avec tolérance
+
+        // Volume du jour = somme des chandelles d'aujourd'hui, moyenne sur la veille
+        assert_eq!(data.session_volume(), Some(2300));
+        // Fourchette du jour : la veille (99 → 105) est ignorée
+        assert_eq!(data.session_range(), Some((109.0, 116.0)));
+        assert_eq!(data.average_session_volume(), Some(1000));
+
+        // Variation de chaque séance : +10% hier, +4.55% aujourd'hui
+        let changes = data.session_changes();
+        assert_eq!(changes.len(), 2);
+        assert!((changes[0] - 10.0).abs() < 0.001);
+        assert!((changes[1] - 4.545454).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_session_volume_daily() {
+        let mut data = OHLCData::new("AAPL".to_string(), Interval::D1, Timeframe::SixMonths);
+        assert_eq!(data.session_volume(), None);
+
+        data.add_candle(OHLC::new(Utc::now(), 1.0, 1.0, 1.0, 1.0, 3000));
+        assert_eq!(data.session_volume(), Some(3000));
+        assert_eq!(data.average_session_volume(), None);
+
+        // En 1d, chaque chandelle est une séance (même si les dates se répètent)
+        data.add_candle(OHLC::new(Utc::now(), 1.0, 1.0, 1.0, 1.0, 1000));
+        data.add_candle(OHLC::new(Utc::now(), 1.0, 1.0, 1.0, 1.0, 500));
+        assert_eq!(data.session_volume(), Some(500));
+        assert_eq!(data.average_session_volume(), Some(2000));
+    }
+
+    #[test]
+    fn test_range_since() {
+        use chrono::TimeZone;
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut data = OHLCData::new("AAPL".to_string(), Interval::W1, Timeframe::OneYear);
+        for (week, (low, high)) in [(80.0, 90.0), (95.0, 120.0), (100.0, 110.0)].into_iter().enumerate() {
+            data.add_candle(OHLC::new(start + chrono::Duration::weeks(week as i64), low, high, low, high, 0));
+        }
+
+        assert_eq!(data.range_since(start), Some((80.0, 120.0)));
+        // La première semaine sort de la période
+        assert_eq!(data.range_since(start + chrono::Duration::days(1)), Some((95.0, 120.0)));
+        assert_eq!(data.range_since(start + chrono::Duration::weeks(3)), None);
     }
 }
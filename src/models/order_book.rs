@@ -0,0 +1,127 @@
+// ============================================================================
+// Structure : OrderBook
+// ============================================================================
+// Carnet d'ordres (profondeur de marché) : offres d'achat (bids) et de
+// vente (asks) au meilleur prix
+//
+// CONCEPTS :
+// - Bids : triés par prix décroissant (meilleure offre d'achat en premier)
+// - Asks : triés par prix croissant (meilleure offre de vente en premier)
+// - Spread : écart entre le meilleur ask et le meilleur bid
+// ============================================================================
+
+use chrono::{DateTime, Utc};
+
+/// Un niveau de prix du carnet d'ordres
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BookLevel {
+    /// Prix du niveau
+    pub price: f64,
+
+    /// Quantité disponible à ce prix
+    pub quantity: f64,
+}
+
+/// Carnet d'ordres d'un ticker
+#[derive(Debug, Clone)]
+pub struct OrderBook {
+    /// Symbole du ticker dans la watchlist (ex: "BTC-USD")
+    pub symbol: String,
+
+    /// Offres d'achat, prix décroissants
+    pub bids: Vec<BookLevel>,
+
+    /// Offres de vente, prix croissants
+    pub asks: Vec<BookLevel>,
+
+    /// Instant de récupération
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OrderBook {
+    /// Crée un carnet d'ordres (les niveaux sont triés si nécessaire)
+    pub fn new(symbol: String, mut bids: Vec<BookLevel>, mut asks: Vec<BookLevel>) -> Self {
+        bids.sort_by(|a, b| b.price.total_cmp(&a.price));
+        asks.sort_by(|a, b| a.price.total_cmp(&b.price));
+
+        Self {
+            symbol,
+            bids,
+            asks,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Meilleure offre d'achat
+    pub fn best_bid(&self) -> Option<&BookLevel> {
+        self.bids.first()
+    }
+
+    /// Meilleure offre de vente
+    pub fn best_ask(&self) -> Option<&BookLevel> {
+        self.asks.first()
+    }
+
+    /// Écart entre le meilleur ask et le meilleur bid
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Spread en pourcentage du prix médian
+    pub fn spread_percent(&self) -> Option<f64> {
+        let bid = self.best_bid()?.price;
+        let ask = self.best_ask()?.price;
+        let mid = (bid + ask) / 2.0;
+        if mid == 0.0 {
+            return None;
+        }
+        Some((ask - bid) / mid * 100.0)
+    }
+
+    /// Quantité maximale sur les `depth` premiers niveaux des deux côtés
+    ///
+    /// Sert d'échelle commune pour les barres de taille
+    pub fn max_quantity(&self, depth: usize) -> f64 {
+        self.bids
+            .iter()
+            .take(depth)
+            .chain(self.asks.iter().take(depth))
+            .fold(0.0, |max, level| max.max(level.quantity))
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(price: f64, quantity: f64) -> BookLevel {
+        BookLevel { price, quantity }
+    }
+
+    #[test]
+    fn test_order_book_sorting_and_spread() {
+        let book = OrderBook::new(
+            "BTC-USD".to_string(),
+            vec![level(99.0, 1.0), level(100.0, 2.0)],
+            vec![level(102.0, 0.5), level(101.0, 3.0)],
+        );
+
+        assert_eq!(book.best_bid().unwrap().price, 100.0);
+        assert_eq!(book.best_ask().unwrap().price, 101.0);
+        assert_eq!(book.spread(), Some(1.0));
+        assert!((book.spread_percent().unwrap() - 0.995).abs() < 0.001);
+        assert_eq!(book.max_quantity(10), 3.0);
+        assert_eq!(book.max_quantity(1), 3.0);
+    }
+
+    #[test]
+    fn test_empty_order_book() {
+        let book = OrderBook::new("BTC-USD".to_string(), vec![], vec![]);
+        assert!(book.spread().is_none());
+        assert_eq!(book.max_quantity(10), 0.0);
+    }
+}
@@ -0,0 +1,493 @@
+// ============================================================================
+// Structure : Portfolio
+// ============================================================================
+// Positions détenues par l'utilisateur (symbole + quantité), soldes de
+// liquidités par devise et calcul du résumé du portefeuille à partir des
+// cotations de la watchlist
+//
+// CONCEPTS RUST :
+// 1. Serde : sérialisation JSON pour la persistance sur disque
+// 2. Iterators : agrégation des positions (sum, fold, min_by)
+// 3. Option : une position sans cotation est ignorée dans le résumé
+//
+// Fichier : ~/.config/lazywallet/portfolio.json
+// {
+//   "positions": [ { "symbol": "AAPL", "quantity": 10.0 } ],
+//   "cash": [ { "currency": "EUR", "amount": 1500.0 } ]
+// }
+//
+// CONCEPT : Comptes multi-devises
+// - Positions et liquidités sont converties en dollars avant d'être
+//   additionnées, avec les paires de change de la watchlist : EURUSD=X,
+//   à défaut USDEUR=X ou EUR=X (inversées)
+// - Sans paire dans la watchlist, le montant est compté tel quel (pence
+//   ramenés en livres)
+// - Les liquidités entrent dans la valeur totale et la répartition, sans
+//   variation du jour
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::models::currency::{usd_rate, Currency};
+use crate::models::{TickerType, WatchlistItem};
+use crate::profile;
+
+/// Une position détenue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Position {
+    /// Symbole du ticker (ex: "AAPL")
+    pub symbol: String,
+
+    /// Quantité détenue (fractionnaire pour les cryptos)
+    pub quantity: f64,
+}
+
+/// Solde de liquidités dans une devise
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CashBalance {
+    /// Code de la devise (ex: "EUR")
+    pub currency: String,
+
+    /// Montant (négatif en cas de découvert)
+    pub amount: f64,
+}
+
+/// Ensemble des positions de l'utilisateur
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Portfolio {
+    #[serde(default)]
+    pub positions: Vec<Position>,
+
+    /// Liquidités non investies, par devise (alignées sur le journal)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cash: Vec<CashBalance>,
+}
+
+/// Performance d'une position sur la journée
+#[derive(Debug, Clone, PartialEq)]
+pub struct Performer {
+    pub symbol: String,
+    pub change_percent: f64,
+}
+
+/// Résumé du portefeuille calculé avec les cotations actuelles
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortfolioSummary {
+    /// Valeur totale des positions cotées et des liquidités
+    pub total_value: f64,
+
+    /// Part des liquidités dans la valeur totale
+    pub cash: f64,
+
+    /// Variation du jour en devise
+    pub day_change: f64,
+
+    /// Variation du jour en pourcentage (par rapport à la valeur de la veille)
+    pub day_change_percent: f64,
+
+    /// Meilleure et pire position du jour
+    pub best: Option<Performer>,
+    pub worst: Option<Performer>,
+
+    /// Nombre de positions sans cotation (exclues du total)
+    pub unpriced: usize,
+}
+
+/// Part d'une catégorie dans le portefeuille (vue répartition)
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationSlice {
+    /// Ticker ou classe d'actif
+    pub label: String,
+
+    /// Valeur en devise
+    pub value: f64,
+
+    /// Part de la valeur totale (0-100)
+    pub percent: f64,
+}
+
+/// Montant converti en dollars (tel quel sans paire de change)
+pub fn to_usd(amount: f64, currency: &Currency, watchlist: &[WatchlistItem]) -> f64 {
+    let (unit, factor) = currency.main_unit();
+    let amount = amount * factor;
+    let price_of = |symbol: &str| watchlist.iter().find(|item| item.symbol == symbol)?.current_price();
+    usd_rate(unit.code(), price_of).map_or(amount, |rate| amount * rate)
+}
+
+/// Convertit des valeurs par catégorie en parts triées (plus grosse en premier)
+fn to_slices(values: Vec<(String, f64)>) -> Vec<AllocationSlice> {
+    let total: f64 = values.iter().map(|(_, value)| value).sum();
+    if total <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut slices: Vec<AllocationSlice> = values
+        .into_iter()
+        .map(|(label, value)| AllocationSlice {
+            label,
+            value,
+            percent: value / total * 100.0,
+        })
+        .collect();
+    slices.sort_by(|a, b| b.value.total_cmp(&a.value));
+    slices
+}
+
+impl Portfolio {
+    /// Chemin par défaut du fichier de portefeuille
+    ///
+    /// - Linux : ~/.config/lazywallet/portfolio.json
+    /// - Profil : ~/.config/lazywallet/profiles/<nom>/portfolio.json
+    /// - Fallback : ./portfolio.json
+    pub fn default_path() -> PathBuf {
+        profile::config_dir()
+            .map(|dir| dir.join("portfolio.json"))
+            .unwrap_or_else(|| PathBuf::from("./portfolio.json"))
+    }
+
+    /// Charge le portefeuille depuis un fichier JSON
+    ///
+    /// Un fichier absent donne un portefeuille vide (premier lancement)
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Échec de la lecture de {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Format invalide dans {}", path.display()))
+    }
+
+    /// Sauvegarde le portefeuille en JSON (crée le répertoire si besoin)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Échec de l'écriture de {}", path.display()))
+    }
+
+    /// Vérifie si aucune position ni liquidité n'est détenue
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty() && self.cash.is_empty()
+    }
+
+    /// Retourne la position d'un symbole
+    pub fn position(&self, symbol: &str) -> Option<&Position> {
+        self.positions.iter().find(|p| p.symbol == symbol)
+    }
+
+    /// Définit la quantité détenue pour un symbole
+    ///
+    /// Une quantité nulle (ou négative) supprime la position
+    pub fn set_position(&mut self, symbol: &str, quantity: f64) {
+        self.positions.retain(|p| p.symbol != symbol);
+
+        if quantity > 0.0 {
+            self.positions.push(Position {
+                symbol: symbol.to_string(),
+                quantity,
+            });
+        }
+    }
+
+    /// Remplace les soldes de liquidités (ceux du journal)
+    ///
+    /// Un solde nul supprime la devise
+    pub fn set_cash(&mut self, balances: Vec<(String, f64)>) {
+        self.cash = balances
+            .into_iter()
+            .filter(|(_, amount)| amount.abs() >= 0.005)
+            .map(|(currency, amount)| CashBalance { currency, amount })
+            .collect();
+    }
+
+    /// Valeur actuelle de chaque position cotée, en dollars (symbole, valeur)
+    fn position_values(&self, watchlist: &[WatchlistItem]) -> Vec<(String, f64)> {
+        self.positions
+            .iter()
+            .filter_map(|position| {
+                let item = watchlist.iter().find(|item| item.symbol == position.symbol)?;
+                let price = item.current_price()?;
+                Some((position.symbol.clone(), to_usd(position.quantity * price, &item.currency(), watchlist)))
+            })
+            .collect()
+    }
+
+    /// Valeur de chaque solde de liquidités, en dollars (devise, valeur)
+    fn cash_values(&self, watchlist: &[WatchlistItem]) -> Vec<(String, f64)> {
+        self.cash
+            .iter()
+            .map(|balance| {
+                let value = to_usd(balance.amount, &Currency::new(&balance.currency), watchlist);
+                (balance.currency.clone(), value)
+            })
+            .collect()
+    }
+
+    /// Répartition du portefeuille par ticker (liquidités : "Cash EUR")
+    ///
+    /// Un découvert n'a pas de part dans la répartition
+    pub fn allocation_by_ticker(&self, watchlist: &[WatchlistItem]) -> Vec<AllocationSlice> {
+        let mut values = self.position_values(watchlist);
+        values.extend(
+            self.cash_values(watchlist)
+                .into_iter()
+                .filter(|(_, value)| *value > 0.0)
+                .map(|(currency, value)| (format!("Cash {}", currency), value)),
+        );
+        to_slices(values)
+    }
+
+    /// Répartition du portefeuille par classe d'actif (actions, crypto, ETF, ...)
+    ///
+    /// La classe est le type d'actif de l'item (détecté à l'ajout), pas une
+    /// déduction du symbole : un ETF européen ("VWCE.DE") compte comme ETF
+    ///
+    /// CONCEPT : Agrégation avec un Vec de paires
+    /// - Peu de classes d'actif → une recherche linéaire suffit
+    /// - Conserve l'ordre d'apparition avant le tri final
+    pub fn allocation_by_asset_class(&self, watchlist: &[WatchlistItem]) -> Vec<AllocationSlice> {
+        let mut by_class: Vec<(TickerType, f64)> = Vec::new();
+
+        for (symbol, value) in self.position_values(watchlist) {
+            let class = watchlist
+                .iter()
+                .find(|item| item.symbol == symbol)
+                .map_or_else(|| TickerType::from_symbol(&symbol), WatchlistItem::ticker_type);
+            match by_class.iter_mut().find(|(c, _)| *c == class) {
+                Some((_, total)) => *total += value,
+                None => by_class.push((class, value)),
+            }
+        }
+
+        let mut values: Vec<(String, f64)> = by_class
+            .into_iter()
+            .map(|(class, value)| (class.label().to_string(), value))
+            .collect();
+        let cash: f64 = self
+            .cash_values(watchlist)
+            .into_iter()
+            .map(|(_, value)| value)
+            .filter(|value| *value > 0.0)
+            .sum();
+        if cash > 0.0 {
+            values.push(("Liquidités".to_string(), cash));
+        }
+        to_slices(values)
+    }
+
+    /// Calcule le résumé du portefeuille avec les cotations de la watchlist
+    ///
+    /// CONCEPT : Valeur de la veille
+    /// - La variation du jour est connue en % par ticker
+    /// - valeur_veille = valeur / (1 + variation/100)
+    /// - Le % global est pondéré par la valeur de chaque position
+    /// - Les liquidités comptent dans les deux valeurs (variation nulle)
+    ///
+    /// Retourne None si aucune position n'est cotée et sans liquidités
+    pub fn summary(&self, watchlist: &[WatchlistItem]) -> Option<PortfolioSummary> {
+        let mut total_value = 0.0;
+        let mut previous_value = 0.0;
+        let mut performers: Vec<Performer> = Vec::new();
+        let mut unpriced = 0;
+
+        for position in &self.positions {
+            let item = watchlist.iter().find(|item| item.symbol == position.symbol);
+            let price = item.and_then(|item| item.current_price());
+
+            let Some(price) = price else {
+                unpriced += 1;
+                continue;
+            };
+
+            let value = to_usd(position.quantity * price, &item.map(WatchlistItem::currency).unwrap_or_default(), watchlist);
+            let change_percent = item.and_then(|item| item.change_percent()).unwrap_or(0.0);
+
+            total_value += value;
+            previous_value += value / (1.0 + change_percent / 100.0);
+            performers.push(Performer {
+                symbol: position.symbol.clone(),
+                change_percent,
+            });
+        }
+
+        if performers.is_empty() && self.cash.is_empty() {
+            return None;
+        }
+
+        let cash: f64 = self.cash_values(watchlist).into_iter().map(|(_, value)| value).sum();
+        total_value += cash;
+        previous_value += cash;
+
+        let day_change = total_value - previous_value;
+        let day_change_percent = if previous_value > 0.0 {
+            day_change / previous_value * 100.0
+        } else {
+            0.0
+        };
+
+        let best = performers
+            .iter()
+            .max_by(|a, b| a.change_percent.total_cmp(&b.change_percent))
+            .cloned();
+        let worst = performers
+            .iter()
+            .min_by(|a, b| a.change_percent.total_cmp(&b.change_percent))
+            .cloned();
+
+        Some(PortfolioSummary {
+            total_value,
+            cash,
+            day_change,
+            day_change_percent,
+            best,
+            worst,
+            unpriced,
+        })
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interval, OHLCData, Timeframe, OHLC};
+    use chrono::Utc;
+
+    /// Crée un item avec une chandelle journalière open → close
+    fn item(symbol: &str, open: f64, close: f64) -> WatchlistItem {
+        let mut data = OHLCData::new(symbol.to_string(), Interval::D1, Timeframe::OneWeek);
+        data.add_candle(OHLC::new(Utc::now(), open, open.max(close), open.min(close), close, 1000));
+        WatchlistItem::with_data(symbol.to_string(), symbol.to_string(), data)
+    }
+
+    #[test]
+    fn test_set_position_replaces_and_removes() {
+        let mut portfolio = Portfolio::default();
+        portfolio.set_position("AAPL", 10.0);
+        portfolio.set_position("AAPL", 15.0);
+        assert_eq!(portfolio.positions.len(), 1);
+        assert_eq!(portfolio.position("AAPL").unwrap().quantity, 15.0);
+
+        portfolio.set_position("AAPL", 0.0);
+        assert!(portfolio.is_empty());
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut portfolio = Portfolio::default();
+        portfolio.set_position("AAPL", 10.0); // 100 → 110 : +10%
+        portfolio.set_position("MSFT", 5.0); // 200 → 190 : -5%
+        portfolio.set_position("TSLA", 1.0); // pas dans la watchlist
+
+        let watchlist = vec![item("AAPL", 100.0, 110.0), item("MSFT", 200.0, 190.0)];
+        let summary = portfolio.summary(&watchlist).unwrap();
+
+        // 10 × 110 + 5 × 190 = 2050, veille : 1000 + 1000 = 2000
+        assert!((summary.total_value - 2050.0).abs() < 1e-9);
+        assert!((summary.day_change - 50.0).abs() < 1e-9);
+        assert!((summary.day_change_percent - 2.5).abs() < 1e-9);
+        assert_eq!(summary.best.unwrap().symbol, "AAPL");
+        assert_eq!(summary.worst.unwrap().symbol, "MSFT");
+        assert_eq!(summary.unpriced, 1);
+    }
+
+    #[test]
+    fn test_allocation() {
+        let mut portfolio = Portfolio::default();
+        portfolio.set_position("AAPL", 3.0); // 300
+        portfolio.set_position("MSFT", 1.0); // 100
+        portfolio.set_position("BTC-USD", 0.01); // 400 (40 000 × 0.01)
+
+        let watchlist = vec![
+            item("AAPL", 100.0, 100.0),
+            item("MSFT", 100.0, 100.0),
+            item("BTC-USD", 40_000.0, 40_000.0),
+        ];
+
+        let by_ticker = portfolio.allocation_by_ticker(&watchlist);
+        assert_eq!(by_ticker[0].label, "BTC-USD");
+        assert!((by_ticker[0].percent - 50.0).abs() < 1e-9);
+        assert_eq!(by_ticker.len(), 3);
+
+        let by_class = portfolio.allocation_by_asset_class(&watchlist);
+        assert_eq!(by_class.len(), 2);
+        assert!(by_class.iter().all(|slice| (slice.percent - 50.0).abs() < 1e-9));
+
+        // Type détecté à l'ajout : MSFT compté comme ETF, pas comme action
+        let mut watchlist = watchlist;
+        watchlist[1].ticker_type = TickerType::ETF;
+        let by_class = portfolio.allocation_by_asset_class(&watchlist);
+        assert_eq!(by_class.len(), 3);
+        let etf = by_class.iter().find(|slice| slice.label == TickerType::ETF.label()).unwrap();
+        assert!((etf.percent - 12.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cash_and_currencies() {
+        let mut portfolio = Portfolio::default();
+        portfolio.set_position("AAPL", 10.0); // 100 → 110 : +10%
+        portfolio.set_position("AIR.PA", 10.0); // 150 € × 1.10 = 165 $
+        portfolio.set_cash(vec![
+            ("EUR".to_string(), 1000.0),
+            ("USD".to_string(), 735.0),
+            ("GBP".to_string(), 0.0),
+        ]);
+        assert_eq!(portfolio.cash.len(), 2);
+
+        let eurusd = item("EURUSD=X", 1.1, 1.1);
+        let watchlist = vec![item("AAPL", 100.0, 110.0), item("AIR.PA", 150.0, 150.0), eurusd];
+        let summary = portfolio.summary(&watchlist).unwrap();
+
+        // 1100 + 1650 + 1100 + 735 = 4585, veille : 1000 + 1650 + 1835
+        assert!((summary.cash - 1835.0).abs() < 1e-9);
+        assert!((summary.total_value - 4585.0).abs() < 1e-9);
+        assert!((summary.day_change - 100.0).abs() < 1e-9);
+
+        let by_ticker = portfolio.allocation_by_ticker(&watchlist);
+        assert_eq!(by_ticker[0].label, "AIR.PA");
+        assert!(by_ticker.iter().any(|slice| slice.label == "Cash EUR"));
+
+        let by_class = portfolio.allocation_by_asset_class(&watchlist);
+        let cash = by_class.iter().find(|slice| slice.label == "Liquidités").unwrap();
+        assert!((cash.percent - 1835.0 / 4585.0 * 100.0).abs() < 1e-9);
+
+        // Liquidités seules : résumé sans meilleure / pire position
+        portfolio.positions.clear();
+        let summary = portfolio.summary(&[]).unwrap();
+        assert!((summary.total_value - 1735.0).abs() < 1e-9);
+        assert!(summary.best.is_none());
+    }
+
+    #[test]
+    fn test_summary_without_quotes() {
+        let mut portfolio = Portfolio::default();
+        portfolio.set_position("AAPL", 10.0);
+        assert!(portfolio.summary(&[]).is_none());
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = std::env::temp_dir().join(format!("lazywallet_portfolio_{}.json", std::process::id()));
+        let mut portfolio = Portfolio::default();
+        portfolio.set_position("BTC-USD", 0.25);
+
+        portfolio.save(&path).unwrap();
+        assert_eq!(Portfolio::load(&path).unwrap(), portfolio);
+        let _ = std::fs::remove_file(&path);
+
+        // Fichier absent → portefeuille vide
+        assert!(Portfolio::load(&path).unwrap().is_empty());
+    }
+}
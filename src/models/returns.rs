@@ -0,0 +1,290 @@
+// ============================================================================
+// Rendements par période
+// ============================================================================
+// Calcule la performance d'un ticker sur plusieurs horizons à partir de son
+// historique journalier (D1, 2 ans chargés par défaut) :
+//
+//   1J    1S    1M    3M    6M    1A    YTD
+//   +0.8% +2.1% -3.4% +9.0% +12% +25% +7.5%
+//
+// CONCEPT : Date de référence
+// - Rendement = dernière clôture / clôture de référence - 1
+// - Référence = dernière chandelle dont la date est <= (dernière date - période)
+// - Historique trop court : pas de rendement (None) plutôt qu'une valeur fausse
+// ============================================================================
+
+use chrono::{DateTime, Datelike, Duration, Months, Utc};
+
+use crate::models::{Interval, OHLCData, WatchlistItem};
+
+/// Horizon de calcul d'un rendement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnPeriod {
+    OneDay,
+    OneWeek,
+    OneMonth,
+    ThreeMonths,
+    SixMonths,
+    OneYear,
+    /// Depuis la dernière clôture de l'année précédente (Year To Date)
+    YearToDate,
+}
+
+impl ReturnPeriod {
+    /// Toutes les périodes, dans l'ordre des colonnes du tableau
+    pub const ALL: [ReturnPeriod; 7] = [
+        ReturnPeriod::OneDay,
+        ReturnPeriod::OneWeek,
+        ReturnPeriod::OneMonth,
+        ReturnPeriod::ThreeMonths,
+        ReturnPeriod::SixMonths,
+        ReturnPeriod::OneYear,
+        ReturnPeriod::YearToDate,
+    ];
+
+    /// Périodes du classement de la watchlist (touches h / l)
+    pub const LEADERBOARD: [ReturnPeriod; 4] = [
+        ReturnPeriod::OneDay,
+        ReturnPeriod::OneWeek,
+        ReturnPeriod::OneMonth,
+        ReturnPeriod::YearToDate,
+    ];
+
+    /// Périodes de la vue comparaison (touches h / l)
+    pub const COMPARE: [ReturnPeriod; 6] = [
+        ReturnPeriod::OneWeek,
+        ReturnPeriod::OneMonth,
+        ReturnPeriod::ThreeMonths,
+        ReturnPeriod::SixMonths,
+        ReturnPeriod::OneYear,
+        ReturnPeriod::YearToDate,
+    ];
+
+    /// En-tête de colonne
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReturnPeriod::OneDay => "1D",
+            ReturnPeriod::OneWeek => "1W",
+            ReturnPeriod::OneMonth => "1M",
+            ReturnPeriod::ThreeMonths => "3M",
+            ReturnPeriod::SixMonths => "6M",
+            ReturnPeriod::OneYear => "1Y",
+            ReturnPeriod::YearToDate => "YTD",
+        }
+    }
+
+    /// Date limite de la chandelle de référence (None pour 1D, calculé à part)
+    pub fn reference_date(&self, last: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            ReturnPeriod::OneDay => None,
+            ReturnPeriod::OneWeek => Some(last - Duration::days(7)),
+            ReturnPeriod::OneMonth => last.checked_sub_months(Months::new(1)),
+            ReturnPeriod::ThreeMonths => last.checked_sub_months(Months::new(3)),
+            ReturnPeriod::SixMonths => last.checked_sub_months(Months::new(6)),
+            ReturnPeriod::OneYear => last.checked_sub_months(Months::new(12)),
+            // Dernière seconde de l'année précédente
+            ReturnPeriod::YearToDate => last
+                .with_ordinal(1)?
+                .date_naive()
+                .and_hms_opt(0, 0, 0)
+                .map(|start| start.and_utc() - Duration::seconds(1)),
+        }
+    }
+}
+
+/// Rendement (en %) sur une période, à partir de données journalières
+pub fn period_return(data: &OHLCData, period: ReturnPeriod) -> Option<f64> {
+    let last = data.last()?;
+
+    let reference = match period.reference_date(last.timestamp) {
+        // 1D : clôture précédente
+        None => data.candles.iter().rev().nth(1)?,
+        Some(date) => data.candles.iter().rev().find(|candle| candle.timestamp <= date)?,
+    };
+
+    (reference.close > 0.0).then(|| (last.close / reference.close - 1.0) * 100.0)
+}
+
+/// Rendements sur toutes les périodes (ordre de ReturnPeriod::ALL)
+pub fn all_returns(data: &OHLCData) -> Vec<Option<f64>> {
+    ReturnPeriod::ALL
+        .iter()
+        .map(|period| period_return(data, *period))
+        .collect()
+}
+
+/// Performance cumulée depuis `start`, en % : points (jours depuis `start`, %)
+///
+/// CONCEPT : Normalisation en base 0
+/// - Référence = dernière clôture à `start` (comme period_return), premier
+///   point à (0, 0 %) : toutes les courbes partent du même point
+/// - Abscisse en jours depuis `start` : une crypto (7 j / 7) et une action
+///   (5 j / 7) restent alignées sur le calendrier
+/// - Historique qui commence après `start` : None plutôt qu'une courbe tronquée
+pub fn normalized_performance(data: &OHLCData, start: DateTime<Utc>) -> Option<Vec<(f64, f64)>> {
+    let base_index = data.candles.partition_point(|candle| candle.timestamp <= start).checked_sub(1)?;
+    let base = data.candles[base_index].close;
+    if base <= 0.0 {
+        return None;
+    }
+
+    let mut points = vec![(0.0, 0.0)];
+    points.extend(data.candles[base_index + 1..].iter().map(|candle| {
+        let days = (candle.timestamp - start).num_seconds() as f64 / 86_400.0;
+        (days, (candle.close / base - 1.0) * 100.0)
+    }));
+    Some(points)
+}
+
+/// Force relative sur `days` jours calendaires, en points de % :
+/// rendement du ticker - rendement de l'indice sur la même fenêtre
+///
+/// CONCEPT : Même fenêtre pour les deux séries
+/// - Fenêtre = [dernière date du ticker - days, dernière date du ticker]
+/// - Chaque série prend sa dernière clôture à chaque borne : un indice qui
+///   ne cote pas le week-end reste comparable à une crypto
+/// - Historique trop court d'un côté ou de l'autre : None
+pub fn relative_strength(data: &OHLCData, benchmark: &OHLCData, days: usize) -> Option<f64> {
+    let end = data.last()?.timestamp;
+    let start = end - Duration::days(days as i64);
+    Some(return_between(data, start, end)? - return_between(benchmark, start, end)?)
+}
+
+/// Rendement (en %) entre les dernières clôtures à `start` et à `end`
+fn return_between(data: &OHLCData, start: DateTime<Utc>, end: DateTime<Utc>) -> Option<f64> {
+    let close_at = |date: DateTime<Utc>| {
+        let index = data.candles.partition_point(|candle| candle.timestamp <= date).checked_sub(1)?;
+        Some(data.candles[index].close)
+    };
+    let (base, last) = (close_at(start)?, close_at(end)?);
+    (base > 0.0).then(|| (last / base - 1.0) * 100.0)
+}
+
+/// Classement de la watchlist par rendement décroissant sur une période
+///
+/// Retourne (index dans la watchlist, rendement) ; les tickers sans
+/// historique D1 suffisant ferment la marche, dans l'ordre de la watchlist
+///
+/// CONCEPT RUST : sort_by stable
+/// - Deux rendements égaux gardent l'ordre de la watchlist
+pub fn leaderboard(items: &[WatchlistItem], period: ReturnPeriod) -> Vec<(usize, Option<f64>)> {
+    let mut ranking: Vec<(usize, Option<f64>)> = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| (index, item.data_for(Interval::D1).and_then(|data| period_return(data, period))))
+        .collect();
+    ranking.sort_by(|(_, a), (_, b)| match (a, b) {
+        (Some(a), Some(b)) => b.total_cmp(a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    ranking
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interval, OHLC};
+    use chrono::TimeZone;
+
+    /// Historique journalier : clôture = 100 + nombre de jours depuis le départ
+    fn daily_data(start: DateTime<Utc>, days: i64) -> OHLCData {
+        let mut data = OHLCData::with_interval("TEST".to_string(), Interval::D1);
+        for day in 0..days {
+            let close = 100.0 + day as f64;
+            data.add_candle(OHLC::new(start + Duration::days(day), close, close, close, close, 1000));
+        }
+        data
+    }
+
+    #[test]
+    fn test_period_returns() {
+        // Du 1er juillet 2023 au 31 mars 2024 (275 jours)
+        let start = Utc.with_ymd_and_hms(2023, 7, 1, 0, 0, 0).unwrap();
+        let data = daily_data(start, 275);
+        let last = 100.0 + 274.0;
+
+        let one_day = period_return(&data, ReturnPeriod::OneDay).unwrap();
+        assert!((one_day - (last / (last - 1.0) - 1.0) * 100.0).abs() < 1e-9);
+
+        let one_week = period_return(&data, ReturnPeriod::OneWeek).unwrap();
+        assert!((one_week - (last / (last - 7.0) - 1.0) * 100.0).abs() < 1e-9);
+
+        // YTD : référence au 31 décembre 2023 (jour 183)
+        let ytd = period_return(&data, ReturnPeriod::YearToDate).unwrap();
+        assert!((ytd - (last / 283.0 - 1.0) * 100.0).abs() < 1e-9);
+
+        // Historique trop court pour 1 an
+        assert!(period_return(&data, ReturnPeriod::OneYear).is_none());
+        assert_eq!(all_returns(&data).len(), ReturnPeriod::ALL.len());
+    }
+
+    #[test]
+    fn test_leaderboard() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let item = |symbol: &str, days: i64, step: f64| {
+            let mut data = OHLCData::with_interval(symbol.to_string(), Interval::D1);
+            for day in 0..days {
+                let close = 100.0 + step * day as f64;
+                data.add_candle(OHLC::new(start + Duration::days(day), close, close, close, close, 1000));
+            }
+            WatchlistItem::with_data(symbol.to_string(), symbol.to_string(), data)
+        };
+        let items = vec![
+            WatchlistItem::new("NEW".to_string(), "Sans données".to_string()),
+            item("SLOW", 40, 0.5),
+            item("DOWN", 40, -1.0),
+            item("FAST", 40, 2.0),
+        ];
+
+        let ranking = leaderboard(&items, ReturnPeriod::OneWeek);
+        let order: Vec<usize> = ranking.iter().map(|(index, _)| *index).collect();
+        assert_eq!(order, vec![3, 1, 2, 0]);
+        assert!(ranking[2].1.unwrap() < 0.0);
+        assert_eq!(ranking[3].1, None);
+    }
+
+    #[test]
+    fn test_normalized_performance() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let data = daily_data(start, 30);
+
+        // Référence : clôture du 11 janvier (110), prise à midi
+        let points = normalized_performance(&data, start + Duration::days(10) + Duration::hours(12)).unwrap();
+        assert_eq!(points[0], (0.0, 0.0));
+        assert_eq!(points.len(), 20);
+        assert!((points[1].0 - 0.5).abs() < 1e-9);
+        assert!((points[1].1 - (111.0 / 110.0 - 1.0) * 100.0).abs() < 1e-9);
+        assert!((points[19].1 - (129.0 / 110.0 - 1.0) * 100.0).abs() < 1e-9);
+
+        // Historique trop court
+        assert!(normalized_performance(&data, start - Duration::days(1)).is_none());
+    }
+
+    #[test]
+    fn test_relative_strength() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let data = daily_data(start, 30);
+
+        // Indice plat puis +10% sur les 10 derniers jours
+        let mut benchmark = OHLCData::with_interval("SPY".to_string(), Interval::D1);
+        for day in 0..30 {
+            let close = if day < 20 { 100.0 } else { 110.0 };
+            benchmark.add_candle(OHLC::new(start + Duration::days(day), close, close, close, close, 1000));
+        }
+
+        // Ticker : 119 -> 129 sur 10 jours
+        let rs = relative_strength(&data, &benchmark, 10).unwrap();
+        assert!((rs - ((129.0 / 119.0 - 1.0) * 100.0 - 10.0)).abs() < 1e-9);
+
+        // Indice sans historique suffisant
+        let short = daily_data(start + Duration::days(25), 5);
+        assert!(relative_strength(&data, &short, 10).is_none());
+        assert!(relative_strength(&data, &benchmark, 60).is_none());
+    }
+}
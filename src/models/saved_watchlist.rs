@@ -0,0 +1,148 @@
+// ============================================================================
+// Structure : SavedWatchlist
+// ============================================================================
+// Watchlist modifiée dans la TUI (touches a / d), sauvegardée avec
+// "storage_format": "toml" (voir persist.rs)
+//
+// Fichier : ~/.config/lazywallet/watchlist.toml
+//   [[tickers]]
+//   symbol = "AAPL"
+//   ticker_type = "Stock"
+//
+//   [[tickers]]
+//   symbol = "MC.PA"
+//   ticker_type = "Stock"
+//
+// - Un ticker par table ; l'ordre est celui du dashboard (choisi par
+//   l'utilisateur, donc gardé tel quel) : un ajout n'ajoute qu'une table
+// - Présent, le fichier remplace config.watchlist au démarrage
+// - Les symboles de la liste distante et les instruments CSV n'y sont pas :
+//   ils viennent de config.json
+// - ticker_type : type d'actif détecté à l'ajout (quoteType de Yahoo) ;
+//   absent (ancien fichier), il est déduit du symbole
+// ============================================================================
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::models::TickerType;
+use crate::persist;
+use crate::profile;
+
+/// Tickers suivis localement
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SavedWatchlist {
+    #[serde(default)]
+    pub tickers: Vec<SavedTicker>,
+}
+
+/// Ticker de la watchlist sauvegardée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedTicker {
+    pub symbol: String,
+
+    /// Type d'actif (None dans un fichier antérieur à ce champ)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ticker_type: Option<TickerType>,
+}
+
+impl SavedTicker {
+    /// Type d'actif enregistré, sinon déduit du symbole
+    pub fn ticker_type(&self) -> TickerType {
+        self.ticker_type.unwrap_or_else(|| TickerType::from_symbol(&self.symbol))
+    }
+}
+
+impl SavedWatchlist {
+    /// Watchlist des tickers donnés (symbole, type d'actif), dans cet ordre
+    pub fn from_tickers(tickers: &[(String, TickerType)]) -> Self {
+        Self {
+            tickers: tickers
+                .iter()
+                .map(|(symbol, ticker_type)| SavedTicker { symbol: symbol.clone(), ticker_type: Some(*ticker_type) })
+                .collect(),
+        }
+    }
+
+    /// Symboles, dans l'ordre du fichier
+    pub fn symbols(&self) -> Vec<String> {
+        self.tickers.iter().map(|ticker| ticker.symbol.clone()).collect()
+    }
+
+    /// Type d'actif de chaque symbole
+    pub fn ticker_types(&self) -> HashMap<String, TickerType> {
+        self.tickers.iter().map(|ticker| (ticker.symbol.clone(), ticker.ticker_type())).collect()
+    }
+
+    /// Chemin par défaut du fichier
+    ///
+    /// - Linux : ~/.config/lazywallet/watchlist.toml
+    /// - Profil : ~/.config/lazywallet/profiles/<nom>/watchlist.toml
+    /// - Fallback : ./watchlist.toml
+    pub fn default_path() -> PathBuf {
+        profile::config_dir()
+            .map(|dir| dir.join("watchlist.toml"))
+            .unwrap_or_else(|| PathBuf::from("./watchlist.toml"))
+    }
+
+    /// Charge la watchlist (None : fichier absent, config.watchlist s'applique)
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        persist::load(path).map(Some)
+    }
+
+    /// Sauvegarde la watchlist (fichier laissé tel quel s'il est inchangé)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        persist::save(path, self).map(|_| ())
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_load_keep_order() {
+        let dir = std::env::temp_dir().join(format!("lazywallet-watchlist-{}", std::process::id()));
+        let path = dir.join("watchlist.toml");
+        assert_eq!(SavedWatchlist::load(&path).unwrap(), None);
+
+        let symbols = vec!["MSFT".to_string(), "AAPL".to_string()];
+        let tickers = vec![(symbols[0].clone(), TickerType::Stock), (symbols[1].clone(), TickerType::Stock)];
+        SavedWatchlist::from_tickers(&tickers).save(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            "[[tickers]]\nsymbol = \"MSFT\"\nticker_type = \"Stock\"\n\n[[tickers]]\nsymbol = \"AAPL\"\nticker_type = \"Stock\"\n"
+        );
+        assert_eq!(SavedWatchlist::load(&path).unwrap().map(|saved| saved.symbols()), Some(symbols));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_ticker_type_saved_or_guessed() {
+        let dir = std::env::temp_dir().join(format!("lazywallet-watchlist-types-{}", std::process::id()));
+        let path = dir.join("watchlist.toml");
+
+        // Ancien fichier : type déduit du symbole
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "[[tickers]]\nsymbol = \"BTC-USD\"\n").unwrap();
+        let saved = SavedWatchlist::load(&path).unwrap().unwrap();
+        assert_eq!(saved.ticker_types().get("BTC-USD"), Some(&TickerType::Crypto));
+
+        // Type détecté (ETF que le symbole ne trahit pas) : relu tel quel
+        SavedWatchlist::from_tickers(&[("CW8.PA".to_string(), TickerType::ETF)]).save(&path).unwrap();
+        let saved = SavedWatchlist::load(&path).unwrap().unwrap();
+        assert_eq!(saved.ticker_types().get("CW8.PA"), Some(&TickerType::ETF));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
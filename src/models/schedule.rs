@@ -0,0 +1,613 @@
+// ============================================================================
+// Horaires : séances des places boursières et heures calmes
+// ============================================================================
+// Une action ne bouge pas quand sa place est fermée : inutile de la
+// recharger toutes les minutes la nuit ou le week-end
+//
+//   Place          Suffixes            Séance (heure locale)
+//   New York       (aucun), ^GSPC      09:30 – 16:00
+//   Toronto        .TO                 09:30 – 16:00
+//   Londres        .L, ^FTSE           08:00 – 16:30
+//   Euronext       .PA .AS .BR, ^FCHI  09:00 – 17:30
+//   Francfort      .DE .F, ^GDAXI      09:00 – 17:30
+//   Europe         .MI .MC .SW ...     09:00 – 17:30
+//   Tokyo          .T, ^N225           09:00 – 15:30
+//   Hong Kong      .HK, ^HSI           09:30 – 16:00
+//
+// - Crypto : marché continu, toujours ouvert
+// - Forex : du dimanche 22:00 au vendredi 22:00 (UTC)
+// - Pause déjeuner (Tokyo, Hong Kong) ignorée
+//
+// CONCEPT : Calendrier des jours fériés
+// - Embarqué et calculé par règles (date fixe, n-ième lundi, Pâques) :
+//   rien à télécharger ni à mettre à jour chaque année
+// - Un férié tombant le week-end est reporté selon l'usage de la place
+//   (vendredi ou lundi à New York, lundi à Londres, Toronto, Sydney)
+// - Calendrier complet pour New York, Toronto, Londres, Euronext,
+//   Francfort et Sydney ; ailleurs, fêtes fixes seulement (les fêtes
+//   lunaires, l'équinoxe japonais et les fêtes nationales des autres
+//   places européennes ne sont pas couvertes)
+// - Fermetures exceptionnelles et séances écourtées ignorées
+//
+// CONCEPT : Heure d'été sans base de fuseaux
+// - Chaque place a un décalage UTC d'hiver et une règle de changement
+//   d'heure (États-Unis, Europe, Australie ou aucune)
+// - La règle ne dépend que de la date : les séances n'ont jamais lieu à
+//   l'heure du changement (nuit du samedi au dimanche)
+//
+// Heures calmes (config "quiet_hours") : plage d'heures locales pendant
+// laquelle les alertes n'appellent ni webhook ni commande
+// ============================================================================
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Timelike, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::models::TickerType;
+
+/// Règle de changement d'heure d'une place
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dst {
+    /// Pas d'heure d'été (Tokyo, Hong Kong, Inde)
+    None,
+    /// Du 2e dimanche de mars au 1er dimanche de novembre
+    Us,
+    /// Du dernier dimanche de mars au dernier dimanche d'octobre
+    Eu,
+    /// Hémisphère sud : du 1er dimanche d'octobre au 1er dimanche d'avril
+    Au,
+}
+
+/// Jours fériés d'une place
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Calendar {
+    Us,
+    Canada,
+    Uk,
+    Euronext,
+    /// Euronext, plus le 24 et le 31 décembre
+    Xetra,
+    Australia,
+    /// Fêtes communes aux places européennes (celles d'Euronext)
+    Europe,
+    Japan,
+    HongKong,
+    Korea,
+    India,
+}
+
+/// Place boursière : fuseau, horaires de séance et jours fériés
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exchange {
+    /// Nom affiché ("New York", "Europe")
+    pub name: &'static str,
+    /// Décalage UTC d'hiver, en minutes
+    utc_offset: i64,
+    dst: Dst,
+    /// Ouverture et clôture, en minutes depuis minuit (heure locale)
+    open: u32,
+    close: u32,
+    calendar: Calendar,
+}
+
+const fn exchange(name: &'static str, utc_offset: i64, dst: Dst, open: u32, close: u32, calendar: Calendar) -> Exchange {
+    Exchange { name, utc_offset, dst, open, close, calendar }
+}
+
+const NEW_YORK: Exchange = exchange("New York", -300, Dst::Us, 9 * 60 + 30, 16 * 60, Calendar::Us);
+const TORONTO: Exchange = exchange("Toronto", -300, Dst::Us, 9 * 60 + 30, 16 * 60, Calendar::Canada);
+const LONDON: Exchange = exchange("Londres", 0, Dst::Eu, 8 * 60, 16 * 60 + 30, Calendar::Uk);
+const LISBON: Exchange = exchange("Lisbonne", 0, Dst::Eu, 8 * 60, 16 * 60 + 30, Calendar::Euronext);
+const EURONEXT: Exchange = exchange("Euronext", 60, Dst::Eu, 9 * 60, 17 * 60 + 30, Calendar::Euronext);
+const FRANKFURT: Exchange = exchange("Francfort", 60, Dst::Eu, 9 * 60, 17 * 60 + 30, Calendar::Xetra);
+const EUROPE: Exchange = exchange("Europe", 60, Dst::Eu, 9 * 60, 17 * 60 + 30, Calendar::Europe);
+const TOKYO: Exchange = exchange("Tokyo", 540, Dst::None, 9 * 60, 15 * 60 + 30, Calendar::Japan);
+const HONG_KONG: Exchange = exchange("Hong Kong", 480, Dst::None, 9 * 60 + 30, 16 * 60, Calendar::HongKong);
+const SEOUL: Exchange = exchange("Séoul", 540, Dst::None, 9 * 60, 15 * 60 + 30, Calendar::Korea);
+const MUMBAI: Exchange = exchange("Bombay", 330, Dst::None, 9 * 60 + 15, 15 * 60 + 30, Calendar::India);
+const SYDNEY: Exchange = exchange("Sydney", 600, Dst::Au, 10 * 60, 16 * 60, Calendar::Australia);
+
+/// Suffixes Yahoo de chaque place (New York sinon)
+const EXCHANGE_SUFFIXES: &[(&str, Exchange)] = &[
+    (".PA", EURONEXT),
+    (".AS", EURONEXT),
+    (".BR", EURONEXT),
+    (".DE", FRANKFURT),
+    (".F", FRANKFURT),
+    (".MI", EUROPE),
+    (".MC", EUROPE),
+    (".SW", EUROPE),
+    (".ST", EUROPE),
+    (".OL", EUROPE),
+    (".CO", EUROPE),
+    (".LS", LISBON),
+    (".L", LONDON),
+    (".TO", TORONTO),
+    (".T", TOKYO),
+    (".HK", HONG_KONG),
+    (".KS", SEOUL),
+    (".NS", MUMBAI),
+    (".AX", SYDNEY),
+];
+
+/// Indices hors New York
+const INDEX_EXCHANGES: &[(&str, Exchange)] = &[
+    ("^FCHI", EURONEXT),
+    ("^GDAXI", FRANKFURT),
+    ("^STOXX50E", EUROPE),
+    ("^AEX", EURONEXT),
+    ("^IBEX", EUROPE),
+    ("^SSMI", EUROPE),
+    ("^FTSE", LONDON),
+    ("^GSPTSE", TORONTO),
+    ("^N225", TOKYO),
+    ("^HSI", HONG_KONG),
+    ("^KS11", SEOUL),
+    ("^NSEI", MUMBAI),
+    ("^AXJO", SYDNEY),
+];
+
+/// n-ième jour de la semaine d'un mois (n = 1 : le premier)
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
+/// Dernier jour de la semaine d'un mois (dernier lundi de mai...)
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    (1..=5).rev().find_map(|n| nth_weekday(year, month, weekday, n))
+}
+
+fn nth_sunday(year: i32, month: u32, n: u8) -> Option<NaiveDate> {
+    nth_weekday(year, month, Weekday::Sun, n)
+}
+
+fn last_sunday(year: i32, month: u32) -> Option<NaiveDate> {
+    last_weekday(year, month, Weekday::Sun)
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Dimanche de Pâques (algorithme grégorien anonyme, dit de Meeus)
+fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// Report américain : samedi → vendredi, dimanche → lundi
+fn us_observed(date: Option<NaiveDate>) -> Option<NaiveDate> {
+    date.map(|date| match date.weekday() {
+        Weekday::Sat => date - Duration::days(1),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    })
+}
+
+/// Report au lundi suivant (Royaume-Uni, Canada, Australie)
+fn substitute(date: Option<NaiveDate>) -> Option<NaiveDate> {
+    date.map(|date| match date.weekday() {
+        Weekday::Sat => date + Duration::days(2),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    })
+}
+
+/// Noël et le lendemain, reportés sans se chevaucher (Noël un samedi :
+/// fermé lundi 27 et mardi 28)
+fn christmas_substitutes(year: i32) -> [Option<NaiveDate>; 2] {
+    let christmas = substitute(NaiveDate::from_ymd_opt(year, 12, 25));
+    let mut boxing_day = substitute(NaiveDate::from_ymd_opt(year, 12, 26));
+    if boxing_day == christmas {
+        boxing_day = boxing_day.map(|date| date + Duration::days(1));
+    }
+    [christmas, boxing_day]
+}
+
+impl Calendar {
+    /// Jours fériés d'une année (après report des week-ends)
+    fn holidays(&self, year: i32) -> Vec<NaiveDate> {
+        let date = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+        let easter = easter(year);
+        let good_friday = easter.map(|date| date - Duration::days(2));
+        let easter_monday = easter.map(|date| date + Duration::days(1));
+        let monday = |month, n| nth_weekday(year, month, Weekday::Mon, n);
+        let euronext = [date(1, 1), good_friday, easter_monday, date(5, 1), date(12, 25), date(12, 26)];
+
+        let holidays: Vec<Option<NaiveDate>> = match self {
+            Calendar::Us => vec![
+                // Jour de l'an un samedi : pas de report au 31 décembre
+                us_observed(date(1, 1)).filter(|date| date.year() == year),
+                monday(1, 3),
+                monday(2, 3),
+                good_friday,
+                last_weekday(year, 5, Weekday::Mon),
+                us_observed(date(6, 19)).filter(|_| year >= 2022),
+                us_observed(date(7, 4)),
+                monday(9, 1),
+                nth_weekday(year, 11, Weekday::Thu, 4),
+                us_observed(date(12, 25)),
+            ],
+            Calendar::Canada => {
+                // Fête de la Reine : le lundi précédant le 25 mai
+                let victoria_day = date(5, 24).map(|date| date - Duration::days(date.weekday().num_days_from_monday() as i64));
+                let mut holidays = vec![
+                    substitute(date(1, 1)),
+                    monday(2, 3),
+                    good_friday,
+                    victoria_day,
+                    substitute(date(7, 1)),
+                    monday(8, 1),
+                    monday(9, 1),
+                    monday(10, 2),
+                ];
+                holidays.extend(christmas_substitutes(year));
+                holidays
+            }
+            Calendar::Uk => {
+                let mut holidays = vec![
+                    substitute(date(1, 1)),
+                    good_friday,
+                    easter_monday,
+                    monday(5, 1),
+                    last_weekday(year, 5, Weekday::Mon),
+                    last_weekday(year, 8, Weekday::Mon),
+                ];
+                holidays.extend(christmas_substitutes(year));
+                holidays
+            }
+            Calendar::Euronext | Calendar::Europe => euronext.to_vec(),
+            Calendar::Xetra => {
+                let mut holidays = euronext.to_vec();
+                holidays.extend([date(12, 24), date(12, 31)]);
+                holidays
+            }
+            Calendar::Australia => {
+                let mut holidays = vec![
+                    substitute(date(1, 1)),
+                    substitute(date(1, 26)),
+                    good_friday,
+                    easter_monday,
+                    date(4, 25),
+                    monday(6, 2),
+                ];
+                holidays.extend(christmas_substitutes(year));
+                holidays
+            }
+            Calendar::Japan => vec![
+                date(1, 1),
+                date(1, 2),
+                date(1, 3),
+                monday(1, 2),
+                date(2, 11),
+                date(2, 23),
+                date(4, 29),
+                date(5, 3),
+                date(5, 4),
+                date(5, 5),
+                monday(7, 3),
+                monday(9, 3),
+                monday(10, 2),
+                date(11, 3),
+                date(11, 23),
+                date(12, 31),
+            ],
+            Calendar::HongKong => vec![
+                date(1, 1),
+                good_friday,
+                easter_monday,
+                date(5, 1),
+                date(7, 1),
+                date(10, 1),
+                date(12, 25),
+                date(12, 26),
+            ],
+            Calendar::Korea => vec![
+                date(1, 1),
+                date(3, 1),
+                date(5, 5),
+                date(6, 6),
+                date(8, 15),
+                date(10, 3),
+                date(10, 9),
+                date(12, 25),
+                date(12, 31),
+            ],
+            Calendar::India => vec![date(1, 26), date(5, 1), date(8, 15), date(10, 2), date(12, 25)],
+        };
+        holidays.into_iter().flatten().collect()
+    }
+
+    /// Vérifie si le calendrier couvre toutes les fermetures de la place
+    /// (sinon, une séance absente des données n'est pas forcément un trou)
+    fn is_complete(&self) -> bool {
+        matches!(
+            self,
+            Calendar::Us | Calendar::Canada | Calendar::Uk | Calendar::Euronext | Calendar::Xetra | Calendar::Australia
+        )
+    }
+}
+
+impl Dst {
+    /// Vérifie si l'heure d'été s'applique à une date locale
+    fn applies(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let between = |start: Option<NaiveDate>, end: Option<NaiveDate>| match (start, end) {
+            (Some(start), Some(end)) => date >= start && date < end,
+            _ => false,
+        };
+        match self {
+            Dst::None => false,
+            Dst::Us => between(nth_sunday(year, 3, 2), nth_sunday(year, 11, 1)),
+            Dst::Eu => between(last_sunday(year, 3), last_sunday(year, 10)),
+            // Été austral : à cheval sur deux années
+            Dst::Au => !between(nth_sunday(year, 4, 1), nth_sunday(year, 10, 1)),
+        }
+    }
+}
+
+impl Exchange {
+    /// Place de cotation d'un symbole (New York par défaut)
+    pub fn of(symbol: &str) -> Exchange {
+        let symbol = symbol.trim().to_uppercase();
+        if symbol.starts_with('^') {
+            return INDEX_EXCHANGES
+                .iter()
+                .find(|(index, _)| *index == symbol)
+                .map(|(_, exchange)| *exchange)
+                .unwrap_or(NEW_YORK);
+        }
+        EXCHANGE_SUFFIXES
+            .iter()
+            .find(|(suffix, _)| symbol.ends_with(suffix))
+            .map(|(_, exchange)| *exchange)
+            .unwrap_or(NEW_YORK)
+    }
+
+    /// Date et minute (depuis minuit) à l'heure locale de la place
+    fn local(&self, now: DateTime<Utc>) -> (NaiveDate, u32) {
+        let standard = now.naive_utc() + Duration::minutes(self.utc_offset);
+        let local = if self.dst.applies(standard.date()) { standard + Duration::hours(1) } else { standard };
+        (local.date(), local.hour() * 60 + local.minute())
+    }
+
+    /// Date de séance d'un instant (date locale de la place)
+    pub fn session_date(&self, timestamp: DateTime<Utc>) -> NaiveDate {
+        self.local(timestamp).0
+    }
+
+    /// Vérifie si une date (locale) est un jour férié de la place
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.calendar.holidays(date.year()).contains(&date)
+    }
+
+    /// Vérifie si la place tient séance ce jour (ni week-end, ni férié)
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        !is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Vérifie si la place est en séance
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        let (date, minute) = self.local(now);
+        self.is_trading_day(date) && (self.open..self.close).contains(&minute)
+    }
+}
+
+/// Date de séance d'un instant pour un symbole
+///
+/// Crypto et forex : jour UTC ; sinon, date locale de la place
+pub fn session_date(symbol: &str, timestamp: DateTime<Utc>) -> NaiveDate {
+    match TickerType::from_symbol(symbol) {
+        TickerType::Crypto | TickerType::Forex => timestamp.date_naive(),
+        _ => Exchange::of(symbol).session_date(timestamp),
+    }
+}
+
+/// Place dont le calendrier permet de repérer les séances journalières
+/// manquantes d'un symbole
+///
+/// None : crypto (continue), forex (pas de fériés) ou calendrier incomplet
+pub fn session_calendar(symbol: &str) -> Option<Exchange> {
+    match TickerType::from_symbol(symbol) {
+        TickerType::Crypto | TickerType::Forex => None,
+        _ => Some(Exchange::of(symbol)).filter(|exchange| exchange.calendar.is_complete()),
+    }
+}
+
+/// Forex : ouvert du dimanche 22:00 au vendredi 22:00 (UTC)
+fn forex_open(now: DateTime<Utc>) -> bool {
+    match now.weekday() {
+        Weekday::Sat => false,
+        Weekday::Sun => now.hour() >= 22,
+        Weekday::Fri => now.hour() < 22,
+        _ => true,
+    }
+}
+
+/// Vérifie si le marché d'un symbole est ouvert (crypto : toujours)
+pub fn is_market_open(symbol: &str, now: DateTime<Utc>) -> bool {
+    match TickerType::from_symbol(symbol) {
+        TickerType::Crypto => true,
+        TickerType::Forex => forex_open(now),
+        _ => Exchange::of(symbol).is_open(now),
+    }
+}
+
+/// Vérifie si un symbole est à recharger, selon l'ouverture de son marché
+///
+/// - Marché ouvert, ou jamais chargé : oui
+/// - Dernier chargement pendant la séance : oui, une fois, pour le cours
+///   de clôture
+/// - Marché fermé : toutes les `closed_every` (None : en pause jusqu'à
+///   la prochaine séance)
+pub fn refresh_due(
+    symbol: &str,
+    now: DateTime<Utc>,
+    last_refresh: Option<DateTime<Utc>>,
+    closed_every: Option<Duration>,
+) -> bool {
+    let Some(last) = last_refresh else {
+        return true;
+    };
+    if is_market_open(symbol, now) || is_market_open(symbol, last) {
+        return true;
+    }
+    closed_every.is_some_and(|every| now - last >= every)
+}
+
+// ============================================================================
+// Heures calmes
+// ============================================================================
+
+/// Plage d'heures locales sans notification externe ("22:00" → "07:00")
+///
+/// La plage peut passer minuit (début après la fin)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Vérifie si une heure tombe dans la plage
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_exchange_hours_follow_dst() {
+        // Mercredi 15 janvier 2025 : New York en UTC-5, Paris en UTC+1
+        assert!(!is_market_open("AAPL", utc(2025, 1, 15, 14, 0)));
+        assert!(is_market_open("AAPL", utc(2025, 1, 15, 14, 30)));
+        assert!(!is_market_open("AAPL", utc(2025, 1, 15, 21, 0)));
+        assert!(is_market_open("AIR.PA", utc(2025, 1, 15, 8, 0)));
+        assert!(!is_market_open("AIR.PA", utc(2025, 1, 15, 16, 30)));
+
+        // Mercredi 16 juillet 2025 : heure d'été (UTC-4, UTC+2)
+        assert!(is_market_open("AAPL", utc(2025, 7, 16, 13, 30)));
+        assert!(!is_market_open("AAPL", utc(2025, 7, 16, 20, 0)));
+        assert!(is_market_open("^FCHI", utc(2025, 7, 16, 7, 0)));
+        assert!(!is_market_open("^FCHI", utc(2025, 7, 16, 15, 30)));
+
+        // Mi-mars : New York déjà à l'heure d'été, pas encore Paris
+        assert!(is_market_open("AAPL", utc(2025, 3, 12, 13, 30)));
+        assert!(is_market_open("AIR.PA", utc(2025, 3, 12, 8, 0)));
+
+        // Tokyo : pas d'heure d'été, séance le matin en UTC
+        assert!(is_market_open("7203.T", utc(2025, 7, 16, 1, 0)));
+        assert!(!is_market_open("7203.T", utc(2025, 7, 16, 7, 0)));
+    }
+
+    #[test]
+    fn test_weekend_crypto_and_forex() {
+        // Samedi 18 janvier 2025
+        let saturday = utc(2025, 1, 18, 15, 0);
+        assert!(!is_market_open("AAPL", saturday));
+        assert!(!is_market_open("EURUSD=X", saturday));
+        assert!(is_market_open("BTC-USD", saturday));
+
+        // Forex : réouverture le dimanche soir
+        assert!(!is_market_open("EURUSD=X", utc(2025, 1, 19, 21, 0)));
+        assert!(is_market_open("EURUSD=X", utc(2025, 1, 19, 22, 0)));
+    }
+
+    #[test]
+    fn test_holidays() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        assert_eq!(easter(2025), Some(date(2025, 4, 20)));
+        assert_eq!(easter(2024), Some(date(2024, 3, 31)));
+
+        let new_york = Exchange::of("AAPL");
+        // Thanksgiving, Vendredi saint, 4 juillet un samedi (reporté au vendredi)
+        assert!(new_york.is_holiday(date(2025, 11, 27)));
+        assert!(new_york.is_holiday(date(2025, 4, 18)));
+        assert!(new_york.is_holiday(date(2026, 7, 3)));
+        // Jour de l'an 2022 un samedi : le 31 décembre reste ouvert
+        assert!(new_york.is_trading_day(date(2021, 12, 31)));
+        assert!(!new_york.is_trading_day(date(2025, 12, 25)));
+        assert!(new_york.is_trading_day(date(2025, 12, 26)));
+
+        // Londres : Noël 2021 un samedi, fermé lundi 27 et mardi 28
+        let london = Exchange::of("HSBA.L");
+        assert!(london.is_holiday(date(2021, 12, 27)));
+        assert!(london.is_holiday(date(2021, 12, 28)));
+        assert!(london.is_holiday(date(2025, 8, 25)));
+
+        // Euronext : lundi de Pâques ; Francfort : réveillon en plus
+        assert!(Exchange::of("AIR.PA").is_holiday(date(2025, 4, 21)));
+        assert!(!Exchange::of("AIR.PA").is_holiday(date(2025, 12, 24)));
+        assert!(Exchange::of("SAP.DE").is_holiday(date(2025, 12, 24)));
+
+        // Séance un jour férié : fermé, et pas de rechargement en boucle
+        let thanksgiving = utc(2025, 11, 27, 16, 0);
+        assert!(!is_market_open("AAPL", thanksgiving));
+        assert!(!refresh_due("AAPL", thanksgiving, Some(thanksgiving - Duration::minutes(5)), None));
+        assert!(is_market_open("BTC-USD", thanksgiving));
+
+        assert!(session_calendar("AAPL").is_some());
+        assert!(session_calendar("BTC-USD").is_none());
+        assert!(session_calendar("0700.HK").is_none());
+    }
+
+    #[test]
+    fn test_refresh_due() {
+        let open = utc(2025, 1, 15, 15, 0);
+        let evening = utc(2025, 1, 15, 23, 0);
+        let hour = Some(Duration::hours(1));
+
+        assert!(refresh_due("AAPL", open, Some(open - Duration::minutes(1)), None));
+        assert!(refresh_due("AAPL", evening, None, None));
+        // Dernier chargement en séance : un de plus pour la clôture
+        assert!(refresh_due("AAPL", evening, Some(open), None));
+        // Fermé et déjà à jour : en pause, ou ralenti
+        assert!(!refresh_due("AAPL", evening, Some(evening - Duration::minutes(5)), None));
+        assert!(!refresh_due("AAPL", evening, Some(evening - Duration::minutes(5)), hour));
+        assert!(refresh_due("AAPL", evening, Some(evening - Duration::minutes(61)), hour));
+        // Crypto : jamais en pause
+        assert!(refresh_due("BTC-USD", evening, Some(evening - Duration::minutes(1)), None));
+    }
+
+    #[test]
+    fn test_quiet_hours() {
+        let time = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+        let night: QuietHours = serde_json::from_str(r#"{ "start": "22:00", "end": "07:00" }"#).unwrap();
+        assert!(night.contains(time(23, 30)));
+        assert!(night.contains(time(6, 59)));
+        assert!(!night.contains(time(7, 0)));
+        assert!(!night.contains(time(12, 0)));
+
+        let lunch = QuietHours { start: time(12, 0), end: time(14, 0) };
+        assert!(lunch.contains(time(13, 0)));
+        assert!(!lunch.contains(time(14, 30)));
+    }
+}
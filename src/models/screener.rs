@@ -0,0 +1,98 @@
+// ============================================================================
+// Structure : Screeners prédéfinis
+// ============================================================================
+// Listes "découverte" calculées par Yahoo Finance (plus fortes hausses,
+// plus fortes baisses, plus gros volumes) : permettent de trouver des
+// tickers sans connaître leur symbole à l'avance
+// ============================================================================
+
+/// Screener prédéfini de Yahoo Finance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Screener {
+    /// Plus fortes hausses du jour
+    #[default]
+    DayGainers,
+
+    /// Plus fortes baisses du jour
+    DayLosers,
+
+    /// Plus gros volumes du jour
+    MostActives,
+}
+
+impl Screener {
+    /// Tous les screeners, dans l'ordre des onglets
+    pub const ALL: [Screener; 3] = [Screener::DayGainers, Screener::DayLosers, Screener::MostActives];
+
+    /// Identifiant Yahoo (paramètre scrIds)
+    pub fn scr_id(&self) -> &'static str {
+        match self {
+            Screener::DayGainers => "day_gainers",
+            Screener::DayLosers => "day_losers",
+            Screener::MostActives => "most_actives",
+        }
+    }
+
+    /// Libellé de l'onglet
+    pub fn label(&self) -> &'static str {
+        match self {
+            Screener::DayGainers => "Hausses",
+            Screener::DayLosers => "Baisses",
+            Screener::MostActives => "Plus actifs",
+        }
+    }
+
+    /// Screener suivant (avec bouclage)
+    pub fn next(&self) -> Self {
+        match self {
+            Screener::DayGainers => Screener::DayLosers,
+            Screener::DayLosers => Screener::MostActives,
+            Screener::MostActives => Screener::DayGainers,
+        }
+    }
+
+    /// Screener précédent (avec bouclage)
+    pub fn previous(&self) -> Self {
+        match self {
+            Screener::DayGainers => Screener::MostActives,
+            Screener::DayLosers => Screener::DayGainers,
+            Screener::MostActives => Screener::DayLosers,
+        }
+    }
+}
+
+/// Une ligne de résultat d'un screener
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenerQuote {
+    /// Symbole du ticker (ex: "NVDA")
+    pub symbol: String,
+
+    /// Nom court de l'entreprise
+    pub name: String,
+
+    /// Dernier prix
+    pub price: f64,
+
+    /// Variation du jour en pourcentage
+    pub change_percent: f64,
+
+    /// Volume échangé dans la journée
+    pub volume: Option<u64>,
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_screener_cycle() {
+        for screener in Screener::ALL {
+            assert_eq!(screener.next().previous(), screener);
+        }
+        assert_eq!(Screener::MostActives.next(), Screener::DayGainers);
+    }
+}
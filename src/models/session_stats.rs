@@ -0,0 +1,180 @@
+// ============================================================================
+// Statistiques de la séance en cours (intraday)
+// ============================================================================
+// Résumé de la dernière séance à partir des bougies intraday chargées :
+//
+//   Ouverture 271.20 · Haut 273.10 · Bas 269.85 · VWAP 271.90
+//   Volume 48.2M · Amplitude 3.25 (79% de la moyenne 20 j)
+//
+// CONCEPT : VWAP (Volume Weighted Average Price)
+// - Prix moyen pondéré par le volume : Σ(prix typique × volume) / Σ volume
+// - Prix typique d'une bougie = (haut + bas + clôture) / 3
+// - Sans volume (indices, forex) : pas de VWAP
+//
+// CONCEPT : Amplitude relative
+// - Amplitude = plus haut - plus bas de la séance
+// - Comparée à la moyenne des 20 séances précédentes : prise sur les
+//   bougies journalières si elles sont chargées, sinon sur les séances
+//   complètes présentes dans les bougies intraday
+// - Séance = date locale de la place (jour UTC pour crypto et forex,
+//   voir schedule::session_date)
+// ============================================================================
+
+use chrono::NaiveDate;
+
+use crate::models::schedule;
+use crate::models::{OHLCData, OHLC};
+
+/// Nombre de séances de la moyenne d'amplitude
+pub const AVERAGE_RANGE_SESSIONS: usize = 20;
+
+/// Statistiques d'une séance
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SessionStats {
+    /// Date de la séance (heure locale de la place)
+    pub date: NaiveDate,
+
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+
+    /// Dernier prix de la séance
+    pub last: f64,
+
+    /// Prix moyen pondéré par le volume (None sans volume)
+    pub vwap: Option<f64>,
+
+    /// Volume cumulé de la séance
+    pub volume: u64,
+
+    /// Moyenne des amplitudes des séances précédentes (None si aucune)
+    pub average_range: Option<f64>,
+}
+
+impl SessionStats {
+    /// Amplitude de la séance (plus haut - plus bas)
+    pub fn range(&self) -> f64 {
+        self.high - self.low
+    }
+
+    /// Variation depuis l'ouverture, en %
+    pub fn change_percent(&self) -> Option<f64> {
+        (self.open != 0.0).then(|| (self.last - self.open) / self.open * 100.0)
+    }
+
+    /// Amplitude rapportée à la moyenne (1.0 : séance ordinaire)
+    pub fn range_ratio(&self) -> Option<f64> {
+        self.average_range.filter(|average| *average > 0.0).map(|average| self.range() / average)
+    }
+}
+
+/// Amplitude de chaque séance, dans l'ordre des bougies
+fn session_ranges(symbol: &str, candles: &[OHLC]) -> Vec<(NaiveDate, f64, f64)> {
+    let mut sessions: Vec<(NaiveDate, f64, f64)> = Vec::new();
+    for candle in candles {
+        let date = schedule::session_date(symbol, candle.timestamp);
+        match sessions.last_mut() {
+            Some((last_date, high, low)) if *last_date == date => {
+                *high = high.max(candle.high);
+                *low = low.min(candle.low);
+            }
+            _ => sessions.push((date, candle.high, candle.low)),
+        }
+    }
+    sessions
+}
+
+/// Moyenne des amplitudes des séances antérieures à `date`
+fn average_range(symbol: &str, candles: &[OHLC], date: NaiveDate) -> Option<f64> {
+    let ranges: Vec<f64> = session_ranges(symbol, candles)
+        .into_iter()
+        .filter(|(session, _, _)| *session < date)
+        .map(|(_, high, low)| high - low)
+        .collect();
+    let recent = &ranges[ranges.len().saturating_sub(AVERAGE_RANGE_SESSIONS)..];
+    (!recent.is_empty()).then(|| recent.iter().sum::<f64>() / recent.len() as f64)
+}
+
+/// Statistiques de la dernière séance des bougies intraday
+///
+/// # Arguments
+/// * `intraday` - Bougies intraday chargées (None si l'intervalle est journalier ou plus)
+/// * `daily` - Bougies journalières, si chargées (moyenne d'amplitude sur 20 séances)
+pub fn session_stats(intraday: &OHLCData, daily: Option<&OHLCData>) -> Option<SessionStats> {
+    if !intraday.interval.is_intraday() {
+        return None;
+    }
+    let symbol = &intraday.symbol;
+    let date = schedule::session_date(symbol, intraday.last()?.timestamp);
+    let session: Vec<&OHLC> = intraday
+        .candles
+        .iter()
+        .filter(|candle| schedule::session_date(symbol, candle.timestamp) == date)
+        .collect();
+    let first = session.first()?;
+    let last = session.last()?;
+
+    let volume: u64 = session.iter().map(|candle| candle.volume).sum();
+    let weighted: f64 = session
+        .iter()
+        .map(|candle| (candle.high + candle.low + candle.close) / 3.0 * candle.volume as f64)
+        .sum();
+
+    let average_range = daily
+        .and_then(|daily| average_range(symbol, &daily.candles, date))
+        .or_else(|| average_range(symbol, &intraday.candles, date));
+
+    Some(SessionStats {
+        date,
+        open: first.open,
+        high: session.iter().map(|candle| candle.high).fold(f64::MIN, f64::max),
+        low: session.iter().map(|candle| candle.low).fold(f64::MAX, f64::min),
+        last: last.close,
+        vwap: (volume > 0).then(|| weighted / volume as f64),
+        volume,
+        average_range,
+    })
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Interval;
+    use chrono::{Duration, TimeZone, Utc};
+
+    #[test]
+    fn test_session_stats() {
+        // Deux séances AAPL (mardi et mercredi), bougies 1h depuis 13:30 UTC
+        let mut intraday = OHLCData::with_interval("AAPL".to_string(), Interval::H1);
+        let tuesday = Utc.with_ymd_and_hms(2024, 7, 9, 13, 30, 0).unwrap();
+        intraday.add_candle(OHLC::new(tuesday, 100.0, 104.0, 99.0, 103.0, 10));
+        let wednesday = tuesday + Duration::days(1);
+        intraday.add_candle(OHLC::new(wednesday, 103.0, 105.0, 102.0, 104.0, 100));
+        intraday.add_candle(OHLC::new(wednesday + Duration::hours(1), 104.0, 106.0, 103.0, 106.0, 300));
+
+        let stats = session_stats(&intraday, None).unwrap();
+        assert_eq!(stats.date, NaiveDate::from_ymd_opt(2024, 7, 10).unwrap());
+        assert_eq!((stats.open, stats.high, stats.low, stats.last), (103.0, 106.0, 102.0, 106.0));
+        assert_eq!(stats.volume, 400);
+        // (103.67 × 100 + 105 × 300) / 400
+        assert!((stats.vwap.unwrap() - 104.6667).abs() < 1e-3);
+        // Moyenne prise sur la séance de mardi (amplitude 5)
+        assert_eq!(stats.average_range, Some(5.0));
+        assert_eq!(stats.range_ratio(), Some(0.8));
+
+        // Journalier chargé : moyenne sur ses séances antérieures
+        let mut daily = OHLCData::with_interval("AAPL".to_string(), Interval::D1);
+        for (day, range) in [(5, 2.0), (8, 4.0), (9, 6.0), (10, 3.0)] {
+            let open = Utc.with_ymd_and_hms(2024, 7, day, 13, 30, 0).unwrap();
+            daily.add_candle(OHLC::new(open, 100.0, 100.0 + range, 100.0, 100.0, 0));
+        }
+        assert_eq!(session_stats(&intraday, Some(&daily)).unwrap().average_range, Some(4.0));
+
+        // Journalier : pas de statistiques de séance
+        assert!(session_stats(&daily, None).is_none());
+    }
+}
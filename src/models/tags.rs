@@ -0,0 +1,138 @@
+// ============================================================================
+// Structure : Tags
+// ============================================================================
+// Étiquettes libres posées sur les tickers ("dividend", "ai", "long") pour
+// filtrer la watchlist du dashboard
+//
+// CONCEPTS RUST :
+// 1. BTreeMap : symboles triés, fichier JSON stable d'une sauvegarde à l'autre
+// 2. Normalisation : un tag est en minuscules, sans '#' ni espace
+//
+// Fichier : ~/.config/lazywallet/tags.json
+// {
+//   "tags": { "AAPL": ["dividend", "long"], "NVDA": ["ai"] }
+// }
+//
+// Fichier à part plutôt que dans la watchlist (config.json ou
+// watchlist.toml) : les symboles de la liste distante et les instruments CSV
+// n'y sont jamais écrits, mais peuvent aussi porter des tags
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::profile;
+
+/// Tags de chaque symbole
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Tags {
+    #[serde(default)]
+    pub tags: BTreeMap<String, Vec<String>>,
+}
+
+impl Tags {
+    /// Chemin par défaut du fichier de tags
+    ///
+    /// - Linux : ~/.config/lazywallet/tags.json
+    /// - Profil : ~/.config/lazywallet/profiles/<nom>/tags.json
+    /// - Fallback : ./tags.json
+    pub fn default_path() -> PathBuf {
+        profile::config_dir()
+            .map(|dir| dir.join("tags.json"))
+            .unwrap_or_else(|| PathBuf::from("./tags.json"))
+    }
+
+    /// Charge les tags depuis un fichier JSON (fichier absent = aucun tag)
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Échec de la lecture de {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Format invalide dans {}", path.display()))
+    }
+
+    /// Sauvegarde les tags en JSON (crée le répertoire si besoin)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Échec de l'écriture de {}", path.display()))
+    }
+
+    /// Tags d'un symbole (vide si aucun)
+    pub fn of(&self, symbol: &str) -> &[String] {
+        self.tags.get(symbol).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Indique si un symbole porte un tag
+    pub fn has(&self, symbol: &str, tag: &str) -> bool {
+        self.of(symbol).iter().any(|t| t == tag)
+    }
+
+    /// Remplace les tags d'un symbole (liste vide : tags supprimés)
+    pub fn set(&mut self, symbol: &str, tags: Vec<String>) {
+        if tags.is_empty() {
+            self.tags.remove(symbol);
+        } else {
+            self.tags.insert(symbol.to_string(), tags);
+        }
+    }
+
+    /// Tous les tags utilisés, triés et sans doublon
+    pub fn all(&self) -> Vec<String> {
+        let mut all: Vec<String> = self.tags.values().flatten().cloned().collect();
+        all.sort();
+        all.dedup();
+        all
+    }
+}
+
+/// Lit une saisie de tags : "Dividend, #AI long" → ["dividend", "ai", "long"]
+///
+/// Séparateurs : espaces et virgules ; doublons ignorés (ordre conservé)
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for word in input.split([' ', ',']) {
+        let tag = word.trim().trim_start_matches('#').to_lowercase();
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_set_tags() {
+        assert_eq!(parse_tags("Dividend, #AI  long ai"), vec!["dividend", "ai", "long"]);
+        assert!(parse_tags(" , ").is_empty());
+
+        let mut tags = Tags::default();
+        tags.set("AAPL", parse_tags("dividend long"));
+        tags.set("NVDA", parse_tags("ai long"));
+        assert!(tags.has("AAPL", "long"));
+        assert!(!tags.has("AAPL", "ai"));
+        assert_eq!(tags.all(), vec!["ai", "dividend", "long"]);
+
+        tags.set("AAPL", Vec::new());
+        assert!(tags.of("AAPL").is_empty());
+        assert_eq!(tags.all(), vec!["ai", "long"]);
+    }
+}
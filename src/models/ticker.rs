@@ -17,8 +17,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::models::Interval;
+
 /// Type d'actif financier
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TickerType {
     Stock,      // Action (ex: AAPL, TSLA)
     Crypto,     // Cryptomonnaie (ex: BTC, ETH)
@@ -27,6 +29,133 @@ pub enum TickerType {
     Forex,      // Devise (ex: EURUSD)
 }
 
+/// ETFs courants reconnus par from_symbol()
+/// (Yahoo ne distingue pas un ETF d'une action dans le symbole)
+const KNOWN_ETFS: &[&str] = &[
+    "SPY", "QQQ", "VOO", "VTI", "IVV", "IWM", "DIA", "VEA", "VWO", "EFA", "AGG", "BND",
+    "TLT", "GLD", "SLV", "XLK", "XLF", "XLE", "XLV", "ARKK", "SCHD", "VT", "VXUS",
+];
+
+/// Paires de devises majeures proposées dans le menu d'ajout rapide
+/// (symbole Yahoo, libellé)
+pub const MAJOR_FOREX_PAIRS: &[(&str, &str)] = &[
+    ("EURUSD=X", "EUR/USD"),
+    ("GBPUSD=X", "GBP/USD"),
+    ("USDJPY=X", "USD/JPY"),
+    ("USDCHF=X", "USD/CHF"),
+    ("AUDUSD=X", "AUD/USD"),
+    ("USDCAD=X", "USD/CAD"),
+    ("NZDUSD=X", "NZD/USD"),
+    ("EURGBP=X", "EUR/GBP"),
+    ("EURJPY=X", "EUR/JPY"),
+    ("EURCHF=X", "EUR/CHF"),
+];
+
+/// Symboles populaires proposés quand la watchlist est vide
+/// (symbole Yahoo, libellé)
+pub const POPULAR_SYMBOLS: &[(&str, &str)] = &[
+    ("AAPL", "Apple"),
+    ("MSFT", "Microsoft"),
+    ("NVDA", "NVIDIA"),
+    ("TSLA", "Tesla"),
+    ("SPY", "S&P 500 ETF"),
+    ("BTC-USD", "Bitcoin"),
+    ("ETH-USD", "Ethereum"),
+    ("EURUSD=X", "EUR/USD"),
+];
+
+/// Devises de cotation des paires crypto sur Yahoo (ex: "BTC-USD")
+pub(crate) const CRYPTO_QUOTES: &[&str] = &["USD", "USDT", "USDC", "EUR", "GBP", "BTC", "ETH"];
+
+impl TickerType {
+    /// Devine le type d'actif à partir du symbole Yahoo
+    ///
+    /// CONCEPT : Heuristique sur la forme du symbole
+    /// - "^GSPC" : préfixe ^ → indice
+    /// - "EURUSD=X" : suffixe =X → devise
+    /// - "BTC-USD" : BASE-DEVISE → crypto (mais "BRK-B" reste une action)
+    /// - liste d'ETFs courants, sinon action
+    pub fn from_symbol(symbol: &str) -> Self {
+        let symbol = symbol.trim().to_uppercase();
+
+        if symbol.starts_with('^') {
+            return TickerType::Index;
+        }
+        if symbol.ends_with("=X") {
+            return TickerType::Forex;
+        }
+        if let Some((_, quote)) = symbol.split_once('-') {
+            if CRYPTO_QUOTES.contains(&quote) {
+                return TickerType::Crypto;
+            }
+        }
+        if KNOWN_ETFS.contains(&symbol.as_str()) {
+            return TickerType::ETF;
+        }
+
+        TickerType::Stock
+    }
+
+    /// Type d'actif correspondant au quoteType de Yahoo
+    ///
+    /// "MUTUALFUND" (fonds, OPCVM) est traité comme un ETF ; None pour les
+    /// types sans équivalent (contrats à terme, options)
+    pub fn from_quote_type(quote_type: &str) -> Option<Self> {
+        match quote_type.trim().to_uppercase().as_str() {
+            "EQUITY" => Some(TickerType::Stock),
+            "ETF" | "MUTUALFUND" => Some(TickerType::ETF),
+            "CRYPTOCURRENCY" => Some(TickerType::Crypto),
+            "INDEX" => Some(TickerType::Index),
+            "CURRENCY" => Some(TickerType::Forex),
+            _ => None,
+        }
+    }
+
+    /// Indique si le volume échangé est significatif pour ce type d'actif
+    ///
+    /// Yahoo renvoie un volume toujours nul pour les devises et la plupart
+    /// des indices : il ne doit pas être affiché
+    pub fn has_volume(&self) -> bool {
+        !matches!(self, TickerType::Forex | TickerType::Index)
+    }
+
+    /// Intervalle d'ouverture d'un nouveau graphique
+    ///
+    /// CONCEPT : Granularité par type d'actif
+    /// - Crypto : marché ouvert 24h/24, le 15m montre la séance en cours
+    /// - Forex : marché continu en semaine, 1h
+    /// - Actions, ETF, indices : une séance de 6h30, le 1d donne la tendance
+    pub fn default_interval(&self) -> Interval {
+        match self {
+            TickerType::Crypto => Interval::M15,
+            TickerType::Forex => Interval::H1,
+            TickerType::Stock | TickerType::ETF | TickerType::Index => Interval::D1,
+        }
+    }
+
+    /// Pictogramme d'une colonne devant le symbole (lignes du dashboard)
+    pub fn icon(&self) -> char {
+        match self {
+            TickerType::Stock => '▪',
+            TickerType::Crypto => '₿',
+            TickerType::ETF => '≡',
+            TickerType::Index => '∑',
+            TickerType::Forex => '¤',
+        }
+    }
+
+    /// Libellé affiché dans l'interface
+    pub fn label(&self) -> &'static str {
+        match self {
+            TickerType::Stock => "Actions",
+            TickerType::Crypto => "Crypto",
+            TickerType::ETF => "ETF",
+            TickerType::Index => "Indices",
+            TickerType::Forex => "Forex",
+        }
+    }
+}
+
 /// Ticker représentant un symbole boursier
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ticker {
@@ -134,4 +263,34 @@ mod tests {
         assert_eq!(ticker.current_price, Some(185.23));
         assert_eq!(ticker.change_percent_24h, Some(2.34));
     }
+
+    #[test]
+    fn test_ticker_type_from_symbol() {
+        assert_eq!(TickerType::from_symbol("AAPL"), TickerType::Stock);
+        assert_eq!(TickerType::from_symbol("BRK-B"), TickerType::Stock);
+        assert_eq!(TickerType::from_symbol("BTC-USD"), TickerType::Crypto);
+        assert_eq!(TickerType::from_symbol("eth-eur"), TickerType::Crypto);
+        assert_eq!(TickerType::from_symbol("SPY"), TickerType::ETF);
+        assert_eq!(TickerType::from_symbol("^GSPC"), TickerType::Index);
+        assert_eq!(TickerType::from_symbol("EURUSD=X"), TickerType::Forex);
+    }
+
+    #[test]
+    fn test_ticker_type_from_quote_type() {
+        assert_eq!(TickerType::from_quote_type("EQUITY"), Some(TickerType::Stock));
+        assert_eq!(TickerType::from_quote_type("ETF"), Some(TickerType::ETF));
+        assert_eq!(TickerType::from_quote_type("MUTUALFUND"), Some(TickerType::ETF));
+        assert_eq!(TickerType::from_quote_type("CRYPTOCURRENCY"), Some(TickerType::Crypto));
+        assert_eq!(TickerType::from_quote_type("INDEX"), Some(TickerType::Index));
+        assert_eq!(TickerType::from_quote_type("currency"), Some(TickerType::Forex));
+        assert_eq!(TickerType::from_quote_type("FUTURE"), None);
+    }
+
+    #[test]
+    fn test_default_interval_by_type() {
+        assert_eq!(TickerType::from_symbol("BTC-USD").default_interval(), Interval::M15);
+        assert_eq!(TickerType::from_symbol("AAPL").default_interval(), Interval::D1);
+        assert_eq!(TickerType::from_symbol("SPY").default_interval(), Interval::D1);
+        assert_eq!(TickerType::from_symbol("EURUSD=X").default_interval(), Interval::H1);
+    }
 }
@@ -0,0 +1,248 @@
+// ============================================================================
+// Validation des bougies reçues
+// ============================================================================
+// Les API renvoient parfois des bougies incohérentes : high sous la clôture,
+// prix négatif, horodatage répété ou dans le désordre. Sans contrôle, elles
+// arrivent telles quelles au graphique et aux indicateurs.
+//
+// Règles d'une série valide :
+// - Horodatages strictement croissants
+// - high ≥ max(open, close) et low ≤ min(open, close)
+// - Prix finis et positifs
+//
+// CONCEPT : Réparer ou rejeter (CandlePolicy)
+// - Repair : les mèches sont étendues jusqu'au corps, une bougie répétée
+//   remplace la précédente ; seules les bougies irréparables sont écartées
+// - Reject : toute bougie invalide est écartée
+//
+// CONCEPT RUST : Builder
+// - OHLCDataBuilder::new(...).with_policy(CandlePolicy::Reject)
+// - push() valide chaque bougie, build() rend la série et le bilan
+// ============================================================================
+
+use std::fmt;
+
+use crate::models::{Interval, OHLCData, Timeframe, OHLC};
+
+/// Traitement des bougies invalides
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CandlePolicy {
+    /// Corrige ce qui peut l'être, écarte le reste
+    #[default]
+    Repair,
+
+    /// Écarte toute bougie invalide
+    Reject,
+}
+
+/// Défaut d'une bougie
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandleIssue {
+    /// Date antérieure ou égale à celle de la bougie précédente
+    NotIncreasing,
+    /// Plus haut sous l'ouverture ou la clôture
+    HighBelowBody,
+    /// Plus bas au-dessus de l'ouverture ou de la clôture
+    LowAboveBody,
+    /// Prix négatif ou non fini (NaN, infini)
+    InvalidPrice,
+}
+
+impl fmt::Display for CandleIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            CandleIssue::NotIncreasing => "date non croissante",
+            CandleIssue::HighBelowBody => "plus haut sous le corps",
+            CandleIssue::LowAboveBody => "plus bas au-dessus du corps",
+            CandleIssue::InvalidPrice => "prix invalide",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+/// Vérifie une bougie seule (sans l'ordre des dates)
+///
+/// Premier défaut trouvé, None si la bougie est valide
+pub fn candle_issue(candle: &OHLC) -> Option<CandleIssue> {
+    let prices = [candle.open, candle.high, candle.low, candle.close];
+    if prices.iter().any(|price| !price.is_finite() || *price < 0.0) {
+        return Some(CandleIssue::InvalidPrice);
+    }
+    if candle.high < candle.open.max(candle.close) {
+        return Some(CandleIssue::HighBelowBody);
+    }
+    if candle.low > candle.open.min(candle.close) {
+        return Some(CandleIssue::LowAboveBody);
+    }
+    None
+}
+
+/// Bilan de la validation d'une série
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Bougies corrigées (mèches étendues, doublon remplacé)
+    pub repaired: usize,
+
+    /// Bougies écartées
+    pub rejected: usize,
+}
+
+impl ValidationReport {
+    /// Indique si toutes les bougies étaient valides
+    pub fn is_clean(&self) -> bool {
+        self.repaired == 0 && self.rejected == 0
+    }
+}
+
+/// Construit une OHLCData en validant chaque bougie
+#[derive(Debug, Clone)]
+pub struct OHLCDataBuilder {
+    data: OHLCData,
+    policy: CandlePolicy,
+    report: ValidationReport,
+}
+
+impl OHLCDataBuilder {
+    /// Série vide à remplir avec push()
+    pub fn new(symbol: String, interval: Interval, timeframe: Timeframe) -> Self {
+        Self {
+            data: OHLCData::new(symbol, interval, timeframe),
+            policy: CandlePolicy::default(),
+            report: ValidationReport::default(),
+        }
+    }
+
+    /// Choisit le traitement des bougies invalides (Repair par défaut)
+    pub fn with_policy(mut self, policy: CandlePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Réserve la place de `additional` bougies (taille de la réponse connue)
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.candles.reserve(additional);
+    }
+
+    /// Ajoute une bougie si elle est valide (ou réparable)
+    ///
+    /// Retourne le défaut constaté (None si la bougie était valide)
+    pub fn push(&mut self, mut candle: OHLC) -> Option<CandleIssue> {
+        let issue = candle_issue(&candle);
+        let repair = self.policy == CandlePolicy::Repair;
+
+        match issue {
+            Some(CandleIssue::InvalidPrice) => {
+                self.report.rejected += 1;
+                return issue;
+            }
+            Some(_) if !repair => {
+                self.report.rejected += 1;
+                return issue;
+            }
+            Some(_) => {
+                // Mèches étendues jusqu'au corps
+                candle.high = candle.high.max(candle.open).max(candle.close);
+                candle.low = candle.low.min(candle.open).min(candle.close);
+            }
+            None => {}
+        }
+
+        if let Some(last) = self.data.candles.last_mut() {
+            if candle.timestamp < last.timestamp || (candle.timestamp == last.timestamp && !repair) {
+                self.report.rejected += 1;
+                return Some(CandleIssue::NotIncreasing);
+            }
+            if candle.timestamp == last.timestamp {
+                // Même bougie reçue deux fois : la dernière l'emporte
+                *last = candle;
+                self.report.repaired += 1;
+                return Some(CandleIssue::NotIncreasing);
+            }
+        }
+
+        if issue.is_some() {
+            self.report.repaired += 1;
+        }
+        self.data.add_candle(candle);
+        issue
+    }
+
+    /// Série validée et bilan
+    pub fn build(self) -> (OHLCData, ValidationReport) {
+        (self.data, self.report)
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    fn at(hour: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 4, 0, 0, 0).unwrap() + Duration::hours(hour)
+    }
+
+    /// Une bougie valide, deux réparables, un prix négatif, une bougie en
+    /// retard et un doublon
+    fn push_all(builder: &mut OHLCDataBuilder) -> Vec<Option<CandleIssue>> {
+        vec![
+            builder.push(OHLC::new(at(0), 10.0, 12.0, 9.0, 11.0, 1)),
+            builder.push(OHLC::new(at(1), 11.0, 10.5, 10.0, 12.0, 1)),
+            builder.push(OHLC::new(at(2), 12.0, 13.0, 12.5, 12.2, 1)),
+            builder.push(OHLC::new(at(3), -1.0, 13.0, 12.0, 12.5, 1)),
+            builder.push(OHLC::new(at(1), 12.0, 13.0, 11.0, 12.5, 1)),
+            builder.push(OHLC::new(at(2), 12.0, 14.0, 11.5, 13.0, 1)),
+        ]
+    }
+
+    #[test]
+    fn test_candle_issue() {
+        assert_eq!(candle_issue(&OHLC::new(at(0), 10.0, 12.0, 9.0, 11.0, 0)), None);
+        assert_eq!(candle_issue(&OHLC::new(at(0), 10.0, 10.5, 9.0, 11.0, 0)), Some(CandleIssue::HighBelowBody));
+        assert_eq!(candle_issue(&OHLC::new(at(0), 10.0, 12.0, 10.5, 11.0, 0)), Some(CandleIssue::LowAboveBody));
+        assert_eq!(candle_issue(&OHLC::new(at(0), f64::NAN, 12.0, 9.0, 11.0, 0)), Some(CandleIssue::InvalidPrice));
+    }
+
+    #[test]
+    fn test_repair_policy() {
+        let mut builder = OHLCDataBuilder::new("AAPL".to_string(), Interval::H1, Timeframe::OneWeek);
+        let issues = push_all(&mut builder);
+        assert_eq!(
+            issues,
+            vec![
+                None,
+                Some(CandleIssue::HighBelowBody),
+                Some(CandleIssue::LowAboveBody),
+                Some(CandleIssue::InvalidPrice),
+                Some(CandleIssue::NotIncreasing),
+                Some(CandleIssue::NotIncreasing),
+            ]
+        );
+
+        let (data, report) = builder.build();
+        assert_eq!(report, ValidationReport { repaired: 3, rejected: 2 });
+        assert_eq!(data.len(), 3);
+        assert_eq!((data.candles[1].high, data.candles[1].low), (12.0, 10.0));
+        // Doublon de 2h : la dernière version remplace la bougie réparée
+        assert_eq!(data.candles[2].close, 13.0);
+        assert!(data.candles.iter().all(|candle| candle_issue(candle).is_none()));
+        assert!(data.candles.windows(2).all(|pair| pair[0].timestamp < pair[1].timestamp));
+    }
+
+    #[test]
+    fn test_reject_policy() {
+        let mut builder =
+            OHLCDataBuilder::new("AAPL".to_string(), Interval::H1, Timeframe::OneWeek).with_policy(CandlePolicy::Reject);
+        push_all(&mut builder);
+
+        let (data, report) = builder.build();
+        // Seules les bougies valides et dans l'ordre restent (0h, 1h, 2h)
+        assert_eq!(report, ValidationReport { repaired: 0, rejected: 3 });
+        assert_eq!(data.candles.iter().map(|candle| candle.close).collect::<Vec<_>>(), vec![11.0, 12.5, 13.0]);
+        assert!(!report.is_clean());
+    }
+}
@@ -9,7 +9,15 @@
 // 3. Option : gérer les données manquantes
 // ============================================================================
 
-use crate::models::{OHLCData, OHLC};
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::models::analyst::AnalystRatings;
+use crate::models::dividends::DividendInfo;
+use crate::models::financials::Financials;
+use crate::models::holders::Holders;
+use crate::models::{Currency, Interval, OHLCData, TickerType, OHLC};
 
 /// Un ticker dans la watchlist avec ses données
 #[derive(Debug, Clone)]
@@ -20,29 +28,78 @@ pub struct WatchlistItem {
     /// Nom complet (ex: "Apple Inc.")
     pub name: String,
 
+    /// Type d'actif : déduit du symbole à la création, remplacé par le
+    /// quoteType de Yahoo à l'ajout d'un ticker (ETF hors de la liste connue)
+    pub ticker_type: TickerType,
+
     /// Données OHLC chargées (None si pas encore chargées ou erreur)
     /// CONCEPT RUST : Option pour les données optionnelles
     /// - Some(data) : données disponibles
     /// - None : pas encore chargées ou erreur de chargement
     pub data: Option<OHLCData>,
+
+    /// Données chargées pour d'autres intervalles (vue multi-timeframe, ...)
+    /// CONCEPT : Cache par intervalle
+    /// - Indépendant de `data` (intervalle principal affiché)
+    /// - Chaque intervalle est fetché et conservé séparément
+    pub interval_cache: HashMap<Interval, OHLCData>,
+
+    /// Date de la prochaine publication de résultats (actions uniquement)
+    pub earnings_date: Option<DateTime<Utc>>,
+
+    /// Recommandations des analystes et objectif de cours (actions,
+    /// chargés à l'ouverture de la fiche détaillée)
+    pub analyst_ratings: Option<AnalystRatings>,
+
+    /// Transactions des initiés et détenteurs institutionnels (actions,
+    /// chargés à l'ouverture du panneau [h] de la fiche détaillée)
+    pub holders: Option<Holders>,
+
+    /// Comptes annuels et trimestriels publiés (actions, chargés à
+    /// l'ouverture de la fiche détaillée)
+    pub financials: Option<Financials>,
+
+    /// Dernier dividende annoncé (actions et ETF, chargé à l'ouverture du
+    /// calendrier des dividendes)
+    pub dividends: Option<DividendInfo>,
+
+    /// Erreur du dernier chargement (effacée au prochain succès)
+    /// Affichée dans la ligne et le graphique avec "[r] Réessayer"
+    pub load_error: Option<String>,
 }
 
 impl WatchlistItem {
     /// Crée un nouvel item de watchlist sans données
     pub fn new(symbol: String, name: String) -> Self {
         Self {
+            ticker_type: TickerType::from_symbol(&symbol),
             symbol,
             name,
             data: None,
+            interval_cache: HashMap::new(),
+            earnings_date: None,
+            analyst_ratings: None,
+            holders: None,
+            financials: None,
+            dividends: None,
+            load_error: None,
         }
     }
 
     /// Crée un item avec des données déjà chargées
     pub fn with_data(symbol: String, name: String, data: OHLCData) -> Self {
         Self {
+            ticker_type: TickerType::from_symbol(&symbol),
             symbol,
             name,
             data: Some(data),
+            interval_cache: HashMap::new(),
+            earnings_date: None,
+            analyst_ratings: None,
+            holders: None,
+            financials: None,
+            dividends: None,
+            load_error: None,
         }
     }
 
@@ -74,11 +131,137 @@ impl WatchlistItem {
             .and_then(|data| data.daily_change_percent())
     }
 
+    /// Retourne la variation journalière absolue (en devise, pips ou points)
+    pub fn change_absolute(&self) -> Option<f64> {
+        self.data.as_ref()?.daily_change()
+    }
+
+    /// Plus bas et plus haut de la dernière séance
+    pub fn day_range(&self) -> Option<(f64, f64)> {
+        self.data.as_ref()?.session_range()
+    }
+
+    /// Variations des `count` dernières séances, de la plus ancienne à la
+    /// plus récente
+    ///
+    /// Historique 1d s'il est chargé, sinon données intraday du graphique
+    /// (vide avec des données 1w et plus : une chandelle n'est pas une séance)
+    pub fn daily_changes(&self, count: usize) -> Vec<f64> {
+        let source = self
+            .data_for(Interval::D1)
+            .or_else(|| self.data.as_ref().filter(|data| data.interval.is_intraday()));
+        let Some(changes) = source.map(OHLCData::session_changes) else {
+            return Vec::new();
+        };
+        changes[changes.len().saturating_sub(count)..].to_vec()
+    }
+
+    /// Volume de la dernière séance
+    pub fn session_volume(&self) -> Option<u64> {
+        self.data.as_ref()?.session_volume()
+    }
+
+    /// Volume moyen des séances précédentes
+    pub fn average_volume(&self) -> Option<u64> {
+        self.data.as_ref()?.average_session_volume()
+    }
+
+    /// Nombre de jours avant la publication des résultats (None si passée ou inconnue)
+    pub fn days_until_earnings(&self, today: NaiveDate) -> Option<i64> {
+        let days = (self.earnings_date?.date_naive() - today).num_days();
+        (days >= 0).then_some(days)
+    }
+
+    /// Badge de compte à rebours des résultats : "ER today", "ER in 6d"
+    ///
+    /// Affiché seulement dans les 30 prochains jours
+    pub fn earnings_badge(&self, today: NaiveDate) -> Option<String> {
+        match self.days_until_earnings(today)? {
+            0 => Some("ER today".to_string()),
+            days if days <= 30 => Some(format!("ER in {}d", days)),
+            _ => None,
+        }
+    }
+
+    /// Type d'actif du ticker (détecté à l'ajout, sinon déduit du symbole)
+    pub fn ticker_type(&self) -> TickerType {
+        self.ticker_type
+    }
+
+    /// Devise de cotation (celle des données, sinon déduite du symbole)
+    pub fn currency(&self) -> Currency {
+        match self.data.as_ref().and_then(|data| data.currency.as_deref()) {
+            Some(code) => Currency::new(code),
+            None => Currency::from_symbol(&self.symbol),
+        }
+    }
+
+    /// Intervalle du graphique de l'item
+    ///
+    /// Celui des données chargées, sinon celui du type d'actif (15m pour
+    /// une crypto, 1d pour une action)
+    pub fn interval(&self) -> Interval {
+        self.data
+            .as_ref()
+            .map_or_else(|| self.ticker_type().default_interval(), |data| data.interval)
+    }
+
     /// Retourne la dernière chandelle OHLC
     pub fn last_ohlc(&self) -> Option<&OHLC> {
         self.data.as_ref()?.last()
     }
 
+    /// Reconstruit un intervalle à partir des données plus fines déjà chargées
+    ///
+    /// Permet de changer d'intervalle hors ligne (1h en cache → 4h, 1d)
+    /// Parmi les sources possibles, garde celle qui couvre la plus longue période
+    pub fn resampled_for(&self, interval: Interval) -> Option<OHLCData> {
+        let span = |data: &OHLCData| match (data.candles.first(), data.candles.last()) {
+            (Some(first), Some(last)) => last.timestamp - first.timestamp,
+            _ => chrono::Duration::zero(),
+        };
+
+        self.data
+            .iter()
+            .chain(self.interval_cache.values())
+            .filter(|data| data.interval.minutes() < interval.minutes() && !data.is_empty())
+            .filter(|data| interval.minutes() % data.interval.minutes() == 0)
+            .max_by_key(|data| span(data))?
+            .resample(interval)
+    }
+
+    /// Retourne les données pour un intervalle donné
+    ///
+    /// Cherche d'abord dans les données principales, puis dans le cache
+    pub fn data_for(&self, interval: Interval) -> Option<&OHLCData> {
+        self.data
+            .as_ref()
+            .filter(|data| data.interval == interval)
+            .or_else(|| self.interval_cache.get(&interval))
+    }
+
+    /// Stocke des données dans le cache (fusionnées avec l'entrée du même
+    /// intervalle, voir OHLCData::merge)
+    pub fn cache_data(&mut self, data: OHLCData) {
+        match self.interval_cache.get_mut(&data.interval) {
+            Some(cached) => cached.merge(data),
+            None => {
+                self.interval_cache.insert(data.interval, data);
+            }
+        }
+    }
+
+    /// Met à jour les données principales avec une série rechargée
+    ///
+    /// Fusionnées avec les données déjà chargées (voir OHLCData::merge) :
+    /// un rafraîchissement qui chevauche le précédent ne duplique rien
+    pub fn update_data(&mut self, data: OHLCData) {
+        match &mut self.data {
+            Some(current) => current.merge(data),
+            None => self.data = Some(data),
+        }
+    }
+
     /// Vérifie si les données sont chargées
     pub fn has_data(&self) -> bool {
         self.data.is_some()
@@ -170,6 +353,62 @@ mod tests {
         assert_eq!(item.current_price(), Some(105.0));
     }
 
+    #[test]
+    fn test_data_for_uses_primary_then_cache() {
+        let data = OHLCData::new("AAPL".to_string(), Interval::D1, Timeframe::OneWeek);
+        let mut item = WatchlistItem::with_data("AAPL".to_string(), "Apple Inc.".to_string(), data);
+
+        assert!(item.data_for(Interval::D1).is_some());
+        assert!(item.data_for(Interval::H1).is_none());
+
+        item.cache_data(OHLCData::new("AAPL".to_string(), Interval::H1, Timeframe::OneWeek));
+        assert_eq!(item.data_for(Interval::H1).unwrap().interval, Interval::H1);
+    }
+
+    #[test]
+    fn test_resampled_for_uses_longest_finer_data() {
+        let hourly = |hours: i64| {
+            let mut data = OHLCData::new("AAPL".to_string(), Interval::H1, Timeframe::OneWeek);
+            let start = Utc::now() - chrono::Duration::hours(hours);
+            for i in 0..hours {
+                data.add_candle(OHLC::new(start + chrono::Duration::hours(i), 100.0, 101.0, 99.0, 100.5, 1));
+            }
+            data
+        };
+        let mut minutes = OHLCData::new("AAPL".to_string(), Interval::M30, Timeframe::OneWeek);
+        minutes.add_candle(OHLC::new(Utc::now(), 100.0, 101.0, 99.0, 100.5, 1));
+
+        let mut item = WatchlistItem::with_data("AAPL".to_string(), "Apple Inc.".to_string(), minutes);
+        item.cache_data(hourly(72));
+
+        // 1h couvre 3 jours, 30m une seule bougie : 1d est reconstruit depuis 1h
+        let daily = item.resampled_for(Interval::D1).unwrap();
+        assert_eq!(daily.interval, Interval::D1);
+        assert_eq!(daily.candles.iter().map(|c| c.volume).sum::<u64>(), 72);
+
+        // Rien de plus fin que 30m n'est chargé
+        assert!(item.resampled_for(Interval::M15).is_none());
+    }
+
+    #[test]
+    fn test_earnings_badge() {
+        use chrono::TimeZone;
+
+        let today = NaiveDate::from_ymd_opt(2024, 4, 26).unwrap();
+        let mut item = WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string());
+        assert!(item.earnings_badge(today).is_none());
+
+        item.earnings_date = Some(Utc.with_ymd_and_hms(2024, 5, 2, 20, 30, 0).unwrap());
+        assert_eq!(item.earnings_badge(today), Some("ER in 6d".to_string()));
+
+        let day_of = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+        assert_eq!(item.earnings_badge(day_of), Some("ER today".to_string()));
+
+        // Résultats déjà publiés
+        let after = NaiveDate::from_ymd_opt(2024, 5, 3).unwrap();
+        assert!(item.earnings_badge(after).is_none());
+    }
+
     #[test]
     fn test_is_positive() {
         let mut data = OHLCData::new("AAPL".to_string(), Interval::D1, Timeframe::OneWeek);
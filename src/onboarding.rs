@@ -0,0 +1,384 @@
+// ============================================================================
+// Premier lancement : configuration guidée
+// ============================================================================
+// Sans config.json, la TUI s'ouvre sur quatre étapes avant de charger les
+// données, puis écrit le fichier :
+//
+//   1. Devise de référence  USD / EUR / GBP / JPY : indices du bandeau,
+//                           indice de référence des graphiques et paire de
+//                           change proposée (valorisation du portefeuille)
+//   2. Affichage            standard, ASCII, accessible (sans emoji ni
+//                           clignotement)
+//   3. Rafraîchissement     désactivé, 30 s, 1 min, 5 min
+//   4. Watchlist            symboles suggérés à cocher (Espace)
+//
+// - Entrée : étape suivante, Retour arrière : étape précédente
+// - Échap : configuration par défaut (écrite aussi : la question n'est
+//   posée qu'une fois)
+//
+// CONCEPT : État sans App
+// - L'assistant tourne avant le chargement de la watchlist : son état vit
+//   ici et produit une Config, la vue est dans ui/onboarding.rs
+// ============================================================================
+
+use crate::config::Config;
+
+/// Devise de référence proposée
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaseCurrency {
+    Usd,
+    Eur,
+    Gbp,
+    Jpy,
+}
+
+impl BaseCurrency {
+    pub const ALL: [BaseCurrency; 4] = [BaseCurrency::Usd, BaseCurrency::Eur, BaseCurrency::Gbp, BaseCurrency::Jpy];
+
+    /// Libellé affiché
+    pub fn label(&self) -> &'static str {
+        match self {
+            BaseCurrency::Usd => "USD  Dollar (marchés US)",
+            BaseCurrency::Eur => "EUR  Euro (marchés européens)",
+            BaseCurrency::Gbp => "GBP  Livre sterling (Londres)",
+            BaseCurrency::Jpy => "JPY  Yen (Tokyo)",
+        }
+    }
+
+    /// Indices du bandeau "marchés" du dashboard
+    pub fn market_overview(&self) -> &'static [&'static str] {
+        match self {
+            BaseCurrency::Usd => &["^GSPC", "^NDX", "^DJI"],
+            BaseCurrency::Eur => &["^STOXX50E", "^GDAXI", "^FCHI"],
+            BaseCurrency::Gbp => &["^FTSE", "^STOXX50E", "^GSPC"],
+            BaseCurrency::Jpy => &["^N225", "^HSI", "^GSPC"],
+        }
+    }
+
+    /// Indice de référence superposé aux graphiques (touche v)
+    pub fn benchmark(&self) -> &'static str {
+        match self {
+            BaseCurrency::Usd => "SPY",
+            BaseCurrency::Eur => "^STOXX50E",
+            BaseCurrency::Gbp => "^FTSE",
+            BaseCurrency::Jpy => "^N225",
+        }
+    }
+
+    /// Symboles suggérés (symbole, nom), la paire de change en premier
+    ///
+    /// Les totaux du portefeuille sont en dollars : la paire convertit les
+    /// positions et liquidités dans la devise choisie
+    pub fn suggestions(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            BaseCurrency::Usd => &[
+                ("AAPL", "Apple"),
+                ("MSFT", "Microsoft"),
+                ("NVDA", "NVIDIA"),
+                ("SPY", "S&P 500 ETF"),
+                ("BTC-USD", "Bitcoin"),
+                ("ETH-USD", "Ethereum"),
+            ],
+            BaseCurrency::Eur => &[
+                ("EURUSD=X", "EUR/USD"),
+                ("AIR.PA", "Airbus"),
+                ("MC.PA", "LVMH"),
+                ("ASML.AS", "ASML"),
+                ("SAP.DE", "SAP"),
+                ("BTC-EUR", "Bitcoin (EUR)"),
+            ],
+            BaseCurrency::Gbp => &[
+                ("GBPUSD=X", "GBP/USD"),
+                ("SHEL.L", "Shell"),
+                ("AZN.L", "AstraZeneca"),
+                ("HSBA.L", "HSBC"),
+                ("ULVR.L", "Unilever"),
+                ("BTC-GBP", "Bitcoin (GBP)"),
+            ],
+            BaseCurrency::Jpy => &[
+                ("JPY=X", "USD/JPY"),
+                ("7203.T", "Toyota"),
+                ("6758.T", "Sony"),
+                ("9984.T", "SoftBank"),
+                ("8306.T", "Mitsubishi UFJ"),
+                ("BTC-JPY", "Bitcoin (JPY)"),
+            ],
+        }
+    }
+}
+
+/// Affichage proposé (options ascii, no_emoji et reduced_motion)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Appearance {
+    Standard,
+    Ascii,
+    Accessible,
+}
+
+impl Appearance {
+    pub const ALL: [Appearance; 3] = [Appearance::Standard, Appearance::Ascii, Appearance::Accessible];
+
+    /// Libellé affiché
+    pub fn label(&self) -> &'static str {
+        match self {
+            Appearance::Standard => "Standard  (Unicode et emojis)",
+            Appearance::Ascii => "ASCII     (terminaux sans glyphes de dessin)",
+            Appearance::Accessible => "Accessible (sans emoji ni clignotement)",
+        }
+    }
+}
+
+/// Périodes de rafraîchissement proposées, en secondes (0 : désactivé)
+pub const REFRESH_CHOICES: [u64; 4] = [0, 30, 60, 300];
+
+/// Libellé d'une période de rafraîchissement
+pub fn refresh_label(seconds: u64) -> String {
+    match seconds {
+        0 => "Désactivé (touche r après un échec)".to_string(),
+        seconds if seconds < 60 => format!("Toutes les {} secondes", seconds),
+        60 => "Toutes les minutes".to_string(),
+        seconds => format!("Toutes les {} minutes", seconds / 60),
+    }
+}
+
+/// Nombre de symboles suggérés cochés au départ
+const PRESELECTED: usize = 3;
+
+/// Étape de l'assistant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Currency,
+    Appearance,
+    Refresh,
+    Watchlist,
+}
+
+impl Step {
+    pub const ALL: [Step; 4] = [Step::Currency, Step::Appearance, Step::Refresh, Step::Watchlist];
+
+    /// Titre de l'étape
+    pub fn title(&self) -> &'static str {
+        match self {
+            Step::Currency => "Devise de référence",
+            Step::Appearance => "Affichage",
+            Step::Refresh => "Rafraîchissement automatique",
+            Step::Watchlist => "Watchlist",
+        }
+    }
+
+    /// Numéro de l'étape (à partir de 1)
+    pub fn number(&self) -> usize {
+        Step::ALL.iter().position(|step| step == self).unwrap_or(0) + 1
+    }
+}
+
+/// État de l'assistant de premier lancement
+#[derive(Debug, Clone)]
+pub struct Onboarding {
+    pub step: Step,
+    currency: usize,
+    appearance: usize,
+    refresh: usize,
+
+    /// Suggestion sous le curseur et suggestions cochées (étape Watchlist)
+    watchlist_cursor: usize,
+    selected: Vec<bool>,
+}
+
+impl Default for Onboarding {
+    fn default() -> Self {
+        let mut onboarding = Self {
+            step: Step::Currency,
+            currency: 0,
+            appearance: 0,
+            refresh: 0,
+            watchlist_cursor: 0,
+            selected: Vec::new(),
+        };
+        onboarding.reset_selection();
+        onboarding
+    }
+}
+
+impl Onboarding {
+    /// Devise choisie
+    pub fn currency(&self) -> BaseCurrency {
+        BaseCurrency::ALL[self.currency]
+    }
+
+    /// Affichage choisi
+    pub fn appearance(&self) -> Appearance {
+        Appearance::ALL[self.appearance]
+    }
+
+    /// Période de rafraîchissement choisie (secondes, 0 : désactivé)
+    pub fn refresh_seconds(&self) -> u64 {
+        REFRESH_CHOICES[self.refresh]
+    }
+
+    /// Nombre d'options de l'étape courante
+    fn option_count(&self) -> usize {
+        match self.step {
+            Step::Currency => BaseCurrency::ALL.len(),
+            Step::Appearance => Appearance::ALL.len(),
+            Step::Refresh => REFRESH_CHOICES.len(),
+            Step::Watchlist => self.selected.len(),
+        }
+    }
+
+    /// Option sous le curseur de l'étape courante
+    pub fn cursor(&self) -> usize {
+        match self.step {
+            Step::Currency => self.currency,
+            Step::Appearance => self.appearance,
+            Step::Refresh => self.refresh,
+            Step::Watchlist => self.watchlist_cursor,
+        }
+    }
+
+    /// Déplace le curseur de l'étape courante (circulaire)
+    pub fn move_cursor(&mut self, down: bool) {
+        let count = self.option_count();
+        if count == 0 {
+            return;
+        }
+        let cursor = self.cursor();
+        let moved = if down { (cursor + 1) % count } else { (cursor + count - 1) % count };
+        match self.step {
+            Step::Currency => {
+                self.currency = moved;
+                self.reset_selection();
+            }
+            Step::Appearance => self.appearance = moved,
+            Step::Refresh => self.refresh = moved,
+            Step::Watchlist => self.watchlist_cursor = moved,
+        }
+    }
+
+    /// Suggestions de la devise choisie (symbole, nom, cochée)
+    pub fn suggestions(&self) -> impl Iterator<Item = (&'static str, &'static str, bool)> + '_ {
+        self.currency()
+            .suggestions()
+            .iter()
+            .zip(&self.selected)
+            .map(|((symbol, name), selected)| (*symbol, *name, *selected))
+    }
+
+    /// Coche ou décoche la suggestion sous le curseur (étape Watchlist)
+    pub fn toggle(&mut self) {
+        if self.step == Step::Watchlist {
+            if let Some(selected) = self.selected.get_mut(self.watchlist_cursor) {
+                *selected = !*selected;
+            }
+        }
+    }
+
+    /// Passe à l'étape suivante
+    ///
+    /// Retourne true quand l'assistant est terminé (Entrée sur la dernière)
+    pub fn advance(&mut self) -> bool {
+        match Step::ALL.get(self.step.number()) {
+            Some(step) => {
+                self.step = *step;
+                false
+            }
+            None => true,
+        }
+    }
+
+    /// Revient à l'étape précédente (sans effet sur la première)
+    pub fn go_back(&mut self) {
+        if let Some(index) = self.step.number().checked_sub(2) {
+            self.step = Step::ALL[index];
+        }
+    }
+
+    /// Coche les premières suggestions de la devise choisie
+    fn reset_selection(&mut self) {
+        let count = self.currency().suggestions().len();
+        self.selected = (0..count).map(|index| index < PRESELECTED).collect();
+        self.watchlist_cursor = 0;
+    }
+
+    /// Configuration initiale issue des choix
+    ///
+    /// Sans symbole coché, la watchlist par défaut est chargée
+    pub fn into_config(self) -> Config {
+        let currency = self.currency();
+        let appearance = self.appearance();
+        Config {
+            market_overview: currency.market_overview().iter().map(|symbol| symbol.to_string()).collect(),
+            benchmark: currency.benchmark().to_string(),
+            watchlist: self
+                .suggestions()
+                .filter(|(_, _, selected)| *selected)
+                .map(|(symbol, _, _)| symbol.to_string())
+                .collect(),
+            auto_refresh_seconds: self.refresh_seconds(),
+            ascii: appearance == Appearance::Ascii,
+            no_emoji: appearance == Appearance::Accessible,
+            reduced_motion: appearance == Appearance::Accessible,
+            ..Config::default()
+        }
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_onboarding_steps_and_config() {
+        let mut onboarding = Onboarding::default();
+        assert_eq!(onboarding.step.number(), 1);
+
+        // Devise : EUR (la sélection suit la devise)
+        onboarding.move_cursor(true);
+        assert_eq!(onboarding.currency(), BaseCurrency::Eur);
+        assert_eq!(onboarding.suggestions().filter(|(_, _, selected)| *selected).count(), 3);
+        onboarding.go_back();
+        assert_eq!(onboarding.step, Step::Currency);
+        assert!(!onboarding.advance());
+
+        // Affichage : accessible (curseur circulaire vers le haut)
+        onboarding.move_cursor(false);
+        assert_eq!(onboarding.appearance(), Appearance::Accessible);
+        assert!(!onboarding.advance());
+
+        // Rafraîchissement : 1 min
+        onboarding.move_cursor(true);
+        onboarding.move_cursor(true);
+        assert_eq!(onboarding.refresh_seconds(), 60);
+        assert!(!onboarding.advance());
+
+        // Watchlist : décoche Airbus, coche SAP
+        assert_eq!(onboarding.step, Step::Watchlist);
+        onboarding.move_cursor(true);
+        onboarding.toggle();
+        for _ in 0..3 {
+            onboarding.move_cursor(true);
+        }
+        onboarding.toggle();
+        assert!(onboarding.advance());
+
+        let config = onboarding.into_config();
+        assert_eq!(config.watchlist, ["EURUSD=X", "MC.PA", "SAP.DE"]);
+        assert_eq!(config.market_overview, ["^STOXX50E", "^GDAXI", "^FCHI"]);
+        assert_eq!(config.benchmark, "^STOXX50E");
+        assert_eq!(config.auto_refresh_seconds, 60);
+        assert!(config.no_emoji && config.reduced_motion && !config.ascii);
+        assert_eq!(config.tick_rate_ms, Config::default().tick_rate_ms);
+    }
+
+    #[test]
+    fn test_refresh_labels() {
+        assert_eq!(refresh_label(0), "Désactivé (touche r après un échec)");
+        assert_eq!(refresh_label(30), "Toutes les 30 secondes");
+        assert_eq!(refresh_label(60), "Toutes les minutes");
+        assert_eq!(refresh_label(300), "Toutes les 5 minutes");
+    }
+}
@@ -0,0 +1,166 @@
+// ============================================================================
+// Format des fichiers de données (JSON ou TOML)
+// ============================================================================
+// Watchlist, alertes et notes peuvent être gardées dans un dépôt de
+// dotfiles : avec "storage_format": "toml", elles sont écrites en TOML trié,
+// un élément par table, pour des diffs lisibles :
+//
+//   # alerts.toml
+//   [[rules]]
+//   symbol = "AAPL"
+//   condition = "RSI(14) < 30"
+//
+//   [[rules]]
+//   symbol = "BTC-USD"
+//   condition = "price crosses SMA(50)"
+//
+// - Ordre stable : clés triées (BTreeMap), éléments triés par l'appelant
+// - Un fichier dont le contenu ne change pas n'est pas réécrit (ni sa date)
+// - Migration : sans fichier .toml, le fichier .json voisin est relu ; la
+//   sauvegarde suivante écrit le .toml
+// - Le format suit l'extension du chemin : load et save ne dépendent pas du
+//   réglage global (tests, chemins explicites)
+// ============================================================================
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+static FORMAT: OnceLock<StorageFormat> = OnceLock::new();
+
+/// Format des fichiers de données (config "storage_format")
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageFormat {
+    /// JSON indenté (historique)
+    #[default]
+    Json,
+
+    /// TOML trié, un élément par table (dépôt de dotfiles)
+    Toml,
+}
+
+impl StorageFormat {
+    /// Extension des fichiers
+    pub fn extension(self) -> &'static str {
+        match self {
+            StorageFormat::Json => "json",
+            StorageFormat::Toml => "toml",
+        }
+    }
+
+    /// Format déduit de l'extension d'un chemin (JSON par défaut)
+    fn of(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => StorageFormat::Toml,
+            _ => StorageFormat::Json,
+        }
+    }
+}
+
+/// Choisit le format des fichiers de données (à appeler au démarrage,
+/// avant le premier default_path ; les appels suivants sont ignorés)
+pub fn set_format(format: StorageFormat) {
+    let _ = FORMAT.set(format);
+}
+
+/// Format choisi au démarrage
+pub fn format() -> StorageFormat {
+    FORMAT.get().copied().unwrap_or_default()
+}
+
+/// Nom de fichier dans le format choisi ("notes" → "notes.toml")
+pub fn file_name(stem: &str) -> String {
+    format!("{}.{}", stem, format().extension())
+}
+
+/// Charge un fichier de données (absent = valeur par défaut)
+///
+/// Un .toml absent est remplacé par le .json voisin s'il existe (migration)
+pub fn load<T: DeserializeOwned + Default>(path: &Path) -> Result<T> {
+    let path = if !path.exists() && StorageFormat::of(path) == StorageFormat::Toml {
+        path.with_extension("json")
+    } else {
+        path.to_path_buf()
+    };
+    if !path.exists() {
+        return Ok(T::default());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Échec de la lecture de {}", path.display()))?;
+    let value = match StorageFormat::of(&path) {
+        StorageFormat::Json => serde_json::from_str(&content).map_err(anyhow::Error::from),
+        StorageFormat::Toml => toml::from_str(&content).map_err(anyhow::Error::from),
+    };
+    value.with_context(|| format!("Format invalide dans {}", path.display()))
+}
+
+/// Sauvegarde un fichier de données (crée le répertoire si besoin)
+///
+/// Retourne false si le fichier avait déjà ce contenu (non réécrit)
+pub fn save<T: Serialize>(path: &Path, value: &T) -> Result<bool> {
+    let content = match StorageFormat::of(path) {
+        StorageFormat::Json => serde_json::to_string_pretty(value)?,
+        StorageFormat::Toml => toml::to_string(value)?,
+    };
+    if std::fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+        return Ok(false);
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+    }
+    std::fs::write(path, content).with_context(|| format!("Échec de l'écriture de {}", path.display()))?;
+    Ok(true)
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        #[serde(default)]
+        notes: BTreeMap<String, String>,
+    }
+
+    fn sample() -> Sample {
+        let notes = [("MSFT", "Cloud"), ("AAPL", "Services")];
+        Sample { notes: notes.iter().map(|(symbol, note)| (symbol.to_string(), note.to_string())).collect() }
+    }
+
+    #[test]
+    fn test_toml_is_sorted_and_rewritten_only_on_change() {
+        let dir = std::env::temp_dir().join(format!("lazywallet-persist-{}", std::process::id()));
+        let path = dir.join("notes.toml");
+
+        assert!(save(&path, &sample()).unwrap());
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content, "[notes]\nAAPL = \"Services\"\nMSFT = \"Cloud\"\n");
+        assert_eq!(load::<Sample>(&path).unwrap(), sample());
+
+        // Même contenu : fichier laissé tel quel
+        assert!(!save(&path, &sample()).unwrap());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_toml_falls_back_to_json_file() {
+        let dir = std::env::temp_dir().join(format!("lazywallet-persist-migrate-{}", std::process::id()));
+        assert_eq!(load::<Sample>(&dir.join("notes.toml")).unwrap(), Sample::default());
+
+        save(&dir.join("notes.json"), &sample()).unwrap();
+        assert_eq!(load::<Sample>(&dir.join("notes.toml")).unwrap(), sample());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
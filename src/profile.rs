@@ -0,0 +1,99 @@
+// ============================================================================
+// Profils : configurations isolées (--profile NOM)
+// ============================================================================
+// `--profile work` sépare tous les fichiers personnels de la session :
+//
+//   ~/.config/lazywallet/                    profil par défaut
+//   ~/.config/lazywallet/profiles/work/      config.json, portfolio.json,
+//                                            journal.json, alerts.json, tags.json
+//   ~/.local/share/lazywallet/profiles/work/ state.json (mode démon)
+//
+// - Les données de marché restent partagées entre profils : historique des
+//   bougies, enregistrements --record, exports
+// - Un nom de profil se limite aux lettres, chiffres, '-' et '_' (c'est un
+//   nom de répertoire)
+//
+// CONCEPT RUST : OnceLock global
+// - Choisi une fois au démarrage, avant le chargement de la configuration
+// - Lu par tous les default_path() sans avoir à passer le profil partout
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::Result;
+
+static PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Choisit le profil de la session (une fois, au démarrage)
+pub fn set(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
+        anyhow::bail!("Nom de profil invalide : \"{}\" (lettres, chiffres, '-' et '_')", name);
+    }
+    PROFILE
+        .set(name.to_string())
+        .map_err(|_| anyhow::anyhow!("Le profil est déjà choisi"))
+}
+
+/// Profil de la session (None : profil par défaut)
+pub fn name() -> Option<&'static str> {
+    PROFILE.get().map(String::as_str)
+}
+
+/// Lit "--profile NOM" dans les arguments
+pub fn parse_args(args: &[String]) -> Option<&str> {
+    let position = args.iter().position(|arg| arg == "--profile")?;
+    args.get(position + 1).map(String::as_str)
+}
+
+/// Répertoire des fichiers de configuration du profil
+///
+/// - Linux : ~/.config/lazywallet (ou .../profiles/NOM)
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| profile_dir(&dir, name()))
+}
+
+/// Répertoire des données du profil (état du démon)
+///
+/// - Linux : ~/.local/share/lazywallet (ou .../profiles/NOM)
+pub fn data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| profile_dir(&dir, name()))
+}
+
+/// Répertoire lazywallet d'un profil sous un répertoire de base
+fn profile_dir(base: &Path, profile: Option<&str>) -> PathBuf {
+    let dir = base.join("lazywallet");
+    match profile {
+        Some(profile) => dir.join("profiles").join(profile),
+        None => dir,
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_dir() {
+        let base = Path::new("/home/me/.config");
+        assert_eq!(profile_dir(base, None), PathBuf::from("/home/me/.config/lazywallet"));
+        assert_eq!(
+            profile_dir(base, Some("work")),
+            PathBuf::from("/home/me/.config/lazywallet/profiles/work")
+        );
+    }
+
+    #[test]
+    fn test_parse_args_and_validation() {
+        let args: Vec<String> = ["--demo", "--profile", "paper"].map(String::from).to_vec();
+        assert_eq!(parse_args(&args), Some("paper"));
+        assert_eq!(parse_args(&args[..2]), None);
+
+        assert!(set("../etc").is_err());
+        assert!(set("").is_err());
+    }
+}
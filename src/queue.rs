@@ -0,0 +1,277 @@
+// ============================================================================
+// File de commandes priorisée (worker)
+// ============================================================================
+// Remplace le canal mpsc FIFO entre l'interface et le worker :
+//
+//   User         touche de l'utilisateur (rechargement, ajout, export...)
+//   AutoRefresh  rafraîchissement de ce qui est à l'écran (watchlist,
+//                carnet d'ordres, bandeau des indices)
+//   Background   préchargement (dates de résultats, historiques D1)
+//
+// CONCEPT : Priorité puis ordre d'arrivée
+// - recv() rend la plus ancienne commande de la priorité la plus haute :
+//   une touche n'attend pas la fin d'une rafale de préchargements
+//
+// CONCEPT : Travail remplacé (supersede_key)
+// - Une commande peut porter une clé ("reload:AAPL") : à son arrivée, les
+//   commandes de même clé encore en file et de priorité inférieure ou égale
+//   sont retirées (leur résultat serait périmé avant d'être affiché)
+// - Une commande de priorité supérieure n'est jamais retirée par une plus
+//   basse : un rechargement demandé par l'utilisateur reste en file
+//
+// CONCEPT RUST : Mutex + Condvar
+// - La file est partagée entre émetteurs (clonables) et récepteur
+// - Condvar::wait() endort le worker jusqu'à l'arrivée d'une commande, sans
+//   attente active ; wait() relâche le Mutex pendant le sommeil
+// - Mêmes erreurs que std::sync::mpsc : SendError si le récepteur a
+//   disparu, RecvError quand tous les émetteurs sont détruits
+// ============================================================================
+
+use std::collections::VecDeque;
+use std::sync::mpsc::{RecvError, SendError};
+use std::sync::{Arc, Condvar, Mutex};
+
+use tracing::debug;
+
+/// Priorité d'une commande (de la plus basse à la plus haute)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    /// Préchargement sans urgence
+    Background,
+    /// Rafraîchissement de données affichées
+    AutoRefresh,
+    /// Demandé par l'utilisateur
+    User,
+}
+
+impl Priority {
+    /// Toutes les priorités, de la plus haute à la plus basse
+    const DESCENDING: [Priority; 3] = [Priority::User, Priority::AutoRefresh, Priority::Background];
+
+    /// Rang de la sous-file de cette priorité
+    fn slot(self) -> usize {
+        self as usize
+    }
+}
+
+/// Commande rangée dans une file priorisée
+pub trait Prioritized {
+    /// Priorité par défaut de la commande (voir QueueSender::send)
+    fn priority(&self) -> Priority;
+
+    /// Clé des commandes qu'elle remplace (None : jamais remplacée)
+    fn supersede_key(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Commande en attente et sa clé de remplacement
+struct Queued<T> {
+    key: Option<String>,
+    item: T,
+}
+
+/// État partagé de la file
+struct State<T> {
+    /// Une sous-file FIFO par priorité (index : Priority::slot)
+    slots: [VecDeque<Queued<T>>; 3],
+    senders: usize,
+    receiver_alive: bool,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    ready: Condvar,
+}
+
+/// Crée une file priorisée : (émetteur clonable, récepteur unique)
+pub fn channel<T: Prioritized>() -> (QueueSender<T>, QueueReceiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            slots: [VecDeque::new(), VecDeque::new(), VecDeque::new()],
+            senders: 1,
+            receiver_alive: true,
+        }),
+        ready: Condvar::new(),
+    });
+    (QueueSender { shared: shared.clone() }, QueueReceiver { shared })
+}
+
+/// Émetteur de la file (clonable, comme mpsc::Sender)
+pub struct QueueSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: Prioritized> QueueSender<T> {
+    /// Met une commande en file avec sa priorité par défaut
+    pub fn send(&self, item: T) -> Result<(), SendError<T>> {
+        let priority = item.priority();
+        self.send_with_priority(priority, item)
+    }
+
+    /// Met une commande en file avec une priorité imposée
+    /// (ex: historique D1 demandé en préchargement plutôt qu'à l'écran)
+    pub fn send_with_priority(&self, priority: Priority, item: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if !state.receiver_alive {
+            return Err(SendError(item));
+        }
+
+        let key = item.supersede_key();
+        if let Some(key) = &key {
+            for lower in Priority::DESCENDING.iter().filter(|lower| **lower <= priority) {
+                let slot = &mut state.slots[lower.slot()];
+                let before = slot.len();
+                slot.retain(|queued| queued.key.as_ref() != Some(key));
+                if slot.len() < before {
+                    debug!(key = %key, priority = ?lower, dropped = before - slot.len(), "Dropping superseded queued work");
+                }
+            }
+        }
+
+        state.slots[priority.slot()].push_back(Queued { key, item });
+        self.shared.ready.notify_one();
+        Ok(())
+    }
+
+    /// Nombre de commandes en attente
+    pub fn len(&self) -> usize {
+        self.shared.state.lock().unwrap().slots.iter().map(VecDeque::len).sum()
+    }
+
+    /// Vérifie si la file est vide
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Clone for QueueSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.state.lock().unwrap().senders += 1;
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl<T> Drop for QueueSender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.senders -= 1;
+        if state.senders == 0 {
+            // Réveille le récepteur : plus rien n'arrivera
+            self.shared.ready.notify_all();
+        }
+    }
+}
+
+/// Récepteur de la file (worker)
+pub struct QueueReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> QueueReceiver<T> {
+    /// Attend la prochaine commande : la plus ancienne de la plus haute priorité
+    ///
+    /// RecvError quand la file est vide et que tous les émetteurs sont détruits
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut state = self.shared.state.lock().unwrap();
+        loop {
+            if let Some(queued) = Priority::DESCENDING
+                .iter()
+                .find_map(|priority| state.slots[priority.slot()].pop_front())
+            {
+                return Ok(queued.item);
+            }
+            if state.senders == 0 {
+                return Err(RecvError);
+            }
+            state = self.shared.ready.wait(state).unwrap();
+        }
+    }
+}
+
+impl<T> Drop for QueueReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().receiver_alive = false;
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Commande de test : (nom, priorité, clé)
+    #[derive(Debug, PartialEq)]
+    struct Job(&'static str, Priority, Option<&'static str>);
+
+    impl Prioritized for Job {
+        fn priority(&self) -> Priority {
+            self.1
+        }
+
+        fn supersede_key(&self) -> Option<String> {
+            self.2.map(str::to_string)
+        }
+    }
+
+    #[test]
+    fn test_priority_then_arrival_order() {
+        let (tx, rx) = channel();
+        tx.send(Job("earnings", Priority::Background, None)).unwrap();
+        tx.send(Job("refresh", Priority::AutoRefresh, None)).unwrap();
+        tx.send(Job("add", Priority::User, None)).unwrap();
+        tx.send(Job("export", Priority::User, None)).unwrap();
+        assert_eq!(tx.len(), 4);
+
+        let order: Vec<&str> = (0..4).map(|_| rx.recv().unwrap().0).collect();
+        assert_eq!(order, ["add", "export", "refresh", "earnings"]);
+        assert!(tx.is_empty());
+    }
+
+    #[test]
+    fn test_superseded_work_is_dropped() {
+        let (tx, rx) = channel();
+        tx.send(Job("auto AAPL", Priority::AutoRefresh, Some("reload:AAPL"))).unwrap();
+        tx.send(Job("auto MSFT", Priority::AutoRefresh, Some("reload:MSFT"))).unwrap();
+
+        // Rechargement demandé : l'auto-refresh d'AAPL en file est retiré
+        tx.send(Job("user AAPL", Priority::User, Some("reload:AAPL"))).unwrap();
+        // Une priorité plus basse ne retire pas la demande de l'utilisateur
+        tx.send(Job("backfill AAPL", Priority::Background, Some("reload:AAPL"))).unwrap();
+
+        let order: Vec<&str> = (0..3).map(|_| rx.recv().unwrap().0).collect();
+        assert_eq!(order, ["user AAPL", "auto MSFT", "backfill AAPL"]);
+
+        // Priorité imposée à l'envoi
+        tx.send_with_priority(Priority::User, Job("pushed", Priority::Background, None)).unwrap();
+        tx.send(Job("refresh", Priority::AutoRefresh, None)).unwrap();
+        assert_eq!(rx.recv().unwrap().0, "pushed");
+        assert_eq!(rx.recv().unwrap().0, "refresh");
+    }
+
+    #[test]
+    fn test_disconnection() {
+        let (tx, rx) = channel::<Job>();
+        let worker = std::thread::spawn(move || {
+            let mut received = Vec::new();
+            while let Ok(job) = rx.recv() {
+                received.push(job.0);
+            }
+            received
+        });
+
+        let other = tx.clone();
+        other.send(Job("a", Priority::User, None)).unwrap();
+        drop(other);
+        tx.send(Job("b", Priority::User, None)).unwrap();
+        drop(tx);
+        assert_eq!(worker.join().unwrap(), ["a", "b"]);
+
+        let (tx, rx) = channel::<Job>();
+        drop(rx);
+        assert!(tx.send(Job("lost", Priority::User, None)).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+// ============================================================================
+// API HTTP locale (lecture seule)
+// ============================================================================
+// Expose l'état de l'application en JSON pour d'autres outils (barre
+// d'état, tableau de bord, script ...), en mode TUI comme en mode démon.
+// Activée par "http_api": "127.0.0.1:8787" dans config.json
+//
+//   GET /api/watchlist  → [{ "symbol": "AAPL", "name": "Apple Inc.", "type": "Stock" }]
+//   GET /api/quotes     → [{ "symbol": "AAPL", "price": 168.42, "change_percent": 1.25, ... }]
+//   GET /api/portfolio  → { "positions": [...], "summary": { "total_value": ... } }
+//                          (prix dans la devise du ticker, valeurs en dollars)
+//   GET /metrics        → métriques des téléchargements (format Prometheus)
+//
+// CONCEPT : Lecture seule
+// - Seules les requêtes GET sont acceptées (405 sinon)
+// - Le lock sur App n'est tenu que le temps de construire la réponse
+// ============================================================================
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{header, Body, Method, Request, Response, Server, StatusCode};
+use serde_json::{json, Value};
+use tracing::{debug, info};
+
+use crate::api::metrics;
+use crate::app::App;
+use crate::models::portfolio::to_usd;
+use crate::service::Quote;
+
+/// Réponse JSON d'une route (None si la route n'existe pas)
+pub fn route(path: &str, app: &App) -> Option<Value> {
+    match path.trim_end_matches('/') {
+        "" | "/api" => Some(json!({
+            "endpoints": ["/api/watchlist", "/api/quotes", "/api/portfolio", "/metrics"],
+        })),
+        "/api/watchlist" => Some(
+            app.watchlist
+                .iter()
+                .map(|item| json!({
+                    "symbol": item.symbol,
+                    "name": item.name,
+                    "type": item.ticker_type(),
+                }))
+                .collect(),
+        ),
+        "/api/quotes" => Some(json!(app.watchlist.iter().map(Quote::from_item).collect::<Vec<_>>())),
+        "/api/portfolio" => Some(portfolio(app)),
+        _ => None,
+    }
+}
+
+/// Positions valorisées au prix actuel et résumé du portefeuille
+///
+/// "price" est dans la devise du ticker ("currency"), "value" en dollars
+/// comme "total_value" : les valeurs s'additionnent
+fn portfolio(app: &App) -> Value {
+    let positions: Vec<Value> = app
+        .portfolio
+        .positions
+        .iter()
+        .map(|position| {
+            let item = app.watchlist.iter().find(|item| item.symbol == position.symbol);
+            let price = item.and_then(|item| item.current_price());
+            let currency = item.map(|item| item.currency());
+            let value = price
+                .zip(currency.as_ref())
+                .map(|(price, currency)| to_usd(price * position.quantity, currency, &app.watchlist));
+            json!({
+                "symbol": position.symbol,
+                "quantity": position.quantity,
+                "price": price,
+                "currency": currency.as_ref().map(|currency| currency.code()),
+                "value": value,
+            })
+        })
+        .collect();
+
+    let summary = app.portfolio.summary(&app.watchlist).map(|summary| {
+        json!({
+            "total_value": summary.total_value,
+            "cash": summary.cash,
+            "day_change": summary.day_change,
+            "day_change_percent": summary.day_change_percent,
+            "unpriced": summary.unpriced,
+        })
+    });
+
+    json!({ "positions": positions, "cash": app.portfolio.cash, "summary": summary })
+}
+
+/// Traite une requête HTTP
+fn handle(request: &Request<Body>, app: &Mutex<App>) -> Response<Body> {
+    debug!(method = %request.method(), path = %request.uri().path(), "HTTP API request");
+
+    if request.method() != Method::GET {
+        return json_response(StatusCode::METHOD_NOT_ALLOWED, json!({ "error": "method not allowed" }));
+    }
+
+    // Métriques Prometheus : texte, sans passer par App
+    if request.uri().path().trim_end_matches('/') == "/metrics" {
+        return metrics_response(&metrics::prometheus_text(&metrics::snapshot()));
+    }
+
+    let body = {
+        let app_lock = app.lock().unwrap();
+        route(request.uri().path(), &app_lock)
+    };
+
+    match body {
+        Some(body) => json_response(StatusCode::OK, body),
+        None => json_response(StatusCode::NOT_FOUND, json!({ "error": "not found" })),
+    }
+}
+
+/// Construit une réponse JSON
+fn json_response(status: StatusCode, body: Value) -> Response<Body> {
+    let mut response = Response::new(Body::from(body.to_string()));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(header::CONTENT_TYPE, header::HeaderValue::from_static("application/json"));
+    response
+}
+
+/// Construit la réponse texte des métriques (format d'exposition Prometheus)
+fn metrics_response(text: &str) -> Response<Body> {
+    let mut response = Response::new(Body::from(text.to_string()));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("text/plain; version=0.0.4"),
+    );
+    response
+}
+
+/// Lance le serveur HTTP (tourne jusqu'à l'arrêt du runtime)
+pub async fn serve(address: &str, app: Arc<Mutex<App>>) -> Result<()> {
+    let address: SocketAddr = address
+        .parse()
+        .with_context(|| format!("Adresse d'écoute invalide : \"{}\"", address))?;
+
+    // CONCEPT HYPER : make_service_fn
+    // - Crée un service par connexion, chacun avec sa copie de l'Arc
+    let make_service = make_service_fn(move |_connection| {
+        let app = app.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let response = handle(&request, &app);
+                async move { Ok::<_, Infallible>(response) }
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&address)
+        .with_context(|| format!("Impossible d'écouter sur {}", address))?
+        .serve(make_service);
+    info!(%address, "HTTP API listening");
+
+    server.await.context("Arrêt du serveur HTTP")
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interval, OHLCData, Timeframe, WatchlistItem, OHLC};
+
+    fn app() -> App {
+        let mut app = App::with_watchlist(vec![WatchlistItem::new("AAPL".to_string(), "Apple".to_string())]);
+        app.portfolio.set_position("AAPL", 10.0);
+        app
+    }
+
+    #[test]
+    fn test_routes() {
+        let app = app();
+
+        let watchlist = route("/api/watchlist", &app).unwrap();
+        assert_eq!(watchlist[0]["symbol"], "AAPL");
+        assert_eq!(watchlist[0]["type"], "Stock");
+
+        let quotes = route("/api/quotes/", &app).unwrap();
+        assert!(quotes[0]["price"].is_null());
+
+        let portfolio = route("/api/portfolio", &app).unwrap();
+        assert_eq!(portfolio["positions"][0]["quantity"], 10.0);
+        assert!(portfolio["positions"][0]["value"].is_null());
+
+        assert!(route("/api/unknown", &app).is_none());
+    }
+
+    #[test]
+    fn test_portfolio_values_in_dollars() {
+        let item = |symbol: &str, price: f64| {
+            let mut data = OHLCData::new(symbol.to_string(), Interval::D1, Timeframe::OneWeek);
+            data.add_candle(OHLC::new(chrono::Utc::now(), price, price, price, price, 1000));
+            WatchlistItem::with_data(symbol.to_string(), symbol.to_string(), data)
+        };
+        let mut app = App::with_watchlist(vec![item("AIR.PA", 150.0), item("EURUSD=X", 1.1)]);
+        app.portfolio.set_position("AIR.PA", 10.0);
+
+        // 10 × 150 € × 1.10 = 1650 $, comme dans le total
+        let portfolio = route("/api/portfolio", &app).unwrap();
+        let position = &portfolio["positions"][0];
+        assert_eq!(position["price"], 150.0);
+        assert_eq!(position["currency"], "EUR");
+        assert!((position["value"].as_f64().unwrap() - 1650.0).abs() < 1e-9);
+        assert_eq!(position["value"], portfolio["summary"]["total_value"]);
+    }
+
+    #[test]
+    fn test_only_get_is_allowed() {
+        let app = Mutex::new(app());
+        let request = Request::post("/api/quotes").body(Body::empty()).unwrap();
+        assert_eq!(handle(&request, &app).status(), StatusCode::METHOD_NOT_ALLOWED);
+
+        let request = Request::get("/nope").body(Body::empty()).unwrap();
+        assert_eq!(handle(&request, &app).status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_metrics_endpoint() {
+        let app = Mutex::new(app());
+        let request = Request::get("/metrics").body(Body::empty()).unwrap();
+        let response = handle(&request, &app);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/plain; version=0.0.4");
+    }
+}
@@ -0,0 +1,217 @@
+// ============================================================================
+// WatchlistService - Couche données embarquable
+// ============================================================================
+// API de haut niveau, sans TUI, pour utiliser les données de LazyWallet
+// depuis un autre programme Rust :
+//
+//   let mut service = WatchlistService::new();
+//   service.add("AAPL");
+//   service.refresh().await;
+//   if let Some(quote) = service.quote("AAPL") { ... }
+//
+// Avec `default-features = false`, ni ratatui ni crossterm ne sont compilés
+// (voir la feature "tui" dans Cargo.toml)
+//
+// CONCEPT RUST : Façade
+// - Regroupe WatchlistItem, les appels Yahoo et le cache par intervalle
+//   derrière quelques méthodes
+// - Le mode démon l'utilise aussi pour décrire les cours (Quote)
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use tracing::{error, info};
+
+use crate::api::yahoo::fetch_ticker_data;
+use crate::models::{Interval, OHLCData, WatchlistItem};
+
+/// Cotation d'un ticker
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Quote {
+    pub symbol: String,
+    pub name: String,
+
+    /// Dernier prix (None si les données ne sont pas chargées)
+    pub price: Option<f64>,
+
+    /// Variation du jour en pourcentage
+    pub change_percent: Option<f64>,
+}
+
+impl Quote {
+    /// Cotation d'un item de la watchlist
+    pub fn from_item(item: &WatchlistItem) -> Self {
+        Self {
+            symbol: item.symbol.clone(),
+            name: item.name.clone(),
+            price: item.current_price(),
+            change_percent: item.change_percent(),
+        }
+    }
+}
+
+/// Watchlist pilotable sans interface
+#[derive(Debug, Clone, Default)]
+pub struct WatchlistService {
+    items: Vec<WatchlistItem>,
+
+    /// Intervalle chargé par refresh()
+    interval: Interval,
+}
+
+impl WatchlistService {
+    /// Watchlist vide, intervalle par défaut (30m)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Watchlist vide rafraîchie à l'intervalle donné
+    pub fn with_interval(interval: Interval) -> Self {
+        Self {
+            items: Vec::new(),
+            interval,
+        }
+    }
+
+    /// Ajoute un symbole (false s'il est déjà suivi)
+    ///
+    /// Aucune donnée n'est chargée avant le prochain refresh()
+    pub fn add(&mut self, symbol: &str) -> bool {
+        let symbol = symbol.trim().to_uppercase();
+        if symbol.is_empty() || self.contains(&symbol) {
+            return false;
+        }
+
+        self.items.push(WatchlistItem::new(symbol.clone(), symbol));
+        true
+    }
+
+    /// Retire un symbole (false s'il n'était pas suivi)
+    pub fn remove(&mut self, symbol: &str) -> bool {
+        let before = self.items.len();
+        self.items.retain(|item| !item.symbol.eq_ignore_ascii_case(symbol));
+        self.items.len() != before
+    }
+
+    /// Le symbole est-il suivi ?
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.item(symbol).is_some()
+    }
+
+    /// Symboles suivis, dans l'ordre d'ajout
+    pub fn symbols(&self) -> Vec<&str> {
+        self.items.iter().map(|item| item.symbol.as_str()).collect()
+    }
+
+    /// Items de la watchlist (données et cache compris)
+    pub fn items(&self) -> &[WatchlistItem] {
+        &self.items
+    }
+
+    /// Recharge tous les symboles
+    ///
+    /// Un échec n'interrompt pas les autres : les erreurs sont retournées
+    /// avec leur symbole
+    pub async fn refresh(&mut self) -> Vec<(String, anyhow::Error)> {
+        let mut errors = Vec::new();
+        for symbol in self.symbols().into_iter().map(String::from).collect::<Vec<_>>() {
+            if let Err(e) = self.refresh_symbol(&symbol).await {
+                error!(ticker = %symbol, error = ?e, "Failed to refresh ticker");
+                errors.push((symbol, e));
+            }
+        }
+        errors
+    }
+
+    /// Recharge un symbole à l'intervalle du service
+    pub async fn refresh_symbol(&mut self, symbol: &str) -> Result<()> {
+        let interval = self.interval;
+        let (data, long_name) = fetch_ticker_data(symbol, interval).await?;
+        info!(ticker = %symbol, candles = data.len(), "Ticker refreshed");
+
+        let item = self
+            .item_mut(symbol)
+            .with_context(|| format!("{} n'est pas dans la watchlist", symbol))?;
+        if let Some(name) = long_name {
+            item.name = name;
+        }
+        item.update_data(data);
+        Ok(())
+    }
+
+    /// Cotation d'un symbole suivi
+    pub fn quote(&self, symbol: &str) -> Option<Quote> {
+        self.item(symbol).map(Quote::from_item)
+    }
+
+    /// Cotations de tous les symboles suivis
+    pub fn quotes(&self) -> Vec<Quote> {
+        self.items.iter().map(Quote::from_item).collect()
+    }
+
+    /// Historique chargé par le dernier refresh()
+    pub fn history(&self, symbol: &str) -> Option<&OHLCData> {
+        self.item(symbol)?.data.as_ref()
+    }
+
+    /// Historique à un intervalle donné (chargé une fois puis gardé en cache)
+    pub async fn fetch_history(&mut self, symbol: &str, interval: Interval) -> Result<&OHLCData> {
+        let item = self
+            .item(symbol)
+            .with_context(|| format!("{} n'est pas dans la watchlist", symbol))?;
+
+        if item.data_for(interval).is_none() {
+            let (data, _) = fetch_ticker_data(symbol, interval).await?;
+            if let Some(item) = self.item_mut(symbol) {
+                item.cache_data(data);
+            }
+        }
+
+        self.item(symbol)
+            .and_then(|item| item.data_for(interval))
+            .with_context(|| format!("Pas de données {} pour {}", interval.label(), symbol))
+    }
+
+    fn item(&self, symbol: &str) -> Option<&WatchlistItem> {
+        self.items.iter().find(|item| item.symbol.eq_ignore_ascii_case(symbol))
+    }
+
+    fn item_mut(&mut self, symbol: &str) -> Option<&mut WatchlistItem> {
+        self.items.iter_mut().find(|item| item.symbol.eq_ignore_ascii_case(symbol))
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_and_remove() {
+        let mut service = WatchlistService::new();
+        assert!(service.add("aapl"));
+        assert!(service.add("BTC-USD"));
+        assert!(!service.add("AAPL"));
+        assert!(!service.add("  "));
+        assert_eq!(service.symbols(), vec!["AAPL", "BTC-USD"]);
+
+        assert!(service.remove("aapl"));
+        assert!(!service.remove("AAPL"));
+        assert_eq!(service.symbols(), vec!["BTC-USD"]);
+    }
+
+    #[test]
+    fn test_quote_without_data() {
+        let mut service = WatchlistService::new();
+        service.add("AAPL");
+
+        let quote = service.quote("AAPL").unwrap();
+        assert_eq!(quote.symbol, "AAPL");
+        assert!(quote.price.is_none());
+        assert!(service.history("AAPL").is_none());
+        assert!(service.quote("MSFT").is_none());
+    }
+}
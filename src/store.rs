@@ -0,0 +1,222 @@
+// ============================================================================
+// Historique local des bougies (SQLite)
+// ============================================================================
+// Option "candle_store" de config.json : chaque série téléchargée est ajoutée
+// à ~/.local/share/lazywallet/candles.sqlite, par symbole et intervalle
+//
+// - Les graphiques sont servis depuis la base : seules les bougies plus
+//   récentes que la dernière connue sont demandées à Yahoo (delta)
+// - L'historique dépasse peu à peu la limite de Yahoo (60 jours en 5m,
+//   2 ans en 1h) : la base garde tout ce qui a été téléchargé un jour
+// - Sans réseau, la dernière série connue reste affichable
+//
+// Table candles : (symbol, interval, timestamp) → open, high, low, close, volume
+//
+// CONCEPT RUST : Mutex<Option<CandleStore>> global
+// - La base est ouverte une fois au démarrage (si l'option est active)
+// - rusqlite::Connection n'est pas Sync : le Mutex sérialise les accès du
+//   worker, du démon et de l'API HTTP
+// - Sans base ouverte, les fonctions globales ne font rien
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use tracing::warn;
+
+use crate::models::{Interval, OHLCData, OHLC};
+
+/// Schéma de la base (créé à l'ouverture si absent)
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS candles (
+        symbol    TEXT    NOT NULL,
+        interval  TEXT    NOT NULL,
+        timestamp INTEGER NOT NULL,
+        open      REAL    NOT NULL,
+        high      REAL    NOT NULL,
+        low       REAL    NOT NULL,
+        close     REAL    NOT NULL,
+        volume    INTEGER NOT NULL,
+        PRIMARY KEY (symbol, interval, timestamp)
+    )";
+
+/// Base SQLite des bougies téléchargées
+pub struct CandleStore {
+    connection: Connection,
+}
+
+impl CandleStore {
+    /// Chemin par défaut de la base
+    ///
+    /// - Linux : ~/.local/share/lazywallet/candles.sqlite
+    /// - Fallback : ./candles.sqlite
+    pub fn default_path() -> PathBuf {
+        dirs::data_dir()
+            .map(|dir| dir.join("lazywallet").join("candles.sqlite"))
+            .unwrap_or_else(|| PathBuf::from("./candles.sqlite"))
+    }
+
+    /// Ouvre (ou crée) la base
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Échec de la création du répertoire {}", parent.display()))?;
+        }
+        let connection =
+            Connection::open(path).with_context(|| format!("Échec de l'ouverture de {}", path.display()))?;
+        Self::with_connection(connection)
+    }
+
+    /// Base temporaire en mémoire (tests)
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> Result<Self> {
+        connection.execute_batch(SCHEMA).context("Échec de la création de la table candles")?;
+        Ok(Self { connection })
+    }
+
+    /// Ajoute les bougies d'une série
+    ///
+    /// Une bougie déjà connue (même date) est remplacée : la dernière bougie
+    /// d'une série, encore en cours, est ainsi mise à jour au delta suivant
+    pub fn save(&mut self, data: &OHLCData) -> Result<()> {
+        let transaction = self.connection.transaction()?;
+        {
+            let mut insert = transaction.prepare_cached(
+                "INSERT OR REPLACE INTO candles (symbol, interval, timestamp, open, high, low, close, volume)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            )?;
+            for candle in &data.candles {
+                insert.execute(params![
+                    data.symbol,
+                    data.interval.label(),
+                    candle.timestamp.timestamp(),
+                    candle.open,
+                    candle.high,
+                    candle.low,
+                    candle.close,
+                    candle.volume as i64,
+                ])?;
+            }
+        }
+        transaction.commit().context("Échec de l'enregistrement des bougies")
+    }
+
+    /// Relit toute la série connue d'un symbole (None si aucune bougie)
+    pub fn load(&self, symbol: &str, interval: Interval) -> Result<Option<OHLCData>> {
+        let mut select = self.connection.prepare_cached(
+            "SELECT timestamp, open, high, low, close, volume FROM candles
+             WHERE symbol = ?1 AND interval = ?2 ORDER BY timestamp",
+        )?;
+        let rows = select.query_map(params![symbol, interval.label()], |row| {
+            let timestamp = DateTime::from_timestamp(row.get(0)?, 0).unwrap_or_default();
+            let volume: i64 = row.get(5)?;
+            Ok(OHLC::new(timestamp, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?, volume as u64))
+        })?;
+
+        let mut data = OHLCData::with_interval(symbol.to_string(), interval);
+        for candle in rows {
+            data.add_candle(candle?);
+        }
+        Ok((!data.is_empty()).then_some(data))
+    }
+
+    /// Date de la bougie la plus récente connue
+    pub fn last_timestamp(&self, symbol: &str, interval: Interval) -> Result<Option<DateTime<Utc>>> {
+        let latest: Option<i64> = self
+            .connection
+            .query_row(
+                "SELECT MAX(timestamp) FROM candles WHERE symbol = ?1 AND interval = ?2",
+                params![symbol, interval.label()],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        Ok(latest.and_then(|seconds| DateTime::from_timestamp(seconds, 0)))
+    }
+}
+
+// ============================================================================
+// Base globale
+// ============================================================================
+
+static STORE: Mutex<Option<CandleStore>> = Mutex::new(None);
+
+/// Ouvre la base pour toute la durée du programme
+pub fn enable(path: &Path) -> Result<()> {
+    let store = CandleStore::open(path)?;
+    *STORE.lock().unwrap() = Some(store);
+    Ok(())
+}
+
+/// Date de la bougie la plus récente connue (None : base fermée ou série inconnue)
+pub fn last_timestamp(symbol: &str, interval: Interval) -> Option<DateTime<Utc>> {
+    let store = STORE.lock().unwrap();
+    store.as_ref()?.last_timestamp(symbol, interval).unwrap_or_else(|e| {
+        warn!(symbol = %symbol, error = %e, "Failed to read the candle store");
+        None
+    })
+}
+
+/// Série complète connue d'un symbole
+pub fn load(symbol: &str, interval: Interval) -> Option<OHLCData> {
+    let store = STORE.lock().unwrap();
+    store.as_ref()?.load(symbol, interval).unwrap_or_else(|e| {
+        warn!(symbol = %symbol, error = %e, "Failed to read the candle store");
+        None
+    })
+}
+
+/// Ajoute une série téléchargée (sans effet si la base est fermée)
+pub fn save(data: &OHLCData) {
+    if let Some(store) = STORE.lock().unwrap().as_mut() {
+        if let Err(e) = store.save(data) {
+            warn!(symbol = %data.symbol, error = %e, "Failed to write the candle store");
+        }
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn series(hours: &[i64], close: f64) -> OHLCData {
+        let start = Utc.with_ymd_and_hms(2024, 3, 4, 14, 0, 0).unwrap();
+        let mut data = OHLCData::with_interval("AAPL".to_string(), Interval::H1);
+        for hour in hours {
+            data.add_candle(OHLC::new(start + chrono::Duration::hours(*hour), 1.0, 2.0, 0.5, close, 100));
+        }
+        data
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let mut store = CandleStore::open_in_memory().unwrap();
+        assert!(store.load("AAPL", Interval::H1).unwrap().is_none());
+        assert_eq!(store.last_timestamp("AAPL", Interval::H1).unwrap(), None);
+
+        store.save(&series(&[0, 1, 2], 1.5)).unwrap();
+        // Delta : la dernière bougie connue est remplacée, la suivante ajoutée
+        store.save(&series(&[2, 3], 1.8)).unwrap();
+
+        let data = store.load("AAPL", Interval::H1).unwrap().unwrap();
+        assert_eq!(data.len(), 4);
+        assert_eq!(data.candles[1].close, 1.5);
+        assert_eq!(data.candles[2].close, 1.8);
+        assert_eq!(data.candles[3].volume, 100);
+        assert_eq!(store.last_timestamp("AAPL", Interval::H1).unwrap(), Some(data.candles[3].timestamp));
+
+        // Séries séparées par intervalle
+        assert!(store.load("AAPL", Interval::D1).unwrap().is_none());
+    }
+}
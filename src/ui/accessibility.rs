@@ -0,0 +1,119 @@
+// ============================================================================
+// Accessibilité : sans animation, sans emoji
+// ============================================================================
+// Deux options indépendantes (config.json ou ligne de commande) :
+//
+// - "reduced_motion" / `--reduced-motion` : plus aucun texte clignotant
+//   (confirmations, chargement, notifications), remplacé par du gras
+// - "no_emoji" / `--no-emoji` : les pictogrammes (🕯️ 📊 ⚠ ⏳ ...) deviennent
+//   du texte simple, pour les lecteurs d'écran et les polices sans emoji
+//
+//   " 🕯️ AAPL - 1d "   →   "    AAPL - 1d "
+//   "⚠ Échec"          →   "! Échec"
+//   "⏳ Chargement..."  →   ".. Chargement..."
+//
+// CONCEPT : Conversion après le rendu (comme le mode ASCII)
+// - Les écrans dessinent normalement, puis chaque cellule du buffer est
+//   corrigée : dashboard, graphique, footers et notifications d'un coup
+// - Un pictogramme garde sa largeur : un emoji de 2 colonnes devient 2
+//   caractères (ou des espaces s'il n'est que décoratif, le texte qui le
+//   suit disant déjà tout : "📅 ER in 6d", "🔒 discret")
+// - Les coches ✓ ✗ restent : ce sont des caractères de texte, pas des emojis
+// ============================================================================
+
+use ratatui::buffer::Buffer;
+use ratatui::style::Modifier;
+
+/// Équivalent texte d'un pictogramme (None : symbole conservé)
+///
+/// Le texte rendu a la largeur du pictogramme, espaces compris
+pub fn plain_symbol(symbol: &str) -> Option<&'static str> {
+    let c = symbol.chars().next()?;
+    let plain = match c {
+        // Pictogrammes porteurs de sens : texte court
+        '⚠' => "!",
+        '⏳' => "..",
+        '✅' => "OK",
+        // Coches : caractères de texte, conservés
+        '✓' | '✗' => return None,
+        // Autres pictogrammes (titres, badges) : décoratifs
+        '\u{2300}'..='\u{23FF}' | '\u{2600}'..='\u{27BF}' => " ",
+        '\u{1F000}'.. => "  ",
+        _ => return None,
+    };
+    Some(plain)
+}
+
+/// Remplace les pictogrammes du buffer par leur équivalent texte
+///
+/// Un emoji de 2 colonnes masque la cellule suivante (un espace dans le
+/// buffer) : son équivalent s'étale sur les deux
+pub fn strip_emoji(buffer: &mut Buffer) {
+    let mut index = 0;
+    while index < buffer.content.len() {
+        if let Some(plain) = plain_symbol(buffer.content[index].symbol()) {
+            let width = buffer.content.len() - index;
+            for (offset, c) in plain.chars().take(width).enumerate() {
+                buffer.content[index + offset].set_char(c);
+            }
+            index += plain.chars().count().max(1);
+        } else {
+            index += 1;
+        }
+    }
+}
+
+/// Retire le clignotement de tout le buffer (gras à la place)
+pub fn reduce_motion(buffer: &mut Buffer) {
+    let blink = Modifier::SLOW_BLINK | Modifier::RAPID_BLINK;
+    for cell in buffer.content.iter_mut() {
+        if cell.modifier.intersects(blink) {
+            cell.modifier.remove(blink);
+            cell.modifier.insert(Modifier::BOLD);
+        }
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::models::WatchlistItem;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+
+    #[test]
+    fn test_plain_symbols() {
+        assert_eq!(plain_symbol("⚠"), Some("!"));
+        assert_eq!(plain_symbol("⚠️"), Some("!"));
+        assert_eq!(plain_symbol("⏳"), Some(".."));
+        assert_eq!(plain_symbol("🕯️"), Some("  "));
+        assert_eq!(plain_symbol("✓"), None);
+        assert_eq!(plain_symbol("▲"), None);
+        assert_eq!(plain_symbol("é"), None);
+    }
+
+    #[test]
+    fn test_dashboard_without_emoji_or_blink() {
+        let mut app = App::with_watchlist(vec![
+            sample_item("AAPL", "Apple Inc.", 170.0, 30),
+            WatchlistItem::new("TSLA".to_string(), "Tesla".to_string()),
+        ]);
+        app.watchlist[1].load_error = Some("HTTP 500".to_string());
+        app.request_quit();
+
+        let mut buffer = render_to_buffer(100, 20, |frame| crate::ui::render(frame, &app));
+        assert!(buffer.content.iter().any(|cell| cell.modifier.contains(Modifier::SLOW_BLINK)));
+        strip_emoji(&mut buffer);
+        reduce_motion(&mut buffer);
+
+        let text = buffer_text(&buffer);
+        assert!(text.contains("! Échec"), "{}", text);
+        assert!(text.contains("Watchlist"), "{}", text);
+        assert!(!text.chars().any(|c| plain_symbol(&c.to_string()).is_some()), "{}", text);
+        assert!(!buffer.content.iter().any(|cell| cell.modifier.contains(Modifier::SLOW_BLINK)));
+    }
+}
@@ -0,0 +1,232 @@
+// ============================================================================
+// Allocation - Vue répartition du portefeuille
+// ============================================================================
+// Affiche la part de chaque position dans la valeur totale, par ticker et
+// par classe d'actif, sous forme de jauges horizontales :
+//
+//   AAPL      ████████████░░░░░░░░  58.2%   $1 100.00
+//   BTC-USD   ██████░░░░░░░░░░░░░░  31.0%     $586.00
+//   Cash EUR  ██░░░░░░░░░░░░░░░░░░  10.8%     $204.00
+//
+// Les parts sont recalculées à chaque frame à partir des prix de la watchlist
+// Les liquidités (touche t : dépôt / retrait) ont leur ligne par devise et la
+// classe "Liquidités", converties en dollars avec les paires de change
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::models::portfolio::AllocationSlice;
+use crate::ui::dashboard::toast_line;
+use crate::ui::format::format_money;
+
+/// Largeurs des colonnes libellé, pourcentage et valeur
+const LABEL_WIDTH: usize = 10;
+const PERCENT_WIDTH: usize = 7;
+const VALUE_WIDTH: usize = 13;
+
+/// Couleurs des jauges (cycle si plus de lignes que de couleurs)
+const BAR_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Blue,
+    Color::LightRed,
+];
+
+/// Dessine la vue répartition (header, deux panneaux, footer)
+pub fn render_allocation(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Jauges
+            Constraint::Length(3), // Footer
+        ])
+        .split(area)
+        .to_vec();
+
+    render_header(frame, app, chunks[0]);
+
+    let by_ticker = app.portfolio.allocation_by_ticker(&app.watchlist);
+    let by_class = app.portfolio.allocation_by_asset_class(&app.watchlist);
+
+    if by_ticker.is_empty() {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let text = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                "Aucune position cotée",
+                Style::default().fg(Color::Gray),
+            )),
+            Line::from(Span::styled(
+                "Appuyez sur [p] depuis le dashboard pour saisir une quantité détenue",
+                Style::default().fg(Color::DarkGray),
+            )),
+            Line::from(Span::styled(
+                "ou sur [t] pour déposer des liquidités",
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+        let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
+        frame.render_widget(paragraph, chunks[1]);
+    } else {
+        let panels = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1])
+            .to_vec();
+
+        render_slices(frame, " Par ticker ", &by_ticker, panels[0]);
+        render_slices(frame, " Par classe d'actif ", &by_class, panels[1]);
+    }
+
+    render_footer(frame, app, chunks[2]);
+}
+
+/// Dessine le header avec la valeur totale
+fn render_header(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" 🥧 Répartition du portefeuille ")
+        .title_alignment(Alignment::Center);
+
+    let line = match app.portfolio.summary(&app.watchlist) {
+        Some(summary) => {
+            let mut spans = vec![
+                Span::styled("Valeur totale ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format_money(summary.total_value),
+                    Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                ),
+            ];
+            if !app.portfolio.cash.is_empty() {
+                spans.push(Span::styled(
+                    format!("  dont liquidités {}", format_money(summary.cash)),
+                    Style::default().fg(Color::Gray),
+                ));
+            }
+            Line::from(spans)
+        }
+        None => Line::from(Span::styled("Portefeuille vide", Style::default().fg(Color::Gray))),
+    };
+
+    let paragraph = Paragraph::new(line).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+/// Dessine un panneau de jauges (une ligne par part)
+fn render_slices(frame: &mut Frame, title: &str, slices: &[AllocationSlice], area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(title.to_string());
+
+    // Largeur disponible pour la jauge : bordures (2) + colonnes + espaces (3)
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let bar_width = inner_width
+        .saturating_sub(LABEL_WIDTH + PERCENT_WIDTH + VALUE_WIDTH + 3)
+        .max(1);
+
+    let lines: Vec<Line> = slices
+        .iter()
+        .enumerate()
+        .map(|(i, slice)| {
+            let color = BAR_COLORS[i % BAR_COLORS.len()];
+            let (filled, empty) = gauge(slice.percent, bar_width);
+
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", slice.label, width = LABEL_WIDTH),
+                    Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(filled, Style::default().fg(color)),
+                Span::styled(empty, Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!(" {:>width$.1}%", slice.percent, width = PERCENT_WIDTH - 1),
+                    Style::default().fg(color),
+                ),
+                Span::styled(
+                    format!(" {:>width$}", format_money(slice.value), width = VALUE_WIDTH),
+                    Style::default().fg(Color::Gray),
+                ),
+            ])
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Découpe une jauge de `width` caractères en partie remplie / vide
+fn gauge(percent: f64, width: usize) -> (String, String) {
+    let filled = ((percent / 100.0) * width as f64).round().clamp(0.0, width as f64) as usize;
+    ("█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// Dessine le footer (raccourcis ou notification)
+fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let line = match app.active_toast() {
+        Some(toast) => toast_line(toast),
+        None => Line::from(vec![
+            Span::styled("[t]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" Dépôt / retrait  "),
+            Span::styled("[w / ESC]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Retour  "),
+            Span::styled("[q]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Quit"),
+        ]),
+    };
+
+    let paragraph = Paragraph::new(line).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::journal::CashMovement;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+
+    #[test]
+    fn test_allocation_includes_cash() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 10)]);
+        app.portfolio.set_position("AAPL", 1.0);
+        app.record_cash_movement(CashMovement::parse_input("+500").unwrap());
+        app.show_allocation();
+
+        let text = buffer_text(&render_to_buffer(120, 20, |frame| render_allocation(frame, &app, frame.size())));
+        assert!(text.contains("dont liquidités $500.00"), "{}", text);
+        assert!(text.contains("Cash USD"), "{}", text);
+        assert!(text.contains("Liquidités"), "{}", text);
+        assert!(text.contains("[t] Dépôt / retrait"), "{}", text);
+    }
+
+    #[test]
+    fn test_gauge() {
+        let (filled, empty) = gauge(50.0, 10);
+        assert_eq!(filled.chars().count(), 5);
+        assert_eq!(empty.chars().count(), 5);
+
+        let (filled, empty) = gauge(100.0, 8);
+        assert_eq!(filled.chars().count(), 8);
+        assert!(empty.is_empty());
+    }
+}
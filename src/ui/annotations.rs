@@ -0,0 +1,114 @@
+// ============================================================================
+// Annotations - Panneau des annotations du graphique
+// ============================================================================
+// Affiché à droite du graphique (touche a), les annotations du ticker de la
+// plus récente à la plus ancienne :
+//
+//   ┌ ◆ Annotations (3) ─────────────────┐
+//   │ 05/01 14:30  Cassure des 180       │
+//   │ 02/01 09:00  Résultats T3          │
+//   │ 12/12/2023   Nouveau CEO           │
+//   └────────────────────────────────────┘
+//
+// - Année affichée seulement si elle diffère de celle de la plus récente
+// - L'annotation du chandelier sous le curseur de mesure est surlignée
+// - Les annotations hors des données chargées sont grisées
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use chrono::Datelike;
+
+use crate::app::App;
+use crate::models::annotations::candle_index;
+use crate::models::{OHLCData, WatchlistItem};
+
+/// Largeur du panneau (bordures incluses)
+pub const ANNOTATIONS_PANEL_WIDTH: u16 = 36;
+
+/// Dessine le panneau des annotations du ticker
+pub fn render_annotations(frame: &mut Frame, app: &App, item: &WatchlistItem, data: &OHLCData, area: Rect) {
+    let annotations = app.annotations.of(&item.symbol);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(format!(" ◆ Annotations ({}) ", annotations.len()));
+
+    if annotations.is_empty() {
+        let paragraph = Paragraph::new(vec![
+            Line::from(Span::styled("Aucune annotation", Style::default().fg(Color::Gray))),
+            Line::from(Span::styled("[u] curseur puis [n] annoter", Style::default().fg(Color::DarkGray))),
+        ])
+        .block(block)
+        .alignment(Alignment::Center);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let cursor = app.measure.map(|measure| measure.cursor);
+    let latest_year = annotations.last().map(|annotation| annotation.timestamp.year());
+
+    let lines: Vec<Line> = annotations
+        .iter()
+        .rev()
+        .map(|annotation| {
+            let index = candle_index(&data.candles, data.interval, annotation.timestamp);
+            let date = if Some(annotation.timestamp.year()) == latest_year {
+                annotation.timestamp.format("%d/%m %H:%M").to_string()
+            } else {
+                annotation.timestamp.format("%d/%m/%Y").to_string()
+            };
+
+            let mut text_style = Style::default().fg(if index.is_some() { Color::White } else { Color::DarkGray });
+            if index.is_some() && index == cursor {
+                text_style = text_style.add_modifier(Modifier::REVERSED);
+            }
+            Line::from(vec![
+                Span::styled(format!("{:<12} ", date), Style::default().fg(Color::Yellow)),
+                Span::styled(annotation.text.clone(), text_style),
+            ])
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: true }), area);
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::MeasureCursor;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+
+    #[test]
+    fn test_annotations_panel_lists_newest_first() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 60)]);
+        let data = app.watchlist[0].data.clone().unwrap();
+        app.annotations.set("AAPL", data.candles[10].timestamp, "Support");
+        app.annotations.set("AAPL", data.candles[40].timestamp, "Cassure");
+        app.measure = Some(MeasureCursor { cursor: 40, anchor: None });
+
+        let item = &app.watchlist[0];
+        let text = buffer_text(&render_to_buffer(40, 8, |frame| {
+            render_annotations(frame, &app, item, &data, frame.size())
+        }));
+        assert!(text.contains("Annotations (2)"), "{}", text);
+        let cassure = text.find("Cassure").expect("annotation récente affichée");
+        let support = text.find("Support").expect("annotation ancienne affichée");
+        assert!(cassure < support, "{}", text);
+
+        let empty = buffer_text(&render_to_buffer(40, 6, |frame| {
+            render_annotations(frame, &App::default(), item, &data, frame.size())
+        }));
+        assert!(empty.contains("Aucune annotation"), "{}", empty);
+    }
+}
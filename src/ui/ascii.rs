@@ -0,0 +1,105 @@
+// ============================================================================
+// Mode ASCII : rendu sans caractères de dessin Unicode
+// ============================================================================
+// Pour les terminaux ou polices sans glyphes de dessin (`--ascii` ou
+// "ascii": true dans config.json) :
+//
+//   ┌──┐ │ ┃ ▲ ▼ ●   →   +--+ | # ^ v o
+//
+// CONCEPT : Conversion après le rendu
+// - Les écrans dessinent normalement, puis chaque cellule du buffer est
+//   convertie : un seul point de passage pour les bordures, chandeliers,
+//   flèches et jauges de tous les écrans
+// - Les lettres accentuées restent intactes (seuls les symboles changent)
+// - Un emoji (2 colonnes) devient "*" : la cellule suivante, masquée par
+//   l'emoji, est déjà un espace dans le buffer
+// ============================================================================
+
+use ratatui::buffer::Buffer;
+
+/// Équivalent ASCII d'un symbole (None : symbole conservé)
+pub fn ascii_symbol(symbol: &str) -> Option<&'static str> {
+    let c = symbol.chars().next()?;
+    if symbol.is_ascii() {
+        return None;
+    }
+
+    let ascii = match c {
+        // Chandeliers : corps plein ou partiel → #, mèches → |
+        '┃' | '╻' | '╹' | '╽' | '╿' => "#",
+        '│' | '╷' | '╵' => "|",
+        '─' | '━' | '—' => "-",
+        '┊' => ":",
+        '╱' => "/",
+        '╲' => "\\",
+        // Flèches et marqueurs
+        '▲' | '↑' => "^",
+        '▼' | '↓' => "v",
+        '▶' | '→' => ">",
+        '◀' | '←' => "<",
+        '●' => "o",
+        '×' | '✗' => "x",
+        '✓' => "+",
+        '⚠' => "!",
+        '⏳' => "~",
+        '…' | '·' => ".",
+        // Pictogrammes des types d'actif (▪ tombe dans les formes → *)
+        '₿' => "B",
+        '≡' => "=",
+        '∑' => "I",
+        '¤' => "$",
+        '░' => ".",
+        // Autres traits et coins (┌ ┐ └ ┘ ├ ┤ ...)
+        '\u{2500}'..='\u{257F}' => "+",
+        // Blocs des jauges (█ ▓ ▏ ...)
+        '\u{2580}'..='\u{259F}' => "#",
+        // Formes, pictogrammes et emojis
+        '\u{25A0}'..='\u{27BF}' | '\u{1F000}'.. => "*",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Convertit tout le buffer en ASCII
+pub fn to_ascii(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        if let Some(ascii) = ascii_symbol(cell.symbol()) {
+            cell.set_symbol(ascii);
+        }
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+
+    #[test]
+    fn test_ascii_symbols() {
+        assert_eq!(ascii_symbol("┌"), Some("+"));
+        assert_eq!(ascii_symbol("┃"), Some("#"));
+        assert_eq!(ascii_symbol("▼"), Some("v"));
+        assert_eq!(ascii_symbol("📊"), Some("*"));
+        assert_eq!(ascii_symbol("🕯️"), Some("*"));
+        assert_eq!(ascii_symbol("é"), None);
+        assert_eq!(ascii_symbol("a"), None);
+    }
+
+    #[test]
+    fn test_dashboard_in_ascii() {
+        let app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 30)]);
+        let mut buffer = render_to_buffer(100, 20, |frame| crate::ui::render(frame, &app));
+        to_ascii(&mut buffer);
+        let text = buffer_text(&buffer);
+
+        assert!(text.starts_with('+'));
+        assert!(text.contains("^ +1.00%"));
+        // Plus aucun symbole : seules des lettres accentuées restent hors ASCII
+        assert!(text.chars().all(|c| c.is_ascii() || c.is_alphabetic()), "{}", text);
+    }
+}
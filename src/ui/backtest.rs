@@ -0,0 +1,225 @@
+// ============================================================================
+// Backtest - Vue résultats d'une stratégie
+// ============================================================================
+// Affiche le résultat du backtest de la stratégie courante sur les données
+// du graphique :
+//
+//   ┌ 🧪 Backtest AAPL (1d) - SMA 20/50 ─────────────────────────┐
+//   │ Rendement +18.4%  B&H +25.1%  Trades 6  Gagnants 50.0%     │
+//   └────────────────────────────────────────────────────────────┘
+//   ┌ Equity (base 100) ──────────────┐┌ Trades ────────────────┐
+//   │  stratégie (cyan) / B&H (gris)  ││ 12/03 → 02/05  +8.2%   │
+//   └─────────────────────────────────┘└────────────────────────┘
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols,
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::backtest::{BacktestResult, Trade};
+use crate::ui::dashboard::toast_line;
+
+/// Dessine la vue backtest (résumé, courbe d'equity + trades, footer)
+pub fn render_backtest(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Résumé
+            Constraint::Min(0),    // Courbe + trades
+            Constraint::Length(3), // Footer
+        ])
+        .split(area)
+        .to_vec();
+
+    match (&app.backtest, app.selected_item().and_then(|item| item.data.as_ref())) {
+        (Some(result), Some(data)) => {
+            let title = format!(
+                " 🧪 Backtest {} ({}) - {} ",
+                data.symbol,
+                data.interval.label(),
+                result.strategy.label()
+            );
+            render_summary(frame, result, &title, chunks[0]);
+
+            let panels = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+                .split(chunks[1])
+                .to_vec();
+
+            // Achat-conservation ramené en base 100 pour comparer les courbes
+            let first = data.candles.first().map(|c| c.close).unwrap_or(1.0);
+            let buy_and_hold: Vec<f64> = data.candles.iter().map(|c| c.close / first * 100.0).collect();
+            render_equity(frame, &result.equity_curve, &buy_and_hold, panels[0]);
+            render_trades(frame, &result.trades, panels[1]);
+        }
+        _ => {
+            let paragraph = Paragraph::new("Pas de données pour ce ticker")
+                .block(Block::default().borders(Borders::ALL).title(" 🧪 Backtest "))
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, chunks[0]);
+        }
+    }
+
+    render_footer(frame, app, chunks[2]);
+}
+
+/// Dessine la ligne de statistiques
+fn render_summary(frame: &mut Frame, result: &BacktestResult, title: &str, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(title.to_string())
+        .title_alignment(Alignment::Center);
+
+    let colored = |value: f64| {
+        Span::styled(
+            format!("{:+.1}%", value),
+            Style::default()
+                .fg(if value >= 0.0 { Color::Green } else { Color::Red })
+                .add_modifier(Modifier::BOLD),
+        )
+    };
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+
+    let line = Line::from(vec![
+        label("Rendement "),
+        colored(result.total_return),
+        label("   Achat-conservation "),
+        colored(result.buy_and_hold),
+        label("   Trades "),
+        Span::styled(result.trades.len().to_string(), Style::default().fg(Color::White)),
+        label("   Gagnants "),
+        Span::styled(
+            result
+                .win_rate()
+                .map(|rate| format!("{:.1}%", rate))
+                .unwrap_or_else(|| "—".to_string()),
+            Style::default().fg(Color::White),
+        ),
+    ]);
+
+    frame.render_widget(Paragraph::new(line).block(block).alignment(Alignment::Center), area);
+}
+
+/// Dessine la courbe d'equity de la stratégie et celle de l'achat-conservation
+fn render_equity(frame: &mut Frame, equity: &[f64], buy_and_hold: &[f64], area: Rect) {
+    let to_points = |values: &[f64]| -> Vec<(f64, f64)> {
+        values.iter().enumerate().map(|(i, v)| (i as f64, *v)).collect()
+    };
+    let strategy_points = to_points(equity);
+    let hold_points = to_points(buy_and_hold);
+
+    let (min, max) = equity
+        .iter()
+        .chain(buy_and_hold)
+        .fold((f64::MAX, f64::MIN), |(min, max), v| (min.min(*v), max.max(*v)));
+    let margin = ((max - min) * 0.05).max(1.0);
+    let (y_min, y_max) = (min - margin, max + margin);
+
+    let datasets = vec![
+        Dataset::default()
+            .name("Achat-conservation")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::DarkGray))
+            .data(&hold_points),
+        Dataset::default()
+            .name("Stratégie")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Cyan))
+            .data(&strategy_points),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::White))
+                .title(" Equity (base 100) "),
+        )
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, (equity.len().max(2) - 1) as f64]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([y_min, y_max])
+                .labels(vec![
+                    Span::raw(format!("{:.0}", y_min)),
+                    Span::raw(format!("{:.0}", (y_min + y_max) / 2.0)),
+                    Span::raw(format!("{:.0}", y_max)),
+                ]),
+        );
+
+    frame.render_widget(chart, area);
+}
+
+/// Dessine la liste des trades (les plus récents en haut)
+fn render_trades(frame: &mut Frame, trades: &[Trade], area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(" Trades ");
+
+    if trades.is_empty() {
+        let paragraph = Paragraph::new(Span::styled("Aucun signal", Style::default().fg(Color::Gray)))
+            .block(block)
+            .alignment(Alignment::Center);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let lines: Vec<Line> = trades.iter().rev().map(trade_line).collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Formate un trade : "12/03/24 → 02/05/24  +8.2%" (" *" si encore ouvert)
+fn trade_line(trade: &Trade) -> Line<'static> {
+    let value = trade.return_percent();
+    Line::from(vec![
+        Span::styled(
+            format!(
+                "{} → {}",
+                trade.entry_time.format("%d/%m/%y"),
+                trade.exit_time.format("%d/%m/%y")
+            ),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::styled(
+            format!("  {:+.1}%{}", value, if trade.open { " *" } else { "" }),
+            Style::default().fg(if value >= 0.0 { Color::Green } else { Color::Red }),
+        ),
+    ])
+}
+
+/// Dessine le footer (raccourcis ou notification)
+fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let line = match app.active_toast() {
+        Some(toast) => toast_line(toast),
+        None => Line::from(vec![
+            Span::styled("[h / l]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Stratégie  "),
+            Span::styled("[b / ESC]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Retour au graphique  "),
+            Span::styled("*", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" position encore ouverte"),
+        ]),
+    };
+
+    let paragraph = Paragraph::new(line).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
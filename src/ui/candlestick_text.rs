@@ -15,20 +15,43 @@
 // ╻ Demi-corps (bas)     ╹ Demi-corps (haut)
 // ╽ Transition top       ╿ Transition bottom
 // ╷ Demi-mèche sup       ╵ Demi-mèche inf
+//
+// CONCEPT : Cache du rendu (ChartCache)
+// - La grille des chandeliers n'est recalculée que si les données ou la vue
+//   changent (ticker, intervalle, taille, échelle, mesure, annotations...)
+// - Un toast ou une image du spinner redessine l'écran : les lignes du
+//   graphique sont alors reprises du cache
 // ============================================================================
 
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{
+        block::{Position, Title},
+        Block, Borders, Paragraph,
+    },
     Frame,
 };
 
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use chrono::{Datelike, Timelike};
 
-use crate::app::App;
-use crate::models::{Interval, LabelStrategy, OHLC};
+use crate::app::{App, MeasureCursor};
+use crate::models::annotations::{candle_index, AnchoredLine};
+use crate::models::measure::{self, first_visible};
+use crate::models::{Currency, DataGap, Interval, LabelStrategy, TickerType, OHLC};
+use crate::ui::dashboard::toast_line;
+use crate::ui::format::{
+    format_change, format_elapsed, format_price_change_in, format_price_in, format_volume_summary, price_decimals,
+    privacy, MASK,
+};
+use crate::ui::annotations::{render_annotations, ANNOTATIONS_PANEL_WIDTH};
+use crate::ui::order_book::{render_order_book, ORDER_BOOK_PANEL_WIDTH};
+use crate::ui::session_stats::{render_session_stats, SESSION_STATS_PANEL_HEIGHT, SESSION_STATS_PANEL_WIDTH};
 
 // ============================================================================
 // Constantes
@@ -44,10 +67,30 @@ const UNICODE_TOP: char = '╽';               // Transition corps→mèche (hau
 const UNICODE_BOTTOM: char = '╿';            // Transition corps→mèche (bas)
 const UNICODE_UPPER_WICK: char = '╷';        // Demi-mèche supérieure
 const UNICODE_LOWER_WICK: char = '╵';        // Demi-mèche inférieure
+const UNICODE_SESSION_SEPARATOR: char = '┊'; // Frontière entre deux séances
+const UNICODE_GAP_MARK: char = '×';          // Bougies manquantes (axe X)
+const UNICODE_BENCHMARK: char = '•';         // Indice de référence normalisé
+const UNICODE_ANNOTATION: char = '◆';        // Chandelier annoté (au-dessus)
+const UNICODE_TREND_FLAT: char = '─';        // Droite de tendance (plate)
+const UNICODE_TREND_UP: char = '╱';          // Droite de tendance (montante)
+const UNICODE_TREND_DOWN: char = '╲';        // Droite de tendance (descendante)
 
 /// Couleurs pour chandeliers haussiers et baissiers
 const BULLISH_COLOR: Color = Color::Rgb(52, 208, 88);   // Vert
 const BEARISH_COLOR: Color = Color::Rgb(234, 74, 90);   // Rouge
+const SEPARATOR_COLOR: Color = Color::Rgb(70, 70, 70);  // Gris discret
+const GAP_COLOR: Color = Color::Rgb(180, 140, 60);      // Ocre discret
+const BENCHMARK_COLOR: Color = Color::Rgb(100, 149, 237); // Bleu
+const ANNOTATION_COLOR: Color = Color::Rgb(255, 200, 60); // Jaune
+const TREND_LINE_COLOR: Color = Color::Rgb(200, 120, 255); // Violet
+
+/// Fonds de l'outil de mesure : colonnes du curseur et de l'ancre, et plage
+/// mesurée entre les deux
+const MEASURE_MARK_BG: Color = Color::Rgb(70, 70, 110);
+const MEASURE_RANGE_BG: Color = Color::Rgb(30, 30, 50);
+
+/// Marge par défaut au-dessus et au-dessous des prix visibles (% de l'amplitude)
+pub const DEFAULT_MARGIN_PERCENT: f64 = 2.0;
 
 /// Largeur de l'axe Y (pour les prix)
 const Y_AXIS_WIDTH: u16 = 12;
@@ -74,6 +117,103 @@ pub struct CandlestickRenderer<'a> {
     height: u16,
     width: u16,
     y_axis_width: u16,
+    /// Décimales des prix de l'axe Y (2 par défaut, 5 pour le forex)
+    price_decimals: usize,
+    /// Insère une colonne séparatrice entre deux séances (intraday)
+    session_separators: bool,
+    /// Trous détectés dans `candles` (index dans la série complète)
+    gaps: Vec<DataGap>,
+    /// Outil de mesure (index dans la série complète)
+    measure: Option<MeasureCursor>,
+    /// Indice de référence ramené au prix du ticker, un point par
+    /// chandelier visible (None : pas de cotation à cette date)
+    benchmark: Vec<Option<f64>>,
+    /// Variation de l'indice de référence sur la fenêtre affichée, en %
+    benchmark_change: Option<f64>,
+    /// Chandeliers annotés (index dans la série complète)
+    annotations: Vec<usize>,
+    /// Droites de tendance (index dans la série complète)
+    trend_lines: Vec<AnchoredLine>,
+}
+
+/// Hauteurs d'un chandelier dans le graphique (en lignes, depuis le bas)
+#[derive(Debug, Clone, Copy)]
+struct CandleHeights {
+    high_y: f64,
+    low_y: f64,
+    /// Haut du corps
+    max_y: f64,
+    /// Bas du corps
+    min_y: f64,
+}
+
+/// Caractères d'un chandelier, de sa ligne la plus basse à la plus haute
+///
+/// Calculés une fois par chandelier : hors de ces lignes, la colonne est vide
+struct CandleGlyphs {
+    bottom: u16,
+    glyphs: Vec<char>,
+}
+
+impl CandleGlyphs {
+    /// Caractère du chandelier à la ligne `y`
+    fn at(&self, y: u16) -> char {
+        y.checked_sub(self.bottom)
+            .and_then(|row| self.glyphs.get(row as usize))
+            .copied()
+            .unwrap_or(UNICODE_VOID)
+    }
+}
+
+/// Contenu d'une colonne du graphique (le même sur toutes les lignes)
+#[derive(Debug, Clone, Copy)]
+enum Column {
+    Empty,
+    /// Séparateur de séance
+    Separator,
+    /// Chandelier (index dans les chandeliers visibles)
+    Candle(usize),
+}
+
+/// Lignes d'un graphique déjà rendu et ce qui les a produites
+struct CachedChart {
+    symbol: String,
+    /// Empreinte du renderer (voir CandlestickRenderer::fingerprint)
+    fingerprint: u64,
+    lines: Vec<Line<'static>>,
+}
+
+/// Cache des graphiques rendus, un par panneau (principal, secondaire)
+///
+/// CONCEPT RUST : thread_local! + RefCell
+/// - Le rendu ne reçoit que &App : le cache vit à côté, dans le thread de
+///   l'interface (seul à dessiner), comme le mode discret de format.rs
+/// - RefCell : emprunt mutable vérifié à l'exécution, sans Mutex
+#[derive(Default)]
+struct ChartCache {
+    panes: [Option<CachedChart>; 2],
+    /// Nombre de rendus complets (tests)
+    renders: usize,
+}
+
+impl ChartCache {
+    /// Lignes du graphique : reprises du cache si rien n'a changé
+    fn lines(&mut self, pane: usize, symbol: &str, renderer: &CandlestickRenderer) -> Vec<Line<'static>> {
+        let fingerprint = renderer.fingerprint();
+        let slot = &mut self.panes[pane];
+        if let Some(cached) = slot.as_ref().filter(|cached| cached.symbol == symbol && cached.fingerprint == fingerprint) {
+            return cached.lines.clone();
+        }
+
+        self.renders += 1;
+        let lines = renderer.render_lines();
+        *slot = Some(CachedChart { symbol: symbol.to_string(), fingerprint, lines: lines.clone() });
+        lines
+    }
+}
+
+thread_local! {
+    static CHART_CACHE: RefCell<ChartCache> = RefCell::new(ChartCache::default());
 }
 
 /// Position d'un chandelier dans le graphique
@@ -86,6 +226,7 @@ struct CandlePosition {
     /// Position absolue de la colonne (0-based depuis le début de la zone graphique)
     column: usize,
     /// Nombre de caractères alloués à ce chandelier (généralement 1)
+    #[allow(dead_code)]
     width: usize,
 }
 
@@ -100,7 +241,7 @@ impl<'a> CandlestickRenderer<'a> {
         // CORRECTION : Calcule les bornes de prix sur les chandeliers VISIBLES uniquement
         // Évite que des pics/creux hors de la fenêtre d'affichage n'étirent l'axe Y
         let visible = Self::get_visible_slice(candles);
-        let (min_price, max_price) = Self::compute_price_bounds(visible);
+        let (min_price, max_price) = Self::compute_price_bounds(visible, DEFAULT_MARGIN_PERCENT);
 
         // Largeur adaptative de l'axe Y selon la largeur du terminal
         let y_axis_width = if area.width < ADAPTIVE_Y_AXIS_THRESHOLD {
@@ -118,11 +259,238 @@ impl<'a> CandlestickRenderer<'a> {
             height: area.height.saturating_sub(6),
             width: area.width.saturating_sub(y_axis_width),
             y_axis_width,
+            price_decimals: 2,
+            session_separators: false,
+            gaps: Vec::new(),
+            measure: None,
+            benchmark: Vec::new(),
+            benchmark_change: None,
+            annotations: Vec::new(),
+            trend_lines: Vec::new(),
+        }
+    }
+
+    /// Définit le nombre de décimales des prix de l'axe Y
+    ///
+    /// CONCEPT : Builder pattern
+    /// - Consomme self et le retourne modifié
+    /// - CandlestickRenderer::new(...).with_price_decimals(5)
+    pub fn with_price_decimals(mut self, decimals: usize) -> Self {
+        self.price_decimals = decimals;
+        self
+    }
+
+    /// Définit la marge de l'axe Y (en % de l'amplitude des prix visibles)
+    ///
+    /// Ramenée entre 0 et 50 % (valeur invalide : marge par défaut)
+    pub fn with_margin(mut self, margin_percent: f64) -> Self {
+        let margin_percent = if margin_percent.is_finite() {
+            margin_percent.clamp(0.0, 50.0)
+        } else {
+            DEFAULT_MARGIN_PERCENT
+        };
+        (self.min_price, self.max_price) = Self::compute_price_bounds(self.visible_candles(), margin_percent);
+        self
+    }
+
+    /// Fixe les bornes de l'axe Y (échelle verrouillée, None : automatique)
+    ///
+    /// Les chandeliers qui sortent des bornes sont coupés au bord du graphique
+    pub fn with_price_bounds(mut self, bounds: Option<(f64, f64)>) -> Self {
+        if let Some((min_price, max_price)) = bounds {
+            self.min_price = min_price;
+            self.max_price = max_price;
+        }
+        self
+    }
+
+    /// Active les séparateurs de séance (sans effet sur 1d / 1w)
+    pub fn with_session_separators(mut self, enabled: bool) -> Self {
+        self.session_separators = enabled;
+        self
+    }
+
+    /// Marque les trous de données sur l'axe X (voir OHLCData::gaps)
+    pub fn with_gaps(mut self, gaps: Vec<DataGap>) -> Self {
+        self.gaps = gaps;
+        self
+    }
+
+    /// Surligne le curseur et l'ancre de l'outil de mesure
+    pub fn with_measure(mut self, measure: Option<MeasureCursor>) -> Self {
+        self.measure = measure;
+        self
+    }
+
+    /// Marque d'un ◆ les chandeliers annotés (voir models/annotations.rs)
+    pub fn with_annotations(mut self, annotations: Vec<usize>) -> Self {
+        self.annotations = annotations;
+        self
+    }
+
+    /// Trace les droites de tendance, prolongées jusqu'au dernier chandelier
+    pub fn with_trend_lines(mut self, trend_lines: Vec<AnchoredLine>) -> Self {
+        self.trend_lines = trend_lines;
+        self
+    }
+
+    /// Superpose un indice de référence normalisé (SPY, ^FCHI...)
+    ///
+    /// CONCEPT : Normalisation
+    /// - L'indice démarre au prix d'ouverture du premier chandelier visible :
+    ///   les deux courbes partent du même point et leur écart se lit
+    ///   directement comme une surperformance
+    /// - Point de chaque chandelier : dernière clôture de l'indice à sa date
+    ///
+    /// L'échelle s'élargit pour contenir l'indice : à appeler après
+    /// with_margin() et avant with_price_bounds()
+    pub fn with_benchmark(mut self, benchmark: Option<&[OHLC]>) -> Self {
+        let Some(benchmark) = benchmark.filter(|candles| !candles.is_empty()) else {
+            return self;
+        };
+        let visible = self.visible_candles();
+        let (Some(first), Some(last)) = (visible.first(), visible.last()) else {
+            return self;
+        };
+
+        // Base : premier chandelier de l'indice dans la fenêtre affichée
+        let start = benchmark.partition_point(|candle| candle.timestamp < first.timestamp);
+        let Some(base) = benchmark
+            .get(start)
+            .filter(|candle| candle.timestamp <= last.timestamp)
+            .map(|candle| candle.open)
+            .filter(|open| *open > 0.0)
+        else {
+            return self;
+        };
+        let bench = &benchmark[start..];
+        let close_at = |timestamp| {
+            let index = bench.partition_point(|candle| candle.timestamp <= timestamp);
+            index.checked_sub(1).map(|index| bench[index].close)
+        };
+
+        let points: Vec<Option<f64>> = visible
+            .iter()
+            .map(|candle| close_at(candle.timestamp).map(|close| first.open * close / base))
+            .collect();
+        self.benchmark_change = close_at(last.timestamp).map(|close| (close - base) / base * 100.0);
+
+        for price in points.iter().flatten() {
+            self.min_price = self.min_price.min(*price);
+            self.max_price = self.max_price.max(*price);
         }
+        self.benchmark = points;
+        self
+    }
+
+    /// Variation de l'indice de référence sur la fenêtre affichée, en %
+    pub fn benchmark_change_percent(&self) -> Option<f64> {
+        self.benchmark_change
+    }
+
+    /// Cases des droites de tendance : (ligne, caractère) par chandelier visible
+    ///
+    /// CONCEPT : Rastérisation
+    /// - Chaque droite part de son premier chandelier et se prolonge jusqu'au
+    ///   dernier chandelier visible
+    /// - Dans la colonne d'un chandelier, elle remplit les lignes jusqu'à sa
+    ///   hauteur au chandelier suivant : une pente raide reste continue
+    /// - Caractère selon la pente : ─ (moins d'une demi-ligne), ╱ ou ╲
+    fn trend_line_cells(&self, offset: usize, visible_len: usize) -> Vec<Vec<(u16, char)>> {
+        let mut cells = vec![Vec::new(); visible_len];
+        let top = self.height as f64;
+
+        for line in &self.trend_lines {
+            for (index, column) in cells.iter_mut().enumerate().skip(line.start.saturating_sub(offset)) {
+                let here = self.price_to_height(line.price_at(offset + index));
+                let next = self.price_to_height(line.price_at(offset + index + 1));
+                let slope = next - here;
+                let ch = if slope.abs() < 0.5 {
+                    UNICODE_TREND_FLAT
+                } else if slope > 0.0 {
+                    UNICODE_TREND_UP
+                } else {
+                    UNICODE_TREND_DOWN
+                };
+
+                // Lignes couvertes, de la hauteur ici jusqu'à celle du chandelier
+                // suivant (exclue), coupées aux bords du graphique
+                let from = here.ceil();
+                let to = if slope.abs() < 1.0 || index + 1 == visible_len {
+                    from
+                } else {
+                    next.ceil() - slope.signum()
+                };
+                let (low, high) = (from.min(to).max(1.0), from.max(to).min(top));
+                if low > high {
+                    continue;
+                }
+                for row in low as u16..=high as u16 {
+                    column.push((row, ch));
+                }
+            }
+        }
+        cells
+    }
+
+    /// Fond de chaque colonne selon l'outil de mesure
+    ///
+    /// Curseur et ancre en fond marqué, chandeliers entre les deux en fond
+    /// discret ; les séparateurs de séance de la plage restent sans fond
+    fn measure_backgrounds(&self, positions: &[CandlePosition]) -> Vec<Option<Color>> {
+        let mut backgrounds = vec![None; self.width as usize];
+        let Some(measure) = self.measure else {
+            return backgrounds;
+        };
+
+        // Index de la série complète → index dans les chandeliers visibles
+        let offset = first_visible(self.candles.len());
+        let column = |index: usize| {
+            index
+                .checked_sub(offset)
+                .and_then(|visible| positions.get(visible))
+                .map(|pos| pos.column)
+        };
+
+        let marks: Vec<usize> = [Some(measure.cursor), measure.anchor]
+            .into_iter()
+            .flatten()
+            .filter_map(column)
+            .collect();
+        if let (Some(&first), Some(&last)) = (marks.iter().min(), marks.iter().max()) {
+            for pos in positions.iter().filter(|pos| pos.column > first && pos.column < last) {
+                backgrounds[pos.column] = Some(MEASURE_RANGE_BG);
+            }
+        }
+        for mark in marks {
+            if let Some(background) = backgrounds.get_mut(mark) {
+                *background = Some(MEASURE_MARK_BG);
+            }
+        }
+        backgrounds
+    }
+
+    /// Indique pour chaque chandelier s'il ouvre une nouvelle séance
+    ///
+    /// CONCEPT : Frontière de séance
+    /// - Deux chandeliers consécutifs plus espacés que l'intervalle encadrent
+    ///   une période sans cotation (nuit, week-end, jour férié)
+    /// - Le crypto (24h/24) n'a donc jamais de frontière
+    fn session_starts(&self, visible: &[OHLC]) -> Vec<bool> {
+        let step = chrono::Duration::minutes(self.interval.minutes());
+        let enabled = self.session_separators && self.interval.is_intraday();
+
+        std::iter::once(false)
+            .chain(visible.windows(2).map(|pair| enabled && pair[1].timestamp - pair[0].timestamp > step))
+            .take(visible.len())
+            .collect()
     }
 
     /// Calcule les prix min et max sur tous les chandeliers
-    fn compute_price_bounds(candles: &[OHLC]) -> (f64, f64) {
+    ///
+    /// # Arguments
+    /// * `margin_percent` - Marge ajoutée en haut et en bas, en % de l'amplitude
+    fn compute_price_bounds(candles: &[OHLC], margin_percent: f64) -> (f64, f64) {
         let max_price = candles
             .iter()
             .fold(f64::NEG_INFINITY, |max, c| max.max(c.high));
@@ -131,8 +499,7 @@ impl<'a> CandlestickRenderer<'a> {
             .iter()
             .fold(f64::INFINITY, |min, c| min.min(c.low));
 
-        // Ajoute une marge de 2%
-        let margin = (max_price - min_price) * 0.02;
+        let margin = (max_price - min_price) * margin_percent / 100.0;
         (
             (min_price - margin).max(0.0),
             max_price + margin,
@@ -162,18 +529,36 @@ impl<'a> CandlestickRenderer<'a> {
         }
     }
 
+    /// Convertit les prix d'un chandelier en coordonnées de hauteur
+    ///
+    /// Calculé une fois par chandelier, pas à chaque ligne du graphique
+    fn candle_heights(&self, candle: &OHLC) -> CandleHeights {
+        CandleHeights {
+            high_y: self.price_to_height(candle.high),
+            low_y: self.price_to_height(candle.low),
+            max_y: self.price_to_height(candle.open.max(candle.close)),
+            min_y: self.price_to_height(candle.close.min(candle.open)),
+        }
+    }
+
+    /// Caractères d'un chandelier sur les lignes qu'il occupe (mèches comprises)
+    fn candle_glyphs(&self, candle: &OHLC) -> CandleGlyphs {
+        let heights = self.candle_heights(candle);
+        let bottom = heights.low_y.floor().max(1.0) as u16;
+        let top = (heights.high_y.ceil().min(self.height as f64) as u16).max(bottom.saturating_sub(1));
+        CandleGlyphs {
+            bottom,
+            glyphs: (bottom..=top).map(|y| Self::render_candle(&heights, y)).collect(),
+        }
+    }
+
     /// Rend un chandelier à une hauteur donnée
     ///
     /// Ceci est le cœur de l'algorithme, adapté de cli-candlestick-chart.
     /// Il détermine quel caractère Unicode afficher selon la position verticale.
-    fn render_candle(&self, candle: &OHLC, y: u16) -> char {
+    fn render_candle(heights: &CandleHeights, y: u16) -> char {
         let height_unit = y as f64;
-
-        // Convertit les prix en coordonnées de hauteur
-        let high_y = self.price_to_height(candle.high);
-        let low_y = self.price_to_height(candle.low);
-        let max_y = self.price_to_height(candle.open.max(candle.close));
-        let min_y = self.price_to_height(candle.close.min(candle.open));
+        let CandleHeights { high_y, low_y, max_y, min_y } = *heights;
 
         let mut output = UNICODE_VOID;
 
@@ -236,27 +621,47 @@ impl<'a> CandlestickRenderer<'a> {
         output
     }
 
-    /// Rend une ligne de l'axe Y avec le prix
-    fn render_y_axis(&self, y: u16) -> String {
-        // Affiche le prix tous les 4 lignes
-        if y % 4 == 0 {
-            let price = self.min_price
-                + (y as f64 * (self.max_price - self.min_price) / self.height as f64);
-            format!("{:>9.2} │ ", price)
-        } else {
-            format!("{:>9} │ ", "")
+    /// Graduations de l'axe Y : (ligne, prix) sur des valeurs rondes
+    ///
+    /// CONCEPT : "Nice numbers"
+    /// - Pas choisi parmi 1, 2, 2.5, 5 × 10^n (voir nice_step)
+    /// - Environ une graduation toutes les 4 lignes, jamais plus serrées
+    /// - Chaque graduation est placée sur la ligne dont le prix est le plus proche
+    fn y_axis_ticks(&self) -> Vec<(u16, f64)> {
+        let range = self.max_price - self.min_price;
+        if self.height == 0 || range <= 0.0 {
+            return Vec::new();
+        }
+
+        let step = nice_step(range, (self.height / 4).max(1) as usize);
+        let mut ticks = Vec::new();
+        let mut index = (self.min_price / step).ceil() as i64;
+        loop {
+            let price = index as f64 * step;
+            if price > self.max_price {
+                break;
+            }
+            let y = ((price - self.min_price) / range * self.height as f64).round() as u16;
+            if (1..=self.height).contains(&y) {
+                ticks.push((y, price));
+            }
+            index += 1;
+        }
+        ticks
+    }
+
+    /// Rend une ligne de l'axe Y avec le prix (si une graduation y tombe)
+    fn render_y_axis(&self, y: u16, ticks: &[(u16, f64)], decimals: usize) -> String {
+        match ticks.iter().find(|(row, _)| *row == y) {
+            Some(_) if privacy() => format!("{:>9} │ ", MASK),
+            Some((_, price)) => format!("{:>9.*} │ ", decimals, price),
+            None => format!("{:>9} │ ", ""),
         }
     }
 
     /// Fonction helper : extrait les chandeliers visibles (les ~250 derniers)
     fn get_visible_slice(candles: &[OHLC]) -> &[OHLC] {
-        const MAX_VISIBLE_CANDLES: usize = 250;
-
-        if candles.len() <= MAX_VISIBLE_CANDLES {
-            candles
-        } else {
-            &candles[candles.len() - MAX_VISIBLE_CANDLES..]
-        }
+        &candles[first_visible(candles.len())..]
     }
 
     /// Sélectionne les chandeliers visibles (les ~250 derniers pour cohérence visuelle)
@@ -268,6 +673,17 @@ impl<'a> CandlestickRenderer<'a> {
         Self::get_visible_slice(self.candles)
     }
 
+    /// Variation sur la fenêtre affichée : ouverture du premier chandelier
+    /// visible → clôture du dernier, en %
+    ///
+    /// Complète la variation du jour du header (None si l'ouverture est nulle)
+    pub fn window_change_percent(&self) -> Option<f64> {
+        let visible = self.visible_candles();
+        let first = visible.first()?;
+        let last = visible.last()?;
+        (first.open != 0.0).then(|| (last.close - first.open) / first.open * 100.0)
+    }
+
     /// Pré-calcule les positions exactes de chaque chandelier
     ///
     /// CONCEPT : Accumulator pattern pour éviter le drift
@@ -311,6 +727,41 @@ impl<'a> CandlestickRenderer<'a> {
         positions
     }
 
+    /// Empreinte de tout ce qui détermine les lignes rendues
+    ///
+    /// Deux renderers de même empreinte produisent les mêmes lignes : les
+    /// chandeliers visibles (valeurs comprises), la taille, l'échelle et les
+    /// couches superposées, plus le mode discret qui masque l'axe Y
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.candles.len().hash(&mut hasher);
+        for candle in self.visible_candles() {
+            candle.timestamp.hash(&mut hasher);
+            for price in [candle.open, candle.high, candle.low, candle.close] {
+                price.to_bits().hash(&mut hasher);
+            }
+            candle.volume.hash(&mut hasher);
+        }
+        self.interval.hash(&mut hasher);
+        self.min_price.to_bits().hash(&mut hasher);
+        self.max_price.to_bits().hash(&mut hasher);
+        (self.height, self.width, self.y_axis_width, self.price_decimals).hash(&mut hasher);
+        self.session_separators.hash(&mut hasher);
+        for gap in &self.gaps {
+            (gap.index, gap.missing).hash(&mut hasher);
+        }
+        self.measure.map(|measure| (measure.cursor, measure.anchor)).hash(&mut hasher);
+        for point in &self.benchmark {
+            point.map(f64::to_bits).hash(&mut hasher);
+        }
+        self.annotations.hash(&mut hasher);
+        for line in &self.trend_lines {
+            (line.start, line.start_price.to_bits(), line.end, line.end_price.to_bits()).hash(&mut hasher);
+        }
+        privacy().hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Génère toutes les lignes du graphique (chandeliers + axe X)
     ///
     /// CONCEPT : Position array pour alignement parfait
@@ -318,7 +769,7 @@ impl<'a> CandlestickRenderer<'a> {
     /// - Construit chaque ligne avec un tableau de caractères
     /// - Place les chandeliers exactement aux positions calculées
     /// - Utilise les MÊMES positions pour l'axe X → alignement garanti
-    pub fn render_lines(&self) -> Vec<Line<'a>> {
+    pub fn render_lines(&self) -> Vec<Line<'static>> {
         let mut lines = Vec::new();
         let visible = self.visible_candles();
 
@@ -327,7 +778,90 @@ impl<'a> CandlestickRenderer<'a> {
         }
 
         // Pré-calcule les positions de tous les chandeliers (source unique de vérité)
-        let positions = Self::compute_candle_positions(self.width as usize, visible.len());
+        // Chaque frontière de séance réserve une colonne de plus pour son séparateur
+        let session_starts = self.session_starts(visible);
+        let separator_count = session_starts.iter().filter(|start| **start).count();
+        let slots = Self::compute_candle_positions(self.width as usize, visible.len() + separator_count);
+
+        let mut positions = Vec::with_capacity(visible.len());
+        let mut separator_columns = Vec::with_capacity(separator_count);
+        let mut slot_iter = slots.into_iter();
+        for starts_session in &session_starts {
+            if *starts_session {
+                separator_columns.extend(slot_iter.next().map(|slot| slot.column));
+            }
+            positions.extend(slot_iter.next());
+        }
+
+        let backgrounds = self.measure_backgrounds(&positions);
+
+        // Graduations rondes, avec assez de décimales pour distinguer le pas
+        let ticks = self.y_axis_ticks();
+        let step = match ticks.as_slice() {
+            [(_, first), (_, second), ..] => second - first,
+            _ => 1.0,
+        };
+        let decimals = self.price_decimals.max(step_decimals(step));
+
+        // Contenu et style de chaque colonne : identiques sur toutes les lignes
+        // (seul le caractère d'un chandelier dépend de la hauteur)
+        // Séparateurs de séance d'abord (sous les chandeliers en cas de chevauchement)
+        let width = self.width as usize;
+        let mut columns = vec![Column::Empty; width];
+        let mut colors: Vec<Option<Color>> = vec![None; width];
+        for &column in &separator_columns {
+            if column < width {
+                columns[column] = Column::Separator;
+                colors[column] = Some(SEPARATOR_COLOR);
+            }
+        }
+        for (index, (candle, pos)) in visible.iter().zip(positions.iter()).enumerate() {
+            if pos.column < width {
+                columns[pos.column] = Column::Candle(index);
+                colors[pos.column] = Some(Self::candle_color(candle));
+            }
+        }
+        let glyphs: Vec<CandleGlyphs> = visible.iter().map(|candle| self.candle_glyphs(candle)).collect();
+
+        // Ligne du point de l'indice de référence de chaque chandelier
+        let benchmark_rows: Vec<Option<u16>> = (0..visible.len())
+            .map(|index| {
+                let price = (*self.benchmark.get(index)?)?;
+                let height = self.price_to_height(price);
+                // Hors échelle (bornes verrouillées) : point masqué
+                (0.0..=self.height as f64)
+                    .contains(&height)
+                    .then(|| (height.ceil() as u16).clamp(1, self.height))
+            })
+            .collect();
+
+        // Ligne du ◆ de chaque chandelier annoté : juste au-dessus de sa mèche
+        // haute (sur la mèche quand le chandelier touche le haut du graphique)
+        let offset = first_visible(self.candles.len());
+        let mut annotation_rows: Vec<Option<u16>> = vec![None; visible.len()];
+        for index in self.annotations.iter().filter_map(|index| index.checked_sub(offset)) {
+            if let Some(candle) = glyphs.get(index) {
+                let drawn = candle.glyphs.iter().rposition(|ch| *ch != UNICODE_VOID).map_or(0, |top| top + 1);
+                let above = candle.bottom + drawn as u16;
+                annotation_rows[index] = Some(above.clamp(1, self.height));
+            }
+        }
+
+        let trend_cells = self.trend_line_cells(offset, visible.len());
+
+        // Style de chaque colonne (couleur du chandelier, fond de l'outil de mesure)
+        let styles: Vec<Style> = (0..width)
+            .map(|i| {
+                let mut style = Style::default();
+                if let Some(color) = colors[i] {
+                    style = style.fg(color);
+                }
+                if let Some(background) = backgrounds[i] {
+                    style = style.bg(background);
+                }
+                style
+            })
+            .collect();
 
         // Parcourt de haut en bas (reversed)
         for y in (1..=self.height).rev() {
@@ -335,53 +869,49 @@ impl<'a> CandlestickRenderer<'a> {
 
             // Ajoute l'axe Y
             spans.push(Span::styled(
-                self.render_y_axis(y),
+                self.render_y_axis(y, &ticks, decimals),
                 Style::default().fg(Color::Gray),
             ));
 
-            // Construit la ligne avec un tableau de caractères
-            let mut line_chars = vec![' '; self.width as usize];
-            let mut line_colors: Vec<Option<Color>> = vec![None; self.width as usize];
-
-            // Place chaque chandelier à sa position exacte
-            for (candle, pos) in visible.iter().zip(positions.iter()) {
-                if pos.column < line_chars.len() {
-                    line_chars[pos.column] = self.render_candle(candle, y);
-                    line_colors[pos.column] = Some(Self::candle_color(candle));
+            // Un span par suite de colonnes de même style : un espace sans fond
+            // s'affiche pareil quelle que soit sa couleur, il rejoint le span
+            // en cours (quelques spans par ligne au lieu d'un par chandelier)
+            let mut text = String::with_capacity(width * 3);
+            let mut current: Option<Style> = None;
+            for (i, column) in columns.iter().enumerate() {
+                let mut ch = match column {
+                    Column::Empty => UNICODE_VOID,
+                    Column::Separator => UNICODE_SESSION_SEPARATOR,
+                    Column::Candle(index) => glyphs[*index].at(y),
+                };
+                // Point de l'indice et droites de tendance, seulement là où le
+                // chandelier laisse la place ; le ◆ d'une annotation passe devant tout
+                let mut style = styles[i];
+                if let Column::Candle(index) = column {
+                    if annotation_rows[*index] == Some(y) {
+                        ch = UNICODE_ANNOTATION;
+                        style = style.fg(ANNOTATION_COLOR);
+                    } else if ch == UNICODE_VOID && benchmark_rows[*index] == Some(y) {
+                        ch = UNICODE_BENCHMARK;
+                        style = style.fg(BENCHMARK_COLOR);
+                    } else if let Some(&(_, trend)) = trend_cells[*index].iter().find(|(row, _)| *row == y) {
+                        if ch == UNICODE_VOID {
+                            ch = trend;
+                            style = style.fg(TREND_LINE_COLOR);
+                        }
+                    }
                 }
-            }
-
-            // Convertit le tableau de caractères en spans avec couleurs
-            let mut current_color = line_colors[0];
-            let mut current_string = String::new();
-            current_string.push(line_chars[0]);
-
-            for i in 1..line_chars.len() {
-                if line_colors[i] == current_color {
-                    // Continue le span actuel
-                    current_string.push(line_chars[i]);
-                } else {
-                    // Émet le span actuel et commence un nouveau
-                    if let Some(color) = current_color {
-                        spans.push(Span::styled(
-                            current_string.clone(),
-                            Style::default().fg(color),
-                        ));
-                    } else {
-                        spans.push(Span::raw(current_string.clone()));
+                let neutral = ch == UNICODE_VOID && backgrounds[i].is_none();
+                if !neutral && current != Some(style) {
+                    if !text.is_empty() {
+                        spans.push(Span::styled(std::mem::take(&mut text), current.unwrap_or_default()));
                     }
-
-                    current_string.clear();
-                    current_string.push(line_chars[i]);
-                    current_color = line_colors[i];
+                    current = Some(style);
                 }
+                text.push(ch);
             }
-
-            // Émet le dernier span
-            if let Some(color) = current_color {
-                spans.push(Span::styled(current_string, Style::default().fg(color)));
-            } else {
-                spans.push(Span::raw(current_string));
+            if !text.is_empty() {
+                spans.push(Span::styled(text, current.unwrap_or_default()));
             }
 
             lines.push(Line::from(spans));
@@ -402,7 +932,7 @@ impl<'a> CandlestickRenderer<'a> {
         match strategy {
             LabelStrategy::RoundHours { interval_hours } => {
                 // Affiche si l'heure est un multiple de interval_hours
-                candle.timestamp.hour() % interval_hours == 0
+                candle.timestamp.hour().is_multiple_of(interval_hours)
                     && candle.timestamp.minute() == 0
             }
             LabelStrategy::DayChanges => {
@@ -468,7 +998,7 @@ impl<'a> CandlestickRenderer<'a> {
     /// - Séparation claire heures/dates
     /// - Format de date uniforme
     /// - Année affichée automatiquement si données multi-années
-    fn render_x_axis(&self, visible: &[OHLC], positions: &[CandlePosition]) -> Vec<Line<'a>> {
+    fn render_x_axis(&self, visible: &[OHLC], positions: &[CandlePosition]) -> Vec<Line<'static>> {
         let mut lines = vec![];
         let axis_formats = self.interval.x_axis_format();
         let label_strategy = axis_formats.label_strategy;
@@ -514,9 +1044,29 @@ impl<'a> CandlestickRenderer<'a> {
             prev_candle = Some(candle);
         }
 
+        // Trous de données : marque le premier chandelier après le trou
+        // (les index des trous portent sur la série complète, pas la partie visible)
+        let offset = self.candles.len() - visible.len();
+        let gap_columns: Vec<usize> = self
+            .gaps
+            .iter()
+            .filter_map(|gap| gap.index.checked_sub(offset))
+            .filter_map(|index| positions.get(index).map(|pos| pos.column))
+            .filter(|column| *column < tick_line.len())
+            .collect();
+
         let mut tick_spans = vec![Span::raw(format!("{:>width$}", "", width = self.y_axis_width as usize))];
+        let mut start = 0;
+        for column in gap_columns {
+            tick_spans.push(Span::styled(
+                tick_line[start..column].iter().collect::<String>(),
+                Style::default().fg(Color::Gray),
+            ));
+            tick_spans.push(Span::styled(UNICODE_GAP_MARK.to_string(), Style::default().fg(GAP_COLOR)));
+            start = column + 1;
+        }
         tick_spans.push(Span::styled(
-            tick_line.iter().collect::<String>(),
+            tick_line[start..].iter().collect::<String>(),
             Style::default().fg(Color::Gray),
         ));
         lines.push(Line::from(tick_spans));
@@ -609,6 +1159,42 @@ impl<'a> CandlestickRenderer<'a> {
     }
 }
 
+// ============================================================================
+// Graduations de l'axe Y
+// ============================================================================
+
+/// Pas "rond" donnant au plus `max_ticks` intervalles sur `range`
+///
+/// 1, 2, 2.5 ou 5 × 10^n : 0.0005, 0.25, 2, 50, 1000 ...
+fn nice_step(range: f64, max_ticks: usize) -> f64 {
+    let raw = range / max_ticks.max(1) as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+    let factor = [1.0, 2.0, 2.5, 5.0, 10.0]
+        .into_iter()
+        .find(|factor| *factor >= normalized - 1e-9)
+        .unwrap_or(10.0);
+    factor * magnitude
+}
+
+/// Décimales nécessaires pour afficher les multiples d'un pas (2.5 → 1, 0.05 → 2)
+fn step_decimals(step: f64) -> usize {
+    (0..8)
+        .find(|decimals| {
+            let scaled = step * 10f64.powi(*decimals as i32);
+            (scaled - scaled.round()).abs() < 1e-6
+        })
+        .unwrap_or(8)
+}
+
+/// Bornes de l'axe Y affichées pour `candles` (pour verrouiller l'échelle)
+///
+/// None sans chandelier
+pub fn visible_price_bounds(candles: &[OHLC], margin_percent: f64) -> Option<(f64, f64)> {
+    let visible = CandlestickRenderer::get_visible_slice(candles);
+    (!visible.is_empty()).then(|| CandlestickRenderer::compute_price_bounds(visible, margin_percent))
+}
+
 // ============================================================================
 // Fonction principale de rendu
 // ============================================================================
@@ -628,8 +1214,10 @@ pub fn render_candlestick_chart(frame: &mut Frame, app: &App, area: Rect) {
     let data = match &item.data {
         Some(data) => data,
         None => {
-            let msg = format!("Pas de données pour {}", item.symbol);
-            render_no_data(frame, area, &msg);
+            match &item.load_error {
+                Some(error) => render_load_error(frame, area, &item.symbol, error),
+                None => render_no_data(frame, area, &format!("Pas de données pour {}", item.symbol)),
+            }
             return;
         }
     };
@@ -659,14 +1247,138 @@ pub fn render_candlestick_chart(frame: &mut Frame, app: &App, area: Rect) {
     // Dessine le header
     render_header(frame, app, item, chunks[0]);
 
+    // Panneaux à droite : statistiques de séance (intraday) au-dessus du
+    // carnet d'ordres (crypto uniquement), puis les annotations
+    let order_book = app.order_book_visible && app.selected_has_order_book();
+    let session_stats = app.selected_session_stats().filter(|_| app.session_stats_visible);
+    let annotations = app.annotations_visible;
+    let chart_area = if order_book || session_stats.is_some() || annotations {
+        let width = [
+            (order_book, ORDER_BOOK_PANEL_WIDTH),
+            (session_stats.is_some(), SESSION_STATS_PANEL_WIDTH),
+            (annotations, ANNOTATIONS_PANEL_WIDTH),
+        ]
+        .iter()
+        .filter(|(visible, _)| *visible)
+        .map(|(_, width)| *width)
+        .max()
+        .unwrap_or_default();
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(width)])
+            .split(chunks[1])
+            .to_vec();
+        // Avec le carnet d'ordres, les annotations prennent 40 % du bas
+        let panels = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(if session_stats.is_some() { SESSION_STATS_PANEL_HEIGHT } else { 0 }),
+                if order_book { Constraint::Min(0) } else { Constraint::Length(0) },
+                match (annotations, order_book) {
+                    (false, _) => Constraint::Length(0),
+                    (true, true) => Constraint::Percentage(40),
+                    (true, false) => Constraint::Min(0),
+                },
+            ])
+            .split(columns[1])
+            .to_vec();
+        if let Some(stats) = &session_stats {
+            render_session_stats(frame, item, stats, panels[0]);
+        }
+        if order_book {
+            render_order_book(frame, app.selected_order_book(), panels[1]);
+        }
+        if annotations {
+            render_annotations(frame, app, item, data, panels[2]);
+        }
+        columns[0]
+    } else {
+        chunks[1]
+    };
+
+    if app.split_view {
+        // Vue multi-timeframe : tendance en haut, détail en bas
+        let panes = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chart_area)
+            .to_vec();
+
+        render_chart_pane(frame, app, item, data, app.current_interval, true, panes[0]);
+
+        match item.data_for(app.secondary_interval) {
+            Some(secondary) if !secondary.candles.is_empty() => {
+                render_chart_pane(frame, app, item, secondary, app.secondary_interval, false, panes[1]);
+            }
+            _ => render_pane_loading(frame, item, app.secondary_interval, panes[1]),
+        }
+    } else {
+        render_chart_pane(frame, app, item, data, app.current_interval, true, chart_area);
+    }
+}
+
+/// Dessine un graphique en chandeliers dans une zone (avec bordure et titre)
+///
+/// # Arguments
+/// * `data` - Données à dessiner
+/// * `selected_interval` - Intervalle choisi par l'utilisateur pour ce graphique
+///   (peut différer de data.interval pendant un rechargement)
+/// * `primary` - Graphique principal : seul à porter l'outil de mesure,
+///   l'échelle verrouillée et l'indice de référence
+fn render_chart_pane(
+    frame: &mut Frame,
+    app: &App,
+    item: &crate::models::WatchlistItem,
+    data: &crate::models::OHLCData,
+    selected_interval: Interval,
+    primary: bool,
+    area: Rect,
+) {
+    let measure = app.measure.filter(|_| primary);
+    let locked_bounds = app.y_axis_lock.filter(|_| primary);
+    let benchmark = if primary && app.benchmark_enabled(&item.symbol) {
+        let symbol = app.benchmark_symbol(&item.symbol);
+        app.benchmark_data(symbol, data.interval).map(|bench| (symbol, bench))
+    } else {
+        None
+    };
+
     // Crée le renderer et génère les lignes
-    let renderer = CandlestickRenderer::new(&data.candles, data.interval, chunks[1]);
-    let lines = renderer.render_lines();
+    let currency = item.currency();
+    let decimals = data
+        .last()
+        .map(|c| price_decimals(item.ticker_type(), &currency, c.close))
+        .unwrap_or(2);
+    let gaps = data.gaps(item.ticker_type() == TickerType::Crypto);
+    let missing: usize = gaps.iter().map(|gap| gap.missing).sum();
+    let annotated: Vec<usize> = app
+        .annotations
+        .of(&item.symbol)
+        .iter()
+        .filter_map(|annotation| candle_index(&data.candles, data.interval, annotation.timestamp))
+        .collect();
+    let trend_lines: Vec<AnchoredLine> = app
+        .annotations
+        .trend_lines(&item.symbol)
+        .iter()
+        .filter_map(|line| line.anchor(&data.candles, data.interval))
+        .collect();
+    let renderer = CandlestickRenderer::new(&data.candles, data.interval, area)
+        .with_price_decimals(decimals)
+        .with_margin(app.config.chart_margin_percent)
+        .with_benchmark(benchmark.map(|(_, bench)| bench.candles.as_slice()))
+        .with_price_bounds(locked_bounds)
+        .with_session_separators(app.session_separators)
+        .with_gaps(gaps)
+        .with_measure(measure)
+        .with_annotations(annotated)
+        .with_trend_lines(trend_lines);
+    let pane = if primary { 0 } else { 1 };
+    let lines = CHART_CACHE.with(|cache| cache.borrow_mut().lines(pane, &item.symbol, &renderer));
 
-    // Crée le widget Paragraph avec les lignes
     // Note : data.interval = interval des données chargées
-    //        app.current_interval = interval sélectionné par l'utilisateur
-    let displayed_interval = app.current_interval.label();
+    //        selected_interval = interval sélectionné par l'utilisateur
+    let displayed_interval = selected_interval.label();
     let data_interval = data.interval.label();
 
     // Indicateur si l'intervalle sélectionné diffère des données chargées
@@ -676,20 +1388,102 @@ pub fn render_candlestick_chart(frame: &mut Frame, app: &App, area: Rect) {
         format!("{} ", displayed_interval)
     };
 
-    let paragraph = Paragraph::new(lines).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::White))
-            .title(format!(
-                " 🕯️ {} - {}({}, {} chandeliers) [h/l: changer interval] ",
-                item.symbol,
-                interval_display,
-                data.timeframe.label(),
-                data.candles.len()
-            )),
-    );
-
-    frame.render_widget(paragraph, chunks[1]);
+    // Bougies manquantes : signalées plutôt que reliées en silence
+    let gap_note = if missing > 0 {
+        format!(", ⚠ {} manquantes", missing)
+    } else {
+        String::new()
+    };
+
+    // Variation sur la fenêtre affichée, en couleur entre description et raccourcis
+    let mut title = vec![Span::raw(format!(
+        " 🕯️ {} - {}({}, {} chandeliers{}{}) ",
+        item.symbol,
+        interval_display,
+        data.timeframe.label(),
+        data.candles.len(),
+        gap_note,
+        if locked_bounds.is_some() { ", 🔒 échelle fixe" } else { "" }
+    ))];
+    if let Some(change) = renderer.window_change_percent() {
+        let (arrow, color) = if change >= 0.0 { ("▲", BULLISH_COLOR) } else { ("▼", BEARISH_COLOR) };
+        title.push(Span::styled(format!("{} {:+.2}% ", arrow, change), Style::default().fg(color)));
+    }
+    // Indice de référence : "vs SPY +2.10%" (couleur de sa courbe)
+    if let (Some((symbol, _)), Some(change)) = (benchmark, renderer.benchmark_change_percent()) {
+        title.push(Span::styled(format!("vs {} {:+.2}% ", symbol, change), Style::default().fg(BENCHMARK_COLOR)));
+    }
+    // Graphique du bas de la vue partagée : intervalle changé par H / L
+    title.push(Span::raw(if primary { "[h/l: changer interval] " } else { "[H/L: changer interval] " }));
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(Line::from(title));
+    if let Some(label) = measure.and_then(|measure| measure_label(item.ticker_type(), &currency, data, measure)) {
+        block = block.title(
+            Title::from(Span::styled(label, Style::default().fg(Color::White).bg(MEASURE_MARK_BG)))
+                .position(Position::Bottom),
+        );
+    }
+
+    let paragraph = Paragraph::new(lines).block(block);
+    frame.render_widget(paragraph, area);
+}
+
+/// Légende de l'outil de mesure (bas du graphique)
+///
+/// - Sans ancre : " 📏 01/03/2024 · $171.20 · [Enter] ancrer  [←/→] déplacer  [n] annoter  [u] quitter "
+/// - Avec ancre : " 📏 +5.23% (+$8.40) · 12 barres · 16j 6h · [Enter] ré-ancrer  [d] droite  [u] quitter "
+fn measure_label(
+    ticker_type: TickerType,
+    currency: &Currency,
+    data: &crate::models::OHLCData,
+    cursor: MeasureCursor,
+) -> Option<String> {
+    let candle = data.candles.get(cursor.cursor)?;
+
+    let Some(anchor) = cursor.anchor else {
+        let date_format = if data.interval.is_intraday() { "%d/%m %H:%M" } else { "%d/%m/%Y" };
+        return Some(format!(
+            " 📏 {} · {} · [Enter] ancrer  [←/→] déplacer  [n] annoter  [u] quitter ",
+            candle.timestamp.format(date_format),
+            format_price_in(ticker_type, currency, candle.close)
+        ));
+    };
+
+    let measurement = measure::measure(&data.candles, anchor, cursor.cursor)?;
+    let percent = measurement
+        .change_percent
+        .map(|percent| format!("{:+.2}%", percent))
+        .unwrap_or_else(|| "N/A".to_string());
+    Some(format!(
+        " 📏 {} ({}) · {} barre{} · {} · [Enter] ré-ancrer  [d] droite  [u] quitter ",
+        percent,
+        format_price_change_in(ticker_type, currency, candle.close, measurement.change),
+        measurement.bars,
+        if measurement.bars > 1 { "s" } else { "" },
+        format_elapsed(measurement.elapsed)
+    ))
+}
+
+/// Affiche un graphique secondaire en attente de données
+fn render_pane_loading(frame: &mut Frame, item: &crate::models::WatchlistItem, interval: Interval, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(format!(" 🕯️ {} - {} [H/L: changer interval] ", item.symbol, interval.label()));
+
+    let text = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("⏳ Chargement de {} en {}...", item.symbol, interval.label()),
+            Style::default().fg(Color::Cyan),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
 }
 
 // ============================================================================
@@ -698,35 +1492,21 @@ pub fn render_candlestick_chart(frame: &mut Frame, app: &App, area: Rect) {
 
 /// Dessine le header avec infos du ticker
 fn render_header(frame: &mut Frame, app: &App, item: &crate::models::WatchlistItem, area: Rect) {
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan))
         .title(format!(" 🕯️ {} - {} ", item.symbol, item.name));
 
-    // CONCEPT : Confirmation de quit two-step et loading indicator
-    // - Si app.is_awaiting_quit_confirmation(), affiche message d'avertissement
+    // Légende du volume à droite du titre (la ligne d'infos est déjà pleine)
+    if let Some(volume) = format_volume_summary(item.session_volume(), item.average_volume()) {
+        block = block.title(Title::from(format!(" {} ", volume)).alignment(Alignment::Right));
+    }
+
+    // CONCEPT : Loading indicator
     // - Si app.is_loading_data(), affiche indicateur de chargement
     // - Sinon, affiche les infos normales avec shortcuts
-    let text = if app.is_awaiting_quit_confirmation() {
-        // Message de confirmation de quit
-        vec![Line::from(vec![
-            Span::styled(
-                "⚠  Appuyez sur ",
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                "[q]",
-                Style::default()
-                    .fg(Color::Red)
-                    .add_modifier(Modifier::BOLD)
-                    .add_modifier(Modifier::SLOW_BLINK),
-            ),
-            Span::styled(
-                " à nouveau pour quitter, ou n'importe quelle autre touche pour annuler ⚠",
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            ),
-        ])]
-    } else if app.is_loading_data() {
+    // - Confirmation de quit : popup par-dessus l'écran (confirm_popup.rs)
+    let text = if app.is_loading_data() {
         // Indicateur de chargement
         let message = app.loading_message.clone().unwrap_or_else(|| "Chargement en cours...".to_string());
         vec![Line::from(vec![
@@ -739,19 +1519,43 @@ fn render_header(frame: &mut Frame, app: &App, item: &crate::models::WatchlistIt
                 Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
             ),
         ])]
+    } else if let Some(toast) = app.active_toast() {
+        // Notification temporaire (export terminé, erreur, ...)
+        vec![toast_line(toast)]
+    } else if let Some(error) = &item.load_error {
+        // Dernier rechargement échoué (les bougies affichées sont les anciennes)
+        vec![Line::from(vec![
+            Span::styled(
+                format!("⚠ Échec du chargement : {}  ", error),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("[r]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Réessayer  "),
+            Span::styled("[ESC]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Retour"),
+        ])]
     } else if let (Some(price), Some(change)) = (item.current_price(), item.change_percent()) {
         let color = if change >= 0.0 { Color::Green } else { Color::Red };
-        let arrow = if change >= 0.0 { "▲" } else { "▼" };
+        let ticker_type = item.ticker_type();
+        let intraday = app.current_interval.is_intraday();
+        let earnings = item
+            .earnings_badge(chrono::Local::now().date_naive())
+            .map(|badge| format!("📅 {}  ", badge))
+            .unwrap_or_default();
 
         vec![Line::from(vec![
             Span::raw("Prix: "),
             Span::styled(
-                format!("${:.2}", price),
+                format_price_in(ticker_type, &item.currency(), price),
                 Style::default().fg(color).add_modifier(Modifier::BOLD),
             ),
             Span::raw("  "),
-            Span::styled(format!("{} {:+.2}%", arrow, change), Style::default().fg(color)),
+            Span::styled(
+                format_change(ticker_type, &item.symbol, item.change_absolute(), change),
+                Style::default().fg(color),
+            ),
             Span::raw("  "),
+            Span::styled(earnings, Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
             Span::styled(
                 "[ESC]",
                 Style::default()
@@ -759,6 +1563,56 @@ fn render_header(frame: &mut Frame, app: &App, item: &crate::models::WatchlistIt
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(" Retour  "),
+            Span::styled(
+                "[e]",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" Export PNG  "),
+            Span::styled(
+                "[m]",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" Multi-TF  "),
+            Span::styled(
+                "[o]",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" Carnet  "),
+            // Séparateurs de séance : utiles seulement en intraday
+            Span::styled(
+                if intraday { "[g]" } else { "" },
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(if intraday { " Séances  " } else { "" }),
+            Span::styled(
+                if intraday { "[s]" } else { "" },
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(if intraday { " Stats  " } else { "" }),
+            Span::styled(
+                "[b]",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" Backtest  "),
+            Span::styled(
+                "[v]",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(" vs {}  ", app.benchmark_symbol(&item.symbol))),
             Span::styled(
                 "[q]",
                 Style::default()
@@ -803,6 +1657,32 @@ fn render_no_data(frame: &mut Frame, area: Rect, message: &str) {
     frame.render_widget(paragraph, area);
 }
 
+/// Affiche l'échec du chargement d'un ticker avec la touche pour réessayer
+fn render_load_error(frame: &mut Frame, area: Rect, symbol: &str, error: &str) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red))
+        .title(" ⚠ Erreur ");
+
+    let text = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Échec du chargement de {}", symbol),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(error, Style::default().fg(Color::Red))),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("[r]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Réessayer  "),
+            Span::styled("[ESC] Retour", Style::default().fg(Color::Gray)),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
 /// Affiche un message quand le terminal est trop étroit
 ///
 /// CONCEPT : Responsive design - graceful degradation
@@ -863,3 +1743,366 @@ fn render_too_narrow(frame: &mut Frame, area: Rect) {
 // - Curseur pour afficher OHLC au survol
 //
 // ============================================================================
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::snapshot::{assert_snapshot, buffer_text, render_to_buffer, sample_item};
+
+    #[test]
+    fn test_candlestick_chart_snapshot() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 60)]);
+        app.current_interval = Interval::D1;
+        app.show_chart();
+
+        let buffer = render_to_buffer(120, 30, |frame| render_candlestick_chart(frame, &app, frame.size()));
+        assert_snapshot("candlestick_chart", &buffer);
+    }
+
+    #[test]
+    fn test_chart_cache_skips_unchanged_redraws() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 60)]);
+        app.current_interval = Interval::D1;
+        app.show_chart();
+
+        let render = |app: &App| render_to_buffer(120, 30, |frame| render_candlestick_chart(frame, app, frame.size()));
+        let renders = || CHART_CACHE.with(|cache| cache.borrow().renders);
+        let first = render(&app);
+        let before = renders();
+
+        // Toast : l'écran change, pas le graphique
+        app.show_toast("Copié".to_string());
+        render(&app);
+        assert_eq!(renders(), before);
+        app.toast = None;
+        assert_eq!(render(&app), first);
+        assert_eq!(renders(), before);
+
+        // Vue modifiée (outil de mesure) puis données modifiées : nouveau rendu
+        app.measure = Some(MeasureCursor { cursor: 59, anchor: None });
+        render(&app);
+        assert_eq!(renders(), before + 1);
+        app.watchlist[0].data.as_mut().unwrap().candles[59].close += 1.0;
+        render(&app);
+        assert_eq!(renders(), before + 2);
+
+        // Autre taille de terminal : nouveau rendu
+        render_to_buffer(100, 30, |frame| render_candlestick_chart(frame, &app, frame.size()));
+        assert_eq!(renders(), before + 3);
+    }
+
+    #[test]
+    fn test_split_view_pane_hints() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 60)]);
+        app.current_interval = Interval::D1;
+        app.show_chart();
+        app.toggle_split_view();
+        let render = |app: &App| buffer_text(&render_to_buffer(120, 40, |frame| render_candlestick_chart(frame, app, frame.size())));
+
+        // Graphique du bas chargé : raccourcis H / L, pas ceux du haut
+        app.secondary_interval = Interval::D1;
+        let text = render(&app);
+        assert_eq!(text.matches("[h/l: changer interval]").count(), 1, "{}", text);
+        assert_eq!(text.matches("[H/L: changer interval]").count(), 1, "{}", text);
+
+        // En attente de données : même raccourci
+        app.secondary_interval = Interval::H1;
+        let text = render(&app);
+        assert_eq!(text.matches("[H/L: changer interval]").count(), 1, "{}", text);
+    }
+
+    #[test]
+    fn test_failed_load_offers_retry() {
+        let mut item = crate::models::WatchlistItem::new("TSLA".to_string(), "Tesla".to_string());
+        item.load_error = Some("HTTP 500".to_string());
+        let app = App::with_watchlist(vec![item]);
+
+        let text = buffer_text(&render_to_buffer(80, 12, |frame| render_candlestick_chart(frame, &app, frame.size())));
+        assert!(text.contains("Échec du chargement de TSLA"));
+        assert!(text.contains("HTTP 500"));
+        assert!(text.contains("[r] Réessayer"));
+    }
+
+    #[test]
+    fn test_measure_tool_legend_and_highlight() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 60)]);
+        app.current_interval = Interval::D1;
+        app.show_chart();
+        app.toggle_measure();
+
+        let render = |app: &App| render_to_buffer(120, 30, |frame| render_candlestick_chart(frame, app, frame.size()));
+        let text = buffer_text(&render(&app));
+        assert!(text.contains("[Enter] ancrer"), "{}", text);
+
+        app.anchor_measure();
+        app.move_measure_cursor(-12);
+        let buffer = render(&app);
+        let text = buffer_text(&buffer);
+        assert!(text.contains("12 barres · 12j"), "{}", text);
+
+        // Ancre et curseur en fond marqué, plage entre les deux en fond discret
+        let backgrounds: Vec<Color> = buffer.content.iter().map(|cell| cell.bg).collect();
+        assert!(backgrounds.contains(&MEASURE_MARK_BG));
+        assert!(backgrounds.contains(&MEASURE_RANGE_BG));
+    }
+
+    #[test]
+    fn test_margin_and_locked_bounds() {
+        let candles = two_sessions();
+        let area = Rect::new(0, 0, 100, 20);
+        // Plus bas 111, plus haut 121 : amplitude 10
+        let auto = CandlestickRenderer::new(&candles, Interval::H1, area);
+        assert!((auto.max_price - 121.2).abs() < 1e-9);
+
+        let wide = CandlestickRenderer::new(&candles, Interval::H1, area).with_margin(10.0);
+        assert!((wide.min_price - 110.0).abs() < 1e-9);
+        assert!((wide.max_price - 122.0).abs() < 1e-9);
+        let invalid = CandlestickRenderer::new(&candles, Interval::H1, area).with_margin(f64::NAN);
+        assert_eq!(invalid.max_price, auto.max_price);
+
+        // Échelle verrouillée : les bornes ne suivent plus les chandeliers
+        let bounds = visible_price_bounds(&candles, 2.0);
+        assert_eq!(bounds, Some((auto.min_price, auto.max_price)));
+        let mut more = candles.clone();
+        more.push(OHLC::new(more.last().unwrap().timestamp + chrono::Duration::hours(1), 140.0, 150.0, 139.0, 149.0, 1));
+        let locked = CandlestickRenderer::new(&more, Interval::H1, area).with_price_bounds(bounds);
+        assert_eq!((locked.min_price, locked.max_price), bounds.unwrap());
+        assert!(visible_price_bounds(&[], 2.0).is_none());
+    }
+
+    #[test]
+    fn test_window_change_percent() {
+        let candles = two_sessions();
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, Rect::new(0, 0, 100, 20));
+        // Première ouverture 113, dernière clôture 120
+        assert!((renderer.window_change_percent().unwrap() - 700.0 / 113.0).abs() < 1e-9);
+        assert!(CandlestickRenderer::new(&[], Interval::H1, Rect::new(0, 0, 100, 20)).window_change_percent().is_none());
+    }
+
+    #[test]
+    fn test_benchmark_overlay() {
+        let candles = two_sessions();
+        let area = Rect::new(0, 0, 100, 20);
+        let scaled = |factor: f64, candles: &[OHLC]| -> Vec<OHLC> {
+            candles
+                .iter()
+                .map(|c| OHLC::new(c.timestamp, c.open * factor, c.high * factor, c.low * factor, c.close * factor, 1))
+                .collect()
+        };
+
+        // Même courbe à une autre échelle : points sur les clôtures, même variation
+        let bench = scaled(2.0, &candles);
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, area).with_benchmark(Some(&bench));
+        assert!((renderer.benchmark_change_percent().unwrap() - 700.0 / 113.0).abs() < 1e-9);
+        for (point, candle) in renderer.benchmark.iter().zip(&candles) {
+            assert!((point.unwrap() - candle.close).abs() < 1e-9);
+        }
+
+        // Indice à plat : points au niveau de la première ouverture, dans
+        // les cases laissées vides par les chandeliers
+        let flat: Vec<OHLC> = candles.iter().map(|c| OHLC::new(c.timestamp, 300.0, 300.0, 300.0, 300.0, 1)).collect();
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, area).with_benchmark(Some(&flat));
+        assert_eq!(renderer.benchmark_change_percent(), Some(0.0));
+        let text: String = renderer
+            .render_lines()
+            .iter()
+            .flat_map(|line| line.spans.iter())
+            .map(|span| span.content.to_string())
+            .collect();
+        assert!(text.contains(UNICODE_BENCHMARK));
+
+        // Indice en chute : l'échelle s'élargit pour le contenir
+        let falling: Vec<OHLC> = candles
+            .iter()
+            .enumerate()
+            .map(|(i, c)| OHLC::new(c.timestamp, 300.0, 300.0, 150.0, 300.0 - 10.0 * i as f64, 1))
+            .collect();
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, area).with_benchmark(Some(&falling));
+        assert!(renderer.min_price < 113.0 * 170.0 / 300.0 + 1e-9);
+
+        // Pas de recouvrement (indice postérieur) : rien à superposer
+        let later: Vec<OHLC> = flat
+            .iter()
+            .map(|c| OHLC::new(c.timestamp + chrono::Duration::days(30), 1.0, 1.0, 1.0, 1.0, 1))
+            .collect();
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, area).with_benchmark(Some(&later));
+        assert!(renderer.benchmark.is_empty() && renderer.benchmark_change_percent().is_none());
+    }
+
+    #[test]
+    fn test_candle_glyphs_match_render_candle() {
+        let candles = two_sessions();
+        // Échelle verrouillée plus étroite que les prix : chandeliers coupés aux bords
+        for bounds in [None, Some((114.0, 118.0)), Some((130.0, 140.0))] {
+            let renderer = CandlestickRenderer::new(&candles, Interval::H1, Rect::new(0, 0, 100, 20)).with_price_bounds(bounds);
+            for candle in &candles {
+                let heights = renderer.candle_heights(candle);
+                let glyphs = renderer.candle_glyphs(candle);
+                for y in 0..=renderer.height + 1 {
+                    let expected = if (1..=renderer.height).contains(&y) {
+                        CandlestickRenderer::render_candle(&heights, y)
+                    } else {
+                        UNICODE_VOID
+                    };
+                    assert_eq!(glyphs.at(y), expected, "ligne {} de {:?}", y, candle);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nice_step() {
+        assert_eq!(nice_step(37.0, 5), 10.0);
+        assert_eq!(nice_step(12.0, 5), 2.5);
+        assert_eq!(nice_step(9.0, 5), 2.0);
+        assert_eq!(nice_step(400.0, 4), 100.0);
+        assert!((nice_step(0.0042, 4) - 0.002).abs() < 1e-12);
+
+        assert_eq!(step_decimals(10.0), 0);
+        assert_eq!(step_decimals(2.5), 1);
+        assert_eq!(step_decimals(0.05), 2);
+        assert_eq!(step_decimals(0.0005), 4);
+    }
+
+    #[test]
+    fn test_y_axis_ticks_are_round() {
+        let candles = two_sessions();
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, Rect::new(0, 0, 100, 26));
+
+        let ticks = renderer.y_axis_ticks();
+        assert!(ticks.len() >= 2, "{:?}", ticks);
+        let step = ticks[1].1 - ticks[0].1;
+        assert_eq!(step, nice_step(renderer.max_price - renderer.min_price, (renderer.height / 4) as usize));
+        for (row, price) in &ticks {
+            assert!((price / step - (price / step).round()).abs() < 1e-9, "{} n'est pas un multiple de {}", price, step);
+            assert!((1..=renderer.height).contains(row));
+        }
+        // Environ 4 lignes entre deux graduations (arrondi des lignes compris)
+        assert!(ticks.windows(2).all(|pair| pair[1].0 - pair[0].0 >= 3));
+    }
+
+    /// Deux séances de 7 bougies 1h séparées par une nuit
+    fn two_sessions() -> Vec<OHLC> {
+        use chrono::TimeZone;
+
+        let mut candles = Vec::new();
+        for day in [2, 3] {
+            for hour in 13..20 {
+                let timestamp = chrono::Utc.with_ymd_and_hms(2024, 5, day, hour, 30, 0).unwrap();
+                let open = 100.0 + hour as f64;
+                candles.push(OHLC::new(timestamp, open, open + 2.0, open - 2.0, open + 1.0, 1));
+            }
+        }
+        candles
+    }
+
+    fn separator_count(lines: &[Line]) -> usize {
+        lines
+            .iter()
+            .flat_map(|line| line.spans.iter())
+            .map(|span| span.content.matches(UNICODE_SESSION_SEPARATOR).count())
+            .sum()
+    }
+
+    #[test]
+    fn test_annotation_markers() {
+        let candles = two_sessions();
+        let area = Rect::new(0, 0, 100, 20);
+        // Index hors de la série ignoré
+        let lines = CandlestickRenderer::new(&candles, Interval::H1, area)
+            .with_annotations(vec![0, candles.len() + 5])
+            .render_lines();
+        let rows: Vec<Vec<char>> = lines
+            .iter()
+            .map(|line| line.spans.iter().flat_map(|span| span.content.chars()).collect())
+            .collect();
+
+        let marks: Vec<(usize, usize)> = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, ch)| **ch == UNICODE_ANNOTATION).map(move |(x, _)| (y, x)))
+            .collect();
+        assert_eq!(marks.len(), 1);
+
+        // ◆ juste au-dessus de la mèche haute du premier chandelier
+        let (y, x) = marks[0];
+        assert_ne!(rows[y + 1][x], UNICODE_VOID);
+        assert!(rows[..y].iter().all(|row| row[x] == UNICODE_VOID));
+    }
+
+    #[test]
+    fn test_trend_line_cells() {
+        let candles = two_sessions();
+        let area = Rect::new(0, 0, 100, 20);
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, area);
+        let (low, high) = (renderer.min_price, renderer.max_price);
+        let horizontal = AnchoredLine { start: 2, start_price: (low + high) / 2.0, end: 5, end_price: (low + high) / 2.0 };
+
+        // Droite plate : une case par chandelier, à partir du premier point
+        let renderer = renderer.with_trend_lines(vec![horizontal]);
+        let cells = renderer.trend_line_cells(0, candles.len());
+        assert!(cells[..2].iter().all(Vec::is_empty));
+        let row = cells[2][0].0;
+        assert!(cells[2..].iter().all(|cell| cell == &vec![(row, UNICODE_TREND_FLAT)]));
+
+        // Droite raide : colonnes remplies sans trou
+        let steep = AnchoredLine { start: 0, start_price: low, end: 2, end_price: high };
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, area).with_trend_lines(vec![steep]);
+        let cells = renderer.trend_line_cells(0, candles.len());
+        let rows: Vec<u16> = cells[..2].iter().flatten().map(|(row, _)| *row).collect();
+        assert!(rows.windows(2).all(|pair| pair[1] == pair[0] + 1), "{:?}", rows);
+        assert!(cells[0].iter().all(|(_, ch)| *ch == UNICODE_TREND_UP));
+        // Prolongée au-delà du haut du graphique : coupée
+        assert!(cells[3..].iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn test_session_separators() {
+        let candles = two_sessions();
+        let area = Rect::new(0, 0, 100, 20);
+
+        let plain = CandlestickRenderer::new(&candles, Interval::H1, area).render_lines();
+        assert_eq!(separator_count(&plain), 0);
+
+        // Une frontière (nuit du 2 au 3) : un séparateur sur chaque ligne du graphique
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, area).with_session_separators(true);
+        assert_eq!(renderer.session_starts(&candles).iter().filter(|start| **start).count(), 1);
+        assert_eq!(separator_count(&renderer.render_lines()), renderer.height as usize);
+
+        // 1d : pas de séparateur même activé
+        let daily = CandlestickRenderer::new(&candles, Interval::D1, area).with_session_separators(true);
+        assert_eq!(separator_count(&daily.render_lines()), 0);
+    }
+
+    #[test]
+    fn test_gap_marks_on_x_axis() {
+        let mut candles = two_sessions();
+        // Retire 15:30 et 16:30 du premier jour : trou de 2 bougies
+        candles.drain(2..4);
+        let area = Rect::new(0, 0, 100, 20);
+
+        let gaps = vec![DataGap { index: 2, missing: 2 }];
+        let renderer = CandlestickRenderer::new(&candles, Interval::H1, area).with_gaps(gaps);
+        let lines = renderer.render_lines();
+
+        // Marque uniquement sur la ligne des ticks (première ligne de l'axe X)
+        let tick_line = &lines[renderer.height as usize];
+        let marks: usize = tick_line.spans.iter().map(|span| span.content.matches(UNICODE_GAP_MARK).count()).sum();
+        assert_eq!(marks, 1);
+
+        let plain = CandlestickRenderer::new(&candles, Interval::H1, area).render_lines();
+        assert!(plain.iter().flat_map(|line| line.spans.iter()).all(|span| !span.content.contains(UNICODE_GAP_MARK)));
+    }
+
+    #[test]
+    fn test_candlestick_chart_too_narrow() {
+        let app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 60)]);
+        let buffer = render_to_buffer(40, 12, |frame| render_candlestick_chart(frame, &app, frame.size()));
+        assert_snapshot("candlestick_chart_narrow", &buffer);
+    }
+}
+
@@ -24,6 +24,7 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::ui::format::{format_money, privacy, MASK};
 
 // ============================================================================
 // Fonction principale de rendu du graphique
@@ -96,7 +97,7 @@ fn render_chart_header(frame: &mut Frame, item: &crate::models::WatchlistItem, a
         vec![Line::from(vec![
             Span::raw("Prix: "),
             Span::styled(
-                format!("${:.2}", price),
+                format_money(price),
                 Style::default().fg(color).add_modifier(Modifier::BOLD),
             ),
             Span::raw("  "),
@@ -210,11 +211,11 @@ fn render_chart_graph(
         .title("Prix ($)")
         .style(Style::default().fg(Color::Gray))
         .bounds([y_min, y_max])
-        .labels(vec![
-            Span::raw(format!("${:.0}", y_min)),
-            Span::raw(format!("${:.0}", (y_min + y_max) / 2.0)),
-            Span::raw(format!("${:.0}", y_max)),
-        ]);
+        .labels(
+            [y_min, (y_min + y_max) / 2.0, y_max]
+                .map(|price| Span::raw(if privacy() { MASK.to_string() } else { format!("${:.0}", price) }))
+                .to_vec(),
+        );
 
     // Crée le widget Chart
     // CONCEPT RATATUI : Chart widget
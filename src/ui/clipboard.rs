@@ -0,0 +1,99 @@
+// ============================================================================
+// Presse-papiers : copie du ticker sélectionné (touches y / Y)
+// ============================================================================
+// Le texte est confié au terminal par la séquence OSC 52 :
+//
+//   ESC ] 52 ; c ; <texte en base64> BEL
+//
+// CONCEPT : Presse-papiers via le terminal
+// - Aucune bibliothèque système (X11, Wayland, Cocoa) n'est nécessaire
+// - Fonctionne aussi en SSH : c'est le terminal local qui copie
+// - Sous tmux, la séquence est enveloppée (passthrough) ; tmux doit
+//   l'autoriser (set -g allow-passthrough on)
+// - Les terminaux qui ignorent OSC 52 ne copient rien, sans erreur
+// ============================================================================
+
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::models::WatchlistItem;
+use crate::ui::format::{format_change, format_price_in};
+
+/// Séquence OSC 52 qui place `text` dans le presse-papiers
+///
+/// `tmux` : séquence enveloppée pour traverser tmux
+pub fn osc52_sequence(text: &str, tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", STANDARD.encode(text));
+    if tmux {
+        format!("\x1bPtmux;\x1b{}\x1b\\", sequence)
+    } else {
+        sequence
+    }
+}
+
+/// Copie un texte dans le presse-papiers du terminal
+pub fn copy(text: &str) -> Result<()> {
+    let tmux = std::env::var_os("TMUX").is_some();
+    let mut stdout = std::io::stdout();
+    stdout
+        .write_all(osc52_sequence(text, tmux).as_bytes())
+        .and_then(|_| stdout.flush())
+        .context("Échec de l'écriture dans le terminal")
+}
+
+/// Symbole et prix : "AAPL $271.49", "AIR.PA 153.42 €"
+///
+/// Sans cotation : le symbole seul
+pub fn quote_text(item: &WatchlistItem) -> String {
+    match item.current_price() {
+        Some(price) => format!("{} {}", item.symbol, format_price_in(item.ticker_type(), &item.currency(), price)),
+        None => item.symbol.clone(),
+    }
+}
+
+/// Ligne complète : "AAPL  Apple Inc.  $271.49  ▲ +2.11%"
+pub fn row_text(item: &WatchlistItem) -> String {
+    let mut fields = vec![item.symbol.clone(), item.name.clone()];
+    if let Some(price) = item.current_price() {
+        fields.push(format_price_in(item.ticker_type(), &item.currency(), price));
+    }
+    if let Some(change) = item.change_percent() {
+        fields.push(format_change(item.ticker_type(), &item.symbol, item.change_absolute(), change));
+    }
+    fields.join("  ")
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::snapshot::sample_item;
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(osc52_sequence("AAPL", false), "\x1b]52;c;QUFQTA==\x07");
+        assert_eq!(osc52_sequence("AAPL", true), "\x1bPtmux;\x1b\x1b]52;c;QUFQTA==\x07\x1b\\");
+    }
+
+    #[test]
+    fn test_quote_and_row_text() {
+        let item = sample_item("AIR.PA", "Airbus SE", 150.0, 10);
+        let price = format_price_in(item.ticker_type(), &item.currency(), item.current_price().unwrap());
+        assert_eq!(quote_text(&item), format!("AIR.PA {}", price));
+        assert!(price.ends_with(" €"), "{}", price);
+
+        let row = row_text(&item);
+        assert!(row.starts_with(&format!("AIR.PA  Airbus SE  {}  ", price)), "{}", row);
+        assert!(row.contains('%'), "{}", row);
+
+        let empty = WatchlistItem::new("TSLA".to_string(), "Tesla".to_string());
+        assert_eq!(quote_text(&empty), "TSLA");
+        assert_eq!(row_text(&empty), "TSLA  Tesla");
+    }
+}
@@ -0,0 +1,321 @@
+// ============================================================================
+// Compare - Performances normalisées de plusieurs tickers
+// ============================================================================
+// Les tickers choisis partent tous de 0 % au début de la période, sur un
+// même graphique :
+//
+//   ┌──────────────────────── ⚖ Comparaison ───────────────────────┐
+//   │              1W   [1M]   3M    6M    1Y    YTD               │
+//   └──────────────────────────────────────────────────────────────┘
+//   ┌ Tickers (2/8) ────┐┌ Performance sur 1M (base 0 %) ───────────┐
+//   │▶ [x] AAPL   +4.2% ││+6.0%│           ⣀⠤⠒⠉  ┌───────────────┐│
+//   │  [ ] MSFT         ││     │      ⣀⡠⠔⠊       │AAPL +4.2%     ││
+//   │  [x] BTC-USD -1.3%││     │⠤⠤⠤⠤⢄⣀⡠⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤└───────────────┘│
+//   │                   ││-3.0%│          ⠉⠒⠢⠤⣀                    │
+//   │                   ││     └─────────────────────────────────── │
+//   │                   ││ 01/09           15/09             30/09  │
+//
+// - Historique D1 (chargé à l'ouverture de la vue s'il manque), comme le
+//   classement
+// - Une couleur par ticker, dans l'ordre de sélection (MAX_COMPARED au plus)
+// - Début de période commun, pris depuis la clôture la plus récente : une
+//   crypto et une action restent alignées sur le calendrier
+// ============================================================================
+
+use chrono::Duration;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols,
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, MAX_COMPARED};
+use crate::models::returns::{normalized_performance, ReturnPeriod};
+use crate::models::Interval;
+use crate::ui::dashboard::toast_line;
+
+/// Largeur de la liste des tickers (bordures incluses)
+const LIST_WIDTH: u16 = 26;
+
+/// Couleur de chaque ticker comparé, dans l'ordre de sélection
+const PALETTE: [Color; MAX_COMPARED] = [
+    Color::Cyan,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Green,
+    Color::LightRed,
+    Color::LightBlue,
+    Color::White,
+    Color::Rgb(255, 165, 0),
+];
+
+/// Courbe d'un ticker comparé
+struct Series {
+    /// Index dans la watchlist
+    index: usize,
+    color: Color,
+    /// Points (jours depuis le début, %) ; None : historique absent ou trop court
+    points: Option<Vec<(f64, f64)>>,
+}
+
+impl Series {
+    /// Performance sur toute la période (dernier point)
+    fn change(&self) -> Option<f64> {
+        self.points.as_ref()?.last().map(|(_, change)| *change)
+    }
+}
+
+/// Dessine la vue comparaison (onglets des périodes, liste, graphique, footer)
+pub fn render_compare(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Périodes
+            Constraint::Min(0),    // Liste + graphique
+            Constraint::Length(3), // Footer
+        ])
+        .split(area)
+        .to_vec();
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(LIST_WIDTH), Constraint::Min(0)])
+        .split(chunks[1])
+        .to_vec();
+
+    let series = compared_series(app);
+    render_tabs(frame, app, chunks[0]);
+    render_list(frame, app, &series, body[0]);
+    render_graph(frame, app, &series, body[1]);
+    render_footer(frame, app, chunks[2]);
+}
+
+/// Courbes des tickers comparés, depuis le début commun de la période
+fn compared_series(app: &App) -> Vec<Series> {
+    let start = app.compare_start();
+    app.compared_items()
+        .zip(PALETTE.iter().cycle())
+        .map(|((index, item), color)| Series {
+            index,
+            color: *color,
+            points: start.and_then(|start| normalized_performance(item.data_for(Interval::D1)?, start)),
+        })
+        .collect()
+}
+
+/// Dessine les onglets des périodes (la période active est surlignée)
+fn render_tabs(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" ⚖ Comparaison ")
+        .title_alignment(Alignment::Center);
+
+    let spans: Vec<Span> = ReturnPeriod::COMPARE
+        .iter()
+        .map(|period| {
+            if *period == app.compare_period {
+                Span::styled(
+                    format!(" [{}] ", period.label()),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled(format!("  {}  ", period.label()), Style::default().fg(Color::Gray))
+            }
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(Line::from(spans)).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+/// Dessine la liste de la watchlist, cases cochées pour les tickers comparés
+fn render_list(frame: &mut Frame, app: &App, series: &[Series], area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(" Tickers ({}/{}) ", series.len(), MAX_COMPARED));
+
+    let lines: Vec<Line> = app
+        .watchlist
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let selected = index == app.compare_index;
+            let compared = series.iter().find(|series| series.index == index);
+            let mut style = Style::default()
+                .fg(compared.map_or(Color::Gray, |series| series.color))
+                .add_modifier(Modifier::BOLD);
+            if selected {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+
+            let mut spans = vec![Span::styled(
+                format!(
+                    "{}[{}] {:<9}",
+                    if selected { "▶ " } else { "  " },
+                    if compared.is_some() { "x" } else { " " },
+                    item.symbol
+                ),
+                style,
+            )];
+            if let Some(change) = compared.and_then(Series::change) {
+                let color = if change >= 0.0 { Color::Green } else { Color::Red };
+                spans.push(Span::styled(format!(" {:+.1}%", change), Style::default().fg(color)));
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Dessine les courbes normalisées sur un même graphique, avec légende
+fn render_graph(frame: &mut Frame, app: &App, series: &[Series], area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(format!(" Performance sur {} (base 0 %) ", app.compare_period.label()));
+
+    let message = if series.is_empty() {
+        Some("Cochez des tickers avec [Espace]")
+    } else if series.iter().all(|series| series.points.is_none()) {
+        let loaded = app.compared_items().all(|(_, item)| item.data_for(Interval::D1).is_some());
+        Some(if loaded { "Historique insuffisant pour cette période" } else { "Chargement..." })
+    } else {
+        None
+    };
+    if let Some(message) = message {
+        let paragraph = Paragraph::new(Span::styled(message, Style::default().fg(Color::Gray)))
+            .block(block)
+            .alignment(Alignment::Center);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Bornes : toutes les courbes et la ligne 0 %, avec une marge de 5 %
+    let all_points = series.iter().filter_map(|series| series.points.as_deref()).flatten();
+    let (x_max, y_min, y_max) = all_points.fold((1.0_f64, 0.0_f64, 0.0_f64), |(x_max, y_min, y_max), &(x, y)| {
+        (x_max.max(x), y_min.min(y), y_max.max(y))
+    });
+    let margin = ((y_max - y_min) * 0.05).max(0.5);
+    let (y_min, y_max) = (y_min - margin, y_max + margin);
+
+    let names: Vec<String> = series
+        .iter()
+        .map(|series| {
+            let symbol = &app.watchlist[series.index].symbol;
+            match series.change() {
+                Some(change) => format!("{} {:+.1}%", symbol, change),
+                None => format!("{} —", symbol),
+            }
+        })
+        .collect();
+    let zero = [(0.0, 0.0), (x_max, 0.0)];
+    let mut datasets = vec![Dataset::default()
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::DarkGray))
+        .data(&zero)];
+    datasets.extend(series.iter().zip(&names).filter_map(|(series, name)| {
+        Some(
+            Dataset::default()
+                .name(name.as_str())
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(series.color))
+                .data(series.points.as_deref()?),
+        )
+    }));
+
+    // Axe X : dates de début, milieu et fin de période
+    let start = app.compare_start();
+    let date_label = |days: f64| {
+        start
+            .map(|start| (start + Duration::seconds((days * 86_400.0) as i64)).format("%d/%m").to_string())
+            .unwrap_or_default()
+    };
+    let x_axis = Axis::default()
+        .style(Style::default().fg(Color::Gray))
+        .bounds([0.0, x_max])
+        .labels([0.0, x_max / 2.0, x_max].map(|days| Span::raw(date_label(days))).to_vec());
+    let y_axis = Axis::default()
+        .style(Style::default().fg(Color::Gray))
+        .bounds([y_min, y_max])
+        .labels(
+            [y_min, (y_min + y_max) / 2.0, y_max]
+                .map(|change| Span::raw(format!("{:+.1}%", change)))
+                .to_vec(),
+        );
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(x_axis)
+        .y_axis(y_axis)
+        .hidden_legend_constraints((Constraint::Percentage(50), Constraint::Percentage(50)));
+    frame.render_widget(chart, area);
+}
+
+/// Dessine le footer (raccourcis ou notification)
+fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let line = match app.active_toast() {
+        Some(toast) => toast_line(toast),
+        None => Line::from(vec![
+            Span::styled("[↑↓ / j k]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Navigate  "),
+            Span::styled("[Espace]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" Comparer  "),
+            Span::styled("[h / l]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Période  "),
+            Span::styled("[Enter]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" Graphique  "),
+            Span::styled("[m / ESC]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Retour"),
+        ]),
+    };
+
+    let paragraph = Paragraph::new(line).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::WatchlistItem;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+
+    #[test]
+    fn test_compare_view_legend_and_selection() {
+        let mut app = App::with_watchlist(vec![
+            sample_item("AAPL", "Apple Inc.", 170.0, 60),
+            sample_item("TSLA", "Tesla", 250.0, 60),
+            WatchlistItem::new("NEW".to_string(), "Sans données".to_string()),
+        ]);
+        app.current_interval = Interval::D1;
+        app.show_compare();
+        app.compare_down();
+        assert!(app.toggle_compare_selection());
+
+        let text = buffer_text(&render_to_buffer(110, 24, |frame| render_compare(frame, &app, frame.size())));
+        assert!(text.contains("[1M]"), "{}", text);
+        assert!(text.contains("[x] AAPL") && text.contains("[x] TSLA"), "{}", text);
+        assert!(text.contains("[ ] NEW"), "{}", text);
+        assert!(text.contains("Tickers (2/8)"), "{}", text);
+        assert!(text.contains("[Espace] Comparer"), "{}", text);
+
+        // Rien de coché : invite à sélectionner
+        app.compare_symbols.clear();
+        let text = buffer_text(&render_to_buffer(110, 24, |frame| render_compare(frame, &app, frame.size())));
+        assert!(text.contains("Cochez des tickers"), "{}", text);
+    }
+}
@@ -0,0 +1,117 @@
+// ============================================================================
+// Popup de confirmation (quitter, supprimer un ticker)
+// ============================================================================
+// Fenêtre modale centrée par-dessus l'écran courant, bien visible même sur
+// un grand terminal (le footer est loin du regard) :
+//
+//   ┌─────────────────── ⚠ Confirmation ───────────────────┐
+//   │                                                      │
+//   │                 Quitter LazyWallet ?                 │
+//   │   ⚠ Téléchargement en cours : Ajout de AAPL...       │
+//   │                                                      │
+//   │          [Entrée / q] Oui     [Échap] Non            │
+//   └──────────────────────────────────────────────────────┘
+//
+// - Quitter : popup seulement si du travail serait perdu (App::quit_blocker)
+// - Touches gérées dans handle_event : tant que le popup est affiché,
+//   toute autre touche annule sans rien déclencher
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::ui::forex_menu::centered_rect;
+
+/// Largeur du popup (bordures incluses)
+const POPUP_WIDTH: u16 = 56;
+
+/// Dessine le popup de la confirmation en attente (rien sinon)
+pub fn render_confirm_popup(frame: &mut Frame, app: &App, area: Rect) {
+    let (question, reason, key) = if app.is_awaiting_delete_confirmation() {
+        let ticker = app
+            .watchlist
+            .get(app.selected_index)
+            .map(|item| format!("{} ({})", item.symbol, item.name))
+            .unwrap_or_else(|| "?".to_string());
+        (format!("Supprimer {} de la watchlist ?", ticker), None, "[Entrée / d]")
+    } else if app.is_awaiting_quit_confirmation() {
+        // Raison relue à chaque rendu : elle disparaît si le téléchargement se termine
+        ("Quitter LazyWallet ?".to_string(), app.quit_blocker(), "[Entrée / q]")
+    } else {
+        return;
+    };
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(question, Style::default().fg(Color::White).add_modifier(Modifier::BOLD))),
+    ];
+    if let Some(reason) = reason {
+        lines.push(Line::from(Span::styled(format!("⚠ {}", reason), Style::default().fg(Color::Yellow))));
+    }
+    lines.extend([
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(key, Style::default().fg(Color::Red).add_modifier(Modifier::BOLD).add_modifier(Modifier::SLOW_BLINK)),
+            Span::raw(" Oui     "),
+            Span::styled("[Échap]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" Non"),
+        ]),
+    ]);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(" ⚠ Confirmation ")
+        .title_alignment(Alignment::Center);
+
+    // Lignes après retour à la ligne (nom long, raison), plus les bordures
+    let inner = POPUP_WIDTH as usize - 2;
+    let rows: usize = lines.iter().map(|line| line.width().max(1).div_ceil(inner)).sum();
+    let popup = centered_rect(POPUP_WIDTH, rows as u16 + 2, area);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(block).alignment(Alignment::Center).wrap(Wrap { trim: true }),
+        popup,
+    );
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+
+    #[test]
+    fn test_confirm_popup_asks_the_pending_question() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 30)]);
+        let render = |app: &App| buffer_text(&render_to_buffer(80, 12, |frame| render_confirm_popup(frame, app, frame.size())));
+        assert!(!render(&app).contains("Confirmation"));
+
+        app.request_quit();
+        let text = render(&app);
+        assert!(text.contains("⚠ Confirmation"), "{}", text);
+        assert!(text.contains("Quitter LazyWallet ?"), "{}", text);
+        assert!(text.contains("[Entrée / q] Oui"), "{}", text);
+        assert!(!text.contains("Téléchargement"), "{}", text);
+
+        app.start_loading(Some("Ajout de TSLA...".to_string()));
+        let text = render(&app);
+        assert!(text.contains("⚠ Téléchargement en cours : Ajout de TSLA..."), "{}", text);
+        app.stop_loading();
+
+        app.cancel_quit();
+        app.request_delete();
+        let text = render(&app);
+        assert!(text.contains("Supprimer AAPL (Apple Inc.)"), "{}", text);
+        assert!(text.contains("[Entrée / d] Oui"), "{}", text);
+    }
+}
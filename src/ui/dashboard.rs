@@ -15,16 +15,28 @@
 // 4. Style : couleurs et attributs de texte
 // ============================================================================
 
+use chrono::{DateTime, Utc};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
+    widgets::block::{Position, Title},
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
-use crate::app::{App, Screen};
-use crate::ui::candlestick_text;
+use crate::api::health::{HealthState, ProviderStatus};
+use crate::app::{App, InputPurpose, Screen, Toast};
+use crate::models::cost_basis::{pnl_totals, CostBasisMethod, PnlTotals};
+use crate::models::economic_calendar::EconomicEvent;
+use crate::models::expression::{WatchExpression, WatchValue};
+use crate::models::fear_greed::FearGreed;
+use crate::models::portfolio::PortfolioSummary;
+use crate::models::ticker::POPULAR_SYMBOLS;
+use crate::models::{Currency, TickerType};
+use crate::profile;
+use crate::ui::format::{format_change, format_elapsed, format_money, format_price_in, format_signed_money, format_volume_summary, privacy};
+use crate::ui::{allocation, backtest, candlestick_text, compare, confirm_popup, debug_overlay, detail, dividends, forex_menu, leaderboard, returns, screener, status_bar};
 
 // ============================================================================
 // Fonction principale de rendu
@@ -39,49 +51,126 @@ use crate::ui::candlestick_text;
 // - &App : on lit l'état, pas de modification
 // ============================================================================
 
-/// Dessine l'interface complète
+/// Dessine l'interface complète sur tout le terminal
+///
+/// # Arguments
+/// * `frame` - Surface de dessin ratatui
+/// * `app` - État de l'application
+pub fn render(frame: &mut Frame, app: &App) {
+    render_in(frame, app, frame.size());
+}
+
+/// Dessine l'interface complète dans une zone donnée
 ///
 /// CONCEPT RUST : Routing avec match sur enum
 /// - Pattern matching sur app.current_screen
 /// - Affiche Dashboard OU ChartView selon l'état
 /// - Le compilateur garantit l'exhaustivité (tous les cas gérés)
 ///
-/// # Arguments
-/// * `frame` - Surface de dessin ratatui
-/// * `app` - État de l'application
-pub fn render(frame: &mut Frame, app: &App) {
+/// CONCEPT : Zone explicite
+/// - Aucune vue ne lit frame.size() : la zone est toujours passée en argument
+/// - Les tests dessinent ainsi dans un TestBackend de taille fixe
+///
+/// La dernière ligne est réservée à la barre d'état, commune à tous les écrans
+pub fn render_in(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+    let area = chunks[0];
+    status_bar::render_status_bar(frame, app, chunks[1]);
+
     // CONCEPT RUST : Match sur enum pour router
     // - Pattern "State Machine"
     // - Le compilateur force à gérer tous les variants
     match app.current_screen {
         Screen::Dashboard => {
             // Affiche la watchlist
-            render_dashboard(frame, app);
+            render_dashboard(frame, app, area);
         }
         Screen::ChartView => {
             // Affiche le graphique en chandeliers japonais (Unicode text)
-            candlestick_text::render_candlestick_chart(frame, app, frame.size());
+            candlestick_text::render_candlestick_chart(frame, app, area);
         }
         Screen::InputMode => {
             // Affiche le dashboard avec l'input mode en bas
-            render_input_mode(frame, app);
+            render_input_mode(frame, app, area);
+        }
+        Screen::ForexMenu => {
+            // Menu d'ajout rapide par-dessus le dashboard
+            render_dashboard(frame, app, area);
+            forex_menu::render_forex_menu(frame, app, area);
+        }
+        Screen::Allocation => {
+            // Affiche la répartition du portefeuille
+            allocation::render_allocation(frame, app, area);
+        }
+        Screen::Screener => {
+            // Affiche les résultats du screener courant
+            screener::render_screener(frame, app, area);
+        }
+        Screen::Returns => {
+            // Affiche le tableau des rendements
+            returns::render_returns(frame, app, area);
+        }
+        Screen::Leaderboard => {
+            // Affiche le classement de la watchlist
+            leaderboard::render_leaderboard(frame, app, area);
+        }
+        Screen::Dividends => {
+            // Affiche le calendrier des dividendes
+            dividends::render_dividends(frame, app, area);
+        }
+        Screen::Compare => {
+            // Affiche la comparaison des tickers choisis
+            compare::render_compare(frame, app, area);
         }
+        Screen::Backtest => {
+            // Affiche le résultat du backtest
+            backtest::render_backtest(frame, app, area);
+        }
+        Screen::Detail => {
+            // Affiche la fiche détaillée du ticker sélectionné
+            detail::render_detail(frame, app, area);
+        }
+    }
+
+    // Confirmation en attente (quitter, supprimer) par-dessus l'écran courant
+    confirm_popup::render_confirm_popup(frame, app, area);
+
+    // Métriques des téléchargements (F12) par-dessus l'écran courant
+    if app.debug_overlay {
+        debug_overlay::render_debug_overlay(frame, app, area);
     }
 }
 
 /// Dessine le dashboard (watchlist)
-fn render_dashboard(frame: &mut Frame, app: &App) {
-    let size = frame.size();
-    let chunks = create_layout(size);
+pub fn render_dashboard(frame: &mut Frame, app: &App, area: Rect) {
+    let summary = app.portfolio.summary(&app.watchlist);
+    let pnl = journal_pnl(app);
+    let banner_height = u16::from(app.current_notification().is_some());
+    let calendar_height = u16::from(!app.todays_economic_events().is_empty());
+    let chunks = create_layout(area, banner_height, calendar_height, summary_height(summary.as_ref(), pnl.as_ref()));
 
     // Dessine le header (titre)
-    render_header(frame, chunks[0]);
+    render_header(frame, app, chunks[0]);
+
+    // Dessine l'alerte en cours (résultats demain, ...)
+    render_notification_banner(frame, app, chunks[1]);
+
+    // Dessine les publications économiques du jour (calendrier configuré)
+    render_economic_calendar(frame, app, chunks[2]);
+
+    // Dessine le résumé du portefeuille (seulement si des positions sont cotées)
+    if let Some(summary) = &summary {
+        render_portfolio_summary(frame, summary, pnl.as_ref(), chunks[3]);
+    }
 
     // Dessine le contenu principal (watchlist)
-    render_main_content(frame, app, chunks[1]);
+    render_main_content(frame, app, chunks[4]);
 
     // Dessine le footer (instructions)
-    render_footer(frame, app, chunks[2]);
+    render_footer(frame, app, chunks[5]);
 }
 
 // ============================================================================
@@ -96,17 +185,24 @@ fn render_dashboard(frame: &mut Frame, app: &App) {
 //   - Max(n) : maximum n
 // ============================================================================
 
-/// Crée le layout principal (header, content, footer)
+/// Crée le layout principal (header, bandeaux, résumé, content, footer)
 ///
 /// CONCEPT RUST : Rc<[T]> vs Vec<T>
 /// - Layout::split() retourne Rc<[Rect]> (reference counted slice)
 /// - Rc permet le partage sans copie (efficient)
 /// - On le convertit en Vec avec .to_vec() pour simplifier
-fn create_layout(area: Rect) -> Vec<Rect> {
+///
+/// Les bandeaux (alerte, calendrier économique, portefeuille) ont une hauteur
+/// nulle quand ils sont vides : les index des zones restent les mêmes dans
+/// tous les cas
+fn create_layout(area: Rect, banner_height: u16, calendar_height: u16, summary_height: u16) -> Vec<Rect> {
     Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),      // Header : 3 lignes
+            Constraint::Length(banner_height), // Bandeau d'alerte : 1 ligne
+            Constraint::Length(calendar_height), // Calendrier économique : 1 ligne
+            Constraint::Length(summary_height), // Résumé du portefeuille : 3 lignes
             Constraint::Min(0),          // Content : tout le reste
             Constraint::Length(3),       // Footer : 3 lignes
         ])
@@ -124,30 +220,61 @@ fn create_layout(area: Rect) -> Vec<Rect> {
 // ============================================================================
 
 /// Dessine le header avec le titre
-fn render_header(frame: &mut Frame, area: Rect) {
+///
+/// Si des indices sont configurés et chargés, le header affiche le bandeau
+/// "marchés" à la place du sous-titre :
+/// "S&P 500 5234.18 ▲ +0.45%  │  NASDAQ 100 18320.50 ▼ -0.12%  │  CAC 40 ..."
+fn render_header(frame: &mut Frame, app: &App, area: Rect) {
     // Crée un Block avec bordures
     // CONCEPT : Builder pattern
     // - Chaque méthode retourne self
     // - Permet de chaîner les appels
-    let block = Block::default()
+    // Profil actif (--profile) rappelé dans le titre
+    let title = match profile::name() {
+        Some(name) => format!(" LazyWallet · {} ", name),
+        None => " LazyWallet ".to_string(),
+    };
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan))
-        .title(" LazyWallet ")
+        .title(title)
         .title_alignment(Alignment::Center);
 
+    // Indice Fear & Greed, en bas à gauche du cadre (watchlist avec crypto)
+    if let Some(index) = app.fear_greed.filter(|_| app.config.fear_greed && app.has_crypto()) {
+        block = block.title(
+            Title::from(fear_greed_line(&index))
+                .alignment(Alignment::Left)
+                .position(Position::Bottom),
+        );
+    }
+
+    // Santé des fournisseurs, en bas à droite du cadre
+    if !app.provider_health.is_empty() {
+        block = block.title(
+            Title::from(provider_health_line(&app.provider_health))
+                .alignment(Alignment::Right)
+                .position(Position::Bottom),
+        );
+    }
+
     // Texte du header
     // CONCEPT RATATUI : Span et Line
     // - Span : morceau de texte avec style
     // - Line : une ligne composée de Spans
     // - Vec<Line> : paragraphe multi-lignes
-    let text = vec![
-        Line::from(Span::styled(
-            "🚀 Terminal User Interface Mode",
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD),
-        )),
-    ];
+    let text = if app.market_overview.is_empty() {
+        vec![
+            Line::from(Span::styled(
+                "🚀 Terminal User Interface Mode",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            )),
+        ]
+    } else {
+        vec![market_overview_line(app)]
+    };
 
     let paragraph = Paragraph::new(text)
         .block(block)
@@ -159,6 +286,315 @@ fn render_header(frame: &mut Frame, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
+/// Construit le segment de santé des fournisseurs
+///
+/// " Yahoo ● OK │ Binance ● limité 14:32 " (heure locale de la dernière
+/// erreur, affichée tant que le fournisseur n'est pas revenu à OK)
+fn provider_health_line(statuses: &[ProviderStatus]) -> Line<'static> {
+    let mut spans = vec![Span::raw(" ")];
+
+    for (i, status) in statuses.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+        }
+
+        let color = match status.state {
+            HealthState::Ok => Color::Green,
+            HealthState::Degraded => Color::Yellow,
+            HealthState::RateLimited => Color::Red,
+        };
+        spans.push(Span::styled(format!("{} ", status.provider.label()), Style::default().fg(Color::Gray)));
+        spans.push(Span::styled(format!("● {}", status.state.label()), Style::default().fg(color)));
+
+        if status.state != HealthState::Ok {
+            if let Some((at, _)) = &status.last_error {
+                let time = at.with_timezone(&chrono::Local).format("%H:%M");
+                spans.push(Span::styled(format!(" {}", time), Style::default().fg(Color::DarkGray)));
+            }
+        }
+    }
+
+    spans.push(Span::raw(" "));
+    Line::from(spans)
+}
+
+/// Largeur de la jauge Fear & Greed
+const FEAR_GREED_WIDTH: usize = 10;
+
+/// Construit la jauge Fear & Greed : " F&G ███████░░░ 72 Avidité "
+///
+/// Du rouge (peur extrême) au vert (avidité extrême)
+fn fear_greed_line(index: &FearGreed) -> Line<'static> {
+    let filled = (index.value as usize * FEAR_GREED_WIDTH + 50) / 100;
+    let color = match index.value {
+        0..=24 => Color::Red,
+        25..=44 => Color::LightRed,
+        45..=55 => Color::Yellow,
+        56..=75 => Color::LightGreen,
+        _ => Color::Green,
+    };
+
+    Line::from(vec![
+        Span::styled(" F&G ", Style::default().fg(Color::Gray)),
+        Span::styled("█".repeat(filled), Style::default().fg(color)),
+        Span::styled("░".repeat(FEAR_GREED_WIDTH - filled), Style::default().fg(Color::DarkGray)),
+        Span::styled(format!(" {} {} ", index.value, index.label()), Style::default().fg(color)),
+    ])
+}
+
+/// Construit la ligne du bandeau des indices (nom, niveau, variation du jour)
+fn market_overview_line(app: &App) -> Line<'static> {
+    let mut spans = Vec::new();
+
+    for (i, item) in app.market_overview.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled("  │  ", Style::default().fg(Color::DarkGray)));
+        }
+
+        spans.push(Span::styled(
+            format!("{} ", truncate_with_ellipsis(&item.name, 14)),
+            Style::default().fg(Color::Gray),
+        ));
+
+        let ticker_type = item.ticker_type();
+        if let Some(price) = item.current_price() {
+            spans.push(Span::styled(
+                format!("{} ", format_price_in(ticker_type, &item.currency(), price)),
+                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(change) = item.change_percent() {
+            let color = if change >= 0.0 { Color::Green } else { Color::Red };
+            let arrow = if change >= 0.0 { "▲" } else { "▼" };
+            spans.push(Span::styled(format!("{} {:+.2}%", arrow, change), Style::default().fg(color)));
+        }
+    }
+
+    Line::from(spans)
+}
+
+// ============================================================================
+// Portfolio Summary : Bandeau du portefeuille
+// ============================================================================
+
+/// P&L agrégé du journal (None si aucune transaction saisie)
+fn journal_pnl(app: &App) -> Option<(PnlTotals, CostBasisMethod)> {
+    if app.journal.transactions.is_empty() {
+        return None;
+    }
+    Some((pnl_totals(&app.pnl()), app.config.cost_basis))
+}
+
+/// Hauteur du bandeau : 0 sans positions, une ligne de plus avec le P&L
+fn summary_height(summary: Option<&PortfolioSummary>, pnl: Option<&(PnlTotals, CostBasisMethod)>) -> u16 {
+    match (summary, pnl) {
+        (None, _) => 0,
+        (Some(_), None) => 3,
+        (Some(_), Some(_)) => 4,
+    }
+}
+
+/// Couleur d'un montant signé
+fn signed_color(value: f64) -> Color {
+    if value >= 0.0 { Color::Green } else { Color::Red }
+}
+
+/// Dessine le bandeau résumant le portefeuille
+///
+/// Format : "Valeur $12 345.67 (liquidités $500.00) │ Jour ▲ +$50.00 (+0.41%) │ Meilleur AAPL +2.10% │ Pire TSLA -1.30%"
+/// Seconde ligne si des transactions existent : "Latent +$320.00 │ Réalisé +$80.00 (FIFO)"
+fn render_portfolio_summary(
+    frame: &mut Frame,
+    summary: &PortfolioSummary,
+    pnl: Option<&(PnlTotals, CostBasisMethod)>,
+    area: Rect,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" 💼 Portefeuille ");
+
+    let change_color = if summary.day_change >= 0.0 { Color::Green } else { Color::Red };
+    let arrow = if summary.day_change >= 0.0 { "▲" } else { "▼" };
+    let separator = Span::styled("  │  ", Style::default().fg(Color::DarkGray));
+
+    let mut spans = vec![
+        Span::styled("Valeur ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format_money(summary.total_value),
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if summary.cash != 0.0 {
+        spans.push(Span::styled(
+            format!(" (liquidités {})", format_money(summary.cash)),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    spans.extend([
+        separator.clone(),
+        Span::styled("Jour ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            // Mode discret : variation en % seulement
+            if privacy() {
+                format!("{} {:+.2}%", arrow, summary.day_change_percent)
+            } else {
+                format!(
+                    "{} {} ({:+.2}%)",
+                    arrow,
+                    format_signed_money(summary.day_change),
+                    summary.day_change_percent
+                )
+            },
+            Style::default().fg(change_color).add_modifier(Modifier::BOLD),
+        ),
+    ]);
+
+    for (label, performer) in [("Meilleur ", &summary.best), ("Pire ", &summary.worst)] {
+        if let Some(performer) = performer {
+            let color = if performer.change_percent >= 0.0 { Color::Green } else { Color::Red };
+            spans.push(separator.clone());
+            spans.push(Span::styled(label, Style::default().fg(Color::Gray)));
+            spans.push(Span::styled(
+                format!("{} {:+.2}%", performer.symbol, performer.change_percent),
+                Style::default().fg(color),
+            ));
+        }
+    }
+
+    if summary.unpriced > 0 {
+        spans.push(separator.clone());
+        spans.push(Span::styled(
+            format!("{} sans cotation", summary.unpriced),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let mut lines = vec![Line::from(spans)];
+
+    if let Some((totals, method)) = pnl {
+        lines.push(Line::from(vec![
+            Span::styled("Latent ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format_signed_money(totals.unrealized),
+                Style::default().fg(signed_color(totals.unrealized)).add_modifier(Modifier::BOLD),
+            ),
+            separator,
+            Span::styled("Réalisé ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format_signed_money(totals.realized),
+                Style::default().fg(signed_color(totals.realized)).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  ({})", method.label()), Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, area);
+}
+
+// ============================================================================
+// Bandeau d'alerte
+// ============================================================================
+
+/// Dessine l'alerte la plus ancienne (reste affichée jusqu'à 'x')
+fn render_notification_banner(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(notification) = app.current_notification() else {
+        return;
+    };
+
+    let mut spans = vec![
+        Span::styled(
+            notification.message.clone(),
+            Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("  [x] Fermer", Style::default().fg(Color::Yellow)),
+    ];
+    if notification.rule.is_some() {
+        spans.push(Span::styled("  [z] Snooze", Style::default().fg(Color::Yellow)));
+    }
+
+    let pending = app.notifications.len() - 1;
+    if pending > 0 {
+        spans.push(Span::styled(format!("  (+{} en attente)", pending), Style::default().fg(Color::DarkGray)));
+    }
+
+    frame.render_widget(Paragraph::new(Line::from(spans)).alignment(Alignment::Center), area);
+}
+
+// ============================================================================
+// Calendrier économique
+// ============================================================================
+
+/// Dessine les publications du jour (rien sans calendrier configuré)
+fn render_economic_calendar(frame: &mut Frame, app: &App, area: Rect) {
+    let events = app.todays_economic_events();
+    if events.is_empty() {
+        return;
+    }
+    let line = economic_calendar_line(&events, Utc::now());
+    frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+}
+
+/// Compte à rebours sous lequel une publication est mise en évidence
+const IMMINENT_MINUTES: i64 = 15;
+
+/// Construit le bandeau du calendrier économique
+///
+/// "📅 14:30 US CPI dans 2h 15min  │  20:00 US FOMC dans 7h 45min"
+/// (publication passée en gris avec ✓, imminente en jaune)
+fn economic_calendar_line(events: &[&EconomicEvent], now: DateTime<Utc>) -> Line<'static> {
+    let mut spans = vec![Span::styled("📅 ", Style::default().fg(Color::Cyan))];
+
+    for (i, event) in events.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled("  │  ", Style::default().fg(Color::DarkGray)));
+        }
+
+        let time = if event.all_day {
+            "Journée".to_string()
+        } else {
+            event.at.with_timezone(&chrono::Local).format("%H:%M").to_string()
+        };
+        match event.countdown(now) {
+            Some(remaining) => {
+                let imminent = remaining.num_minutes() < IMMINENT_MINUTES;
+                let color = if imminent { Color::Yellow } else { Color::Gray };
+                let countdown = if remaining.num_minutes() == 0 {
+                    "imminent".to_string()
+                } else {
+                    format!("dans {}", format_elapsed(remaining))
+                };
+                spans.push(Span::styled(format!("{} ", time), Style::default().fg(color)));
+                spans.push(Span::styled(
+                    event.label(),
+                    Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                ));
+                let mut style = Style::default().fg(color);
+                if imminent {
+                    style = style.add_modifier(Modifier::BOLD);
+                }
+                spans.push(Span::styled(format!(" {}", countdown), style));
+            }
+            None if event.all_day => {
+                spans.push(Span::styled(format!("{} ", time), Style::default().fg(Color::Gray)));
+                spans.push(Span::styled(event.label(), Style::default().fg(Color::White)));
+            }
+            None => {
+                spans.push(Span::styled(
+                    format!("{} {} ✓", time, event.label()),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+        }
+    }
+
+    Line::from(spans)
+}
+
 // ============================================================================
 // Main Content : Contenu principal
 // ============================================================================
@@ -177,7 +613,7 @@ fn render_header(frame: &mut Frame, area: Rect) {
 /// * String tronquée avec "…" si elle dépasse max_len, sinon texte original
 ///
 /// # Exemple
-/// ```
+/// ```text
 /// truncate_with_ellipsis("Microsoft Corporation", 20) // "Microsoft Corporat…"
 /// truncate_with_ellipsis("Apple Inc.", 20)            // "Apple Inc."
 /// ```
@@ -190,6 +626,206 @@ fn truncate_with_ellipsis(text: &str, max_len: usize) -> String {
     }
 }
 
+/// Largeur maximale et minimale de la colonne nom (en dessous : masquée)
+const NAME_WIDTH: usize = 20;
+const MIN_NAME_WIDTH: usize = 6;
+
+/// Largeur de la colonne prix (alignée à droite)
+const PRICE_WIDTH: usize = 12;
+
+/// Colonnes d'une ligne de la watchlist, avant mise en page
+struct RowColumns {
+    /// Pictogramme du type d'actif (ƒ pour une expression)
+    icon: char,
+    symbol: String,
+    name: String,
+    /// Prix, ou état du chargement ("Loading...")
+    price: String,
+    /// Variation avec sa flèche (vide si inconnue)
+    change: String,
+    /// Compléments par ordre d'affichage (bande des séances, jauge, volume,
+    /// P&L, résultats, tags, ⚠)
+    extras: Vec<String>,
+}
+
+/// Largeur affichée d'un texte (les emojis occupent deux colonnes)
+fn display_width(text: &str) -> usize {
+    Span::raw(text).width()
+}
+
+/// Assemble une ligne de la watchlist dans la largeur disponible
+///
+/// CONCEPT : Colonnes adaptatives
+/// - Quand le terminal rétrécit, les colonnes se réduisent dans cet ordre :
+///   1. le nom est raccourci (20 → 6 caractères) puis masqué
+///   2. la flèche de la variation est retirée
+///   3. le prix perd sa colonne alignée de 12 caractères
+///   4. la variation est masquée
+/// - Un complément n'est ajouté que s'il tient en entier : aucune ligne
+///   n'est coupée au milieu d'un mot par le bord du cadre
+fn fit_row(width: usize, row: &RowColumns) -> String {
+    let core = |name_width: usize, price_width: usize, change: &str| {
+        let name = if name_width > 0 {
+            format!(" {:<w$}", truncate_with_ellipsis(&row.name, name_width), w = name_width)
+        } else {
+            String::new()
+        };
+        let change = if change.is_empty() { String::new() } else { format!("  {}", change) };
+        format!(" {} {:<8}{} {:>w$}{}", row.icon, row.symbol, name, row.price, change, w = price_width)
+    };
+
+    let bare_change = row.change.trim_start_matches(['▲', '▼']).trim_start();
+    let without_name = display_width(&core(0, PRICE_WIDTH, &row.change));
+
+    let mut line = if without_name + 1 + MIN_NAME_WIDTH <= width {
+        core((width - without_name - 1).min(NAME_WIDTH), PRICE_WIDTH, &row.change)
+    } else {
+        [(PRICE_WIDTH, row.change.as_str()), (PRICE_WIDTH, bare_change), (0, bare_change), (0, "")]
+            .iter()
+            .map(|(price_width, change)| core(0, *price_width, change))
+            .find(|line| display_width(line) <= width)
+            .unwrap_or_else(|| core(0, 0, ""))
+    };
+
+    for extra in &row.extras {
+        if display_width(&line) + display_width(extra) <= width {
+            line.push_str(extra);
+        }
+    }
+    line
+}
+
+/// Largeur de la jauge de fourchette du jour (entre les bornes)
+const DAY_RANGE_WIDTH: usize = 10;
+
+/// Jauge de la position du prix dans la fourchette du jour : "├───●──────┤"
+///
+/// Fourchette nulle (une seule cotation) : curseur au milieu
+fn day_range_bar(low: f64, high: f64, price: f64) -> String {
+    let ratio = if high > low { ((price - low) / (high - low)).clamp(0.0, 1.0) } else { 0.5 };
+    let cursor = (ratio * (DAY_RANGE_WIDTH - 1) as f64).round() as usize;
+
+    let track: String = (0..DAY_RANGE_WIDTH).map(|i| if i == cursor { '●' } else { '─' }).collect();
+    format!("├{}┤", track)
+}
+
+/// Bloc d'une séance dans la bande des variations
+const HEAT_BLOCK: char = '█';
+
+/// Bande des variations des dernières séances : "   ██████" (un bloc par jour)
+///
+/// Texte seul : les couleurs sont posées par heat_strip_line() une fois la
+/// ligne mise en page
+fn heat_strip(changes: &[f64]) -> String {
+    if changes.is_empty() {
+        return String::new();
+    }
+    format!("   {}", HEAT_BLOCK.to_string().repeat(changes.len()))
+}
+
+/// Couleur d'un bloc : vert en hausse, rouge en baisse (foncé sous 1%)
+fn heat_color(change: f64) -> Color {
+    match change {
+        c if c >= 1.0 => Color::LightGreen,
+        c if c > 0.0 => Color::Green,
+        c if c <= -1.0 => Color::LightRed,
+        c if c < 0.0 => Color::Red,
+        _ => Color::DarkGray,
+    }
+}
+
+/// Colore la bande des variations dans une ligne mise en page
+///
+/// Bande absente (ne tenait pas dans la largeur) : ligne inchangée
+fn heat_strip_line(line: String, changes: &[f64]) -> Line<'static> {
+    let strip = heat_strip(changes);
+    let Some(start) = (!strip.is_empty()).then(|| line.find(&strip)).flatten() else {
+        return Line::from(line);
+    };
+
+    let blocks_start = start + 3;
+    let blocks_end = start + strip.len();
+    let mut spans = vec![Span::raw(line[..blocks_start].to_string())];
+    spans.extend(
+        changes
+            .iter()
+            .map(|change| Span::styled(HEAT_BLOCK.to_string(), Style::default().fg(heat_color(*change)))),
+    );
+    spans.push(Span::raw(line[blocks_end..].to_string()));
+    Line::from(spans)
+}
+
+/// Colore un segment d'une ligne mise en page (découpe le span qui le contient)
+///
+/// Segment absent (ne tenait pas dans la largeur) : ligne inchangée
+fn color_segment(line: Line<'static>, segment: &str, color: Color) -> Line<'static> {
+    if segment.is_empty() {
+        return line;
+    }
+    let mut spans = Vec::with_capacity(line.spans.len() + 2);
+    let mut found = false;
+    for span in line.spans {
+        match span.content.find(segment).filter(|_| !found) {
+            Some(start) => {
+                found = true;
+                let end = start + segment.len();
+                spans.push(Span::styled(span.content[..start].to_string(), span.style));
+                spans.push(Span::styled(span.content[start..end].to_string(), span.style.fg(color)));
+                spans.push(Span::styled(span.content[end..].to_string(), span.style));
+            }
+            None => spans.push(span),
+        }
+    }
+    Line::from(spans)
+}
+
+/// Watchlist vide : touche d'ajout bien visible et symboles populaires
+///
+/// ```text
+///                  Watchlist vide
+///
+///        Appuyez sur [a] pour ajouter un ticker
+///
+///   ou choisissez un symbole populaire (↑↓ puis Entrée)
+///              ▶ AAPL       Apple
+///                MSFT       Microsoft
+///
+///        [f] Paires de devises · [s] Découverte
+/// ```
+fn empty_state_lines(app: &App) -> Vec<Line<'static>> {
+    let key = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled("Watchlist vide", Style::default().fg(Color::Gray))),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Appuyez sur ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+            Span::styled("[a]", key),
+            Span::styled(" pour ajouter un ticker", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("ou choisissez un symbole populaire (↑↓ puis Entrée)", Style::default().fg(Color::Gray))),
+    ];
+
+    let selected = app.selected_suggestion().map(|(symbol, _)| symbol);
+    lines.extend(POPULAR_SYMBOLS.iter().map(|(symbol, label)| {
+        if Some(*symbol) == selected {
+            Line::from(Span::styled(format!("▶ {:<10} {:<12}", symbol, label), key))
+        } else {
+            Line::from(Span::styled(format!("  {:<10} {:<12}", symbol, label), Style::default().fg(Color::Gray)))
+        }
+    }));
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("[f]", key),
+        Span::styled(" Paires de devises · ", Style::default().fg(Color::Gray)),
+        Span::styled("[s]", key),
+        Span::styled(" Découverte", Style::default().fg(Color::Gray)),
+    ]));
+    lines
+}
+
 /// Dessine le contenu principal : la watchlist
 ///
 /// CONCEPT RATATUI : List widget
@@ -197,40 +833,48 @@ fn truncate_with_ellipsis(text: &str, max_len: usize) -> String {
 /// - Highlight : style spécial pour l'item sélectionné
 /// - ListItem : chaque ligne de la liste
 fn render_main_content(frame: &mut Frame, app: &App, area: Rect) {
-    // Block principal
+    // Block principal (avec le tag filtrant la liste)
+    let title = match &app.tag_filter {
+        Some(tag) => format!(" 📊 Watchlist · #{} ", tag),
+        None => " 📊 Watchlist ".to_string(),
+    };
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan))
-        .title(" 📊 Watchlist ");
+        .title(title);
 
-    // Si la watchlist est vide, affiche un message
+    // Si la watchlist est vide, propose de quoi démarrer
     if app.watchlist.is_empty() {
+        frame.render_widget(Paragraph::new(empty_state_lines(app)).block(block).alignment(Alignment::Center), area);
+        return;
+    }
+
+    // Filtre actif sans aucun ticker correspondant
+    let visible = app.visible_rows();
+    if visible.is_empty() {
         let text = vec![
             Line::from(""),
             Line::from(Span::styled(
-                "Watchlist vide",
+                "Aucun ticker avec ce tag — [C] filtre suivant",
                 Style::default().fg(Color::Gray),
             )),
         ];
-
-        let paragraph = Paragraph::new(text)
-            .block(block)
-            .alignment(Alignment::Center);
-
-        frame.render_widget(paragraph, area);
+        frame.render_widget(Paragraph::new(text).block(block).alignment(Alignment::Center), area);
         return;
     }
 
+    let today = chrono::Local::now().date_naive();
+    let width = area.width.saturating_sub(2) as usize;
+
     // Crée les items de la liste
     // CONCEPT RUST : Iterator chaining
     // - .iter() : itère sur les WatchlistItem
     // - .enumerate() : ajoute l'index
     // - .map() : transforme chaque item en ListItem
     // - .collect() : collecte dans un Vec<ListItem>
-    let items: Vec<ListItem> = app
-        .watchlist
-        .iter()
-        .enumerate()
+    let mut items: Vec<ListItem> = visible
+        .into_iter()
+        .map(|index| (index, &app.watchlist[index]))
         .map(|(index, item)| {
             // Détermine le style selon la variation
             let style = if item.has_data() {
@@ -239,38 +883,109 @@ fn render_main_content(frame: &mut Frame, app: &App, area: Rect) {
                 } else {
                     Style::default().fg(Color::Red)
                 }
+            } else if item.load_error.is_some() {
+                Style::default().fg(Color::Red)
             } else {
                 Style::default().fg(Color::Gray)
             };
 
-            // Formate la ligne pour cet item
-            let line = if item.has_data() {
+            let heat_changes = match app.config.heat_strip_days {
+                0 => Vec::new(),
+                days => item.daily_changes(days),
+            };
+            let relative_strength = app.relative_strength(&item.symbol);
+
+            // Colonnes de cet item, mises en page selon la largeur du cadre
+            let columns = if item.has_data() {
                 // Données chargées : affiche prix et variation
+                let ticker_type = item.ticker_type();
                 let price_str = item
                     .current_price()
-                    .map(|p| format!("${:.2}", p))
+                    .map(|p| format_price_in(ticker_type, &item.currency(), p))
                     .unwrap_or_else(|| "N/A".to_string());
 
                 let change_str = item
                     .change_percent()
-                    .map(|c| {
-                        let arrow = if c >= 0.0 { "▲" } else { "▼" };
-                        format!("{} {:+.2}%", arrow, c)
-                    })
-                    .unwrap_or_else(|| String::new());
-
-                // Tronque le nom à 20 caractères pour éviter le débordement
-                let truncated_name = truncate_with_ellipsis(&item.name, 20);
-                format!(
-                    " {:<8} {:<20} {:>12}  {}",
-                    item.symbol, truncated_name, price_str, change_str
-                )
+                    .map(|c| format_change(ticker_type, &item.symbol, item.change_absolute(), c))
+                    .unwrap_or_default();
+
+                // Position du prix dans la fourchette du jour (option "day_range_bar")
+                let range_str = item
+                    .day_range()
+                    .zip(item.current_price())
+                    .filter(|_| app.config.day_range_bar)
+                    .map(|((low, high), price)| format!("   {}", day_range_bar(low, high, price)))
+                    .unwrap_or_default();
+
+                // Variations des dernières séances (option "heat_strip_days")
+                let strip_str = heat_strip(&heat_changes);
+
+                // Force relative vs l'indice (option "relative_strength_days")
+                let rs_str = relative_strength
+                    .map(|rs| format!("   RS {:+.1}", rs))
+                    .unwrap_or_default();
+
+                // Volume de la séance comparé à la moyenne des précédentes
+                let volume_str = format_volume_summary(item.session_volume(), item.average_volume())
+                    .map(|summary| format!("   {}", summary))
+                    .unwrap_or_default();
+
+                // P&L latent de la position (si des transactions existent)
+                let pnl_str = app
+                    .position_pnl(&item.symbol)
+                    .filter(|pnl| pnl.quantity > 0.0)
+                    .and_then(|pnl| pnl.unrealized)
+                    .map(|unrealized| format!("   P&L {}", format_signed_money(unrealized)))
+                    .unwrap_or_default();
+
+                // Compte à rebours avant la publication des résultats
+                let earnings_str = item
+                    .earnings_badge(today)
+                    .map(|badge| format!("   📅 {}", badge))
+                    .unwrap_or_default();
+
+                // Tags de l'utilisateur ("#dividend #long")
+                let tags_str = app
+                    .tags
+                    .of(&item.symbol)
+                    .iter()
+                    .map(|tag| format!("#{}", tag))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let tags_str = if tags_str.is_empty() { tags_str } else { format!("   {}", tags_str) };
+
+                // Dernier rechargement échoué : les données affichées sont anciennes
+                let retry_str = if item.load_error.is_some() { "   ⚠ [r]".to_string() } else { String::new() };
+
+                RowColumns {
+                    icon: item.ticker_type().icon(),
+                    symbol: item.symbol.clone(),
+                    name: item.name.clone(),
+                    price: price_str,
+                    change: change_str,
+                    extras: vec![strip_str, range_str, rs_str, volume_str, pnl_str, earnings_str, tags_str, retry_str],
+                }
             } else {
-                // Pas de données : affiche "Loading..."
-                // Tronque le nom à 20 caractères pour cohérence
-                let truncated_name = truncate_with_ellipsis(&item.name, 20);
-                format!(" {:<8} {:<20} {:>12}", item.symbol, truncated_name, "Loading...")
+                // Pas de données : "Loading..." ou l'échec avec la touche pour réessayer
+                let status = if item.load_error.is_some() {
+                    "⚠ Échec — [r] Réessayer"
+                } else {
+                    "Loading..."
+                };
+                RowColumns {
+                    icon: item.ticker_type().icon(),
+                    symbol: item.symbol.clone(),
+                    name: item.name.clone(),
+                    price: status.to_string(),
+                    change: String::new(),
+                    extras: Vec::new(),
+                }
             };
+            let mut line = heat_strip_line(fit_row(width, &columns), &heat_changes);
+            if let Some(rs) = relative_strength.filter(|_| item.has_data()) {
+                let color = if rs >= 0.0 { Color::LightGreen } else { Color::LightRed };
+                line = color_segment(line, &format!("RS {:+.1}", rs), color);
+            }
 
             // Crée un ListItem avec style
             let mut list_item = ListItem::new(line).style(style);
@@ -288,70 +1003,72 @@ fn render_main_content(frame: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
+    // Lignes synthétiques des expressions de surveillance (non sélectionnables)
+    items.extend(
+        app.config
+            .watch_expressions
+            .iter()
+            .map(|watch| expression_row(watch, watch.evaluate(&app.watchlist), width)),
+    );
+
     // Crée le widget List
     let list = List::new(items).block(block);
 
     frame.render_widget(list, area);
 }
 
+/// Ligne synthétique d'une expression : "ƒ Paire  AAPL - 0.5 * MSFT  171.30  ▲ +1.20%"
+///
+/// - Valeur dans la devise de conversion ("in EUR"), nombre nu sinon
+/// - Expression invalide ou cours manquant : le message à la place du prix
+fn expression_row(watch: &WatchExpression, value: anyhow::Result<WatchValue>, width: usize) -> ListItem<'static> {
+    let (price, change, color) = match value {
+        Ok(value) => {
+            let price = match &value.currency {
+                Some(currency) => format_price_in(TickerType::Stock, currency, value.value),
+                None => format_price_in(TickerType::Index, &Currency::default(), value.value),
+            };
+            let change = value
+                .change_percent
+                .map(|change| format_change(TickerType::Stock, watch.label(), None, change))
+                .unwrap_or_default();
+            let color = match value.change_percent {
+                Some(change) if change < 0.0 => Color::Red,
+                Some(_) => Color::Green,
+                None => Color::Gray,
+            };
+            (price, change, color)
+        }
+        Err(e) => (format!("⚠ {}", e), String::new(), Color::DarkGray),
+    };
+
+    let columns = RowColumns {
+        icon: 'ƒ',
+        symbol: watch.label().to_string(),
+        name: watch.expression.clone(),
+        price,
+        change,
+        extras: Vec::new(),
+    };
+    ListItem::new(fit_row(width, &columns)).style(Style::default().fg(color).add_modifier(Modifier::ITALIC))
+}
+
 // ============================================================================
 // Footer : Instructions
 // ============================================================================
 
 /// Dessine le footer avec les raccourcis clavier
 fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
-    // CONCEPT : Confirmation de quit two-step
-    // - Si app.is_awaiting_quit_confirmation(), affiche message d'avertissement
-    // - Sinon, affiche les raccourcis normaux
+    // Les confirmations (quitter, supprimer) sont dans un popup
+    // (confirm_popup.rs) : le footer garde les raccourcis
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan));
 
-    let shortcuts = if app.is_awaiting_delete_confirmation() {
-        // Message de confirmation de suppression
-        // CONCEPT : Style avec BLINK pour attirer l'attention
-        let ticker_name = app.watchlist.get(app.selected_index)
-            .map(|item| item.symbol.as_str())
-            .unwrap_or("?");
-
-        Line::from(vec![
-            Span::styled(
-                "⚠  Appuyez sur ",
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                "[d]",
-                Style::default()
-                    .fg(Color::Red)
-                    .add_modifier(Modifier::BOLD)
-                    .add_modifier(Modifier::SLOW_BLINK),
-            ),
-            Span::styled(
-                format!(" à nouveau pour supprimer {} ou autre touche pour annuler ⚠", ticker_name),
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            ),
-        ])
-    } else if app.is_awaiting_quit_confirmation() {
-        // Message de confirmation de quit
-        // CONCEPT : Style avec BLINK pour attirer l'attention
-        Line::from(vec![
-            Span::styled(
-                "⚠  Appuyez sur ",
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                "[q]",
-                Style::default()
-                    .fg(Color::Red)
-                    .add_modifier(Modifier::BOLD)
-                    .add_modifier(Modifier::SLOW_BLINK),
-            ),
-            Span::styled(
-                " à nouveau pour quitter, ou n'importe quelle autre touche pour annuler ⚠",
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            ),
-        ])
+    let shortcuts = if let Some(toast) = app.active_toast() {
+        // Notification temporaire (export terminé, erreur, ...)
+        toast_line(toast)
     } else {
         // Shortcuts normaux avec différentes couleurs
         // CONCEPT RATATUI : Spans multiples dans une Line
@@ -363,8 +1080,24 @@ fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
             Span::raw(" Navigate  "),
             Span::styled("[Enter]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
             Span::raw(" Chart  "),
+            Span::styled("[I]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Détail  "),
             Span::styled("[a]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
             Span::raw(" Add  "),
+            Span::styled("[f]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" Forex  "),
+            Span::styled("[s]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" Découverte  "),
+            Span::styled("[p]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Position  "),
+            Span::styled("[t]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Transaction  "),
+            Span::styled("[n]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Alerte  "),
+            Span::styled("[w]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Répartition  "),
+            Span::styled("[v]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Rendements  "),
             Span::styled("[d]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
             Span::raw(" Delete"),
         ])
@@ -377,6 +1110,22 @@ fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
+/// Construit la ligne affichant une notification (toast)
+///
+/// Partagée entre le footer du dashboard et le header du graphique
+pub fn toast_line(toast: &Toast) -> Line<'static> {
+    let (icon, color) = if toast.is_error {
+        ("✗ ", Color::Red)
+    } else {
+        ("✓ ", Color::Green)
+    };
+
+    Line::from(vec![
+        Span::styled(icon, Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        Span::styled(toast.message.clone(), Style::default().fg(color)),
+    ])
+}
+
 // ============================================================================
 // Input Mode : Saisie de ticker
 // ============================================================================
@@ -387,18 +1136,41 @@ fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
 /// - Affiche la watchlist en arrière-plan
 /// - Affiche une ligne d'input en bas pour saisir le ticker
 /// - ESC annule, Enter valide
-fn render_input_mode(frame: &mut Frame, app: &App) {
-    let size = frame.size();
-    let chunks = create_layout(size);
+///
+/// Une annotation se saisit devant le graphique annoté plutôt que la watchlist
+fn render_input_mode(frame: &mut Frame, app: &App, area: Rect) {
+    if matches!(app.input_purpose, InputPurpose::AddAnnotation { .. }) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3)])
+            .split(area);
+        candlestick_text::render_candlestick_chart(frame, app, chunks[0]);
+        render_input_footer(frame, app, chunks[1]);
+        return;
+    }
+
+    let summary = app.portfolio.summary(&app.watchlist);
+    let pnl = journal_pnl(app);
+    let banner_height = u16::from(app.current_notification().is_some());
+    let calendar_height = u16::from(!app.todays_economic_events().is_empty());
+    let chunks = create_layout(area, banner_height, calendar_height, summary_height(summary.as_ref(), pnl.as_ref()));
 
     // Dessine le header
-    render_header(frame, chunks[0]);
+    render_header(frame, app, chunks[0]);
+
+    render_notification_banner(frame, app, chunks[1]);
+
+    render_economic_calendar(frame, app, chunks[2]);
+
+    if let Some(summary) = &summary {
+        render_portfolio_summary(frame, summary, pnl.as_ref(), chunks[3]);
+    }
 
     // Dessine la watchlist (en arrière-plan)
-    render_main_content(frame, app, chunks[1]);
+    render_main_content(frame, app, chunks[4]);
 
     // Footer : affiche l'input line au lieu des shortcuts
-    render_input_footer(frame, app, chunks[2]);
+    render_input_footer(frame, app, chunks[5]);
 }
 
 /// Dessine le footer en mode input avec la ligne de saisie
@@ -475,3 +1247,279 @@ fn render_input_footer(frame: &mut Frame, app: &App, area: Rect) {
 // - Scrolling et navigation
 //
 // ============================================================================
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::WatchlistItem;
+    use crate::ui::snapshot::{assert_snapshot, buffer_text, render_to_buffer, sample_item};
+
+    fn sample_app() -> App {
+        App::with_watchlist(vec![
+            sample_item("AAPL", "Apple Inc.", 170.0, 30),
+            sample_item("BTC-USD", "Bitcoin USD", 64_000.0, 30),
+            WatchlistItem::new("TSLA".to_string(), "Tesla".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_dashboard_snapshot() {
+        let app = sample_app();
+        let buffer = render_to_buffer(100, 20, |frame| render(frame, &app));
+        assert_snapshot("dashboard", &buffer);
+    }
+
+    #[test]
+    fn test_input_mode_snapshot() {
+        let mut app = sample_app();
+        app.start_input("Add ticker: ".to_string());
+        app.input_buffer.push_str("MSFT");
+        let buffer = render_to_buffer(100, 20, |frame| render(frame, &app));
+        assert_snapshot("dashboard_input", &buffer);
+    }
+
+    #[test]
+    fn test_empty_watchlist_suggests_symbols() {
+        let mut app = App::new();
+        app.navigate_down();
+        let text = buffer_text(&render_to_buffer(100, 30, |frame| render(frame, &app)));
+        assert!(text.contains("Appuyez sur [a] pour ajouter un ticker"), "{}", text);
+        assert!(text.contains("▶ MSFT"), "{}", text);
+        assert!(text.contains("  AAPL"), "{}", text);
+        assert!(text.contains("[f] Paires de devises"), "{}", text);
+    }
+
+    #[test]
+    fn test_day_range_bar() {
+        assert_eq!(day_range_bar(100.0, 110.0, 100.0), "├●─────────┤");
+        assert_eq!(day_range_bar(100.0, 110.0, 110.0), "├─────────●┤");
+        assert_eq!(day_range_bar(100.0, 110.0, 105.0), "├─────●────┤");
+        assert_eq!(day_range_bar(100.0, 100.0, 100.0), "├─────●────┤");
+
+        // Colonne absente par défaut, affichée avec l'option
+        let mut app = sample_app();
+        let row = |app: &App| {
+            let text = buffer_text(&render_to_buffer(100, 20, |frame| render(frame, app)));
+            text.lines().find(|line| line.contains("AAPL")).unwrap().to_string()
+        };
+        assert!(!row(&app).contains('●'));
+        app.config.day_range_bar = true;
+        assert!(row(&app).contains('●'));
+    }
+
+    #[test]
+    fn test_heat_strip() {
+        // Séances 25 à 29 de sample_item : baisse quand i % 3 == 0
+        let mut app = sample_app();
+        app.config.heat_strip_days = 5;
+        assert_eq!(app.watchlist[0].daily_changes(5).len(), 5);
+
+        let buffer = render_to_buffer(100, 20, |frame| render(frame, &app));
+        let text = buffer_text(&buffer);
+        let (y, row) = text.lines().enumerate().find(|(_, line)| line.contains("AAPL")).unwrap();
+        assert!(row.contains(&"█".repeat(5)), "{}", row);
+
+        let x = row.chars().position(|c| c == HEAT_BLOCK).unwrap();
+        let up: Vec<bool> = (0..5)
+            .map(|i| matches!(buffer.get((x + i) as u16, y as u16).fg, Color::Green | Color::LightGreen))
+            .collect();
+        assert_eq!(up, [25, 26, 27, 28, 29].map(|i| i % 3 != 0));
+        assert_eq!(heat_color(-0.4), Color::Red);
+        assert_eq!(heat_color(2.5), Color::LightGreen);
+
+        // Sans données (TSLA) : pas de bande
+        let tsla = text.lines().find(|line| line.contains("TSLA")).unwrap();
+        assert!(!tsla.contains(HEAT_BLOCK), "{}", tsla);
+    }
+
+    #[test]
+    fn test_relative_strength_column() {
+        // Indice de référence pris dans la watchlist : BTC-USD
+        let mut app = sample_app();
+        app.config.benchmark = "BTC-USD".to_string();
+        let rows = |app: &App| {
+            let buffer = render_to_buffer(100, 20, |frame| render(frame, app));
+            (buffer_text(&buffer), buffer)
+        };
+        assert!(!rows(&app).0.contains("RS "));
+
+        app.config.relative_strength_days = 10;
+        let rs = app.relative_strength("AAPL").unwrap();
+        let (text, buffer) = rows(&app);
+        let (y, row) = text.lines().enumerate().find(|(_, line)| line.contains("AAPL")).unwrap();
+        let label = format!("RS {:+.1}", rs);
+        assert!(row.contains(&label), "{}", row);
+        let x = row[..row.find(&label).unwrap()].chars().count();
+        let expected = if rs >= 0.0 { Color::LightGreen } else { Color::LightRed };
+        assert_eq!(buffer.get(x as u16 + 3, y as u16).fg, expected);
+
+        // Pas de force relative pour l'indice lui-même ni sans données
+        for symbol in ["BTC-USD", "TSLA"] {
+            let line = text.lines().find(|line| line.contains(symbol)).unwrap();
+            assert!(!line.contains("RS "), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_row_columns_shrink_with_width() {
+        let row = RowColumns {
+            icon: '▪',
+            symbol: "AAPL".to_string(),
+            name: "Apple Inc.".to_string(),
+            price: "$164.64".to_string(),
+            change: "▲ +1.00%".to_string(),
+            extras: vec!["   Vol 1M (moy. 1M)".to_string(), "   ⚠ [r]".to_string()],
+        };
+
+        // Large : tout tient, nom sur 20 colonnes
+        assert_eq!(
+            fit_row(102, &row),
+            " ▪ AAPL     Apple Inc.                $164.64  ▲ +1.00%   Vol 1M (moy. 1M)   ⚠ [r]"
+        );
+        // Le volume ne tient plus : ignoré, le ⚠ (plus court) reste
+        assert_eq!(fit_row(64, &row), " ▪ AAPL     Apple Inc.                $164.64  ▲ +1.00%   ⚠ [r]");
+        // Nom raccourci, puis masqué, puis flèche retirée
+        assert_eq!(fit_row(42, &row), " ▪ AAPL     Apple …      $164.64  ▲ +1.00%");
+        assert_eq!(fit_row(36, &row), " ▪ AAPL          $164.64  ▲ +1.00%");
+        assert_eq!(fit_row(32, &row), " ▪ AAPL          $164.64  +1.00%");
+        assert_eq!(fit_row(27, &row), " ▪ AAPL     $164.64  +1.00%");
+        assert_eq!(fit_row(22, &row), " ▪ AAPL     $164.64");
+
+        // Aucune ligne ne dépasse du cadre
+        let app = sample_app();
+        for width in [40, 60, 79] {
+            let text = buffer_text(&render_to_buffer(width, 20, |frame| render(frame, &app)));
+            let row = text.lines().find(|line| line.contains("AAPL")).unwrap();
+            assert!(row.contains("$164.64"), "{}", row);
+            assert!(row.trim_end().ends_with('│'), "{}", row);
+        }
+    }
+
+    #[test]
+    fn test_failed_row_offers_retry() {
+        let mut app = sample_app();
+        app.watchlist[2].load_error = Some("HTTP 500".to_string());
+        let buffer = render_to_buffer(100, 20, |frame| render(frame, &app));
+        let text = buffer_text(&buffer);
+        let row = text.lines().find(|line| line.contains("TSLA")).unwrap();
+        assert!(row.contains("Échec — [r] Réessayer"));
+    }
+
+    #[test]
+    fn test_tag_filter_hides_rows() {
+        let mut app = sample_app();
+        app.tags.set("BTC-USD", vec!["crypto".to_string(), "long".to_string()]);
+        app.tags.set("TSLA", vec!["long".to_string()]);
+        app.tag_filter = Some("crypto".to_string());
+
+        let text = buffer_text(&render_to_buffer(140, 20, |frame| render(frame, &app)));
+        assert!(text.contains("Watchlist · #crypto"), "{}", text);
+        assert!(!text.contains("AAPL") && !text.contains("TSLA"), "{}", text);
+        let row = text.lines().find(|line| line.contains("BTC-USD")).unwrap();
+        assert!(row.contains("#crypto #long"), "{}", row);
+
+        app.tag_filter = Some("dividend".to_string());
+        let text = buffer_text(&render_to_buffer(140, 20, |frame| render(frame, &app)));
+        assert!(text.contains("Aucun ticker avec ce tag"), "{}", text);
+    }
+
+    #[test]
+    fn test_header_shows_fear_greed_with_crypto() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 30)]);
+        app.fear_greed = Some(FearGreed { value: 72, published_at: chrono::Utc::now() });
+
+        let header = |app: &App| {
+            let text = buffer_text(&render_to_buffer(100, 30, |frame| render(frame, app)));
+            text.lines().nth(2).unwrap().to_string()
+        };
+        assert!(!header(&app).contains("F&G"));
+
+        app.watchlist.push(sample_item("BTC-USD", "Bitcoin", 60000.0, 30));
+        let header = header(&app);
+        assert!(header.contains("F&G ███████░░░ 72 Avidité"), "{}", header);
+    }
+
+    #[test]
+    fn test_economic_calendar_strip() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 30)]);
+        let text = buffer_text(&render_to_buffer(120, 30, |frame| render(frame, &app)));
+        assert!(!text.contains("📅"), "{}", text);
+
+        let now = Utc::now();
+        let event = |title: &str, at: DateTime<Utc>| EconomicEvent {
+            title: title.to_string(),
+            country: Some("US".to_string()),
+            at,
+            all_day: false,
+        };
+        let cpi = event("CPI", now - chrono::Duration::minutes(30));
+        let fomc = event("FOMC", now + chrono::Duration::minutes(135) + chrono::Duration::seconds(30));
+        let nfp = event("NFP", now + chrono::Duration::minutes(10) + chrono::Duration::seconds(30));
+        let line: String = economic_calendar_line(&[&cpi, &nfp, &fomc], now)
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert!(line.contains("US CPI ✓"), "{}", line);
+        assert!(line.contains("US NFP dans 10min"), "{}", line);
+        assert!(line.contains("US FOMC dans 2h 15min"), "{}", line);
+
+        // Publication du jour : bandeau sous le header (sauf si elle tombe demain, juste avant minuit)
+        app.economic_events = vec![event("FOMC", now + chrono::Duration::seconds(30))];
+        if app.todays_economic_events().len() == 1 {
+            let text = buffer_text(&render_to_buffer(120, 30, |frame| render(frame, &app)));
+            assert!(text.lines().nth(3).unwrap().contains("US FOMC imminent"), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_watch_expressions_render_as_synthetic_rows() {
+        let mut app = App::with_watchlist(vec![
+            sample_item("AAPL", "Apple Inc.", 170.0, 30),
+            sample_item("MSFT", "Microsoft", 400.0, 30),
+        ]);
+        app.config.watch_expressions = vec![
+            WatchExpression { label: Some("Ratio".to_string()), expression: "AAPL / MSFT".to_string() },
+            WatchExpression { label: None, expression: "AAPL in EUR".to_string() },
+        ];
+
+        let text = buffer_text(&render_to_buffer(120, 30, |frame| render(frame, &app)));
+        let ratio = text.lines().find(|line| line.contains("ƒ Ratio")).unwrap();
+        assert!(ratio.contains("AAPL / MSFT") && ratio.contains('%'), "{}", ratio);
+        assert!(text.contains("⚠ Taux EUR/USD inconnu"), "{}", text);
+    }
+
+    #[test]
+    fn test_header_shows_provider_health() {
+        use crate::api::health::Provider;
+
+        let mut app = sample_app();
+        app.provider_health = vec![
+            ProviderStatus { provider: Provider::Yahoo, state: HealthState::Ok, last_error: None },
+            ProviderStatus {
+                provider: Provider::Binance,
+                state: HealthState::RateLimited,
+                last_error: Some((chrono::Utc::now(), "HTTP 429".to_string())),
+            },
+        ];
+
+        let text = buffer_text(&render_to_buffer(100, 20, |frame| render(frame, &app)));
+        let header_bottom = text.lines().nth(2).unwrap();
+        assert!(header_bottom.contains("Yahoo ● OK │ Binance ● limité"), "{}", header_bottom);
+    }
+
+    #[test]
+    fn test_render_in_sub_area() {
+        // Dessiner dans une zone réduite ne déborde pas sur le reste du terminal
+        let app = sample_app();
+        let buffer = render_to_buffer(100, 20, |frame| render_in(frame, &app, Rect::new(0, 0, 60, 20)));
+        for y in 0..20 {
+            assert_eq!(buffer.get(80, y).symbol(), " ");
+        }
+    }
+}
+
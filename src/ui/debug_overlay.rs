@@ -0,0 +1,142 @@
+// ============================================================================
+// Overlay de debug - Métriques des téléchargements (F12)
+// ============================================================================
+// Popup centré par-dessus n'importe quel écran, une ligne par ticker :
+//
+//   ┌────────────────── 🔧 Téléchargements Yahoo ──────────────────┐
+//   │Symbole    Req. Échecs  Dernière  Moyenne   Taille Retry  HTTP│
+//   │AAPL         12      0    184 ms   201 ms  42.1 Ko     0   200│
+//   │TSLA          3      1     2.3 s    1.1 s      0 o     2   429│
+//   └──────────────────────────────────────────────────────────────┘
+//
+// - Données : copie de api::metrics, mise à jour à chaque tour de boucle
+//   tant que l'overlay est affiché
+// - Statut en rouge hors 2xx ("—" : pas de réponse, erreur réseau)
+// ============================================================================
+
+use std::time::Duration;
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::api::metrics::TickerMetrics;
+use crate::app::App;
+use crate::ui::forex_menu::centered_rect;
+
+/// Largeur du popup (bordures incluses)
+const OVERLAY_WIDTH: u16 = 64;
+
+/// Dessine l'overlay des métriques centré dans `area`
+pub fn render_debug_overlay(frame: &mut Frame, app: &App, area: Rect) {
+    let header = Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD);
+    let mut lines = vec![Line::from(Span::styled(
+        format!("{:<9} {:>5} {:>6} {:>9} {:>8} {:>8} {:>5} {:>5}", "Symbole", "Req.", "Échecs", "Dernière", "Moyenne", "Taille", "Retry", "HTTP"),
+        header,
+    ))];
+
+    if app.fetch_metrics.is_empty() {
+        lines.push(Line::from(Span::styled("Aucun téléchargement pour l'instant", Style::default().fg(Color::DarkGray))));
+    }
+    lines.extend(app.fetch_metrics.iter().map(metrics_line));
+
+    lines.push(Line::from(vec![
+        Span::styled("[F12]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+        Span::raw(" Fermer"),
+    ]));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta))
+        .title(" 🔧 Téléchargements Yahoo ")
+        .title_alignment(Alignment::Center);
+
+    // Une ligne par ticker + en-tête, aide et bordures
+    let popup = centered_rect(OVERLAY_WIDTH, lines.len() as u16 + 2, area);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+/// Ligne d'un ticker
+fn metrics_line(metrics: &TickerMetrics) -> Line<'static> {
+    let status = match metrics.last.status {
+        Some(status) => status.to_string(),
+        None => "—".to_string(),
+    };
+    let status_color = if metrics.last.is_success() { Color::Green } else { Color::LightRed };
+    let failures_color = if metrics.failures > 0 { Color::LightRed } else { Color::White };
+
+    Line::from(vec![
+        Span::styled(format!("{:<9} {:>5} ", metrics.symbol, metrics.fetches), Style::default().fg(Color::White)),
+        Span::styled(format!("{:>6} ", metrics.failures), Style::default().fg(failures_color)),
+        Span::styled(
+            format!(
+                "{:>9} {:>8} {:>8} {:>5} ",
+                format_duration(metrics.last.duration),
+                format_duration(metrics.average_duration()),
+                format_bytes(metrics.last.bytes),
+                metrics.last.retries
+            ),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(format!("{:>5}", status), Style::default().fg(status_color)),
+    ])
+}
+
+/// Durée courte : "184 ms", "2.3 s"
+fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{} ms", duration.as_millis())
+    } else {
+        format!("{:.1} s", duration.as_secs_f64())
+    }
+}
+
+/// Taille courte : "512 o", "42.1 Ko", "1.2 Mo"
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{} o", bytes),
+        1024..=1_048_575 => format!("{:.1} Ko", bytes as f64 / 1024.0),
+        _ => format!("{:.1} Mo", bytes as f64 / 1_048_576.0),
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metrics::{FetchSample, MetricsStore};
+    use crate::ui::snapshot::{buffer_text, render_to_buffer};
+
+    #[test]
+    fn test_debug_overlay_lists_tickers() {
+        let mut store = MetricsStore::default();
+        let sample = |millis, bytes, retries, status| FetchSample {
+            duration: Duration::from_millis(millis),
+            bytes,
+            retries,
+            status,
+        };
+        store.record("AAPL", sample(184, 43_110, 0, Some(200)), chrono::Utc::now());
+        store.record("TSLA", sample(2300, 0, 2, Some(429)), chrono::Utc::now());
+
+        let mut app = App::default();
+        let render = |app: &App| buffer_text(&render_to_buffer(80, 12, |frame| render_debug_overlay(frame, app, frame.size())));
+        assert!(render(&app).contains("Aucun téléchargement"));
+
+        app.fetch_metrics = store.tickers();
+        let text = render(&app);
+        assert!(text.contains("Téléchargements Yahoo"), "{}", text);
+        assert!(text.contains("184 ms"), "{}", text);
+        assert!(text.contains("42.1 Ko"), "{}", text);
+        assert!(text.contains("2.3 s"), "{}", text);
+        assert!(text.lines().any(|line| line.contains("TSLA") && line.trim_end().ends_with("429│")), "{}", text);
+    }
+}
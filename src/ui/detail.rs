@@ -0,0 +1,523 @@
+// ============================================================================
+// Detail - Fiche détaillée d'un ticker
+// ============================================================================
+// Résumé d'une page du ticker sélectionné (touche [I] sur le dashboard) :
+//
+//   ┌ 🔎 AAPL · Apple Inc. ─────────────────────────────────────┐
+//   │ Prix: $271.49  ▲ +2.11%   Actions · USD                   │
+//   ├───────────────────────────────────────────────────────────┤
+//   │ Séance        $266.10 – $272.80                           │
+//   │ 52 semaines   $164.08 – $280.02  ──────────●───  84%      │
+//   │ Volume        Vol 48.2M (moy. 52.1M)                      │
+//   │ Résultats     30/10/2026 (ER in 16d)                      │
+//   │ Analystes     Achat · 12 achat fort, 20 achat, 8 conserver│
+//   │ Objectif      $300.00 ($220.00 – $350.00) · +10.5% vs cours │
+//   │ Bêta          1.24 · corrélation 0.81 vs SPY (252 séances)│
+//   │ Position      10 titres · $2714.90 · latent +$320.00      │
+//   │ Tags          dividend, long                              │
+//   │ Note          Thèse : services > 25% du CA                │
+//   ├───────────────────────────────────────────────────────────┤
+//   │ (chandeliers hebdomadaires sur un an)                     │
+//
+// La fourchette 52 semaines et le graphique viennent de l'historique 1w
+// (chargé à l'ouverture de la fiche s'il manque), sinon des données du
+// graphique principal ; le bêta, des historiques 1d du ticker et de son
+// indice de référence (voir models/beta.rs) ; les lignes des analystes
+// (actions seulement), de quoteSummary à l'ouverture de la fiche ; pour
+// une crypto, valorisation, volume 24h, offre en circulation et dominance
+// viennent de CoinGecko (rechargées toutes les 5 minutes, en cache)
+//
+// Actions : à droite du graphique, les comptes publiés (voir ui/financials.rs,
+// [f] annuels / trimestriels) ou, avec [h], les initiés et institutionnels
+// (voir ui/holders.rs)
+// ============================================================================
+
+use chrono::Duration;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::models::analyst::AnalystRatings;
+use crate::models::crypto_stats::CryptoStats;
+use crate::models::{Interval, OHLCData, TickerType, WatchlistItem};
+use crate::ui::candlestick_text::CandlestickRenderer;
+use crate::ui::dashboard::toast_line;
+use crate::ui::financials::{render_financials, FINANCIALS_PANEL_WIDTH};
+use crate::ui::holders::{render_holders, HOLDERS_PANEL_WIDTH};
+use crate::ui::format::{
+    format_change, format_money, format_price_in, format_signed_money, format_volume, format_volume_summary, price_decimals,
+};
+
+/// Largeur de la colonne des libellés
+const LABEL_WIDTH: usize = 14;
+
+/// Largeur de la jauge de la fourchette 52 semaines
+const RANGE_BAR_WIDTH: usize = 16;
+
+/// Dessine la fiche détaillée (header, statistiques, graphique, footer)
+pub fn render_detail(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(item) = app.selected_item() else {
+        frame.render_widget(Paragraph::new("Aucun ticker sélectionné"), area);
+        return;
+    };
+
+    let stats = stat_lines(app, item);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),                       // Cours
+            Constraint::Length(stats.len() as u16 + 2), // Statistiques
+            Constraint::Min(0),                          // Graphique
+            Constraint::Length(3),                       // Footer
+        ])
+        .split(area)
+        .to_vec();
+
+    render_quote(frame, item, chunks[0]);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    frame.render_widget(Paragraph::new(stats).block(block), chunks[1]);
+    if item.ticker_type() == TickerType::Stock {
+        // Comptes à droite du graphique, remplacés par les détenteurs avec [h]
+        let width = if app.holders_visible { HOLDERS_PANEL_WIDTH } else { FINANCIALS_PANEL_WIDTH };
+        let panels = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(width)])
+            .split(chunks[2]);
+        render_small_chart(frame, item, panels[0]);
+        if app.holders_visible {
+            render_holders(frame, item, panels[1]);
+        } else {
+            render_financials(frame, item, app.statement_period, panels[1]);
+        }
+    } else {
+        render_small_chart(frame, item, chunks[2]);
+    }
+    render_footer(frame, app, chunks[3]);
+}
+
+/// Cours et variation du jour, avec le type d'actif et la devise
+fn render_quote(frame: &mut Frame, item: &WatchlistItem, area: Rect) {
+    let ticker_type = item.ticker_type();
+    let currency = item.currency();
+
+    let mut spans = vec![Span::raw("Prix: ")];
+    match (item.current_price(), item.change_percent()) {
+        (Some(price), Some(change)) => {
+            let color = if change >= 0.0 { Color::Green } else { Color::Red };
+            spans.push(Span::styled(
+                format_price_in(ticker_type, &currency, price),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                format_change(ticker_type, &item.symbol, item.change_absolute(), change),
+                Style::default().fg(color),
+            ));
+        }
+        _ => spans.push(Span::styled("Chargement...", Style::default().fg(Color::DarkGray))),
+    }
+    spans.push(Span::styled(
+        format!("   {} · {}", ticker_type.label(), currency.code()),
+        Style::default().fg(Color::Gray),
+    ));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(" 🔎 {} · {} ", item.symbol, item.name))
+        .title_alignment(Alignment::Center);
+    frame.render_widget(Paragraph::new(Line::from(spans)).block(block), area);
+}
+
+/// Série annuelle : historique 1w si chargé, sinon données du graphique
+fn yearly_data(item: &WatchlistItem) -> Option<&OHLCData> {
+    item.data_for(Interval::W1).or(item.data.as_ref())
+}
+
+/// Lignes "libellé  valeur" des statistiques
+fn stat_lines(app: &App, item: &WatchlistItem) -> Vec<Line<'static>> {
+    let ticker_type = item.ticker_type();
+    let currency = item.currency();
+    let price = |value: f64| format_price_in(ticker_type, &currency, value);
+
+    let session = item
+        .day_range()
+        .map(|(low, high)| format!("{} – {}", price(low), price(high)));
+
+    // 52 semaines comptées depuis la dernière bougie connue
+    let year = yearly_data(item).and_then(|data| {
+        let last = data.last()?;
+        data.range_since(last.timestamp - Duration::weeks(52))
+    });
+    let year = match (year, item.current_price()) {
+        (Some((low, high)), Some(current)) => {
+            Some(format!("{} – {}  {}", price(low), price(high), range_bar(low, high, current)))
+        }
+        (Some((low, high)), None) => Some(format!("{} – {}", price(low), price(high))),
+        (None, _) => None,
+    };
+
+    let volume = format_volume_summary(item.session_volume(), item.average_volume());
+
+    let earnings = item.earnings_date.map(|date| {
+        match item.earnings_badge(chrono::Local::now().date_naive()) {
+            Some(badge) => format!("{} ({})", date.format("%d/%m/%Y"), badge),
+            None => date.format("%d/%m/%Y").to_string(),
+        }
+    });
+
+    let beta = app.beta(&item.symbol).map(|stats| {
+        format!(
+            "{:.2} · corrélation {:.2} vs {} ({} séances)",
+            stats.beta,
+            stats.correlation,
+            app.benchmark_symbol(&item.symbol),
+            stats.samples
+        )
+    });
+
+    let tags = app.tags.of(&item.symbol);
+    let tags = (!tags.is_empty()).then(|| tags.join(", "));
+
+    let mut lines = vec![
+        stat_line("Séance", session),
+        stat_line("52 semaines", year),
+        stat_line("Volume", volume),
+        stat_line("Résultats", earnings),
+    ];
+    if ticker_type == TickerType::Stock {
+        let ratings = item.analyst_ratings.as_ref();
+        lines.push(stat_line("Analystes", ratings.and_then(recommendation_text)));
+        lines.push(target_line(ratings, item.current_price(), &price));
+    }
+    if ticker_type == TickerType::Crypto {
+        lines.extend(crypto_stat_lines(app.crypto_stats.get(&item.symbol), item));
+    }
+    lines.extend([
+        stat_line("Bêta", beta),
+        stat_line("Position", position_text(app, item)),
+        stat_line("Tags", tags),
+        match app.notes.of(&item.symbol) {
+            Some(note) => stat_line("Note", Some(note.to_string())),
+            None => Line::from(vec![
+                label_span("Note"),
+                Span::styled("— [n] pour en ajouter une", Style::default().fg(Color::DarkGray)),
+            ]),
+        },
+    ]);
+    lines
+}
+
+/// Statistiques CoinGecko : valorisation, volume 24h, offre et dominance
+fn crypto_stat_lines(stats: Option<&CryptoStats>, item: &WatchlistItem) -> Vec<Line<'static>> {
+    let currency = item.currency();
+    let amount = |value: f64| currency.wrap(&format_volume(value as u64));
+    let coin = item.symbol.split('-').next().unwrap_or(&item.symbol).to_string();
+
+    let market_cap = stats.and_then(|stats| {
+        let cap = amount(stats.market_cap?);
+        Some(match stats.market_cap_rank {
+            Some(rank) => format!("{} · rang #{}", cap, rank),
+            None => cap,
+        })
+    });
+    let volume = stats.and_then(|stats| stats.volume_24h).map(amount);
+    let supply = stats.and_then(|stats| {
+        let circulating = format!("{} {}", format_volume(stats.circulating_supply? as u64), coin);
+        Some(match (stats.supply_percent(), stats.max_supply) {
+            (Some(percent), Some(max)) => format!("{} ({:.0}% de {})", circulating, percent, format_volume(max as u64)),
+            _ => circulating,
+        })
+    });
+    let dominance = stats
+        .and_then(|stats| stats.dominance)
+        .map(|dominance| format!("{:.1}% du marché crypto", dominance));
+
+    vec![
+        stat_line("Valorisation", market_cap),
+        stat_line("Volume 24h", volume),
+        stat_line("Circulation", supply),
+        stat_line("Dominance", dominance),
+    ]
+}
+
+/// Consensus et répartition du mois : "Achat · 12 achat fort, 20 achat, 8 conserver"
+fn recommendation_text(ratings: &AnalystRatings) -> Option<String> {
+    let counts: Vec<String> = [
+        (ratings.strong_buy, "achat fort"),
+        (ratings.buy, "achat"),
+        (ratings.hold, "conserver"),
+        (ratings.sell, "vente"),
+        (ratings.strong_sell, "vente forte"),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, label)| format!("{} {}", count, label))
+    .collect();
+
+    match (ratings.consensus_label(), counts.is_empty()) {
+        (Some(consensus), true) => Some(consensus.to_string()),
+        (Some(consensus), false) => Some(format!("{} · {}", consensus, counts.join(", "))),
+        (None, false) => Some(counts.join(", ")),
+        (None, true) => None,
+    }
+}
+
+/// Objectif moyen, fourchette et potentiel vs le cours (vert : hausse)
+///
+/// Objectif et fourchette passent par `price` : masqués en mode discret
+fn target_line(ratings: Option<&AnalystRatings>, current: Option<f64>, price: &dyn Fn(f64) -> String) -> Line<'static> {
+    let Some((ratings, target)) = ratings.and_then(|ratings| Some((ratings, ratings.target_mean?))) else {
+        return stat_line("Objectif", None);
+    };
+
+    let mut text = price(target);
+    if let (Some(low), Some(high)) = (ratings.target_low, ratings.target_high) {
+        text.push_str(&format!(" ({} – {})", price(low), price(high)));
+    }
+    let mut spans = vec![label_span("Objectif"), Span::styled(text, Style::default().fg(Color::White))];
+    if let Some(upside) = current.and_then(|current| ratings.upside_percent(current)) {
+        let color = if upside >= 0.0 { Color::Green } else { Color::Red };
+        spans.push(Span::raw(" · "));
+        spans.push(Span::styled(format!("{:+.1}% vs cours", upside), Style::default().fg(color)));
+    }
+    if let Some(analysts) = ratings.analysts {
+        spans.push(Span::styled(format!(" · {} analystes", analysts), Style::default().fg(Color::Gray)));
+    }
+    Line::from(spans)
+}
+
+/// Quantité détenue, valeur et P&L du journal : "10 titres · $2714.90 · latent +$320.00"
+fn position_text(app: &App, item: &WatchlistItem) -> Option<String> {
+    let pnl = app.position_pnl(&item.symbol);
+    let quantity = match &pnl {
+        Some(pnl) => pnl.quantity,
+        None => app.portfolio.position(&item.symbol)?.quantity,
+    };
+    if quantity <= 0.0 && pnl.is_none() {
+        return None;
+    }
+
+    let mut text = format!("{} titres", quantity);
+    if let Some(price) = item.current_price() {
+        text.push_str(&format!(" · {}", format_money(quantity * price)));
+    }
+    if let Some(pnl) = pnl {
+        if let Some(unrealized) = pnl.unrealized {
+            text.push_str(&format!(" · latent {}", format_signed_money(unrealized)));
+        }
+        if pnl.realized != 0.0 {
+            text.push_str(&format!(" · réalisé {}", format_signed_money(pnl.realized)));
+        }
+    }
+    Some(text)
+}
+
+/// Position du cours dans la fourchette : "───────●────────  45%"
+fn range_bar(low: f64, high: f64, current: f64) -> String {
+    let ratio = if high > low { ((current - low) / (high - low)).clamp(0.0, 1.0) } else { 1.0 };
+    let marker = (ratio * (RANGE_BAR_WIDTH - 1) as f64).round() as usize;
+    let bar: String = (0..RANGE_BAR_WIDTH).map(|i| if i == marker { '●' } else { '─' }).collect();
+    format!("{}  {:.0}%", bar, ratio * 100.0)
+}
+
+fn label_span(label: &str) -> Span<'static> {
+    Span::styled(
+        format!(" {:<width$}", label, width = LABEL_WIDTH),
+        Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD),
+    )
+}
+
+/// Ligne de statistique ("—" si la valeur est inconnue)
+fn stat_line(label: &str, value: Option<String>) -> Line<'static> {
+    let value = match value {
+        Some(value) => Span::styled(value, Style::default().fg(Color::White)),
+        None => Span::styled("—", Style::default().fg(Color::DarkGray)),
+    };
+    Line::from(vec![label_span(label), value])
+}
+
+/// Petit graphique en chandeliers de la série annuelle
+fn render_small_chart(frame: &mut Frame, item: &WatchlistItem, area: Rect) {
+    let Some(data) = yearly_data(item).filter(|data| !data.is_empty()) else {
+        let block = Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::White));
+        let loading = Paragraph::new(Span::styled("Chargement de l'historique...", Style::default().fg(Color::DarkGray)))
+            .block(block)
+            .alignment(Alignment::Center);
+        frame.render_widget(loading, area);
+        return;
+    };
+
+    let decimals = data
+        .last()
+        .map(|c| price_decimals(item.ticker_type(), &item.currency(), c.close))
+        .unwrap_or(2);
+    let lines = CandlestickRenderer::new(&data.candles, data.interval, area)
+        .with_price_decimals(decimals)
+        .render_lines();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(format!(" 🕯️ {} · {} ", data.interval.label(), data.timeframe.label()));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Dessine le footer (raccourcis ou notification)
+fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let key = |text: &'static str| Span::styled(text, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    let line = match app.active_toast() {
+        Some(toast) => toast_line(toast),
+        None => Line::from(vec![
+            key("[Enter]"),
+            Span::raw(" Graphique  "),
+            key("[n]"),
+            Span::raw(" Note  "),
+            key("[f]"),
+            Span::raw(" Comptes  "),
+            key("[h]"),
+            Span::raw(" Détenteurs  "),
+            key("[I / ESC]"),
+            Span::raw(" Retour  "),
+            key("[q]"),
+            Span::raw(" Quit"),
+        ]),
+    };
+
+    let paragraph = Paragraph::new(line).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+
+    #[test]
+    fn test_range_bar() {
+        assert_eq!(range_bar(100.0, 200.0, 100.0), format!("●{}  0%", "─".repeat(RANGE_BAR_WIDTH - 1)));
+        assert_eq!(range_bar(100.0, 200.0, 250.0), format!("{}●  100%", "─".repeat(RANGE_BAR_WIDTH - 1)));
+    }
+
+    #[test]
+    fn test_detail_shows_stats_position_and_note() {
+        let mut app = App::with_watchlist(vec![sample_item("AIR.PA", "Airbus SE", 150.0, 60)]);
+        app.portfolio.set_position("AIR.PA", 10.0);
+        app.notes.set("AIR.PA", "Thèse : carnet de commandes");
+        app.show_detail();
+
+        let text = buffer_text(&render_to_buffer(100, 30, |frame| render_detail(frame, &app, frame.size())));
+        assert!(text.contains("AIR.PA · Airbus SE"), "{}", text);
+        assert!(text.contains("Actions · EUR"), "{}", text);
+        assert!(text.contains(" €"), "{}", text);
+        assert!(text.contains("52 semaines"), "{}", text);
+        assert!(text.contains("●"), "{}", text);
+        assert!(text.contains("10 titres"), "{}", text);
+        assert!(text.contains("Thèse : carnet de commandes"), "{}", text);
+        assert!(text.contains("[n] Note"), "{}", text);
+    }
+
+    #[test]
+    fn test_detail_shows_analyst_ratings() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 60)]);
+        app.show_detail();
+        let render = |app: &App| buffer_text(&render_to_buffer(100, 30, |frame| render_detail(frame, app, frame.size())));
+        let text = render(&app);
+        assert!(text.contains("Analystes     —"), "{}", text);
+        assert!(text.contains("Objectif      —"), "{}", text);
+
+        let price = app.watchlist[0].current_price().unwrap();
+        app.watchlist[0].analyst_ratings = Some(AnalystRatings {
+            strong_buy: 12,
+            buy: 20,
+            hold: 8,
+            recommendation: Some("buy".to_string()),
+            target_mean: Some(price * 1.1),
+            target_low: Some(150.0),
+            target_high: Some(260.0),
+            analysts: Some(38),
+            ..AnalystRatings::default()
+        });
+        let text = render(&app);
+        assert!(text.contains("Analystes     Achat · 12 achat fort, 20 achat, 8 conserver"), "{}", text);
+        assert!(text.contains("($150.00 – $260.00) · +10.0% vs cours · 38 analystes"), "{}", text);
+    }
+
+    #[test]
+    fn test_target_range_masked_in_privacy_mode() {
+        let mut app = App::with_watchlist(vec![sample_item("AAPL", "Apple Inc.", 170.0, 60)]);
+        app.show_detail();
+        app.watchlist[0].analyst_ratings = Some(AnalystRatings {
+            target_mean: Some(200.0),
+            target_low: Some(150.0),
+            target_high: Some(260.0),
+            ..AnalystRatings::default()
+        });
+
+        let text = crate::ui::format::with_privacy(|| {
+            buffer_text(&render_to_buffer(100, 30, |frame| render_detail(frame, &app, frame.size())))
+        });
+        assert!(text.contains("Objectif      ••• (••• – •••)"), "{}", text);
+        assert!(!text.contains("150.00") && !text.contains("260.00"), "{}", text);
+    }
+
+    #[test]
+    fn test_detail_shows_crypto_market_stats() {
+        let mut app = App::with_watchlist(vec![sample_item("BTC-EUR", "Bitcoin EUR", 60000.0, 60)]);
+        app.show_detail();
+        let render = |app: &App| buffer_text(&render_to_buffer(100, 30, |frame| render_detail(frame, app, frame.size())));
+        let text = render(&app);
+        assert!(text.contains("Valorisation  —"), "{}", text);
+        assert!(!text.contains("Analystes"), "{}", text);
+
+        app.crypto_stats.insert(
+            "BTC-EUR".to_string(),
+            CryptoStats {
+                coin_id: "bitcoin".to_string(),
+                market_cap: Some(1.24e12),
+                market_cap_rank: Some(1),
+                volume_24h: Some(2.95e10),
+                circulating_supply: Some(19_740_000.0),
+                max_supply: Some(21_000_000.0),
+                dominance: Some(52.34),
+                fetched_at: chrono::Utc::now(),
+            },
+        );
+        let text = render(&app);
+        assert!(text.contains("Valorisation  1.24T € · rang #1"), "{}", text);
+        assert!(text.contains("Volume 24h    29.5B €"), "{}", text);
+        assert!(text.contains("Circulation   19.7M BTC (94% de 21M)"), "{}", text);
+        assert!(text.contains("Dominance     52.3% du marché crypto"), "{}", text);
+    }
+
+    #[test]
+    fn test_detail_shows_beta_vs_benchmark() {
+        let mut app = App::with_watchlist(vec![
+            sample_item("AAPL", "Apple Inc.", 170.0, 60),
+            sample_item("SPY", "SPDR S&P 500", 500.0, 60),
+        ]);
+        app.show_detail();
+
+        let text = buffer_text(&render_to_buffer(100, 30, |frame| render_detail(frame, &app, frame.size())));
+        assert!(text.contains("Bêta          1.00 · corrélation 1.00 vs SPY (59 séances)"), "{}", text);
+
+        // L'indice lui-même : pas de bêta
+        app.selected_index = 1;
+        assert!(app.beta("SPY").is_none());
+    }
+}
@@ -0,0 +1,202 @@
+// ============================================================================
+// Dividends - Calendrier des dividendes de la watchlist
+// ============================================================================
+// Prochains détachements et paiements des actions et ETF, par date :
+//
+//   ┌ 💰 Dividendes à venir ────────────────────────────────────────────┐
+//   │ ▶ 13/06/2024  Détachement  KO          $0.49   100 titres   $48.50 │
+//   │   20/06/2024  Détachement  TTE.PA     0.79 €            —        — │
+//   │   01/07/2024  Paiement     KO          $0.49   100 titres   $48.50 │
+//   │                                                                    │
+//   │ Total à percevoir : $48.50                                         │
+//
+// - Dividendes chargés à l'ouverture de la vue (quoteSummary de Yahoo)
+// - Montant reçu converti en dollars, comme la valeur du portefeuille
+// - Mode discret : montants et quantités masqués
+// - Enter ouvre le graphique du ticker sélectionné
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::models::dividends::{expected_income, DividendEvent, UpcomingDividend};
+use crate::models::TickerType;
+use crate::ui::dashboard::toast_line;
+use crate::ui::format::{format_money, format_price_in, privacy, MASK};
+
+/// Largeur des colonnes symbole, montant par action, quantité et total
+const SYMBOL_WIDTH: usize = 10;
+const AMOUNT_WIDTH: usize = 10;
+const QUANTITY_WIDTH: usize = 13;
+const TOTAL_WIDTH: usize = 11;
+
+/// Dessine le calendrier (lignes, total, footer)
+pub fn render_dividends(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Calendrier
+            Constraint::Length(3), // Footer
+        ])
+        .split(area)
+        .to_vec();
+
+    render_calendar(frame, app, chunks[0]);
+    render_footer(frame, app, chunks[1]);
+}
+
+/// Dessine une ligne par date à venir, puis le total à percevoir
+fn render_calendar(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" 💰 Dividendes à venir ");
+
+    let entries = app.dividend_calendar();
+    let loading = app.dividends_to_load().len();
+
+    let mut lines: Vec<Line> = entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| entry_line(entry, index == app.dividends_index))
+        .collect();
+
+    if entries.is_empty() && loading == 0 {
+        let paragraph = Paragraph::new(Span::styled("Aucun dividende à venir", Style::default().fg(Color::Gray)))
+            .block(block)
+            .alignment(Alignment::Center);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    if !entries.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Total à percevoir : ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format_money(expected_income(&entries)),
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+    if loading > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("Chargement des dividendes... ({} ticker(s) restant(s))", loading),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Ligne du calendrier : date, événement, symbole, montant, quantité, total
+fn entry_line(entry: &UpcomingDividend, selected: bool) -> Line<'static> {
+    let mut label = Style::default().fg(Color::White).add_modifier(Modifier::BOLD);
+    if selected {
+        label = label.add_modifier(Modifier::REVERSED);
+    }
+    let event_color = match entry.event {
+        DividendEvent::ExDividend => Color::Yellow,
+        DividendEvent::Payment => Color::Green,
+    };
+
+    // Actions et ETF seulement : montant dans la devise de cotation
+    let amount = entry
+        .amount
+        .map(|amount| format_price_in(TickerType::Stock, &entry.currency, amount))
+        .unwrap_or_else(|| "—".to_string());
+    let quantity = entry
+        .quantity
+        .map(|quantity| if privacy() { MASK.to_string() } else { format!("{} titres", quantity) })
+        .unwrap_or_else(|| "—".to_string());
+    let total = entry.total_usd.map(format_money).unwrap_or_else(|| "—".to_string());
+
+    Line::from(vec![
+        Span::styled(
+            format!("{}{}", if selected { "▶ " } else { "  " }, entry.date.format("%d/%m/%Y")),
+            label,
+        ),
+        Span::styled(format!("  {:<12}", entry.event.label()), Style::default().fg(event_color)),
+        Span::styled(format!("{:<width$}", entry.symbol, width = SYMBOL_WIDTH), label),
+        Span::raw(format!("{:>width$}", amount, width = AMOUNT_WIDTH)),
+        Span::styled(format!("{:>width$}", quantity, width = QUANTITY_WIDTH), Style::default().fg(Color::Gray)),
+        Span::styled(format!("{:>width$}", total, width = TOTAL_WIDTH), Style::default().fg(Color::Green)),
+    ])
+}
+
+/// Dessine le footer (raccourcis ou notification)
+fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let line = match app.active_toast() {
+        Some(toast) => toast_line(toast),
+        None => Line::from(vec![
+            Span::styled("[↑↓ / j k]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Navigate  "),
+            Span::styled("[Enter]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" Graphique  "),
+            Span::styled("[$ / ESC]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Retour"),
+        ]),
+    };
+
+    let paragraph = Paragraph::new(line).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::dividends::DividendInfo;
+    use crate::models::WatchlistItem;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer};
+
+    #[test]
+    fn test_dividends_lists_upcoming_dates() {
+        let mut app = App::with_watchlist(vec![
+            WatchlistItem::new("KO".to_string(), "Coca-Cola".to_string()),
+            WatchlistItem::new("TTE.PA".to_string(), "TotalEnergies".to_string()),
+        ]);
+        let in_days = |days| Some(chrono::Local::now().date_naive() + chrono::Duration::days(days));
+        app.watchlist[0].dividends = Some(DividendInfo { ex_date: in_days(3), payment_date: in_days(20), amount: Some(0.485) });
+        app.watchlist[1].dividends = Some(DividendInfo { ex_date: in_days(10), payment_date: None, amount: Some(0.79) });
+        app.portfolio.set_position("KO", 100.0);
+        app.show_dividends();
+
+        let text = buffer_text(&render_to_buffer(100, 20, |frame| render_dividends(frame, &app, frame.size())));
+        assert!(text.contains("▶ "), "{}", text);
+        assert!(text.contains("Détachement") && text.contains("Paiement"), "{}", text);
+        assert!(text.contains("100 titres") && text.contains("$48.50"), "{}", text);
+        assert!(text.contains("0.79 €"), "{}", text);
+        assert!(text.contains("Total à percevoir : $48.50"), "{}", text);
+        assert!(text.contains("[Enter] Graphique"), "{}", text);
+    }
+
+    #[test]
+    fn test_dividends_masked_in_privacy_mode() {
+        let mut app = App::with_watchlist(vec![WatchlistItem::new("KO".to_string(), "Coca-Cola".to_string())]);
+        let in_days = |days| Some(chrono::Local::now().date_naive() + chrono::Duration::days(days));
+        app.watchlist[0].dividends = Some(DividendInfo { ex_date: in_days(3), payment_date: None, amount: Some(0.485) });
+        app.portfolio.set_position("KO", 100.0);
+        app.show_dividends();
+
+        let text = crate::ui::format::with_privacy(|| {
+            buffer_text(&render_to_buffer(100, 20, |frame| render_dividends(frame, &app, frame.size())))
+        });
+        assert!(text.contains("Détachement"), "{}", text);
+        assert!(!text.contains("0.49") && !text.contains("100 titres") && !text.contains("48.50"), "{}", text);
+        assert_eq!(text.matches(MASK).count(), 4, "{}", text);
+    }
+}
@@ -10,10 +10,13 @@
 // 4. Error handling avec Result
 // ============================================================================
 
-use std::time::Duration;
+use std::io;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
-use crossterm::event::{self, Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{self, Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
 // ============================================================================
 // Enum Event
@@ -42,58 +45,119 @@ pub enum Event {
 // ============================================================================
 // Structure EventHandler
 // ============================================================================
-// CONCEPT : Singleton pattern pour gérer les événements
-// - Un seul handler pour toute l'application
-// - Pas besoin de stocker d'état (stateless)
+// CONCEPT : Thread d'entrée dédié
+// - Un thread lit le clavier en continu et pousse les événements dans un
+//   channel : aucune touche n'attend la fin d'un rendu coûteux
+// - Le même thread émet un Tick à intervalle fixe (tick_rate), quelle que
+//   soit l'activité du clavier
+// - La boucle principale bloque sur le channel, traite tous les événements
+//   en attente, puis dessine une seule fois
 // ============================================================================
 
-/// Gestionnaire d'événements
-pub struct EventHandler;
+/// Intervalle par défaut entre deux ticks
+pub const DEFAULT_TICK_RATE: Duration = Duration::from_millis(250);
+
+/// Gestionnaire d'événements (thread de lecture du clavier + ticks)
+pub struct EventHandler {
+    receiver: mpsc::Receiver<Event>,
+}
 
 impl EventHandler {
-    /// Crée un nouveau gestionnaire d'événements
-    pub fn new() -> Self {
-        Self
+    /// Démarre le thread d'entrée
+    ///
+    /// CONCEPT RUST : move closure
+    /// - Le thread possède l'émetteur du channel
+    /// - Il s'arrête de lui-même quand le récepteur (EventHandler) est détruit :
+    ///   send() échoue alors
+    ///
+    /// # Arguments
+    /// * `tick_rate` - Intervalle entre deux Event::Tick
+    pub fn new(tick_rate: Duration) -> Self {
+        Self::with_source(tick_rate, read_terminal)
     }
 
-    /// Lit le prochain événement (bloquant avec timeout)
-    ///
-    /// CONCEPT RUST : Result et ?
-    /// - poll() peut échouer (I/O error)
-    /// - read() peut échouer
-    /// - ? propage automatiquement les erreurs
+    /// Démarre le thread d'entrée sur une source d'événements (le terminal,
+    /// ou une source simulée dans les tests)
     ///
-    /// CONCEPT : Non-blocking I/O avec timeout
-    /// - poll(timeout) attend max 250ms
-    /// - Si pas d'événement, retourne Ok(Event::Tick)
-    /// - Si événement, le lit et le convertit
-    pub fn next(&self) -> Result<Event> {
-        // Poll avec timeout de 250ms
-        // CONCEPT RUST : if expression
-        // - if retourne une valeur en Rust (comme un ternaire ?)
-        if event::poll(Duration::from_millis(250))? {
-            // Il y a un événement, on le lit
-            match event::read()? {
-                // Événement clavier
-                CrosstermEvent::Key(key) => {
-                    // CONCEPT : Filter sur KeyEventKind
-                    // Sur certains OS, on reçoit Press ET Release
-                    // On ne veut gérer que Press pour éviter les doublons
-                    if key.kind == KeyEventKind::Press {
-                        Ok(Event::Key(key))
-                    } else {
-                        // Ignore Release, retourne Tick
-                        Ok(Event::Tick)
+    /// `source(timeout)` attend un événement au plus `timeout`
+    fn with_source<F>(tick_rate: Duration, mut source: F) -> Self
+    where
+        F: FnMut(Duration) -> io::Result<Option<CrosstermEvent>> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut last_tick = Instant::now();
+            loop {
+                // Attend une touche au plus jusqu'au prochain tick
+                let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+                let event = match source(timeout) {
+                    Ok(Some(event)) => translate(event),
+                    Ok(None) => None,
+                    Err(_) => {
+                        // Terminal indisponible : évite de boucler à vide
+                        thread::sleep(tick_rate);
+                        Some(Event::Error)
                     }
-                }
+                };
 
-                // Autres événements (resize, mouse, etc.) ignorés pour l'instant
-                _ => Ok(Event::Tick),
+                if let Some(event) = event {
+                    if sender.send(event).is_err() {
+                        return;
+                    }
+                }
+                if last_tick.elapsed() >= tick_rate {
+                    if sender.send(Event::Tick).is_err() {
+                        return;
+                    }
+                    last_tick = Instant::now();
+                }
             }
-        } else {
-            // Timeout : pas d'événement, retourne Tick
-            Ok(Event::Tick)
-        }
+        });
+
+        Self { receiver }
+    }
+
+    /// Attend le prochain événement (touche ou tick)
+    ///
+    /// Erreur si le thread d'entrée s'est arrêté
+    pub fn next(&self) -> Result<Event> {
+        Ok(self.receiver.recv()?)
+    }
+
+    /// Événement déjà en attente, sans bloquer (None si le channel est vide)
+    pub fn try_next(&self) -> Option<Event> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+impl Default for EventHandler {
+    fn default() -> Self {
+        Self::new(DEFAULT_TICK_RATE)
+    }
+}
+
+/// Lit un événement du terminal, en attendant au plus `timeout`
+fn read_terminal(timeout: Duration) -> io::Result<Option<CrosstermEvent>> {
+    if event::poll(timeout)? {
+        event::read().map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Convertit un événement du terminal (None : ignoré)
+fn translate(event: CrosstermEvent) -> Option<Event> {
+    match event {
+        // CONCEPT : Filter sur KeyEventKind
+        // Sur certains OS, on reçoit Press ET Release
+        // On ne veut gérer que Press pour éviter les doublons
+        CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => Some(Event::Key(key)),
+        CrosstermEvent::Key(_) => None,
+        // Redimensionnement : un tick suffit à redessiner
+        CrosstermEvent::Resize(_, _) => Some(Event::Tick),
+        // Autres événements (souris, focus, ...) ignorés
+        _ => None,
     }
 }
 
@@ -117,6 +181,21 @@ pub fn is_quit_event(event: &Event) -> bool {
     }
 }
 
+/// Vérifie si l'événement est Ctrl+C (interruption)
+///
+/// CONCEPT : Raw mode et SIGINT
+/// - En raw mode, le terminal n'envoie plus SIGINT : Ctrl+C arrive comme
+///   une touche 'c' avec le modificateur CONTROL
+/// - Traité comme une demande de quitter « propre » (sauvegardes, terminal
+///   restauré), sur tous les écrans, saisie comprise
+pub fn is_interrupt_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('c') | KeyCode::Char('C')) && key.modifiers.contains(KeyModifiers::CONTROL)
+    } else {
+        false
+    }
+}
+
 /// Vérifie si l'événement est Échap
 pub fn is_escape_event(event: &Event) -> bool {
     if let Event::Key(key) = event {
@@ -184,6 +263,42 @@ pub fn is_previous_interval_event(event: &Event) -> bool {
     }
 }
 
+/// Vérifie si l'événement est 'L' (intervalle suivant du graphique secondaire)
+pub fn is_next_secondary_interval_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('L'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'H' (intervalle précédent du graphique secondaire)
+pub fn is_previous_secondary_interval_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('H'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'm' (vue multi-timeframe)
+pub fn is_split_view_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('m') | KeyCode::Char('M'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'g' (séparateurs de séance)
+pub fn is_session_separator_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('g') | KeyCode::Char('G'))
+    } else {
+        false
+    }
+}
+
 /// Vérifie si l'événement est 'a' (add ticker)
 ///
 /// CONCEPT : Vim-style 'a' for append
@@ -208,6 +323,270 @@ pub fn is_delete_event(event: &Event) -> bool {
     }
 }
 
+/// Vérifie si l'événement est 'p' (saisir la position détenue)
+pub fn is_position_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('p') | KeyCode::Char('P'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'f' (menu d'ajout rapide forex)
+pub fn is_forex_menu_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('f') | KeyCode::Char('F'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 't' (saisir une transaction)
+pub fn is_transaction_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('t') | KeyCode::Char('T'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'w' (répartition du portefeuille)
+pub fn is_allocation_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('w') | KeyCode::Char('W'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'o' (carnet d'ordres)
+pub fn is_order_book_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('o') | KeyCode::Char('O'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 's' (statistiques de séance, vue graphique)
+///
+/// Même touche que le screener, qui ne s'ouvre que depuis le dashboard
+pub fn is_session_stats_event(event: &Event) -> bool {
+    is_screener_event(event)
+}
+
+/// Vérifie si l'événement est 'n' (annoter le chandelier du curseur, vue graphique)
+///
+/// Même touche que la note de la fiche détaillée
+pub fn is_annotation_event(event: &Event) -> bool {
+    is_note_event(event)
+}
+
+/// Vérifie si l'événement est 'd' (droite de tendance de l'ancre au curseur, vue graphique)
+///
+/// Même touche que la suppression de ticker, qui ne se fait que depuis le dashboard
+pub fn is_trend_line_event(event: &Event) -> bool {
+    is_delete_event(event)
+}
+
+/// Vérifie si l'événement est 'a' (panneau des annotations, vue graphique)
+///
+/// Même touche que l'ajout de ticker, qui ne se fait que depuis le dashboard
+pub fn is_annotations_panel_event(event: &Event) -> bool {
+    is_add_event(event)
+}
+
+/// Vérifie si l'événement est 'h' (panneau des initiés et institutionnels, fiche détaillée)
+///
+/// Même touche que l'intervalle précédent, qui ne sert pas sur la fiche détaillée
+pub fn is_holders_event(event: &Event) -> bool {
+    is_previous_interval_event(event)
+}
+
+/// Vérifie si l'événement est 'f' (comptes annuels / trimestriels, fiche détaillée)
+///
+/// Même touche que le menu forex, qui ne s'ouvre que depuis le dashboard
+pub fn is_statement_period_event(event: &Event) -> bool {
+    is_forex_menu_event(event)
+}
+
+/// Vérifie si l'événement est 'e' (export du graphique en PNG)
+pub fn is_export_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('e') | KeyCode::Char('E'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'E' (export CSV des cotations, sur le dashboard)
+///
+/// Testé avant is_export_event, qui accepte aussi 'E'
+pub fn is_csv_export_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        key.code == KeyCode::Char('E')
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 's' (vue découverte / screeners)
+pub fn is_screener_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('s') | KeyCode::Char('S'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'v' (tableau des rendements)
+pub fn is_returns_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('v') | KeyCode::Char('V'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est '$' (calendrier des dividendes)
+pub fn is_dividends_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('$'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'L' (classement de la watchlist)
+pub fn is_leaderboard_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('L'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'm' (vue comparaison, depuis le dashboard)
+///
+/// Même touche que la vue multi-timeframe du graphique : plusieurs tickers
+/// au lieu de plusieurs intervalles
+pub fn is_compare_event(event: &Event) -> bool {
+    is_split_view_event(event)
+}
+
+/// Vérifie si l'événement est 'v' (indice de référence sur le graphique)
+pub fn is_benchmark_event(event: &Event) -> bool {
+    is_returns_event(event)
+}
+
+/// Vérifie si l'événement est 'b' (backtest du graphique affiché)
+pub fn is_backtest_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('b') | KeyCode::Char('B'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'n' (nouvelle alerte sur indicateur)
+pub fn is_new_alert_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('n') | KeyCode::Char('N'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'c' (modifier les tags du ticker sélectionné)
+pub fn is_tags_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('c'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'C' (filtre par tag suivant)
+pub fn is_tag_filter_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('C'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'i' (mode discret : montants masqués)
+pub fn is_privacy_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('i'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est F12 (overlay de debug : métriques des téléchargements)
+pub fn is_debug_overlay_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        key.code == KeyCode::F(12)
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'I' (fiche détaillée du ticker)
+pub fn is_detail_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('I'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'n' (note du ticker, sur la fiche détaillée)
+pub fn is_note_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('n') | KeyCode::Char('N'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'x' (fermer l'alerte affichée)
+pub fn is_dismiss_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('x') | KeyCode::Char('X'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'z' (snooze de la règle de l'alerte affichée)
+pub fn is_snooze_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('z') | KeyCode::Char('Z'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est 'r' ou 'R' (réessayer un chargement échoué)
+pub fn is_retry_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('r') | KeyCode::Char('R'))
+    } else {
+        false
+    }
+}
+
+/// Vérifie si l'événement est ':' (saisie d'une commande)
+pub fn is_command_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char(':'))
+    } else {
+        false
+    }
+}
+
 /// Vérifie si l'événement est Backspace
 pub fn is_backspace_event(event: &Event) -> bool {
     if let Event::Key(key) = event {
@@ -226,6 +605,105 @@ pub fn is_ticker_char_event(event: &Event) -> bool {
     }
 }
 
+/// Vérifie si l'événement est un caractère de saisie libre (transaction, ...)
+///
+/// Contrairement à is_ticker_char_event(), accepte espaces et signes (+, -, ,)
+pub fn is_text_char_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char(c) if !c.is_control())
+    } else {
+        false
+    }
+}
+
+/// Touches de sélection rapide : ligne visée (0 à 8) et ouverture du graphique
+///
+/// - '1' à '9' : sélectionne la ligne
+/// - Shift + chiffre : sélectionne et ouvre le graphique. Sans protocole
+///   clavier étendu, le terminal n'envoie que le symbole de la touche : les
+///   symboles d'un clavier QWERTY (! @ # $ % ^ & * () sont donc acceptés
+///
+/// CONCEPT RUST : Option<(usize, bool)>
+/// - None : pas une touche de sélection rapide
+/// - Some((index, open)) : une seule fonction pour les deux variantes
+pub fn quick_select_event(event: &Event) -> Option<(usize, bool)> {
+    const SHIFTED: [char; 9] = ['!', '@', '#', '$', '%', '^', '&', '*', '('];
+
+    let Event::Key(key) = event else {
+        return None;
+    };
+    let KeyCode::Char(c) = key.code else {
+        return None;
+    };
+
+    if let Some(digit) = c.to_digit(10).filter(|digit| (1..=9).contains(digit)) {
+        let open = key.modifiers.contains(KeyModifiers::SHIFT);
+        return Some((digit as usize - 1, open));
+    }
+    SHIFTED.iter().position(|&shifted| shifted == c).map(|index| (index, true))
+}
+
+/// Vérifie si l'événement est 'u' (outil de mesure du graphique)
+pub fn is_measure_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('u') | KeyCode::Char('U'))
+    } else {
+        false
+    }
+}
+
+/// Touche d'export du graphique en texte (sur le graphique)
+///
+/// - 't' : texte brut → Some(false)
+/// - 'T' : couleurs ANSI conservées → Some(true)
+pub fn text_export_event(event: &Event) -> Option<bool> {
+    let Event::Key(key) = event else {
+        return None;
+    };
+    match key.code {
+        KeyCode::Char('t') => Some(false),
+        KeyCode::Char('T') => Some(true),
+        _ => None,
+    }
+}
+
+/// Touche de copie dans le presse-papiers (sur le dashboard)
+///
+/// - 'y' : symbole et prix → Some(false)
+/// - 'Y' : ligne complète → Some(true)
+pub fn yank_event(event: &Event) -> Option<bool> {
+    let Event::Key(key) = event else {
+        return None;
+    };
+    match key.code {
+        KeyCode::Char('y') => Some(false),
+        KeyCode::Char('Y') => Some(true),
+        _ => None,
+    }
+}
+
+/// Vérifie si l'événement est 'y' (verrouiller l'échelle de l'axe Y)
+pub fn is_y_axis_lock_event(event: &Event) -> bool {
+    if let Event::Key(key) = event {
+        matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y'))
+    } else {
+        false
+    }
+}
+
+/// Déplacement du curseur de mesure : ← / → (un chandelier), avec Shift (dix)
+pub fn measure_step_event(event: &Event) -> Option<isize> {
+    let Event::Key(key) = event else {
+        return None;
+    };
+    let step = if key.modifiers.contains(KeyModifiers::SHIFT) { 10 } else { 1 };
+    match key.code {
+        KeyCode::Left => Some(-step),
+        KeyCode::Right => Some(step),
+        _ => None,
+    }
+}
+
 /// Extrait le caractère d'un événement clavier si c'est un caractère
 pub fn get_char_from_event(event: &Event) -> Option<char> {
     if let Event::Key(key) = event {
@@ -243,6 +721,33 @@ pub fn get_char_from_event(event: &Event) -> Option<char> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_input_thread_forwards_keys_and_ticks() {
+        let press = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty());
+        let mut release = press;
+        release.kind = KeyEventKind::Release;
+        let mut script: VecDeque<CrosstermEvent> =
+            [CrosstermEvent::Key(press), CrosstermEvent::Key(release), CrosstermEvent::Resize(80, 24)].into();
+
+        // Source simulée : le script, puis plus aucune touche
+        let handler = EventHandler::with_source(Duration::from_millis(20), move |timeout| {
+            Ok(script.pop_front().or_else(|| {
+                thread::sleep(timeout);
+                None
+            }))
+        });
+        let next = || handler.receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        // Touche relâchée ignorée, redimensionnement → Tick
+        assert!(matches!(next(), Event::Key(key) if key.code == KeyCode::Char('a')));
+        assert!(matches!(next(), Event::Tick));
+
+        // Sans activité du clavier, les ticks continuent
+        assert!(matches!(next(), Event::Tick));
+        assert!(matches!(next(), Event::Tick));
+    }
 
     #[test]
     fn test_is_quit_event() {
@@ -254,4 +759,27 @@ mod tests {
 
         assert!(!is_quit_event(&Event::Tick));
     }
+
+    #[test]
+    fn test_is_interrupt_event() {
+        let key = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+
+        assert!(is_interrupt_event(&key(KeyCode::Char('c'), KeyModifiers::CONTROL)));
+        assert!(!is_interrupt_event(&key(KeyCode::Char('c'), KeyModifiers::empty())));
+        assert!(!is_interrupt_event(&key(KeyCode::Char('x'), KeyModifiers::CONTROL)));
+        assert!(!is_interrupt_event(&Event::Tick));
+    }
+
+    #[test]
+    fn test_quick_select_event() {
+        let key = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+
+        assert_eq!(quick_select_event(&key(KeyCode::Char('1'), KeyModifiers::empty())), Some((0, false)));
+        assert_eq!(quick_select_event(&key(KeyCode::Char('9'), KeyModifiers::empty())), Some((8, false)));
+        assert_eq!(quick_select_event(&key(KeyCode::Char('3'), KeyModifiers::SHIFT)), Some((2, true)));
+        assert_eq!(quick_select_event(&key(KeyCode::Char('#'), KeyModifiers::SHIFT)), Some((2, true)));
+        assert_eq!(quick_select_event(&key(KeyCode::Char('0'), KeyModifiers::empty())), None);
+        assert_eq!(quick_select_event(&key(KeyCode::Char('a'), KeyModifiers::empty())), None);
+        assert_eq!(quick_select_event(&Event::Tick), None);
+    }
 }
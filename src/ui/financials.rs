@@ -0,0 +1,199 @@
+// ============================================================================
+// Financials - Panneau des comptes publiés
+// ============================================================================
+// Affiché à droite du graphique de la fiche détaillée (actions), un
+// histogramme par poste sur les dernières périodes publiées :
+//
+//   ┌ 📊 Comptes annuels ────────────┐
+//   │ Chiffre d'affaires  $391B +2.0%│
+//   │ ▆▆▆▆▆ ▇▇▇▇▇ ▇▇▇▇▇ █████        │
+//   │ Résultat net       $93.7B -3.4%│
+//   │ ▇▇▇▇▇ █████ ▇▇▇▇▇ ▇▇▇▇▇        │
+//   │ Free cash flow      $109B +9.3%│
+//   │ ▇▇▇▇▇ █████ ▇▇▇▇▇ ▇▇▇▇▇        │
+//   │ 2021  2022  2023  2024         │
+//   └────────────────────────────────┘
+//
+// - Barres à l'échelle de la plus grande valeur absolue du poste
+// - Pertes et free cash flow négatif en rouge
+// - Variation de la dernière période vs la précédente dans le titre du poste
+// - [f] bascule entre comptes annuels et trimestriels
+// ============================================================================
+
+use chrono::NaiveDate;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::models::financials::{value_at, StatementPeriod, StatementValue};
+use crate::models::{Currency, WatchlistItem};
+use crate::ui::format::format_volume;
+
+/// Largeur du panneau (bordures incluses)
+pub const FINANCIALS_PANEL_WIDTH: u16 = 34;
+
+/// Largeur d'une barre (libellé "T3 24" compris)
+const BAR_WIDTH: usize = 5;
+
+/// Niveaux de remplissage d'une cellule, par huitièmes
+const BAR_LEVELS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Dessine les histogrammes des comptes du ticker pour la périodicité choisie
+pub fn render_financials(frame: &mut Frame, item: &WatchlistItem, period: StatementPeriod, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(format!(" 📊 Comptes {} ", period.label()));
+
+    let financials = item.financials.as_ref();
+    let statements = financials.map(|financials| financials.statements(period));
+    let (Some(financials), Some(statements)) = (financials, statements.filter(|statements| !statements.is_empty())) else {
+        let message = if financials.is_some() { "Aucun compte publié" } else { "Chargement..." };
+        let paragraph = Paragraph::new(Span::styled(message, Style::default().fg(Color::Gray)))
+            .block(block)
+            .alignment(Alignment::Center);
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let width = area.width.saturating_sub(2) as usize;
+    let columns = ((width + 1) / (BAR_WIDTH + 1)).max(1);
+    let dates = statements.dates();
+    let dates = &dates[dates.len().saturating_sub(columns)..];
+
+    // Hauteur intérieure : les trois postes (titre + barres) puis les périodes
+    let bar_height = ((area.height.saturating_sub(3) as usize) / 3).saturating_sub(1).max(1);
+
+    let mut lines = Vec::new();
+    for (label, values) in [
+        ("Chiffre d'affaires", &statements.revenue),
+        ("Résultat net", &statements.net_income),
+        ("Free cash flow", &statements.free_cash_flow),
+    ] {
+        lines.push(title_line(label, values, &financials.currency, width));
+        lines.extend(bar_lines(values, dates, bar_height));
+    }
+    let periods: Vec<String> = dates.iter().map(|date| format!("{:<BAR_WIDTH$}", period.period_label(*date))).collect();
+    lines.push(Line::from(Span::styled(periods.join(" "), Style::default().fg(Color::Gray))));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Montant compact signé dans la devise des comptes : "$391B", "-824M €"
+fn format_amount(currency: &Currency, value: f64) -> String {
+    let amount = currency.wrap(&format_volume(value.abs() as u64));
+    if value < 0.0 {
+        format!("-{}", amount)
+    } else {
+        amount
+    }
+}
+
+/// Nom du poste, dernière valeur et variation vs la période précédente
+fn title_line(label: &'static str, values: &[StatementValue], currency: &Currency, width: usize) -> Line<'static> {
+    let label_span = Span::styled(label, Style::default().fg(Color::Cyan));
+    let Some(last) = values.last() else {
+        let missing = format!("{:>1$}", "—", width.saturating_sub(label.chars().count()));
+        return Line::from(vec![label_span, Span::styled(missing, Style::default().fg(Color::DarkGray))]);
+    };
+
+    let value = format_amount(currency, last.value);
+    let change = values
+        .len()
+        .checked_sub(2)
+        .map(|index| values[index].value)
+        .filter(|previous| *previous != 0.0)
+        .map(|previous| (last.value - previous) / previous.abs() * 100.0);
+    let change_text = change.map(|change| format!(" {:+.1}%", change)).unwrap_or_default();
+
+    let padding = width.saturating_sub(label.chars().count() + value.chars().count() + change_text.chars().count());
+    let mut spans = vec![
+        label_span,
+        Span::raw(" ".repeat(padding)),
+        Span::styled(value, Style::default().fg(Color::White)),
+    ];
+    if let Some(change) = change {
+        let color = if change >= 0.0 { Color::Green } else { Color::Red };
+        spans.push(Span::styled(change_text, Style::default().fg(color)));
+    }
+    Line::from(spans)
+}
+
+/// Lignes de l'histogramme d'un poste, de haut en bas
+fn bar_lines(values: &[StatementValue], dates: &[NaiveDate], height: usize) -> Vec<Line<'static>> {
+    let max = dates
+        .iter()
+        .filter_map(|date| value_at(values, *date))
+        .fold(0.0_f64, |max, value| max.max(value.abs()));
+
+    (0..height)
+        .map(|row| {
+            // Huitièmes de cellule sous la ligne courante
+            let below = (height - 1 - row) * 8;
+            let spans: Vec<Span> = dates
+                .iter()
+                .flat_map(|date| {
+                    let value = value_at(values, *date);
+                    let eighths = match value {
+                        Some(value) if max > 0.0 => ((value.abs() / max) * (height * 8) as f64).round() as usize,
+                        _ => 0,
+                    };
+                    let level = BAR_LEVELS[eighths.saturating_sub(below).min(8)];
+                    let color = if value.is_some_and(|value| value < 0.0) { Color::Red } else { Color::Green };
+                    [Span::styled(level.to_string().repeat(BAR_WIDTH), Style::default().fg(color)), Span::raw(" ")]
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::financials::{Financials, Statements};
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+
+    fn values(values: &[(i32, f64)]) -> Vec<StatementValue> {
+        values
+            .iter()
+            .map(|(year, value)| StatementValue { date: NaiveDate::from_ymd_opt(*year, 9, 30).unwrap(), value: *value })
+            .collect()
+    }
+
+    #[test]
+    fn test_financials_panel_draws_bars_per_statement() {
+        let mut item = sample_item("AAPL", "Apple Inc.", 170.0, 60);
+        let render = |item: &WatchlistItem, period| {
+            buffer_text(&render_to_buffer(34, 14, |frame| render_financials(frame, item, period, frame.size())))
+        };
+        assert!(render(&item, StatementPeriod::Annual).contains("Chargement..."));
+
+        item.financials = Some(Financials {
+            annual: Statements {
+                revenue: values(&[(2023, 383e9), (2024, 391e9)]),
+                net_income: values(&[(2023, 97e9), (2024, -93.7e9)]),
+                free_cash_flow: Vec::new(),
+            },
+            ..Financials::default()
+        });
+        let text = render(&item, StatementPeriod::Annual);
+        assert!(text.contains("Comptes annuels"), "{}", text);
+        assert!(text.contains("Chiffre d'affaires   $391B +2.1%"), "{}", text);
+        assert!(text.contains("Résultat net     -$93.7B -196.6%"), "{}", text);
+        assert!(text.contains("Free cash flow"), "{}", text);
+        assert!(text.contains("█████ █████"), "{}", text);
+        assert!(text.contains("2023  2024"), "{}", text);
+
+        let quarterly = render(&item, StatementPeriod::Quarterly);
+        assert!(quarterly.contains("Aucun compte publié"), "{}", quarterly);
+    }
+}
@@ -0,0 +1,107 @@
+// ============================================================================
+// Forex Menu - Ajout rapide des paires de devises majeures
+// ============================================================================
+// Popup centré par-dessus le dashboard listant les paires majeures :
+//
+//   ┌ 💱 Paires de devises ┐
+//   │ ▶ EUR/USD  EURUSD=X  │
+//   │   GBP/USD  GBPUSD=X ✓│   ← ✓ : déjà dans la watchlist
+//   └──────────────────────┘
+//
+// CONCEPT RATATUI : Clear widget
+// - Efface la zone avant de dessiner le popup
+// - Sinon le texte du dashboard en dessous transparaîtrait
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::models::ticker::MAJOR_FOREX_PAIRS;
+
+/// Largeur du popup (bordures incluses)
+const MENU_WIDTH: u16 = 34;
+
+/// Dessine le menu d'ajout rapide forex centré dans `area`
+pub fn render_forex_menu(frame: &mut Frame, app: &App, area: Rect) {
+    // Une ligne par paire + bordures (2) + ligne d'aide (1)
+    let height = MAJOR_FOREX_PAIRS.len() as u16 + 3;
+    let popup = centered_rect(MENU_WIDTH, height, area);
+
+    let mut lines: Vec<Line> = MAJOR_FOREX_PAIRS
+        .iter()
+        .enumerate()
+        .map(|(index, (symbol, label))| {
+            let selected = index == app.forex_menu_index;
+            let marker = if selected { "▶ " } else { "  " };
+            let added = if app.is_in_watchlist(symbol) { " ✓" } else { "" };
+
+            let style = if selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+                    .add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default().fg(Color::White)
+            };
+
+            Line::from(vec![
+                Span::styled(format!("{}{:<9} {:<10}", marker, label, symbol), style),
+                Span::styled(added, Style::default().fg(Color::Green)),
+            ])
+        })
+        .collect();
+
+    lines.push(Line::from(vec![
+        Span::styled("[Enter]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+        Span::raw(" Ajouter  "),
+        Span::styled("[ESC]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+        Span::raw(" Fermer"),
+    ]));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green))
+        .title(" 💱 Paires de devises ")
+        .title_alignment(Alignment::Center);
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+/// Calcule un rectangle de taille fixe centré dans `area` (tronqué si trop petit)
+pub(crate) fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_centered_rect() {
+        let area = Rect::new(0, 0, 100, 40);
+        assert_eq!(centered_rect(30, 10, area), Rect::new(35, 15, 30, 10));
+
+        // Zone trop petite : le popup est réduit
+        let small = Rect::new(0, 0, 20, 5);
+        assert_eq!(centered_rect(30, 10, small), Rect::new(0, 0, 20, 5));
+    }
+}
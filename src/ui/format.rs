@@ -0,0 +1,291 @@
+// ============================================================================
+// Format - Formatage des prix et variations selon le type d'actif
+// ============================================================================
+// Partagé par le dashboard et le graphique pour que chaque type d'actif
+// s'affiche de manière cohérente sur tous les écrans :
+//
+//   Action / ETF / Crypto : "$271.49"   "▲ +2.11%"
+//   Action européenne     : "153.42 €"  (devise de cotation, voir Currency)
+//   Forex                 : "1.08543"   "▲ +12.3 pips (+0.11%)"
+//   Indice                : "5234.18"   "▲ +45.20 pts (+0.87%)"
+//
+// Volumes compacts : "950", "12.5K", "1.24M", "3.1B"
+//
+// Mode discret (touche [i]) : les prix et montants deviennent "•••", seules
+// les variations en % restent visibles (partage d'écran, lieu public)
+//
+// CONCEPT RUST : AtomicBool global
+// - Lu par toutes les fonctions de formatage sans passer l'App partout
+// - Bascule depuis la boucle d'événements, lecture sans verrou au rendu
+// ============================================================================
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::models::{Currency, TickerType};
+
+/// Texte affiché à la place d'un montant en mode discret
+pub const MASK: &str = "•••";
+
+static PRIVACY: AtomicBool = AtomicBool::new(false);
+
+/// Active ou désactive le mode discret
+pub fn set_privacy(enabled: bool) {
+    PRIVACY.store(enabled, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Mode discret du seul thread de test (voir with_privacy)
+    static TEST_PRIVACY: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Indique si le mode discret est actif
+pub fn privacy() -> bool {
+    #[cfg(test)]
+    if TEST_PRIVACY.with(|privacy| privacy.get()) {
+        return true;
+    }
+    PRIVACY.load(Ordering::Relaxed)
+}
+
+/// Exécute `f` en mode discret (tests)
+///
+/// Les tests tournent en parallèle : le drapeau global n'est pas touché, seul
+/// le thread courant voit le mode discret
+#[cfg(test)]
+pub(crate) fn with_privacy<T>(f: impl FnOnce() -> T) -> T {
+    TEST_PRIVACY.with(|privacy| privacy.set(true));
+    let result = f();
+    TEST_PRIVACY.with(|privacy| privacy.set(false));
+    result
+}
+
+/// Nombre de décimales pour afficher un prix
+///
+/// CONCEPT : Précision adaptée à l'actif
+/// - Forex : 5 décimales (EUR/USD 1.08543), 4 au-dessus de 10 (USD/JPY 151.2345)
+/// - Autres : celles de la devise (2 en général, 0 pour le yen)
+pub fn price_decimals(ticker_type: TickerType, currency: &Currency, price: f64) -> usize {
+    match ticker_type {
+        TickerType::Forex if price.abs() < 10.0 => 5,
+        TickerType::Forex => 4,
+        _ => currency.decimals(),
+    }
+}
+
+/// Formate un prix en dollars (voir format_price_in)
+pub fn format_price(ticker_type: TickerType, price: f64) -> String {
+    format_price_in(ticker_type, &Currency::default(), price)
+}
+
+/// Formate un prix dans sa devise de cotation : "$271.49", "153.42 €"
+///
+/// Pas de symbole pour les devises (cotées en taux) ni pour les indices (en points)
+pub fn format_price_in(ticker_type: TickerType, currency: &Currency, price: f64) -> String {
+    if privacy() {
+        return MASK.to_string();
+    }
+    let decimals = price_decimals(ticker_type, currency, price);
+    let amount = format!("{:.*}", decimals, price);
+    match ticker_type {
+        TickerType::Forex | TickerType::Index => amount,
+        _ => currency.wrap(&amount),
+    }
+}
+
+/// Taille d'un pip pour une paire de devises
+///
+/// - Paires en yen (USDJPY=X, EURJPY=X) : 0.01
+/// - Autres paires : 0.0001
+pub fn pip_size(symbol: &str) -> f64 {
+    if symbol.to_uppercase().contains("JPY") {
+        0.01
+    } else {
+        0.0001
+    }
+}
+
+/// Formate la variation du jour avec sa flèche
+///
+/// # Arguments
+/// * `change` - Variation absolue (None si inconnue : seul le % est affiché)
+/// * `change_percent` - Variation en pourcentage
+///
+/// Mode discret : seul le % est affiché
+pub fn format_change(ticker_type: TickerType, symbol: &str, change: Option<f64>, change_percent: f64) -> String {
+    let arrow = if change_percent >= 0.0 { "▲" } else { "▼" };
+
+    match (ticker_type, change.filter(|_| !privacy())) {
+        (TickerType::Forex, Some(change)) => {
+            format!("{} {:+.1} pips ({:+.2}%)", arrow, change / pip_size(symbol), change_percent)
+        }
+        (TickerType::Index, Some(change)) => {
+            format!("{} {:+.2} pts ({:+.2}%)", arrow, change, change_percent)
+        }
+        _ => format!("{} {:+.2}%", arrow, change_percent),
+    }
+}
+
+/// Formate un écart de prix signé en dollars (voir format_price_change_in)
+pub fn format_price_change(ticker_type: TickerType, price: f64, change: f64) -> String {
+    format_price_change_in(ticker_type, &Currency::default(), price, change)
+}
+
+/// Formate un écart de prix signé : "+$8.40", "+8.40 €", "-0.00123", "+45.20"
+///
+/// Même précision que format_price_in au niveau `price` ("•••" en mode discret)
+pub fn format_price_change_in(ticker_type: TickerType, currency: &Currency, price: f64, change: f64) -> String {
+    if privacy() {
+        return MASK.to_string();
+    }
+    let decimals = price_decimals(ticker_type, currency, price);
+    let sign = if change < 0.0 { "-" } else { "+" };
+    let amount = format!("{:.*}", decimals, change.abs());
+    match ticker_type {
+        TickerType::Forex | TickerType::Index => format!("{}{}", sign, amount),
+        _ => format!("{}{}", sign, currency.wrap(&amount)),
+    }
+}
+
+/// Formate une durée écoulée : "16j 6h", "3h 15min", "45min"
+pub fn format_elapsed(elapsed: chrono::Duration) -> String {
+    let minutes = elapsed.num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    match (days, hours, minutes) {
+        (0, 0, minutes) => format!("{}min", minutes),
+        (0, hours, 0) => format!("{}h", hours),
+        (0, hours, minutes) => format!("{}h {}min", hours, minutes),
+        (days, 0, _) => format!("{}j", days),
+        (days, hours, _) => format!("{}j {}h", days, hours),
+    }
+}
+
+/// Formate un montant en dollars : "$1234.56" ("•••" en mode discret)
+pub fn format_money(value: f64) -> String {
+    if privacy() {
+        return MASK.to_string();
+    }
+    format!("${:.2}", value)
+}
+
+/// Formate un montant signé : "+$12.30", "-$4.00" ("•••" en mode discret)
+pub fn format_signed_money(value: f64) -> String {
+    if privacy() {
+        return MASK.to_string();
+    }
+    format!("{}${:.2}", if value < 0.0 { "-" } else { "+" }, value.abs())
+}
+
+/// Formate un volume en notation compacte (3 chiffres significatifs)
+///
+/// 950 → "950", 12_480 → "12.5K", 1_240_000 → "1.24M", 3_100_000_000 → "3.1B"
+pub fn format_volume(volume: u64) -> String {
+    const UNITS: [(f64, &str); 4] = [(1e12, "T"), (1e9, "B"), (1e6, "M"), (1e3, "K")];
+
+    // 999_600 s'affiche "1M" plutôt que "1000K"
+    let value = volume as f64;
+    let Some((scale, unit)) = UNITS.iter().find(|(scale, _)| value >= *scale * 0.9995) else {
+        return volume.to_string();
+    };
+
+    let scaled = value / scale;
+    let decimals = if scaled < 10.0 {
+        2
+    } else if scaled < 100.0 {
+        1
+    } else {
+        0
+    };
+    let number = format!("{:.*}", decimals, scaled);
+    // Zéros inutiles retirés : "3.10" → "3.1", "12.0" → "12"
+    let number = if number.contains('.') {
+        number.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &number
+    };
+    format!("{}{}", number, unit)
+}
+
+/// Volume de la séance et moyenne des précédentes : "Vol 1.24M (moy. 980K)"
+///
+/// None si le fournisseur ne donne pas de volume (forex, certains indices)
+pub fn format_volume_summary(volume: Option<u64>, average: Option<u64>) -> Option<String> {
+    let volume = volume.filter(|volume| *volume > 0)?;
+    Some(match average.filter(|average| *average > 0) {
+        Some(average) => format!("Vol {} (moy. {})", format_volume(volume), format_volume(average)),
+        None => format!("Vol {}", format_volume(volume)),
+    })
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_price() {
+        assert_eq!(format_price(TickerType::Stock, 271.489), "$271.49");
+        assert_eq!(format_price(TickerType::Forex, 1.085432), "1.08543");
+        assert_eq!(format_price(TickerType::Forex, 151.23456), "151.2346");
+        assert_eq!(format_price(TickerType::Index, 5234.181), "5234.18");
+    }
+
+    #[test]
+    fn test_format_price_in_quote_currency() {
+        let euro = Currency::new("EUR");
+        assert_eq!(format_price_in(TickerType::Stock, &euro, 153.421), "153.42 €");
+        assert_eq!(format_price_in(TickerType::Stock, &Currency::new("JPY"), 2345.6), "¥2346");
+        assert_eq!(format_price_in(TickerType::Stock, &Currency::new("GBp"), 1234.5), "1234.50p");
+        // Indice européen : toujours en points
+        assert_eq!(format_price_in(TickerType::Index, &euro, 8012.3), "8012.30");
+        assert_eq!(format_price_change_in(TickerType::Stock, &euro, 153.4, -2.1), "-2.10 €");
+    }
+
+    #[test]
+    fn test_format_price_change_and_elapsed() {
+        assert_eq!(format_price_change(TickerType::Stock, 170.0, 8.4), "+$8.40");
+        assert_eq!(format_price_change(TickerType::Forex, 1.08, -0.00123), "-0.00123");
+        assert_eq!(format_price_change(TickerType::Index, 5234.0, 45.2), "+45.20");
+
+        assert_eq!(format_elapsed(chrono::Duration::minutes(45)), "45min");
+        assert_eq!(format_elapsed(chrono::Duration::minutes(195)), "3h 15min");
+        assert_eq!(format_elapsed(chrono::Duration::hours(4)), "4h");
+        assert_eq!(format_elapsed(chrono::Duration::hours(16 * 24 + 6)), "16j 6h");
+        assert_eq!(format_elapsed(chrono::Duration::days(7)), "7j");
+    }
+
+    #[test]
+    fn test_format_change_in_pips() {
+        assert_eq!(
+            format_change(TickerType::Forex, "EURUSD=X", Some(0.00123), 0.11),
+            "▲ +12.3 pips (+0.11%)"
+        );
+        assert_eq!(
+            format_change(TickerType::Forex, "USDJPY=X", Some(-0.45), -0.3),
+            "▼ -45.0 pips (-0.30%)"
+        );
+        assert_eq!(format_change(TickerType::Stock, "AAPL", Some(5.0), 2.114), "▲ +2.11%");
+        assert_eq!(
+            format_change(TickerType::Index, "^GSPC", Some(45.2), 0.87),
+            "▲ +45.20 pts (+0.87%)"
+        );
+    }
+
+    #[test]
+    fn test_format_volume() {
+        assert_eq!(format_volume(950), "950");
+        assert_eq!(format_volume(12_480), "12.5K");
+        assert_eq!(format_volume(1_240_000), "1.24M");
+        assert_eq!(format_volume(3_100_000_000), "3.1B");
+        assert_eq!(format_volume(163_224_100), "163M");
+        assert_eq!(format_volume(1_000_000), "1M");
+        assert_eq!(format_volume(999_600), "1M");
+
+        assert_eq!(format_volume_summary(Some(1_240_000), Some(980_000)).as_deref(), Some("Vol 1.24M (moy. 980K)"));
+        assert_eq!(format_volume_summary(Some(1_240_000), None).as_deref(), Some("Vol 1.24M"));
+        assert_eq!(format_volume_summary(Some(0), Some(0)), None);
+    }
+}
@@ -0,0 +1,144 @@
+// ============================================================================
+// Holders - Panneau des initiés et détenteurs institutionnels
+// ============================================================================
+// Affiché à droite du graphique de la fiche détaillée (touche h, actions) :
+//
+//   ┌ 👥 Détenteurs ────────────────────────────────┐
+//   │ Initiés                                       │
+//   │ 02/04/24  Vente   196K  $45.8M  COOK TIMOTHY D│
+//   │ 01/04/24  Achat     1K   $170K  ADAMS KATHERIN│
+//   │                                               │
+//   │ Institutionnels                               │
+//   │  8.77%  1.35B  Vanguard Group Inc             │
+//   │  6.52%  1.01B  Blackrock Inc.                 │
+//   └───────────────────────────────────────────────┘
+//
+// - Les deux sections se partagent la hauteur disponible
+// - Achats en vert, ventes en rouge, attributions et exercices en gris
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::models::holders::{Holders, InsiderKind};
+use crate::models::WatchlistItem;
+use crate::ui::format::format_volume;
+
+/// Largeur du panneau (bordures incluses)
+pub const HOLDERS_PANEL_WIDTH: u16 = 50;
+
+/// Dessine le panneau des initiés et institutionnels du ticker
+pub fn render_holders(frame: &mut Frame, item: &WatchlistItem, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(" 👥 Détenteurs ");
+
+    let holders = match &item.holders {
+        Some(holders) if !holders.is_empty() => holders,
+        holders => {
+            let message = if holders.is_some() { "Aucune donnée pour ce ticker" } else { "Chargement..." };
+            let paragraph = Paragraph::new(Span::styled(message, Style::default().fg(Color::Gray)))
+                .block(block)
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+    };
+
+    // Hauteur intérieure moins les deux titres et la ligne de séparation
+    let rows = (area.height.saturating_sub(2) as usize).saturating_sub(3) / 2;
+    frame.render_widget(Paragraph::new(holders_lines(holders, rows.max(1))).block(block), area);
+}
+
+/// Lignes des deux sections, `rows` lignes au plus par section
+fn holders_lines(holders: &Holders, rows: usize) -> Vec<Line<'static>> {
+    let title = |text: &'static str| {
+        Line::from(Span::styled(text, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)))
+    };
+    let empty = || Line::from(Span::styled("—", Style::default().fg(Color::DarkGray)));
+
+    let mut lines = vec![title("Initiés")];
+    if holders.insider_transactions.is_empty() {
+        lines.push(empty());
+    }
+    for transaction in holders.insider_transactions.iter().take(rows) {
+        let color = match transaction.kind {
+            InsiderKind::Buy => Color::Green,
+            InsiderKind::Sale => Color::Red,
+            InsiderKind::Other => Color::Gray,
+        };
+        let value = transaction
+            .value
+            .map(|value| format!("${}", format_volume(value as u64)))
+            .unwrap_or_else(|| "—".to_string());
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}  ", transaction.date.format("%d/%m/%y")), Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{:<6}", transaction.kind.label()), Style::default().fg(color)),
+            Span::raw(format!("{:>6}  {:>6}  ", format_volume(transaction.shares as u64), value)),
+            Span::styled(transaction.name.clone(), Style::default().fg(Color::White)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(title("Institutionnels"));
+    if holders.institutions.is_empty() {
+        lines.push(empty());
+    }
+    for holder in holders.institutions.iter().take(rows) {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:>5.2}%  ", holder.percent_held), Style::default().fg(Color::Yellow)),
+            Span::raw(format!("{:>5}  ", format_volume(holder.shares as u64))),
+            Span::styled(holder.organization.clone(), Style::default().fg(Color::White)),
+        ]));
+    }
+    lines
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::holders::{InsiderTransaction, InstitutionalHolder};
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_holders_panel_lists_both_sections() {
+        let mut item = sample_item("AAPL", "Apple Inc.", 170.0, 60);
+        let render = |item: &WatchlistItem| buffer_text(&render_to_buffer(50, 12, |frame| render_holders(frame, item, frame.size())));
+        assert!(render(&item).contains("Chargement..."));
+
+        item.holders = Some(Holders::default());
+        assert!(render(&item).contains("Aucune donnée"));
+
+        item.holders = Some(Holders {
+            insider_transactions: vec![InsiderTransaction {
+                name: "COOK TIMOTHY D".to_string(),
+                relation: "Chief Executive Officer".to_string(),
+                date: NaiveDate::from_ymd_opt(2024, 4, 2).unwrap(),
+                kind: InsiderKind::Sale,
+                shares: 196_410.0,
+                value: Some(45_834_560.0),
+            }],
+            institutions: vec![InstitutionalHolder {
+                organization: "Vanguard Group Inc".to_string(),
+                percent_held: 8.77,
+                shares: 1_353_017_330.0,
+                report_date: None,
+            }],
+        });
+        let text = render(&item);
+        assert!(text.contains("02/04/24  Vente   196K  $45.8M  COOK TIMOTHY D"), "{}", text);
+        assert!(text.contains(" 8.77%  1.35B  Vanguard Group Inc"), "{}", text);
+        assert!(text.find("Initiés").unwrap() < text.find("Institutionnels").unwrap(), "{}", text);
+    }
+}
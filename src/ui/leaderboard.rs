@@ -0,0 +1,225 @@
+// ============================================================================
+// Leaderboard - Classement de la watchlist par rendement
+// ============================================================================
+// Tous les tickers, du meilleur au pire sur la période choisie :
+//
+//   ┌ 🏆 Classement ────────────────────────────────────────────┐
+//   │           [1D]   1W   1M   YTD                            │
+//   ├───────────────────────────────────────────────────────────┤
+//   │ ▶  1. NVDA        +12.4%                 │██████████████  │
+//   │    2. AAPL         +3.1%                 │████            │
+//   │    3. TSLA         -6.8%         ████████│                │
+//   │    4. AIR.PA           —                                  │
+//
+// - Rendements calculés sur l'historique D1 (chargé à l'ouverture de la vue
+//   s'il manque), comme le tableau des rendements
+// - Barres proportionnelles au plus grand écart, de part et d'autre de zéro
+// - Enter ouvre le graphique du ticker sélectionné
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::models::returns::ReturnPeriod;
+use crate::models::Interval;
+use crate::ui::dashboard::toast_line;
+
+/// Largeur de la colonne symbole et de la colonne rendement
+const SYMBOL_WIDTH: usize = 10;
+const RETURN_WIDTH: usize = 9;
+
+/// Largeur de chaque moitié de barre (baisses à gauche, hausses à droite)
+const BAR_HALF_WIDTH: usize = 20;
+
+/// Dessine le classement (onglets des périodes, lignes, footer)
+pub fn render_leaderboard(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Périodes
+            Constraint::Min(0),    // Classement
+            Constraint::Length(3), // Footer
+        ])
+        .split(area)
+        .to_vec();
+
+    render_tabs(frame, app, chunks[0]);
+    render_ranking(frame, app, chunks[1]);
+    render_footer(frame, app, chunks[2]);
+}
+
+/// Dessine les onglets des périodes (la période active est surlignée)
+fn render_tabs(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" 🏆 Classement ")
+        .title_alignment(Alignment::Center);
+
+    let spans: Vec<Span> = ReturnPeriod::LEADERBOARD
+        .iter()
+        .map(|period| {
+            if *period == app.leaderboard_period {
+                Span::styled(
+                    format!(" [{}] ", period.label()),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled(format!("  {}  ", period.label()), Style::default().fg(Color::Gray))
+            }
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(Line::from(spans)).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+/// Dessine une ligne par ticker, du meilleur rendement au pire
+fn render_ranking(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" Rendements sur les clôtures journalières ");
+
+    if app.watchlist.is_empty() {
+        let paragraph = Paragraph::new(Span::styled("Watchlist vide", Style::default().fg(Color::Gray)))
+            .block(block)
+            .alignment(Alignment::Center);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let ranking = app.leaderboard();
+    let max_abs = ranking
+        .iter()
+        .filter_map(|(_, value)| value.map(f64::abs))
+        .fold(0.0, f64::max);
+
+    let lines: Vec<Line> = ranking
+        .iter()
+        .enumerate()
+        .map(|(rank, &(index, value))| {
+            let item = &app.watchlist[index];
+            let selected = rank == app.leaderboard_index;
+            let mut label = Style::default().fg(Color::White).add_modifier(Modifier::BOLD);
+            if selected {
+                label = label.add_modifier(Modifier::REVERSED);
+            }
+
+            let mut spans = vec![Span::styled(
+                format!(
+                    "{}{:>2}. {:<width$}",
+                    if selected { "▶ " } else { "  " },
+                    rank + 1,
+                    item.symbol,
+                    width = SYMBOL_WIDTH
+                ),
+                label,
+            )];
+            match value {
+                Some(value) => {
+                    let color = if value >= 0.0 { Color::Green } else { Color::Red };
+                    let (left, right) = return_bar(value, max_abs, BAR_HALF_WIDTH);
+                    spans.push(Span::styled(
+                        format!("{:>width$}  ", format!("{:+.1}%", value), width = RETURN_WIDTH),
+                        Style::default().fg(color),
+                    ));
+                    spans.push(Span::styled(left, Style::default().fg(color)));
+                    spans.push(Span::styled("│", Style::default().fg(Color::Gray)));
+                    spans.push(Span::styled(right, Style::default().fg(color)));
+                }
+                None => {
+                    let message = if item.data_for(Interval::D1).is_some() { "—" } else { "Chargement..." };
+                    spans.push(Span::styled(
+                        format!("{:>width$}", message, width = RETURN_WIDTH),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Barre d'un rendement relative au plus grand écart : (gauche, droite)
+///
+/// - Baisse : blocs alignés contre l'axe, à gauche ; hausse : à droite
+/// - Au moins un bloc pour un rendement non nul
+fn return_bar(value: f64, max_abs: f64, half_width: usize) -> (String, String) {
+    let length = if max_abs > 0.0 && value != 0.0 {
+        ((value.abs() / max_abs) * half_width as f64).round().clamp(1.0, half_width as f64) as usize
+    } else {
+        0
+    };
+    let bar = "█".repeat(length);
+    if value < 0.0 {
+        (format!("{:>width$}", bar, width = half_width), String::new())
+    } else {
+        (" ".repeat(half_width), bar)
+    }
+}
+
+/// Dessine le footer (raccourcis ou notification)
+fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let line = match app.active_toast() {
+        Some(toast) => toast_line(toast),
+        None => Line::from(vec![
+            Span::styled("[↑↓ / j k]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Navigate  "),
+            Span::styled("[h / l]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Période  "),
+            Span::styled("[Enter]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" Graphique  "),
+            Span::styled("[L / ESC]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Retour"),
+        ]),
+    };
+
+    let paragraph = Paragraph::new(line).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer, sample_item};
+
+    #[test]
+    fn test_return_bar() {
+        assert_eq!(return_bar(10.0, 10.0, 4), ("    ".to_string(), "████".to_string()));
+        assert_eq!(return_bar(-5.0, 10.0, 4), ("  ██".to_string(), String::new()));
+        assert_eq!(return_bar(0.1, 10.0, 4).1, "█");
+        assert_eq!(return_bar(0.0, 0.0, 4), ("    ".to_string(), String::new()));
+    }
+
+    #[test]
+    fn test_leaderboard_lists_every_ticker() {
+        let mut app = App::with_watchlist(vec![
+            sample_item("AAPL", "Apple Inc.", 170.0, 60),
+            sample_item("TSLA", "Tesla", 250.0, 60),
+        ]);
+        app.show_leaderboard();
+
+        let text = buffer_text(&render_to_buffer(100, 20, |frame| render_leaderboard(frame, &app, frame.size())));
+        assert!(text.contains("[1D]"), "{}", text);
+        assert!(text.contains("▶  1."), "{}", text);
+        assert!(text.contains("AAPL") && text.contains("TSLA"), "{}", text);
+        assert!(text.contains("[Enter] Graphique"), "{}", text);
+    }
+}
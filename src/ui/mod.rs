@@ -8,6 +8,31 @@ pub mod events;           // Gestion des événements clavier
 pub mod dashboard;        // Rendu de l'interface principale
 pub mod chart;            // Rendu du graphique ligne
 pub mod candlestick_text; // Rendu des chandeliers japonais (Unicode text)
+pub mod order_book;       // Panneau du carnet d'ordres (crypto)
+pub mod session_stats;    // Panneau des statistiques de séance (intraday)
+pub mod annotations;      // Panneau des annotations du graphique
+pub mod allocation;       // Vue répartition du portefeuille
+pub mod format;           // Formatage des prix et variations par type d'actif
+pub mod forex_menu;       // Menu d'ajout rapide des paires de devises
+pub mod screener;         // Vue découverte (screeners prédéfinis)
+pub mod returns;          // Tableau des rendements par période
+pub mod leaderboard;      // Classement de la watchlist par rendement
+pub mod dividends;        // Calendrier des dividendes de la watchlist
+pub mod compare;          // Vue comparaison (performances normalisées)
+pub mod backtest;         // Vue résultats du backtest
+pub mod detail;           // Fiche détaillée d'un ticker
+pub mod holders;          // Panneau des initiés et institutionnels (fiche détaillée)
+pub mod financials;       // Histogrammes des comptes publiés (fiche détaillée)
+pub mod ascii;            // Mode ASCII (terminaux sans glyphes de dessin)
+pub mod accessibility;    // Sans animation, sans emoji (lecteurs d'écran)
+pub mod clipboard;        // Copie dans le presse-papiers (OSC 52)
+pub mod status_bar;       // Barre d'état commune à tous les écrans
+pub mod debug_overlay;    // Overlay des métriques de téléchargement (F12)
+pub mod confirm_popup;    // Popup de confirmation (quitter, supprimer)
+pub mod onboarding;       // Configuration guidée du premier lancement
+
+#[cfg(test)]
+pub mod snapshot;         // Tests de rendu sur TestBackend (références texte)
 
 // Re-exports pour simplifier les imports
 pub use events::{Event, EventHandler};
@@ -0,0 +1,123 @@
+// ============================================================================
+// Vue : configuration guidée du premier lancement
+// ============================================================================
+// Popup centré, une étape à la fois (état dans onboarding.rs) :
+//
+//   ┌──────────────── 👋 Bienvenue dans LazyWallet ────────────────┐
+//   │Étape 4/4 · Watchlist                                         │
+//   │                                                              │
+//   │▶ [x] EURUSD=X   EUR/USD                                      │
+//   │  [x] AIR.PA     Airbus                                       │
+//   │  [x] MC.PA      LVMH                                         │
+//   │  [ ] ASML.AS    ASML                                         │
+//   │                                                              │
+//   │[↑↓] Choisir [Espace] Cocher [Entrée] Terminer [⌫] Retour     │
+//   │[Échap] Configuration par défaut                              │
+//   └──────────────────────────────────────────────────────────────┘
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::onboarding::{refresh_label, Appearance, BaseCurrency, Onboarding, Step, REFRESH_CHOICES};
+use crate::ui::forex_menu::centered_rect;
+
+/// Largeur du popup (bordures incluses)
+const POPUP_WIDTH: u16 = 64;
+
+/// Dessine l'étape courante de l'assistant centrée dans `area`
+pub fn render_onboarding(frame: &mut Frame, onboarding: &Onboarding, area: Rect) {
+    let step = onboarding.step;
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("Étape {}/{}", step.number(), Step::ALL.len()), Style::default().fg(Color::DarkGray)),
+            Span::styled(format!(" · {}", step.title()), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(""),
+    ];
+
+    let options: Vec<String> = match step {
+        Step::Currency => BaseCurrency::ALL.iter().map(|currency| currency.label().to_string()).collect(),
+        Step::Appearance => Appearance::ALL.iter().map(|appearance| appearance.label().to_string()).collect(),
+        Step::Refresh => REFRESH_CHOICES.iter().map(|seconds| refresh_label(*seconds)).collect(),
+        Step::Watchlist => onboarding
+            .suggestions()
+            .map(|(symbol, name, selected)| format!("[{}] {:<10} {}", if selected { 'x' } else { ' ' }, symbol, name))
+            .collect(),
+    };
+    for (index, option) in options.into_iter().enumerate() {
+        let line = if index == onboarding.cursor() {
+            Line::from(Span::styled(format!("▶ {}", option), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
+        } else {
+            Line::from(Span::styled(format!("  {}", option), Style::default().fg(Color::White)))
+        };
+        lines.push(line);
+    }
+
+    lines.push(Line::from(""));
+    let key = |label: &'static str| Span::styled(label, Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
+    let mut help = vec![key("[↑↓]"), Span::raw(" Choisir ")];
+    if step == Step::Watchlist {
+        help.extend([key("[Espace]"), Span::raw(" Cocher ")]);
+    }
+    let enter = if step == Step::Watchlist { " Terminer " } else { " Suivant " };
+    help.extend([key("[Entrée]"), Span::raw(enter)]);
+    if step != Step::Currency {
+        help.extend([key("[⌫]"), Span::raw(" Retour")]);
+    }
+    lines.push(Line::from(help));
+    lines.push(Line::from(vec![
+        Span::styled("[Échap]", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+        Span::raw(" Configuration par défaut"),
+    ]));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" 👋 Bienvenue dans LazyWallet ")
+        .title_alignment(Alignment::Center);
+
+    let popup = centered_rect(POPUP_WIDTH, lines.len() as u16 + 2, area);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer};
+
+    #[test]
+    fn test_onboarding_renders_current_step() {
+        let mut onboarding = Onboarding::default();
+        let render = |onboarding: &Onboarding| {
+            buffer_text(&render_to_buffer(80, 16, |frame| render_onboarding(frame, onboarding, frame.size())))
+        };
+
+        let text = render(&onboarding);
+        assert!(text.contains("Bienvenue dans LazyWallet"), "{}", text);
+        assert!(text.contains("Étape 1/4 · Devise de référence"), "{}", text);
+        assert!(text.contains("▶ USD"), "{}", text);
+        assert!(!text.contains("Retour"), "{}", text);
+
+        onboarding.move_cursor(true);
+        for _ in 0..3 {
+            onboarding.advance();
+        }
+        onboarding.toggle();
+        let text = render(&onboarding);
+        assert!(text.contains("Étape 4/4 · Watchlist"), "{}", text);
+        assert!(text.contains("▶ [ ] EURUSD=X"), "{}", text);
+        assert!(text.contains("  [x] AIR.PA"), "{}", text);
+        assert!(text.contains("[Entrée] Terminer"), "{}", text);
+    }
+}
@@ -0,0 +1,156 @@
+// ============================================================================
+// Order Book - Panneau de profondeur de marché
+// ============================================================================
+// Affiche le carnet d'ordres (asks en haut, bids en bas) avec une barre
+// proportionnelle à la quantité de chaque niveau et le spread au milieu
+//
+//   Prix        Qté   Taille
+//   43 252.00   0.80  ████        ← asks (rouge), meilleur ask en bas
+//   43 251.00   0.30  █
+//   ── Spread 0.90 (0.002%) ──
+//   43 250.10   0.52  ██          ← bids (vert), meilleur bid en haut
+//   43 249.00   1.20  ██████
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::models::order_book::{BookLevel, OrderBook};
+use crate::ui::format::{privacy, MASK};
+
+/// Largeur recommandée du panneau (bordures incluses)
+pub const ORDER_BOOK_PANEL_WIDTH: u16 = 36;
+
+/// Largeurs des colonnes prix et quantité
+const PRICE_WIDTH: usize = 11;
+const QUANTITY_WIDTH: usize = 10;
+
+/// Couleurs des deux côtés du carnet
+const BID_COLOR: Color = Color::Rgb(52, 208, 88);
+const ASK_COLOR: Color = Color::Rgb(234, 74, 90);
+
+/// Dessine le panneau du carnet d'ordres
+///
+/// # Arguments
+/// * `book` - Carnet d'ordres (None tant que le premier fetch n'est pas arrivé)
+pub fn render_order_book(frame: &mut Frame, book: Option<&OrderBook>, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(" 📖 Carnet d'ordres ");
+
+    let book = match book {
+        Some(book) => book,
+        None => {
+            let paragraph = Paragraph::new(vec![
+                Line::from(""),
+                Line::from(Span::styled("⏳ Chargement...", Style::default().fg(Color::Cyan))),
+            ])
+            .block(block)
+            .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+    };
+
+    // Lignes disponibles : bordures (2) + en-tête (1) + spread (1) + horodatage (1)
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let rows_per_side = (area.height.saturating_sub(5) / 2) as usize;
+    let bar_width = inner_width.saturating_sub(PRICE_WIDTH + QUANTITY_WIDTH + 2);
+    let max_quantity = book.max_quantity(rows_per_side);
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!("{:>pw$} {:>qw$} Taille", "Prix", "Qté", pw = PRICE_WIDTH, qw = QUANTITY_WIDTH),
+        Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD),
+    ))];
+
+    // Asks : du plus cher au meilleur (le meilleur ask touche le spread)
+    let asks: Vec<&BookLevel> = book.asks.iter().take(rows_per_side).collect();
+    for _ in asks.len()..rows_per_side {
+        lines.push(Line::from(""));
+    }
+    for level in asks.iter().rev() {
+        lines.push(level_line(level, max_quantity, bar_width, ASK_COLOR));
+    }
+
+    // Spread
+    let spread_text = match (book.spread(), book.spread_percent()) {
+        // Mode discret : spread en % seulement
+        (_, Some(percent)) if privacy() => format!("Spread {:.3}%", percent),
+        (Some(spread), Some(percent)) => format!("Spread {:.2} ({:.3}%)", spread, percent),
+        _ => "Spread N/A".to_string(),
+    };
+    lines.push(Line::from(Span::styled(
+        format!("{:─^width$}", format!(" {} ", spread_text), width = inner_width),
+        Style::default().fg(Color::Yellow),
+    )));
+
+    // Bids : du meilleur au moins cher
+    for level in book.bids.iter().take(rows_per_side) {
+        lines.push(level_line(level, max_quantity, bar_width, BID_COLOR));
+    }
+
+    lines.push(Line::from(Span::styled(
+        format!("Binance · {}", book.updated_at.format("%H:%M:%S UTC")),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let paragraph = Paragraph::new(lines).block(block);
+    frame.render_widget(paragraph, area);
+}
+
+/// Construit la ligne d'un niveau de prix avec sa barre de taille
+fn level_line(level: &BookLevel, max_quantity: f64, bar_width: usize, color: Color) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            if privacy() {
+                format!("{:>width$}", MASK, width = PRICE_WIDTH)
+            } else {
+                format!("{:>width$.2}", level.price, width = PRICE_WIDTH)
+            },
+            Style::default().fg(color),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("{:>width$.4}", level.quantity, width = QUANTITY_WIDTH),
+            Style::default().fg(Color::White),
+        ),
+        Span::raw(" "),
+        Span::styled(size_bar(level.quantity, max_quantity, bar_width), Style::default().fg(color)),
+    ])
+}
+
+/// Barre proportionnelle à la quantité (au moins un caractère si quantité > 0)
+fn size_bar(quantity: f64, max_quantity: f64, width: usize) -> String {
+    if max_quantity <= 0.0 || width == 0 {
+        return String::new();
+    }
+
+    let length = ((quantity / max_quantity) * width as f64).round() as usize;
+    let length = if quantity > 0.0 { length.clamp(1, width) } else { 0 };
+    "█".repeat(length)
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_bar() {
+        assert_eq!(size_bar(10.0, 10.0, 8).chars().count(), 8);
+        assert_eq!(size_bar(5.0, 10.0, 8).chars().count(), 4);
+        // Une toute petite quantité reste visible
+        assert_eq!(size_bar(0.001, 10.0, 8).chars().count(), 1);
+        assert_eq!(size_bar(0.0, 10.0, 8), "");
+        assert_eq!(size_bar(1.0, 0.0, 8), "");
+    }
+}
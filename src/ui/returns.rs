@@ -0,0 +1,132 @@
+// ============================================================================
+// Returns - Tableau des rendements de la watchlist
+// ============================================================================
+// Une ligne par ticker, une colonne par période, pour comparer rapidement :
+//
+//   Ticker        1D      1W      1M      3M      6M      1Y     YTD
+//   AAPL       +0.8%   +2.1%   -3.4%   +9.0%  +12.3%  +25.1%   +7.5%
+//   BTC-USD    -1.2%   +5.6%  +15.2%  +40.1%  +61.0% +120.4%  +55.3%
+//
+// Les rendements sont calculés sur l'historique D1 (chargé à l'ouverture de
+// la vue si absent du cache)
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::models::returns::{all_returns, ReturnPeriod};
+use crate::models::Interval;
+use crate::ui::dashboard::toast_line;
+
+/// Largeur de la colonne symbole et des colonnes de rendement
+const SYMBOL_WIDTH: usize = 10;
+const RETURN_WIDTH: usize = 9;
+
+/// Dessine la vue rendements (header, tableau, footer)
+pub fn render_returns(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Tableau
+            Constraint::Length(3), // Footer
+        ])
+        .split(area)
+        .to_vec();
+
+    let header = Paragraph::new(Line::from(Span::styled(
+        "Rendements calculés sur les clôtures journalières",
+        Style::default().fg(Color::Gray),
+    )))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" 📈 Rendements ")
+            .title_alignment(Alignment::Center),
+    )
+    .alignment(Alignment::Center);
+    frame.render_widget(header, chunks[0]);
+
+    render_table(frame, app, chunks[1]);
+    render_footer(frame, app, chunks[2]);
+}
+
+/// Dessine le tableau : en-têtes puis une ligne par ticker
+fn render_table(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let mut header = vec![Span::styled(
+        format!(" {:<width$}", "Ticker", width = SYMBOL_WIDTH),
+        Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD),
+    )];
+    header.extend(ReturnPeriod::ALL.iter().map(|period| {
+        Span::styled(
+            format!("{:>width$}", period.label(), width = RETURN_WIDTH),
+            Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD),
+        )
+    }));
+
+    let mut lines = vec![Line::from(header)];
+
+    for item in &app.watchlist {
+        let mut spans = vec![Span::styled(
+            format!(" {:<width$}", item.symbol, width = SYMBOL_WIDTH),
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        )];
+
+        match item.data_for(Interval::D1) {
+            Some(data) => spans.extend(all_returns(data).into_iter().map(return_cell)),
+            None => spans.push(Span::styled(
+                format!("{:>width$}", "Chargement...", width = RETURN_WIDTH * 2),
+                Style::default().fg(Color::DarkGray),
+            )),
+        }
+
+        lines.push(Line::from(spans));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Cellule de rendement colorée (— si l'historique est trop court)
+fn return_cell(value: Option<f64>) -> Span<'static> {
+    match value {
+        Some(value) => Span::styled(
+            format!("{:>width$}", format!("{:+.1}%", value), width = RETURN_WIDTH),
+            Style::default().fg(if value >= 0.0 { Color::Green } else { Color::Red }),
+        ),
+        None => Span::styled(
+            format!("{:>width$}", "—", width = RETURN_WIDTH),
+            Style::default().fg(Color::DarkGray),
+        ),
+    }
+}
+
+/// Dessine le footer (raccourcis ou notification)
+fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let line = match app.active_toast() {
+        Some(toast) => toast_line(toast),
+        None => Line::from(vec![
+            Span::styled("[v / ESC]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Retour  "),
+            Span::styled("[q]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Quit"),
+        ]),
+    };
+
+    let paragraph = Paragraph::new(line).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,184 @@
+// ============================================================================
+// Screener - Vue découverte (hausses, baisses, plus actifs)
+// ============================================================================
+// Affiche les résultats d'un screener prédéfini de Yahoo Finance :
+//
+//   ┌ 🔎 Découverte ─────────────────────────────────────────┐
+//   │  [Hausses]  Baisses  Plus actifs                       │
+//   ├────────────────────────────────────────────────────────┤
+//   │ ▶ NVDA     NVIDIA Corporation     $905.10  ▲ +6.20%  ✓ │   ← ✓ : déjà suivi
+//   │   AMD      Advanced Micro Devi…   $180.25  ▲ +4.87%    │
+//   └────────────────────────────────────────────────────────┘
+//
+// Enter ajoute le ticker sélectionné à la watchlist
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::models::screener::{Screener, ScreenerQuote};
+use crate::models::TickerType;
+use crate::ui::dashboard::toast_line;
+use crate::ui::format::{format_change, format_price, format_volume};
+
+/// Dessine la vue découverte (onglets, résultats, footer)
+pub fn render_screener(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Onglets
+            Constraint::Min(0),    // Résultats
+            Constraint::Length(3), // Footer
+        ])
+        .split(area)
+        .to_vec();
+
+    render_tabs(frame, app, chunks[0]);
+    render_results(frame, app, chunks[1]);
+    render_footer(frame, app, chunks[2]);
+}
+
+/// Dessine les onglets des screeners (l'onglet actif est surligné)
+fn render_tabs(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" 🔎 Découverte ")
+        .title_alignment(Alignment::Center);
+
+    let spans: Vec<Span> = Screener::ALL
+        .iter()
+        .map(|screener| {
+            if *screener == app.screener {
+                Span::styled(
+                    format!(" [{}] ", screener.label()),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled(format!("  {}  ", screener.label()), Style::default().fg(Color::Gray))
+            }
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(Line::from(spans)).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+/// Dessine la liste des résultats (ou un message pendant le chargement)
+fn render_results(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(" {} du jour ", app.screener.label()));
+
+    if app.screener_quotes.is_empty() {
+        let message = if app.is_loading_data() { "Chargement..." } else { "Aucun résultat" };
+        let text = vec![
+            Line::from(""),
+            Line::from(Span::styled(message, Style::default().fg(Color::Gray))),
+        ];
+        let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .screener_quotes
+        .iter()
+        .enumerate()
+        .map(|(index, quote)| {
+            let selected = index == app.screener_index;
+            let color = if quote.change_percent >= 0.0 { Color::Green } else { Color::Red };
+            let mut style = Style::default().fg(color);
+            if selected {
+                style = style.add_modifier(Modifier::BOLD).add_modifier(Modifier::REVERSED);
+            }
+
+            let added = if app.is_in_watchlist(&quote.symbol) { "  ✓" } else { "" };
+            ListItem::new(format!("{}{}", quote_line(quote, selected), added)).style(style)
+        })
+        .collect();
+
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+/// Formate une ligne de résultat : symbole, nom, prix, variation, volume
+fn quote_line(quote: &ScreenerQuote, selected: bool) -> String {
+    let marker = if selected { "▶ " } else { "  " };
+    let ticker_type = TickerType::from_symbol(&quote.symbol);
+    let name: String = if quote.name.chars().count() > 24 {
+        format!("{}…", quote.name.chars().take(23).collect::<String>())
+    } else {
+        quote.name.clone()
+    };
+    let volume = quote
+        .volume
+        .map(|volume| format!("  Vol {}", format_volume(volume)))
+        .unwrap_or_default();
+
+    format!(
+        "{}{:<8} {:<24} {:>12}  {}{}",
+        marker,
+        quote.symbol,
+        name,
+        format_price(ticker_type, quote.price),
+        format_change(ticker_type, &quote.symbol, None, quote.change_percent),
+        volume
+    )
+}
+
+/// Dessine le footer (raccourcis ou notification)
+fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let line = match app.active_toast() {
+        Some(toast) => toast_line(toast),
+        None => Line::from(vec![
+            Span::styled("[↑↓ / j k]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Navigate  "),
+            Span::styled("[h / l]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Liste  "),
+            Span::styled("[Enter]", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(" Ajouter à la watchlist  "),
+            Span::styled("[s / ESC]", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Retour"),
+        ]),
+    };
+
+    let paragraph = Paragraph::new(line).block(block).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_line() {
+        let quote = ScreenerQuote {
+            symbol: "NVDA".to_string(),
+            name: "NVIDIA Corporation".to_string(),
+            price: 905.1,
+            change_percent: 6.2,
+            volume: Some(52_300_000),
+        };
+
+        let line = quote_line(&quote, true);
+        assert!(line.starts_with("▶ NVDA"));
+        assert!(line.contains("$905.10"));
+        assert!(line.contains("▲ +6.20%"));
+        assert!(line.ends_with("Vol 52.3M"));
+    }
+}
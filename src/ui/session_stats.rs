@@ -0,0 +1,150 @@
+// ============================================================================
+// Session Stats - Panneau des statistiques de la séance (intraday)
+// ============================================================================
+// Affiché à droite du graphique (touche s), calculé sur les bougies de la
+// séance en cours (voir models/session_stats.rs) :
+//
+//   ┌ 📊 Séance du 14/10 ─────────┐
+//   │ Ouverture        $271.20    │
+//   │ Plus haut        $273.10    │
+//   │ Plus bas         $269.85    │
+//   │ Dernier          $272.40    │
+//   │ Variation         +0.44%    │
+//   │ VWAP             $271.90    │
+//   │ Volume             48.2M    │
+//   │ Amplitude   $3.25 (1.20%)   │
+//   │ vs moy. 20 j  79% ███░░░░░  │
+//   └─────────────────────────────┘
+//
+// - Mode discret : prix masqués, pourcentages et volume conservés
+// ============================================================================
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::models::session_stats::{SessionStats, AVERAGE_RANGE_SESSIONS};
+use crate::models::WatchlistItem;
+use crate::ui::format::{format_price_in, format_volume, privacy, MASK};
+
+/// Largeur du panneau (bordures incluses)
+pub const SESSION_STATS_PANEL_WIDTH: u16 = 32;
+
+/// Hauteur du panneau : 9 lignes et les bordures
+pub const SESSION_STATS_PANEL_HEIGHT: u16 = 11;
+
+/// Largeur de la jauge d'amplitude (2 × la moyenne la remplit)
+const RANGE_GAUGE_WIDTH: usize = 8;
+
+/// Dessine le panneau des statistiques de séance
+pub fn render_session_stats(frame: &mut Frame, item: &WatchlistItem, stats: &SessionStats, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White))
+        .title(format!(" 📊 Séance du {} ", stats.date.format("%d/%m")));
+
+    let frame_width = area.width.saturating_sub(2) as usize;
+    let price = |value: f64| {
+        if privacy() {
+            MASK.to_string()
+        } else {
+            format_price_in(item.ticker_type(), &item.currency(), value)
+        }
+    };
+    let change_color = |value: f64| if value >= 0.0 { Color::Green } else { Color::Red };
+
+    let mut lines = vec![
+        stat_line("Ouverture", price(stats.open), Color::White, frame_width),
+        stat_line("Plus haut", price(stats.high), Color::Green, frame_width),
+        stat_line("Plus bas", price(stats.low), Color::Red, frame_width),
+        stat_line("Dernier", price(stats.last), Color::White, frame_width),
+    ];
+    if let Some(change) = stats.change_percent() {
+        lines.push(stat_line("Variation", format!("{:+.2}%", change), change_color(change), frame_width));
+    }
+    lines.push(match stats.vwap {
+        Some(vwap) => stat_line("VWAP", price(vwap), Color::Cyan, frame_width),
+        None => stat_line("VWAP", "—".to_string(), Color::DarkGray, frame_width),
+    });
+    lines.push(match stats.volume {
+        0 => stat_line("Volume", "—".to_string(), Color::DarkGray, frame_width),
+        volume => stat_line("Volume", format_volume(volume), Color::White, frame_width),
+    });
+
+    let range = if stats.open != 0.0 {
+        format!("{} ({:.2}%)", price(stats.range()), stats.range() / stats.open * 100.0)
+    } else {
+        price(stats.range())
+    };
+    lines.push(stat_line("Amplitude", range, Color::White, frame_width));
+    lines.push(range_ratio_line(stats.range_ratio(), frame_width));
+
+    let paragraph = Paragraph::new(lines).block(block).alignment(Alignment::Left);
+    frame.render_widget(paragraph, area);
+}
+
+/// Ligne "libellé ........ valeur" (valeur alignée à droite)
+fn stat_line(label: &str, value: String, color: Color, width: usize) -> Line<'static> {
+    let label = format!(" {}", label);
+    let padding = width.saturating_sub(label.chars().count() + value.chars().count() + 1);
+    Line::from(vec![
+        Span::styled(label, Style::default().fg(Color::Gray)),
+        Span::raw(" ".repeat(padding)),
+        Span::styled(value, Style::default().fg(color).add_modifier(Modifier::BOLD)),
+    ])
+}
+
+/// Amplitude rapportée à la moyenne : "79% ███░░░░░" (jaune au-delà de 150%)
+fn range_ratio_line(ratio: Option<f64>, width: usize) -> Line<'static> {
+    let label = format!("vs moy. {} j", AVERAGE_RANGE_SESSIONS);
+    let Some(ratio) = ratio else {
+        return stat_line(&label, "—".to_string(), Color::DarkGray, width);
+    };
+
+    let filled = ((ratio / 2.0).clamp(0.0, 1.0) * RANGE_GAUGE_WIDTH as f64).round() as usize;
+    let color = if ratio >= 1.5 { Color::Yellow } else { Color::White };
+    let value = format!("{:.0}% {}{}", ratio * 100.0, "█".repeat(filled), "░".repeat(RANGE_GAUGE_WIDTH - filled));
+    stat_line(&label, value, color, width)
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use crate::ui::snapshot::{buffer_text, render_to_buffer};
+
+    #[test]
+    fn test_session_stats_panel() {
+        let item = WatchlistItem::new("AAPL".to_string(), "Apple Inc.".to_string());
+        let stats = SessionStats {
+            date: NaiveDate::from_ymd_opt(2026, 10, 14).unwrap(),
+            open: 100.0,
+            high: 103.0,
+            low: 99.0,
+            last: 102.0,
+            vwap: Some(101.5),
+            volume: 48_200_000,
+            average_range: Some(5.0),
+        };
+
+        let panel = |frame: &mut Frame| {
+            let area = Rect::new(0, 0, SESSION_STATS_PANEL_WIDTH, SESSION_STATS_PANEL_HEIGHT);
+            render_session_stats(frame, &item, &stats, area)
+        };
+        let text = buffer_text(&render_to_buffer(SESSION_STATS_PANEL_WIDTH, SESSION_STATS_PANEL_HEIGHT, panel));
+        assert!(text.contains("Séance du 14/10"), "{}", text);
+        assert!(text.contains("+2.00%"), "{}", text);
+        assert!(text.contains("48.2M"), "{}", text);
+        assert!(text.contains("80% ███░░░░░"), "{}", text);
+        // Tout tient dans le cadre : dernière ligne de contenu encore visible
+        assert!(text.lines().nth(SESSION_STATS_PANEL_HEIGHT as usize - 2).unwrap().contains("vs moy. 20 j"), "{}", text);
+    }
+}
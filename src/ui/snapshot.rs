@@ -0,0 +1,90 @@
+// ============================================================================
+// Snapshot - Tests de rendu sur un terminal virtuel
+// ============================================================================
+// Dessine une vue dans un ratatui::backend::TestBackend de taille fixe puis
+// compare le texte du buffer à un fichier de référence ("golden") :
+//
+//   src/ui/snapshots/<nom>.txt
+//
+// Après un changement d'affichage volontaire, régénérer les références :
+//
+//   UPDATE_SNAPSHOTS=1 cargo test
+//
+// CONCEPT : Buffer texte
+// - Seuls les symboles sont comparés (pas les couleurs) : une référence
+//   reste lisible dans un diff
+// - Un caractère large (emoji) occupe deux cellules, la seconde est un
+//   espace
+// ============================================================================
+
+use std::path::PathBuf;
+
+use chrono::{Duration, TimeZone, Utc};
+use ratatui::{backend::TestBackend, buffer::Buffer, Frame, Terminal};
+
+use crate::models::{Interval, OHLCData, Timeframe, WatchlistItem, OHLC};
+
+/// Dessine avec `draw` sur un terminal virtuel de `width` × `height`
+pub fn render_to_buffer(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(draw).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+/// Texte du buffer, une ligne par rangée (espaces de fin retirés)
+pub fn buffer_text(buffer: &Buffer) -> String {
+    let width = buffer.area.width as usize;
+    buffer
+        .content
+        .chunks(width)
+        .map(|row| {
+            let line: String = row.iter().map(|cell| cell.symbol()).collect();
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Compare le rendu à sa référence (ou l'écrit avec UPDATE_SNAPSHOTS=1)
+pub fn assert_snapshot(name: &str, buffer: &Buffer) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/ui/snapshots")
+        .join(format!("{}.txt", name));
+    let actual = buffer_text(buffer) + "\n";
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("Référence absente : {} (lancer UPDATE_SNAPSHOTS=1 cargo test)", path.display())
+    });
+    assert!(
+        expected == actual,
+        "Le rendu ne correspond plus à {}\n--- attendu ---\n{}\n--- obtenu ---\n{}",
+        path.display(),
+        expected,
+        actual
+    );
+}
+
+/// Item déterministe : `count` bougies journalières autour de `base_price`
+pub fn sample_item(symbol: &str, name: &str, base_price: f64, count: usize) -> WatchlistItem {
+    let mut data = OHLCData::new(symbol.to_string(), Interval::D1, Timeframe::SixMonths);
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    for i in 0..count {
+        let open = base_price * (1.0 + (i as f64 * 0.4).sin() * 0.05);
+        let close = open * if i % 3 == 0 { 0.99 } else { 1.01 };
+        data.add_candle(OHLC::new(
+            start + Duration::days(i as i64),
+            open,
+            open.max(close) * 1.005,
+            open.min(close) * 0.995,
+            close,
+            1_000_000,
+        ));
+    }
+    WatchlistItem::with_data(symbol.to_string(), name.to_string(), data)
+}
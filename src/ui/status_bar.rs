@@ -0,0 +1,146 @@
+// ============================================================================
+// Barre d'état : une ligne en bas de tous les écrans
+// ============================================================================
+// " ● en ligne │ Maj 14:32 │ 2 en chargement │ 1 en erreur │ ✓ Export terminé"
+//
+// - Connectivité : pire état des fournisseurs (détail dans le header), ou
+//   bandeau rouge hors ligne quand la sonde réseau échoue
+// - Heure du dernier chargement réussi
+// - Tickers sans données (en chargement) ou en échec, masqués à zéro
+// - Mode discret actif (montants masqués)
+// - Dernière notification : en couleur tant qu'elle est active, grisée
+//   ensuite (le footer la remplace par les raccourcis après expiration)
+// ============================================================================
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::api::health::HealthState;
+use crate::app::App;
+use crate::ui::format::privacy;
+
+/// Dessine la barre d'état (une ligne, sans bordure)
+pub fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
+    frame.render_widget(Paragraph::new(status_line(app)), area);
+}
+
+/// Construit la ligne de la barre d'état
+pub fn status_line(app: &App) -> Line<'static> {
+    let separator = || Span::styled(" │ ", Style::default().fg(Color::DarkGray));
+    let mut spans = vec![Span::raw(" ")];
+
+    // Connectivité : le fournisseur le plus en difficulté l'emporte
+    let worst = app
+        .provider_health
+        .iter()
+        .map(|status| status.state)
+        .max_by_key(|state| match state {
+            HealthState::Ok => 0,
+            HealthState::Degraded => 1,
+            HealthState::RateLimited => 2,
+        });
+    if let Some(since) = app.offline_since {
+        // Bandeau hors ligne : les données affichées sont celles en cache
+        spans.push(Span::styled(
+            format!("⚠ Hors ligne depuis {} — données en cache ", since.format("%H:%M")),
+            Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    } else {
+        let (label, color) = match worst {
+            None => ("en attente", Color::DarkGray),
+            Some(HealthState::Ok) => ("en ligne", Color::Green),
+            Some(state) => (state.label(), if state == HealthState::Degraded { Color::Yellow } else { Color::Red }),
+        };
+        spans.push(Span::styled(format!("● {}", label), Style::default().fg(color)));
+    }
+
+    spans.push(separator());
+    let refresh = app
+        .last_refresh
+        .map(|at| format!("Maj {}", at.format("%H:%M")))
+        .unwrap_or_else(|| "Maj --:--".to_string());
+    spans.push(Span::styled(refresh, Style::default().fg(Color::Gray)));
+
+    let loading = app
+        .watchlist
+        .iter()
+        .filter(|item| !item.has_data() && item.load_error.is_none())
+        .count();
+    let errors = app.watchlist.iter().filter(|item| item.load_error.is_some()).count();
+    if loading > 0 {
+        spans.push(separator());
+        spans.push(Span::styled(format!("{} en chargement", loading), Style::default().fg(Color::Yellow)));
+    }
+    if errors > 0 {
+        spans.push(separator());
+        spans.push(Span::styled(format!("{} en erreur", errors), Style::default().fg(Color::Red)));
+    }
+
+    if privacy() {
+        spans.push(separator());
+        spans.push(Span::styled("🔒 discret", Style::default().fg(Color::Magenta)));
+    }
+
+    if let Some(toast) = &app.last_toast {
+        let color = match (app.active_toast().is_some(), toast.is_error) {
+            (false, _) => Color::DarkGray,
+            (true, true) => Color::Red,
+            (true, false) => Color::Green,
+        };
+        let icon = if toast.is_error { "✗" } else { "✓" };
+        spans.push(separator());
+        spans.push(Span::styled(format!("{} {}", icon, toast.message), Style::default().fg(color)));
+    }
+
+    Line::from(spans)
+}
+
+// ============================================================================
+// Tests unitaires
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::health::{Provider, ProviderStatus};
+    use crate::models::WatchlistItem;
+    use crate::ui::snapshot::sample_item;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|span| span.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_status_line() {
+        let mut failed = WatchlistItem::new("MSFT".to_string(), "Microsoft".to_string());
+        failed.load_error = Some("timeout".to_string());
+        let mut app = App::with_watchlist(vec![
+            sample_item("AAPL", "Apple Inc.", 170.0, 30),
+            WatchlistItem::new("TSLA".to_string(), "Tesla".to_string()),
+            failed,
+        ]);
+        assert_eq!(text(&status_line(&app)), " ● en attente │ Maj --:-- │ 1 en chargement │ 1 en erreur");
+
+        app.provider_health = vec![
+            ProviderStatus { provider: Provider::Yahoo, state: HealthState::Ok, last_error: None },
+            ProviderStatus { provider: Provider::Binance, state: HealthState::RateLimited, last_error: None },
+        ];
+        app.watchlist.truncate(1);
+        app.show_error_toast("Export échoué".to_string());
+        assert_eq!(text(&status_line(&app)), " ● limité │ Maj --:-- │ ✗ Export échoué");
+
+        // Notification expirée (effacée par tick) : toujours visible dans la barre
+        app.toast = None;
+        assert!(text(&status_line(&app)).ends_with("✗ Export échoué"));
+
+        app.set_online(false);
+        let line = text(&status_line(&app));
+        assert!(line.starts_with(" ⚠ Hors ligne depuis "), "{}", line);
+        assert!(line.contains(" — données en cache "), "{}", line);
+    }
+}
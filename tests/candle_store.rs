@@ -0,0 +1,61 @@
+// ============================================================================
+// Test d'intégration : historique local des bougies (SQLite)
+// ============================================================================
+// Les réponses Yahoo sont rejouées (--replay) : la base accumule les bougies
+// et reste servie quand la réponse n'est plus disponible
+//
+// Processus séparé de yahoo_mock.rs : la base et le rejeu sont globaux
+// ============================================================================
+
+#![cfg(feature = "store")]
+
+use chrono::TimeZone;
+
+use lazywallet::api::recording::{self, Mode, Recording};
+use lazywallet::api::yahoo::fetch_ticker_data;
+use lazywallet::models::{Interval, OHLCData, OHLC};
+use lazywallet::store::{self, CandleStore};
+
+#[tokio::test]
+async fn test_store_keeps_history_and_serves_it_offline() {
+    let dir = std::env::temp_dir().join(format!("lazywallet_store_{}", std::process::id()));
+    let database = dir.join("candles.sqlite");
+    let recordings = dir.join("recordings");
+    let body = std::fs::read_to_string(format!("{}/tests/fixtures/chart_aapl.json", env!("CARGO_MANIFEST_DIR"))).unwrap();
+
+    recording::save(
+        &recordings,
+        &Recording {
+            url: "https://query1.finance.yahoo.com/v8/finance/chart/AAPL?interval=1d&period1=1&period2=2".to_string(),
+            status: 200,
+            body,
+        },
+    )
+    .unwrap();
+    assert!(recording::set_mode(Mode::Replay(recordings.clone())));
+    store::enable(&database).unwrap();
+
+    // Premier chargement : série complète, enregistrée dans la base
+    let (data, _) = fetch_ticker_data("AAPL", Interval::D1).await.unwrap();
+    assert_eq!(data.len(), 5);
+
+    // Bougie ancienne que Yahoo ne fournit plus : gardée par la base
+    let mut old = OHLCData::with_interval("AAPL".to_string(), Interval::D1);
+    old.add_candle(OHLC::new(chrono::Utc.with_ymd_and_hms(2020, 1, 2, 14, 30, 0).unwrap(), 75.0, 75.5, 74.0, 75.1, 1));
+    CandleStore::open(&database).unwrap().save(&old).unwrap();
+
+    let (data, _) = fetch_ticker_data("AAPL", Interval::D1).await.unwrap();
+    assert_eq!(data.len(), 6);
+    assert_eq!(data.candles[0].close, 75.1);
+
+    // Réponse indisponible : la série connue est servie depuis la base
+    std::fs::remove_dir_all(&recordings).unwrap();
+    let (data, long_name) = fetch_ticker_data("AAPL", Interval::D1).await.unwrap();
+    assert_eq!(data.len(), 6);
+    assert_eq!(long_name, None);
+
+    // Série jamais téléchargée : l'erreur reste visible
+    assert!(fetch_ticker_data("MSFT", Interval::D1).await.is_err());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,47 @@
+// ============================================================================
+// Test d'intégration : instrument personnalisé lu depuis un fichier CSV
+// ============================================================================
+// Processus séparé : les instruments personnalisés sont globaux
+// ============================================================================
+
+use std::io::Write;
+
+use lazywallet::api::csv::{self, CustomInstrument};
+use lazywallet::api::yahoo::fetch_ticker_data;
+use lazywallet::models::Interval;
+
+#[tokio::test]
+async fn test_csv_instrument_is_reread_on_refresh() {
+    let path = std::env::temp_dir().join(format!("lazywallet_fonds_{}.csv", std::process::id()));
+    std::fs::write(
+        &path,
+        "timestamp,open,high,low,close,volume\n2024-03-01,100,101,99,100.5,0\n2024-03-04,100.5,102,100,101.8,0\n",
+    )
+    .unwrap();
+
+    assert!(csv::configure(&[CustomInstrument {
+        symbol: "FONDS-X".to_string(),
+        name: Some("Fonds privé X".to_string()),
+        csv: path.clone(),
+    }]));
+
+    // Intervalle plus fin que le fichier : bougies journalières telles quelles
+    let (data, name) = fetch_ticker_data("FONDS-X", Interval::M30).await.unwrap();
+    assert_eq!(name.as_deref(), Some("Fonds privé X"));
+    assert_eq!(data.interval, Interval::D1);
+    assert_eq!(data.len(), 2);
+
+    // Une ligne ajoutée au fichier apparaît au rechargement suivant
+    let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+    writeln!(file, "2024-03-05,101.8,103,101,102.4,0").unwrap();
+    let (data, _) = fetch_ticker_data("FONDS-X", Interval::D1).await.unwrap();
+    assert_eq!(data.len(), 3);
+    assert_eq!(data.candles.last().unwrap().close, 102.4);
+
+    // Agrégé en semaines (lundi 4 et mardi 5 dans la même)
+    let (weekly, _) = fetch_ticker_data("FONDS-X", Interval::W1).await.unwrap();
+    assert_eq!(weekly.interval, Interval::W1);
+    assert_eq!(weekly.len(), 2);
+
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,45 @@
+// ============================================================================
+// Test d'intégration : timeout du client HTTP
+// ============================================================================
+// Un serveur accepte la connexion mais ne répond jamais (proxy bloqué) :
+// la requête doit échouer après le timeout configuré au lieu de rester figée
+//
+// Processus séparé de yahoo_mock.rs : les réglages du client sont globaux
+// ============================================================================
+
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+use lazywallet::api::http::{self, HttpSettings};
+use lazywallet::api::yahoo::{fetch_ticker_data, set_base_url};
+use lazywallet::models::Interval;
+
+#[tokio::test]
+async fn test_stalled_server_times_out() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    // Garde les connexions ouvertes sans jamais répondre
+    std::thread::spawn(move || {
+        let mut connections = Vec::new();
+        for stream in listener.incoming().flatten() {
+            connections.push(stream);
+        }
+    });
+
+    http::configure(&HttpSettings {
+        timeout_secs: 1,
+        ..HttpSettings::default()
+    })
+    .unwrap();
+    assert!(set_base_url(&format!("http://{}", address)));
+
+    let started = Instant::now();
+    let result = fetch_ticker_data("AAPL", Interval::D1).await;
+
+    assert!(result.is_err());
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    // Le client est déjà créé : il ne peut plus être reconfiguré
+    assert!(http::configure(&HttpSettings::default()).is_err());
+}
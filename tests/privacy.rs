@@ -0,0 +1,64 @@
+// ============================================================================
+// Test d'intégration : mode discret (montants masqués)
+// ============================================================================
+// Processus séparé : le mode discret est global et fausserait les tests de
+// rendu de la bibliothèque lancés en parallèle
+// ============================================================================
+
+#![cfg(feature = "tui")]
+
+use chrono::{Duration, TimeZone, Utc};
+use ratatui::{backend::TestBackend, Terminal};
+
+use lazywallet::app::App;
+use lazywallet::models::{Interval, OHLCData, TickerType, Timeframe, WatchlistItem, OHLC};
+use lazywallet::ui::{dashboard, format};
+
+/// Item de 30 bougies journalières en hausse régulière
+fn item(symbol: &str, base_price: f64) -> WatchlistItem {
+    let mut data = OHLCData::new(symbol.to_string(), Interval::D1, Timeframe::SixMonths);
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    for i in 0..30 {
+        let open = base_price + i as f64;
+        data.add_candle(OHLC::new(start + Duration::days(i), open, open + 2.0, open - 1.0, open + 1.0, 1_000));
+    }
+    WatchlistItem::with_data(symbol.to_string(), symbol.to_string(), data)
+}
+
+/// Texte du dashboard rendu sur un terminal virtuel
+fn dashboard_text(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+    terminal.draw(|frame| dashboard::render(frame, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    buffer
+        .content
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test_privacy_masks_amounts_but_keeps_percentages() {
+    let mut app = App::with_watchlist(vec![item("AAPL", 170.0), item("MSFT", 400.0)]);
+    app.portfolio.set_position("AAPL", 10.0);
+
+    let visible = dashboard_text(&app);
+    assert!(visible.contains("$200.00"), "{}", visible);
+    assert!(!visible.contains("discret"), "{}", visible);
+
+    format::set_privacy(true);
+    assert_eq!(format::format_price(TickerType::Stock, 200.0), format::MASK);
+    assert_eq!(format::format_money(1990.0), format::MASK);
+    assert_eq!(format::format_signed_money(-4.0), format::MASK);
+    assert_eq!(format::format_change(TickerType::Index, "^GSPC", Some(45.2), 0.87), "▲ +0.87%");
+
+    let masked = dashboard_text(&app);
+    assert!(!masked.contains('$'), "{}", masked);
+    assert!(masked.contains(format::MASK), "{}", masked);
+    assert!(masked.contains('%'), "{}", masked);
+    assert!(masked.contains("discret"), "{}", masked);
+
+    format::set_privacy(false);
+    assert_eq!(dashboard_text(&app), visible);
+}
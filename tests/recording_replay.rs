@@ -0,0 +1,37 @@
+// ============================================================================
+// Test d'intégration : rejeu d'une réponse Yahoo enregistrée
+// ============================================================================
+// Processus séparé de yahoo_mock.rs : le mode d'enregistrement est global
+// ============================================================================
+
+use lazywallet::api::recording::{self, Mode, Recording};
+use lazywallet::api::yahoo::fetch_ticker_data;
+use lazywallet::models::Interval;
+
+#[tokio::test]
+async fn test_replay_serves_recorded_chart() {
+    let dir = std::env::temp_dir().join(format!("lazywallet_replay_{}", std::process::id()));
+    let body = std::fs::read_to_string(format!("{}/tests/fixtures/chart_aapl.json", env!("CARGO_MANIFEST_DIR"))).unwrap();
+
+    // Enregistrement fait "hier" : period1 / period2 différents de la requête rejouée
+    recording::save(
+        &dir,
+        &Recording {
+            url: "https://query1.finance.yahoo.com/v8/finance/chart/AAPL?interval=1d&period1=1&period2=2".to_string(),
+            status: 200,
+            body,
+        },
+    )
+    .unwrap();
+    assert!(recording::set_mode(Mode::Replay(dir.clone())));
+
+    let (data, long_name) = fetch_ticker_data("AAPL", Interval::D1).await.unwrap();
+    assert_eq!(long_name.as_deref(), Some("Apple Inc."));
+    assert_eq!(data.len(), 5);
+
+    // Pas d'enregistrement : erreur explicite, aucun appel réseau
+    let error = fetch_ticker_data("MSFT", Interval::D1).await.unwrap_err();
+    assert!(error.to_string().contains("Aucun enregistrement"), "{}", error);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
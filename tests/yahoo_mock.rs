@@ -0,0 +1,239 @@
+// ============================================================================
+// Tests d'intégration : client Yahoo Finance contre un serveur local
+// ============================================================================
+// Un serveur HTTP (hyper) rejoue des réponses enregistrées (tests/fixtures)
+// selon le symbole demandé, à la place de query1/query2.finance.yahoo.com :
+//
+//   AAPL     → réponse normale
+//   GAPS     → bougies incomplètes (valeurs null)
+//   EMPTY    → aucune bougie
+//   DELISTED → HTTP 404 avec la charge utile "chart.error" de Yahoo
+//   LIMITED  → HTTP 429 à chaque requête
+//   FLAKY    → HTTP 429 une fois, puis réponse normale
+//
+// quoteSummary exige une session : cookie "A3" (servi sur "/") et le dernier
+// crumb délivré par /v1/test/getcrumb, sinon HTTP 401 "Invalid Crumb"
+//
+// CONCEPT : Serveur partagé
+// - set_base_url() ne peut être appelé qu'une fois par processus
+// - Le serveur tourne sur son propre thread (et son propre runtime tokio),
+//   chaque #[tokio::test] ayant un runtime qui s'arrête avec le test
+// ============================================================================
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server, StatusCode};
+
+use lazywallet::api::yahoo::{fetch_earnings_date, fetch_ticker_data, set_base_url, ChartError};
+use lazywallet::models::Interval;
+
+/// Nombre de requêtes reçues pour FLAKY
+static FLAKY_HITS: AtomicUsize = AtomicUsize::new(0);
+
+/// Nombre de requêtes reçues pour LIMITED
+static LIMITED_HITS: AtomicUsize = AtomicUsize::new(0);
+
+/// Nombre de crumbs délivrés (seul le dernier est valide)
+static CRUMBS_ISSUED: AtomicUsize = AtomicUsize::new(0);
+
+/// Cookie de session servi par le mock
+const SESSION_COOKIE: &str = "A3=mock-session";
+
+fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{} : {}", path, e))
+}
+
+fn respond(status: StatusCode, body: String) -> Response<Body> {
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = status;
+    response
+}
+
+fn too_many_requests() -> Response<Body> {
+    let mut response = respond(StatusCode::TOO_MANY_REQUESTS, "Too Many Requests".to_string());
+    response.headers_mut().insert("Retry-After", "0".parse().unwrap());
+    response
+}
+
+/// La requête porte-t-elle le cookie de session ?
+fn has_session_cookie(request: &Request<Body>) -> bool {
+    request
+        .headers()
+        .get("Cookie")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|cookie| cookie.contains(SESSION_COOKIE))
+}
+
+/// La requête porte-t-elle le dernier crumb délivré ?
+fn has_valid_crumb(request: &Request<Body>) -> bool {
+    let expected = format!("crumb={}", CRUMBS_ISSUED.load(Ordering::SeqCst));
+    request
+        .uri()
+        .query()
+        .is_some_and(|query| query.split('&').any(|param| param == expected))
+}
+
+/// Route une requête vers sa réponse enregistrée
+fn route(request: &Request<Body>) -> Response<Body> {
+    let path = request.uri().path();
+    let symbol = path.rsplit('/').next().unwrap_or_default();
+
+    if path == "/" {
+        let mut response = respond(StatusCode::NOT_FOUND, String::new());
+        let set_cookie = format!("{}; Domain=.yahoo.com; Path=/", SESSION_COOKIE);
+        response.headers_mut().insert("Set-Cookie", set_cookie.parse().unwrap());
+        return response;
+    }
+
+    if path == "/v1/test/getcrumb" {
+        if !has_session_cookie(request) {
+            return respond(StatusCode::UNAUTHORIZED, "{}".to_string());
+        }
+        let crumb = CRUMBS_ISSUED.fetch_add(1, Ordering::SeqCst) + 1;
+        return respond(StatusCode::OK, crumb.to_string());
+    }
+
+    if path.starts_with("/v10/finance/quoteSummary/") {
+        if !has_session_cookie(request) || !has_valid_crumb(request) {
+            let body = r#"{"finance":{"result":null,"error":{"code":"Unauthorized","description":"Invalid Crumb"}}}"#;
+            return respond(StatusCode::UNAUTHORIZED, body.to_string());
+        }
+        return respond(StatusCode::OK, fixture("quote_summary_aapl.json"));
+    }
+
+    match symbol {
+        "AAPL" => respond(StatusCode::OK, fixture("chart_aapl.json")),
+        "GAPS" => respond(StatusCode::OK, fixture("chart_missing_candles.json")),
+        "EMPTY" => respond(StatusCode::OK, fixture("chart_empty_quote.json")),
+        "DELISTED" => respond(StatusCode::NOT_FOUND, fixture("chart_not_found.json")),
+        "LIMITED" => {
+            LIMITED_HITS.fetch_add(1, Ordering::SeqCst);
+            too_many_requests()
+        }
+        "FLAKY" if FLAKY_HITS.fetch_add(1, Ordering::SeqCst) == 0 => too_many_requests(),
+        "FLAKY" => respond(StatusCode::OK, fixture("chart_aapl.json")),
+        _ => respond(StatusCode::NOT_FOUND, fixture("chart_not_found.json")),
+    }
+}
+
+/// Démarre le serveur (une seule fois) et y redirige le client Yahoo
+fn mock_server() -> SocketAddr {
+    static ADDRESS: OnceLock<SocketAddr> = OnceLock::new();
+
+    *ADDRESS.get_or_init(|| {
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().unwrap();
+            runtime.block_on(async move {
+                let make_service = make_service_fn(|_connection| async {
+                    Ok::<_, Infallible>(service_fn(|request| async move { Ok::<_, Infallible>(route(&request)) }))
+                });
+                let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+                tx.send(server.local_addr()).unwrap();
+                server.await.unwrap();
+            });
+        });
+
+        let address = rx.recv().unwrap();
+        assert!(set_base_url(&format!("http://{}", address)));
+        address
+    })
+}
+
+#[tokio::test]
+async fn test_normal_chart() {
+    mock_server();
+    let (data, long_name) = fetch_ticker_data("AAPL", Interval::D1).await.unwrap();
+
+    assert_eq!(long_name.as_deref(), Some("Apple Inc."));
+    assert_eq!(data.len(), 5);
+    assert_eq!(data.interval, Interval::D1);
+    let last = data.candles.last().unwrap();
+    assert_eq!(last.close, 183.38);
+    assert_eq!(last.volume, 163_224_100);
+}
+
+#[tokio::test]
+async fn test_h4_is_resampled_locally() {
+    mock_server();
+    let (data, _) = fetch_ticker_data("AAPL", Interval::H4).await.unwrap();
+
+    // Demandé en 1h à Yahoo puis agrégé : l'intervalle retourné reste 4h
+    assert_eq!(data.interval, Interval::H4);
+    assert_eq!(data.candles.last().unwrap().close, 183.38);
+}
+
+#[tokio::test]
+async fn test_missing_candles_are_skipped() {
+    mock_server();
+    let (data, long_name) = fetch_ticker_data("GAPS", Interval::D1).await.unwrap();
+
+    // Deux bougies incomplètes sur cinq (open ou close null)
+    assert!(long_name.is_none());
+    assert_eq!(data.len(), 3);
+    // Volume absent : 0
+    assert_eq!(data.candles[2].volume, 0);
+}
+
+#[tokio::test]
+async fn test_empty_chart_is_an_error() {
+    mock_server();
+    let error = fetch_ticker_data("EMPTY", Interval::D1).await.unwrap_err();
+    assert!(error.to_string().contains("Aucune donnée OHLC"), "{}", error);
+}
+
+#[tokio::test]
+async fn test_error_payload() {
+    mock_server();
+    let error = fetch_ticker_data("DELISTED", Interval::D1).await.unwrap_err();
+    // HTTP 404 : le message de chart.error est remonté tel quel
+    assert!(matches!(error.downcast_ref::<ChartError>(), Some(ChartError::Api { .. })), "{}", error);
+    assert!(error.to_string().contains("symbol may be delisted"), "{}", error);
+}
+
+#[tokio::test]
+async fn test_rate_limit_is_retried() {
+    mock_server();
+    let (data, _) = fetch_ticker_data("FLAKY", Interval::D1).await.unwrap();
+
+    assert_eq!(data.len(), 5);
+    assert_eq!(FLAKY_HITS.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_persistent_rate_limit_gives_up() {
+    mock_server();
+    let error = fetch_ticker_data("LIMITED", Interval::D1).await.unwrap_err();
+
+    assert!(error.to_string().contains("429"), "{}", error);
+    // Requête initiale + 2 nouvelles tentatives
+    assert_eq!(LIMITED_HITS.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_earnings_date() {
+    mock_server();
+    let date = fetch_earnings_date("AAPL").await.unwrap().unwrap();
+    assert_eq!(date.format("%Y-%m-%d").to_string(), "2024-05-02");
+}
+
+#[tokio::test]
+async fn test_expired_crumb_is_refreshed() {
+    let address = mock_server();
+    fetch_earnings_date("AAPL").await.unwrap();
+
+    // Un autre client obtient un crumb : celui en cache n'est plus valide
+    let crumb_url = format!("http://{}/v1/test/getcrumb", address);
+    let client = reqwest::Client::new();
+    client.get(&crumb_url).header("Cookie", SESSION_COOKIE).send().await.unwrap();
+    let issued = CRUMBS_ISSUED.load(Ordering::SeqCst);
+
+    let date = fetch_earnings_date("AAPL").await.unwrap().unwrap();
+    assert_eq!(date.format("%Y-%m-%d").to_string(), "2024-05-02");
+    assert!(CRUMBS_ISSUED.load(Ordering::SeqCst) > issued);
+}